@@ -0,0 +1,43 @@
+//! Parses shape libraries of growing size, each node shape carrying property
+//! shapes with a mix of constraints.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use shacl_rust::parse_shapes;
+use shacl_rust::rdf::read_graph_from_string;
+
+fn shapes(node_shapes: usize) -> String {
+    let mut shapes = String::from(
+        "@prefix ex: <http://example.org/> .\n\
+         @prefix sh: <http://www.w3.org/ns/shacl#> .\n\
+         @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n\
+         ex:PersonShape a sh:NodeShape ; sh:property [ sh:path ex:name ; sh:minCount 1 ] .\n",
+    );
+    for i in 0..node_shapes {
+        shapes.push_str(&format!(
+            "ex:Shape{i} a sh:NodeShape ; sh:targetClass ex:Class{i} ;\n\
+             \x20   sh:property [ sh:path ex:name{i} ; sh:datatype xsd:string ; sh:minCount 1 ; sh:maxLength 80 ] ;\n\
+             \x20   sh:property [ sh:path ex:age{i} ; sh:datatype xsd:integer ; sh:minInclusive 0 ; sh:maxCount 1 ] ;\n\
+             \x20   sh:property [ sh:path ex:email{i} ; sh:pattern \"^.+@.+$\" ; sh:nodeKind sh:Literal ] ;\n\
+             \x20   sh:property [ sh:path ex:status{i} ; sh:in ( \"active\" \"closed\" ) ] ;\n\
+             \x20   sh:property [ sh:path ex:knows{i} ; sh:class ex:Class{i} ; sh:node ex:PersonShape ] .\n"
+        ));
+    }
+    shapes
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    group.sample_size(10);
+    for node_shapes in [100, 1_000] {
+        let graph = read_graph_from_string(&shapes(node_shapes), "turtle").unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("parse_shapes", node_shapes),
+            &node_shapes,
+            |b, _| b.iter(|| parse_shapes(&graph).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
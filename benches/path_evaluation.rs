@@ -0,0 +1,59 @@
+//! Compares the path evaluation strategies on a data graph of people who know
+//! each other, with a direct, an inverse and a transitive path per person.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use shacl_rust::rdf::read_graph_from_string;
+use shacl_rust::validation::config::{PathEvaluation, ValidationConfig};
+use shacl_rust::validation::dataset::ValidationDataset;
+use shacl_rust::{parse_shapes, validate};
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:name ; sh:minCount 1 ] ;
+        sh:property [ sh:path [ sh:inversePath ex:knows ] ; sh:maxCount 5 ] ;
+        sh:property [ sh:path [ sh:oneOrMorePath ex:knows ] ; sh:minCount 1 ] .
+"#;
+
+fn data(people: usize) -> String {
+    let mut data = String::from("@prefix ex: <http://example.org/> .\n");
+    for i in 0..people {
+        data.push_str(&format!(
+            "ex:p{i} a ex:Person ; ex:name \"Person {i}\" ; ex:knows ex:p{} .\n",
+            (i * 7 + 1) % people
+        ));
+    }
+    data
+}
+
+fn bench_path_evaluation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("path_evaluation");
+    group.sample_size(10);
+    for people in [100, 1_000] {
+        for (name, path_evaluation) in [
+            ("walker", PathEvaluation::Walker),
+            ("sparql", PathEvaluation::Sparql),
+        ] {
+            let dataset = ValidationDataset::from_graphs(
+                read_graph_from_string(&data(people), "turtle").unwrap(),
+                read_graph_from_string(SHAPES, "turtle").unwrap(),
+            )
+            .unwrap()
+            .with_config(ValidationConfig {
+                path_evaluation,
+                ..ValidationConfig::default()
+            });
+            let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+            group.bench_with_input(BenchmarkId::new(name, people), &people, |b, _| {
+                b.iter(|| validate(&dataset, &shapes))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_path_evaluation);
+criterion_main!(benches);
@@ -0,0 +1,215 @@
+//! EARL reports for the W3C SHACL test suite, run by
+//! [`shacl_rust::conformance`].
+
+use log::warn;
+use oxigraph::model::{
+    vocab::{rdf, rdfs, xsd},
+    BlankNode, Graph, Literal, NamedNode, NamedNodeRef, NamedOrBlankNode, Triple,
+};
+use shacl_rust::conformance::TestOutcome;
+
+/// Evaluation and Report Language vocabulary.
+mod earl {
+    use oxigraph::model::NamedNodeRef;
+    pub const ASSERTION: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#Assertion");
+    pub const ASSERTED_BY: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#assertedBy");
+    pub const SUBJECT: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#subject");
+    pub const TEST: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#test");
+    pub const RESULT: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#result");
+    pub const MODE: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#mode");
+    pub const AUTOMATIC: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#automatic");
+    pub const TEST_RESULT: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#TestResult");
+    pub const OUTCOME: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#outcome");
+    pub const PASSED: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#passed");
+    pub const FAILED: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#failed");
+    pub const INFO: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#info");
+    pub const SOFTWARE: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#Software");
+    pub const TEST_SUBJECT: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/earl#TestSubject");
+}
+
+/// Description of a Project vocabulary.
+mod doap {
+    use oxigraph::model::NamedNodeRef;
+    pub const PROJECT: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://usefulinc.com/ns/doap#Project");
+    pub const NAME: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://usefulinc.com/ns/doap#name");
+    pub const HOMEPAGE: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://usefulinc.com/ns/doap#homepage");
+    pub const PROGRAMMING_LANGUAGE: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://usefulinc.com/ns/doap#programming-language");
+    pub const RELEASE: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://usefulinc.com/ns/doap#release");
+    pub const REVISION: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://usefulinc.com/ns/doap#revision");
+}
+
+const DC_DATE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/date");
+
+const PROJECT_IRI: &str = "https://github.com/ensaremirerol/shacl-rust";
+
+/// Builds an EARL report graph from test outcomes.
+pub fn earl_report(outcomes: &[TestOutcome], assertor: Option<&str>) -> Graph {
+    let mut graph = Graph::new();
+
+    let project = NamedNode::new_unchecked(PROJECT_IRI);
+    let assertor = assertor
+        .and_then(|iri| NamedNode::new(iri).ok())
+        .unwrap_or_else(|| project.clone());
+
+    graph.insert(&Triple::new(
+        project.clone(),
+        NamedNode::from(rdf::TYPE),
+        NamedNode::from(doap::PROJECT),
+    ));
+    graph.insert(&Triple::new(
+        project.clone(),
+        NamedNode::from(rdf::TYPE),
+        NamedNode::from(earl::SOFTWARE),
+    ));
+    graph.insert(&Triple::new(
+        project.clone(),
+        NamedNode::from(rdf::TYPE),
+        NamedNode::from(earl::TEST_SUBJECT),
+    ));
+    graph.insert(&Triple::new(
+        project.clone(),
+        NamedNode::from(doap::NAME),
+        Literal::new_simple_literal("shacl-rust"),
+    ));
+    graph.insert(&Triple::new(
+        project.clone(),
+        NamedNode::from(doap::HOMEPAGE),
+        project.clone(),
+    ));
+    graph.insert(&Triple::new(
+        project.clone(),
+        NamedNode::from(doap::PROGRAMMING_LANGUAGE),
+        Literal::new_simple_literal("Rust"),
+    ));
+
+    let release = BlankNode::default();
+    graph.insert(&Triple::new(
+        project.clone(),
+        NamedNode::from(doap::RELEASE),
+        release.clone(),
+    ));
+    graph.insert(&Triple::new(
+        release,
+        NamedNode::from(doap::REVISION),
+        Literal::new_simple_literal(env!("CARGO_PKG_VERSION")),
+    ));
+
+    let date =
+        Literal::new_typed_literal(oxsdatatypes::DateTime::now().to_string(), xsd::DATE_TIME);
+
+    for outcome in outcomes {
+        let Ok(test) = NamedNode::new(outcome.test.clone()) else {
+            warn!("Skipping test with invalid IRI: {}", outcome.test);
+            continue;
+        };
+
+        let assertion = NamedOrBlankNode::from(BlankNode::default());
+        graph.insert(&Triple::new(
+            assertion.clone(),
+            NamedNode::from(rdf::TYPE),
+            NamedNode::from(earl::ASSERTION),
+        ));
+        graph.insert(&Triple::new(
+            assertion.clone(),
+            NamedNode::from(earl::ASSERTED_BY),
+            assertor.clone(),
+        ));
+        graph.insert(&Triple::new(
+            assertion.clone(),
+            NamedNode::from(earl::SUBJECT),
+            project.clone(),
+        ));
+        graph.insert(&Triple::new(
+            assertion.clone(),
+            NamedNode::from(earl::TEST),
+            test.clone(),
+        ));
+        graph.insert(&Triple::new(
+            assertion.clone(),
+            NamedNode::from(earl::MODE),
+            NamedNode::from(earl::AUTOMATIC),
+        ));
+
+        let result = BlankNode::default();
+        graph.insert(&Triple::new(
+            assertion,
+            NamedNode::from(earl::RESULT),
+            result.clone(),
+        ));
+        graph.insert(&Triple::new(
+            result.clone(),
+            NamedNode::from(rdf::TYPE),
+            NamedNode::from(earl::TEST_RESULT),
+        ));
+        graph.insert(&Triple::new(
+            result.clone(),
+            NamedNode::from(earl::OUTCOME),
+            NamedNode::from(if outcome.passed {
+                earl::PASSED
+            } else {
+                earl::FAILED
+            }),
+        ));
+        graph.insert(&Triple::new(
+            result.clone(),
+            NamedNode::from(DC_DATE),
+            date.clone(),
+        ));
+        graph.insert(&Triple::new(
+            result,
+            NamedNode::from(earl::INFO),
+            Literal::new_simple_literal(outcome.info.clone()),
+        ));
+
+        if let Some(label) = &outcome.label {
+            graph.insert(&Triple::new(
+                test,
+                NamedNode::from(rdfs::LABEL),
+                Literal::new_simple_literal(label.clone()),
+            ));
+        }
+    }
+
+    graph
+}
+
+/// Converts outcomes into a short human-readable summary.
+pub fn summary(outcomes: &[TestOutcome]) -> String {
+    let passed = outcomes.iter().filter(|o| o.passed).count();
+    let failed = outcomes.len() - passed;
+
+    let mut text = format!(
+        "Conformance: {} passed, {} failed, {} total",
+        passed,
+        failed,
+        outcomes.len()
+    );
+    for outcome in outcomes.iter().filter(|o| !o.passed) {
+        text.push_str(&format!(
+            "\n  FAIL {} - {}",
+            outcome.label.as_deref().unwrap_or(&outcome.test),
+            outcome.info
+        ));
+    }
+    text
+}
@@ -0,0 +1,463 @@
+//! Opt-in "follow your nose" lookup of type information for `sh:class` checks.
+//!
+//! IRIs from the data graph that lack `rdf:type` statements, and classes that lack
+//! `rdfs:subClassOf` statements, are dereferenced over HTTP when they match the
+//! allowlist. Only `rdf:type` and `rdfs:subClassOf` triples about the requested IRI
+//! are merged into the data graph.
+
+use log::{debug, info, warn};
+use oxigraph::io::RdfFormat;
+use oxigraph::model::{
+    vocab::{rdf, rdfs},
+    Graph, NamedNode, NamedNodeRef, NamedOrBlankNodeRef, TermRef, Triple,
+};
+use sha2::{Digest, Sha256};
+use shacl_rust::{err::ShaclError, rdf as shacl_rdf, vocab::sh};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+const ACCEPT: &str = "text/turtle, application/n-triples;q=0.9, application/rdf+xml;q=0.8, application/ld+json;q=0.5";
+
+/// Most redirects followed for one document.
+const MAX_REDIRECTS: usize = 5;
+
+/// Fetches and caches remote RDF documents within an allowlist.
+pub struct Dereferencer {
+    agent: ureq::Agent,
+    allowlist: Vec<String>,
+    cache_dir: Option<PathBuf>,
+    interval: Duration,
+    max_requests: usize,
+    requests: usize,
+    last_request: Option<Instant>,
+    documents: HashMap<String, Option<Graph>>,
+}
+
+impl Dereferencer {
+    pub fn new(allowlist: Vec<String>) -> Self {
+        // Redirects are followed by hand, so each hop is checked against the allowlist
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .max_redirects(0)
+            .build()
+            .into();
+
+        Self {
+            agent,
+            allowlist,
+            cache_dir: None,
+            interval: Duration::from_millis(1000),
+            max_requests: 100,
+            requests: 0,
+            last_request: None,
+            documents: HashMap::new(),
+        }
+    }
+
+    /// Stores fetched documents in `dir` and reuses them on later runs.
+    pub fn with_cache_dir(mut self, dir: PathBuf) -> Self {
+        self.cache_dir = Some(dir);
+        self
+    }
+
+    /// Sets the minimum delay between two HTTP requests.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the maximum number of HTTP requests per run.
+    pub fn with_max_requests(mut self, max_requests: usize) -> Self {
+        self.max_requests = max_requests;
+        self
+    }
+
+    /// Adds remote type information to `data_graph` until no new IRIs turn up.
+    ///
+    /// Does nothing when the shapes graph has no `sh:class` constraints.
+    pub fn enrich(&mut self, data_graph: &mut Graph, shapes_graph: &Graph) {
+        if shapes_graph
+            .triples_for_predicate(sh::CLASS)
+            .next()
+            .is_none()
+        {
+            debug!("Shapes graph has no sh:class constraints, skipping dereferencing");
+            return;
+        }
+
+        let mut visited = HashSet::new();
+        loop {
+            let candidates: Vec<NamedNode> = lookup_candidates(data_graph, shapes_graph)
+                .into_iter()
+                .filter(|iri| self.is_allowed(iri.as_str()) && visited.insert(iri.clone()))
+                .collect();
+            if candidates.is_empty() {
+                break;
+            }
+
+            let mut added = 0;
+            for iri in candidates {
+                let Some(document) = self.fetch(document_url(iri.as_str())) else {
+                    continue;
+                };
+                let before = data_graph.len();
+                data_graph.extend(
+                    document
+                        .triples_for_subject(NamedOrBlankNodeRef::from(iri.as_ref()))
+                        .filter(|t| t.predicate == rdf::TYPE || t.predicate == rdfs::SUB_CLASS_OF)
+                        .map(Triple::from)
+                        .collect::<Vec<_>>(),
+                );
+                added += data_graph.len() - before;
+            }
+
+            info!("Dereferencing added {} triples to the data graph", added);
+            if added == 0 {
+                break;
+            }
+        }
+    }
+
+    fn is_allowed(&self, iri: &str) -> bool {
+        (iri.starts_with("http://") || iri.starts_with("https://"))
+            && self.allowlist.iter().any(|prefix| iri.starts_with(prefix))
+    }
+
+    fn fetch(&mut self, url: &str) -> Option<&Graph> {
+        if !self.documents.contains_key(url) {
+            let document = self.load(url).unwrap_or_else(|e| {
+                warn!("Failed to dereference {}: {}", url, e);
+                None
+            });
+            self.documents.insert(url.to_string(), document);
+        }
+        self.documents.get(url).and_then(|d| d.as_ref())
+    }
+
+    fn load(&mut self, url: &str) -> Result<Option<Graph>, ShaclError> {
+        if let Some(graph) = self.load_cached(url)? {
+            return Ok(Some(graph));
+        }
+
+        let mut location = url.to_string();
+        let mut redirects = 0;
+        let mut response = loop {
+            let Some(response) = self.get(&location)? else {
+                return Ok(None);
+            };
+            if !response.status().is_redirection() {
+                break response;
+            }
+            let target = response
+                .headers()
+                .get("location")
+                .and_then(|v| v.to_str().ok())
+                .ok_or_else(|| {
+                    ShaclError::Io(format!("{} redirected without a location", location))
+                })?;
+            let target = oxiri::Iri::parse(location.as_str())
+                .and_then(|base| base.resolve(target))
+                .map_err(|e| ShaclError::Io(format!("Invalid redirect from {}: {}", location, e)))?
+                .into_inner();
+            if redirects == MAX_REDIRECTS {
+                return Err(ShaclError::Io(format!(
+                    "More than {} redirects from {}",
+                    MAX_REDIRECTS, url
+                )));
+            }
+            if !self.is_allowed(&target) {
+                debug!(
+                    "Not following redirect from {} to {} outside the allowlist",
+                    location, target
+                );
+                return Ok(None);
+            }
+            redirects += 1;
+            location = target;
+        };
+
+        let content_type = response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.split(';').next().unwrap_or(v).trim().to_string())
+            .unwrap_or_default();
+        let Some(format) = RdfFormat::from_media_type(&content_type) else {
+            debug!("{} returned non-RDF content type '{}'", url, content_type);
+            return Ok(None);
+        };
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| ShaclError::Io(e.to_string()))?;
+
+        if let Some(path) = self.cache_path(url, format) {
+            if let Err(e) = std::fs::write(&path, &body) {
+                warn!("Failed to cache {} in {}: {}", url, path.display(), e);
+            }
+        }
+
+        shacl_rdf::read_graph_from_string_with_base(&body, format.file_extension(), url).map(Some)
+    }
+
+    /// Requests `url` without following redirects, within the request limit
+    /// and interval; `None` once the limit is reached.
+    fn get(&mut self, url: &str) -> Result<Option<ureq::http::Response<ureq::Body>>, ShaclError> {
+        if self.requests >= self.max_requests {
+            debug!("Request limit reached, not dereferencing {}", url);
+            return Ok(None);
+        }
+        if let Some(last_request) = self.last_request {
+            std::thread::sleep(self.interval.saturating_sub(last_request.elapsed()));
+        }
+        self.requests += 1;
+        self.last_request = Some(Instant::now());
+
+        debug!("Dereferencing {}", url);
+        self.agent
+            .get(url)
+            .header("Accept", ACCEPT)
+            .call()
+            .map(Some)
+            .map_err(|e| ShaclError::Io(e.to_string()))
+    }
+
+    fn load_cached(&self, url: &str) -> Result<Option<Graph>, ShaclError> {
+        for format in [
+            RdfFormat::Turtle,
+            RdfFormat::NTriples,
+            RdfFormat::RdfXml,
+            RdfFormat::JsonLd {
+                profile: Default::default(),
+            },
+        ] {
+            let Some(path) = self.cache_path(url, format) else {
+                return Ok(None);
+            };
+            if let Ok(body) = std::fs::read_to_string(&path) {
+                debug!("Using cached copy of {} from {}", url, path.display());
+                return shacl_rdf::read_graph_from_string_with_base(
+                    &body,
+                    format.file_extension(),
+                    url,
+                )
+                .map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Cache file of `url`, named by the SHA-256 of the URL so that distinct
+    /// URLs never share a file and names stay short.
+    fn cache_path(&self, url: &str, format: RdfFormat) -> Option<PathBuf> {
+        let file_name: String = Sha256::digest(url.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        self.cache_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{}.{}", file_name, format.file_extension())))
+    }
+}
+
+/// IRIs whose type information is missing from the data graph.
+fn lookup_candidates(data_graph: &Graph, shapes_graph: &Graph) -> HashSet<NamedNode> {
+    let mut candidates = HashSet::new();
+    let has_type = |node: NamedNodeRef<'_>| {
+        data_graph
+            .object_for_subject_predicate(node, rdf::TYPE)
+            .is_some()
+    };
+    let has_superclass = |node: NamedNodeRef<'_>| {
+        data_graph
+            .object_for_subject_predicate(node, rdfs::SUB_CLASS_OF)
+            .is_some()
+    };
+
+    for triple in data_graph.iter() {
+        if triple.predicate == rdf::TYPE || triple.predicate == rdfs::SUB_CLASS_OF {
+            if let TermRef::NamedNode(class) = triple.object {
+                if !has_superclass(class) {
+                    candidates.insert(class.into_owned());
+                }
+            }
+            continue;
+        }
+        if let TermRef::NamedNode(node) = triple.object {
+            if !has_type(node) {
+                candidates.insert(node.into_owned());
+            }
+        }
+    }
+
+    for triple in shapes_graph.triples_for_predicate(sh::CLASS) {
+        if let TermRef::NamedNode(class) = triple.object {
+            if !has_superclass(class) {
+                candidates.insert(class.into_owned());
+            }
+        }
+    }
+
+    candidates
+}
+
+/// The document an IRI is served from, i.e. the IRI without its fragment.
+fn document_url(iri: &str) -> &str {
+    iri.split_once('#').map_or(iri, |(document, _)| document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{self, Response};
+
+    const PERSON: &str = "http://example.org/Person";
+
+    /// Serves every path as a Turtle document typing it, and its `#a` and `#b`
+    /// fragments, as `ex:Person`. Returns the base URL and a request counter.
+    fn serve() -> (String, impl Fn() -> usize) {
+        let (base, requests) = test_server::serve(|_| {
+            // Relative IRIs resolve against the requested document
+            Response::new(
+                200,
+                format!("<> a <{0}> . <#a> a <{0}> . <#b> a <{0}> .", PERSON),
+            )
+            .with_header("Content-Type", "text/turtle")
+        });
+        (base, move || requests.lock().unwrap().len())
+    }
+
+    fn graphs(base: &str, objects: &[&str]) -> (Graph, Graph) {
+        let data: String = objects
+            .iter()
+            .map(|object| {
+                format!(
+                    "<http://example.org/x> <http://example.org/knows> <{}{}> .\n",
+                    base, object
+                )
+            })
+            .collect();
+        let shapes = format!(
+            "<http://example.org/S> <http://www.w3.org/ns/shacl#class> <{}> .",
+            PERSON
+        );
+        (
+            shacl_rdf::read_graph_from_string(&data, "nt").unwrap(),
+            shacl_rdf::read_graph_from_string(&shapes, "nt").unwrap(),
+        )
+    }
+
+    /// Number of `ex:Person` type statements in `data_graph`.
+    fn typed(data_graph: &Graph) -> usize {
+        data_graph
+            .triples_for_predicate(rdf::TYPE)
+            .filter(|t| t.object == NamedNodeRef::new_unchecked(PERSON).into())
+            .count()
+    }
+
+    fn dereferencer(base: &str) -> Dereferencer {
+        Dereferencer::new(vec![format!("{}/", base)]).with_interval(Duration::ZERO)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("shacl-dereference-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_only_http_iris_in_the_allowlist_are_dereferenced() {
+        let vocab = Dereferencer::new(vec!["https://example.org/vocab/".to_string()]);
+        assert!(vocab.is_allowed("https://example.org/vocab/Person"));
+        assert!(!vocab.is_allowed("https://example.org/other/Person"));
+        assert!(!vocab.is_allowed("urn:example:vocab/Person"));
+
+        let (base, requests) = serve();
+        let (mut data_graph, shapes_graph) = graphs(&base, &["/alice"]);
+        Dereferencer::new(vec!["http://example.org/".to_string()])
+            .enrich(&mut data_graph, &shapes_graph);
+        assert_eq!(typed(&data_graph), 0);
+        assert_eq!(requests(), 0);
+
+        dereferencer(&base).enrich(&mut data_graph, &shapes_graph);
+        assert_eq!(typed(&data_graph), 1);
+        assert_eq!(requests(), 1);
+    }
+
+    #[test]
+    fn test_requests_stop_at_the_limit() {
+        let (base, requests) = serve();
+        let (mut data_graph, shapes_graph) = graphs(&base, &["/alice", "/bob", "/carol"]);
+        dereferencer(&base)
+            .with_max_requests(2)
+            .enrich(&mut data_graph, &shapes_graph);
+
+        assert_eq!(requests(), 2);
+        assert_eq!(typed(&data_graph), 2);
+    }
+
+    #[test]
+    fn test_documents_are_fetched_once_and_reused_from_the_cache() {
+        let (base, requests) = serve();
+        let dir = temp_dir("cache");
+
+        // Both fragments come from one request, which is written to the cache
+        let (mut data_graph, shapes_graph) = graphs(&base, &["/people#a", "/people#b"]);
+        dereferencer(&base)
+            .with_cache_dir(dir.clone())
+            .enrich(&mut data_graph, &shapes_graph);
+        assert_eq!(typed(&data_graph), 2);
+        assert_eq!(requests(), 1);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        // A cache hit needs no request, a miss is skipped once requests run out
+        let (mut data_graph, shapes_graph) = graphs(&base, &["/people#a", "/others#a"]);
+        dereferencer(&base)
+            .with_cache_dir(dir.clone())
+            .with_max_requests(0)
+            .enrich(&mut data_graph, &shapes_graph);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(typed(&data_graph), 1);
+        assert_eq!(requests(), 1);
+    }
+
+    #[test]
+    fn test_redirects_are_followed_only_within_the_allowlist() {
+        let (base, requests) = test_server::serve(|request| match request.target.as_str() {
+            "/moved" => Response::new(301, "").with_header("Location", "/people"),
+            "/away" => Response::new(302, "").with_header("Location", "http://example.org/people"),
+            _ => Response::new(200, format!("<> a <{}> .", PERSON))
+                .with_header("Content-Type", "text/turtle"),
+        });
+        let (mut data_graph, shapes_graph) = graphs(&base, &["/moved", "/away"]);
+        dereferencer(&base).enrich(&mut data_graph, &shapes_graph);
+
+        assert_eq!(typed(&data_graph), 1);
+        let mut targets: Vec<String> = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|request| request.target.clone())
+            .collect();
+        targets.sort();
+        assert_eq!(targets, vec!["/away", "/moved", "/people"]);
+    }
+
+    #[test]
+    fn test_cache_files_are_distinct_per_url() {
+        let dereferencer = dereferencer("http://example.org").with_cache_dir(temp_dir("names"));
+        let path = |url: &str| dereferencer.cache_path(url, RdfFormat::Turtle).unwrap();
+
+        assert_ne!(
+            path("http://example.org/a-b"),
+            path("http://example.org/a_b")
+        );
+        assert_eq!(path("http://example.org/a"), path("http://example.org/a"));
+        let long = format!("http://example.org/{}", "segment/".repeat(100));
+        let name = path(&long).file_name().unwrap().len();
+        assert!(name < 255, "{}", name);
+        std::fs::remove_dir_all(temp_dir("names")).unwrap();
+    }
+}
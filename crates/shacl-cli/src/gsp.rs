@@ -0,0 +1,245 @@
+//! SPARQL 1.1 Graph Store Protocol client for validating graphs of a remote store.
+//!
+//! Graphs are fetched with an HTTP GET on the graph store endpoint. Responses are
+//! kept in a cache directory with their ETag, so unchanged graphs are not
+//! downloaded again on later runs.
+
+use log::{debug, info, warn};
+use oxigraph::io::RdfFormat;
+use oxigraph::model::Graph;
+use shacl_rust::{err::ShaclError, rdf as shacl_rdf};
+use std::path::PathBuf;
+use std::time::Duration;
+
+const ACCEPT: &str = "application/n-triples, text/turtle;q=0.9, application/rdf+xml;q=0.8, application/ld+json;q=0.5";
+
+/// Fetches graphs from a Graph Store Protocol endpoint.
+pub struct GraphStoreClient {
+    agent: ureq::Agent,
+    endpoint: String,
+    cache_dir: Option<PathBuf>,
+}
+
+impl GraphStoreClient {
+    pub fn new(endpoint: String) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(300)))
+            .build()
+            .into();
+
+        Self {
+            agent,
+            endpoint,
+            cache_dir: None,
+        }
+    }
+
+    /// Keeps fetched graphs and their ETags in `dir`.
+    pub fn with_cache_dir(mut self, dir: PathBuf) -> Self {
+        self.cache_dir = Some(dir);
+        self
+    }
+
+    /// Fetches the named graph `graph`, or the default graph when `None`.
+    pub fn get(&self, graph: Option<&str>) -> Result<Graph, ShaclError> {
+        let cached = self.load_cached(graph);
+
+        let mut request = match graph {
+            Some(graph) => self.agent.get(&self.endpoint).query("graph", graph),
+            // The protocol names the default graph with a bare `default` parameter
+            None => {
+                let separator = if self.endpoint.contains('?') {
+                    '&'
+                } else {
+                    '?'
+                };
+                self.agent
+                    .get(format!("{}{}default", self.endpoint, separator))
+            }
+        }
+        .header("Accept", ACCEPT);
+        if let Some((etag, _, _)) = &cached {
+            request = request.header("If-None-Match", etag);
+        }
+
+        debug!(
+            "Fetching graph {} from {}",
+            graph_label(graph),
+            self.endpoint
+        );
+        let mut response = request.call().map_err(|e| {
+            ShaclError::Io(format!(
+                "Failed to fetch graph {} from {}: {}",
+                graph_label(graph),
+                self.endpoint,
+                e
+            ))
+        })?;
+
+        if response.status() == 304 {
+            if let Some((_, format, body)) = cached {
+                info!(
+                    "Graph {} is unchanged, using cached copy",
+                    graph_label(graph)
+                );
+                return shacl_rdf::read_graph_from_string(&body, format.file_extension());
+            }
+        }
+
+        let content_type = response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.split(';').next().unwrap_or(v).trim().to_string())
+            .unwrap_or_default();
+        let format = RdfFormat::from_media_type(&content_type).ok_or_else(|| {
+            ShaclError::Parse(format!(
+                "Graph store returned non-RDF content type '{}'",
+                content_type
+            ))
+        })?;
+        let etag = response
+            .headers()
+            .get("etag")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body = response
+            .body_mut()
+            .with_config()
+            .limit(u64::MAX)
+            .read_to_string()
+            .map_err(|e| ShaclError::Io(e.to_string()))?;
+
+        if let Some(etag) = etag {
+            self.store_cached(graph, &etag, format, &body);
+        }
+        shacl_rdf::read_graph_from_string(&body, format.file_extension())
+    }
+
+    /// ETag, format and body of the cached copy of `graph`.
+    fn load_cached(&self, graph: Option<&str>) -> Option<(String, RdfFormat, String)> {
+        let (meta_path, body_path) = self.cache_paths(graph)?;
+        let meta = std::fs::read_to_string(meta_path).ok()?;
+        let (etag, extension) = meta.split_once('\n')?;
+        let format = RdfFormat::from_extension(extension.trim())?;
+        let body = std::fs::read_to_string(body_path).ok()?;
+        Some((etag.to_string(), format, body))
+    }
+
+    fn store_cached(&self, graph: Option<&str>, etag: &str, format: RdfFormat, body: &str) {
+        let Some((meta_path, body_path)) = self.cache_paths(graph) else {
+            return;
+        };
+        let written = std::fs::write(&body_path, body).and_then(|_| {
+            std::fs::write(&meta_path, format!("{}\n{}", etag, format.file_extension()))
+        });
+        if let Err(e) = written {
+            warn!("Failed to cache graph in {}: {}", body_path.display(), e);
+        }
+    }
+
+    fn cache_paths(&self, graph: Option<&str>) -> Option<(PathBuf, PathBuf)> {
+        let file_name: String = format!("{}_{}", self.endpoint, graph.unwrap_or("default"))
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        self.cache_dir.as_ref().map(|dir| {
+            (
+                dir.join(format!("{}.etag", file_name)),
+                dir.join(format!("{}.body", file_name)),
+            )
+        })
+    }
+}
+
+fn graph_label(graph: Option<&str>) -> String {
+    graph.map_or("default graph".to_string(), |graph| format!("<{}>", graph))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{self, Response};
+
+    const TRIPLE: &str = "<http://example.org/a> <http://example.org/p> \"o\" .\n";
+
+    fn triples() -> Response {
+        Response::new(200, TRIPLE).with_header("Content-Type", "application/n-triples")
+    }
+
+    #[test]
+    fn test_requests_name_the_graph_in_the_query_string() {
+        let (base, requests) = test_server::serve(|_| triples());
+        let client = GraphStoreClient::new(format!("{}/store", base));
+
+        assert_eq!(client.get(None).unwrap().len(), 1);
+        assert_eq!(
+            client
+                .get(Some("http://example.org/graphs/1"))
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let requests = requests.lock().unwrap();
+        let targets: Vec<&str> = requests.iter().map(|r| r.target.as_str()).collect();
+        assert_eq!(
+            targets,
+            [
+                "/store?default",
+                "/store?graph=http%3A%2F%2Fexample.org%2Fgraphs%2F1"
+            ]
+        );
+        assert!(requests
+            .iter()
+            .all(|r| r.method == "GET" && r.header("accept") == Some(ACCEPT)));
+    }
+
+    #[test]
+    fn test_unchanged_graphs_are_read_from_the_cache() {
+        let (base, requests) =
+            test_server::serve(|request| match request.header("if-none-match") {
+                Some("\"v1\"") => Response::new(304, ""),
+                _ => triples().with_header("ETag", "\"v1\""),
+            });
+        let dir = std::env::temp_dir().join(format!("shacl-gsp-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let client = GraphStoreClient::new(base).with_cache_dir(dir.clone());
+
+        let fetched = client.get(None).unwrap();
+        let cached = client.get(None).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(fetched, cached);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].header("if-none-match"), None);
+        assert_eq!(requests[1].header("if-none-match"), Some("\"v1\""));
+    }
+
+    #[test]
+    fn test_failed_and_non_rdf_responses_are_errors() {
+        let (base, _) = test_server::serve(|request| {
+            if request.target.contains("missing") {
+                Response::new(404, "Not Found")
+            } else {
+                Response::new(200, "<html></html>").with_header("Content-Type", "text/html")
+            }
+        });
+        let client = GraphStoreClient::new(base);
+
+        match client.get(Some("http://example.org/missing")) {
+            Err(ShaclError::Io(message)) => assert!(
+                message.starts_with("Failed to fetch graph <http://example.org/missing>"),
+                "{}",
+                message
+            ),
+            other => panic!("expected an I/O error, got {:?}", other.map(|g| g.len())),
+        }
+        match client.get(None) {
+            Err(ShaclError::Parse(message)) => {
+                assert!(message.contains("non-RDF content type 'text/html'"))
+            }
+            other => panic!("expected a parse error, got {:?}", other.map(|g| g.len())),
+        }
+    }
+}
@@ -1,14 +1,52 @@
-use clap::{Parser, Subcommand};
-use log::{debug, info};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use log::{debug, info, warn};
 use rayon::prelude::*;
 use shacl_rust::{
-    core::{shape::Shape, ShapesInfo},
+    analysis::{
+        closed_world::{parse_allowlist, ClosedWorldAudit},
+        skos::SkosAnalysis,
+    },
+    core::{shape::Shape, ShapesInfo, ValueTransform},
+    diagram::{shapes_diagram, DiagramFormat},
     err::{path_to_str, ShaclError},
-    parser, rdf, validate,
-    validation::dataset::ValidationDataset,
+    generator::{counter_examples_manifest, example_data, ExampleGenerator},
+    mutation::run_mutation_tests,
+    parser,
+    presets::Preset,
+    rdf,
+    registry::ShapesRegistry,
+    rules,
+    shape_tests::{junit_report, read_shape_tests, run_shape_tests},
+    snapshots,
+    utils::local_name_from_iri,
+    validate,
+    validation::{
+        config::{PathEvaluation, PathLimits, ReportLimits, ValidationConfig},
+        dataset::ValidationDataset,
+        plan::explain_plan,
+        render::{renderer_for, NdjsonRenderer, Renderer, TemplateRenderer},
+        report::QualityWeights,
+        stored_report::read_report_graph,
+    },
+    ReportNodeIds, ReportProvenance, Severity, ValidationReport, ValidationResult, Validator,
 };
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+mod conformance;
+mod dereference;
+mod gsp;
+mod sparql_update;
+#[cfg(test)]
+mod test_server;
+mod version_check;
+
+use dereference::Dereferencer;
+use gsp::GraphStoreClient;
 
 /// SHACL (Shapes Constraint Language) validator and toolkit
 #[derive(Parser)]
@@ -19,6 +57,10 @@ struct Cli {
     #[arg(short, long, action = clap::ArgAction::Count)]
     verbose: u8,
 
+    /// Check online whether a newer release is available (never fails the run)
+    #[arg(long, global = true)]
+    version_check: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -42,58 +84,564 @@ enum Commands {
     },
 
     /// Validate RDF data against SHACL shapes
-    Validate {
+    Validate(Box<ValidateArgs>),
+
+    /// Show information about SHACL shapes
+    Info {
         /// Path to the SHACL shapes file
         #[arg(value_name = "SHAPES_FILE")]
         shapes_file: PathBuf,
 
-        /// Data files to validate (one or more)
-        #[arg(value_name = "DATA_FILE", required = true)]
-        data_files: Vec<PathBuf>,
-
-        /// RDF format of the data file (auto-detected from extension if not specified)
+        /// RDF format of the shapes file (auto-detected from extension if not specified)
         /// Supported: ttl, nt, nq, rdf, jsonld, trig
-        #[arg(short = 'd', long)]
-        data_format: Option<String>,
+        #[arg(short, long)]
+        format: Option<String>,
+
+        /// Show detailed statistics
+        #[arg(short, long)]
+        detailed: bool,
+    },
+
+    /// Run a W3C SHACL test suite and emit an EARL conformance report
+    Conformance {
+        /// Path to a test manifest file or a directory containing manifest.ttl
+        #[arg(value_name = "MANIFEST")]
+        manifest: PathBuf,
+
+        /// Output file for the EARL report (if not specified, prints to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// RDF format of the EARL report as file extension (ttl, nt, rdf, jsonld)
+        #[arg(long, default_value = "ttl")]
+        output_format: String,
+
+        /// IRI of the assertor (defaults to the project IRI)
+        #[arg(long)]
+        assertor: Option<String>,
+    },
+
+    /// Validate named-graph snapshots of a dataset and print a conformance time series as JSON
+    Trend {
+        /// Path to the SHACL shapes file
+        #[arg(value_name = "SHAPES_FILE")]
+        shapes_file: PathBuf,
+
+        /// Path to a TriG or N-Quads file with one named graph per snapshot
+        #[arg(value_name = "DATASET_FILE")]
+        dataset_file: PathBuf,
+
+        /// Snapshot graph IRI to validate (can be repeated; defaults to all named graphs)
+        #[arg(long = "snapshot")]
+        snapshots: Vec<String>,
 
         /// RDF format of the shapes file (auto-detected from extension if not specified)
-        /// Supported: ttl, nt, nq, rdf, jsonld, trig
-        #[arg(short = 's', long)]
+        #[arg(short, long)]
         shapes_format: Option<String>,
+    },
+
+    /// Filter, group, convert or compare a stored validation report
+    Report(Box<ReportArgs>),
+
+    /// Export the shapes as a PlantUML or Mermaid class diagram
+    Diagram {
+        /// Path to the SHACL shapes file
+        #[arg(value_name = "SHAPES_FILE")]
+        shapes_file: PathBuf,
+
+        /// RDF format of the shapes file (auto-detected from extension if not specified)
+        #[arg(short, long)]
+        format: Option<String>,
+
+        /// Diagram language (plantuml, mermaid)
+        #[arg(long, default_value = "plantuml", value_parser = parse_diagram_format)]
+        diagram_format: DiagramFormat,
+
+        /// Output file (if not specified, prints to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
 
-        /// Output file for validation report (if not specified, prints to stdout)
+    /// Generate synthetic example instances of the shapes
+    ExampleData {
+        /// Path to the SHACL shapes file
+        #[arg(value_name = "SHAPES_FILE")]
+        shapes_file: PathBuf,
+
+        /// RDF format of the shapes file (auto-detected from extension if not specified)
+        #[arg(short, long)]
+        format: Option<String>,
+
+        /// Number of instances to generate per targeted node shape
+        #[arg(long, default_value_t = 1)]
+        count: usize,
+
+        /// Seed of the random generator, for reproducible output
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Output file (if not specified, prints to stdout)
         #[arg(short, long)]
         output: Option<PathBuf>,
 
-        /// Output format as file extension (ttl, nt, nq, rdf, jsonld, trig, json, yaml)
-        /// If omitted or 'text', prints human-readable format. Otherwise exports as RDF graph.
-        #[arg(long, default_value = "text")]
+        /// RDF format of the output as file extension (ttl, nt, rdf, jsonld)
+        #[arg(long, default_value = "ttl")]
         output_format: String,
 
-        /// Disable progress output
-        #[arg(long, visible_alias = "quite")]
-        quiet: bool,
+        /// Generate counter-examples that each break one constraint instead, and write
+        /// the manifest of their expected violations as JSON to this file
+        #[arg(long, value_name = "MANIFEST_FILE")]
+        counter_examples: Option<PathBuf>,
     },
 
-    /// Show information about SHACL shapes
-    Info {
+    /// Run the shx:ShapeTest unit tests of a shapes graph and print JUnit XML
+    /// (exits with 1 when a test fails)
+    Test {
         /// Path to the SHACL shapes file
         #[arg(value_name = "SHAPES_FILE")]
         shapes_file: PathBuf,
 
+        /// Path to the Turtle file with the shape tests
+        #[arg(value_name = "TEST_FILE")]
+        test_file: PathBuf,
+
         /// RDF format of the shapes file (auto-detected from extension if not specified)
-        /// Supported: ttl, nt, nq, rdf, jsonld, trig
         #[arg(short, long)]
         format: Option<String>,
 
-        /// Show detailed statistics
+        /// Output file for the JUnit report (if not specified, prints to stdout)
         #[arg(short, long)]
-        detailed: bool,
+        output: Option<PathBuf>,
+    },
+
+    /// Print how each shape will be evaluated: target resolution, path backend,
+    /// constraint order and caches. Reads no data.
+    ExplainPlan {
+        /// Path to the SHACL shapes file
+        #[arg(value_name = "SHAPES_FILE")]
+        shapes_file: PathBuf,
+
+        /// RDF format of the shapes file (auto-detected from extension if not specified)
+        #[arg(short, long)]
+        format: Option<String>,
+
+        /// How sh:path values would be found
+        #[arg(long, value_enum, default_value = "walker")]
+        path_evaluation: PathMode,
+
+        /// Print the plan as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Experimental: mutate the shapes and rerun the shape tests to find untested
+    /// constraints (exits with 1 when a mutant survives)
+    Mutate {
+        /// Path to the SHACL shapes file
+        #[arg(value_name = "SHAPES_FILE")]
+        shapes_file: PathBuf,
+
+        /// Path to the Turtle file with the shape tests
+        #[arg(value_name = "TEST_FILE")]
+        test_file: PathBuf,
+
+        /// RDF format of the shapes file (auto-detected from extension if not specified)
+        #[arg(short, long)]
+        format: Option<String>,
+
+        /// Print the surviving mutants as JSON
+        #[arg(long)]
+        json: bool,
     },
 }
 
+#[derive(Args)]
+struct ReportArgs {
+    /// Path to a report written by `validate` (json, or an RDF format such as ttl or jsonld)
+    #[arg(value_name = "REPORT_FILE")]
+    report_file: PathBuf,
+
+    /// Format of the report file (auto-detected from extension if not specified)
+    #[arg(short, long)]
+    format: Option<String>,
+
+    /// Keep only results of these severities (violation, warning, info)
+    #[arg(long, value_delimiter = ',')]
+    severity: Vec<String>,
+
+    /// Keep only results of these source shapes (IRIs, can be repeated)
+    #[arg(long)]
+    shape: Vec<String>,
+
+    /// Keep only results of these constraint components (IRIs, can be repeated)
+    #[arg(long)]
+    component: Vec<String>,
+
+    /// Keep only results about these focus nodes (IRIs, can be repeated)
+    #[arg(long)]
+    focus_node: Vec<String>,
+
+    /// Print the results grouped by a field instead of the report
+    #[arg(long, value_enum, conflicts_with_all = ["baseline", "stats"])]
+    group_by: Option<ResultField>,
+
+    /// Print the results added and resolved since this baseline report
+    /// (exits with 1 when results were added), or with --stats the change
+    /// of the quality score since it
+    #[arg(long, value_name = "REPORT_FILE")]
+    baseline: Option<PathBuf>,
+
+    /// Print summary statistics and the quality score instead of the report
+    #[arg(long)]
+    stats: bool,
+
+    /// Output file (if not specified, prints to stdout)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Output format: text or json, or for reports also html, markdown, sarif,
+    /// ndjson and RDF formats (ttl, nt, rdf, jsonld, ...)
+    #[arg(long, default_value = "text")]
+    output_format: String,
+}
+
+/// Result field to group stored reports by.
+#[derive(Clone, Copy, ValueEnum)]
+enum ResultField {
+    Severity,
+    Shape,
+    Component,
+    FocusNode,
+    Path,
+}
+
+impl ResultField {
+    fn value(self, result: &ValidationResult<'_>) -> String {
+        match self {
+            ResultField::Severity => result.get_severity().to_string(),
+            ResultField::Shape => result.get_source_shape().to_string(),
+            ResultField::Component => result
+                .get_source_constraint_component()
+                .map(|component| component.to_string())
+                .unwrap_or_default(),
+            ResultField::FocusNode => result.get_focus_node().to_string(),
+            ResultField::Path => result
+                .get_result_path()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Layout of the `--profile-output` file.
+#[derive(Clone, Copy, ValueEnum)]
+enum ProfileFormat {
+    /// Shapes and components sorted by time, most expensive first
+    Table,
+    /// `shape;component microseconds` lines for flamegraph tools
+    Folded,
+}
+
+/// Evaluation strategy of `sh:path`.
+#[derive(Clone, Copy, ValueEnum)]
+enum PathMode {
+    /// SPARQL property paths on the validation store
+    Sparql,
+    /// Walking the data graph step by step
+    Walker,
+}
+
+impl From<PathMode> for PathEvaluation {
+    fn from(mode: PathMode) -> Self {
+        match mode {
+            PathMode::Sparql => PathEvaluation::Sparql,
+            PathMode::Walker => PathEvaluation::Walker,
+        }
+    }
+}
+
+/// Results that fail a `validate` run. Failures and cancellations always fail it.
+#[derive(Clone, Copy, ValueEnum)]
+enum FailOn {
+    /// Violations and results of non-SHACL severities
+    Violation,
+    /// Warnings and more severe results
+    Warning,
+    /// Any result
+    Info,
+    /// Never fail because of results
+    Never,
+}
+
+impl FailOn {
+    fn fails(self, report: &ValidationReport<'_>) -> bool {
+        if !report.get_failures().is_empty() || report.is_cancelled() {
+            return true;
+        }
+        let fails_on = |severity: Severity<'_>| match self {
+            FailOn::Violation => !matches!(severity, Severity::Warning | Severity::Info),
+            FailOn::Warning => severity != Severity::Info,
+            FailOn::Info => true,
+            FailOn::Never => false,
+        };
+        report
+            .get_results()
+            .iter()
+            .any(|result| fails_on(result.get_severity()))
+    }
+}
+
+#[derive(Args)]
+struct ValidateArgs {
+    /// Path to the SHACL shapes file, or a directory of versioned shape releases
+    /// (omit when using --preset)
+    #[arg(
+        value_name = "SHAPES_FILE",
+        env = "SHACL_SHAPES",
+        required_unless_present = "preset"
+    )]
+    shapes_file: Option<PathBuf>,
+
+    /// SHAPES_FILE also holds the data: its shapes are split off and its other
+    /// triples validated against them
+    #[arg(long, conflicts_with_all = ["preset", "shapes_version"])]
+    self_contained: bool,
+
+    /// With --self-contained, validate the whole file as data, shapes included
+    #[arg(long, requires = "self_contained")]
+    keep_shapes_in_data: bool,
+
+    /// Data files to validate (one or more), or from SHACL_DATA as a list
+    /// separated like PATH
+    #[arg(value_name = "DATA_FILE")]
+    data_files: Vec<PathBuf>,
+
+    /// Also validate a graph fetched from this SPARQL Graph Store Protocol endpoint
+    #[arg(long, value_name = "ENDPOINT")]
+    data_gsp: Option<String>,
+
+    /// Named graph to fetch from --data-gsp (defaults to the default graph)
+    #[arg(long, value_name = "IRI", requires = "data_gsp")]
+    graph: Option<String>,
+
+    /// Directory used to cache fetched graphs by ETag between runs
+    /// (defaults to a directory in the system temp dir)
+    #[arg(long, value_name = "DIR", requires = "data_gsp")]
+    gsp_cache: Option<PathBuf>,
+
+    /// Validate against bundled shapes instead of SHAPES_FILE (dcat-ap, skos, schema-org)
+    #[arg(long, value_parser = parse_preset, conflicts_with_all = ["shapes_format", "shapes_version"])]
+    preset: Option<Preset>,
+
+    /// RDF format of the data file (auto-detected from extension if not specified)
+    /// Supported: ttl, nt, nq, rdf, jsonld, trig
+    #[arg(short = 'd', long)]
+    data_format: Option<String>,
+
+    /// RDF format of the shapes file (auto-detected from extension if not specified)
+    /// Supported: ttl, nt, nq, rdf, jsonld, trig
+    #[arg(short = 's', long)]
+    shapes_format: Option<String>,
+
+    /// Shapes release to use when SHAPES_FILE is a registry directory (defaults to the latest)
+    #[arg(long)]
+    shapes_version: Option<String>,
+
+    /// Replace {{KEY}} placeholders in SHAPES_FILE with VALUE before parsing
+    /// (can be used multiple times)
+    #[arg(long, value_name = "KEY=VALUE", value_parser = parse_define, conflicts_with = "preset")]
+    define: Vec<(String, String)>,
+
+    /// File of KEY=VALUE lines for the {{KEY}} placeholders of SHAPES_FILE
+    /// (--define takes precedence)
+    #[arg(long, value_name = "FILE", conflicts_with = "preset")]
+    define_file: Option<PathBuf>,
+
+    /// Dereference IRIs starting with this prefix to fetch type information for sh:class
+    /// (can be used multiple times; disabled unless given)
+    #[arg(long, value_name = "IRI_PREFIX")]
+    dereference: Vec<String>,
+
+    /// Directory used to cache dereferenced documents between runs
+    #[arg(long, value_name = "DIR", requires = "dereference")]
+    dereference_cache: Option<PathBuf>,
+
+    /// Minimum delay between two dereferencing requests, in milliseconds
+    #[arg(long, default_value_t = 1000)]
+    dereference_interval_ms: u64,
+
+    /// Maximum number of dereferencing requests per run
+    #[arg(long, default_value_t = 100)]
+    dereference_max_requests: usize,
+
+    /// Add the triples inferred by the SHACL-AF rules of the shapes graph before validating
+    #[arg(long)]
+    infer: bool,
+
+    /// Also run SKOS heuristics (hierarchy cycles, orphan concepts, missing prefLabels)
+    #[arg(long)]
+    skos_checks: bool,
+
+    /// Languages every SKOS concept needs a prefLabel in (defaults to all languages in use)
+    #[arg(long, value_delimiter = ',', requires = "skos_checks")]
+    skos_languages: Option<Vec<String>>,
+
+    /// Report triples whose predicate no shape targeting their subject mentions
+    #[arg(long)]
+    closed_world_audit: bool,
+
+    /// File of predicates the closed-world audit allows everywhere
+    /// (one IRI per line, a trailing '*' allows a namespace)
+    #[arg(long, value_name = "FILE", requires = "closed_world_audit")]
+    closed_world_allowlist: Option<PathBuf>,
+
+    /// Attach a Turtle description of each focus node to text and JSON results,
+    /// following IRI objects up to DEPTH hops
+    #[arg(long, value_name = "DEPTH")]
+    with_context: Option<usize>,
+
+    /// Transform literal values before length and pattern checks
+    /// (comma-separated: trim, nfc, casefold)
+    #[arg(long, value_delimiter = ',', value_parser = parse_value_transform)]
+    value_transform: Vec<ValueTransform>,
+
+    /// Check lengths and patterns on NFC-normalized values, counting lengths in
+    /// grapheme clusters instead of characters
+    #[arg(long)]
+    unicode_mode: bool,
+
+    /// Reference time of shx:minDaysFromNow and shx:maxDaysFromNow as an
+    /// xsd:dateTime (defaults to the current time)
+    #[arg(long, value_name = "DATETIME")]
+    now: Option<oxsdatatypes::DateTime>,
+
+    /// Cut result messages longer than this many characters
+    #[arg(long, value_name = "CHARS")]
+    max_message_length: Option<usize>,
+
+    /// Replace literal values longer than this many characters by a prefix
+    /// and a hash of the full value
+    #[arg(long, value_name = "CHARS")]
+    max_value_length: Option<usize>,
+
+    /// Keep nested results (sh:detail) only up to this depth
+    #[arg(long, value_name = "DEPTH")]
+    max_details_depth: Option<usize>,
+
+    /// Group text and JSON reports by the shx:requirement identifiers of the shapes
+    #[arg(long)]
+    group_by_requirement: bool,
+
+    /// Show at most K results per shape and constraint component in text and
+    /// JSON reports, keeping the full counts in the summary
+    #[arg(long, value_name = "K", conflicts_with = "group_by_requirement")]
+    examples_per_shape: Option<usize>,
+
+    /// Label the report and result nodes of RDF reports n0, n1, ... instead
+    /// of using fresh blank nodes
+    #[arg(long, conflicts_with = "skolem_base")]
+    sequential_node_ids: bool,
+
+    /// Name the report and result nodes of RDF reports with skolem IRIs
+    /// under this base instead of blank nodes
+    #[arg(long, value_name = "IRI")]
+    skolem_base: Option<String>,
+
+    /// Output file for validation report (if not specified, prints to stdout)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Output format as file extension (ttl, nt, nq, rdf, jsonld, trig, json, yaml)
+    /// or report format (html, markdown, sarif, ndjson with one result per line).
+    /// If omitted or 'text', prints human-readable format. Otherwise exports as RDF graph.
+    #[arg(long, env = "SHACL_OUTPUT_FORMAT", default_value = "text")]
+    output_format: String,
+
+    /// Least severe result that makes the run exit with 1
+    #[arg(long, env = "SHACL_FAIL_ON", value_enum, default_value = "info")]
+    fail_on: FailOn,
+
+    /// Render the report with a Tera template instead of --output-format
+    /// (the template receives the JSON report fields)
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["output_format", "group_by_requirement", "examples_per_shape"])]
+    template: Option<PathBuf>,
+
+    /// Write violations, warnings and info results into separate files in this
+    /// directory (violations.<ext>, warnings.<ext>, info.<ext>) instead of one report;
+    /// results of custom severities go to a file named after the severity's local name
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["output", "template", "group_by_requirement", "examples_per_shape"])]
+    split_by_severity: Option<PathBuf>,
+
+    /// SPARQL Update endpoint to upload the report graph to
+    #[arg(long, value_name = "URL", requires = "report_graph")]
+    report_endpoint: Option<String>,
+
+    /// Named graph the uploaded report replaces
+    #[arg(long, value_name = "IRI", requires = "report_endpoint")]
+    report_graph: Option<String>,
+
+    /// Write the time spent per shape and constraint component to this file
+    #[arg(long, value_name = "FILE")]
+    profile_output: Option<PathBuf>,
+
+    /// Layout of --profile-output
+    #[arg(long, value_enum, default_value = "table", requires = "profile_output")]
+    profile_format: ProfileFormat,
+
+    /// How sh:path values are found in the data graph
+    #[arg(long, value_enum, default_value = "walker")]
+    path_evaluation: PathMode,
+
+    /// Predicate that also makes a node an instance of a class besides
+    /// rdf:type, such as http://www.wikidata.org/prop/direct/P31 (repeatable)
+    #[arg(long, value_name = "IRI", value_parser = parse_iri)]
+    type_predicate: Vec<oxigraph::model::NamedNode>,
+
+    /// Fail a focus node whose sh:zeroOrMorePath or sh:oneOrMorePath step
+    /// reaches more than this many nodes
+    #[arg(long, value_name = "N")]
+    max_path_nodes: Option<usize>,
+
+    /// Fail a focus node whose sh:zeroOrMorePath or sh:oneOrMorePath step
+    /// repeats more than this many times
+    #[arg(long, value_name = "N")]
+    max_path_depth: Option<usize>,
+
+    /// Disable progress output
+    #[arg(long, visible_alias = "quite")]
+    quiet: bool,
+}
+
+/// Environment variables that configure `validate` on their own.
+const VALIDATE_ENV: [&str; 2] = ["SHACL_SHAPES", "SHACL_DATA"];
+
+/// Command-line arguments, running `validate` when none are given and the
+/// shapes or data come from the environment.
+///
+/// This lets a minimal container image use the binary as its entrypoint and be
+/// configured with `SHACL_SHAPES`, `SHACL_DATA`, `SHACL_OUTPUT_FORMAT` and
+/// `SHACL_FAIL_ON` alone.
+fn args(mut args: Vec<std::ffi::OsString>) -> Vec<std::ffi::OsString> {
+    if args.len() == 1
+        && VALIDATE_ENV
+            .iter()
+            .any(|name| std::env::var_os(name).is_some())
+    {
+        args.push("validate".into());
+    }
+    args
+}
+
+/// `data_files`, or the files listed in `SHACL_DATA` when there are none.
+fn data_files_or_env(data_files: Vec<PathBuf>) -> Vec<PathBuf> {
+    match std::env::var_os("SHACL_DATA") {
+        Some(list) if data_files.is_empty() => std::env::split_paths(&list).collect(),
+        _ => data_files,
+    }
+}
+
 fn main() -> Result<(), ShaclError> {
-    let cli = Cli::parse();
+    let cli = Cli::parse_from(args(std::env::args_os().collect()));
 
     // Initialize logger based on verbosity
     let log_level = match cli.verbose {
@@ -106,6 +654,10 @@ fn main() -> Result<(), ShaclError> {
 
     debug!("Starting SHACL validator");
 
+    if cli.version_check {
+        version_check::check_version();
+    }
+
     match cli.command {
         Commands::Parse {
             shapes_file,
@@ -115,34 +667,98 @@ fn main() -> Result<(), ShaclError> {
             info!("Parsing shapes from: {}", shapes_file.display());
             parse_shapes_command(shapes_file, format, &output)
         }
-        Commands::Validate {
+        Commands::Validate(args) => validate_command(*args),
+        Commands::Info {
+            shapes_file,
+            format,
+            detailed,
+        } => {
+            info!("Showing info for shapes: {}", shapes_file.display());
+            info_command(shapes_file, format, detailed)
+        }
+        Commands::Conformance {
+            manifest,
+            output,
+            output_format,
+            assertor,
+        } => {
+            info!("Running conformance suite: {}", manifest.display());
+            conformance_command(manifest, output, &output_format, assertor.as_deref())
+        }
+        Commands::Trend {
             shapes_file,
-            data_files,
-            data_format,
+            dataset_file,
+            snapshots,
             shapes_format,
+        } => {
+            info!(
+                "Computing conformance trend for: {}",
+                dataset_file.display()
+            );
+            trend_command(shapes_file, dataset_file, snapshots, shapes_format)
+        }
+        Commands::Report(args) => {
+            info!("Processing report: {}", args.report_file.display());
+            report_command(*args)
+        }
+        Commands::Diagram {
+            shapes_file,
+            format,
+            diagram_format,
+            output,
+        } => {
+            info!("Exporting diagram of shapes: {}", shapes_file.display());
+            diagram_command(shapes_file, format, diagram_format, output)
+        }
+        Commands::ExampleData {
+            shapes_file,
+            format,
+            count,
+            seed,
             output,
             output_format,
-            quiet,
+            counter_examples,
         } => {
-            info!("Validating {} data file(s)", data_files.len());
-            info!("Using shapes: {}", shapes_file.display());
-            validate_command(
+            info!(
+                "Generating example data for shapes: {}",
+                shapes_file.display()
+            );
+            example_data_command(
                 shapes_file,
-                data_files,
-                data_format,
-                shapes_format,
+                format,
+                count,
+                seed,
                 output,
                 &output_format,
-                quiet,
+                counter_examples,
             )
         }
-        Commands::Info {
+        Commands::Test {
             shapes_file,
+            test_file,
             format,
-            detailed,
+            output,
         } => {
-            info!("Showing info for shapes: {}", shapes_file.display());
-            info_command(shapes_file, format, detailed)
+            info!("Running shape tests: {}", test_file.display());
+            test_command(shapes_file, test_file, format, output)
+        }
+        Commands::ExplainPlan {
+            shapes_file,
+            format,
+            path_evaluation,
+            json,
+        } => {
+            info!("Explaining evaluation plan for: {}", shapes_file.display());
+            explain_plan_command(shapes_file, format, path_evaluation, json)
+        }
+        Commands::Mutate {
+            shapes_file,
+            test_file,
+            format,
+            json,
+        } => {
+            info!("Mutation testing shapes: {}", shapes_file.display());
+            mutate_command(shapes_file, test_file, format, json)
         }
     }
 }
@@ -180,7 +796,7 @@ fn parse_shapes_command(
     Ok(())
 }
 
-struct ShapesPretty<'a>(&'a [Shape<'a>]);
+struct ShapesPretty<'a>(&'a [Arc<Shape<'a>>]);
 
 impl Display for ShapesPretty<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -198,7 +814,7 @@ impl Display for ShapesPretty<'_> {
     }
 }
 
-struct ShapesCompact<'a>(&'a [Shape<'a>]);
+struct ShapesCompact<'a>(&'a [Arc<Shape<'a>>]);
 
 impl Display for ShapesCompact<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -217,7 +833,7 @@ impl Display for ShapesCompact<'_> {
     }
 }
 
-fn print_shapes_json(shapes: &[Shape<'_>]) -> Result<(), ShaclError> {
+fn print_shapes_json(shapes: &[Arc<Shape<'_>>]) -> Result<(), ShaclError> {
     use serde_json::json;
 
     let shapes_json: Vec<_> = shapes
@@ -270,15 +886,610 @@ fn info_command(
     Ok(())
 }
 
-fn validate_command(
+fn explain_plan_command(
     shapes_file: PathBuf,
-    data_files: Vec<PathBuf>,
-    data_format: Option<String>,
-    shapes_format: Option<String>,
+    format: Option<String>,
+    path_evaluation: PathMode,
+    json: bool,
+) -> Result<(), ShaclError> {
+    let graph = read_graph_from_file(&shapes_file, format.as_deref())?;
+    let shapes = parser::parse_shapes(&graph)?;
+    let config = ValidationConfig {
+        path_evaluation: path_evaluation.into(),
+        ..ValidationConfig::default()
+    };
+
+    let plan = explain_plan(&shapes, &config);
+    if json {
+        println!("{}", plan.as_json());
+    } else {
+        print!("{}", plan);
+    }
+    Ok(())
+}
+
+fn conformance_command(
+    manifest: PathBuf,
     output: Option<PathBuf>,
     output_format: &str,
-    quiet: bool,
+    assertor: Option<&str>,
+) -> Result<(), ShaclError> {
+    use oxigraph::io::RdfFormat;
+    let rdf_format = RdfFormat::from_extension(output_format).ok_or_else(|| {
+        ShaclError::Parse(format!(
+            "Unsupported output format: '{}'. Supported: ttl, nt, nq, rdf, jsonld, trig",
+            output_format
+        ))
+    })?;
+
+    let outcomes = shacl_rust::conformance::run_test_suite(&manifest)?;
+    let earl_graph = conformance::earl_report(&outcomes, assertor);
+    let output_text = rdf::serialize_graph_to_string(&earl_graph, rdf_format)?;
+
+    if let Some(output_path) = output {
+        debug!("Writing EARL report to {}", output_path.display());
+        std::fs::write(&output_path, &output_text)
+            .map_err(|e| ShaclError::Io(format!("Failed to write output file: {}", e)))?;
+        info!("EARL report written to {}", output_path.display());
+    } else {
+        println!("{}", output_text);
+    }
+
+    eprintln!("{}", conformance::summary(&outcomes));
+
+    Ok(())
+}
+
+fn report_command(args: ReportArgs) -> Result<(), ShaclError> {
+    let ReportArgs {
+        report_file,
+        format,
+        severity,
+        shape,
+        component,
+        focus_node,
+        group_by,
+        baseline,
+        stats,
+        output,
+        output_format,
+    } = args;
+
+    let report_graph = read_report_file(&report_file, format.as_deref())?;
+    let report = ValidationReport::from_graph(&report_graph)?;
+
+    let severities = severity
+        .iter()
+        .map(|name| parse_severity(name))
+        .collect::<Result<Vec<_>, _>>()?;
+    let matches = |filter: &[String], term: String| {
+        filter.is_empty()
+            || filter
+                .iter()
+                .any(|iri| term == *iri || term == format!("<{}>", iri))
+    };
+    let keep = |result: &ValidationResult<'_>| {
+        (severities.is_empty() || severities.contains(&result.get_severity()))
+            && matches(&shape, ResultField::Shape.value(result))
+            && matches(&component, ResultField::Component.value(result))
+            && matches(&focus_node, ResultField::FocusNode.value(result))
+    };
+    let report = report.filter_results(keep);
+
+    let json = match output_format.as_str() {
+        "json" => true,
+        "text" => false,
+        _ if group_by.is_none() && baseline.is_none() && !stats => {
+            return write_report(&report, &output_format, &ReportNodeIds::Fresh, output);
+        }
+        _ => {
+            return Err(ShaclError::Parse(format!(
+                "Unsupported output format '{}' for grouped, diffed or summarized reports. Supported: text, json",
+                output_format
+            )))
+        }
+    };
+
+    let baseline_graph = baseline
+        .map(|baseline_file| read_report_file(&baseline_file, format.as_deref()))
+        .transpose()?;
+    let baseline_report = baseline_graph
+        .as_ref()
+        .map(|graph| ValidationReport::from_graph(graph).map(|report| report.filter_results(keep)))
+        .transpose()?;
+
+    if let Some(baseline_report) = baseline_report.as_ref().filter(|_| !stats) {
+        let diff = report.diff(baseline_report);
+        let output_text = if json {
+            diff.as_json().to_string()
+        } else {
+            diff.to_string()
+        };
+        write_output(output, &output_text)?;
+        if !diff.added.is_empty() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let output_text = if let Some(field) = group_by {
+        let mut groups: std::collections::BTreeMap<String, Vec<&ValidationResult<'_>>> =
+            std::collections::BTreeMap::new();
+        for result in report.get_results() {
+            groups.entry(field.value(result)).or_default().push(result);
+        }
+        if json {
+            serde_json::Value::Object(
+                groups
+                    .into_iter()
+                    .map(|(key, results)| {
+                        let results = results.iter().map(|r| r.as_json()).collect();
+                        (key, serde_json::Value::Array(results))
+                    })
+                    .collect(),
+            )
+            .to_string()
+        } else {
+            let mut text = String::new();
+            for (key, results) in groups {
+                let key = if key.is_empty() { "(none)" } else { &key };
+                text.push_str(&format!("{} ({} result(s))\n", key, results.len()));
+                for result in results {
+                    text.push_str(&format!(
+                        "  - {} {}: {}\n",
+                        result.get_focus_node(),
+                        ResultField::Path.value(result),
+                        result.get_messages().join("; ")
+                    ));
+                }
+            }
+            text
+        }
+    } else if stats {
+        let weights = QualityWeights::default();
+        let quality_score = report.quality_score(&weights);
+        let mut statistics = serde_json::json!({
+            "conforms": report.get_conforms(),
+            "results": report.violation_count(),
+            "focusNodes": report.count_by(|r| ResultField::FocusNode.value(r)).len(),
+            "qualityScore": quality_score,
+            "bySeverity": report.count_by(|r| ResultField::Severity.value(r)),
+            "byShape": report.count_by(|r| ResultField::Shape.value(r)),
+            "byComponent": report.count_by(|r| ResultField::Component.value(r)),
+        });
+        if let Some(baseline_report) = &baseline_report {
+            let baseline_score = baseline_report.quality_score(&weights);
+            statistics["qualityTrend"] = serde_json::json!(quality_score
+                .zip(baseline_score)
+                .map(|(score, baseline_score)| score - baseline_score));
+        }
+        if json {
+            statistics.to_string()
+        } else {
+            let mut text = format!(
+                "Conforms: {}\nResults: {}\nFocus nodes: {}\n",
+                statistics["conforms"], statistics["results"], statistics["focusNodes"]
+            );
+            if let Some(score) = quality_score {
+                text.push_str(&format!("Quality score: {:.1}\n", score));
+            }
+            if let Some(trend) = statistics["qualityTrend"].as_f64() {
+                text.push_str(&format!("Quality trend: {:+.1}\n", trend));
+            }
+            for (title, key) in [
+                ("By severity", "bySeverity"),
+                ("By shape", "byShape"),
+                ("By constraint component", "byComponent"),
+            ] {
+                text.push_str(&format!("\n{}:\n", title));
+                for (group, count) in statistics[key].as_object().into_iter().flatten() {
+                    text.push_str(&format!("  {}: {}\n", group, count));
+                }
+            }
+            text
+        }
+    } else if json {
+        report.as_json().to_string()
+    } else {
+        report.to_string()
+    };
+
+    write_output(output, &output_text)
+}
+
+fn read_report_file(
+    path: &Path,
+    format: Option<&str>,
+) -> Result<oxigraph::model::Graph, ShaclError> {
+    let content = std::fs::read_to_string(path_to_str(path)?).map_err(|e| {
+        ShaclError::Io(format!(
+            "Failed to read report file '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    let format = format
+        .or_else(|| path.extension().and_then(|ext| ext.to_str()))
+        .ok_or_else(|| {
+            ShaclError::Parse(format!(
+                "Could not infer report format for '{}'. Please provide --format.",
+                path.display()
+            ))
+        })?;
+    read_report_graph(&content, format)
+}
+
+fn parse_severity(name: &str) -> Result<Severity<'static>, ShaclError> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "violation" => Ok(Severity::Violation),
+        "warning" => Ok(Severity::Warning),
+        "info" => Ok(Severity::Info),
+        _ => Err(ShaclError::Parse(format!(
+            "Unknown severity '{}'. Supported: violation, warning, info",
+            name
+        ))),
+    }
+}
+
+/// Renders a report with a built-in renderer or as an RDF graph.
+fn render_report(
+    report: &ValidationReport<'_>,
+    output_format: &str,
+    node_ids: &ReportNodeIds,
+) -> Result<String, ShaclError> {
+    if let Some(renderer) = renderer_for(output_format) {
+        return renderer.render(report);
+    }
+
+    // Try to parse as RDF format (ttl, nt, nq, rdf, jsonld, trig)
+    use oxigraph::io::RdfFormat;
+    let rdf_format = RdfFormat::from_extension(output_format).ok_or_else(|| {
+        ShaclError::Parse(format!(
+            "Unsupported output format: '{}'. Supported: text, json, ndjson, yaml, html, markdown, sarif, ttl, nt, nq, rdf, jsonld, trig",
+            output_format
+        ))
+    })?;
+
+    // Convert validation report to RDF graph
+    let report_graph = report.to_graph_with(node_ids);
+
+    // Serialize to string
+    rdf::serialize_graph_to_string(&report_graph, rdf_format)
+}
+
+/// Writes a report to the output file or stdout. NDJSON and RDF formats are
+/// streamed instead of being rendered into one string first.
+fn write_report(
+    report: &ValidationReport<'_>,
+    output_format: &str,
+    node_ids: &ReportNodeIds,
+    output: Option<PathBuf>,
+) -> Result<(), ShaclError> {
+    use oxigraph::io::RdfFormat;
+    let rdf_format =
+        RdfFormat::from_extension(output_format).filter(|_| renderer_for(output_format).is_none());
+    let streamed = rdf_format.is_some() || matches!(output_format, "ndjson" | "jsonl");
+    if !streamed {
+        return write_output(output, &render_report(report, output_format, node_ids)?);
+    }
+
+    let mut writer = open_output(output.as_deref())?;
+    match rdf_format {
+        Some(rdf_format) => {
+            rdf::serialize_graph_to_writer(
+                &report.to_graph_with(node_ids),
+                rdf_format,
+                &mut writer,
+            )?;
+        }
+        None => NdjsonRenderer.render_to(report, &mut writer)?,
+    }
+    finish_output(writer, output.as_deref())
+}
+
+/// Writes the results of each severity into a file of their own in `dir`.
+fn write_split_by_severity(
+    report: &ValidationReport<'_>,
+    output_format: &str,
+    node_ids: &ReportNodeIds,
+    dir: &Path,
+) -> Result<(), ShaclError> {
+    std::fs::create_dir_all(dir)
+        .map_err(|e| ShaclError::Io(format!("Failed to create {}: {}", dir.display(), e)))?;
+    let extension = match output_format {
+        "text" => "txt",
+        "markdown" => "md",
+        other => other,
+    };
+
+    for severity in Severity::ALL {
+        let part = report.filter_results(|result| result.get_severity() == severity);
+        let path = dir.join(format!("{}.{}", severity_file_name(severity), extension));
+        write_report(&part, output_format, node_ids, Some(path))?;
+    }
+
+    // Custom severities only get a file when results carry them
+    let mut custom: Vec<String> = report
+        .get_results()
+        .iter()
+        .filter(|result| matches!(result.get_severity(), Severity::Other(_)))
+        .map(|result| severity_file_name(result.get_severity()))
+        .collect();
+    custom.sort();
+    custom.dedup();
+    for name in custom {
+        let part = report.filter_results(|result| {
+            matches!(result.get_severity(), Severity::Other(_))
+                && severity_file_name(result.get_severity()) == name
+        });
+        let path = dir.join(format!("{}.{}", name, extension));
+        write_report(&part, output_format, node_ids, Some(path))?;
+    }
+    Ok(())
+}
+
+/// File name (without extension) for the results of `severity` when splitting
+/// by severity. Custom severities are named after the lowercased local name of
+/// their IRI, or `other` when that is unusable or taken by a SHACL severity.
+fn severity_file_name(severity: Severity<'_>) -> String {
+    match severity {
+        Severity::Violation => "violations".to_string(),
+        Severity::Warning => "warnings".to_string(),
+        Severity::Info => "info".to_string(),
+        Severity::Other(iri) => local_name_from_iri(iri.as_str())
+            .map(|name| name.to_lowercase())
+            .filter(|name| {
+                name.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+                    && !["violations", "warnings", "info"].contains(&name.as_str())
+            })
+            .unwrap_or_else(|| "other".to_string()),
+    }
+}
+
+type OutputWriter = std::io::BufWriter<Box<dyn Write + Send>>;
+
+/// Buffered writer to the output file, or to stdout.
+fn open_output(output: Option<&Path>) -> Result<OutputWriter, ShaclError> {
+    let sink: Box<dyn Write + Send> = match output {
+        Some(output_path) => Box::new(
+            std::fs::File::create(output_path)
+                .map_err(|e| ShaclError::Io(format!("Failed to write output file: {}", e)))?,
+        ),
+        None => Box::new(std::io::stdout()),
+    };
+    Ok(std::io::BufWriter::new(sink))
+}
+
+fn finish_output(mut writer: OutputWriter, output: Option<&Path>) -> Result<(), ShaclError> {
+    writer
+        .flush()
+        .map_err(|e| ShaclError::Io(format!("Failed to write report: {}", e)))?;
+
+    if let Some(output_path) = output {
+        info!("Report written to {}", output_path.display());
+    }
+    Ok(())
+}
+
+fn write_output(output: Option<PathBuf>, output_text: &str) -> Result<(), ShaclError> {
+    if let Some(output_path) = output {
+        debug!("Writing report to {}", output_path.display());
+        std::fs::write(&output_path, output_text)
+            .map_err(|e| ShaclError::Io(format!("Failed to write output file: {}", e)))?;
+        info!("Report written to {}", output_path.display());
+    } else {
+        // Print to stdout
+        println!("{}", output_text);
+    }
+    Ok(())
+}
+
+fn diagram_command(
+    shapes_file: PathBuf,
+    format: Option<String>,
+    diagram_format: DiagramFormat,
+    output: Option<PathBuf>,
+) -> Result<(), ShaclError> {
+    let graph = read_graph_from_file(&shapes_file, format.as_deref())?;
+    let shapes = parser::parse_shapes(&graph)?;
+    write_output(output, shapes_diagram(&shapes, diagram_format).trim_end())
+}
+
+fn example_data_command(
+    shapes_file: PathBuf,
+    format: Option<String>,
+    count: usize,
+    seed: u64,
+    output: Option<PathBuf>,
+    output_format: &str,
+    counter_examples: Option<PathBuf>,
+) -> Result<(), ShaclError> {
+    use oxigraph::io::RdfFormat;
+    let rdf_format = RdfFormat::from_extension(output_format).ok_or_else(|| {
+        ShaclError::Parse(format!(
+            "Unsupported output format: '{}'. Supported: ttl, nt, nq, rdf, jsonld, trig",
+            output_format
+        ))
+    })?;
+
+    let graph = read_graph_from_file(&shapes_file, format.as_deref())?;
+    let shapes = parser::parse_shapes(&graph)?;
+    let data = match counter_examples {
+        Some(manifest_path) => {
+            let (data, counter_examples) = ExampleGenerator::new(seed).counter_examples(&shapes);
+            info!("Generated {} counter-examples", counter_examples.len());
+            let manifest =
+                serde_json::to_string_pretty(&counter_examples_manifest(&counter_examples))
+                    .map_err(|e| {
+                        ShaclError::Parse(format!("Failed to serialize to JSON: {}", e))
+                    })?;
+            write_output(Some(manifest_path), &manifest)?;
+            data
+        }
+        None => example_data(&shapes, count, seed),
+    };
+    info!("Generated {} triples", data.len());
+    write_output(output, &rdf::serialize_graph_to_string(&data, rdf_format)?)
+}
+
+fn test_command(
+    shapes_file: PathBuf,
+    test_file: PathBuf,
+    format: Option<String>,
+    output: Option<PathBuf>,
+) -> Result<(), ShaclError> {
+    let shapes_graph = read_graph_from_file(&shapes_file, format.as_deref())?;
+    let tests = read_shape_tests(&read_graph_from_file(&test_file, Some("turtle"))?)?;
+    let outcomes = run_shape_tests(&shapes_graph, &tests);
+
+    let failed = outcomes.iter().filter(|outcome| !outcome.passed()).count();
+    info!("{} tests, {} failed", outcomes.len(), failed);
+    let suite = test_file
+        .file_stem()
+        .map_or("shapes".into(), |stem| stem.to_string_lossy());
+    write_output(output, junit_report(&suite, &outcomes).trim_end())?;
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn mutate_command(
+    shapes_file: PathBuf,
+    test_file: PathBuf,
+    format: Option<String>,
+    json: bool,
+) -> Result<(), ShaclError> {
+    let shapes_graph = read_graph_from_file(&shapes_file, format.as_deref())?;
+    let tests = read_shape_tests(&read_graph_from_file(&test_file, Some("turtle"))?)?;
+    let report = run_mutation_tests(&shapes_graph, &tests)?;
+
+    if json {
+        println!("{}", report.as_json());
+    } else {
+        println!("{}", report);
+    }
+    if !report.survived.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn trend_command(
+    shapes_file: PathBuf,
+    dataset_file: PathBuf,
+    snapshot_iris: Vec<String>,
+    shapes_format: Option<String>,
 ) -> Result<(), ShaclError> {
+    use oxigraph::{io::RdfFormat, model::NamedNode, store::Store};
+
+    let shapes_graph = read_graph_from_file(&shapes_file, shapes_format.as_deref())?;
+
+    let format = dataset_file
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(RdfFormat::from_extension)
+        .filter(|format| format.supports_datasets())
+        .ok_or_else(|| {
+            ShaclError::Parse(format!(
+                "'{}' is not a TriG or N-Quads file",
+                dataset_file.display()
+            ))
+        })?;
+    let content = std::fs::read(path_to_str(&dataset_file)?)
+        .map_err(|e| ShaclError::Io(format!("Failed to read dataset file: {}", e)))?;
+    let store =
+        Store::new().map_err(|e| ShaclError::Io(format!("Failed to create store: {}", e)))?;
+    store
+        .load_from_reader(format, content.as_slice())
+        .map_err(|e| ShaclError::Parse(format!("Failed to parse dataset file: {}", e)))?;
+
+    let snapshot_iris = snapshot_iris
+        .iter()
+        .map(|iri| {
+            NamedNode::new(iri)
+                .map_err(|e| ShaclError::Parse(format!("Invalid snapshot IRI '{}': {}", iri, e)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let trend = snapshots::conformance_trend(&store, &snapshot_iris, &shapes_graph)?;
+    println!("{}", snapshots::trend_as_json(&trend));
+    Ok(())
+}
+
+fn validate_command(args: ValidateArgs) -> Result<(), ShaclError> {
+    let ValidateArgs {
+        shapes_file,
+        self_contained,
+        keep_shapes_in_data,
+        data_files,
+        data_gsp,
+        graph,
+        gsp_cache,
+        data_format,
+        shapes_format,
+        shapes_version,
+        define,
+        define_file,
+        preset,
+        dereference,
+        dereference_cache,
+        dereference_interval_ms,
+        dereference_max_requests,
+        infer,
+        skos_checks,
+        skos_languages,
+        closed_world_audit,
+        closed_world_allowlist,
+        with_context,
+        value_transform,
+        unicode_mode,
+        now,
+        max_message_length,
+        max_value_length,
+        max_details_depth,
+        group_by_requirement,
+        examples_per_shape,
+        sequential_node_ids,
+        skolem_base,
+        output,
+        output_format,
+        fail_on,
+        template,
+        split_by_severity,
+        report_endpoint,
+        report_graph,
+        profile_output,
+        profile_format,
+        path_evaluation,
+        type_predicate,
+        max_path_nodes,
+        max_path_depth,
+        quiet,
+    } = args;
+    let shapes_version = shapes_version.as_deref();
+    let output_format = output_format.as_str();
+
+    let mut data_files = data_files_or_env(data_files);
+
+    // With a preset every positional argument is a data file
+    let shapes_file = match (preset, shapes_file) {
+        (Some(_), Some(file)) => {
+            data_files.insert(0, file);
+            None
+        }
+        (_, shapes_file) => shapes_file,
+    };
+    if data_files.is_empty() && data_gsp.is_none() && !self_contained {
+        return Err(ShaclError::Parse(
+            "At least one data file is required".to_string(),
+        ));
+    }
+
+    info!("Validating {} data file(s)", data_files.len());
+
     // If quiet is set, override log level to error
     if quiet {
         env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("error")).init();
@@ -319,74 +1530,317 @@ fn validate_command(
         );
     }
 
-    debug!(
-        "Reading shapes graph from {} with format {}",
-        shapes_file.display(),
-        shapes_format.as_deref().unwrap_or("auto")
-    );
+    if let Some(endpoint) = data_gsp {
+        let cache_dir =
+            gsp_cache.unwrap_or_else(|| std::env::temp_dir().join("shacl-validator-gsp"));
+        std::fs::create_dir_all(&cache_dir)
+            .map_err(|e| ShaclError::Io(format!("Failed to create graph cache: {}", e)))?;
+        let graph = GraphStoreClient::new(endpoint)
+            .with_cache_dir(cache_dir)
+            .get(graph.as_deref())?;
+        info!("Fetched graph with {} triples", graph.len());
+        data_graph.extend(graph.iter());
+    }
 
-    // Load shapes graph
-    let shapes_graph = read_graph_from_file(&shapes_file, shapes_format.as_deref())?;
+    let mut variables = match define_file {
+        Some(path) => rdf::parse_variables(&std::fs::read_to_string(&path).map_err(|e| {
+            ShaclError::Io(format!(
+                "Failed to read definitions {}: {}",
+                path.display(),
+                e
+            ))
+        })?)?,
+        None => HashMap::new(),
+    };
+    variables.extend(define);
+
+    // Load shapes graph, either from a single file or a versioned registry
+    let (shapes_graph, provenance) = match (preset, shapes_file) {
+        (Some(preset), _) => {
+            info!("Using preset shapes: {}", preset);
+            (preset.load_graph()?, preset.provenance())
+        }
+        (None, None) => {
+            return Err(ShaclError::Parse(
+                "Either SHAPES_FILE or --preset is required".to_string(),
+            ))
+        }
+        (None, Some(shapes_file)) if shapes_file.is_dir() => {
+            if !variables.is_empty() {
+                return Err(ShaclError::Parse(
+                    "--define requires SHAPES_FILE to be a file".to_string(),
+                ));
+            }
+            let registry = ShapesRegistry::from_dir(&shapes_file)?;
+            let release = registry.release(shapes_version)?;
+            info!(
+                "Using shapes version {} from registry {}",
+                release.get_version(),
+                shapes_file.display()
+            );
+            (release.load_graph()?, release.provenance(&registry))
+        }
+        (None, Some(shapes_file)) => {
+            if shapes_version.is_some() {
+                return Err(ShaclError::Parse(
+                    "--shapes-version requires SHAPES_FILE to be a registry directory".to_string(),
+                ));
+            }
+            debug!(
+                "Reading shapes graph from {} with format {}",
+                shapes_file.display(),
+                shapes_format.as_deref().unwrap_or("auto")
+            );
+            let graph =
+                read_graph_from_file_with(&shapes_file, shapes_format.as_deref(), &variables)?;
+            (graph, ReportProvenance::default())
+        }
+    };
+    let shapes_graph = if self_contained {
+        let (data, shapes) = parser::split_shapes(&shapes_graph);
+        if keep_shapes_in_data {
+            data_graph.extend(shapes_graph.iter());
+        } else {
+            data_graph.extend(data.iter());
+            warn!(
+                "{} shapes triples are not part of the data graph and are excluded from closed-shape checks",
+                shapes.len()
+            );
+        }
+        shapes
+    } else {
+        shapes_graph
+    };
     info!("Shapes graph loaded with {} triples", shapes_graph.len());
+    let provenance = ReportProvenance {
+        shapes_hash: Some(rdf::graph_hash_hex(&shapes_graph)),
+        ..provenance
+    };
+
+    if !dereference.is_empty() {
+        let mut dereferencer = Dereferencer::new(dereference)
+            .with_interval(Duration::from_millis(dereference_interval_ms))
+            .with_max_requests(dereference_max_requests);
+        if let Some(cache_dir) = dereference_cache {
+            std::fs::create_dir_all(&cache_dir).map_err(|e| {
+                ShaclError::Io(format!("Failed to create dereference cache: {}", e))
+            })?;
+            dereferencer = dereferencer.with_cache_dir(cache_dir);
+        }
+        dereferencer.enrich(&mut data_graph, &shapes_graph);
+    }
+
+    if infer {
+        let inferred = rules::infer(&data_graph, &shapes_graph)?;
+        info!("Rules inferred {} triples", inferred.len());
+        data_graph.extend(inferred.iter());
+    }
 
-    let validation_dataset = ValidationDataset::from_graphs(data_graph, shapes_graph)?;
+    let node_ids = match skolem_base {
+        Some(base) => ReportNodeIds::Skolem(
+            oxigraph::model::NamedNode::new(base)
+                .map_err(|e| ShaclError::Parse(format!("Invalid skolem base IRI: {}", e)))?,
+        ),
+        None if sequential_node_ids => ReportNodeIds::Sequential,
+        None => ReportNodeIds::Fresh,
+    };
+
+    let validation_dataset =
+        ValidationDataset::from_graphs(data_graph, shapes_graph)?.with_config(ValidationConfig {
+            value_transforms: value_transform,
+            unicode_mode,
+            now,
+            report_limits: ReportLimits {
+                max_message_length,
+                max_value_length,
+                max_details_depth,
+            },
+            profile: profile_output.is_some(),
+            path_evaluation: path_evaluation.into(),
+            type_predicates: type_predicate,
+            path_limits: PathLimits {
+                max_visited_nodes: max_path_nodes,
+                max_depth: max_path_depth,
+            },
+            ..ValidationConfig::default()
+        });
 
     // Parse shapes
     let shapes = parser::parse_shapes(validation_dataset.shapes_graph())?;
     info!("Parsed {} shapes", shapes.len());
 
-    let report = validate(&validation_dataset, &shapes);
+    // NDJSON results are written while the remaining shapes are validated
+    let mut results_sink = (matches!(output_format, "ndjson" | "jsonl")
+        && with_context.is_none()
+        && split_by_severity.is_none())
+    .then(|| open_output(output.as_deref()))
+    .transpose()?;
+    let mut report = match &mut results_sink {
+        Some(sink) => {
+            let mut write_error = None;
+            let report =
+                Validator::default().validate_streaming(&validation_dataset, &shapes, |result| {
+                    if write_error.is_none() {
+                        write_error = NdjsonRenderer::write_result(result, sink).err();
+                    }
+                });
+            if let Some(e) = write_error {
+                return Err(e);
+            }
+            report
+        }
+        None => validate(&validation_dataset, &shapes),
+    }
+    .with_provenance(provenance);
 
-    // Determine output format and generate report
-    let output_text = match output_format {
-        "text" => {
-            // Human-readable text format
-            report.to_string()
+    if skos_checks {
+        let mut analysis = SkosAnalysis::new();
+        if let Some(languages) = skos_languages {
+            analysis = analysis.with_languages(languages);
         }
-        "json" => {
-            // JSON format
-            report.as_json().to_string()
+        let skos_report = analysis.analyze(validation_dataset.data_graph());
+        if let Some(sink) = &mut results_sink {
+            NdjsonRenderer.render_to(&skos_report, sink)?;
         }
-        _ => {
-            // Try to parse as RDF format (ttl, nt, nq, rdf, jsonld, trig)
-            use oxigraph::io::RdfFormat;
-            let rdf_format = RdfFormat::from_extension(output_format).ok_or_else(|| {
-                ShaclError::Parse(format!(
-                    "Unsupported output format: '{}'. Supported: text, json, yaml, ttl, nt, nq, rdf, jsonld, trig",
-                    output_format
+        report.merge(skos_report);
+    }
+
+    if closed_world_audit {
+        let mut audit = ClosedWorldAudit::new();
+        if let Some(allowlist) = closed_world_allowlist {
+            let contents = std::fs::read_to_string(&allowlist).map_err(|e| {
+                ShaclError::Io(format!(
+                    "Failed to read allowlist {}: {}",
+                    allowlist.display(),
+                    e
                 ))
             })?;
+            audit = audit.with_allowlist(parse_allowlist(&contents));
+        }
+        let audit_report = audit.audit(validation_dataset.data_graph(), &shapes);
+        if let Some(sink) = &mut results_sink {
+            NdjsonRenderer.render_to(&audit_report, sink)?;
+        }
+        report.merge(audit_report);
+    }
+
+    if let Some(depth) = with_context {
+        report.attach_context(validation_dataset.data_graph(), depth)?;
+    }
 
-            // Convert validation report to RDF graph
-            let report_graph = report.to_graph();
+    if let Some(path) = profile_output {
+        let profile = report.profile();
+        let contents = match profile_format {
+            ProfileFormat::Table => profile.to_string(),
+            ProfileFormat::Folded => profile.folded(),
+        };
+        std::fs::write(&path, contents)
+            .map_err(|e| ShaclError::Io(format!("Failed to write profile: {}", e)))?;
+        info!("Profile written to {}", path.display());
+    }
 
-            // Serialize to string
-            rdf::serialize_graph_to_string(&report_graph, rdf_format)?
-        }
+    // Determine output format and generate report
+    let output_text = if let Some(template) = template {
+        let source = std::fs::read_to_string(&template).map_err(|e| {
+            ShaclError::Io(format!(
+                "Failed to read template {}: {}",
+                template.display(),
+                e
+            ))
+        })?;
+        Some(TemplateRenderer::new(&source)?.render(&report)?)
+    } else if group_by_requirement && output_format == "text" {
+        Some(report.by_requirement(&shapes).to_string())
+    } else if group_by_requirement && output_format == "json" {
+        Some(report.by_requirement(&shapes).as_json().to_string())
+    } else if let (Some(k), "text") = (examples_per_shape, output_format) {
+        Some(report.sample(k).to_string())
+    } else if let (Some(k), "json") = (examples_per_shape, output_format) {
+        Some(report.sample(k).as_json().to_string())
+    } else {
+        None
     };
 
-    // Write output
-    if let Some(output_path) = output {
-        debug!("Writing report to {}", output_path.display());
-        std::fs::write(&output_path, &output_text)
-            .map_err(|e| ShaclError::Io(format!("Failed to write output file: {}", e)))?;
-        info!("Report written to {}", output_path.display());
-    } else {
-        // Print to stdout
-        println!("{}", output_text);
+    match (output_text, results_sink, split_by_severity) {
+        (Some(output_text), _, _) => write_output(output, &output_text)?,
+        (None, Some(sink), _) => finish_output(sink, output.as_deref())?,
+        (None, None, Some(dir)) => {
+            write_split_by_severity(&report, output_format, &node_ids, &dir)?
+        }
+        (None, None, None) => write_report(&report, output_format, &node_ids, output)?,
+    }
+
+    if let (Some(endpoint), Some(graph)) = (report_endpoint, report_graph) {
+        let graph = oxigraph::model::NamedNode::new(&graph)
+            .map_err(|e| ShaclError::Parse(format!("Invalid report graph IRI: {}", e)))?;
+        sparql_update::replace_graph(&endpoint, graph.as_ref(), &report.to_graph_with(&node_ids))?;
     }
 
     // Exit with error code if validation failed
-    if !*report.get_conforms() {
+    if fail_on.fails(&report) {
         std::process::exit(1);
     }
 
     Ok(())
 }
 
+fn parse_value_transform(name: &str) -> Result<ValueTransform, String> {
+    ValueTransform::from_name(name).ok_or_else(|| {
+        let names: Vec<_> = ValueTransform::ALL.iter().map(|t| t.name()).collect();
+        format!(
+            "unknown value transform '{}' (available: {})",
+            name,
+            names.join(", ")
+        )
+    })
+}
+
+fn parse_diagram_format(name: &str) -> Result<DiagramFormat, String> {
+    DiagramFormat::from_name(name).ok_or_else(|| {
+        let names: Vec<_> = DiagramFormat::ALL.iter().map(|f| f.name()).collect();
+        format!(
+            "unknown diagram format '{}' (available: {})",
+            name,
+            names.join(", ")
+        )
+    })
+}
+
+fn parse_preset(name: &str) -> Result<Preset, String> {
+    Preset::from_name(name).ok_or_else(|| {
+        let names: Vec<_> = Preset::ALL.iter().map(Preset::name).collect();
+        format!(
+            "unknown preset '{}' (available: {})",
+            name,
+            names.join(", ")
+        )
+    })
+}
+
+fn parse_iri(iri: &str) -> Result<oxigraph::model::NamedNode, String> {
+    oxigraph::model::NamedNode::new(iri).map_err(|e| format!("invalid IRI '{}': {}", iri, e))
+}
+
+fn parse_define(definition: &str) -> Result<(String, String), String> {
+    definition
+        .split_once('=')
+        .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+        .ok_or_else(|| format!("expected KEY=VALUE, got '{}'", definition))
+}
+
 fn read_graph_from_file(
     path: &Path,
     format: Option<&str>,
+) -> Result<oxigraph::model::Graph, ShaclError> {
+    read_graph_from_file_with(path, format, &HashMap::new())
+}
+
+/// Reads a graph file, first replacing its `{{KEY}}` placeholders when
+/// `variables` are given.
+fn read_graph_from_file_with(
+    path: &Path,
+    format: Option<&str>,
+    variables: &HashMap<String, String>,
 ) -> Result<oxigraph::model::Graph, ShaclError> {
     let content = std::fs::read_to_string(path_to_str(path)?).map_err(|e| {
         ShaclError::Io(format!(
@@ -396,6 +1850,12 @@ fn read_graph_from_file(
         ))
     })?;
 
+    let content = if variables.is_empty() {
+        content
+    } else {
+        rdf::substitute_variables(&content, variables)?
+    };
+
     let effective_format = format.or_else(|| path.extension().and_then(|ext| ext.to_str()));
     let effective_format = effective_format.ok_or_else(|| {
         ShaclError::Parse(format!(
@@ -405,3 +1865,182 @@ fn read_graph_from_file(
     })?;
     rdf::read_graph_from_string(&content, effective_format)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::error::ErrorKind;
+    use shacl_rust::ValidationFailure;
+    use std::sync::Mutex;
+
+    const ENV: [&str; 4] = [
+        "SHACL_SHAPES",
+        "SHACL_DATA",
+        "SHACL_OUTPUT_FORMAT",
+        "SHACL_FAIL_ON",
+    ];
+
+    /// Serializes the tests that change the process environment.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Runs `test` with only the given `SHACL_*` variables set.
+    fn with_env(vars: &[(&str, &std::ffi::OsStr)], test: impl FnOnce()) {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let clear = || ENV.iter().for_each(|name| std::env::remove_var(name));
+        clear();
+        for (name, value) in vars {
+            std::env::set_var(name, value);
+        }
+        test();
+        clear();
+    }
+
+    fn validate_args(argv: &[&str]) -> Result<ValidateArgs, clap::Error> {
+        let argv = std::iter::once("shacl-validator")
+            .chain(argv.iter().copied())
+            .map(Into::into)
+            .collect();
+        match Cli::try_parse_from(args(argv))?.command {
+            Commands::Validate(args) => Ok(*args),
+            _ => panic!("expected the validate command"),
+        }
+    }
+
+    fn paths(paths: &[&str]) -> Vec<PathBuf> {
+        paths.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn test_validate_prefers_flags_over_environment_over_defaults() {
+        with_env(&[], || {
+            let args = validate_args(&["validate", "shapes.ttl", "data.ttl"]).unwrap();
+            assert_eq!(args.output_format, "text");
+            assert!(matches!(args.fail_on, FailOn::Info));
+            assert!(validate_args(&[]).is_err());
+        });
+
+        let data = std::env::join_paths(["a.ttl", "b.ttl"]).unwrap();
+        let env = [
+            ("SHACL_SHAPES", "env.ttl".as_ref()),
+            ("SHACL_DATA", data.as_os_str()),
+            ("SHACL_OUTPUT_FORMAT", "json".as_ref()),
+            ("SHACL_FAIL_ON", "warning".as_ref()),
+        ];
+        with_env(&env, || {
+            // Without arguments the environment alone runs validate
+            let args = validate_args(&[]).unwrap();
+            assert_eq!(args.shapes_file, Some(PathBuf::from("env.ttl")));
+            assert_eq!(
+                data_files_or_env(args.data_files),
+                paths(&["a.ttl", "b.ttl"])
+            );
+            assert_eq!(args.output_format, "json");
+            assert!(matches!(args.fail_on, FailOn::Warning));
+
+            let args = validate_args(&[
+                "validate",
+                "shapes.ttl",
+                "data.ttl",
+                "--output-format",
+                "ttl",
+                "--fail-on",
+                "never",
+            ])
+            .unwrap();
+            assert_eq!(args.shapes_file, Some(PathBuf::from("shapes.ttl")));
+            assert_eq!(data_files_or_env(args.data_files), paths(&["data.ttl"]));
+            assert_eq!(args.output_format, "ttl");
+            assert!(matches!(args.fail_on, FailOn::Never));
+        });
+    }
+
+    #[test]
+    fn test_failures_and_cancellation_fail_regardless_of_fail_on() {
+        let mut failed = ValidationReport::new();
+        failed.add_failure(ValidationFailure::new(
+            "<http://example.org/Shape>",
+            "<http://example.org/alice>",
+            "Path limit exceeded",
+        ));
+        let mut cancelled = ValidationReport::new();
+        cancelled.mark_cancelled();
+
+        for fail_on in [
+            FailOn::Violation,
+            FailOn::Warning,
+            FailOn::Info,
+            FailOn::Never,
+        ] {
+            assert!(fail_on.fails(&failed));
+            assert!(fail_on.fails(&cancelled));
+            assert!(!fail_on.fails(&ValidationReport::new()));
+        }
+    }
+
+    #[test]
+    fn test_split_by_severity_keeps_custom_severities() {
+        let dataset = ValidationDataset::from_graphs(
+            rdf::read_graph_from_string(
+                r#"
+                @prefix ex: <http://example.org/> .
+                ex:alice a ex:Person .
+                "#,
+                "turtle",
+            )
+            .unwrap(),
+            rdf::read_graph_from_string(
+                r#"
+                @prefix ex: <http://example.org/> .
+                @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+                ex:PersonShape a sh:NodeShape ;
+                    sh:targetClass ex:Person ;
+                    sh:property [ sh:path ex:name ; sh:minCount 1 ] ;
+                    sh:property [ sh:path ex:email ; sh:minCount 1 ; sh:severity sh:Warning ] ;
+                    sh:property [ sh:path ex:age ; sh:minCount 1 ; sh:severity ex:Critical ] ;
+                    sh:property [ sh:path ex:nick ; sh:minCount 1 ; sh:severity <urn:x:info> ] .
+                "#,
+                "turtle",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let shapes = parser::parse_shapes(dataset.shapes_graph()).unwrap();
+        let report = validate(&dataset, &shapes);
+
+        let dir = std::env::temp_dir().join(format!("shacl-split-{}", std::process::id()));
+        write_split_by_severity(&report, "ndjson", &ReportNodeIds::default(), &dir).unwrap();
+        let lines = |name: &str| {
+            std::fs::read_to_string(dir.join(name))
+                .unwrap()
+                .lines()
+                .count()
+        };
+        assert_eq!(lines("violations.ndjson"), 1);
+        assert_eq!(lines("warnings.ndjson"), 1);
+        assert_eq!(lines("info.ndjson"), 0);
+        assert_eq!(lines("critical.ndjson"), 1);
+        // A local name taken by a SHACL severity falls back to `other`
+        assert_eq!(lines("other.ndjson"), 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_fail_on_values_are_rejected() {
+        let argv = ["validate", "shapes.ttl", "data.ttl"];
+        with_env(&[("SHACL_FAIL_ON", "severe".as_ref())], || {
+            let error = validate_args(&argv).err().unwrap();
+            assert_eq!(error.kind(), ErrorKind::InvalidValue);
+            assert!(error.to_string().contains("severe"));
+
+            // A flag replaces the invalid environment value
+            assert!(validate_args(&[&argv[..], &["--fail-on", "never"]].concat()).is_ok());
+        });
+        with_env(&[], || {
+            let error = validate_args(&[&argv[..], &["--fail-on", "severe"]].concat())
+                .err()
+                .unwrap();
+            assert_eq!(error.kind(), ErrorKind::InvalidValue);
+        });
+    }
+}
@@ -0,0 +1,110 @@
+//! Upload of validation reports to a SPARQL 1.1 Update endpoint.
+//!
+//! The report graph replaces the previous content of a named graph, so the
+//! latest report can be queried next to the data it describes.
+
+use log::{debug, info};
+use oxigraph::model::{Graph, NamedNodeRef};
+use shacl_rust::err::ShaclError;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Replaces the named graph `graph` on `endpoint` with `triples`.
+pub fn replace_graph(
+    endpoint: &str,
+    graph: NamedNodeRef<'_>,
+    triples: &Graph,
+) -> Result<(), ShaclError> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(Duration::from_secs(300)))
+        .build()
+        .into();
+
+    debug!("Replacing graph {} on {}", graph, endpoint);
+    agent
+        .post(endpoint)
+        .header("Content-Type", "application/sparql-update")
+        .send(replace_graph_update(graph, triples))
+        .map_err(|e| ShaclError::Io(format!("Failed to upload report to {}: {}", endpoint, e)))?;
+    info!(
+        "Uploaded {} report triples into {} on {}",
+        triples.len(),
+        graph,
+        endpoint
+    );
+    Ok(())
+}
+
+/// SPARQL Update that drops `graph` and inserts `triples` into it.
+fn replace_graph_update(graph: NamedNodeRef<'_>, triples: &Graph) -> String {
+    let mut update = format!(
+        "DROP SILENT GRAPH {} ;\nINSERT DATA {{ GRAPH {} {{\n",
+        graph, graph
+    );
+    for triple in triples {
+        let _ = writeln!(update, "  {} .", triple);
+    }
+    update.push_str("} }\n");
+    update
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{self, Response};
+    use shacl_rust::rdf::read_graph_from_string;
+
+    const GRAPH: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://example.org/reports");
+
+    fn report_graph() -> Graph {
+        read_graph_from_string(
+            "<http://example.org/r> <http://www.w3.org/ns/shacl#conforms> true .",
+            "ttl",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_updates_replace_the_named_graph() {
+        assert_eq!(
+            replace_graph_update(GRAPH, &report_graph()),
+            "DROP SILENT GRAPH <http://example.org/reports> ;\n\
+             INSERT DATA { GRAPH <http://example.org/reports> {\n  \
+             <http://example.org/r> <http://www.w3.org/ns/shacl#conforms> \"true\"^^<http://www.w3.org/2001/XMLSchema#boolean> .\n\
+             } }\n"
+        );
+    }
+
+    #[test]
+    fn test_reports_are_posted_as_sparql_update() {
+        let (base, requests) = test_server::serve(|_| Response::new(204, ""));
+        let endpoint = format!("{}/update", base);
+        replace_graph(&endpoint, GRAPH, &report_graph()).unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].target, "/update");
+        assert_eq!(
+            requests[0].header("content-type"),
+            Some("application/sparql-update")
+        );
+        assert_eq!(
+            requests[0].body,
+            replace_graph_update(GRAPH, &report_graph())
+        );
+    }
+
+    #[test]
+    fn test_rejected_uploads_are_errors() {
+        let (base, _) = test_server::serve(|_| Response::new(400, "Bad Request"));
+        match replace_graph(&base, GRAPH, &report_graph()) {
+            Err(ShaclError::Io(message)) => assert!(
+                message.starts_with(&format!("Failed to upload report to {}", base)),
+                "{}",
+                message
+            ),
+            other => panic!("expected an I/O error, got {:?}", other),
+        }
+    }
+}
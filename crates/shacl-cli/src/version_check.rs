@@ -0,0 +1,131 @@
+//! Opt-in comparison of the running validator with the latest release.
+//!
+//! The check never fails a run: without network access it only logs why the
+//! latest release could not be determined.
+
+use log::{debug, warn};
+use shacl_rust::err::ShaclError;
+use std::time::Duration;
+
+/// Releases of the repository, as served by the GitHub API.
+const RELEASES_URL: &str =
+    "https://api.github.com/repos/ensaremirerol/shacl-rust/releases?per_page=100";
+
+/// Tag prefix release-please gives releases of this crate; the repository
+/// also tags the library, MCP server and WASM package releases.
+const TAG_PREFIX: &str = "shacl-cli-v";
+
+/// Prints to stderr whether a newer release than the running one exists.
+pub fn check_version() {
+    let current = env!("CARGO_PKG_VERSION");
+    match latest_release() {
+        Ok(latest) if is_newer(&latest, current) => eprintln!(
+            "A newer shacl-validator release is available: {} (running {})",
+            latest, current
+        ),
+        Ok(latest) => debug!("Running {}, latest release is {}", current, latest),
+        Err(e) => warn!("Skipping version check: {}", e),
+    }
+}
+
+fn latest_release() -> Result<String, ShaclError> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(Duration::from_secs(5)))
+        .build()
+        .into();
+    let body = agent
+        .get(RELEASES_URL)
+        .header("Accept", "application/vnd.github+json")
+        .header(
+            "User-Agent",
+            concat!("shacl-validator/", env!("CARGO_PKG_VERSION")),
+        )
+        .call()
+        .map_err(|e| ShaclError::Io(format!("Failed to fetch release metadata: {}", e)))?
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| ShaclError::Io(format!("Failed to read release metadata: {}", e)))?;
+
+    let releases: serde_json::Value = serde_json::from_str(&body)
+        .map_err(|e| ShaclError::Parse(format!("Invalid release metadata: {}", e)))?;
+    latest_cli_version(&releases)
+        .ok_or_else(|| ShaclError::Parse("No published shacl-cli release found".to_string()))
+}
+
+/// Newest version among the published (non-draft, non-prerelease) releases of
+/// this crate in a GitHub release listing.
+fn latest_cli_version(releases: &serde_json::Value) -> Option<String> {
+    releases
+        .as_array()?
+        .iter()
+        .filter(|release| {
+            !release["draft"].as_bool().unwrap_or(false)
+                && !release["prerelease"].as_bool().unwrap_or(false)
+        })
+        .filter_map(|release| tag_version(release["tag_name"].as_str()?))
+        .fold(None, |latest: Option<&str>, version| match latest {
+            Some(latest) if !is_newer(version, latest) => Some(latest),
+            _ => Some(version),
+        })
+        .map(str::to_string)
+}
+
+/// The version of a `shacl-cli-v<version>` tag, `None` for other components.
+fn tag_version(tag: &str) -> Option<&str> {
+    tag.strip_prefix(TAG_PREFIX)
+        .filter(|version| parse_version(version).is_some())
+}
+
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    version
+        .split(['-', '+'])
+        .next()?
+        .split('.')
+        .map(|part| part.parse().ok())
+        .collect()
+}
+
+/// Whether the `major.minor.patch` version `candidate` is later than `current`.
+fn is_newer(candidate: &str, current: &str) -> bool {
+    match (parse_version(candidate), parse_version(current)) {
+        (Some(candidate), Some(current)) => candidate > current,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_tag_version_only_accepts_cli_tags() {
+        assert_eq!(tag_version("shacl-cli-v0.1.4"), Some("0.1.4"));
+        assert_eq!(tag_version("shacl-rust-v0.2.0"), None);
+        assert_eq!(tag_version("shacl-mcp-v0.3.0"), None);
+        assert_eq!(tag_version("v0.1.4"), None);
+        assert_eq!(tag_version("shacl-cli-vnext"), None);
+    }
+
+    #[test]
+    fn test_is_newer_compares_numerically() {
+        assert!(is_newer("0.1.10", "0.1.4"));
+        assert!(is_newer("1.0.0", "0.9.9"));
+        assert!(!is_newer("0.1.4", "0.1.4"));
+        assert!(!is_newer("0.1.3", "0.1.4"));
+        assert!(!is_newer("shacl-cli-v0.2.0", "0.1.4"));
+    }
+
+    #[test]
+    fn test_latest_cli_version_skips_other_components_and_prereleases() {
+        let releases = json!([
+            { "tag_name": "shacl-rust-v0.9.0", "draft": false, "prerelease": false },
+            { "tag_name": "shacl-cli-v0.2.0", "draft": false, "prerelease": true },
+            { "tag_name": "shacl-cli-v0.1.10", "draft": false, "prerelease": false },
+            { "tag_name": "shacl-mcp-v0.5.0", "draft": false, "prerelease": false },
+            { "tag_name": "shacl-cli-v0.1.9", "draft": false, "prerelease": false },
+        ]);
+        assert_eq!(latest_cli_version(&releases).as_deref(), Some("0.1.10"));
+        assert_eq!(latest_cli_version(&json!([])), None);
+    }
+}
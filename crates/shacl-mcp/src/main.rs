@@ -6,12 +6,27 @@ use rmcp::{
     ServerHandler, ServiceExt,
 };
 
+use oxigraph::model::{Graph, NamedOrBlankNodeRef};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::BTreeMap;
 
-use shacl_rust::{core::ShapesInfo, validation::dataset::ValidationDataset};
 use shacl_rust::{
-    parse_shapes, rdf::read_graph_from_string, rdf::serialize_graph_to_string, validate,
+    generator::ExampleGenerator,
+    parser::{
+        contradictions::find_contradictions, shape_registry::ShapeRegistry,
+        well_formed::check_well_formed,
+    },
+    presets::Preset,
+    validation::{dataset::ValidationDataset, report::ValidationReport},
+};
+use shacl_rust::{
+    parse_shapes,
+    rdf::{
+        parse_rdf_format, read_graph_from_string, read_graph_from_string_with_base,
+        serialize_graph_to_string, serialize_graph_with_prefixes,
+    },
+    validate,
 };
 use tracing_subscriber::EnvFilter;
 
@@ -35,6 +50,12 @@ struct ValidateGraphsArgs {
         description = "Format of the output report ('text', 'json', or RDF format like 'ttl')"
     )]
     output_format: String,
+    #[schemars(description = "Index of the first result to return (text and json only)")]
+    offset: Option<usize>,
+    #[schemars(
+        description = "Maximum number of results to return; the response includes the offset of the next page (text and json only)"
+    )]
+    limit: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
@@ -60,14 +81,67 @@ struct LintGraphArgs {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
-#[schemars(description = "Arguments for parsing SHACL shapes graph")]
-struct ParseShapesGraphArgs {
+#[schemars(description = "Arguments for linting a SHACL shapes graph")]
+struct LintShapesArgs {
     #[schemars(description = "SHACL shapes graph as a string")]
     shapes_graph: String,
     #[schemars(description = "Format of the shapes graph (e.g., 'ttl', 'nt', 'jsonld')")]
     shapes_format: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+#[schemars(description = "Arguments for validating data against a bundled shapes preset")]
+struct ValidateAgainstPresetArgs {
+    #[schemars(description = "RDF data graph as a string")]
+    data_graph: String,
+    #[schemars(description = "Format of the data graph (e.g., 'ttl', 'nt', 'jsonld')")]
+    data_format: String,
+    #[schemars(description = "Name of the preset: 'dcat-ap', 'skos' or 'schema-org'")]
+    preset: String,
+    #[schemars(
+        description = "Format of the output report ('text', 'json', or RDF format like 'ttl')"
+    )]
+    output_format: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+#[schemars(description = "Arguments for generating example data from SHACL shapes")]
+struct GenerateExampleDataArgs {
+    #[schemars(description = "SHACL shapes graph as a string")]
+    shapes_graph: String,
+    #[schemars(description = "Format of the shapes graph (e.g., 'ttl', 'nt', 'jsonld')")]
+    shapes_format: String,
+    #[schemars(
+        description = "IRI of the node shape to generate instances of; every targeted node shape when omitted"
+    )]
+    shape: Option<String>,
+    #[schemars(description = "Number of instances to generate per shape (default 1)")]
+    count: Option<usize>,
+    #[schemars(description = "Seed of the random generator, for reproducible output (default 0)")]
+    seed: Option<u64>,
+    #[schemars(
+        description = "Format of the generated data (e.g., 'ttl', 'nt', 'jsonld'; default 'ttl')"
+    )]
+    output_format: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+#[schemars(description = "Arguments for converting an RDF graph between formats")]
+struct ConvertFormatArgs {
+    #[schemars(description = "RDF graph as a string")]
+    graph: String,
+    #[schemars(description = "Format of the input graph (e.g., 'ttl', 'nt', 'jsonld')")]
+    from_format: String,
+    #[schemars(description = "Format to convert the graph to (e.g., 'ttl', 'nt', 'jsonld')")]
+    to_format: String,
+    #[schemars(
+        description = "Base IRI to resolve relative IRIs of the input against and to write IRIs relative to"
+    )]
+    base_iri: Option<String>,
+    #[schemars(description = "Prefixes to declare in the output, as prefix name to namespace IRI")]
+    prefixes: Option<BTreeMap<String, String>>,
+}
+
 impl Default for ShaclServer {
     fn default() -> Self {
         Self::new()
@@ -91,6 +165,8 @@ impl ShaclServer {
             data_format,
             shapes_format,
             output_format,
+            offset,
+            limit,
         }): Parameters<ValidateGraphsArgs>,
     ) -> Result<String, String> {
         let data_graph = read_graph_from_string(&data_graph, &data_format)
@@ -107,29 +183,18 @@ impl ShaclServer {
 
         let report = validate(&validation_dataset, &shapes);
 
-        let report_string = match output_format.as_str() {
-            "json" => report.as_json().to_string(),
-            "text" => report.to_string(),
-            _ => {
-                // Try to parse as RDF format (ttl, nt, nq, rdf, jsonld, trig)
-                use oxigraph::io::RdfFormat;
-                let rdf_format = RdfFormat::from_extension(output_format.as_str()).ok_or_else(|| {
-                    format!(
-                        "Unsupported output format: '{}'. Supported: text, json, ttl, nt, nq, rdf, jsonld, trig",
-                        output_format
-                    )
-                })?;
-
-                // Convert validation report to RDF graph
-                let report_graph = report.to_graph();
-
-                // Serialize to string
-                serialize_graph_to_string(&report_graph, rdf_format)
-                    .map_err(|e| format!("Failed to serialize report graph: {}", e))?
-            }
-        };
-
-        Ok(report_string)
+        if offset.is_none() && limit.is_none() {
+            return format_report(&report, &output_format);
+        }
+        let page = report.page(offset.unwrap_or(0), limit.unwrap_or(usize::MAX), |_| true);
+        match output_format.as_str() {
+            "json" => Ok(page.as_json().to_string()),
+            "text" => Ok(page.to_string()),
+            _ => Err(format!(
+                "Pagination is only supported for text and json output, not '{}'",
+                output_format
+            )),
+        }
     }
 
     #[tool(
@@ -161,6 +226,45 @@ impl ShaclServer {
         Ok(json!({ "conforms": *report.get_conforms() }).to_string())
     }
 
+    #[tool(
+        description = "Validate RDF data graph against a bundled shapes preset ('dcat-ap', 'skos' or 'schema-org')"
+    )]
+    async fn validate_against_preset(
+        &self,
+        Parameters(ValidateAgainstPresetArgs {
+            data_graph,
+            data_format,
+            preset,
+            output_format,
+        }): Parameters<ValidateAgainstPresetArgs>,
+    ) -> Result<String, String> {
+        let preset = Preset::from_name(&preset).ok_or_else(|| {
+            let names: Vec<_> = Preset::ALL.iter().map(Preset::name).collect();
+            format!(
+                "Unknown preset '{}' (available: {})",
+                preset,
+                names.join(", ")
+            )
+        })?;
+
+        let data_graph = read_graph_from_string(&data_graph, &data_format)
+            .map_err(|e| format!("Failed to parse data graph: {}", e))?;
+
+        let shapes_graph = preset
+            .load_graph()
+            .map_err(|e| format!("Failed to load preset {}: {}", preset, e))?;
+
+        let validation_dataset = ValidationDataset::from_graphs(data_graph, shapes_graph)
+            .map_err(|e| format!("Failed to create validation dataset: {}", e))?;
+
+        let shapes = parse_shapes(validation_dataset.shapes_graph())
+            .map_err(|e| format!("Failed to parse shapes: {}", e))?;
+
+        let report = validate(&validation_dataset, &shapes).with_provenance(preset.provenance());
+
+        format_report(&report, &output_format)
+    }
+
     #[tool(description = "Validate RDF graph syntax")]
     async fn lint_graph(
         &self,
@@ -172,24 +276,173 @@ impl ShaclServer {
         Ok(json!({ "valid": true }).to_string())
     }
 
-    #[tool(description = "Parse SHACL shapes graph and return parsed shape information")]
-    async fn parse_shapes_graph(
+    #[tool(
+        description = "Generate example instances that satisfy the constraints of a SHACL shapes graph, for one shape or every targeted node shape"
+    )]
+    async fn generate_example_data(
         &self,
-        Parameters(ParseShapesGraphArgs {
+        Parameters(GenerateExampleDataArgs {
             shapes_graph,
             shapes_format,
-        }): Parameters<ParseShapesGraphArgs>,
+            shape,
+            count,
+            seed,
+            output_format,
+        }): Parameters<GenerateExampleDataArgs>,
     ) -> Result<String, String> {
+        let output_format = parse_rdf_format(output_format.as_deref().unwrap_or("ttl"))
+            .map_err(|e| e.to_string())?;
+
         let shapes_graph = read_graph_from_string(&shapes_graph, &shapes_format)
-            .map_err(|e| format!("Shapes graph syntax error: {}", e))?;
+            .map_err(|e| format!("Failed to parse shapes graph: {}", e))?;
 
-        let parsed_shapes =
-            parse_shapes(&shapes_graph).map_err(|e| format!("SHACL shapes error: {}", e))?;
+        let shapes =
+            parse_shapes(&shapes_graph).map_err(|e| format!("Failed to parse shapes: {}", e))?;
+
+        let count = count.unwrap_or(1);
+        let mut generator = ExampleGenerator::new(seed.unwrap_or(0));
+        let data = match shape {
+            Some(shape) => {
+                let iri = shape.trim().trim_start_matches('<').trim_end_matches('>');
+                let shape = shapes
+                    .iter()
+                    .find(|s| match s.node {
+                        NamedOrBlankNodeRef::NamedNode(node) => node.as_str() == iri,
+                        NamedOrBlankNodeRef::BlankNode(_) => false,
+                    })
+                    .ok_or_else(|| format!("No shape {} in the shapes graph", iri))?;
+                let mut data = Graph::new();
+                for _ in 0..count {
+                    generator.instance(&shapes, shape, &mut data, 0);
+                }
+                data
+            }
+            None => generator.generate(&shapes, count),
+        };
 
-        Ok(ShapesInfo::new(&parsed_shapes, shapes_graph.len(), true).to_string())
+        serialize_graph_to_string(&data, output_format)
+            .map_err(|e| format!("Failed to serialize example data: {}", e))
+    }
+
+    #[tool(description = "Convert an RDF graph from one serialization format to another")]
+    async fn convert_format(
+        &self,
+        Parameters(ConvertFormatArgs {
+            graph,
+            from_format,
+            to_format,
+            base_iri,
+            prefixes,
+        }): Parameters<ConvertFormatArgs>,
+    ) -> Result<String, String> {
+        let graph = match &base_iri {
+            Some(base_iri) => read_graph_from_string_with_base(&graph, &from_format, base_iri),
+            None => read_graph_from_string(&graph, &from_format),
+        }
+        .map_err(|e| format!("Failed to parse graph: {}", e))?;
+
+        let to_format = parse_rdf_format(&to_format).map_err(|e| e.to_string())?;
+        let prefixes: Vec<(String, String)> = prefixes.unwrap_or_default().into_iter().collect();
+        serialize_graph_with_prefixes(&graph, to_format, &prefixes, base_iri.as_deref())
+            .map_err(|e| format!("Failed to serialize graph: {}", e))
+    }
+
+    #[tool(
+        description = "Check a SHACL shapes graph for syntax problems and contradicting constraints, returning JSON diagnostics with node, predicate, problem and suggestion"
+    )]
+    async fn lint_shapes(
+        &self,
+        Parameters(LintShapesArgs {
+            shapes_graph,
+            shapes_format,
+        }): Parameters<LintShapesArgs>,
+    ) -> Result<String, String> {
+        let shapes_graph = read_graph_from_string(&shapes_graph, &shapes_format)
+            .map_err(|e| format!("Shapes graph syntax error: {}", e))?;
+
+        let well_formedness_issues = check_well_formed(&shapes_graph);
+        let well_formed = well_formedness_issues.is_empty();
+        let mut diagnostics: Vec<_> = well_formedness_issues
+            .into_iter()
+            .map(|issue| {
+                json!({
+                    "kind": "well-formedness",
+                    "node": issue.node,
+                    "predicate": issue.predicate,
+                    "problem": issue.message,
+                    "suggestion": issue.suggestion,
+                })
+            })
+            .collect();
+        diagnostics.extend(
+            find_contradictions(&shapes_graph)
+                .into_iter()
+                .map(|contradiction| {
+                    json!({
+                        "kind": "contradiction",
+                        "node": contradiction.node,
+                        "predicate": contradiction.predicate,
+                        "problem": contradiction.message,
+                        "suggestion": contradiction.suggestion,
+                    })
+                }),
+        );
+
+        // Shapes that fail to parse are skipped by validation, so each one is
+        // a diagnostic here.
+        let registry = ShapeRegistry::new(&shapes_graph);
+        let mut shape_nodes: Vec<_> = registry.index().shape_nodes().into_iter().collect();
+        shape_nodes.sort_by_cached_key(ToString::to_string);
+        let mut shapes = 0;
+        for node in shape_nodes {
+            match registry.shape(node) {
+                Ok(_) => shapes += 1,
+                Err(e) => diagnostics.push(json!({
+                    "kind": "parse",
+                    "node": node.to_string(),
+                    "predicate": null,
+                    "problem": e.to_string(),
+                    "suggestion": null,
+                })),
+            }
+        }
+        Ok(json!({
+            "wellFormed": well_formed,
+            "shapes": shapes,
+            "triples": shapes_graph.len(),
+            "diagnostics": diagnostics,
+        })
+        .to_string())
     }
 }
 
+/// Renders a report as text, JSON or an RDF format.
+fn format_report(report: &ValidationReport<'_>, output_format: &str) -> Result<String, String> {
+    let report_string = match output_format {
+        "json" => report.as_json().to_string(),
+        "text" => report.to_string(),
+        _ => {
+            // Try to parse as RDF format (ttl, nt, nq, rdf, jsonld, trig)
+            use oxigraph::io::RdfFormat;
+            let rdf_format = RdfFormat::from_extension(output_format).ok_or_else(|| {
+                format!(
+                    "Unsupported output format: '{}'. Supported: text, json, ttl, nt, nq, rdf, jsonld, trig",
+                    output_format
+                )
+            })?;
+
+            // Convert validation report to RDF graph
+            let report_graph = report.to_graph();
+
+            // Serialize to string
+            serialize_graph_to_string(&report_graph, rdf_format)
+                .map_err(|e| format!("Failed to serialize report graph: {}", e))?
+        }
+    };
+
+    Ok(report_string)
+}
+
 // Implement the server handler
 #[tool_handler]
 impl ServerHandler for ShaclServer {
@@ -225,3 +478,149 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preset_args(preset: &str) -> Parameters<ValidateAgainstPresetArgs> {
+        Parameters(ValidateAgainstPresetArgs {
+            data_graph: r#"
+                @prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+                <http://example.org/cat> a skos:Concept ;
+                    skos:prefLabel "Cat"@en, "Kitty"@en .
+            "#
+            .to_string(),
+            data_format: "ttl".to_string(),
+            preset: preset.to_string(),
+            output_format: "json".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_validate_against_preset_uses_the_named_preset() {
+        let report = ShaclServer::new()
+            .validate_against_preset(preset_args("skos"))
+            .await
+            .unwrap();
+        let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+        assert_eq!(report["conforms"], false);
+        assert_eq!(report["provenance"]["shapesSource"], "preset:skos");
+        assert_eq!(
+            report["results"][0]["sourceConstraintComponent"],
+            "<http://www.w3.org/ns/shacl#UniqueLangConstraintComponent>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_against_preset_rejects_unknown_presets() {
+        let error = ShaclServer::new()
+            .validate_against_preset(preset_args("foaf"))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error,
+            "Unknown preset 'foaf' (available: dcat-ap, skos, schema-org)"
+        );
+    }
+
+    const PERSON_SHAPES: &str = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [ sh:path ex:name ; sh:datatype xsd:string ; sh:minCount 1 ] .
+    "#;
+
+    fn example_args(shape: Option<&str>) -> Parameters<GenerateExampleDataArgs> {
+        Parameters(GenerateExampleDataArgs {
+            shapes_graph: PERSON_SHAPES.to_string(),
+            shapes_format: "ttl".to_string(),
+            shape: shape.map(str::to_string),
+            count: Some(2),
+            seed: Some(7),
+            output_format: Some("nt".to_string()),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_generate_example_data_conforms_to_the_shape() {
+        let server = ShaclServer::new();
+        let data = server
+            .generate_example_data(example_args(Some("<http://example.org/PersonShape>")))
+            .await
+            .unwrap();
+
+        let data_graph = read_graph_from_string(&data, "nt").unwrap();
+        let people = data_graph
+            .subjects_for_predicate_object(
+                oxigraph::model::vocab::rdf::TYPE,
+                oxigraph::model::NamedNodeRef::new_unchecked("http://example.org/Person"),
+            )
+            .count();
+        assert_eq!(people, 2);
+
+        let dataset = ValidationDataset::from_graphs(
+            data_graph,
+            read_graph_from_string(PERSON_SHAPES, "ttl").unwrap(),
+        )
+        .unwrap();
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        assert!(*validate(&dataset, &shapes).get_conforms());
+
+        // The same seed gives the same data
+        let again = server
+            .generate_example_data(example_args(Some("http://example.org/PersonShape")))
+            .await
+            .unwrap();
+        assert_eq!(
+            read_graph_from_string(&again, "nt").unwrap(),
+            *dataset.data_graph()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_example_data_rejects_unknown_shapes() {
+        let error = ShaclServer::new()
+            .generate_example_data(example_args(Some("http://example.org/Missing")))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error,
+            "No shape http://example.org/Missing in the shapes graph"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lint_shapes_reports_shapes_that_fail_to_parse() {
+        let lint = ShaclServer::new()
+            .lint_shapes(Parameters(LintShapesArgs {
+                shapes_graph: r#"
+                    @prefix ex: <http://example.org/> .
+                    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+                    ex:NameShape a sh:PropertyShape ; sh:path ex:name ; sh:minCount 1 .
+                    ex:BrokenShape a sh:PropertyShape ; sh:path "name" .
+                "#
+                .to_string(),
+                shapes_format: "ttl".to_string(),
+            }))
+            .await
+            .unwrap();
+        let lint: serde_json::Value = serde_json::from_str(&lint).unwrap();
+
+        assert_eq!(lint["shapes"], 1);
+        let parse: Vec<_> = lint["diagnostics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|diagnostic| diagnostic["kind"] == "parse")
+            .collect();
+        assert_eq!(parse.len(), 1);
+        assert_eq!(parse[0]["node"], "<http://example.org/BrokenShape>");
+        assert!(!parse[0]["problem"].as_str().unwrap().is_empty());
+    }
+}
@@ -19,5 +19,31 @@ export declare function validateGraphsConforms(
   shapesFormat: InputFormat
 ): boolean;
 
+export interface ValidationCounts {
+  focusNodes: number;
+  passed: number;
+  failed: number;
+  violations: number;
+  warnings: number;
+  infos: number;
+}
+
+export interface ShapeSummary extends ValidationCounts {
+  shape: string;
+}
+
+export interface ValidationSummary {
+  conforms: boolean;
+  totals: ValidationCounts & { shapes: number; failures: number };
+  shapes: ShapeSummary[];
+}
+
+export declare function validateSummary(
+  dataGraph: string,
+  shapesGraph: string,
+  dataFormat: InputFormat,
+  shapesFormat: InputFormat
+): ValidationSummary;
+
 export declare function lintDataGraph(dataGraph: string, dataFormat: InputFormat): void;
 export declare function lintShapesGraph(shapesGraph: string, shapesFormat: InputFormat): void;
@@ -2,7 +2,8 @@ import initWasm, {
   lint_data_graph,
   lint_shapes_graph,
   validate_graphs,
-  validate_graphs_conforms
+  validate_graphs_conforms,
+  validate_summary_json
 } from "./shacl_wasm.js";
 
 let initialized = false;
@@ -36,6 +37,11 @@ export function validateGraphsConforms(dataGraph, shapesGraph, dataFormat, shape
   return validate_graphs_conforms(dataGraph, shapesGraph, dataFormat, shapesFormat);
 }
 
+export function validateSummary(dataGraph, shapesGraph, dataFormat, shapesFormat) {
+  ensureInit();
+  return JSON.parse(validate_summary_json(dataGraph, shapesGraph, dataFormat, shapesFormat));
+}
+
 export function lintDataGraph(dataGraph, dataFormat) {
   ensureInit();
   return lint_data_graph(dataGraph, dataFormat);
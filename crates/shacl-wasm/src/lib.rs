@@ -1,7 +1,18 @@
 use wasm_bindgen::prelude::*;
 
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
 use shacl_rust::{
-    parse_shapes, rdf::read_graph_from_string, rdf::serialize_graph_to_string, validate,
+    core::shape::Shape,
+    parse_shapes,
+    rdf::read_graph_from_string,
+    rdf::serialize_graph_to_string,
+    validate,
+    validation::{dataset::ValidationDataset, report::ValidationReport},
+    CancellationToken, Severity, Validator,
 };
 
 use oxigraph::io::RdfFormat;
@@ -31,7 +42,11 @@ pub fn validate_graphs(
         .map_err(|e| to_js_error(format!("Failed to parse SHACL shapes: {}", e)))?;
 
     let report = validate(&validation_dataset, &parsed_shapes);
+    render_report(&report, output_format)
+}
 
+/// Renders a report as text, JSON or an RDF format named by its extension.
+fn render_report(report: &ValidationReport<'_>, output_format: &str) -> Result<String, JsValue> {
     match output_format.to_ascii_lowercase().as_str() {
         "text" => Ok(report.to_string()),
         "json" => {
@@ -54,6 +69,90 @@ pub fn validate_graphs(
     }
 }
 
+/// A validation run that can be aborted, e.g. when the document is edited again.
+///
+/// `validate` returns a promise and yields to the event loop after each shape,
+/// so `abort()` can be called while it runs.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct ValidationHandle {
+    token: CancellationToken,
+}
+
+#[wasm_bindgen]
+impl ValidationHandle {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn abort(&self) {
+        self.token.cancel();
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn aborted(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Validates like `validate_graphs`, resolving to the rendered report or
+    /// rejecting with "Validation was aborted" once the handle is aborted.
+    pub fn validate(
+        &self,
+        data_graph: String,
+        shapes_graph: String,
+        data_format: String,
+        shapes_format: String,
+        output_format: String,
+    ) -> js_sys::Promise {
+        let token = self.token.clone();
+        wasm_bindgen_futures::future_to_promise(async move {
+            let aborted = || to_js_error("Validation was aborted");
+            let data = read_graph_from_string(&data_graph, &data_format)
+                .map_err(|e| to_js_error(format!("Failed to parse data graph: {}", e)))?;
+            let shapes = read_graph_from_string(&shapes_graph, &shapes_format)
+                .map_err(|e| to_js_error(format!("Failed to parse shapes graph: {}", e)))?;
+
+            let validation_dataset = ValidationDataset::from_graphs(data, shapes)
+                .map_err(|e| to_js_error(format!("Failed to create validation dataset: {}", e)))?;
+
+            let parsed_shapes = parse_shapes(validation_dataset.shapes_graph())
+                .map_err(|e| to_js_error(format!("Failed to parse SHACL shapes: {}", e)))?;
+
+            let validator = Validator::builder()
+                .with_cancellation(token.clone())
+                .build();
+            let mut run = validator.start(&validation_dataset, &parsed_shapes);
+            loop {
+                yield_to_event_loop().await?;
+                if token.is_cancelled() {
+                    return Err(aborted());
+                }
+                if !run.step(1) {
+                    break;
+                }
+            }
+            let report = run.finish();
+            if token.is_cancelled() {
+                return Err(aborted());
+            }
+            render_report(&report, &output_format).map(JsValue::from)
+        })
+    }
+}
+
+/// Resolves on a later turn of the event loop, after pending events such as
+/// clicks were handled.
+async fn yield_to_event_loop() -> Result<(), JsValue> {
+    let set_timeout: js_sys::Function =
+        js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("setTimeout"))?.dyn_into()?;
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+        let _ = set_timeout.call2(&JsValue::UNDEFINED, &resolve, &JsValue::from(0));
+    });
+    wasm_bindgen_futures::JsFuture::from(promise).await?;
+    Ok(())
+}
+
 #[wasm_bindgen]
 pub fn validate_graphs_conforms(
     data_graph: &str,
@@ -76,6 +175,119 @@ pub fn validate_graphs_conforms(
     Ok(*validate(&validation_dataset, &parsed_shapes).get_conforms())
 }
 
+/// Per-shape pass/fail counts of focus nodes and severity totals, without individual results.
+#[wasm_bindgen]
+pub fn validate_summary_json(
+    data_graph: &str,
+    shapes_graph: &str,
+    data_format: &str,
+    shapes_format: &str,
+) -> Result<String, JsValue> {
+    let data = read_graph_from_string(data_graph, data_format)
+        .map_err(|e| to_js_error(format!("Failed to parse data graph: {}", e)))?;
+    let shapes = read_graph_from_string(shapes_graph, shapes_format)
+        .map_err(|e| to_js_error(format!("Failed to parse shapes graph: {}", e)))?;
+
+    let validation_dataset = ValidationDataset::from_graphs(data, shapes)
+        .map_err(|e| to_js_error(format!("Failed to create validation dataset: {}", e)))?;
+
+    let parsed_shapes = parse_shapes(validation_dataset.shapes_graph())
+        .map_err(|e| to_js_error(format!("Failed to parse SHACL shapes: {}", e)))?;
+
+    let summary = validation_summary(&validation_dataset, &parsed_shapes);
+    serde_json::to_string(&summary)
+        .map_err(|e| to_js_error(format!("Failed to serialize validation summary: {}", e)))
+}
+
+/// The summary returned by [`validate_summary_json`].
+///
+/// Results count against the shape whose focus nodes they were found on.
+/// Dataset constraints get a row of their own, with the dataset as their
+/// only focus node.
+fn validation_summary(
+    validation_dataset: &ValidationDataset,
+    parsed_shapes: &[Arc<Shape<'_>>],
+) -> serde_json::Value {
+    let report = validate(validation_dataset, parsed_shapes);
+    let focus_node_counts = report.get_focus_node_counts();
+
+    let mut results_by_shape: HashMap<_, Vec<_>> = HashMap::new();
+    for result in report.get_results() {
+        results_by_shape
+            .entry(result.get_counted_shape())
+            .or_default()
+            .push(result);
+    }
+
+    let mut rows: Vec<_> = parsed_shapes
+        .iter()
+        .map(|shape| {
+            let focus_nodes = focus_node_counts.get(&shape.node).copied().unwrap_or(0);
+            let results = results_by_shape.remove(&shape.node).unwrap_or_default();
+            (shape.node, focus_nodes, results)
+        })
+        .collect();
+    let mut dataset_constraints: Vec<_> = results_by_shape.into_iter().collect();
+    dataset_constraints.sort_by_cached_key(|(node, _)| node.to_string());
+    rows.extend(
+        dataset_constraints
+            .into_iter()
+            .map(|(node, results)| (node, 1, results)),
+    );
+
+    let mut totals = [0usize; 5];
+    let mut shape_summaries = Vec::new();
+    for (node, focus_nodes, results) in rows {
+        let failed = results
+            .iter()
+            .map(|result| result.get_focus_node())
+            .collect::<HashSet<_>>()
+            .len();
+        let count = |severity| {
+            results
+                .iter()
+                .filter(|result| result.get_severity() == severity)
+                .count()
+        };
+        let counts = [
+            focus_nodes,
+            failed,
+            count(Severity::Violation),
+            count(Severity::Warning),
+            count(Severity::Info),
+        ];
+        for (total, count) in totals.iter_mut().zip(counts) {
+            *total += count;
+        }
+
+        shape_summaries.push(serde_json::json!({
+            "shape": node.to_string(),
+            "focusNodes": focus_nodes,
+            "passed": focus_nodes.saturating_sub(failed),
+            "failed": failed,
+            "violations": counts[2],
+            "warnings": counts[3],
+            "infos": counts[4],
+        }));
+    }
+
+    let [focus_nodes, failed, violations, warnings, infos] = totals;
+    serde_json::json!({
+        "conforms": *report.get_conforms(),
+        "totals": {
+            "shapes": parsed_shapes.len(),
+            "focusNodes": focus_nodes,
+            "passed": focus_nodes.saturating_sub(failed),
+            "failed": failed,
+            "violations": violations,
+            "warnings": warnings,
+            "infos": infos,
+            "failures": report.get_failures().len(),
+        },
+        "shapes": shape_summaries,
+    })
+}
+
 #[wasm_bindgen]
 pub fn lint_data_graph(data_graph: &str, data_format: &str) -> Result<(), JsValue> {
     read_graph_from_string(data_graph, data_format)
@@ -92,3 +304,128 @@ pub fn lint_shapes_graph(shapes_graph: &str, shapes_format: &str) -> Result<(),
         .map(|_| ())
         .map_err(|e| to_js_error(format!("SHACL shapes error: {}", e)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shacl_rust::validation::config::{PathLimits, ValidationConfig};
+
+    #[test]
+    fn test_summary_counts_focus_nodes_and_severities_per_shape() {
+        let data = read_graph_from_string(
+            r#"
+            @prefix ex: <http://example.org/> .
+            ex:alice a ex:Person ; ex:name "Alice" .
+            ex:bob a ex:Person .
+            ex:carol a ex:Person .
+            "#,
+            "turtle",
+        )
+        .unwrap();
+        let shapes = read_graph_from_string(
+            r#"
+            @prefix ex: <http://example.org/> .
+            @prefix sh: <http://www.w3.org/ns/shacl#> .
+            ex:NameShape a sh:NodeShape ;
+                sh:targetClass ex:Person ;
+                sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+            ex:AgeShape a sh:NodeShape ;
+                sh:targetClass ex:Person ;
+                sh:severity sh:Warning ;
+                sh:property [ sh:path ex:age ; sh:minCount 1 ; sh:severity sh:Warning ] .
+            "#,
+            "turtle",
+        )
+        .unwrap();
+        let dataset = ValidationDataset::from_graphs(data, shapes).unwrap();
+        let parsed_shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+
+        let summary = validation_summary(&dataset, &parsed_shapes);
+        assert_eq!(summary["conforms"], false);
+        assert_eq!(
+            summary["totals"],
+            serde_json::json!({
+                "shapes": 2,
+                "focusNodes": 6,
+                "passed": 1,
+                "failed": 5,
+                "violations": 2,
+                "warnings": 3,
+                "infos": 0,
+                "failures": 0,
+            })
+        );
+
+        let shape = |name: &str| {
+            summary["shapes"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|shape| shape["shape"] == format!("<http://example.org/{}>", name))
+                .unwrap()
+                .clone()
+        };
+        assert_eq!(shape("NameShape")["passed"], 1);
+        assert_eq!(shape("NameShape")["violations"], 2);
+        assert_eq!(shape("AgeShape")["failed"], 3);
+        assert_eq!(shape("AgeShape")["warnings"], 3);
+    }
+
+    #[test]
+    fn test_summary_includes_dataset_constraints_and_failures() {
+        let data = read_graph_from_string(
+            r#"
+            @prefix ex: <http://example.org/> .
+            @prefix owl: <http://www.w3.org/2002/07/owl#> .
+            ex:First a owl:Ontology .
+            ex:Second a owl:Ontology .
+            ex:alice ex:knows ex:bob .
+            ex:bob ex:knows ex:carol .
+            "#,
+            "turtle",
+        )
+        .unwrap();
+        let shapes = read_graph_from_string(
+            r#"
+            @prefix ex: <http://example.org/> .
+            @prefix sh: <http://www.w3.org/ns/shacl#> .
+            @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+            ex:SingleOntologyHeader a shx:DatasetConstraint ;
+                sh:select """
+                    SELECT (COUNT(?ontology) AS ?count) WHERE {
+                        ?ontology a <http://www.w3.org/2002/07/owl#Ontology> .
+                    }
+                    HAVING (COUNT(?ontology) != 1)
+                """ .
+            ex:KnowsShape a sh:NodeShape ;
+                sh:targetNode ex:alice ;
+                sh:property [ sh:path [ sh:oneOrMorePath ex:knows ] ; sh:minCount 0 ] .
+            "#,
+            "turtle",
+        )
+        .unwrap();
+        let dataset = ValidationDataset::from_graphs(data, shapes)
+            .unwrap()
+            .with_config(ValidationConfig {
+                path_limits: PathLimits {
+                    max_visited_nodes: None,
+                    max_depth: Some(1),
+                },
+                ..ValidationConfig::default()
+            });
+        let parsed_shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+
+        let summary = validation_summary(&dataset, &parsed_shapes);
+        assert_eq!(summary["conforms"], false);
+        assert_eq!(summary["totals"]["violations"], 1);
+        assert_eq!(summary["totals"]["failures"], 1);
+        let dataset_constraint = summary["shapes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|shape| shape["shape"] == "<http://example.org/SingleOntologyHeader>")
+            .unwrap();
+        assert_eq!(dataset_constraint["focusNodes"], 1);
+        assert_eq!(dataset_constraint["failed"], 1);
+    }
+}
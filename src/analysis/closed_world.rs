@@ -0,0 +1,204 @@
+//! Global closed-world audit of the data graph.
+//!
+//! `sh:closed` only applies to shapes that opt in. The audit instead reports
+//! every triple whose predicate is not mentioned by any shape targeting its
+//! subject. Subjects no shape targets are left alone, and an allowlist exempts
+//! predicates that are expected everywhere.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use oxigraph::model::{vocab::rdf, Graph, NamedNodeRef, TermRef};
+
+use crate::{
+    core::{
+        component::ConstraintComponent,
+        constraints::Constraint,
+        path::{Path, PathElement},
+        severity::Severity,
+        shape::Shape,
+    },
+    utils,
+    validation::{
+        report::{ValidationReport, ValidationResult},
+        TargetResolutionCache,
+    },
+    vocab::shx,
+};
+
+/// Reports predicates that no applicable shape mentions.
+#[derive(Debug, Clone)]
+pub struct ClosedWorldAudit {
+    /// Allowed predicate IRIs, or namespaces when ending in `*`.
+    allowlist: Vec<String>,
+    severity: Severity<'static>,
+}
+
+impl Default for ClosedWorldAudit {
+    fn default() -> Self {
+        Self {
+            allowlist: Vec::new(),
+            severity: Severity::Violation,
+        }
+    }
+}
+
+impl ClosedWorldAudit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exempts predicates matching any entry; entries ending in `*` match by prefix.
+    pub fn with_allowlist(mut self, allowlist: Vec<String>) -> Self {
+        self.allowlist.extend(allowlist);
+        self
+    }
+
+    /// Sets the severity of reported results (defaults to `sh:Violation`).
+    pub fn with_severity(mut self, severity: Severity<'static>) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Runs the audit over `data_graph` using the targets of `shapes`.
+    pub fn audit<'a>(
+        &self,
+        data_graph: &'a Graph,
+        shapes: &'a [Arc<Shape<'a>>],
+    ) -> ValidationReport<'a> {
+        let target_cache: TargetResolutionCache<'a> = shapes
+            .iter()
+            .flat_map(|shape| &shape.targets)
+            .map(|&target| (target, target.resolve_target_for_given_graph(data_graph)))
+            .collect();
+
+        let mut declared: HashMap<TermRef<'a>, HashSet<NamedNodeRef<'a>>> = HashMap::new();
+        for shape in shapes.iter().filter(|s| !s.deactivated) {
+            let mut predicates = HashSet::from([rdf::TYPE]);
+            mentioned_predicates(shape, &mut predicates);
+
+            for target in &shape.targets {
+                for &focus in target_cache.get(target).into_iter().flatten() {
+                    declared
+                        .entry(focus)
+                        .or_default()
+                        .extend(predicates.iter().copied());
+                }
+            }
+        }
+
+        let mut focus_nodes: Vec<_> = declared.keys().copied().collect();
+        focus_nodes.sort_by_key(|node| node.to_string());
+
+        let mut report = ValidationReport::new();
+        for focus in focus_nodes {
+            let Some(subject) = utils::term_to_named_or_blank(focus) else {
+                continue;
+            };
+            let allowed = &declared[&focus];
+
+            let mut triples: Vec<_> = data_graph
+                .triples_for_subject(subject)
+                .filter(|t| !allowed.contains(&t.predicate) && !self.is_allowlisted(t.predicate))
+                .collect();
+            triples.sort_by_key(|t| (t.predicate.to_string(), t.object.to_string()));
+
+            for triple in triples {
+                report.add_result(
+                    ValidationResult::new(focus, shx::CLOSED_WORLD_AUDIT.into(), self.severity)
+                        .with_source_constraint_component(Some(ConstraintComponent::Other(
+                            shx::UNDECLARED_PROPERTY_COMPONENT,
+                        )))
+                        .with_result_path(Some(
+                            Path::new().add_element(PathElement::Iri(triple.predicate)),
+                        ))
+                        .with_value(Some(triple.object))
+                        .with_messages(Some(vec![format!(
+                            "Property {} is not declared by any shape targeting this node",
+                            triple.predicate
+                        )])),
+                );
+            }
+        }
+        report
+    }
+
+    fn is_allowlisted(&self, predicate: NamedNodeRef<'_>) -> bool {
+        self.allowlist
+            .iter()
+            .any(|entry| match entry.strip_suffix('*') {
+                Some(prefix) => predicate.as_str().starts_with(prefix),
+                None => predicate.as_str() == entry,
+            })
+    }
+}
+
+/// Parses an allowlist file: one IRI per line, `#` starts a comment.
+///
+/// IRIs may be wrapped in angle brackets; a trailing `*` allows a whole namespace.
+pub fn parse_allowlist(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .map(|line| line.split(" #").next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            line.trim_start_matches('<')
+                .replace(">*", "*")
+                .trim_end_matches('>')
+                .to_string()
+        })
+        .collect()
+}
+
+/// Collects the predicates a shape declares, including nested and referenced shapes.
+fn mentioned_predicates<'a>(shape: &Shape<'a>, predicates: &mut HashSet<NamedNodeRef<'a>>) {
+    if let Some(closed) = &shape.closed {
+        predicates.extend(closed.ignored_properties.iter().copied());
+    }
+    for property_shape in &shape.property_shapes {
+        if let Some(path) = &property_shape.path {
+            predicates.extend(path_predicates(path));
+        }
+        mentioned_predicates(property_shape, predicates);
+    }
+
+    for constraint in &shape.constraints {
+        let nested: Vec<&Shape<'a>> = match constraint {
+            Constraint::Node(c) => vec![&c.0],
+            Constraint::Not(c) => vec![&c.0],
+            Constraint::And(c) => c.0.iter().map(AsRef::as_ref).collect(),
+            Constraint::Or(c) => c.0.iter().map(AsRef::as_ref).collect(),
+            Constraint::Xone(c) => c.0.iter().map(AsRef::as_ref).collect(),
+            _ => Vec::new(),
+        };
+        // Nested shapes without a path constrain the same focus node.
+        for nested in nested.into_iter().filter(|s| s.path.is_none()) {
+            mentioned_predicates(nested, predicates);
+        }
+    }
+}
+
+/// Forward predicates used by the first step of a path.
+fn path_predicates<'a>(path: &Path<'a>) -> Vec<NamedNodeRef<'a>> {
+    match path.normalized().get_elements().first() {
+        Some(PathElement::Iri(iri)) => vec![*iri],
+        Some(PathElement::Alternative(alternatives)) => alternatives
+            .iter()
+            .filter_map(|element| match element {
+                PathElement::Iri(iri) => Some(*iri),
+                _ => None,
+            })
+            .collect(),
+        Some(
+            PathElement::ZeroOrMore(inner)
+            | PathElement::OneOrMore(inner)
+            | PathElement::ZeroOrOne(inner),
+        ) => match inner.as_ref() {
+            PathElement::Iri(iri) => vec![*iri],
+            _ => Vec::new(),
+        },
+        _ => Vec::new(),
+    }
+}
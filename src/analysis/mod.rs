@@ -0,0 +1,4 @@
+//! Optional graph-level analyses reported alongside SHACL validation results.
+
+pub mod closed_world;
+pub mod skos;
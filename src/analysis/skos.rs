@@ -0,0 +1,282 @@
+//! Graph-level quality checks for SKOS vocabularies.
+//!
+//! These checks go beyond what SHACL Core can express: cycles in the
+//! `skos:broader` hierarchy, orphan concepts, and missing preferred labels per
+//! language. Results use components from the extension namespace.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use oxigraph::model::{vocab::rdf, Graph, NamedNodeRef, NamedOrBlankNodeRef, TermRef};
+
+use crate::{
+    core::{
+        component::ConstraintComponent,
+        path::{Path, PathElement},
+        severity::Severity,
+    },
+    utils::term_to_named_or_blank,
+    validation::report::{ValidationReport, ValidationResult},
+    vocab::{shx, skos},
+};
+
+/// Configurable set of SKOS heuristics.
+#[derive(Debug, Clone, Default)]
+pub struct SkosAnalysis {
+    /// Languages every concept needs a `skos:prefLabel` in.
+    languages: Option<Vec<String>>,
+}
+
+impl SkosAnalysis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires a `skos:prefLabel` in each of `languages`.
+    ///
+    /// Without this, every language used by some preferred label is expected.
+    pub fn with_languages(mut self, languages: Vec<String>) -> Self {
+        self.languages = Some(languages);
+        self
+    }
+
+    /// Runs all checks against `graph`.
+    pub fn analyze<'a>(&self, graph: &'a Graph) -> ValidationReport<'a> {
+        let concepts = concepts(graph);
+
+        let mut report = ValidationReport::new();
+        report.extend_results(hierarchy_cycles(graph, &concepts));
+        report.extend_results(orphan_concepts(graph, &concepts));
+        report.extend_results(self.missing_pref_labels(graph, &concepts));
+        report
+    }
+
+    fn missing_pref_labels<'a>(
+        &self,
+        graph: &'a Graph,
+        concepts: &[NamedOrBlankNodeRef<'a>],
+    ) -> Vec<ValidationResult<'a>> {
+        let label_languages = |concept: NamedOrBlankNodeRef<'a>| -> HashSet<String> {
+            graph
+                .objects_for_subject_predicate(concept, skos::PREF_LABEL)
+                .filter_map(|label| match label {
+                    TermRef::Literal(literal) => {
+                        Some(literal.language().unwrap_or_default().to_ascii_lowercase())
+                    }
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let expected: BTreeSet<String> = match &self.languages {
+            Some(languages) => languages.iter().map(|l| l.to_ascii_lowercase()).collect(),
+            None => concepts
+                .iter()
+                .flat_map(|&concept| label_languages(concept))
+                .filter(|language| !language.is_empty())
+                .collect(),
+        };
+
+        let mut results = Vec::new();
+        for &concept in concepts {
+            let present = label_languages(concept);
+
+            if expected.is_empty() {
+                if present.is_empty() {
+                    results.push(
+                        skos_result(
+                            concept,
+                            Severity::Warning,
+                            shx::MISSING_PREF_LABEL_COMPONENT,
+                        )
+                        .with_result_path(Some(iri_path(skos::PREF_LABEL)))
+                        .with_messages(Some(vec!["Concept has no skos:prefLabel".to_string()])),
+                    );
+                }
+                continue;
+            }
+
+            for language in expected.iter().filter(|l| !present.contains(*l)) {
+                results.push(
+                    skos_result(concept, Severity::Info, shx::MISSING_PREF_LABEL_COMPONENT)
+                        .with_result_path(Some(iri_path(skos::PREF_LABEL)))
+                        .with_messages(Some(vec![format!(
+                            "Concept has no skos:prefLabel in language '{}'",
+                            language
+                        )])),
+                );
+            }
+        }
+        results
+    }
+}
+
+/// Instances of `skos:Concept`, in a stable order.
+fn concepts(graph: &Graph) -> Vec<NamedOrBlankNodeRef<'_>> {
+    let mut concepts: Vec<_> = graph
+        .subjects_for_predicate_object(rdf::TYPE, skos::CONCEPT)
+        .collect();
+    concepts.sort_by_key(|c| c.to_string());
+    concepts.dedup();
+    concepts
+}
+
+/// Reports every concept taking part in a `skos:broader` cycle.
+fn hierarchy_cycles<'a>(
+    graph: &'a Graph,
+    concepts: &[NamedOrBlankNodeRef<'a>],
+) -> Vec<ValidationResult<'a>> {
+    let mut broader: HashMap<NamedOrBlankNodeRef<'a>, Vec<NamedOrBlankNodeRef<'a>>> =
+        HashMap::new();
+    for triple in graph.triples_for_predicate(skos::BROADER) {
+        if let Some(object) = term_to_named_or_blank(triple.object) {
+            broader.entry(triple.subject).or_default().push(object);
+        }
+    }
+    for triple in graph.triples_for_predicate(skos::NARROWER) {
+        if let Some(object) = term_to_named_or_blank(triple.object) {
+            broader.entry(object).or_default().push(triple.subject);
+        }
+    }
+
+    let mut results = Vec::new();
+    for component in strongly_connected_components(concepts, &broader) {
+        let is_cycle = component.len() > 1
+            || broader
+                .get(&component[0])
+                .is_some_and(|targets| targets.contains(&component[0]));
+        if !is_cycle {
+            continue;
+        }
+
+        let members: HashSet<_> = component.iter().copied().collect();
+
+        for &concept in &component {
+            let next = broader
+                .get(&concept)
+                .and_then(|targets| targets.iter().find(|t| members.contains(*t)))
+                .copied();
+            results.push(
+                skos_result(concept, Severity::Violation, shx::HIERARCHY_CYCLE_COMPONENT)
+                    .with_result_path(Some(iri_path(skos::BROADER)))
+                    .with_value(next.map(TermRef::from))
+                    .with_messages(Some(vec![format!(
+                        "Concept is part of a skos:broader cycle of {} concept(s)",
+                        component.len()
+                    )])),
+            );
+        }
+    }
+    results
+}
+
+/// Reports concepts without broader, narrower or related links that are not top concepts.
+fn orphan_concepts<'a>(
+    graph: &'a Graph,
+    concepts: &[NamedOrBlankNodeRef<'a>],
+) -> Vec<ValidationResult<'a>> {
+    let relations = [skos::BROADER, skos::NARROWER, skos::RELATED];
+    let is_connected = |concept: NamedOrBlankNodeRef<'a>| {
+        relations.iter().any(|&relation| {
+            graph
+                .object_for_subject_predicate(concept, relation)
+                .is_some()
+                || graph
+                    .subject_for_predicate_object(relation, concept)
+                    .is_some()
+        }) || graph
+            .object_for_subject_predicate(concept, skos::TOP_CONCEPT_OF)
+            .is_some()
+            || graph
+                .subject_for_predicate_object(skos::HAS_TOP_CONCEPT, concept)
+                .is_some()
+    };
+
+    concepts
+        .iter()
+        .filter(|&&concept| !is_connected(concept))
+        .map(|&concept| {
+            skos_result(concept, Severity::Warning, shx::ORPHAN_CONCEPT_COMPONENT).with_messages(
+                Some(vec![
+                    "Concept is not related to any other concept".to_string()
+                ]),
+            )
+        })
+        .collect()
+}
+
+fn skos_result<'a>(
+    concept: NamedOrBlankNodeRef<'a>,
+    severity: Severity<'a>,
+    component: NamedNodeRef<'a>,
+) -> ValidationResult<'a> {
+    ValidationResult::new(concept.into(), shx::SKOS_ANALYSIS.into(), severity)
+        .with_source_constraint_component(Some(ConstraintComponent::Other(component)))
+}
+
+fn iri_path(predicate: NamedNodeRef<'_>) -> Path<'_> {
+    Path::new().add_element(PathElement::Iri(predicate))
+}
+
+/// Tarjan's algorithm, iterative so deep hierarchies do not overflow the stack.
+fn strongly_connected_components<'a>(
+    nodes: &[NamedOrBlankNodeRef<'a>],
+    edges: &HashMap<NamedOrBlankNodeRef<'a>, Vec<NamedOrBlankNodeRef<'a>>>,
+) -> Vec<Vec<NamedOrBlankNodeRef<'a>>> {
+    let mut index: HashMap<NamedOrBlankNodeRef<'a>, usize> = HashMap::new();
+    let mut low: HashMap<NamedOrBlankNodeRef<'a>, usize> = HashMap::new();
+    let mut on_stack = HashSet::new();
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+
+    for &start in nodes {
+        if index.contains_key(&start) {
+            continue;
+        }
+
+        let mut calls = vec![(start, 0usize)];
+        index.insert(start, index.len());
+        low.insert(start, index[&start]);
+        stack.push(start);
+        on_stack.insert(start);
+
+        while let Some((node, next_edge)) = calls.last_mut() {
+            let node = *node;
+            let successor = edges.get(&node).and_then(|targets| targets.get(*next_edge));
+
+            if let Some(&successor) = successor {
+                *next_edge += 1;
+                if !index.contains_key(&successor) {
+                    index.insert(successor, index.len());
+                    low.insert(successor, index[&successor]);
+                    stack.push(successor);
+                    on_stack.insert(successor);
+                    calls.push((successor, 0));
+                } else if on_stack.contains(&successor) {
+                    let lowest = low[&node].min(index[&successor]);
+                    low.insert(node, lowest);
+                }
+                continue;
+            }
+
+            calls.pop();
+            if let Some(&(parent, _)) = calls.last() {
+                let lowest = low[&parent].min(low[&node]);
+                low.insert(parent, lowest);
+            }
+
+            if low[&node] == index[&node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack.remove(&member);
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
@@ -0,0 +1,383 @@
+//! Runner for the W3C SHACL test suite.
+//!
+//! A suite is a tree of `mf:Manifest` files linked by `mf:include`. Every
+//! approved `sht:Validate` entry names a data graph, a shapes graph and the
+//! expected outcome: a report with a given `sh:conforms`, or `sht:Failure`.
+//! Only errors and failures recorded on the report satisfy an expected
+//! failure; a non-conforming report does not.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use log::{debug, info, warn};
+use oxigraph::model::{
+    vocab::{rdf, rdfs},
+    Graph, NamedOrBlankNodeRef, TermRef,
+};
+
+use crate::{
+    err::ShaclError,
+    parser,
+    rdf::read_graph_from_string_with_base,
+    utils::{parse_rdf_list, term_to_named_or_blank},
+    validation::{dataset::ValidationDataset, report::ValidationReport, validate},
+    vocab::sh,
+};
+
+/// Test manifest vocabulary.
+mod mf {
+    use oxigraph::model::NamedNodeRef;
+    pub const MANIFEST: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+        "http://www.w3.org/2001/sw/DataAccess/tests/test-manifest#Manifest",
+    );
+    pub const ENTRIES: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+        "http://www.w3.org/2001/sw/DataAccess/tests/test-manifest#entries",
+    );
+    pub const INCLUDE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+        "http://www.w3.org/2001/sw/DataAccess/tests/test-manifest#include",
+    );
+    pub const ACTION: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+        "http://www.w3.org/2001/sw/DataAccess/tests/test-manifest#action",
+    );
+    pub const RESULT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+        "http://www.w3.org/2001/sw/DataAccess/tests/test-manifest#result",
+    );
+    pub const STATUS: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+        "http://www.w3.org/2001/sw/DataAccess/tests/test-manifest#status",
+    );
+}
+
+/// SHACL test vocabulary.
+mod sht {
+    use oxigraph::model::NamedNodeRef;
+    pub const VALIDATE: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl-test#Validate");
+    pub const DATA_GRAPH: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl-test#dataGraph");
+    pub const SHAPES_GRAPH: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl-test#shapesGraph");
+    pub const APPROVED: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl-test#approved");
+    pub const FAILURE: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl-test#Failure");
+}
+
+/// Base used by the W3C implementation reports to identify test cases.
+pub const TEST_IRI_BASE: &str = "urn:x-shacl-test:";
+
+/// What a test case expects validation to produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExpectedOutcome {
+    /// A report with the given `sh:conforms`.
+    Conforms(bool),
+    /// An error or a failure recorded on the report.
+    Failure,
+}
+
+/// An approved `sht:Validate` entry of a manifest.
+#[derive(Debug, Clone)]
+pub struct TestCase {
+    pub uri: String,
+    pub label: Option<String>,
+    pub data_graph_file: PathBuf,
+    pub shapes_graph_file: PathBuf,
+    pub expected_outcome: ExpectedOutcome,
+}
+
+/// Outcome of running one test case.
+#[derive(Debug, Clone)]
+pub struct TestOutcome {
+    /// Test IRI, relative test IRIs mapped to [`TEST_IRI_BASE`].
+    pub test: String,
+    /// Optional test label.
+    pub label: Option<String>,
+    /// Whether the implementation produced the expected outcome.
+    pub passed: bool,
+    /// Short explanation of the outcome.
+    pub info: String,
+}
+
+/// Runs all approved `sht:Validate` tests reachable from the manifest.
+///
+/// `manifest` may point to a manifest file or to a directory containing a
+/// `manifest.ttl`.
+pub fn run_test_suite(manifest: &Path) -> Result<Vec<TestOutcome>, ShaclError> {
+    let manifest_file = if manifest.is_dir() {
+        manifest.join("manifest.ttl")
+    } else {
+        manifest.to_path_buf()
+    };
+
+    if !manifest_file.exists() {
+        return Err(ShaclError::Io(format!(
+            "Manifest not found: {}",
+            manifest_file.display()
+        )));
+    }
+
+    let root_dir = manifest_file
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .canonicalize()
+        .map_err(|e| ShaclError::Io(format!("Failed to resolve manifest directory: {}", e)))?;
+    let root_prefix = format!("file://{}", root_dir.to_string_lossy());
+
+    let test_cases = load_test_cases(&manifest_file);
+    info!("Running {} conformance test case(s)", test_cases.len());
+
+    Ok(test_cases
+        .iter()
+        .map(|test_case| {
+            let (passed, info) = test_case.run();
+            debug!("{} {}", if passed { "PASS" } else { "FAIL" }, test_case.uri);
+            TestOutcome {
+                test: test_iri(&test_case.uri, &root_prefix),
+                label: test_case.label.clone(),
+                passed,
+                info,
+            }
+        })
+        .collect())
+}
+
+/// Collects the test cases of a manifest and the manifests it includes,
+/// deduplicated and sorted by IRI.
+pub fn load_test_cases(manifest_file: &Path) -> Vec<TestCase> {
+    let mut test_cases = Vec::new();
+    let mut visited_files = HashSet::new();
+    collect_test_cases(manifest_file, &mut test_cases, &mut visited_files);
+
+    let mut seen = HashSet::new();
+    test_cases.retain(|tc| seen.insert(tc.uri.clone()));
+    test_cases.sort_by(|a, b| a.uri.cmp(&b.uri));
+    test_cases
+}
+
+impl TestCase {
+    /// Validates the data graph against the shapes graph and judges the result.
+    pub fn run(&self) -> (bool, String) {
+        let data_graph = match read_graph_file(&self.data_graph_file) {
+            Ok(graph) => graph,
+            Err(e) => return self.outcome_for_error(format!("data read error: {}", e)),
+        };
+        let shapes_graph = match read_graph_file(&self.shapes_graph_file) {
+            Ok(graph) => graph,
+            Err(e) => return self.outcome_for_error(format!("shapes read error: {}", e)),
+        };
+
+        let validation_dataset = match ValidationDataset::from_graphs(data_graph, shapes_graph) {
+            Ok(dataset) => dataset,
+            Err(e) => return self.outcome_for_error(format!("dataset error: {}", e)),
+        };
+
+        let shapes = match parser::parse_shapes(validation_dataset.shapes_graph()) {
+            Ok(shapes) => shapes,
+            Err(e) => return self.outcome_for_error(format!("parse error: {}", e)),
+        };
+
+        let report = validate(&validation_dataset, &shapes);
+        self.outcome_for_report(&report)
+    }
+
+    /// A report never satisfies an expected failure, whether it conforms or
+    /// not: only errors and failures recorded on the report do.
+    pub fn outcome_for_report(&self, report: &ValidationReport) -> (bool, String) {
+        if !report.get_failures().is_empty() {
+            let failures: Vec<String> = report
+                .get_failures()
+                .iter()
+                .map(|f| f.to_string())
+                .collect();
+            return self.outcome_for_error(format!("validation failure: {}", failures.join("; ")));
+        }
+
+        let conforms = *report.get_conforms();
+        match self.expected_outcome {
+            ExpectedOutcome::Conforms(expected) => (
+                conforms == expected,
+                format!(
+                    "expected conforms: {}, got: {} ({} results)",
+                    expected,
+                    conforms,
+                    report.violation_count()
+                ),
+            ),
+            ExpectedOutcome::Failure => (
+                false,
+                format!("expected failure, got a report with conforms: {}", conforms),
+            ),
+        }
+    }
+
+    /// Judges an error raised before a report could be produced.
+    pub fn outcome_for_error(&self, error: String) -> (bool, String) {
+        match self.expected_outcome {
+            ExpectedOutcome::Failure => (true, format!("expected failure observed ({})", error)),
+            ExpectedOutcome::Conforms(_) => (false, error),
+        }
+    }
+}
+
+/// Maps a file-based test IRI to the `urn:x-shacl-test:` scheme used by the
+/// W3C implementation reports.
+fn test_iri(uri: &str, root_prefix: &str) -> String {
+    match uri.strip_prefix(root_prefix) {
+        Some(relative) => format!("{}{}", TEST_IRI_BASE, relative),
+        None => uri.to_string(),
+    }
+}
+
+/// Reads a graph with its `file://` IRI as base, so relative references in
+/// manifests resolve to `file://` IRIs.
+fn read_graph_file(path: &Path) -> Result<Graph, ShaclError> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        ShaclError::Io(format!(
+            "Failed to read graph file '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+
+    let format = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .ok_or_else(|| {
+            ShaclError::Parse(format!(
+                "Could not infer RDF format for '{}'",
+                path.display()
+            ))
+        })?;
+
+    let canonical = path
+        .canonicalize()
+        .map_err(|e| ShaclError::Io(format!("Failed to resolve '{}': {}", path.display(), e)))?;
+    let base_iri = format!("file://{}", canonical.to_string_lossy());
+
+    read_graph_from_string_with_base(&content, format, &base_iri)
+}
+
+/// Resolves a `file://` graph reference, falling back to a file of the same
+/// name next to the manifest when the suite has been moved.
+fn resolve_graph_file(base_file: &Path, graph_ref: TermRef) -> Option<PathBuf> {
+    let TermRef::NamedNode(nn) = graph_ref else {
+        return None;
+    };
+
+    let path = PathBuf::from(nn.as_str().strip_prefix("file://")?);
+    if path.exists() {
+        Some(path)
+    } else {
+        base_file
+            .parent()
+            .zip(path.file_name())
+            .map(|(dir, file_name)| dir.join(file_name))
+            .filter(|candidate| candidate.exists())
+    }
+}
+
+fn collect_test_cases(
+    manifest_file: &Path,
+    test_cases: &mut Vec<TestCase>,
+    visited_files: &mut HashSet<PathBuf>,
+) {
+    if !visited_files.insert(manifest_file.to_path_buf()) {
+        return;
+    }
+
+    let graph = match read_graph_file(manifest_file) {
+        Ok(graph) => graph,
+        Err(e) => {
+            warn!(
+                "Failed to read manifest file {}: {}",
+                manifest_file.display(),
+                e
+            );
+            return;
+        }
+    };
+
+    let manifests: Vec<_> = graph
+        .subjects_for_predicate_object(rdf::TYPE, mf::MANIFEST)
+        .collect();
+
+    for manifest_node in manifests {
+        for include_ref in graph.objects_for_subject_predicate(manifest_node, mf::INCLUDE) {
+            if let Some(include_file) = resolve_graph_file(manifest_file, include_ref) {
+                collect_test_cases(&include_file, test_cases, visited_files);
+            }
+        }
+
+        for entries_ref in graph.objects_for_subject_predicate(manifest_node, mf::ENTRIES) {
+            let Some(entries_node) = term_to_named_or_blank(entries_ref) else {
+                continue;
+            };
+            for entry in parse_rdf_list(&graph, entries_node) {
+                if let Some(test_case) = parse_test_case(&graph, entry, manifest_file) {
+                    test_cases.push(test_case);
+                }
+            }
+        }
+    }
+}
+
+fn parse_test_case(graph: &Graph, test_node: TermRef, base_file: &Path) -> Option<TestCase> {
+    let test_subject = term_to_named_or_blank(test_node)?;
+
+    let is_validate = graph
+        .objects_for_subject_predicate(test_subject, rdf::TYPE)
+        .any(|t| t == sht::VALIDATE.into());
+    let is_approved = graph
+        .objects_for_subject_predicate(test_subject, mf::STATUS)
+        .any(|t| t == sht::APPROVED.into());
+
+    if !is_validate || !is_approved {
+        return None;
+    }
+
+    let label = graph
+        .object_for_subject_predicate(test_subject, rdfs::LABEL)
+        .and_then(|t| match t {
+            TermRef::Literal(lit) => Some(lit.value().to_string()),
+            _ => None,
+        });
+
+    let action =
+        term_to_named_or_blank(graph.object_for_subject_predicate(test_subject, mf::ACTION)?)?;
+    let data_graph_file = resolve_graph_file(
+        base_file,
+        graph.object_for_subject_predicate(action, sht::DATA_GRAPH)?,
+    )?;
+    let shapes_graph_file = resolve_graph_file(
+        base_file,
+        graph.object_for_subject_predicate(action, sht::SHAPES_GRAPH)?,
+    )?;
+
+    let expected_outcome = match graph.object_for_subject_predicate(test_subject, mf::RESULT)? {
+        TermRef::NamedNode(nn) if nn == sht::FAILURE => ExpectedOutcome::Failure,
+        TermRef::BlankNode(bn) => {
+            let result_node = NamedOrBlankNodeRef::BlankNode(bn);
+            let is_report = graph
+                .objects_for_subject_predicate(result_node, rdf::TYPE)
+                .any(|t| t == sh::VALIDATION_REPORT.into());
+            if !is_report {
+                return None;
+            }
+            match graph.object_for_subject_predicate(result_node, sh::CONFORMS)? {
+                TermRef::Literal(lit) => ExpectedOutcome::Conforms(lit.value() == "true"),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    Some(TestCase {
+        uri: test_subject
+            .to_string()
+            .trim_matches(['<', '>'])
+            .to_string(),
+        label,
+        data_graph_file,
+        shapes_graph_file,
+        expected_outcome,
+    })
+}
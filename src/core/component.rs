@@ -0,0 +1,198 @@
+//! Constraint components of validation results.
+
+use std::{
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+use oxigraph::model::NamedNodeRef;
+
+use crate::vocab::sh;
+
+/// The constraint component of a result, with the SHACL components as variants.
+///
+/// [`ConstraintComponent::from_iri`] maps the SHACL components to their
+/// variants. Values compare and hash by IRI, so an [`ConstraintComponent::Other`]
+/// holding a SHACL component still equals its variant.
+#[derive(Debug, Clone, Copy)]
+pub enum ConstraintComponent<'a> {
+    And,
+    Class,
+    Closed,
+    Datatype,
+    Disjoint,
+    Equals,
+    Expression,
+    HasValue,
+    In,
+    LanguageIn,
+    LessThan,
+    LessThanOrEquals,
+    MaxCount,
+    MaxExclusive,
+    MaxInclusive,
+    MaxLength,
+    MinCount,
+    MinExclusive,
+    MinInclusive,
+    MinLength,
+    Node,
+    NodeKind,
+    Not,
+    Or,
+    Pattern,
+    Property,
+    QualifiedMaxCount,
+    QualifiedMinCount,
+    UniqueLang,
+    Xone,
+    Sparql,
+    /// A component defined outside SHACL Core and SHACL-SPARQL, e.g. by an
+    /// extension or a SPARQL-based constraint component.
+    Other(NamedNodeRef<'a>),
+}
+
+impl<'a> ConstraintComponent<'a> {
+    pub const ALL: [ConstraintComponent<'static>; 31] = [
+        ConstraintComponent::And,
+        ConstraintComponent::Class,
+        ConstraintComponent::Closed,
+        ConstraintComponent::Datatype,
+        ConstraintComponent::Disjoint,
+        ConstraintComponent::Equals,
+        ConstraintComponent::Expression,
+        ConstraintComponent::HasValue,
+        ConstraintComponent::In,
+        ConstraintComponent::LanguageIn,
+        ConstraintComponent::LessThan,
+        ConstraintComponent::LessThanOrEquals,
+        ConstraintComponent::MaxCount,
+        ConstraintComponent::MaxExclusive,
+        ConstraintComponent::MaxInclusive,
+        ConstraintComponent::MaxLength,
+        ConstraintComponent::MinCount,
+        ConstraintComponent::MinExclusive,
+        ConstraintComponent::MinInclusive,
+        ConstraintComponent::MinLength,
+        ConstraintComponent::Node,
+        ConstraintComponent::NodeKind,
+        ConstraintComponent::Not,
+        ConstraintComponent::Or,
+        ConstraintComponent::Pattern,
+        ConstraintComponent::Property,
+        ConstraintComponent::QualifiedMaxCount,
+        ConstraintComponent::QualifiedMinCount,
+        ConstraintComponent::UniqueLang,
+        ConstraintComponent::Xone,
+        ConstraintComponent::Sparql,
+    ];
+
+    pub fn iri(self) -> NamedNodeRef<'a> {
+        match self {
+            ConstraintComponent::And => sh::AND_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Class => sh::CLASS_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Closed => sh::CLOSED_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Datatype => sh::DATATYPE_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Disjoint => sh::DISJOINT_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Equals => sh::EQUALS_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Expression => sh::EXPRESSION_CONSTRAINT_COMPONENT,
+            ConstraintComponent::HasValue => sh::HAS_VALUE_CONSTRAINT_COMPONENT,
+            ConstraintComponent::In => sh::IN_CONSTRAINT_COMPONENT,
+            ConstraintComponent::LanguageIn => sh::LANGUAGE_IN_CONSTRAINT_COMPONENT,
+            ConstraintComponent::LessThan => sh::LESS_THAN_CONSTRAINT_COMPONENT,
+            ConstraintComponent::LessThanOrEquals => sh::LESS_THAN_OR_EQUALS_CONSTRAINT_COMPONENT,
+            ConstraintComponent::MaxCount => sh::MAX_COUNT_CONSTRAINT_COMPONENT,
+            ConstraintComponent::MaxExclusive => sh::MAX_EXCLUSIVE_CONSTRAINT_COMPONENT,
+            ConstraintComponent::MaxInclusive => sh::MAX_INCLUSIVE_CONSTRAINT_COMPONENT,
+            ConstraintComponent::MaxLength => sh::MAX_LENGTH_CONSTRAINT_COMPONENT,
+            ConstraintComponent::MinCount => sh::MIN_COUNT_CONSTRAINT_COMPONENT,
+            ConstraintComponent::MinExclusive => sh::MIN_EXCLUSIVE_CONSTRAINT_COMPONENT,
+            ConstraintComponent::MinInclusive => sh::MIN_INCLUSIVE_CONSTRAINT_COMPONENT,
+            ConstraintComponent::MinLength => sh::MIN_LENGTH_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Node => sh::NODE_CONSTRAINT_COMPONENT,
+            ConstraintComponent::NodeKind => sh::NODE_KIND_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Not => sh::NOT_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Or => sh::OR_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Pattern => sh::PATTERN_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Property => sh::PROPERTY_CONSTRAINT_COMPONENT,
+            ConstraintComponent::QualifiedMaxCount => sh::QUALIFIED_MAX_COUNT_CONSTRAINT_COMPONENT,
+            ConstraintComponent::QualifiedMinCount => sh::QUALIFIED_MIN_COUNT_CONSTRAINT_COMPONENT,
+            ConstraintComponent::UniqueLang => sh::UNIQUE_LANG_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Xone => sh::XONE_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Sparql => sh::SPARQL_CONSTRAINT_COMPONENT,
+            ConstraintComponent::Other(iri) => iri,
+        }
+    }
+
+    pub fn from_iri(iri: NamedNodeRef<'a>) -> Self {
+        let Some(local_name) = iri.as_str().strip_prefix(sh::SHACL_NAMESPACE) else {
+            return ConstraintComponent::Other(iri);
+        };
+        match local_name {
+            "AndConstraintComponent" => ConstraintComponent::And,
+            "ClassConstraintComponent" => ConstraintComponent::Class,
+            "ClosedConstraintComponent" => ConstraintComponent::Closed,
+            "DatatypeConstraintComponent" => ConstraintComponent::Datatype,
+            "DisjointConstraintComponent" => ConstraintComponent::Disjoint,
+            "EqualsConstraintComponent" => ConstraintComponent::Equals,
+            "ExpressionConstraintComponent" => ConstraintComponent::Expression,
+            "HasValueConstraintComponent" => ConstraintComponent::HasValue,
+            "InConstraintComponent" => ConstraintComponent::In,
+            "LanguageInConstraintComponent" => ConstraintComponent::LanguageIn,
+            "LessThanConstraintComponent" => ConstraintComponent::LessThan,
+            "LessThanOrEqualsConstraintComponent" => ConstraintComponent::LessThanOrEquals,
+            "MaxCountConstraintComponent" => ConstraintComponent::MaxCount,
+            "MaxExclusiveConstraintComponent" => ConstraintComponent::MaxExclusive,
+            "MaxInclusiveConstraintComponent" => ConstraintComponent::MaxInclusive,
+            "MaxLengthConstraintComponent" => ConstraintComponent::MaxLength,
+            "MinCountConstraintComponent" => ConstraintComponent::MinCount,
+            "MinExclusiveConstraintComponent" => ConstraintComponent::MinExclusive,
+            "MinInclusiveConstraintComponent" => ConstraintComponent::MinInclusive,
+            "MinLengthConstraintComponent" => ConstraintComponent::MinLength,
+            "NodeConstraintComponent" => ConstraintComponent::Node,
+            "NodeKindConstraintComponent" => ConstraintComponent::NodeKind,
+            "NotConstraintComponent" => ConstraintComponent::Not,
+            "OrConstraintComponent" => ConstraintComponent::Or,
+            "PatternConstraintComponent" => ConstraintComponent::Pattern,
+            "PropertyConstraintComponent" => ConstraintComponent::Property,
+            "QualifiedMaxCountConstraintComponent" => ConstraintComponent::QualifiedMaxCount,
+            "QualifiedMinCountConstraintComponent" => ConstraintComponent::QualifiedMinCount,
+            "UniqueLangConstraintComponent" => ConstraintComponent::UniqueLang,
+            "XoneConstraintComponent" => ConstraintComponent::Xone,
+            "SPARQLConstraintComponent" => ConstraintComponent::Sparql,
+            _ => ConstraintComponent::Other(iri),
+        }
+    }
+}
+
+impl PartialEq for ConstraintComponent<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iri() == other.iri()
+    }
+}
+
+impl Eq for ConstraintComponent<'_> {}
+
+impl Hash for ConstraintComponent<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.iri().hash(state)
+    }
+}
+
+impl<'a> From<NamedNodeRef<'a>> for ConstraintComponent<'a> {
+    fn from(iri: NamedNodeRef<'a>) -> Self {
+        ConstraintComponent::from_iri(iri)
+    }
+}
+
+impl PartialEq<NamedNodeRef<'_>> for ConstraintComponent<'_> {
+    fn eq(&self, other: &NamedNodeRef<'_>) -> bool {
+        self.iri() == *other
+    }
+}
+
+impl Display for ConstraintComponent<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.iri().fmt(f)
+    }
+}
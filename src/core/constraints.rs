@@ -1,7 +1,14 @@
 use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
 use std::fmt::Display;
+use std::sync::Arc;
 
-use crate::Path;
+use crate::{
+    core::{
+        component::ConstraintComponent, expression::NodeExpression, identifier::IdentifierFormat,
+    },
+    vocab::{dash, shx},
+    Path,
+};
 
 use super::shape::Shape;
 
@@ -16,12 +23,29 @@ pub enum NodeKind {
     IRIOrLiteral,
 }
 
+impl NodeKind {
+    /// Returns whether `term` has this node kind.
+    pub fn matches(&self, term: TermRef<'_>) -> bool {
+        match self {
+            NodeKind::BlankNode => term.is_blank_node(),
+            NodeKind::IRI => term.is_named_node(),
+            NodeKind::Literal => term.is_literal(),
+            NodeKind::BlankNodeOrIRI => term.is_blank_node() || term.is_named_node(),
+            NodeKind::BlankNodeOrLiteral => term.is_blank_node() || term.is_literal(),
+            NodeKind::IRIOrLiteral => term.is_named_node() || term.is_literal(),
+        }
+    }
+}
+
 // ============ Constraint Newtype Wrappers ============
 // Each constraint type gets its own wrapper that implements Validate
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ClassConstraint<'a>(pub NamedNodeRef<'a>);
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UniqueValueForClassConstraint<'a>(pub NamedNodeRef<'a>);
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DatatypeConstraint<'a>(pub NamedNodeRef<'a>);
 
@@ -52,6 +76,27 @@ pub struct MinLengthConstraint(pub i32);
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MaxLengthConstraint(pub i32);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TotalDigitsConstraint(pub i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FractionDigitsConstraint(pub i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeTimeConstraint {
+    pub min_days: Option<i32>,
+    pub max_days: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamespaceInConstraint(pub Vec<String>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UriTemplateConstraint(pub Vec<String>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdentifierFormatConstraint(pub IdentifierFormat);
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PatternConstraint {
     pub pattern: String,
@@ -83,27 +128,30 @@ pub struct HasValueConstraint<'a>(pub TermRef<'a>);
 pub struct InConstraint<'a>(pub Vec<TermRef<'a>>);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct NodeConstraint<'a>(pub Box<Shape<'a>>);
+pub struct NodeConstraint<'a>(pub Arc<Shape<'a>>);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QualifiedValueShapeConstraint<'a> {
-    pub shape: Box<Shape<'a>>,
+    pub shape: Arc<Shape<'a>>,
     pub qualified_min_count: Option<i32>,
     pub qualified_max_count: Option<i32>,
     pub qualified_value_shapes_disjoint: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct AndConstraint<'a>(pub Vec<Shape<'a>>);
+pub struct AndConstraint<'a>(pub Vec<Arc<Shape<'a>>>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrConstraint<'a>(pub Vec<Arc<Shape<'a>>>);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct OrConstraint<'a>(pub Vec<Shape<'a>>);
+pub struct XoneConstraint<'a>(pub Vec<Arc<Shape<'a>>>);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct XoneConstraint<'a>(pub Vec<Shape<'a>>);
+pub struct NotConstraint<'a>(pub Arc<Shape<'a>>);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct NotConstraint<'a>(pub Box<Shape<'a>>);
+pub struct ExpressionConstraint<'a>(pub NodeExpression<'a>);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SparqlExecutable {
@@ -120,6 +168,12 @@ impl SparqlExecutable {
     }
 }
 
+/// A SPARQL-based constraint or constraint component validator.
+///
+/// `$this`, `$shapesGraph`, `$currentShape` and the component parameters are
+/// pre-bound for every query; `$value` is pre-bound for ASK validators. `$PATH`
+/// is pre-bound only in property shapes whose path is a single predicate, and
+/// queries referencing it anywhere else are reported instead of rewritten.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SparqlConstraint<'a> {
     pub source_constraint: Option<NamedOrBlankNodeRef<'a>>,
@@ -130,6 +184,39 @@ pub struct SparqlConstraint<'a> {
     pub parameter_bindings: Vec<(String, TermRef<'a>)>,
 }
 
+/// An instance of a constraint component that has no SPARQL validator, left to
+/// the validator registered for it in a
+/// [`ConstraintComponentRegistry`](crate::validation::components::ConstraintComponentRegistry).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomConstraint<'a> {
+    pub component: NamedNodeRef<'a>,
+    /// Parameter values, by the `sh:path` of their parameter declaration.
+    pub parameter_bindings: Vec<(NamedNodeRef<'a>, TermRef<'a>)>,
+}
+
+impl<'a> SparqlConstraint<'a> {
+    /// The component the constraint instantiates, `sh:SPARQLConstraintComponent`
+    /// for a `sh:sparql` constraint.
+    pub fn component(&self) -> ConstraintComponent<'a> {
+        match self.source_constraint_component {
+            Some(NamedOrBlankNodeRef::NamedNode(component)) => {
+                ConstraintComponent::from_iri(component)
+            }
+            _ => ConstraintComponent::Sparql,
+        }
+    }
+}
+
+impl<'a> CustomConstraint<'a> {
+    /// The value of the parameter with `sh:path` `path`, if it was given.
+    pub fn parameter(&self, path: NamedNodeRef<'_>) -> Option<TermRef<'a>> {
+        self.parameter_bindings
+            .iter()
+            .find(|(parameter, _)| *parameter == path)
+            .map(|(_, value)| *value)
+    }
+}
+
 /// SHACL Constraint that can be applied to focus nodes or property values
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Constraint<'a> {
@@ -179,6 +266,27 @@ pub enum Constraint<'a> {
     /// Each value must have a unique language tag (requires path)
     UniqueLang(UniqueLangConstraint),
 
+    // ============ Decimal Precision Constraints (extension) ============
+    /// Maximum number of significant digits of decimal values
+    TotalDigits(TotalDigitsConstraint),
+
+    /// Maximum number of fractional digits of decimal values
+    FractionDigits(FractionDigitsConstraint),
+
+    // ============ Temporal Constraints (extension) ============
+    /// Dates and times must lie in a window relative to the time of validation
+    RelativeTime(RelativeTimeConstraint),
+
+    // ============ IRI Constraints (extension) ============
+    /// Value IRIs must be in one of the given namespaces
+    NamespaceIn(NamespaceInConstraint),
+
+    /// Value IRIs must match one of the given URI templates
+    UriTemplate(UriTemplateConstraint),
+
+    /// Values must be identifiers of the given format, with a valid checksum where it has one
+    IdentifierFormat(IdentifierFormatConstraint),
+
     // ============ Property Pair Constraints (require path) ============
     /// Property values must equal values of another property
     Equals(EqualsConstraint<'a>),
@@ -207,6 +315,10 @@ pub enum Constraint<'a> {
     /// Qualified value shape constraint
     QualifiedValueShape(QualifiedValueShapeConstraint<'a>),
 
+    // ============ Key Constraints (require path) ============
+    /// Values must be unique across all instances of a class
+    UniqueValueForClass(UniqueValueForClassConstraint<'a>),
+
     // ============ Logical Constraints (recursive) ============
     /// All of the given shapes must be satisfied
     And(AndConstraint<'a>),
@@ -220,8 +332,15 @@ pub enum Constraint<'a> {
     /// The given shape must not be satisfied
     Not(NotConstraint<'a>),
 
+    // ============ Node Expressions (SHACL-AF) ============
+    /// The node expression must evaluate to exactly `true` for each value node
+    Expression(ExpressionConstraint<'a>),
+
     /// Constraint backed by a SPARQL executable.
     Sparql(SparqlConstraint<'a>),
+
+    /// Constraint component validated by registered Rust code.
+    Custom(CustomConstraint<'a>),
 }
 
 impl<'a> Constraint<'a> {
@@ -237,8 +356,71 @@ impl<'a> Constraint<'a> {
                 | Constraint::LessThan(_)
                 | Constraint::LessThanOrEquals(_)
                 | Constraint::QualifiedValueShape(_)
+                | Constraint::UniqueValueForClass(_)
         )
     }
+
+    /// The component results of this constraint are reported with.
+    ///
+    /// A qualified value shape reports `sh:QualifiedMinCountConstraintComponent`
+    /// unless it only has a maximum.
+    pub fn component(&self) -> ConstraintComponent<'a> {
+        match self {
+            Constraint::Class(_) => ConstraintComponent::Class,
+            Constraint::Datatype(_) => ConstraintComponent::Datatype,
+            Constraint::NodeKind(_) => ConstraintComponent::NodeKind,
+            Constraint::MinCount(_) => ConstraintComponent::MinCount,
+            Constraint::MaxCount(_) => ConstraintComponent::MaxCount,
+            Constraint::MinExclusive(_) => ConstraintComponent::MinExclusive,
+            Constraint::MinInclusive(_) => ConstraintComponent::MinInclusive,
+            Constraint::MaxExclusive(_) => ConstraintComponent::MaxExclusive,
+            Constraint::MaxInclusive(_) => ConstraintComponent::MaxInclusive,
+            Constraint::MinLength(_) => ConstraintComponent::MinLength,
+            Constraint::MaxLength(_) => ConstraintComponent::MaxLength,
+            Constraint::Pattern(_) => ConstraintComponent::Pattern,
+            Constraint::TotalDigits(_) => {
+                ConstraintComponent::Other(shx::TOTAL_DIGITS_CONSTRAINT_COMPONENT)
+            }
+            Constraint::FractionDigits(_) => {
+                ConstraintComponent::Other(shx::FRACTION_DIGITS_CONSTRAINT_COMPONENT)
+            }
+            Constraint::RelativeTime(_) => {
+                ConstraintComponent::Other(shx::RELATIVE_TIME_CONSTRAINT_COMPONENT)
+            }
+            Constraint::NamespaceIn(_) => {
+                ConstraintComponent::Other(shx::NAMESPACE_IN_CONSTRAINT_COMPONENT)
+            }
+            Constraint::UriTemplate(_) => {
+                ConstraintComponent::Other(shx::URI_TEMPLATE_CONSTRAINT_COMPONENT)
+            }
+            Constraint::IdentifierFormat(_) => {
+                ConstraintComponent::Other(shx::IDENTIFIER_FORMAT_CONSTRAINT_COMPONENT)
+            }
+            Constraint::LanguageIn(_) => ConstraintComponent::LanguageIn,
+            Constraint::UniqueLang(_) => ConstraintComponent::UniqueLang,
+            Constraint::Equals(_) => ConstraintComponent::Equals,
+            Constraint::Disjoint(_) => ConstraintComponent::Disjoint,
+            Constraint::LessThan(_) => ConstraintComponent::LessThan,
+            Constraint::LessThanOrEquals(_) => ConstraintComponent::LessThanOrEquals,
+            Constraint::HasValue(_) => ConstraintComponent::HasValue,
+            Constraint::In(_) => ConstraintComponent::In,
+            Constraint::Node(_) => ConstraintComponent::Node,
+            Constraint::QualifiedValueShape(c) => match c.qualified_min_count {
+                None if c.qualified_max_count.is_some() => ConstraintComponent::QualifiedMaxCount,
+                _ => ConstraintComponent::QualifiedMinCount,
+            },
+            Constraint::UniqueValueForClass(_) => {
+                ConstraintComponent::Other(dash::UNIQUE_VALUE_FOR_CLASS_CONSTRAINT_COMPONENT)
+            }
+            Constraint::And(_) => ConstraintComponent::And,
+            Constraint::Or(_) => ConstraintComponent::Or,
+            Constraint::Xone(_) => ConstraintComponent::Xone,
+            Constraint::Not(_) => ConstraintComponent::Not,
+            Constraint::Expression(_) => ConstraintComponent::Expression,
+            Constraint::Sparql(c) => c.component(),
+            Constraint::Custom(c) => ConstraintComponent::Other(c.component),
+        }
+    }
 }
 
 impl Display for NodeKind {
@@ -254,6 +436,21 @@ impl Display for NodeKind {
     }
 }
 
+impl Display for RelativeTimeConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bounds: Vec<_> = [
+            self.min_days
+                .map(|days| format!("shx:minDaysFromNow {}", days)),
+            self.max_days
+                .map(|days| format!("shx:maxDaysFromNow {}", days)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        write!(f, "{}", bounds.join(" ; "))
+    }
+}
+
 impl Display for SparqlExecutable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -300,6 +497,16 @@ impl<'a> Display for SparqlConstraint<'a> {
     }
 }
 
+impl<'a> Display for CustomConstraint<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.component)?;
+        for (parameter, value) in &self.parameter_bindings {
+            write!(f, " {}={}", parameter, value)?;
+        }
+        Ok(())
+    }
+}
+
 impl<'a> Display for Constraint<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -340,6 +547,18 @@ impl<'a> Display for Constraint<'a> {
             }
             Constraint::UniqueLang(c) => write!(f, "sh:uniqueLang {}", c.0),
 
+            // Decimal Precision Constraints
+            Constraint::TotalDigits(c) => write!(f, "shx:totalDigits {}", c.0),
+            Constraint::FractionDigits(c) => write!(f, "shx:fractionDigits {}", c.0),
+
+            // Temporal Constraints
+            Constraint::RelativeTime(c) => write!(f, "{}", c),
+
+            // IRI Constraints
+            Constraint::NamespaceIn(c) => write!(f, "shx:namespaceIn ({})", c.0.join(" ")),
+            Constraint::UriTemplate(c) => write!(f, "shx:uriTemplate {}", c.0.join(", ")),
+            Constraint::IdentifierFormat(c) => write!(f, "shx:identifierFormat shx:{}", c.0),
+
             // Property Pair Constraints
             Constraint::Equals(c) => write!(f, "sh:equals {}", c.0),
             Constraint::Disjoint(c) => write!(f, "sh:disjoint {}", c.0),
@@ -387,6 +606,9 @@ impl<'a> Display for Constraint<'a> {
                 Ok(())
             }
 
+            // Key Constraints
+            Constraint::UniqueValueForClass(c) => write!(f, "dash:uniqueValueForClass {}", c.0),
+
             // Logical Constraints
             Constraint::And(c) => {
                 writeln!(f, "sh:and [")?;
@@ -422,9 +644,11 @@ impl<'a> Display for Constraint<'a> {
                 }
                 write!(f, "}}")
             }
+            Constraint::Expression(c) => write!(f, "sh:expression {}", c.0),
             Constraint::Sparql(c) => {
                 write!(f, "sh:sparql {}", c)
             }
+            Constraint::Custom(c) => write!(f, "component {}", c),
         }
     }
 }
@@ -0,0 +1,35 @@
+//! Dataset-level constraints.
+
+use std::fmt::{Display, Formatter};
+
+use oxigraph::model::NamedOrBlankNodeRef;
+
+use super::severity::Severity;
+
+/// A SPARQL SELECT check that runs once over the whole data graph.
+///
+/// Declared as an instance of `shx:DatasetConstraint` with `sh:select`. Every
+/// solution is reported against the synthetic focus node `shx:Dataset`, so
+/// aggregate conditions are written with `GROUP BY`/`HAVING`. Only
+/// `$shapesGraph` and `$currentShape` are pre-bound.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatasetConstraint<'a> {
+    /// Node declaring the constraint.
+    pub node: NamedOrBlankNodeRef<'a>,
+    /// The SELECT query.
+    pub select: String,
+    /// Prefixes from `sh:prefixes`.
+    pub prefixes: Vec<(String, String)>,
+    /// Message templates from `sh:message`.
+    pub messages: Vec<String>,
+    /// Result severity.
+    pub severity: Severity<'a>,
+    /// Whether the constraint is disabled with `sh:deactivated`.
+    pub deactivated: bool,
+}
+
+impl Display for DatasetConstraint<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DatasetConstraint({})", self.node)
+    }
+}
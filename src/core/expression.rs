@@ -0,0 +1,126 @@
+//! SHACL-AF node expressions.
+
+use std::{fmt::Display, sync::Arc};
+
+use oxigraph::model::TermRef;
+
+use crate::{
+    core::{path::Path, shape::Shape},
+    err::ShaclError,
+    utils,
+    validation::dataset::ValidationDataset,
+};
+
+/// A node expression, producing a list of nodes for a focus node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeExpression<'a> {
+    /// `sh:this`: the focus node itself.
+    FocusNode,
+    /// An IRI or literal standing for itself.
+    Constant(TermRef<'a>),
+    /// `[ sh:path ... ]`: the values of a path, starting from the nodes of
+    /// `sh:nodes` or else from the focus node.
+    Path {
+        path: Path<'a>,
+        nodes: Option<Box<NodeExpression<'a>>>,
+    },
+    /// `[ sh:filterShape ... ]`: the nodes of `sh:nodes`, or the focus node,
+    /// that conform to a shape.
+    FilterShape {
+        shape: Arc<Shape<'a>>,
+        nodes: Box<NodeExpression<'a>>,
+    },
+    /// `[ sh:union (...) ]`: the nodes of any member.
+    Union(Vec<NodeExpression<'a>>),
+    /// `[ sh:intersection (...) ]`: the nodes of every member.
+    Intersection(Vec<NodeExpression<'a>>),
+}
+
+impl<'a> NodeExpression<'a> {
+    /// Evaluates the expression against the data graph, without duplicates.
+    /// Fails when a path goes beyond the configured path limits.
+    pub fn evaluate(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+    ) -> Result<Vec<TermRef<'a>>, ShaclError> {
+        Ok(match self {
+            NodeExpression::FocusNode => vec![focus_node],
+            NodeExpression::Constant(term) => vec![*term],
+            NodeExpression::Path { path, nodes } => {
+                let inputs = match nodes {
+                    Some(nodes) => nodes.evaluate(validation_dataset, focus_node)?,
+                    None => vec![focus_node],
+                };
+                let mut values = Vec::new();
+                for input in inputs {
+                    let Some(input) = utils::term_to_named_or_blank(input) else {
+                        continue;
+                    };
+                    for value in validation_dataset.resolve_path(path, input)? {
+                        if !values.contains(&value) {
+                            values.push(value);
+                        }
+                    }
+                }
+                values
+            }
+            NodeExpression::FilterShape { shape, nodes } => nodes
+                .evaluate(validation_dataset, focus_node)?
+                .into_iter()
+                .filter(|&node| shape.validate_node(validation_dataset, node))
+                .collect(),
+            NodeExpression::Union(members) => {
+                let mut values = Vec::new();
+                for member in members {
+                    for value in member.evaluate(validation_dataset, focus_node)? {
+                        if !values.contains(&value) {
+                            values.push(value);
+                        }
+                    }
+                }
+                values
+            }
+            NodeExpression::Intersection(members) => {
+                let mut members = members.iter();
+                let Some(first) = members.next() else {
+                    return Ok(Vec::new());
+                };
+                let mut values = first.evaluate(validation_dataset, focus_node)?;
+                for member in members {
+                    let nodes = member.evaluate(validation_dataset, focus_node)?;
+                    values.retain(|value| nodes.contains(value));
+                }
+                values
+            }
+        })
+    }
+}
+
+impl Display for NodeExpression<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let list = |members: &[NodeExpression<'_>]| {
+            members
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        match self {
+            NodeExpression::FocusNode => write!(f, "sh:this"),
+            NodeExpression::Constant(term) => write!(f, "{}", term),
+            NodeExpression::Path { path, nodes: None } => write!(f, "[sh:path {}]", path),
+            NodeExpression::Path {
+                path,
+                nodes: Some(nodes),
+            } => write!(f, "[sh:path {} ; sh:nodes {}]", path, nodes),
+            NodeExpression::FilterShape { shape, nodes } => {
+                write!(f, "[sh:filterShape {} ; sh:nodes {}]", shape.node, nodes)
+            }
+            NodeExpression::Union(members) => write!(f, "[sh:union ({})]", list(members)),
+            NodeExpression::Intersection(members) => {
+                write!(f, "[sh:intersection ({})]", list(members))
+            }
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Well-known identifier formats with checksum verification.
+
+use std::fmt::{Display, Formatter};
+
+use oxigraph::model::NamedNodeRef;
+
+use crate::vocab::shx;
+
+/// A format of identifiers that is checked beyond what a regex can express.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdentifierFormat {
+    Uuid,
+    Isbn,
+    Orcid,
+    Doi,
+}
+
+impl IdentifierFormat {
+    pub const ALL: [IdentifierFormat; 4] = [
+        IdentifierFormat::Uuid,
+        IdentifierFormat::Isbn,
+        IdentifierFormat::Orcid,
+        IdentifierFormat::Doi,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            IdentifierFormat::Uuid => "UUID",
+            IdentifierFormat::Isbn => "ISBN",
+            IdentifierFormat::Orcid => "ORCID",
+            IdentifierFormat::Doi => "DOI",
+        }
+    }
+
+    /// The `shx:` term selecting this format.
+    pub fn iri(self) -> NamedNodeRef<'static> {
+        match self {
+            IdentifierFormat::Uuid => shx::UUID,
+            IdentifierFormat::Isbn => shx::ISBN,
+            IdentifierFormat::Orcid => shx::ORCID,
+            IdentifierFormat::Doi => shx::DOI,
+        }
+    }
+
+    pub fn from_iri(iri: NamedNodeRef<'_>) -> Option<Self> {
+        Self::ALL.into_iter().find(|format| format.iri() == iri)
+    }
+
+    /// Checks `value`, returning why it is not a valid identifier of this format.
+    pub fn check(self, value: &str) -> Result<(), &'static str> {
+        match self {
+            IdentifierFormat::Uuid => check_uuid(value),
+            IdentifierFormat::Isbn => check_isbn(value),
+            IdentifierFormat::Orcid => check_orcid(value),
+            IdentifierFormat::Doi => check_doi(value),
+        }
+    }
+}
+
+impl Display for IdentifierFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+fn strip_prefix_ignore_case<'v>(value: &'v str, prefix: &str) -> Option<&'v str> {
+    let head = value.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &value[prefix.len()..])
+}
+
+fn check_uuid(value: &str) -> Result<(), &'static str> {
+    let value = strip_prefix_ignore_case(value, "urn:uuid:").unwrap_or(value);
+    let groups: Vec<&str> = value.split('-').collect();
+    let well_formed = groups.iter().map(|group| group.len()).eq([8, 4, 4, 4, 12])
+        && groups
+            .iter()
+            .all(|group| group.chars().all(|c| c.is_ascii_hexdigit()));
+    if well_formed {
+        Ok(())
+    } else {
+        Err("expected 32 hexadecimal digits in groups of 8-4-4-4-12")
+    }
+}
+
+fn check_isbn(value: &str) -> Result<(), &'static str> {
+    let value = strip_prefix_ignore_case(value, "urn:isbn:").unwrap_or(value);
+    let chars: Vec<char> = value.chars().filter(|c| !matches!(c, '-' | ' ')).collect();
+
+    let digits: Option<Vec<u32>> = chars
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| match c {
+            'X' | 'x' if chars.len() == 10 && i == 9 => Some(10),
+            c => c.to_digit(10),
+        })
+        .collect();
+    let Some(digits) = digits else {
+        return Err("expected 10 or 13 digits");
+    };
+
+    let valid = match digits.len() {
+        10 => {
+            digits
+                .iter()
+                .zip((1..=10).rev())
+                .map(|(d, weight)| d * weight)
+                .sum::<u32>()
+                % 11
+                == 0
+        }
+        13 => {
+            if !chars.starts_with(&['9', '7', '8']) && !chars.starts_with(&['9', '7', '9']) {
+                return Err("ISBN-13 must start with 978 or 979");
+            }
+            digits
+                .iter()
+                .zip([1, 3].into_iter().cycle())
+                .map(|(d, weight)| d * weight)
+                .sum::<u32>()
+                % 10
+                == 0
+        }
+        _ => return Err("expected 10 or 13 digits"),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err("check digit does not match")
+    }
+}
+
+fn check_orcid(value: &str) -> Result<(), &'static str> {
+    let value = ["https://orcid.org/", "http://orcid.org/"]
+        .iter()
+        .find_map(|prefix| strip_prefix_ignore_case(value, prefix))
+        .unwrap_or(value);
+    let groups: Vec<&str> = value.split('-').collect();
+    if !value.is_ascii() || !groups.iter().map(|group| group.len()).eq([4, 4, 4, 4]) {
+        return Err("expected 16 characters in groups of 4-4-4-4");
+    }
+
+    let chars: Vec<char> = groups.concat().chars().collect();
+    let Some(digits) = chars[..15]
+        .iter()
+        .map(|c| c.to_digit(10))
+        .collect::<Option<Vec<u32>>>()
+    else {
+        return Err("expected digits");
+    };
+
+    // ISO 7064 MOD 11-2
+    let total = digits.iter().fold(0, |total, d| (total + d) * 2);
+    let check = (12 - total % 11) % 11;
+    // A check value of 10 is written as X
+    let expected = char::from_digit(check, 10).unwrap_or('X');
+    if chars[15].to_ascii_uppercase() == expected {
+        Ok(())
+    } else {
+        Err("check digit does not match")
+    }
+}
+
+fn check_doi(value: &str) -> Result<(), &'static str> {
+    let value = [
+        "https://doi.org/",
+        "http://doi.org/",
+        "https://dx.doi.org/",
+        "doi:",
+    ]
+    .iter()
+    .find_map(|prefix| strip_prefix_ignore_case(value, prefix))
+    .unwrap_or(value);
+    let Some((prefix, suffix)) = value.split_once('/') else {
+        return Err("expected a prefix and suffix separated by '/'");
+    };
+
+    let registrant = prefix.strip_prefix("10.").unwrap_or("");
+    let well_formed = !registrant.is_empty()
+        && registrant
+            .split('.')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+        && registrant
+            .split('.')
+            .next()
+            .is_some_and(|part| part.len() >= 4)
+        && !suffix.is_empty()
+        && !suffix.chars().any(char::is_whitespace);
+    if well_formed {
+        Ok(())
+    } else {
+        Err("expected the form 10.<registrant>/<suffix>")
+    }
+}
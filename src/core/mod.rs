@@ -3,13 +3,25 @@
 //! This module contains the fundamental types used to represent SHACL shapes,
 //! constraints, paths, and targets.
 
+pub mod component;
 pub mod constraints;
+pub mod dataset_constraint;
+pub mod expression;
+pub mod identifier;
 pub mod path;
+pub mod severity;
 pub mod shape;
 pub mod target;
+pub mod transform;
 
 // Re-export commonly used types
+pub use component::ConstraintComponent;
 pub use constraints::{Constraint, NodeKind};
+pub use dataset_constraint::DatasetConstraint;
+pub use expression::NodeExpression;
+pub use identifier::IdentifierFormat;
 pub use path::{Path, PathElement};
-pub use shape::{ClosedConstraint, Shape, ShapeReference, ShapesInfo};
+pub use severity::Severity;
+pub use shape::{ClosedConstraint, Documentation, Shape, ShapeReference, ShapesInfo};
 pub use target::Target;
+pub use transform::ValueTransform;
@@ -1,7 +1,14 @@
 use std::{collections::HashSet, fmt::Display};
 
 use log::debug;
-use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
+use oxigraph::model::{
+    BlankNode, Graph, NamedNode, NamedNodeRef, NamedOrBlankNode, NamedOrBlankNodeRef, Term,
+    TermRef, Triple,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{err::ShaclError, utils, validation::config::PathLimits, vocab::sh};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PathElement<'a> {
@@ -11,6 +18,8 @@ pub enum PathElement<'a> {
     OneOrMore(Box<PathElement<'a>>),
     ZeroOrOne(Box<PathElement<'a>>),
     Alternative(Vec<PathElement<'a>>),
+    /// A nested sequence, e.g. the `a / b` of `(a / b)*`.
+    Sequence(Vec<PathElement<'a>>),
 }
 
 /// SHACL Path
@@ -85,113 +94,441 @@ impl<'a> Path<'a> {
         self.source
     }
 
+    /// The path with nested modifiers collapsed, nested sequences inlined and
+    /// alternatives flattened, sorted and deduplicated.
+    pub fn normalized(&self) -> Path<'a> {
+        let mut path = Vec::new();
+        for element in &self.path {
+            match element.normalized() {
+                PathElement::Sequence(steps) => path.extend(steps),
+                element => path.push(element),
+            }
+        }
+        Path {
+            source: self.source,
+            path,
+        }
+    }
+
+    /// Whether both paths reach the same nodes by construction, e.g. `(a | b)`
+    /// and `(b | a)`, or `(a*)+` and `a*`. The path sources are ignored.
+    pub fn equivalent(&self, other: &Path<'_>) -> bool {
+        self.normalized().path == other.normalized().path
+    }
+
+    /// The normalized path in SPARQL property path syntax.
+    ///
+    /// ```
+    /// use shacl_rust::{Path, PathElement};
+    /// use oxigraph::model::NamedNodeRef;
+    ///
+    /// let a = NamedNodeRef::new("http://example.org/a").unwrap();
+    /// let b = NamedNodeRef::new("http://example.org/b").unwrap();
+    /// let path = Path::new()
+    ///     .add_element(PathElement::Inverse(a))
+    ///     .add_element(PathElement::OneOrMore(Box::new(PathElement::ZeroOrMore(Box::new(
+    ///         PathElement::Alternative(vec![PathElement::Iri(b), PathElement::Iri(a)]),
+    ///     )))));
+    /// assert_eq!(
+    ///     path.to_sparql_property_path(),
+    ///     "^<http://example.org/a>/(<http://example.org/a>|<http://example.org/b>)*"
+    /// );
+    /// ```
+    pub fn to_sparql_property_path(&self) -> String {
+        self.path
+            .iter()
+            .map(|element| element.normalized().to_sparql())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Writes the path into `graph` as SHACL path nodes and returns the path term.
+    pub fn to_graph_term(&self, graph: &mut Graph) -> Term {
+        let mut elements: Vec<Term> = self
+            .path
+            .iter()
+            .map(|element| element.to_graph_term(graph))
+            .collect();
+        if elements.len() == 1 {
+            elements.remove(0)
+        } else {
+            crate::utils::insert_rdf_list(graph, elements)
+        }
+    }
+
     /// Resolves the path for a given node in the graph, returning all reachable nodes.
     pub fn resolve_path_for_given_node(
         &self,
         graph: &'a oxigraph::model::Graph,
         node: &oxigraph::model::NamedOrBlankNodeRef<'a>,
     ) -> Vec<oxigraph::model::TermRef<'a>> {
+        // Without limits the walk cannot fail.
+        self.resolve_path_with_limits(graph, node, &PathLimits::default())
+            .unwrap_or_default()
+    }
+
+    /// Resolves the path like [`Self::resolve_path_for_given_node`], failing
+    /// once a `*` or `+` step goes beyond `limits`.
+    pub fn resolve_path_with_limits(
+        &self,
+        graph: &'a Graph,
+        node: &NamedOrBlankNodeRef<'a>,
+        limits: &PathLimits,
+    ) -> Result<Vec<TermRef<'a>>, ShaclError> {
         debug!("Resolving path for node {:?} with path: {}", node, self);
         let mut current_nodes: Vec<TermRef<'a>> = vec![(*node).into()];
 
         // Apply each path element in sequence
         for element in &self.path {
-            current_nodes = self.resolve_element(graph, element, &current_nodes);
+            current_nodes = self.resolve_element(graph, element, &current_nodes, limits)?;
         }
         debug!("Resolved nodes: {:?}", current_nodes);
+        Ok(current_nodes)
+    }
+
+    /// Resolves the path backwards from `value`, returning all nodes that reach it.
+    pub fn resolve_inverse_for_given_node(
+        &self,
+        graph: &'a oxigraph::model::Graph,
+        value: TermRef<'a>,
+    ) -> Vec<TermRef<'a>> {
+        let mut current_nodes = vec![value];
+        for element in self.path.iter().rev() {
+            current_nodes = self
+                .resolve_element(
+                    graph,
+                    &element.inverse(),
+                    &current_nodes,
+                    &PathLimits::default(),
+                )
+                .unwrap_or_default();
+        }
         current_nodes
     }
 
+    /// Whether the path has a `*` or `+` step, the steps [`PathLimits`] bound.
+    pub(crate) fn has_repetition(&self) -> bool {
+        self.path.iter().any(PathElement::has_repetition)
+    }
+
     /// Resolves a single path element for a set of nodes
     fn resolve_element(
         &self,
         graph: &'a oxigraph::model::Graph,
         element: &PathElement<'a>,
         nodes: &[TermRef<'a>],
-    ) -> Vec<TermRef<'a>> {
+        limits: &PathLimits,
+    ) -> Result<Vec<TermRef<'a>>, ShaclError> {
         let mut results = Vec::new();
-        let subjects: Vec<NamedOrBlankNodeRef<'a>> = nodes
-            .iter()
-            .filter_map(|node| match node {
-                TermRef::NamedNode(n) => Some(NamedOrBlankNodeRef::from(*n)),
-                TermRef::BlankNode(b) => Some(NamedOrBlankNodeRef::from(*b)),
-                TermRef::Literal(_) => None,
-            })
-            .collect();
-        for subject in subjects {
+        for &node in nodes {
             match element {
                 PathElement::Iri(predicate) => {
-                    for triple in graph {
-                        if triple.subject == subject && triple.predicate == (*predicate) {
-                            results.push(triple.object);
-                        }
+                    // Literals have no outgoing triples
+                    if let Some(subject) = utils::term_to_named_or_blank(node) {
+                        results.extend(graph.objects_for_subject_predicate(subject, *predicate));
                     }
                 }
                 PathElement::Inverse(predicate) => {
-                    // Inverse property: find all subjects where node is object
-                    for triple in graph {
-                        if triple.object == subject.into() && triple.predicate == (*predicate) {
-                            results.push(triple.subject.into());
-                        }
-                    }
+                    // Inverse property: find all subjects where node is object, literals included
+                    results.extend(
+                        graph
+                            .subjects_for_predicate_object(*predicate, node)
+                            .map(TermRef::from),
+                    );
                 }
-                PathElement::ZeroOrMore(path_element) => {
-                    // Transitive closure including the starting node (Kleene star)
-                    results.push(subject.into());
-                    let mut visited: HashSet<TermRef<'a>> = HashSet::new();
-                    visited.insert(subject.into());
-                    let mut to_visit: Vec<TermRef<'a>> = vec![subject.into()];
-
-                    while let Some(current) = to_visit.pop() {
-                        // Get next nodes by applying the path element
-                        let next_nodes = self.resolve_element(graph, path_element, &[current]);
-                        for next in next_nodes {
-                            if visited.insert(next) {
-                                results.push(next);
-                                to_visit.push(next);
-                            }
-                        }
+                PathElement::ZeroOrMore(path_element) | PathElement::OneOrMore(path_element) => {
+                    // Transitive closure, with the starting node for the Kleene star
+                    if matches!(element, PathElement::ZeroOrMore(_)) {
+                        results.push(node);
                     }
-                }
-                PathElement::OneOrMore(path_element) => {
-                    // Transitive closure, not including the starting node (Kleene plus)
-                    let mut visited: HashSet<TermRef<'a>> = HashSet::new();
-                    visited.insert(subject.into());
-                    let mut to_visit: Vec<TermRef<'a>> = vec![subject.into()];
-
-                    while let Some(current) = to_visit.pop() {
-                        // Get next nodes by applying the path element
-                        let next_nodes = self.resolve_element(graph, path_element, &[current]);
-                        for next in next_nodes {
+                    let mut visited: HashSet<TermRef<'a>> = HashSet::from([node]);
+                    let mut frontier: Vec<TermRef<'a>> = vec![node];
+                    let mut depth = 0;
+
+                    while !frontier.is_empty() {
+                        let mut next_frontier = Vec::new();
+                        for next in self.resolve_element(graph, path_element, &frontier, limits)? {
                             if visited.insert(next) {
                                 results.push(next);
-                                to_visit.push(next);
+                                next_frontier.push(next);
                             }
                         }
+                        if next_frontier.is_empty() {
+                            break;
+                        }
+
+                        depth += 1;
+                        if let Some(max) = limits.max_depth.filter(|&max| depth > max) {
+                            return Err(ShaclError::PathLimit(format!(
+                                "Path step {} from {} repeats more than {} times",
+                                element, node, max
+                            )));
+                        }
+                        if let Some(max) =
+                            limits.max_visited_nodes.filter(|&max| visited.len() > max)
+                        {
+                            return Err(ShaclError::PathLimit(format!(
+                                "Path step {} from {} reaches more than {} nodes",
+                                element, node, max
+                            )));
+                        }
+                        frontier = next_frontier;
                     }
                 }
                 PathElement::ZeroOrOne(path_element) => {
                     // Optional path: include the node itself and direct neighbors
-                    results.push(subject.into());
+                    results.push(node);
 
-                    let next_nodes = self.resolve_element(graph, path_element, &[subject.into()]);
+                    let next_nodes = self.resolve_element(graph, path_element, &[node], limits)?;
                     results.extend(next_nodes);
                 }
                 PathElement::Alternative(alternatives) => {
                     // Apply all alternatives and merge results
                     for alt in alternatives {
-                        results.extend(self.resolve_element(graph, alt, &[subject.into()]));
+                        results.extend(self.resolve_element(graph, alt, &[node], limits)?);
                     }
                 }
+                PathElement::Sequence(steps) => {
+                    let mut current = vec![node];
+                    for step in steps {
+                        current = self.resolve_element(graph, step, &current, limits)?;
+                    }
+                    results.extend(current);
+                }
             }
         }
 
         // Remove duplicates
         let mut unique_results = HashSet::new();
-        results
+        Ok(results
             .into_iter()
             .filter(|r| unique_results.insert(*r))
-            .collect()
+            .collect())
+    }
+}
+
+/// A [`Path`] that owns its IRIs, see [`Path::into_owned`].
+///
+/// Serializes with IRIs in their N-Triples form.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedPath {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "utils::serde_rdf::option"
+    )]
+    source: Option<NamedOrBlankNode>,
+    path: Vec<OwnedPathElement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum OwnedPathElement {
+    Iri(#[serde(with = "utils::serde_rdf")] NamedNode),
+    Inverse(#[serde(with = "utils::serde_rdf")] NamedNode),
+    ZeroOrMore(Box<OwnedPathElement>),
+    OneOrMore(Box<OwnedPathElement>),
+    ZeroOrOne(Box<OwnedPathElement>),
+    Alternative(Vec<OwnedPathElement>),
+    Sequence(Vec<OwnedPathElement>),
+}
+
+impl Path<'_> {
+    pub fn into_owned(self) -> OwnedPath {
+        OwnedPath {
+            source: self.source.map(NamedOrBlankNodeRef::into_owned),
+            path: self.path.iter().map(OwnedPathElement::from).collect(),
+        }
+    }
+}
+
+impl OwnedPath {
+    /// Borrows the path back.
+    pub fn as_path(&self) -> Path<'_> {
+        Path {
+            source: self.source.as_ref().map(NamedOrBlankNode::as_ref),
+            path: self.path.iter().map(OwnedPathElement::as_element).collect(),
+        }
+    }
+}
+
+impl OwnedPathElement {
+    fn as_element(&self) -> PathElement<'_> {
+        match self {
+            OwnedPathElement::Iri(iri) => PathElement::Iri(iri.as_ref()),
+            OwnedPathElement::Inverse(iri) => PathElement::Inverse(iri.as_ref()),
+            OwnedPathElement::ZeroOrMore(e) => PathElement::ZeroOrMore(Box::new(e.as_element())),
+            OwnedPathElement::OneOrMore(e) => PathElement::OneOrMore(Box::new(e.as_element())),
+            OwnedPathElement::ZeroOrOne(e) => PathElement::ZeroOrOne(Box::new(e.as_element())),
+            OwnedPathElement::Alternative(alts) => {
+                PathElement::Alternative(alts.iter().map(OwnedPathElement::as_element).collect())
+            }
+            OwnedPathElement::Sequence(steps) => {
+                PathElement::Sequence(steps.iter().map(OwnedPathElement::as_element).collect())
+            }
+        }
+    }
+}
+
+impl From<&PathElement<'_>> for OwnedPathElement {
+    fn from(element: &PathElement<'_>) -> Self {
+        match element {
+            PathElement::Iri(iri) => OwnedPathElement::Iri(iri.into_owned()),
+            PathElement::Inverse(iri) => OwnedPathElement::Inverse(iri.into_owned()),
+            PathElement::ZeroOrMore(e) => OwnedPathElement::ZeroOrMore(Box::new((&**e).into())),
+            PathElement::OneOrMore(e) => OwnedPathElement::OneOrMore(Box::new((&**e).into())),
+            PathElement::ZeroOrOne(e) => OwnedPathElement::ZeroOrOne(Box::new((&**e).into())),
+            PathElement::Alternative(alts) => {
+                OwnedPathElement::Alternative(alts.iter().map(OwnedPathElement::from).collect())
+            }
+            PathElement::Sequence(steps) => {
+                OwnedPathElement::Sequence(steps.iter().map(OwnedPathElement::from).collect())
+            }
+        }
+    }
+}
+
+impl<'a> PathElement<'a> {
+    /// The element with nested modifiers collapsed, nested sequences inlined
+    /// and alternatives flattened, sorted and deduplicated.
+    pub fn normalized(&self) -> PathElement<'a> {
+        match self {
+            PathElement::Iri(_) | PathElement::Inverse(_) => self.clone(),
+            PathElement::ZeroOrMore(inner) => match inner.normalized() {
+                PathElement::ZeroOrMore(inner)
+                | PathElement::OneOrMore(inner)
+                | PathElement::ZeroOrOne(inner) => PathElement::ZeroOrMore(inner),
+                inner => PathElement::ZeroOrMore(Box::new(inner)),
+            },
+            PathElement::OneOrMore(inner) => match inner.normalized() {
+                PathElement::OneOrMore(inner) => PathElement::OneOrMore(inner),
+                PathElement::ZeroOrMore(inner) | PathElement::ZeroOrOne(inner) => {
+                    PathElement::ZeroOrMore(inner)
+                }
+                inner => PathElement::OneOrMore(Box::new(inner)),
+            },
+            PathElement::ZeroOrOne(inner) => match inner.normalized() {
+                PathElement::ZeroOrOne(inner) => PathElement::ZeroOrOne(inner),
+                PathElement::ZeroOrMore(inner) | PathElement::OneOrMore(inner) => {
+                    PathElement::ZeroOrMore(inner)
+                }
+                inner => PathElement::ZeroOrOne(Box::new(inner)),
+            },
+            PathElement::Alternative(alternatives) => {
+                let mut flattened = Vec::new();
+                for alternative in alternatives {
+                    match alternative.normalized() {
+                        PathElement::Alternative(nested) => flattened.extend(nested),
+                        alternative => flattened.push(alternative),
+                    }
+                }
+                flattened.sort_by_cached_key(ToString::to_string);
+                flattened.dedup();
+                if flattened.len() == 1 {
+                    flattened.remove(0)
+                } else {
+                    PathElement::Alternative(flattened)
+                }
+            }
+            PathElement::Sequence(steps) => {
+                let mut flattened = Vec::new();
+                for step in steps {
+                    match step.normalized() {
+                        PathElement::Sequence(nested) => flattened.extend(nested),
+                        step => flattened.push(step),
+                    }
+                }
+                if flattened.len() == 1 {
+                    flattened.remove(0)
+                } else {
+                    PathElement::Sequence(flattened)
+                }
+            }
+        }
+    }
+
+    fn has_repetition(&self) -> bool {
+        match self {
+            PathElement::Iri(_) | PathElement::Inverse(_) => false,
+            PathElement::ZeroOrMore(_) | PathElement::OneOrMore(_) => true,
+            PathElement::ZeroOrOne(inner) => inner.has_repetition(),
+            PathElement::Alternative(elements) | PathElement::Sequence(elements) => {
+                elements.iter().any(PathElement::has_repetition)
+            }
+        }
+    }
+
+    /// The element walked from its end to its start.
+    fn inverse(&self) -> PathElement<'a> {
+        match self {
+            PathElement::Iri(iri) => PathElement::Inverse(*iri),
+            PathElement::Inverse(iri) => PathElement::Iri(*iri),
+            PathElement::ZeroOrMore(inner) => PathElement::ZeroOrMore(Box::new(inner.inverse())),
+            PathElement::OneOrMore(inner) => PathElement::OneOrMore(Box::new(inner.inverse())),
+            PathElement::ZeroOrOne(inner) => PathElement::ZeroOrOne(Box::new(inner.inverse())),
+            PathElement::Alternative(alternatives) => {
+                PathElement::Alternative(alternatives.iter().map(PathElement::inverse).collect())
+            }
+            PathElement::Sequence(steps) => {
+                PathElement::Sequence(steps.iter().rev().map(PathElement::inverse).collect())
+            }
+        }
+    }
+
+    fn to_sparql(&self) -> String {
+        let group = |inner: &PathElement<'_>| match inner {
+            PathElement::Iri(_) | PathElement::Alternative(_) | PathElement::Sequence(_) => {
+                inner.to_sparql()
+            }
+            _ => format!("({})", inner.to_sparql()),
+        };
+        match self {
+            PathElement::Iri(iri) => iri.to_string(),
+            PathElement::Inverse(iri) => format!("^{}", iri),
+            PathElement::ZeroOrMore(inner) => format!("{}*", group(inner)),
+            PathElement::OneOrMore(inner) => format!("{}+", group(inner)),
+            PathElement::ZeroOrOne(inner) => format!("{}?", group(inner)),
+            PathElement::Alternative(alternatives) => format!(
+                "({})",
+                alternatives
+                    .iter()
+                    .map(PathElement::to_sparql)
+                    .collect::<Vec<_>>()
+                    .join("|")
+            ),
+            PathElement::Sequence(steps) => format!(
+                "({})",
+                steps
+                    .iter()
+                    .map(PathElement::to_sparql)
+                    .collect::<Vec<_>>()
+                    .join("/")
+            ),
+        }
+    }
+
+    fn to_graph_term(&self, graph: &mut Graph) -> Term {
+        let (predicate, object) = match self {
+            PathElement::Iri(iri) => return NamedNode::from(*iri).into(),
+            PathElement::Inverse(iri) => (sh::INVERSE_PATH, NamedNode::from(*iri).into()),
+            PathElement::ZeroOrMore(e) => (sh::ZERO_OR_MORE_PATH, e.to_graph_term(graph)),
+            PathElement::OneOrMore(e) => (sh::ONE_OR_MORE_PATH, e.to_graph_term(graph)),
+            PathElement::ZeroOrOne(e) => (sh::ZERO_OR_ONE_PATH, e.to_graph_term(graph)),
+            PathElement::Alternative(alts) => {
+                let alts = alts.iter().map(|alt| alt.to_graph_term(graph)).collect();
+                (
+                    sh::ALTERNATIVE_PATH,
+                    crate::utils::insert_rdf_list(graph, alts),
+                )
+            }
+            PathElement::Sequence(steps) => {
+                let steps = steps.iter().map(|step| step.to_graph_term(graph)).collect();
+                return crate::utils::insert_rdf_list(graph, steps);
+            }
+        };
+        let node = NamedOrBlankNode::from(BlankNode::default());
+        graph.insert(&Triple::new(node.clone(), predicate, object));
+        node.into()
     }
 }
 
@@ -207,6 +544,10 @@ impl Display for PathElement<'_> {
                 let alt_strs: Vec<String> = alts.iter().map(|alt| format!("{}", alt)).collect();
                 write!(f, "({})", alt_strs.join(" | "))
             }
+            PathElement::Sequence(steps) => {
+                let step_strs: Vec<String> = steps.iter().map(|step| format!("{}", step)).collect();
+                write!(f, "({})", step_strs.join(" / "))
+            }
         }
     }
 }
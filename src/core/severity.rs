@@ -0,0 +1,79 @@
+//! Severities of shapes and validation results.
+
+use std::{
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+use oxigraph::model::NamedNodeRef;
+
+use crate::vocab::sh;
+
+/// A result severity, with the SHACL severities as variants.
+///
+/// [`Severity::from_iri`] maps the SHACL severities to their variants. Values
+/// compare and hash by IRI, so a [`Severity::Other`] holding a SHACL severity
+/// still equals its variant.
+#[derive(Debug, Clone, Copy)]
+pub enum Severity<'a> {
+    Violation,
+    Warning,
+    Info,
+    /// A severity defined outside SHACL.
+    Other(NamedNodeRef<'a>),
+}
+
+impl<'a> Severity<'a> {
+    pub const ALL: [Severity<'static>; 3] =
+        [Severity::Violation, Severity::Warning, Severity::Info];
+
+    pub fn iri(self) -> NamedNodeRef<'a> {
+        match self {
+            Severity::Violation => sh::VIOLATION,
+            Severity::Warning => sh::WARNING,
+            Severity::Info => sh::INFO,
+            Severity::Other(iri) => iri,
+        }
+    }
+
+    pub fn from_iri(iri: NamedNodeRef<'a>) -> Self {
+        match iri.as_str().strip_prefix(sh::SHACL_NAMESPACE) {
+            Some("Violation") => Severity::Violation,
+            Some("Warning") => Severity::Warning,
+            Some("Info") => Severity::Info,
+            _ => Severity::Other(iri),
+        }
+    }
+}
+
+impl PartialEq for Severity<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iri() == other.iri()
+    }
+}
+
+impl Eq for Severity<'_> {}
+
+impl Hash for Severity<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.iri().hash(state)
+    }
+}
+
+impl<'a> From<NamedNodeRef<'a>> for Severity<'a> {
+    fn from(iri: NamedNodeRef<'a>) -> Self {
+        Severity::from_iri(iri)
+    }
+}
+
+impl PartialEq<NamedNodeRef<'_>> for Severity<'_> {
+    fn eq(&self, other: &NamedNodeRef<'_>) -> bool {
+        self.iri() == *other
+    }
+}
+
+impl Display for Severity<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.iri().fmt(f)
+    }
+}
@@ -1,11 +1,39 @@
 use std::{
     collections::HashSet,
     fmt::{Display, Formatter},
+    sync::Arc,
 };
 
 use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
-use super::{constraints::Constraint, path::Path, target::Target};
+use super::{
+    component::ConstraintComponent, constraints::Constraint, path::Path, severity::Severity,
+    target::Target, transform::ValueTransform,
+};
+
+/// Remediation guidance authored in the shapes graph for a shape or a
+/// constraint component.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Documentation {
+    /// `skos:definition`, or else `rdfs:comment`.
+    pub help_text: Option<String>,
+    /// `rdfs:seeAlso`.
+    pub documentation_url: Option<String>,
+}
+
+impl Documentation {
+    pub fn is_empty(&self) -> bool {
+        self.help_text.is_none() && self.documentation_url.is_none()
+    }
+
+    /// Fills the fields missing here from `fallback`.
+    pub fn or(self, fallback: Documentation) -> Documentation {
+        Documentation {
+            help_text: self.help_text.or(fallback.help_text),
+            documentation_url: self.documentation_url.or(fallback.documentation_url),
+        }
+    }
+}
 
 /// Reference to another shape, inline or by node.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -50,8 +78,23 @@ pub struct Shape<'a> {
     /// Optional messages.
     pub message: HashSet<String>,
 
+    /// Profile requirement identifiers from `shx:requirement`.
+    pub requirements: Vec<String>,
+
+    /// Guidance for results of the shape.
+    pub documentation: Documentation,
+
+    /// Guidance declared on the custom constraint components of the shape.
+    pub component_documentation: Vec<(NamedNodeRef<'a>, Documentation)>,
+
+    /// Transformations from `shx:valueTransform`, applied before facet checks.
+    pub value_transforms: Vec<ValueTransform>,
+
+    /// QUDT unit of the numeric range facets, from `shx:unit`.
+    pub unit: Option<NamedNodeRef<'a>>,
+
     /// Result severity.
-    pub severity: NamedNodeRef<'a>,
+    pub severity: Severity<'a>,
 
     /// Attached constraints.
     pub constraints: Vec<Constraint<'a>>,
@@ -66,13 +109,13 @@ pub struct Shape<'a> {
 }
 
 pub struct ShapesInfo<'a> {
-    shapes: &'a [Shape<'a>],
+    shapes: &'a [Arc<Shape<'a>>],
     graph_len: usize,
     detailed: bool,
 }
 
 impl<'a> ShapesInfo<'a> {
-    pub fn new(shapes: &'a [Shape<'a>], graph_len: usize, detailed: bool) -> Self {
+    pub fn new(shapes: &'a [Arc<Shape<'a>>], graph_len: usize, detailed: bool) -> Self {
         ShapesInfo {
             shapes,
             graph_len,
@@ -82,7 +125,7 @@ impl<'a> ShapesInfo<'a> {
 }
 
 impl<'a> Shape<'a> {
-    pub fn node_shape(node: NamedOrBlankNodeRef<'a>, severity: NamedNodeRef<'a>) -> Self {
+    pub fn node_shape(node: NamedOrBlankNodeRef<'a>, severity: Severity<'a>) -> Self {
         Shape {
             node,
             name: None,
@@ -91,6 +134,11 @@ impl<'a> Shape<'a> {
             targets: HashSet::new(),
             deactivated: false,
             message: HashSet::new(),
+            requirements: Vec::new(),
+            documentation: Documentation::default(),
+            component_documentation: Vec::new(),
+            value_transforms: Vec::new(),
+            unit: None,
             severity,
             constraints: Vec::new(),
             closed: None,
@@ -102,7 +150,7 @@ impl<'a> Shape<'a> {
     pub fn property_shape(
         node: NamedOrBlankNodeRef<'a>,
         path: Path<'a>,
-        severity: NamedNodeRef<'a>,
+        severity: Severity<'a>,
     ) -> Self {
         Shape {
             node,
@@ -112,6 +160,11 @@ impl<'a> Shape<'a> {
             targets: HashSet::new(),
             deactivated: false,
             message: HashSet::new(),
+            requirements: Vec::new(),
+            documentation: Documentation::default(),
+            component_documentation: Vec::new(),
+            value_transforms: Vec::new(),
+            unit: None,
             severity,
             constraints: Vec::new(),
             closed: None,
@@ -171,7 +224,61 @@ impl<'a> Shape<'a> {
         self
     }
 
-    pub fn with_severity(mut self, severity: NamedNodeRef<'a>) -> Self {
+    pub fn add_requirement(mut self, requirement: String) -> Self {
+        if !self.requirements.contains(&requirement) {
+            self.requirements.push(requirement);
+        }
+        self
+    }
+
+    pub fn with_documentation(mut self, documentation: Documentation) -> Self {
+        self.documentation = documentation;
+        self
+    }
+
+    pub fn add_component_documentation(
+        mut self,
+        component: NamedNodeRef<'a>,
+        documentation: Documentation,
+    ) -> Self {
+        if !self
+            .component_documentation
+            .iter()
+            .any(|(known, _)| *known == component)
+        {
+            self.component_documentation
+                .push((component, documentation));
+        }
+        self
+    }
+
+    /// Guidance for a result of `component`: the shape's own, completed by
+    /// the component's.
+    pub fn documentation_for(&self, component: Option<ConstraintComponent<'_>>) -> Documentation {
+        let component_documentation = component
+            .and_then(|component| {
+                self.component_documentation
+                    .iter()
+                    .find(|(iri, _)| *iri == component.iri())
+            })
+            .map(|(_, documentation)| documentation.clone())
+            .unwrap_or_default();
+        self.documentation.clone().or(component_documentation)
+    }
+
+    pub fn add_value_transform(mut self, transform: ValueTransform) -> Self {
+        if !self.value_transforms.contains(&transform) {
+            self.value_transforms.push(transform);
+        }
+        self
+    }
+
+    pub fn with_unit(mut self, unit: NamedNodeRef<'a>) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    pub fn with_severity(mut self, severity: Severity<'a>) -> Self {
         self.severity = severity;
         self
     }
@@ -241,6 +348,10 @@ impl<'a> Display for Shape<'a> {
 
         writeln!(f, "  Severity: {}", self.severity)?;
 
+        if !self.requirements.is_empty() {
+            writeln!(f, "  Requirements: {}", self.requirements.join(", "))?;
+        }
+
         if !self.targets.is_empty() {
             writeln!(f, "  Targets:")?;
             for target in &self.targets {
@@ -1,8 +1,19 @@
-use log::debug;
+use log::{debug, warn};
 use oxigraph::model::vocab::rdf::TYPE;
-use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
-use std::collections::HashSet;
+use oxigraph::model::{NamedNode, NamedNodeRef, NamedOrBlankNodeRef, Term, TermRef};
+use oxigraph::sparql::QueryResults;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
+use std::sync::Arc;
+
+use crate::{
+    utils::{get_string_value, parse_shacl_prefixes},
+    validation::{
+        constraints::sparql::parse_query, dataset::ValidationDataset,
+        dataset_constraint::find_in_graph,
+    },
+    vocab::sh,
+};
 
 /// SHACL Target that represents a target in the SHACL specification.
 ///
@@ -41,6 +52,8 @@ pub enum Target<'a> {
     Class(NamedOrBlankNodeRef<'a>),
     SubjectsOf(NamedNodeRef<'a>),
     ObjectsOf(NamedNodeRef<'a>),
+    /// A `sh:SPARQLTarget` node: the `?this` bindings of its `sh:select` query.
+    Sparql(NamedOrBlankNodeRef<'a>),
     Advanced(NamedOrBlankNodeRef<'a>),
 }
 
@@ -99,8 +112,235 @@ impl<'a> Target<'a> {
                 }
                 set
             }
-            Target::Advanced(_) => HashSet::new(),
+            Target::Sparql(_) | Target::Advanced(_) => HashSet::new(),
+        }
+    }
+
+    /// Resolves the target on the data graph of `validation_dataset`, running
+    /// SPARQL targets on its store and following subclasses as its
+    /// [`Entailment`](crate::validation::config::Entailment) sets.
+    pub fn resolve_target(
+        &self,
+        validation_dataset: &'a ValidationDataset,
+    ) -> HashSet<TermRef<'a>> {
+        match self {
+            Target::Class(class) => validation_dataset.instances_of(*class),
+            Target::Sparql(node) => self.resolve_sparql_target(validation_dataset, *node),
+            _ => self.resolve_target_for_given_graph(validation_dataset.data_graph()),
+        }
+    }
+
+    /// Resolves the target like [`Self::resolve_target`], handing `sh:target`
+    /// nodes whose type has a resolver in `target_types` to that resolver.
+    pub fn resolve_target_with(
+        &self,
+        validation_dataset: &'a ValidationDataset,
+        target_types: &TargetTypes,
+    ) -> HashSet<TermRef<'a>> {
+        let Target::Advanced(node) = self else {
+            return self.resolve_target(validation_dataset);
+        };
+        let Some(resolver) = target_types.resolver_for(validation_dataset, *node) else {
+            return HashSet::new();
+        };
+
+        let mut focus_nodes = HashSet::new();
+        for term in resolver.resolve(validation_dataset, *node) {
+            // Borrow the node from the graphs so results can refer to it.
+            match find_in_graph(validation_dataset.data_graph(), &term)
+                .or_else(|| find_in_graph(validation_dataset.shapes_graph(), &term))
+            {
+                Some(focus_node) => {
+                    focus_nodes.insert(focus_node);
+                }
+                None => warn!(
+                    "Skipping focus node {} of custom target {}: not found in the data graph",
+                    term, node
+                ),
+            }
+        }
+        focus_nodes
+    }
+
+    /// Whether `node` is a focus node of the target, checked without resolving
+    /// the whole target; `None` for SPARQL and custom targets, which have to be
+    /// resolved.
+    pub fn selects(
+        &self,
+        validation_dataset: &ValidationDataset,
+        node: TermRef<'_>,
+    ) -> Option<bool> {
+        let graph = validation_dataset.data_graph();
+        let subject = crate::utils::term_to_named_or_blank(node);
+        let selects = match self {
+            Target::Node(term) => *term == node,
+            Target::Class(class) => {
+                subject.is_some_and(|subject| validation_dataset.is_instance_of(subject, *class))
+            }
+            Target::SubjectsOf(property) => subject.is_some_and(|subject| {
+                crate::utils::collect_all_subproperties(*property, graph)
+                    .into_iter()
+                    .any(|subproperty| {
+                        graph
+                            .object_for_subject_predicate(subject, subproperty)
+                            .is_some()
+                    })
+            }),
+            Target::ObjectsOf(property) => {
+                subject.is_some()
+                    && crate::utils::collect_all_subproperties(*property, graph)
+                        .into_iter()
+                        .any(|subproperty| {
+                            graph
+                                .subject_for_predicate_object(subproperty, node)
+                                .is_some()
+                        })
+            }
+            Target::Sparql(_) | Target::Advanced(_) => return None,
+        };
+        Some(selects)
+    }
+
+    fn resolve_sparql_target(
+        &self,
+        validation_dataset: &'a ValidationDataset,
+        node: NamedOrBlankNodeRef<'a>,
+    ) -> HashSet<TermRef<'a>> {
+        let shapes_graph = validation_dataset.shapes_graph();
+        let Some(select) = get_string_value(shapes_graph, node, sh::SELECT) else {
+            warn!("SPARQL target {} has no sh:select", node);
+            return HashSet::new();
+        };
+        let query = match parse_query(&select, &parse_shacl_prefixes(shapes_graph, node)) {
+            Ok(query) => query,
+            Err(e) => {
+                warn!("Invalid sh:select on SPARQL target {}: {}", node, e);
+                return HashSet::new();
+            }
+        };
+
+        let store = validation_dataset.store();
+        let solutions = match validation_dataset
+            .sparql_evaluator()
+            .for_query(query)
+            .on_store(store.as_ref())
+            .execute()
+        {
+            Ok(QueryResults::Solutions(solutions)) => solutions,
+            Ok(_) => {
+                warn!("sh:select on SPARQL target {} is not a SELECT query", node);
+                return HashSet::new();
+            }
+            Err(e) => {
+                warn!("SPARQL target {} failed: {}", node, e);
+                return HashSet::new();
+            }
+        };
+
+        let mut focus_nodes = HashSet::new();
+        for solution in solutions.flatten() {
+            let Some(this) = solution.get("this") else {
+                continue;
+            };
+            // Borrow the node from the graphs so results can refer to it.
+            match find_in_graph(validation_dataset.data_graph(), this)
+                .or_else(|| find_in_graph(shapes_graph, this))
+            {
+                Some(focus_node) => {
+                    focus_nodes.insert(focus_node);
+                }
+                None => warn!(
+                    "Skipping focus node {} of SPARQL target {}: not found in the data graph",
+                    this, node
+                ),
+            }
         }
+        focus_nodes
+    }
+}
+
+/// Selects the focus nodes of `sh:target` nodes of a custom target type.
+///
+/// Closures taking the validation dataset and the target node implement it.
+pub trait TargetResolver: Send + Sync {
+    /// Focus nodes of `target`; its parameters are in the shapes graph of
+    /// `validation_dataset`.
+    fn resolve(
+        &self,
+        validation_dataset: &ValidationDataset,
+        target: NamedOrBlankNodeRef<'_>,
+    ) -> HashSet<Term>;
+}
+
+impl<F> TargetResolver for F
+where
+    F: Fn(&ValidationDataset, NamedOrBlankNodeRef<'_>) -> HashSet<Term> + Send + Sync,
+{
+    fn resolve(
+        &self,
+        validation_dataset: &ValidationDataset,
+        target: NamedOrBlankNodeRef<'_>,
+    ) -> HashSet<Term> {
+        self(validation_dataset, target)
+    }
+}
+
+/// Resolvers of custom target types, by the IRI of the type.
+///
+/// ```
+/// use std::collections::HashSet;
+/// use oxigraph::model::{NamedNode, NamedOrBlankNodeRef, Term};
+/// use shacl_rust::core::target::TargetTypes;
+/// use shacl_rust::validation::dataset::ValidationDataset;
+///
+/// let target_types = TargetTypes::new().with_resolver(
+///     NamedNode::new("http://example.org/RecentlyModified").unwrap(),
+///     |_dataset: &ValidationDataset, _target: NamedOrBlankNodeRef<'_>| HashSet::<Term>::new(),
+/// );
+/// assert!(target_types.contains(NamedNode::new("http://example.org/RecentlyModified").unwrap().as_ref()));
+/// ```
+#[derive(Clone, Default)]
+pub struct TargetTypes {
+    resolvers: HashMap<NamedNode, Arc<dyn TargetResolver>>,
+}
+
+impl TargetTypes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves `sh:target` nodes of type `target_type` with `resolver`.
+    pub fn with_resolver(
+        mut self,
+        target_type: NamedNode,
+        resolver: impl TargetResolver + 'static,
+    ) -> Self {
+        self.resolvers.insert(target_type, Arc::new(resolver));
+        self
+    }
+
+    pub fn contains(&self, target_type: NamedNodeRef<'_>) -> bool {
+        self.resolvers.contains_key(&target_type.into_owned())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.resolvers.is_empty()
+    }
+
+    /// Resolver of the first type of `target` in the shapes graph that has one.
+    pub fn resolver_for(
+        &self,
+        validation_dataset: &ValidationDataset,
+        target: NamedOrBlankNodeRef<'_>,
+    ) -> Option<&dyn TargetResolver> {
+        validation_dataset
+            .shapes_graph()
+            .objects_for_subject_predicate(target, TYPE)
+            .find_map(|target_type| match target_type {
+                TermRef::NamedNode(target_type) => self.resolvers.get(&target_type.into_owned()),
+                _ => None,
+            })
+            .map(|resolver| resolver.as_ref())
     }
 }
 
@@ -111,7 +351,9 @@ impl<'a> Display for Target<'a> {
             Target::Class(class) => write!(f, "sh:targetClass {}", class),
             Target::SubjectsOf(property) => write!(f, "sh:targetSubjectsOf {}", property),
             Target::ObjectsOf(property) => write!(f, "sh:targetObjectsOf {}", property),
-            Target::Advanced(target) => write!(f, "sh:target {}", target),
+            Target::Sparql(target) | Target::Advanced(target) => {
+                write!(f, "sh:target {}", target)
+            }
         }
     }
 }
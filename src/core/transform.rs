@@ -0,0 +1,53 @@
+//! Transformations applied to literal values before facet checks.
+
+use std::fmt::{Display, Formatter};
+
+use unicode_normalization::UnicodeNormalization;
+
+/// A normalization of lexical forms, so messy literals are checked predictably.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueTransform {
+    /// Strips leading and trailing whitespace.
+    Trim,
+    /// Unicode normalization form C.
+    Nfc,
+    /// Lowercases the value.
+    CaseFold,
+}
+
+impl ValueTransform {
+    pub const ALL: [ValueTransform; 3] = [
+        ValueTransform::Trim,
+        ValueTransform::Nfc,
+        ValueTransform::CaseFold,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ValueTransform::Trim => "trim",
+            ValueTransform::Nfc => "nfc",
+            ValueTransform::CaseFold => "casefold",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|transform| transform.name() == name)
+    }
+
+    pub fn apply(self, value: &str) -> String {
+        match self {
+            ValueTransform::Trim => value.trim().to_string(),
+            ValueTransform::Nfc => value.nfc().collect(),
+            ValueTransform::CaseFold => value.to_lowercase(),
+        }
+    }
+}
+
+impl Display for ValueTransform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,296 @@
+//! Class diagrams of shapes for architecture documentation.
+//!
+//! Node shapes become classes. Property shapes with a datatype become
+//! attributes, those with `sh:class` or `sh:node` become associations to the
+//! class of the referenced shape. Both carry their `sh:minCount`/`sh:maxCount`
+//! cardinality.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Write},
+    sync::Arc,
+};
+
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
+
+use crate::{
+    core::{constraints::Constraint, path::PathElement, shape::Shape, target::Target},
+    utils::local_name_from_iri,
+};
+
+const XSD_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// Diagram language to export to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagramFormat {
+    PlantUml,
+    Mermaid,
+}
+
+impl DiagramFormat {
+    pub const ALL: [DiagramFormat; 2] = [DiagramFormat::PlantUml, DiagramFormat::Mermaid];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DiagramFormat::PlantUml => "plantuml",
+            DiagramFormat::Mermaid => "mermaid",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|format| format.name().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+impl Display for DiagramFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A class of the diagram with its attributes (`name`, `type`, `cardinality`).
+#[derive(Default)]
+struct Class {
+    attributes: Vec<(String, String, String)>,
+}
+
+/// An association `from` -> `to` labelled with the property and its cardinality.
+struct Association {
+    from: String,
+    to: String,
+    label: String,
+    cardinality: String,
+}
+
+/// Renders node shapes and their property shapes as a class diagram.
+///
+/// ```
+/// use shacl_rust::diagram::{shapes_diagram, DiagramFormat};
+/// use shacl_rust::{parse_shapes, rdf::read_graph_from_string};
+///
+/// let graph = read_graph_from_string(
+///     r#"
+///     @prefix ex: <http://example.org/> .
+///     @prefix sh: <http://www.w3.org/ns/shacl#> .
+///     @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+///
+///     ex:PersonShape a sh:NodeShape ;
+///         sh:property [ sh:path ex:name ; sh:datatype xsd:string ; sh:minCount 1 ] .
+///     "#,
+///     "turtle",
+/// ).unwrap();
+/// let shapes = parse_shapes(&graph).unwrap();
+///
+/// let diagram = shapes_diagram(&shapes, DiagramFormat::Mermaid);
+/// assert!(diagram.contains("+name : xsd:string [1..*]"));
+/// ```
+pub fn shapes_diagram(shapes: &[Arc<Shape<'_>>], format: DiagramFormat) -> String {
+    let mut classes: BTreeMap<String, Class> = BTreeMap::new();
+    let mut associations = Vec::new();
+
+    let node_shapes: Vec<&Shape<'_>> = shapes
+        .iter()
+        .map(Arc::as_ref)
+        .filter(|s| s.is_node_shape())
+        .collect();
+    for shape in &node_shapes {
+        let class_name = shape_class_name(shape.node);
+        classes.entry(class_name.clone()).or_default();
+
+        for property in &shape.property_shapes {
+            let label = property
+                .path
+                .as_ref()
+                .map(|path| {
+                    path.normalized()
+                        .get_elements()
+                        .iter()
+                        .map(path_element_label)
+                        .collect::<Vec<_>>()
+                        .join("/")
+                })
+                .unwrap_or_default();
+            let cardinality = cardinality(property);
+
+            let mut referenced = false;
+            for constraint in &property.constraints {
+                let target = match constraint {
+                    Constraint::Class(c) => Some(class_target_name(&node_shapes, c.0)),
+                    Constraint::Node(c) => Some(shape_class_name(c.0.node)),
+                    _ => None,
+                };
+                if let Some(to) = target {
+                    classes.entry(to.clone()).or_default();
+                    associations.push(Association {
+                        from: class_name.clone(),
+                        to,
+                        label: label.clone(),
+                        cardinality: cardinality.clone(),
+                    });
+                    referenced = true;
+                }
+            }
+
+            if !referenced {
+                let datatype = property
+                    .constraints
+                    .iter()
+                    .find_map(|constraint| match constraint {
+                        Constraint::Datatype(c) => Some(datatype_label(c.0)),
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+                classes
+                    .entry(class_name.clone())
+                    .or_default()
+                    .attributes
+                    .push((label, datatype, cardinality));
+            }
+        }
+    }
+
+    associations.sort_by(|a, b| (&a.from, &a.label, &a.to).cmp(&(&b.from, &b.label, &b.to)));
+    match format {
+        DiagramFormat::PlantUml => render_plantuml(&classes, &associations),
+        DiagramFormat::Mermaid => render_mermaid(&classes, &associations),
+    }
+}
+
+fn render_plantuml(classes: &BTreeMap<String, Class>, associations: &[Association]) -> String {
+    let mut out = String::from("@startuml\n");
+    for (name, class) in classes {
+        let _ = writeln!(out, "class {} {{", name);
+        for (attribute, datatype, cardinality) in &class.attributes {
+            let _ = writeln!(
+                out,
+                "  {}",
+                attribute_line(attribute, datatype, cardinality)
+            );
+        }
+        out.push_str("}\n");
+    }
+    for association in associations {
+        let _ = writeln!(
+            out,
+            "{} --> \"{}\" {} : {}",
+            association.from, association.cardinality, association.to, association.label
+        );
+    }
+    out.push_str("@enduml\n");
+    out
+}
+
+fn render_mermaid(classes: &BTreeMap<String, Class>, associations: &[Association]) -> String {
+    let mut out = String::from("classDiagram\n");
+    for (name, class) in classes {
+        if class.attributes.is_empty() {
+            let _ = writeln!(out, "    class {}", name);
+            continue;
+        }
+        let _ = writeln!(out, "    class {} {{", name);
+        for (attribute, datatype, cardinality) in &class.attributes {
+            let _ = writeln!(
+                out,
+                "        +{}",
+                attribute_line(attribute, datatype, cardinality)
+            );
+        }
+        out.push_str("    }\n");
+    }
+    for association in associations {
+        let _ = writeln!(
+            out,
+            "    {} --> \"{}\" {} : {}",
+            association.from, association.cardinality, association.to, association.label
+        );
+    }
+    out
+}
+
+fn attribute_line(attribute: &str, datatype: &str, cardinality: &str) -> String {
+    if datatype.is_empty() {
+        format!("{} [{}]", attribute, cardinality)
+    } else {
+        format!("{} : {} [{}]", attribute, datatype, cardinality)
+    }
+}
+
+/// UML multiplicity from `sh:minCount` and `sh:maxCount`, e.g. `0..*`.
+fn cardinality(shape: &Shape<'_>) -> String {
+    let mut min = 0;
+    let mut max = None;
+    for constraint in &shape.constraints {
+        match constraint {
+            Constraint::MinCount(c) => min = c.0,
+            Constraint::MaxCount(c) => max = Some(c.0),
+            _ => {}
+        }
+    }
+    match max {
+        Some(max) => format!("{}..{}", min, max),
+        None => format!("{}..*", min),
+    }
+}
+
+/// The class of the node shape targeting `class`, or the class itself.
+fn class_target_name(node_shapes: &[&Shape<'_>], class: NamedNodeRef<'_>) -> String {
+    node_shapes
+        .iter()
+        .find(|shape| shape.targets.contains(&Target::Class(class.into())))
+        .map(|shape| shape_class_name(shape.node))
+        .unwrap_or_else(|| identifier(class.as_str()))
+}
+
+fn shape_class_name(node: NamedOrBlankNodeRef<'_>) -> String {
+    match node {
+        NamedOrBlankNodeRef::NamedNode(nn) => identifier(nn.as_str()),
+        NamedOrBlankNodeRef::BlankNode(bn) => format!("Shape_{}", bn.as_str()),
+    }
+}
+
+/// Local name of `iri` with characters diagram languages reject replaced by `_`.
+fn identifier(iri: &str) -> String {
+    local_name_from_iri(iri)
+        .unwrap_or_else(|| iri.to_string())
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn datatype_label(datatype: NamedNodeRef<'_>) -> String {
+    match datatype.as_str().strip_prefix(XSD_NAMESPACE) {
+        Some(local) => format!("xsd:{}", local),
+        None => local_name_from_iri(datatype.as_str()).unwrap_or_default(),
+    }
+}
+
+fn path_element_label(element: &PathElement<'_>) -> String {
+    let local = |iri: &NamedNodeRef<'_>| {
+        local_name_from_iri(iri.as_str()).unwrap_or_else(|| iri.as_str().to_string())
+    };
+    match element {
+        PathElement::Iri(iri) => local(iri),
+        PathElement::Inverse(iri) => format!("^{}", local(iri)),
+        PathElement::ZeroOrMore(inner) => format!("{}*", path_element_label(inner)),
+        PathElement::OneOrMore(inner) => format!("{}+", path_element_label(inner)),
+        PathElement::ZeroOrOne(inner) => format!("{}?", path_element_label(inner)),
+        PathElement::Alternative(elements) => format!(
+            "({})",
+            elements
+                .iter()
+                .map(path_element_label)
+                .collect::<Vec<_>>()
+                .join("|")
+        ),
+        PathElement::Sequence(elements) => format!(
+            "({})",
+            elements
+                .iter()
+                .map(path_element_label)
+                .collect::<Vec<_>>()
+                .join("/")
+        ),
+    }
+}
@@ -6,6 +6,8 @@ pub enum ShaclError {
     Io(String),
     Parse(String),
     Validation(String),
+    /// A path went beyond the configured path limits.
+    PathLimit(String),
 }
 
 impl Display for ShaclError {
@@ -14,6 +16,7 @@ impl Display for ShaclError {
             ShaclError::Io(e) => write!(f, "IO error: {}", e),
             ShaclError::Parse(e) => write!(f, "Parse error: {}", e),
             ShaclError::Validation(e) => write!(f, "Validation error: {}", e),
+            ShaclError::PathLimit(e) => write!(f, "Path limit exceeded: {}", e),
         }
     }
 }
@@ -0,0 +1,797 @@
+//! Synthetic example data generated from shapes.
+//!
+//! Instances are generated for every node shape with a target, with values
+//! chosen to satisfy the constraints of its property shapes: datatypes,
+//! cardinalities, `sh:in`, `sh:hasValue`, ranges, lengths and patterns. Logical
+//! and property pair constraints are not taken into account, so the data is a
+//! starting point for tests and demos rather than guaranteed to conform.
+//!
+//! Counter-examples break one constraint at a time, so shape authors can check
+//! that each constraint actually fires.
+
+use std::{collections::HashMap, sync::Arc};
+
+use oxigraph::model::{
+    vocab::{rdf, xsd},
+    BlankNode, Graph, Literal, NamedNode, NamedNodeRef, NamedOrBlankNode, NamedOrBlankNodeRef,
+    Term, TermRef, Triple,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use regex_syntax::hir::{Class, Hir, HirKind};
+
+use crate::{
+    core::{
+        constraints::{Constraint, NodeKind},
+        path::PathElement,
+        shape::Shape,
+        target::Target,
+    },
+    utils::{local_name_from_iri, pattern_regex},
+    vocab::sh,
+};
+
+/// How deep `sh:node` and `sh:class` references are followed before plain nodes are used.
+const MAX_DEPTH: usize = 3;
+
+/// How often a value is regenerated when it repeats an earlier value.
+const MAX_RETRIES: usize = 10;
+
+/// Generates example instances of shapes.
+///
+/// ```
+/// use shacl_rust::generator::ExampleGenerator;
+/// use shacl_rust::{parse_shapes, rdf::read_graph_from_string};
+///
+/// let shapes_graph = read_graph_from_string(
+///     r#"
+///     @prefix ex: <http://example.org/> .
+///     @prefix sh: <http://www.w3.org/ns/shacl#> .
+///     @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+///
+///     ex:PersonShape a sh:NodeShape ;
+///         sh:targetClass ex:Person ;
+///         sh:property [ sh:path ex:age ; sh:datatype xsd:integer ; sh:minCount 1 ] .
+///     "#,
+///     "turtle",
+/// ).unwrap();
+/// let shapes = parse_shapes(&shapes_graph).unwrap();
+///
+/// let data = ExampleGenerator::new(42).generate(&shapes, 3);
+/// assert_eq!(data.len(), 6);
+/// ```
+pub struct ExampleGenerator {
+    rng: StdRng,
+    base_iri: String,
+    counters: HashMap<String, usize>,
+}
+
+impl ExampleGenerator {
+    /// A generator whose output is determined by `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+            base_iri: "http://example.org/data/".to_string(),
+            counters: HashMap::new(),
+        }
+    }
+
+    /// Namespace of generated node IRIs (default `http://example.org/data/`).
+    pub fn with_base_iri(mut self, base_iri: impl Into<String>) -> Self {
+        self.base_iri = base_iri.into();
+        self
+    }
+
+    /// Generates `count` instances of every targeted node shape.
+    pub fn generate(&mut self, shapes: &[Arc<Shape<'_>>], count: usize) -> Graph {
+        let mut graph = Graph::new();
+        for shape in shapes {
+            if !shape.is_node_shape() || shape.deactivated || shape.targets.is_empty() {
+                continue;
+            }
+            for _ in 0..count {
+                self.instance(shapes, shape, &mut graph, 0);
+            }
+        }
+        graph
+    }
+
+    /// Adds one instance of `shape` to `graph` and returns its node.
+    pub fn instance(
+        &mut self,
+        shapes: &[Arc<Shape<'_>>],
+        shape: &Shape<'_>,
+        graph: &mut Graph,
+        depth: usize,
+    ) -> NamedOrBlankNode {
+        let node: NamedOrBlankNode = self.new_iri(&shape_name(shape)).into();
+
+        for target in &shape.targets {
+            match *target {
+                Target::Class(class) => {
+                    graph.insert(&Triple::new(node.clone(), rdf::TYPE, class.into_owned()));
+                }
+                Target::ObjectsOf(predicate) => {
+                    let subject = self.new_iri("subject");
+                    graph.insert(&Triple::new(subject, predicate, node.clone()));
+                }
+                Target::SubjectsOf(predicate)
+                    if !shape
+                        .property_shapes
+                        .iter()
+                        .any(|p| simple_predicate(p) == Some(predicate)) =>
+                {
+                    let value = self.literal_value(&[], "value");
+                    graph.insert(&Triple::new(node.clone(), predicate, value));
+                }
+                _ => {}
+            }
+        }
+
+        for constraint in &shape.constraints {
+            if let Constraint::Class(c) = constraint {
+                graph.insert(&Triple::new(node.clone(), rdf::TYPE, c.0));
+            }
+        }
+
+        for property in &shape.property_shapes {
+            if property.deactivated {
+                continue;
+            }
+            let Some(path) = property.path.as_ref() else {
+                continue;
+            };
+            let (predicate, inverse) = match path.get_elements() {
+                [PathElement::Iri(predicate)] => (*predicate, false),
+                [PathElement::Inverse(predicate)] => (*predicate, true),
+                _ => continue,
+            };
+
+            let mut values: Vec<Term> = Vec::new();
+            for index in 0..value_count(property) {
+                // Duplicates collapse into one triple, so retry a few times for a distinct value
+                let mut value = self.value(shapes, property, index, graph, depth);
+                for _ in 0..MAX_RETRIES {
+                    if !values.contains(&value) {
+                        break;
+                    }
+                    value = self.value(shapes, property, index, graph, depth);
+                }
+                values.push(value.clone());
+                let triple = if inverse {
+                    match value {
+                        Term::NamedNode(nn) => Triple::new(nn, predicate, node.clone()),
+                        Term::BlankNode(bn) => Triple::new(bn, predicate, node.clone()),
+                        Term::Literal(_) => continue,
+                    }
+                } else {
+                    Triple::new(node.clone(), predicate, value)
+                };
+                graph.insert(&triple);
+            }
+        }
+
+        node
+    }
+
+    /// Generates one instance per checkable constraint of the property shapes of
+    /// every targeted node shape, each breaking that constraint and no other.
+    ///
+    /// Values that break one constraint can break related ones too, e.g. a value of
+    /// the wrong datatype that is also out of a numeric range. Constraints that
+    /// cannot be broken by changing the values of a simple path are skipped.
+    pub fn counter_examples(&mut self, shapes: &[Arc<Shape<'_>>]) -> (Graph, Vec<CounterExample>) {
+        let mut graph = Graph::new();
+        let mut counter_examples = Vec::new();
+
+        for shape in shapes {
+            if !shape.is_node_shape() || shape.deactivated || shape.targets.is_empty() {
+                continue;
+            }
+            for property in &shape.property_shapes {
+                let Some(predicate) = simple_predicate(property).filter(|_| !property.deactivated)
+                else {
+                    continue;
+                };
+                for constraint in &property.constraints {
+                    let mut instance = Graph::new();
+                    let NamedOrBlankNode::NamedNode(node) =
+                        self.instance(shapes, shape, &mut instance, 0)
+                    else {
+                        continue;
+                    };
+                    let values: Vec<Term> = instance
+                        .objects_for_subject_predicate(&node, predicate)
+                        .map(TermRef::into_owned)
+                        .collect();
+                    let Some((values, component)) =
+                        self.violating_values(shapes, property, constraint, values, &mut instance)
+                    else {
+                        continue;
+                    };
+
+                    let old: Vec<Triple> = instance
+                        .triples_for_subject(&node)
+                        .filter(|triple| triple.predicate == predicate)
+                        .map(|triple| triple.into_owned())
+                        .collect();
+                    for triple in &old {
+                        instance.remove(triple);
+                    }
+                    for value in values {
+                        instance.insert(&Triple::new(node.clone(), predicate, value));
+                    }
+                    graph.extend(&instance);
+
+                    counter_examples.push(CounterExample {
+                        focus_node: node,
+                        source_shape: property.node.to_string(),
+                        path: predicate.into_owned(),
+                        component: component.into_owned(),
+                    });
+                }
+            }
+        }
+        (graph, counter_examples)
+    }
+
+    /// Changes `values` so that they break `constraint`, with the component that should report it.
+    fn violating_values(
+        &mut self,
+        shapes: &[Arc<Shape<'_>>],
+        property: &Shape<'_>,
+        constraint: &Constraint<'_>,
+        mut values: Vec<Term>,
+        graph: &mut Graph,
+    ) -> Option<(Vec<Term>, NamedNodeRef<'static>)> {
+        let name = shape_name(property);
+        let component = match constraint {
+            Constraint::MinCount(c) if c.0 > 0 => {
+                values.truncate(c.0 as usize - 1);
+                sh::MIN_COUNT_CONSTRAINT_COMPONENT
+            }
+            Constraint::MaxCount(c) => {
+                let target = c.0.max(0) as usize + 1;
+                for index in values.len()..target * MAX_RETRIES {
+                    if values.len() == target {
+                        break;
+                    }
+                    let value = self.value(shapes, property, index, graph, 0);
+                    if !values.contains(&value) {
+                        values.push(value);
+                    }
+                }
+                if values.len() < target {
+                    return None;
+                }
+                sh::MAX_COUNT_CONSTRAINT_COMPONENT
+            }
+            Constraint::Datatype(c) => {
+                let lexical = match values.first()? {
+                    Term::Literal(literal) => literal.value().to_string(),
+                    _ => name,
+                };
+                // Keep the lexical form so that only the datatype is wrong
+                let datatype = if c.0 == xsd::STRING {
+                    xsd::NORMALIZED_STRING
+                } else {
+                    xsd::STRING
+                };
+                values[0] = Literal::new_typed_literal(lexical, datatype).into();
+                sh::DATATYPE_CONSTRAINT_COMPONENT
+            }
+            Constraint::NodeKind(c) => {
+                let candidates: [Term; 3] = [
+                    Literal::new_simple_literal(name.clone()).into(),
+                    self.new_iri(&name).into(),
+                    BlankNode::default().into(),
+                ];
+                let value = candidates
+                    .into_iter()
+                    .find(|candidate| !c.0.matches(candidate.as_ref()))?;
+                *values.first_mut()? = value;
+                sh::NODE_KIND_CONSTRAINT_COMPONENT
+            }
+            Constraint::Class(_) => {
+                *values.first_mut()? = self.new_iri(&format!("untyped-{}", name)).into();
+                sh::CLASS_CONSTRAINT_COMPONENT
+            }
+            Constraint::In(c) => {
+                let value: Term = if c.0.iter().all(|term| term.is_literal()) {
+                    Literal::new_simple_literal(format!("not-in-{}", name)).into()
+                } else {
+                    self.new_iri(&format!("not-in-{}", name)).into()
+                };
+                *values.first_mut()? = value;
+                sh::IN_CONSTRAINT_COMPONENT
+            }
+            Constraint::HasValue(c) => {
+                let value: Term = match c.0 {
+                    TermRef::Literal(_) => {
+                        Literal::new_simple_literal(format!("not-{}", name)).into()
+                    }
+                    _ => self.new_iri(&format!("not-{}", name)).into(),
+                };
+                values.retain(|term| term.as_ref() != c.0);
+                values.push(value);
+                sh::HAS_VALUE_CONSTRAINT_COMPONENT
+            }
+            Constraint::Pattern(c) => {
+                let regex = pattern_regex(&c.pattern, c.flags.as_deref()).ok()?;
+                let text = ["", "!", "0", "a", "Z z", "~~~~~~~~~~~~~~~~"]
+                    .into_iter()
+                    .find(|candidate| !regex.is_match(candidate))?;
+                values[0] = with_lexical(values.first()?, text);
+                sh::PATTERN_CONSTRAINT_COMPONENT
+            }
+            Constraint::MinLength(c) if c.0 > 0 => {
+                let text: String = lexical(values.first()?)?
+                    .chars()
+                    .take(c.0 as usize - 1)
+                    .collect();
+                values[0] = with_lexical(&values[0], &text);
+                sh::MIN_LENGTH_CONSTRAINT_COMPONENT
+            }
+            Constraint::MaxLength(c) => {
+                let text = fit_length(
+                    lexical(values.first()?)?,
+                    Some(c.0.max(0) as usize + 1),
+                    None,
+                );
+                values[0] = with_lexical(&values[0], &text);
+                sh::MAX_LENGTH_CONSTRAINT_COMPONENT
+            }
+            Constraint::MinInclusive(c) => {
+                *values.first_mut()? = shifted(c.0, -1.0)?;
+                sh::MIN_INCLUSIVE_CONSTRAINT_COMPONENT
+            }
+            Constraint::MinExclusive(c) => {
+                *values.first_mut()? = c.0.into_owned();
+                sh::MIN_EXCLUSIVE_CONSTRAINT_COMPONENT
+            }
+            Constraint::MaxInclusive(c) => {
+                *values.first_mut()? = shifted(c.0, 1.0)?;
+                sh::MAX_INCLUSIVE_CONSTRAINT_COMPONENT
+            }
+            Constraint::MaxExclusive(c) => {
+                *values.first_mut()? = c.0.into_owned();
+                sh::MAX_EXCLUSIVE_CONSTRAINT_COMPONENT
+            }
+            Constraint::LanguageIn(c) => {
+                let language = ["zz", "xx"]
+                    .into_iter()
+                    .find(|language| !c.0.iter().any(|l| l.eq_ignore_ascii_case(language)))?;
+                let text = lexical(values.first()?)?;
+                values[0] = Literal::new_language_tagged_literal_unchecked(text, language).into();
+                sh::LANGUAGE_IN_CONSTRAINT_COMPONENT
+            }
+            _ => return None,
+        };
+        Some((values, component))
+    }
+
+    /// A value satisfying the value constraints of `property`.
+    fn value(
+        &mut self,
+        shapes: &[Arc<Shape<'_>>],
+        property: &Shape<'_>,
+        index: usize,
+        graph: &mut Graph,
+        depth: usize,
+    ) -> Term {
+        let constraints = &property.constraints;
+        let name = shape_name(property);
+
+        for constraint in constraints {
+            match constraint {
+                Constraint::HasValue(c) if index == 0 => return c.0.into_owned(),
+                Constraint::In(c) if !c.0.is_empty() => {
+                    return c.0[self.rng.random_range(0..c.0.len())].into_owned();
+                }
+                Constraint::Node(c) if depth < MAX_DEPTH => {
+                    return self.instance(shapes, &c.0, graph, depth + 1).into();
+                }
+                Constraint::Class(c) => {
+                    let shape = shapes.iter().find(|shape| {
+                        shape.is_node_shape() && shape.targets.contains(&Target::Class(c.0.into()))
+                    });
+                    if let (Some(shape), true) = (shape, depth < MAX_DEPTH) {
+                        return self.instance(shapes, shape, graph, depth + 1).into();
+                    }
+                    let class_name = local_name_from_iri(c.0.as_str()).unwrap_or_default();
+                    let node = self.new_iri(&class_name);
+                    graph.insert(&Triple::new(node.clone(), rdf::TYPE, c.0));
+                    return node.into();
+                }
+                _ => {}
+            }
+        }
+
+        let node_kind = constraints.iter().find_map(|constraint| match constraint {
+            Constraint::NodeKind(c) => Some(c.0),
+            _ => None,
+        });
+        match node_kind {
+            Some(NodeKind::IRI | NodeKind::BlankNodeOrIRI | NodeKind::IRIOrLiteral)
+                if !has_literal_constraints(constraints) =>
+            {
+                self.new_iri(&name).into()
+            }
+            Some(NodeKind::BlankNode | NodeKind::BlankNodeOrLiteral)
+                if !has_literal_constraints(constraints) =>
+            {
+                BlankNode::default().into()
+            }
+            _ => self.literal_value(constraints, &name).into(),
+        }
+    }
+
+    /// A literal satisfying the datatype, range, length and pattern constraints.
+    fn literal_value(&mut self, constraints: &[Constraint<'_>], name: &str) -> Literal {
+        let mut datatype = None;
+        let mut pattern = None;
+        let mut languages: &[String] = &[];
+        let (mut min_length, mut max_length) = (None, None);
+        let mut range = NumericRange::default();
+
+        for constraint in constraints {
+            match constraint {
+                Constraint::Datatype(c) => datatype = Some(c.0),
+                Constraint::Pattern(c) => pattern = Some(c),
+                Constraint::LanguageIn(c) => languages = &c.0,
+                Constraint::MinLength(c) => min_length = Some(c.0.max(0) as usize),
+                Constraint::MaxLength(c) => max_length = Some(c.0.max(0) as usize),
+                Constraint::MinInclusive(c) => range.min = number(c.0),
+                Constraint::MinExclusive(c) => {
+                    range.min = number(c.0);
+                    range.min_exclusive = true;
+                }
+                Constraint::MaxInclusive(c) => range.max = number(c.0),
+                Constraint::MaxExclusive(c) => {
+                    range.max = number(c.0);
+                    range.max_exclusive = true;
+                }
+                _ => {}
+            }
+        }
+
+        let text = |generator: &mut Self| {
+            if let Some(pattern) = pattern {
+                if let Some(text) =
+                    generator.string_for_pattern(&pattern.pattern, pattern.flags.as_deref())
+                {
+                    return text;
+                }
+            }
+            let text = format!("{} {}", name, generator.rng.random_range(1..1000));
+            fit_length(text, min_length, max_length)
+        };
+
+        let Some(datatype) = datatype else {
+            return match languages.first() {
+                Some(_) => {
+                    let language = &languages[self.rng.random_range(0..languages.len())];
+                    let value = text(self);
+                    Literal::new_language_tagged_literal(value.clone(), language)
+                        .unwrap_or_else(|_| Literal::new_simple_literal(value))
+                }
+                None => Literal::new_simple_literal(text(self)),
+            };
+        };
+
+        let lexical = if is_integer_type(datatype) {
+            range.integer(&mut self.rng).to_string()
+        } else if [xsd::DECIMAL, xsd::DOUBLE, xsd::FLOAT].contains(&datatype) {
+            format!("{:.2}", range.decimal(&mut self.rng))
+        } else if datatype == xsd::BOOLEAN {
+            self.rng.random_bool(0.5).to_string()
+        } else if datatype == xsd::DATE {
+            self.date()
+        } else if datatype == xsd::DATE_TIME || datatype == xsd::DATE_TIME_STAMP {
+            format!("{}T{:02}:00:00Z", self.date(), self.rng.random_range(0..24))
+        } else if datatype == xsd::G_YEAR {
+            self.rng.random_range(1990..2030).to_string()
+        } else if datatype == xsd::ANY_URI {
+            self.new_iri(name).into_string()
+        } else if datatype == rdf::LANG_STRING {
+            let language = languages.first().map_or("en", String::as_str);
+            let value = text(self);
+            return Literal::new_language_tagged_literal(value.clone(), language)
+                .unwrap_or_else(|_| Literal::new_simple_literal(value));
+        } else {
+            text(self)
+        };
+        Literal::new_typed_literal(lexical, datatype)
+    }
+
+    fn date(&mut self) -> String {
+        format!(
+            "{}-{:02}-{:02}",
+            self.rng.random_range(2000..2030),
+            self.rng.random_range(1..=12),
+            self.rng.random_range(1..=28)
+        )
+    }
+
+    /// A string matching `pattern` under `flags`, or `None` if validation
+    /// would reject the pattern or its flags.
+    fn string_for_pattern(&mut self, pattern: &str, flags: Option<&str>) -> Option<String> {
+        pattern_regex(pattern, flags).ok()?;
+        let flags = flags.unwrap_or_default();
+        if flags.contains('q') {
+            return Some(pattern.to_string());
+        }
+        let hir = regex_syntax::ParserBuilder::new()
+            .case_insensitive(flags.contains('i'))
+            .multi_line(flags.contains('m'))
+            .dot_matches_new_line(flags.contains('s'))
+            .ignore_whitespace(flags.contains('x'))
+            .build()
+            .parse(pattern)
+            .ok()?;
+        let mut out = String::new();
+        self.expand(&hir, &mut out);
+        Some(out)
+    }
+
+    fn expand(&mut self, hir: &Hir, out: &mut String) {
+        match hir.kind() {
+            HirKind::Empty | HirKind::Look(_) => {}
+            HirKind::Literal(literal) => out.push_str(&String::from_utf8_lossy(&literal.0)),
+            HirKind::Class(Class::Unicode(class)) => {
+                // Prefer printable ASCII so that e.g. `.` yields readable text
+                let printable: Vec<(char, char)> = class
+                    .ranges()
+                    .iter()
+                    .filter_map(|range| {
+                        let start = range.start().max('!');
+                        let end = range.end().min('~');
+                        (start <= end).then_some((start, end))
+                    })
+                    .collect();
+                let ranges = if printable.is_empty() {
+                    class
+                        .ranges()
+                        .iter()
+                        .map(|r| (r.start(), r.end()))
+                        .collect()
+                } else {
+                    printable
+                };
+                if ranges.is_empty() {
+                    return;
+                }
+                let (start, end) = ranges[self.rng.random_range(0..ranges.len())];
+                let c = self.rng.random_range(u32::from(start)..=u32::from(end));
+                out.push(char::from_u32(c).unwrap_or(start));
+            }
+            HirKind::Class(Class::Bytes(class)) => {
+                if let Some(range) = class.ranges().first() {
+                    out.push(char::from(range.start()));
+                }
+            }
+            HirKind::Repetition(repetition) => {
+                let extra = repetition
+                    .max
+                    .map_or(3, |max| (max - repetition.min).min(3));
+                let times = repetition.min + self.rng.random_range(0..=extra);
+                for _ in 0..times {
+                    self.expand(&repetition.sub, out);
+                }
+            }
+            HirKind::Capture(capture) => self.expand(&capture.sub, out),
+            HirKind::Concat(hirs) => {
+                for hir in hirs {
+                    self.expand(hir, out);
+                }
+            }
+            HirKind::Alternation(hirs) => {
+                let hir = &hirs[self.rng.random_range(0..hirs.len())];
+                self.expand(hir, out);
+            }
+        }
+    }
+
+    fn new_iri(&mut self, name: &str) -> NamedNode {
+        let counter = self.counters.entry(name.to_string()).or_insert(0);
+        *counter += 1;
+        NamedNode::new(format!("{}{}-{}", self.base_iri, name, counter)).unwrap_or_else(|_| {
+            NamedNode::new_unchecked(format!("{}node-{}", self.base_iri, counter))
+        })
+    }
+}
+
+/// Generates `count` instances of every targeted node shape with the given seed.
+pub fn example_data(shapes: &[Arc<Shape<'_>>], count: usize, seed: u64) -> Graph {
+    ExampleGenerator::new(seed).generate(shapes, count)
+}
+
+/// An instance that should fail validation with one specific constraint component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CounterExample {
+    pub focus_node: NamedNode,
+    /// Property shape whose constraint is broken.
+    pub source_shape: String,
+    pub path: NamedNode,
+    /// Constraint component the violation is expected from.
+    pub component: NamedNode,
+}
+
+impl CounterExample {
+    pub fn as_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "focusNode": self.focus_node.to_string(),
+            "sourceShape": self.source_shape,
+            "resultPath": self.path.to_string(),
+            "sourceConstraintComponent": self.component.to_string(),
+        })
+    }
+}
+
+/// Manifest pairing each counter-example with its expected violation.
+pub fn counter_examples_manifest(counter_examples: &[CounterExample]) -> serde_json::Value {
+    serde_json::json!({
+        "counterExamples": counter_examples.iter().map(CounterExample::as_json).collect::<Vec<_>>(),
+    })
+}
+
+/// Bounds of numeric values.
+#[derive(Default)]
+struct NumericRange {
+    min: Option<f64>,
+    max: Option<f64>,
+    min_exclusive: bool,
+    max_exclusive: bool,
+}
+
+impl NumericRange {
+    /// A random integer within the bounds. Bounds beyond `i128` saturate, so
+    /// extreme values yield an integer at the edge rather than overflowing.
+    fn integer(&self, rng: &mut StdRng) -> i128 {
+        let min = self.min.map(|min| {
+            if self.min_exclusive {
+                (min.floor() as i128).saturating_add(1)
+            } else {
+                min.ceil() as i128
+            }
+        });
+        let max = self.max.map(|max| {
+            if self.max_exclusive {
+                (max.ceil() as i128).saturating_sub(1)
+            } else {
+                max.floor() as i128
+            }
+        });
+        let (low, high) = match (min, max) {
+            (Some(min), Some(max)) => (min, max.max(min)),
+            (Some(min), None) => (min, min.saturating_add(100)),
+            (None, Some(max)) => (max.saturating_sub(100), max),
+            (None, None) => (1, 100),
+        };
+        rng.random_range(low..=high)
+    }
+
+    fn decimal(&self, rng: &mut StdRng) -> f64 {
+        let low = self
+            .min
+            .map_or(self.max.map_or(0.0, |max| max - 100.0), |min| min);
+        let high = self.max.map_or(low + 100.0, |max| max.max(low));
+        // Stay clear of exclusive bounds, also after rounding to two digits
+        let margin = ((high - low) / 10.0).min(0.01);
+        let value = rng.random_range(low + margin..=(high - margin).max(low + margin));
+        (value * 100.0).round() / 100.0
+    }
+}
+
+fn number(term: TermRef<'_>) -> Option<f64> {
+    match term {
+        TermRef::Literal(lit) => lit.value().parse().ok(),
+        _ => None,
+    }
+}
+
+fn is_integer_type(datatype: NamedNodeRef<'_>) -> bool {
+    [
+        xsd::INTEGER,
+        xsd::INT,
+        xsd::LONG,
+        xsd::SHORT,
+        xsd::BYTE,
+        xsd::NON_NEGATIVE_INTEGER,
+        xsd::POSITIVE_INTEGER,
+        xsd::NON_POSITIVE_INTEGER,
+        xsd::NEGATIVE_INTEGER,
+        xsd::UNSIGNED_LONG,
+        xsd::UNSIGNED_INT,
+        xsd::UNSIGNED_SHORT,
+        xsd::UNSIGNED_BYTE,
+    ]
+    .contains(&datatype)
+}
+
+fn has_literal_constraints(constraints: &[Constraint<'_>]) -> bool {
+    constraints.iter().any(|constraint| {
+        matches!(
+            constraint,
+            Constraint::Datatype(_)
+                | Constraint::Pattern(_)
+                | Constraint::LanguageIn(_)
+                | Constraint::MinLength(_)
+                | Constraint::MaxLength(_)
+        )
+    })
+}
+
+/// Number of values to generate: at least `sh:minCount` and one, at most `sh:maxCount`.
+fn value_count(property: &Shape<'_>) -> usize {
+    let mut min = 1;
+    let mut max = None;
+    for constraint in &property.constraints {
+        match constraint {
+            Constraint::MinCount(c) => min = min.max(c.0.max(0) as usize),
+            Constraint::MaxCount(c) => max = Some(c.0.max(0) as usize),
+            _ => {}
+        }
+    }
+    max.map_or(min, |max| min.min(max))
+}
+
+fn simple_predicate<'a>(shape: &Shape<'a>) -> Option<NamedNodeRef<'a>> {
+    match shape.path.as_ref()?.get_elements() {
+        [PathElement::Iri(predicate)] => Some(*predicate),
+        _ => None,
+    }
+}
+
+/// Name used for generated nodes: the local name of the shape or its path.
+fn shape_name(shape: &Shape<'_>) -> String {
+    let iri = match (shape.node, simple_predicate(shape)) {
+        (NamedOrBlankNodeRef::NamedNode(node), None) => node.as_str(),
+        (_, Some(predicate)) => predicate.as_str(),
+        (NamedOrBlankNodeRef::BlankNode(_), None) => "node",
+    };
+    local_name_from_iri(iri).unwrap_or_else(|| "node".to_string())
+}
+
+/// Lexical form of a literal.
+fn lexical(term: &Term) -> Option<String> {
+    match term {
+        Term::Literal(literal) => Some(literal.value().to_string()),
+        _ => None,
+    }
+}
+
+/// `term` with its lexical form replaced, keeping datatype and language.
+fn with_lexical(term: &Term, text: &str) -> Term {
+    match term {
+        Term::Literal(literal) => match literal.language() {
+            Some(language) => Literal::new_language_tagged_literal_unchecked(text, language),
+            None => Literal::new_typed_literal(text, literal.datatype()),
+        },
+        _ => Literal::new_simple_literal(text),
+    }
+    .into()
+}
+
+/// A numeric bound moved by `delta`, with the datatype of the bound.
+fn shifted(bound: TermRef<'_>, delta: f64) -> Option<Term> {
+    let TermRef::Literal(literal) = bound else {
+        return None;
+    };
+    let value = number(bound)? + delta;
+    let lexical = if is_integer_type(literal.datatype()) {
+        (value as i64).to_string()
+    } else {
+        value.to_string()
+    };
+    Some(Literal::new_typed_literal(lexical, literal.datatype()).into())
+}
+
+/// Pads or truncates `text` to the length bounds, counted in characters as
+/// `sh:minLength` and `sh:maxLength` count them.
+fn fit_length(text: String, min: Option<usize>, max: Option<usize>) -> String {
+    let padding = min.unwrap_or(0).saturating_sub(text.chars().count());
+    text.chars()
+        .chain(std::iter::repeat_n('x', padding))
+        .take(max.unwrap_or(usize::MAX))
+        .collect()
+}
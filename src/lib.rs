@@ -1,19 +1,40 @@
+pub mod analysis;
+pub mod conformance;
 pub mod core;
+pub mod diagram;
 pub mod err;
+pub mod generator;
+pub mod mutation;
 pub mod parser;
+#[cfg(feature = "presets")]
+pub mod presets;
 pub mod rdf;
+pub mod registry;
+pub mod rules;
+pub mod shape_tests;
+pub mod snapshots;
 pub mod utils;
 pub mod validation;
 pub mod vocab;
 
 // Re-export commonly used items for convenience
 pub use core::{
+    component::ConstraintComponent,
     constraints::{Constraint, NodeKind},
     path::{Path, PathElement},
+    severity::Severity,
     shape::{ClosedConstraint, Shape, ShapeReference},
     target::Target,
 };
 pub use err::ShaclError;
 pub use parser::parse_shapes;
-pub use validation::{report::ValidationReport, report::ValidationResult, validate};
+pub use validation::{
+    report::ReportNodeIds,
+    report::ReportProvenance,
+    report::ValidationFailure,
+    report::ValidationReport,
+    report::ValidationResult,
+    validate, validate_nodes,
+    validator::{CancellationToken, ValidationOptions, ValidationRun, Validator, ValidatorBuilder},
+};
 pub use vocab::sh;
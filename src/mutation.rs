@@ -0,0 +1,212 @@
+//! Mutation testing of shape test suites (experimental).
+//!
+//! Each mutant is a copy of the shapes graph with one constraint dropped or one
+//! cardinality relaxed. A mutant survives when the [shape tests](crate::shape_tests)
+//! still pass against it, which points at a constraint no test exercises.
+
+use std::fmt::{Display, Formatter};
+
+use oxigraph::model::{
+    vocab::xsd, Graph, Literal, NamedNodeRef, NamedOrBlankNodeRef, Term, TermRef, Triple, TripleRef,
+};
+
+use crate::{
+    err::ShaclError,
+    shape_tests::{run_shape_tests, ShapeTest},
+    vocab::sh,
+};
+
+/// Constraint parameters whose triples are dropped by mutants.
+const CONSTRAINT_PARAMETERS: &[NamedNodeRef<'static>] = &[
+    sh::CLASS,
+    sh::DATATYPE,
+    sh::NODE_KIND_PROPERTY,
+    sh::MIN_COUNT,
+    sh::MAX_COUNT,
+    sh::MIN_EXCLUSIVE,
+    sh::MIN_INCLUSIVE,
+    sh::MAX_EXCLUSIVE,
+    sh::MAX_INCLUSIVE,
+    sh::MIN_LENGTH,
+    sh::MAX_LENGTH,
+    sh::PATTERN,
+    sh::LANGUAGE_IN,
+    sh::UNIQUE_LANG,
+    sh::EQUALS,
+    sh::DISJOINT,
+    sh::LESS_THAN,
+    sh::LESS_THAN_OR_EQUALS,
+    sh::NOT,
+    sh::AND,
+    sh::OR,
+    sh::XONE,
+    sh::NODE,
+    sh::QUALIFIED_VALUE_SHAPE,
+    sh::CLOSED,
+    sh::HAS_VALUE,
+    sh::IN,
+    sh::SPARQL,
+];
+
+/// One change applied to the shapes graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mutation {
+    /// Shape the change was made on.
+    pub shape: String,
+    pub description: String,
+}
+
+impl Display for Mutation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.shape, self.description)
+    }
+}
+
+/// Outcome of running the tests against every mutant.
+#[derive(Debug, Clone, Default)]
+pub struct MutationReport {
+    /// Number of mutants some test failed on.
+    pub killed: usize,
+    /// Mutants all tests still passed on.
+    pub survived: Vec<Mutation>,
+}
+
+impl MutationReport {
+    pub fn total(&self) -> usize {
+        self.killed + self.survived.len()
+    }
+
+    pub fn as_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "total": self.total(),
+            "killed": self.killed,
+            "survived": self.survived.iter().map(|mutation| serde_json::json!({
+                "shape": mutation.shape,
+                "mutation": mutation.description,
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl Display for MutationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for mutation in &self.survived {
+            writeln!(f, "Survived: {}", mutation)?;
+        }
+        write!(
+            f,
+            "{} mutants, {} killed, {} survived",
+            self.total(),
+            self.killed,
+            self.survived.len()
+        )
+    }
+}
+
+/// All mutants of `shapes_graph`, in a stable order.
+pub fn mutants(shapes_graph: &Graph) -> Vec<(Mutation, Graph)> {
+    let mut triples: Vec<TripleRef<'_>> = shapes_graph
+        .iter()
+        .filter(|triple| CONSTRAINT_PARAMETERS.contains(&triple.predicate))
+        .collect();
+    triples.sort_by_cached_key(|triple| {
+        (
+            shape_label(shapes_graph, triple.subject),
+            triple.predicate.to_string(),
+            triple.object.to_string(),
+        )
+    });
+
+    let mut mutants = Vec::new();
+    for triple in triples {
+        let shape = shape_label(shapes_graph, triple.subject);
+
+        let mut graph = shapes_graph.clone();
+        graph.remove(triple);
+        mutants.push((
+            Mutation {
+                shape: shape.clone(),
+                description: format!("dropped {} {}", triple.predicate, triple.object),
+            },
+            graph,
+        ));
+
+        if let Some((relaxed, description)) = relaxed_cardinality(triple) {
+            let mut graph = shapes_graph.clone();
+            graph.remove(triple);
+            graph.insert(&relaxed);
+            mutants.push((Mutation { shape, description }, graph));
+        }
+    }
+    mutants
+}
+
+/// Runs `tests` against every mutant of `shapes_graph`.
+///
+/// Fails if a test already fails against the unchanged shapes, as mutants
+/// could not be told apart from that failure.
+pub fn run_mutation_tests(
+    shapes_graph: &Graph,
+    tests: &[ShapeTest],
+) -> Result<MutationReport, ShaclError> {
+    let failing = run_shape_tests(shapes_graph, tests)
+        .into_iter()
+        .filter(|outcome| !outcome.passed())
+        .map(|outcome| outcome.name)
+        .collect::<Vec<_>>();
+    if !failing.is_empty() {
+        return Err(ShaclError::Validation(format!(
+            "Shape tests fail before mutation: {}",
+            failing.join(", ")
+        )));
+    }
+
+    let mut report = MutationReport::default();
+    for (mutation, graph) in mutants(shapes_graph) {
+        if run_shape_tests(&graph, tests)
+            .iter()
+            .all(|outcome| outcome.passed())
+        {
+            report.survived.push(mutation);
+        } else {
+            report.killed += 1;
+        }
+    }
+    Ok(report)
+}
+
+/// `sh:minCount` lowered or `sh:maxCount` raised by one.
+fn relaxed_cardinality(triple: TripleRef<'_>) -> Option<(Triple, String)> {
+    let TermRef::Literal(literal) = triple.object else {
+        return None;
+    };
+    let count: i64 = literal.value().parse().ok()?;
+    let relaxed = if triple.predicate == sh::MIN_COUNT && count > 0 {
+        count - 1
+    } else if triple.predicate == sh::MAX_COUNT {
+        count + 1
+    } else {
+        return None;
+    };
+    Some((
+        Triple::new(
+            triple.subject.into_owned(),
+            triple.predicate.into_owned(),
+            Term::from(Literal::new_typed_literal(
+                relaxed.to_string(),
+                xsd::INTEGER,
+            )),
+        ),
+        format!("relaxed {} {} to {}", triple.predicate, count, relaxed),
+    ))
+}
+
+/// Named shapes by IRI, blank property shapes by their path.
+fn shape_label(graph: &Graph, node: NamedOrBlankNodeRef<'_>) -> String {
+    match (node, graph.object_for_subject_predicate(node, sh::PATH)) {
+        (NamedOrBlankNodeRef::BlankNode(_), Some(TermRef::NamedNode(path))) => {
+            format!("property shape of {}", path)
+        }
+        _ => node.to_string(),
+    }
+}
@@ -1,11 +1,17 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
-use crate::{Constraint, ShaclError};
+use crate::{parser::shape_registry::ShapeRegistry, Constraint, ShaclError};
 
 pub trait ConstraintParserTrait {
+    /// Parameters the constraint is declared with; the parser only runs on
+    /// shapes that have at least one of them.
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>];
+
+    /// Reads the parameters from `shapes.index()` and resolves referenced
+    /// shapes through `shapes`.
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError>;
 }
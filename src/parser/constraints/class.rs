@@ -1,20 +1,26 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef, TermRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
 
 use crate::{
-    core::constraints::ClassConstraint, parser::constraint_parser_trait::ConstraintParserTrait, sh,
-    Constraint, ShaclError,
+    core::constraints::ClassConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh, Constraint, ShaclError,
 };
 
 struct SHClassConstraintParser;
 
 impl ConstraintParserTrait for SHClassConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::CLASS]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        graph
-            .objects_for_subject_predicate(shape_node, sh::CLASS)
+        shapes
+            .index()
+            .objects(shape_node, sh::CLASS)
             .filter_map(|term| match term {
                 TermRef::NamedNode(nn) => Some(Constraint::Class(ClassConstraint(nn))),
                 _ => None,
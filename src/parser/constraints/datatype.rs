@@ -1,20 +1,26 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef, TermRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
 
 use crate::{
-    core::constraints::DatatypeConstraint, parser::constraint_parser_trait::ConstraintParserTrait,
+    core::constraints::DatatypeConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
     sh, Constraint, ShaclError,
 };
 
 struct SHDatatypeConstraintParser;
 
 impl ConstraintParserTrait for SHDatatypeConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::DATATYPE]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        graph
-            .object_for_subject_predicate(shape_node, sh::DATATYPE)
+        shapes
+            .index()
+            .object(shape_node, sh::DATATYPE)
             .and_then(|t| match t {
                 TermRef::NamedNode(nn) => Some(Constraint::Datatype(DatatypeConstraint(nn))),
                 _ => None,
@@ -1,21 +1,28 @@
 use log::warn;
-use oxigraph::model::{Graph, NamedOrBlankNodeRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
 use crate::{
-    core::constraints::DisjointConstraint, parser::constraint_parser_trait::ConstraintParserTrait,
+    core::constraints::DisjointConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
     sh, Constraint, ShaclError,
 };
 
 struct SHDisjointConstraintParser;
 
 impl ConstraintParserTrait for SHDisjointConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::DISJOINT]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        graph
-            .objects_for_subject_predicate(shape_node, sh::DISJOINT)
+        let graph = shapes.graph();
+        shapes
+            .index()
+            .objects(shape_node, sh::DISJOINT)
             .map(|path_term| crate::parser::path::parse_path(graph, path_term))
             .filter_map(|item| match item {
                 Ok(path) => Some(Constraint::Disjoint(DisjointConstraint(path))),
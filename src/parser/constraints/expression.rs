@@ -0,0 +1,37 @@
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
+
+use crate::{
+    core::constraints::ExpressionConstraint,
+    parser::{
+        constraint_parser_trait::ConstraintParserTrait, expression::parse_node_expression_with,
+        shape_registry::ShapeRegistry,
+    },
+    sh, Constraint, ShaclError,
+};
+
+struct ExpressionConstraintParser;
+
+impl ConstraintParserTrait for ExpressionConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::EXPRESSION]
+    }
+
+    fn parse_constraint<'a>(
+        &self,
+        shape_node: NamedOrBlankNodeRef<'a>,
+        shapes: &ShapeRegistry<'a>,
+    ) -> Result<Vec<Constraint<'a>>, ShaclError> {
+        shapes
+            .index()
+            .objects(shape_node, sh::EXPRESSION)
+            .map(|expression| {
+                parse_node_expression_with(shapes, expression)
+                    .map(|expression| Constraint::Expression(ExpressionConstraint(expression)))
+            })
+            .collect()
+    }
+}
+
+pub fn parser() -> &'static dyn ConstraintParserTrait {
+    &ExpressionConstraintParser
+}
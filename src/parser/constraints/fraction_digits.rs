@@ -0,0 +1,39 @@
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
+
+use crate::{
+    core::constraints::FractionDigitsConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    vocab::shx,
+    Constraint, ShaclError,
+};
+
+/// The XSD facet, accepted as an alias of `shx:fractionDigits`.
+const XSD_FRACTION_DIGITS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2001/XMLSchema#fractionDigits");
+
+struct ShxFractionDigitsConstraintParser;
+
+impl ConstraintParserTrait for ShxFractionDigitsConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[shx::FRACTION_DIGITS, XSD_FRACTION_DIGITS]
+    }
+
+    fn parse_constraint<'a>(
+        &self,
+        shape_node: NamedOrBlankNodeRef<'a>,
+        shapes: &ShapeRegistry<'a>,
+    ) -> Result<Vec<Constraint<'a>>, ShaclError> {
+        shapes
+            .index()
+            .integer(shape_node, shx::FRACTION_DIGITS)
+            .or_else(|| shapes.index().integer(shape_node, XSD_FRACTION_DIGITS))
+            .map(|v| Constraint::FractionDigits(FractionDigitsConstraint(v)))
+            .map(Ok)
+            .into_iter()
+            .collect()
+    }
+}
+
+pub fn parser() -> &'static dyn ConstraintParserTrait {
+    &ShxFractionDigitsConstraintParser
+}
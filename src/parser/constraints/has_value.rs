@@ -1,20 +1,26 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
 use crate::{
-    core::constraints::HasValueConstraint, parser::constraint_parser_trait::ConstraintParserTrait,
+    core::constraints::HasValueConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
     sh, Constraint, ShaclError,
 };
 
 struct SHHasValueConstraintParser;
 
 impl ConstraintParserTrait for SHHasValueConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::HAS_VALUE]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        graph
-            .objects_for_subject_predicate(shape_node, sh::HAS_VALUE)
+        shapes
+            .index()
+            .objects(shape_node, sh::HAS_VALUE)
             .map(|v| Constraint::HasValue(HasValueConstraint(v)))
             .map(Ok)
             .collect()
@@ -0,0 +1,45 @@
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
+
+use crate::{
+    core::{constraints::IdentifierFormatConstraint, identifier::IdentifierFormat},
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    vocab::shx,
+    Constraint, ShaclError,
+};
+
+struct ShxIdentifierFormatConstraintParser;
+
+impl ConstraintParserTrait for ShxIdentifierFormatConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[shx::IDENTIFIER_FORMAT]
+    }
+
+    fn parse_constraint<'a>(
+        &self,
+        shape_node: NamedOrBlankNodeRef<'a>,
+        shapes: &ShapeRegistry<'a>,
+    ) -> Result<Vec<Constraint<'a>>, ShaclError> {
+        shapes
+            .index()
+            .objects(shape_node, shx::IDENTIFIER_FORMAT)
+            .map(|term| match term {
+                TermRef::NamedNode(nn) => IdentifierFormat::from_iri(nn)
+                    .map(|format| Constraint::IdentifierFormat(IdentifierFormatConstraint(format)))
+                    .ok_or_else(|| {
+                        ShaclError::Parse(format!(
+                            "Unknown shx:identifierFormat {} of {}",
+                            nn, shape_node
+                        ))
+                    }),
+                _ => Err(ShaclError::Parse(format!(
+                    "shx:identifierFormat of {} must be an IRI",
+                    shape_node
+                ))),
+            })
+            .collect()
+    }
+}
+
+pub fn parser() -> &'static dyn ConstraintParserTrait {
+    &ShxIdentifierFormatConstraintParser
+}
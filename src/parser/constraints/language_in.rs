@@ -1,22 +1,27 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef, TermRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
 
 use crate::{
     core::constraints::LanguageInConstraint,
-    parser::constraint_parser_trait::ConstraintParserTrait, sh, utils::parse_rdf_list, Constraint,
-    ShaclError,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh,
+    utils::parse_rdf_list,
+    Constraint, ShaclError,
 };
 
 struct SHLanguageInConstraintParser;
 
 impl ConstraintParserTrait for SHLanguageInConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::LANGUAGE_IN]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        if let Some(language_in_node) =
-            graph.object_for_subject_predicate(shape_node, sh::LANGUAGE_IN)
-        {
+        let graph = shapes.graph();
+        if let Some(language_in_node) = shapes.index().object(shape_node, sh::LANGUAGE_IN) {
             let language_in_node = match language_in_node {
                 TermRef::NamedNode(nn) => NamedOrBlankNodeRef::NamedNode(nn),
                 TermRef::BlankNode(bn) => NamedOrBlankNodeRef::BlankNode(bn),
@@ -1,20 +1,27 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
 use crate::{
-    core::constraints::LessThanConstraint, parser::constraint_parser_trait::ConstraintParserTrait,
+    core::constraints::LessThanConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
     sh, Constraint, ShaclError,
 };
 
 struct SHLessThanConstraintParser;
 
 impl ConstraintParserTrait for SHLessThanConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::LESS_THAN]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        graph
-            .objects_for_subject_predicate(shape_node, sh::LESS_THAN)
+        let graph = shapes.graph();
+        shapes
+            .index()
+            .objects(shape_node, sh::LESS_THAN)
             .map(|path_term| {
                 crate::parser::path::parse_path(graph, path_term)
                     .map(|p| Constraint::LessThan(LessThanConstraint(p)))
@@ -1,20 +1,27 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
 use crate::{
     core::constraints::LessThanOrEqualsConstraint,
-    parser::constraint_parser_trait::ConstraintParserTrait, sh, Constraint, ShaclError,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh, Constraint, ShaclError,
 };
 
 struct SHLessThanOrEqualsConstraintParser;
 
 impl ConstraintParserTrait for SHLessThanOrEqualsConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::LESS_THAN_OR_EQUALS]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        graph
-            .objects_for_subject_predicate(shape_node, sh::LESS_THAN_OR_EQUALS)
+        let graph = shapes.graph();
+        shapes
+            .index()
+            .objects(shape_node, sh::LESS_THAN_OR_EQUALS)
             .map(|path_term| {
                 crate::parser::path::parse_path(graph, path_term)
                     .map(|p| Constraint::LessThanOrEquals(LessThanOrEqualsConstraint(p)))
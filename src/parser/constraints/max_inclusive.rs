@@ -1,20 +1,26 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
 use crate::{
     core::constraints::MaxInclusiveConstraint,
-    parser::constraint_parser_trait::ConstraintParserTrait, sh, Constraint, ShaclError,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh, Constraint, ShaclError,
 };
 
 struct SHMaxInclusiveConstraintParser;
 
 impl ConstraintParserTrait for SHMaxInclusiveConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::MAX_INCLUSIVE]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        graph
-            .object_for_subject_predicate(shape_node, sh::MAX_INCLUSIVE)
+        shapes
+            .index()
+            .object(shape_node, sh::MAX_INCLUSIVE)
             .into_iter()
             .map(|v| Constraint::MaxInclusive(MaxInclusiveConstraint(v)))
             .map(Ok)
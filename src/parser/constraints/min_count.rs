@@ -1,19 +1,26 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
 use crate::{
-    core::constraints::MinCountConstraint, parser::constraint_parser_trait::ConstraintParserTrait,
-    sh, utils::get_integer_value, Constraint, ShaclError,
+    core::constraints::MinCountConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh, Constraint, ShaclError,
 };
 
 struct SHMinCountConstraintParser;
 
 impl ConstraintParserTrait for SHMinCountConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::MIN_COUNT]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        get_integer_value(graph, shape_node, sh::MIN_COUNT)
+        shapes
+            .index()
+            .integer(shape_node, sh::MIN_COUNT)
             .map(|v| Constraint::MinCount(MinCountConstraint(v)))
             .map(Ok)
             .into_iter()
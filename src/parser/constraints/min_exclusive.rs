@@ -1,20 +1,26 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
 use crate::{
     core::constraints::MinExclusiveConstraint,
-    parser::constraint_parser_trait::ConstraintParserTrait, sh, Constraint, ShaclError,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh, Constraint, ShaclError,
 };
 
 struct SHMinExclusiveConstraintParser;
 
 impl ConstraintParserTrait for SHMinExclusiveConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::MIN_EXCLUSIVE]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        graph
-            .object_for_subject_predicate(shape_node, sh::MIN_EXCLUSIVE)
+        shapes
+            .index()
+            .object(shape_node, sh::MIN_EXCLUSIVE)
             .into_iter()
             .map(|v| Constraint::MinExclusive(MinExclusiveConstraint(v)))
             .map(Ok)
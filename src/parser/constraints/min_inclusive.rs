@@ -1,20 +1,26 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
 use crate::{
     core::constraints::MinInclusiveConstraint,
-    parser::constraint_parser_trait::ConstraintParserTrait, sh, Constraint, ShaclError,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh, Constraint, ShaclError,
 };
 
 struct SHMinInclusiveConstraintParser;
 
 impl ConstraintParserTrait for SHMinInclusiveConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::MIN_INCLUSIVE]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        graph
-            .object_for_subject_predicate(shape_node, sh::MIN_INCLUSIVE)
+        shapes
+            .index()
+            .object(shape_node, sh::MIN_INCLUSIVE)
             .into_iter()
             .map(|v| Constraint::MinInclusive(MinInclusiveConstraint(v)))
             .map(Ok)
@@ -1,19 +1,26 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
 use crate::{
-    core::constraints::MinLengthConstraint, parser::constraint_parser_trait::ConstraintParserTrait,
-    sh, utils::get_integer_value, Constraint, ShaclError,
+    core::constraints::MinLengthConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh, Constraint, ShaclError,
 };
 
 struct SHMinLengthConstraintParser;
 
 impl ConstraintParserTrait for SHMinLengthConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::MIN_LENGTH]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        get_integer_value(graph, shape_node, sh::MIN_LENGTH)
+        shapes
+            .index()
+            .integer(shape_node, sh::MIN_LENGTH)
             .map(|v| Constraint::MinLength(MinLengthConstraint(v)))
             .map(Ok)
             .into_iter()
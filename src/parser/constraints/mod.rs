@@ -2,7 +2,10 @@ pub mod class;
 pub mod datatype;
 pub mod disjoint;
 pub mod equals;
+pub mod expression;
+pub mod fraction_digits;
 pub mod has_value;
+pub mod identifier_format;
 pub mod language_in;
 pub mod less_than;
 pub mod less_than_or_equals;
@@ -14,9 +17,11 @@ pub mod min_count;
 pub mod min_exclusive;
 pub mod min_inclusive;
 pub mod min_length;
+pub mod namespace_in;
 pub mod node_kind;
 pub mod pattern;
 pub mod qualified_value_shape;
+pub mod relative_time;
 pub mod sh_and;
 pub mod sh_in;
 pub mod sh_node;
@@ -24,4 +29,7 @@ pub mod sh_not;
 pub mod sh_or;
 pub mod sh_xone;
 pub mod sparql;
+pub mod total_digits;
 pub mod unique_lang;
+pub mod unique_value_for_class;
+pub mod uri_template;
@@ -0,0 +1,52 @@
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
+
+use crate::{
+    core::constraints::NamespaceInConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    utils::{parse_rdf_list, term_to_named_or_blank},
+    vocab::shx,
+    Constraint, ShaclError,
+};
+
+struct ShxNamespaceInConstraintParser;
+
+impl ConstraintParserTrait for ShxNamespaceInConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[shx::NAMESPACE_IN]
+    }
+
+    fn parse_constraint<'a>(
+        &self,
+        shape_node: NamedOrBlankNodeRef<'a>,
+        shapes: &ShapeRegistry<'a>,
+    ) -> Result<Vec<Constraint<'a>>, ShaclError> {
+        let graph = shapes.graph();
+        let Some(list) = shapes
+            .index()
+            .object(shape_node, shx::NAMESPACE_IN)
+            .and_then(term_to_named_or_blank)
+        else {
+            return Ok(vec![]);
+        };
+
+        let namespaces: Vec<String> = parse_rdf_list(graph, list)
+            .into_iter()
+            .filter_map(|term| match term {
+                TermRef::NamedNode(nn) => Some(nn.as_str().to_string()),
+                TermRef::Literal(lit) => Some(lit.value().to_string()),
+                TermRef::BlankNode(_) => None,
+            })
+            .collect();
+
+        if namespaces.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![Constraint::NamespaceIn(NamespaceInConstraint(
+            namespaces,
+        ))])
+    }
+}
+
+pub fn parser() -> &'static dyn ConstraintParserTrait {
+    &ShxNamespaceInConstraintParser
+}
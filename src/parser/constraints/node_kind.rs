@@ -1,8 +1,8 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef, TermRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
 
 use crate::{
     core::constraints::{NodeKind, NodeKindConstraint},
-    parser::constraint_parser_trait::ConstraintParserTrait,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
     sh, Constraint, ShaclError,
 };
 
@@ -23,13 +23,18 @@ fn parse_node_kind(term: TermRef) -> Option<NodeKind> {
 }
 
 impl ConstraintParserTrait for SHNodeKindConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::NODE_KIND_PROPERTY]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        graph
-            .object_for_subject_predicate(shape_node, sh::NODE_KIND_PROPERTY)
+        shapes
+            .index()
+            .object(shape_node, sh::NODE_KIND_PROPERTY)
             .and_then(parse_node_kind)
             .map(|nk| Constraint::NodeKind(NodeKindConstraint(nk)))
             .map(Ok)
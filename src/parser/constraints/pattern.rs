@@ -1,20 +1,25 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
 use crate::{
-    core::constraints::PatternConstraint, parser::constraint_parser_trait::ConstraintParserTrait,
-    sh, utils::get_string_value, Constraint, ShaclError,
+    core::constraints::PatternConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh, Constraint, ShaclError,
 };
 
 struct SHPatternConstraintParser;
 
 impl ConstraintParserTrait for SHPatternConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::PATTERN]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        if let Some(pattern) = get_string_value(graph, shape_node, sh::PATTERN) {
-            let flags = get_string_value(graph, shape_node, sh::FLAGS);
+        if let Some(pattern) = shapes.index().string(shape_node, sh::PATTERN) {
+            let flags = shapes.index().string(shape_node, sh::FLAGS);
             Ok(vec![Constraint::Pattern(PatternConstraint {
                 pattern,
                 flags,
@@ -0,0 +1,37 @@
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
+
+use crate::{
+    core::constraints::RelativeTimeConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    vocab::shx,
+    Constraint, ShaclError,
+};
+
+struct ShxRelativeTimeConstraintParser;
+
+impl ConstraintParserTrait for ShxRelativeTimeConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[shx::MIN_DAYS_FROM_NOW, shx::MAX_DAYS_FROM_NOW]
+    }
+
+    fn parse_constraint<'a>(
+        &self,
+        shape_node: NamedOrBlankNodeRef<'a>,
+        shapes: &ShapeRegistry<'a>,
+    ) -> Result<Vec<Constraint<'a>>, ShaclError> {
+        let min_days = shapes.index().integer(shape_node, shx::MIN_DAYS_FROM_NOW);
+        let max_days = shapes.index().integer(shape_node, shx::MAX_DAYS_FROM_NOW);
+        if min_days.is_none() && max_days.is_none() {
+            return Ok(vec![]);
+        }
+
+        Ok(vec![Constraint::RelativeTime(RelativeTimeConstraint {
+            min_days,
+            max_days,
+        })])
+    }
+}
+
+pub fn parser() -> &'static dyn ConstraintParserTrait {
+    &ShxRelativeTimeConstraintParser
+}
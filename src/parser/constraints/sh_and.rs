@@ -1,21 +1,29 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef, TermRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
 
 use crate::{
-    core::constraints::AndConstraint, parser::constraint_parser_trait::ConstraintParserTrait, sh,
-    utils::parse_rdf_list, Constraint, ShaclError,
+    core::constraints::AndConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh,
+    utils::parse_rdf_list,
+    Constraint, ShaclError,
 };
 
 struct SHAndConstraintParser;
 
 impl ConstraintParserTrait for SHAndConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::AND]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
+        let graph = shapes.graph();
         let mut constraints = Vec::new();
 
-        for and_obj in graph.objects_for_subject_predicate(shape_node, sh::AND) {
+        for and_obj in shapes.index().objects(shape_node, sh::AND) {
             let and_node = match and_obj {
                 TermRef::NamedNode(nn) => NamedOrBlankNodeRef::NamedNode(nn),
                 TermRef::BlankNode(bn) => NamedOrBlankNodeRef::BlankNode(bn),
@@ -30,7 +38,7 @@ impl ConstraintParserTrait for SHAndConstraintParser {
                     TermRef::BlankNode(bn) => NamedOrBlankNodeRef::BlankNode(bn),
                     _ => continue,
                 };
-                if let Ok(sub_shape) = super::super::parse_shape(graph, sn, Some(shape_node)) {
+                if let Ok(sub_shape) = shapes.resolve(sn, shape_node) {
                     and_shapes.push(sub_shape);
                 }
             }
@@ -1,19 +1,27 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef, TermRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
 
 use crate::{
-    core::constraints::InConstraint, parser::constraint_parser_trait::ConstraintParserTrait, sh,
-    utils::parse_rdf_list, Constraint, ShaclError,
+    core::constraints::InConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh,
+    utils::parse_rdf_list,
+    Constraint, ShaclError,
 };
 
 struct SHInConstraintParser;
 
 impl ConstraintParserTrait for SHInConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::IN]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        if let Some(in_node) = graph.object_for_subject_predicate(shape_node, sh::IN) {
+        let graph = shapes.graph();
+        if let Some(in_node) = shapes.index().object(shape_node, sh::IN) {
             if let Some(in_node) = match in_node {
                 TermRef::NamedNode(nn) => Some(NamedOrBlankNodeRef::NamedNode(nn)),
                 TermRef::BlankNode(bn) => Some(NamedOrBlankNodeRef::BlankNode(bn)),
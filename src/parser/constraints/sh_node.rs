@@ -1,29 +1,34 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef, TermRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
 
 use crate::{
-    core::constraints::NodeConstraint, parser::constraint_parser_trait::ConstraintParserTrait, sh,
-    Constraint, ShaclError,
+    core::constraints::NodeConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh, Constraint, ShaclError,
 };
 
 struct SHNodeConstraintParser;
 
 impl ConstraintParserTrait for SHNodeConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::NODE]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
         let mut constraints = Vec::new();
 
-        for node_obj in graph.objects_for_subject_predicate(shape_node, sh::NODE) {
+        for node_obj in shapes.index().objects(shape_node, sh::NODE) {
             let node_shape = match node_obj {
                 TermRef::NamedNode(nn) => NamedOrBlankNodeRef::NamedNode(nn),
                 TermRef::BlankNode(bn) => NamedOrBlankNodeRef::BlankNode(bn),
                 _ => continue,
             };
 
-            if let Ok(shape) = super::super::parse_shape(graph, node_shape, Some(shape_node)) {
-                constraints.push(Constraint::Node(NodeConstraint(Box::new(shape))));
+            if let Ok(shape) = shapes.resolve(node_shape, shape_node) {
+                constraints.push(Constraint::Node(NodeConstraint(shape)));
             }
         }
 
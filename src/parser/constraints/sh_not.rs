@@ -1,29 +1,34 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef, TermRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
 
 use crate::{
-    core::constraints::NotConstraint, parser::constraint_parser_trait::ConstraintParserTrait, sh,
-    Constraint, ShaclError,
+    core::constraints::NotConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh, Constraint, ShaclError,
 };
 
 struct SHNotConstraintParser;
 
 impl ConstraintParserTrait for SHNotConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::NOT]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
         let mut constraints = Vec::new();
 
-        if let Some(not_obj) = graph.object_for_subject_predicate(shape_node, sh::NOT) {
+        if let Some(not_obj) = shapes.index().object(shape_node, sh::NOT) {
             let not_node = match not_obj {
                 TermRef::NamedNode(nn) => NamedOrBlankNodeRef::NamedNode(nn),
                 TermRef::BlankNode(bn) => NamedOrBlankNodeRef::BlankNode(bn),
                 _ => return Ok(constraints),
             };
 
-            if let Ok(not_shape) = super::super::parse_shape(graph, not_node, Some(shape_node)) {
-                constraints.push(Constraint::Not(NotConstraint(Box::new(not_shape))));
+            if let Ok(not_shape) = shapes.resolve(not_node, shape_node) {
+                constraints.push(Constraint::Not(NotConstraint(not_shape)));
             }
         }
 
@@ -1,28 +1,28 @@
-use oxigraph::model::{vocab::rdf, Graph, NamedOrBlankNodeRef, TermRef};
+use oxigraph::model::{vocab::rdf, Graph, NamedNodeRef, NamedOrBlankNodeRef, TermRef};
 
 use crate::{
-    core::constraints::{Constraint, SparqlConstraint, SparqlExecutable},
+    core::{
+        component::ConstraintComponent,
+        constraints::{Constraint, CustomConstraint, SparqlConstraint, SparqlExecutable},
+    },
     err::ShaclError,
+    parser::{shape_index::ShapeIndex, shape_registry::ShapeRegistry},
     utils::{
         get_all_string_values, get_boolean_value, is_subclass_of, local_name_from_iri,
-        parse_shacl_prefixes, term_to_named_or_blank,
+        parse_node_kind, parse_shacl_prefixes, term_to_named_or_blank,
     },
     vocab::sh,
 };
 
 fn parse_executable<'a>(
-    graph: &'a Graph,
+    index: &ShapeIndex<'a>,
     executable_node: NamedOrBlankNodeRef<'a>,
 ) -> Option<SparqlExecutable> {
-    if let Some(TermRef::Literal(lit)) =
-        graph.object_for_subject_predicate(executable_node, sh::SELECT)
-    {
+    if let Some(TermRef::Literal(lit)) = index.object(executable_node, sh::SELECT) {
         return Some(SparqlExecutable::Select(lit.value().to_string()));
     }
 
-    if let Some(TermRef::Literal(lit)) =
-        graph.object_for_subject_predicate(executable_node, sh::ASK)
-    {
+    if let Some(TermRef::Literal(lit)) = index.object(executable_node, sh::ASK) {
         return Some(SparqlExecutable::Ask(lit.value().to_string()));
     }
 
@@ -30,13 +30,14 @@ fn parse_executable<'a>(
 }
 
 fn parse_direct_shape_sparql_constraints<'a>(
-    graph: &'a Graph,
+    shapes: &ShapeRegistry<'a>,
     shape_node: NamedOrBlankNodeRef<'a>,
 ) -> Vec<Constraint<'a>> {
+    let (graph, index) = (shapes.graph(), shapes.index());
     let mut constraints = Vec::new();
     let mut seen_sources = std::collections::HashSet::new();
 
-    for sparql_term in graph.objects_for_subject_predicate(shape_node, sh::SPARQL) {
+    for sparql_term in index.objects(shape_node, sh::SPARQL) {
         let Some(executable_node) = term_to_named_or_blank(sparql_term) else {
             continue;
         };
@@ -45,7 +46,7 @@ fn parse_direct_shape_sparql_constraints<'a>(
             continue;
         }
 
-        let Some(executable) = parse_executable(graph, executable_node) else {
+        let Some(executable) = parse_executable(index, executable_node) else {
             continue;
         };
 
@@ -53,19 +54,19 @@ fn parse_direct_shape_sparql_constraints<'a>(
             source_constraint: Some(executable_node),
             source_constraint_component: None,
             executable,
-            messages: get_all_string_values(graph, executable_node, sh::MESSAGE),
+            messages: index.strings(executable_node, sh::MESSAGE),
             prefixes: parse_shacl_prefixes(graph, executable_node),
             parameter_bindings: Vec::new(),
         }));
     }
 
     if seen_sources.insert(shape_node) {
-        if let Some(executable) = parse_executable(graph, shape_node) {
+        if let Some(executable) = parse_executable(index, shape_node) {
             constraints.push(Constraint::Sparql(SparqlConstraint {
                 source_constraint: Some(shape_node),
                 source_constraint_component: None,
                 executable,
-                messages: get_all_string_values(graph, shape_node, sh::MESSAGE),
+                messages: index.strings(shape_node, sh::MESSAGE),
                 prefixes: parse_shacl_prefixes(graph, shape_node),
                 parameter_bindings: Vec::new(),
             }));
@@ -88,84 +89,258 @@ fn is_constraint_component_instance<'a>(
         })
 }
 
-fn parse_component_parameter_bindings<'a>(
+/// Checks a parameter value against the `sh:datatype`, `sh:class` and
+/// `sh:nodeKind` declared on the parameter.
+fn check_parameter_value<'a>(
     graph: &'a Graph,
+    parameter_node: NamedOrBlankNodeRef<'a>,
+    value: TermRef<'a>,
+) -> Result<(), String> {
+    if let Some(TermRef::NamedNode(datatype)) =
+        graph.object_for_subject_predicate(parameter_node, sh::DATATYPE)
+    {
+        match value {
+            TermRef::Literal(lit) if lit.datatype() == datatype => {}
+            _ => return Err(format!("expected a literal of datatype {}", datatype)),
+        }
+    }
+
+    if let Some(node_kind) = graph
+        .object_for_subject_predicate(parameter_node, sh::NODE_KIND)
+        .and_then(parse_node_kind)
+    {
+        if !node_kind.matches(value) {
+            return Err(format!("expected node kind {}", node_kind));
+        }
+    }
+
+    for class in graph
+        .objects_for_subject_predicate(parameter_node, sh::CLASS)
+        .filter_map(term_to_named_or_blank)
+    {
+        let is_instance = term_to_named_or_blank(value).is_some_and(|value_node| {
+            graph
+                .objects_for_subject_predicate(value_node, rdf::TYPE)
+                .filter_map(term_to_named_or_blank)
+                .any(|value_type| value_type == class || is_subclass_of(value_type, class, graph))
+        });
+        if !is_instance {
+            return Err(format!("expected an instance of {}", class));
+        }
+    }
+
+    Ok(())
+}
+
+/// Parameter values of a component instance, by parameter path.
+type ParameterBindings<'a> = Vec<(NamedNodeRef<'a>, TermRef<'a>)>;
+
+/// Collects the parameter bindings of `component` for `shape_node`, one list per combination of parameter values.
+///
+/// Returns `Ok(None)` when a mandatory parameter is missing, i.e. the shape
+/// does not instantiate the component, and an error when a supplied value does
+/// not match its parameter declaration, even if a mandatory parameter is missing.
+fn parse_component_parameter_bindings<'a>(
+    shapes: &ShapeRegistry<'a>,
     component: NamedOrBlankNodeRef<'a>,
     shape_node: NamedOrBlankNodeRef<'a>,
-) -> Option<Vec<(String, TermRef<'a>)>> {
-    let mut bindings = Vec::new();
+) -> Result<Option<Vec<ParameterBindings<'a>>>, String> {
+    let (graph, index) = (shapes.graph(), shapes.index());
+    let mut combinations = vec![Vec::new()];
+    let mut pending_errors = Vec::new();
+    // A missing mandatory value does not stop the checks of the other parameters.
+    let mut instantiated = true;
 
     for parameter_term in graph.objects_for_subject_predicate(component, sh::PARAMETER) {
-        let parameter_node = term_to_named_or_blank(parameter_term)?;
+        let Some(parameter_node) = term_to_named_or_blank(parameter_term) else {
+            return Ok(None);
+        };
 
-        let path = graph
+        let Some(path) = graph
             .object_for_subject_predicate(parameter_node, sh::PATH)
             .and_then(|t| match t {
                 TermRef::NamedNode(nn) => Some(nn),
                 _ => None,
-            })?;
+            })
+        else {
+            return Ok(None);
+        };
 
-        let var_name = local_name_from_iri(path.as_str())?;
+        if local_name_from_iri(path.as_str()).is_none() {
+            return Ok(None);
+        }
         let optional = get_boolean_value(graph, parameter_node, sh::OPTIONAL).unwrap_or(false);
 
-        let mut values = graph.objects_for_subject_predicate(shape_node, path);
-        if let Some(value) = values.next() {
-            bindings.push((var_name, value));
-        } else if !optional {
-            return None;
+        let mut values: Vec<_> = index.objects(shape_node, path).collect();
+        values.sort_by_cached_key(ToString::to_string);
+        for value in &values {
+            if let Err(reason) = check_parameter_value(graph, parameter_node, *value) {
+                pending_errors.push(format!(
+                    "Shape {} has invalid value {} for parameter {} of constraint component {}: {}",
+                    shape_node, value, path, component, reason
+                ));
+            }
+        }
+
+        if values.is_empty() {
+            instantiated &= optional;
+            continue;
         }
+        // Every value of a multi-valued parameter makes a constraint of its own.
+        combinations = combinations
+            .into_iter()
+            .flat_map(|bindings: Vec<_>| {
+                values.iter().map(move |value| {
+                    let mut bindings = bindings.clone();
+                    bindings.push((path, *value));
+                    bindings
+                })
+            })
+            .collect();
+    }
+
+    if !pending_errors.is_empty() {
+        return Err(pending_errors.join("; "));
     }
 
-    Some(bindings)
+    Ok(instantiated.then_some(combinations))
 }
 
-fn parse_component_sparql_constraints<'a>(
-    graph: &'a Graph,
-    shape_node: NamedOrBlankNodeRef<'a>,
+/// Validators of `component` that apply to node or property shapes.
+///
+/// A `sh:nodeValidator` or `sh:propertyValidator` takes precedence over the
+/// generic `sh:validator`.
+fn component_validators<'a>(
+    index: &ShapeIndex<'a>,
+    component: NamedOrBlankNodeRef<'a>,
     is_property_shape: bool,
-) -> Vec<Constraint<'a>> {
-    let mut constraints = Vec::new();
-
-    let mut validator_predicates = vec![sh::VALIDATOR];
-    if is_property_shape {
-        validator_predicates.push(sh::PROPERTY_VALIDATOR);
+) -> Vec<(NamedOrBlankNodeRef<'a>, SparqlExecutable)> {
+    let specific = if is_property_shape {
+        sh::PROPERTY_VALIDATOR
+    } else {
+        sh::NODE_VALIDATOR
+    };
+    let validators = |predicate| {
+        index
+            .objects(component, predicate)
+            .filter_map(term_to_named_or_blank)
+            .filter_map(|validator| Some((validator, parse_executable(index, validator)?)))
+            .collect::<Vec<_>>()
+    };
+    let validators_of_kind = validators(specific);
+    if validators_of_kind.is_empty() {
+        validators(sh::VALIDATOR)
     } else {
-        validator_predicates.push(sh::NODE_VALIDATOR);
+        validators_of_kind
     }
+}
 
-    let components: std::collections::HashSet<_> = graph
+/// Messages of results produced by `validator`: its own `sh:message`, else
+/// the `sh:message` or `sh:labelTemplate` of the component.
+fn component_messages<'a>(
+    graph: &'a Graph,
+    component: NamedOrBlankNodeRef<'a>,
+    validator: NamedOrBlankNodeRef<'a>,
+) -> Vec<String> {
+    [
+        (validator, sh::MESSAGE),
+        (component, sh::MESSAGE),
+        (component, sh::LABEL_TEMPLATE),
+    ]
+    .into_iter()
+    .map(|(node, predicate)| get_all_string_values(graph, node, predicate))
+    .find(|messages| !messages.is_empty())
+    .unwrap_or_default()
+}
+
+/// Constraint components of the shapes graph that declare parameters.
+fn parameterized_components(graph: &Graph) -> Vec<NamedOrBlankNodeRef<'_>> {
+    let mut components: Vec<_> = graph
         .triples_for_predicate(sh::PARAMETER)
         .map(|triple| triple.subject)
         .collect();
+    components.sort_by_cached_key(ToString::to_string);
+    components.dedup();
+    components.retain(|&component| is_constraint_component_instance(graph, component));
+    components
+}
 
-    for component in components {
-        if !is_constraint_component_instance(graph, component) {
-            continue;
-        }
+/// Components `shape_node` instantiates with parameter values that do not
+/// match their declarations, with the reason. The parser skips these.
+pub(crate) fn invalid_component_parameters<'a>(
+    shapes: &ShapeRegistry<'a>,
+    shape_node: NamedOrBlankNodeRef<'a>,
+) -> Vec<(NamedOrBlankNodeRef<'a>, String)> {
+    parameterized_components(shapes.graph())
+        .into_iter()
+        .filter_map(|component| {
+            parse_component_parameter_bindings(shapes, component, shape_node)
+                .err()
+                .map(|reason| (component, reason))
+        })
+        .collect()
+}
 
-        let Some(parameter_bindings) =
-            parse_component_parameter_bindings(graph, component, shape_node)
-        else {
-            continue;
-        };
+fn parse_component_sparql_constraints<'a>(
+    shapes: &ShapeRegistry<'a>,
+    shape_node: NamedOrBlankNodeRef<'a>,
+    is_property_shape: bool,
+) -> Vec<Constraint<'a>> {
+    let graph = shapes.graph();
+    let mut constraints = Vec::new();
 
-        for predicate in &validator_predicates {
-            for validator_term in graph.objects_for_subject_predicate(component, *predicate) {
-                let Some(validator_node) = term_to_named_or_blank(validator_term) else {
-                    continue;
-                };
+    for component in parameterized_components(graph) {
+        let combinations = match parse_component_parameter_bindings(shapes, component, shape_node) {
+            Ok(Some(combinations)) => combinations,
+            Ok(None) => continue,
+            Err(reason) => {
+                // Reported as an unsupported feature of the run.
+                log::warn!("Skipping constraint component {}: {}", component, reason);
+                continue;
+            }
+        };
 
-                let Some(executable) = parse_executable(graph, validator_node) else {
+        // Components without any SPARQL validator are left to registered Rust code.
+        let has_validator = [sh::VALIDATOR, sh::NODE_VALIDATOR, sh::PROPERTY_VALIDATOR]
+            .into_iter()
+            .any(|predicate| {
+                graph
+                    .object_for_subject_predicate(component, predicate)
+                    .is_some()
+            });
+        if !has_validator {
+            // Declarations of the built-in components, e.g. from a loaded shacl.ttl, are skipped.
+            if let NamedOrBlankNodeRef::NamedNode(component) = component {
+                if !matches!(
+                    ConstraintComponent::from_iri(component),
+                    ConstraintComponent::Other(_)
+                ) {
                     continue;
-                };
+                }
+                constraints.extend(combinations.into_iter().map(|parameter_bindings| {
+                    Constraint::Custom(CustomConstraint {
+                        component,
+                        parameter_bindings,
+                    })
+                }));
+            }
+            continue;
+        }
 
+        let validators = component_validators(shapes.index(), component, is_property_shape);
+        for parameter_bindings in combinations {
+            let variable_bindings: Vec<(String, TermRef<'a>)> = parameter_bindings
+                .iter()
+                .filter_map(|(path, value)| Some((local_name_from_iri(path.as_str())?, *value)))
+                .collect();
+            for (validator_node, executable) in &validators {
                 constraints.push(Constraint::Sparql(SparqlConstraint {
-                    source_constraint: Some(validator_node),
+                    source_constraint: Some(*validator_node),
                     source_constraint_component: Some(component),
-                    executable,
-                    messages: get_all_string_values(graph, validator_node, sh::MESSAGE),
-                    prefixes: parse_shacl_prefixes(graph, validator_node),
-                    parameter_bindings: parameter_bindings.clone(),
+                    executable: executable.clone(),
+                    messages: component_messages(graph, component, *validator_node),
+                    prefixes: parse_shacl_prefixes(graph, *validator_node),
+                    parameter_bindings: variable_bindings.clone(),
                 }));
             }
         }
@@ -175,13 +350,13 @@ fn parse_component_sparql_constraints<'a>(
 }
 
 pub fn parse_sparql_constraints<'a>(
-    graph: &'a Graph,
+    shapes: &ShapeRegistry<'a>,
     shape_node: NamedOrBlankNodeRef<'a>,
     is_property_shape: bool,
 ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-    let mut constraints = parse_direct_shape_sparql_constraints(graph, shape_node);
+    let mut constraints = parse_direct_shape_sparql_constraints(shapes, shape_node);
     constraints.extend(parse_component_sparql_constraints(
-        graph,
+        shapes,
         shape_node,
         is_property_shape,
     ));
@@ -0,0 +1,39 @@
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
+
+use crate::{
+    core::constraints::TotalDigitsConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    vocab::shx,
+    Constraint, ShaclError,
+};
+
+/// The XSD facet, accepted as an alias of `shx:totalDigits`.
+const XSD_TOTAL_DIGITS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2001/XMLSchema#totalDigits");
+
+struct ShxTotalDigitsConstraintParser;
+
+impl ConstraintParserTrait for ShxTotalDigitsConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[shx::TOTAL_DIGITS, XSD_TOTAL_DIGITS]
+    }
+
+    fn parse_constraint<'a>(
+        &self,
+        shape_node: NamedOrBlankNodeRef<'a>,
+        shapes: &ShapeRegistry<'a>,
+    ) -> Result<Vec<Constraint<'a>>, ShaclError> {
+        shapes
+            .index()
+            .integer(shape_node, shx::TOTAL_DIGITS)
+            .or_else(|| shapes.index().integer(shape_node, XSD_TOTAL_DIGITS))
+            .map(|v| Constraint::TotalDigits(TotalDigitsConstraint(v)))
+            .map(Ok)
+            .into_iter()
+            .collect()
+    }
+}
+
+pub fn parser() -> &'static dyn ConstraintParserTrait {
+    &ShxTotalDigitsConstraintParser
+}
@@ -1,20 +1,24 @@
-use oxigraph::model::{Graph, NamedOrBlankNodeRef};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
 
 use crate::{
     core::constraints::UniqueLangConstraint,
-    parser::constraint_parser_trait::ConstraintParserTrait, sh, utils::get_boolean_value,
-    Constraint, ShaclError,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    sh, Constraint, ShaclError,
 };
 
 struct SHUniqueLangConstraintParser;
 
 impl ConstraintParserTrait for SHUniqueLangConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[sh::UNIQUE_LANG]
+    }
+
     fn parse_constraint<'a>(
         &self,
         shape_node: NamedOrBlankNodeRef<'a>,
-        graph: &'a Graph,
+        shapes: &ShapeRegistry<'a>,
     ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-        if let Some(unique_lang) = get_boolean_value(graph, shape_node, sh::UNIQUE_LANG) {
+        if let Some(unique_lang) = shapes.index().boolean(shape_node, sh::UNIQUE_LANG) {
             Ok(vec![Constraint::UniqueLang(UniqueLangConstraint(
                 unique_lang,
             ))])
@@ -0,0 +1,40 @@
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef, TermRef};
+
+use crate::{
+    core::constraints::UniqueValueForClassConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    vocab::dash,
+    Constraint, ShaclError,
+};
+
+struct DashUniqueValueForClassConstraintParser;
+
+impl ConstraintParserTrait for DashUniqueValueForClassConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[dash::UNIQUE_VALUE_FOR_CLASS]
+    }
+
+    fn parse_constraint<'a>(
+        &self,
+        shape_node: NamedOrBlankNodeRef<'a>,
+        shapes: &ShapeRegistry<'a>,
+    ) -> Result<Vec<Constraint<'a>>, ShaclError> {
+        shapes
+            .index()
+            .objects(shape_node, dash::UNIQUE_VALUE_FOR_CLASS)
+            .map(|term| match term {
+                TermRef::NamedNode(nn) => Ok(Constraint::UniqueValueForClass(
+                    UniqueValueForClassConstraint(nn),
+                )),
+                _ => Err(ShaclError::Parse(format!(
+                    "dash:uniqueValueForClass of {} must be an IRI",
+                    shape_node
+                ))),
+            })
+            .collect()
+    }
+}
+
+pub fn parser() -> &'static dyn ConstraintParserTrait {
+    &DashUniqueValueForClassConstraintParser
+}
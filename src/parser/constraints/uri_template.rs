@@ -0,0 +1,42 @@
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNodeRef};
+
+use crate::{
+    core::constraints::UriTemplateConstraint,
+    parser::{constraint_parser_trait::ConstraintParserTrait, shape_registry::ShapeRegistry},
+    utils::uri_template_regex,
+    vocab::shx,
+    Constraint, ShaclError,
+};
+
+struct ShxUriTemplateConstraintParser;
+
+impl ConstraintParserTrait for ShxUriTemplateConstraintParser {
+    fn parameters(&self) -> &'static [NamedNodeRef<'static>] {
+        &[shx::URI_TEMPLATE]
+    }
+
+    fn parse_constraint<'a>(
+        &self,
+        shape_node: NamedOrBlankNodeRef<'a>,
+        shapes: &ShapeRegistry<'a>,
+    ) -> Result<Vec<Constraint<'a>>, ShaclError> {
+        let mut templates = shapes.index().strings(shape_node, shx::URI_TEMPLATE);
+        if templates.is_empty() {
+            return Ok(vec![]);
+        }
+        templates.sort();
+
+        for template in &templates {
+            uri_template_regex(template).map_err(|e| {
+                ShaclError::Parse(format!("shx:uriTemplate of {}: {}", shape_node, e))
+            })?;
+        }
+        Ok(vec![Constraint::UriTemplate(UriTemplateConstraint(
+            templates,
+        ))])
+    }
+}
+
+pub fn parser() -> &'static dyn ConstraintParserTrait {
+    &ShxUriTemplateConstraintParser
+}
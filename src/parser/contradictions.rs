@@ -0,0 +1,180 @@
+//! Constraints of a shape that no value node can satisfy together.
+//!
+//! A shapes graph can be well-formed and still contain shapes that fail for
+//! every focus node, such as a `sh:minCount` above the `sh:maxCount`. These
+//! checks find such shapes without any data graph.
+
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+};
+
+use oxigraph::model::{Graph, NamedNodeRef, TermRef};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    parser::{find_shape_nodes, well_formed::reachable_shapes},
+    utils,
+    vocab::sh,
+};
+
+/// Two constraints of a shape that cannot both hold.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Contradiction {
+    /// Shape node the constraints are on.
+    pub node: String,
+    /// Parameter that contradicts another one of the shape.
+    pub predicate: String,
+    pub message: String,
+    /// How the shape could be made satisfiable.
+    pub suggestion: String,
+}
+
+impl Display for Contradiction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.node, self.message)
+    }
+}
+
+/// Minimum and maximum parameters of the same count or length.
+const COUNT_BOUNDS: &[(NamedNodeRef<'static>, NamedNodeRef<'static>)] = &[
+    (sh::MIN_COUNT, sh::MAX_COUNT),
+    (sh::MIN_LENGTH, sh::MAX_LENGTH),
+    (sh::QUALIFIED_MIN_COUNT, sh::QUALIFIED_MAX_COUNT),
+];
+
+/// Node kinds that exclude literals.
+const NON_LITERAL_KINDS: &[NamedNodeRef<'static>] =
+    &[sh::IRI, sh::BLANK_NODE, sh::BLANK_NODE_OR_IRI];
+
+/// Finds the contradicting constraints of every shape in `graph`.
+pub fn find_contradictions(graph: &Graph) -> Vec<Contradiction> {
+    let mut contradictions = Vec::new();
+    for node in reachable_shapes(graph, find_shape_nodes(graph).into_iter()) {
+        let mut contradiction = |predicate: NamedNodeRef<'_>, message: String, suggestion| {
+            contradictions.push(Contradiction {
+                node: node.to_string(),
+                predicate: predicate.to_string(),
+                message,
+                suggestion,
+            })
+        };
+        let value = |parameter| graph.object_for_subject_predicate(node, parameter);
+
+        for &(min, max) in COUNT_BOUNDS {
+            if let (Some(lower), Some(upper)) = (integer(value(min)), integer(value(max))) {
+                if lower > upper {
+                    contradiction(
+                        min,
+                        format!("{} {} is greater than {} {}", min, lower, max, upper),
+                        format!("Lower {} or raise {}", min, max),
+                    );
+                }
+            }
+        }
+
+        for min in [sh::MIN_INCLUSIVE, sh::MIN_EXCLUSIVE] {
+            for max in [sh::MAX_INCLUSIVE, sh::MAX_EXCLUSIVE] {
+                let (Some(lower), Some(upper)) = (value(min), value(max)) else {
+                    continue;
+                };
+                let inclusive = min == sh::MIN_INCLUSIVE && max == sh::MAX_INCLUSIVE;
+                let message = match utils::compare_terms(lower, upper) {
+                    Some(Ordering::Less) => continue,
+                    Some(Ordering::Equal) if inclusive => continue,
+                    Some(_) => format!("{} {} and {} {} leave no values", min, lower, max, upper),
+                    None => format!(
+                        "{} {} cannot be compared with {} {}",
+                        min, lower, max, upper
+                    ),
+                };
+                contradiction(
+                    min,
+                    message,
+                    format!("Use bounds of the same datatype with {} below {}", min, max),
+                );
+            }
+        }
+
+        if let Some(has_value) = value(sh::HAS_VALUE) {
+            if let Some(list) = value(sh::IN).and_then(utils::term_to_named_or_blank) {
+                if !utils::parse_rdf_list(graph, list).contains(&has_value) {
+                    contradiction(
+                        sh::HAS_VALUE,
+                        format!(
+                            "{} {} is not a member of {}",
+                            sh::HAS_VALUE,
+                            has_value,
+                            sh::IN
+                        ),
+                        format!(
+                            "Add {} to {} or remove {}",
+                            has_value,
+                            sh::IN,
+                            sh::HAS_VALUE
+                        ),
+                    );
+                }
+            }
+            if integer(value(sh::MAX_COUNT)) == Some(0) {
+                contradiction(
+                    sh::HAS_VALUE,
+                    format!(
+                        "{} {} requires a value, but {} is 0",
+                        sh::HAS_VALUE,
+                        has_value,
+                        sh::MAX_COUNT
+                    ),
+                    format!("Remove {} or {}", sh::HAS_VALUE, sh::MAX_COUNT),
+                );
+            }
+        }
+
+        if let Some(TermRef::NamedNode(kind)) = value(sh::NODE_KIND_PROPERTY) {
+            if let Some(datatype) = value(sh::DATATYPE) {
+                if NON_LITERAL_KINDS.contains(&kind) {
+                    contradiction(
+                        sh::DATATYPE,
+                        format!(
+                            "{} {} requires literals, but {} is {}",
+                            sh::DATATYPE,
+                            datatype,
+                            sh::NODE_KIND_PROPERTY,
+                            kind
+                        ),
+                        format!(
+                            "Remove {} or use a node kind that allows literals",
+                            sh::DATATYPE
+                        ),
+                    );
+                }
+            }
+            if let Some(class) = value(sh::CLASS) {
+                if kind == sh::LITERAL {
+                    contradiction(
+                        sh::CLASS,
+                        format!(
+                            "{} {} requires IRIs or blank nodes, but {} is {}",
+                            sh::CLASS,
+                            class,
+                            sh::NODE_KIND_PROPERTY,
+                            kind
+                        ),
+                        format!("Remove {} or use a node kind that allows IRIs", sh::CLASS),
+                    );
+                }
+            }
+        }
+    }
+
+    contradictions.sort();
+    contradictions.dedup();
+    contradictions
+}
+
+fn integer(term: Option<TermRef<'_>>) -> Option<i64> {
+    match term? {
+        TermRef::Literal(literal) => literal.value().parse().ok(),
+        _ => None,
+    }
+}
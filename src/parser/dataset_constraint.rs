@@ -0,0 +1,42 @@
+//! Dataset-level constraint parsing.
+
+use oxigraph::model::{vocab::rdf, Graph, TermRef};
+
+use crate::{
+    core::{dataset_constraint::DatasetConstraint, severity::Severity},
+    err::ShaclError,
+    utils::{get_all_string_values, get_boolean_value, parse_shacl_prefixes},
+    vocab::{sh, shx},
+};
+
+/// Parses all `shx:DatasetConstraint` declarations from a shapes graph.
+pub fn parse_dataset_constraints(graph: &Graph) -> Result<Vec<DatasetConstraint<'_>>, ShaclError> {
+    let mut constraints = Vec::new();
+
+    for node in graph.subjects_for_predicate_object(rdf::TYPE, shx::DATASET_CONSTRAINT) {
+        let Some(TermRef::Literal(select)) = graph.object_for_subject_predicate(node, sh::SELECT)
+        else {
+            return Err(ShaclError::Parse(format!(
+                "Dataset constraint {} must have exactly one sh:select literal",
+                node
+            )));
+        };
+
+        let severity = match graph.object_for_subject_predicate(node, sh::SEVERITY) {
+            Some(TermRef::NamedNode(severity)) => Severity::from_iri(severity),
+            _ => Severity::Violation,
+        };
+
+        constraints.push(DatasetConstraint {
+            node,
+            select: select.value().to_string(),
+            prefixes: parse_shacl_prefixes(graph, node),
+            messages: get_all_string_values(graph, node, sh::MESSAGE),
+            severity,
+            deactivated: get_boolean_value(graph, node, sh::DEACTIVATED).unwrap_or(false),
+        });
+    }
+
+    constraints.sort_by_key(|c| c.node.to_string());
+    Ok(constraints)
+}
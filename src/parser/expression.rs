@@ -0,0 +1,81 @@
+//! SHACL-AF node expression parsing.
+
+use oxigraph::model::{Graph, NamedOrBlankNodeRef, TermRef};
+
+use crate::{
+    core::expression::NodeExpression,
+    err::ShaclError,
+    utils::{self, parse_rdf_list},
+    vocab::sh,
+};
+
+use super::{path::parse_path, shape_registry::ShapeRegistry};
+
+/// Parses the node expression `term`.
+pub fn parse_node_expression<'a>(
+    graph: &'a Graph,
+    term: TermRef<'a>,
+) -> Result<NodeExpression<'a>, ShaclError> {
+    parse_node_expression_with(&ShapeRegistry::new(graph), term)
+}
+
+/// Parses the node expression `term`, sharing filter shapes through `shapes`.
+pub fn parse_node_expression_with<'a>(
+    shapes: &ShapeRegistry<'a>,
+    term: TermRef<'a>,
+) -> Result<NodeExpression<'a>, ShaclError> {
+    let graph = shapes.graph();
+    if term == sh::THIS.into() {
+        return Ok(NodeExpression::FocusNode);
+    }
+    let node = match term {
+        TermRef::NamedNode(iri) => NamedOrBlankNodeRef::NamedNode(iri),
+        TermRef::BlankNode(node) => NamedOrBlankNodeRef::BlankNode(node),
+        TermRef::Literal(_) => return Ok(NodeExpression::Constant(term)),
+    };
+    let nodes = graph
+        .object_for_subject_predicate(node, sh::NODES)
+        .map(|nodes| parse_node_expression_with(shapes, nodes).map(Box::new))
+        .transpose()?;
+
+    if let Some(path) = graph.object_for_subject_predicate(node, sh::PATH) {
+        return Ok(NodeExpression::Path {
+            path: parse_path(graph, path)?,
+            nodes,
+        });
+    }
+
+    if let Some(shape) = graph.object_for_subject_predicate(node, sh::FILTER_SHAPE) {
+        let shape = utils::term_to_named_or_blank(shape).ok_or_else(|| {
+            ShaclError::Parse(format!("Invalid sh:filterShape {} on {}", shape, node))
+        })?;
+        return Ok(NodeExpression::FilterShape {
+            shape: shapes.resolve(shape, node)?,
+            nodes: nodes.unwrap_or(Box::new(NodeExpression::FocusNode)),
+        });
+    }
+
+    let members = |list: TermRef<'a>| {
+        let list = utils::term_to_named_or_blank(list).ok_or_else(|| {
+            ShaclError::Parse(format!("Invalid node expression list {} on {}", list, node))
+        })?;
+        parse_rdf_list(graph, list)
+            .into_iter()
+            .map(|member| parse_node_expression_with(shapes, member))
+            .collect::<Result<Vec<_>, _>>()
+    };
+    if let Some(list) = graph.object_for_subject_predicate(node, sh::UNION) {
+        return Ok(NodeExpression::Union(members(list)?));
+    }
+    if let Some(list) = graph.object_for_subject_predicate(node, sh::INTERSECTION) {
+        return Ok(NodeExpression::Intersection(members(list)?));
+    }
+
+    match node {
+        NamedOrBlankNodeRef::NamedNode(_) => Ok(NodeExpression::Constant(term)),
+        NamedOrBlankNodeRef::BlankNode(_) => Err(ShaclError::Parse(format!(
+            "Unsupported node expression {}",
+            node
+        ))),
+    }
+}
@@ -1,30 +1,42 @@
 //! SHACL shape parsing.
 pub mod constraint_parser_trait;
 pub mod constraints;
+pub mod contradictions;
+pub mod dataset_constraint;
+pub mod expression;
 pub mod path;
+pub mod shape_index;
+pub mod shape_registry;
 pub mod target;
+pub mod well_formed;
 
 use log::debug;
 use oxigraph::model::{
     vocab::{rdf, rdfs},
-    Graph, NamedNodeRef, NamedOrBlankNodeRef, TermRef,
+    Graph, NamedNodeRef, NamedOrBlankNodeRef, TermRef, TripleRef,
 };
-use std::collections::HashSet;
+use std::{collections::HashSet, sync::Arc};
 
 use crate::{
     core::{
+        component::ConstraintComponent,
         constraints::Constraint,
-        shape::{ClosedConstraint, Shape},
+        severity::Severity,
+        shape::{ClosedConstraint, Documentation, Shape},
+        transform::ValueTransform,
     },
     err::ShaclError,
-    utils::{get_all_string_values, get_boolean_value, get_string_value, parse_rdf_list},
-    vocab::sh,
+    utils::parse_rdf_list,
+    vocab::{sh, shx, skos},
 };
 
-use self::{path::parse_path, target::parse_targets};
+pub use self::dataset_constraint::parse_dataset_constraints;
+use self::{
+    path::parse_path, shape_index::ShapeIndex, shape_registry::ShapeRegistry, target::parse_targets,
+};
 
 /// Parses all SHACL shapes from a graph.
-pub fn parse_shapes(graph: &Graph) -> Result<Vec<Shape<'_>>, ShaclError> {
+pub fn parse_shapes(graph: &Graph) -> Result<Vec<Arc<Shape<'_>>>, ShaclError> {
     debug!("Starting shape parsing");
 
     #[cfg(not(target_family = "wasm"))]
@@ -33,7 +45,8 @@ pub fn parse_shapes(graph: &Graph) -> Result<Vec<Shape<'_>>, ShaclError> {
     let mut shapes = Vec::new();
     let mut visited = HashSet::new();
 
-    let shape_nodes = find_shape_nodes(graph);
+    let registry = ShapeRegistry::new(graph);
+    let shape_nodes = registry.index().shape_nodes();
     debug!("Found {} shape nodes", shape_nodes.len());
 
     for shape_node in shape_nodes {
@@ -43,7 +56,7 @@ pub fn parse_shapes(graph: &Graph) -> Result<Vec<Shape<'_>>, ShaclError> {
         visited.insert(shape_node);
 
         debug!("Parsing shape: {}", shape_node);
-        match parse_shape(graph, shape_node, None) {
+        match registry.shape(shape_node) {
             Ok(shape) => {
                 debug!("Successfully parsed shape: {}", shape_node);
                 shapes.push(shape);
@@ -58,33 +71,63 @@ pub fn parse_shapes(graph: &Graph) -> Result<Vec<Shape<'_>>, ShaclError> {
     debug!("Finished shape parsing at {}", time.elapsed().as_secs_f64());
 
     debug!("Total shapes parsed: {}", shapes.len());
+    debug!("Named shapes parsed: {}", registry.len());
     Ok(shapes)
 }
 
 /// Returns nodes that look like SHACL shapes.
-fn find_shape_nodes(graph: &Graph) -> HashSet<NamedOrBlankNodeRef<'_>> {
-    let mut shape_nodes = HashSet::new();
+pub(crate) fn find_shape_nodes(graph: &Graph) -> HashSet<NamedOrBlankNodeRef<'_>> {
+    ShapeIndex::new(graph).shape_nodes()
+}
 
-    for shape_type in &[sh::NODE_SHAPE, sh::PROPERTY_SHAPE, sh::SHAPE] {
-        shape_nodes.extend(graph.subjects_for_predicate_object(rdf::TYPE, *shape_type));
+/// Splits a graph holding both data and shapes into its data graph and its
+/// shapes graph.
+///
+/// The shapes graph gets the triples of every shape, constraint component,
+/// SPARQL function and prefix declaration, along with the blank nodes they
+/// use; the data graph gets all other triples. Class declarations and
+/// `rdfs:subClassOf` triples of shapes that are also classes go to both, so
+/// instances of their subclasses still match `sh:class` and class targets.
+pub fn split_shapes(graph: &Graph) -> (Graph, Graph) {
+    let mut roots: Vec<NamedOrBlankNodeRef<'_>> =
+        well_formed::reachable_shapes(graph, find_shape_nodes(graph).into_iter());
+    for definition_type in [sh::CONSTRAINT_COMPONENT, sh::SPARQL_FUNCTION] {
+        roots.extend(graph.subjects_for_predicate_object(rdf::TYPE, definition_type));
     }
+    roots.extend(
+        graph
+            .triples_for_predicate(sh::DECLARE)
+            .map(|triple| triple.subject),
+    );
 
-    let shape_defining_predicates = &[
-        sh::TARGET_CLASS,
-        sh::TARGET_NODE,
-        sh::TARGET_SUBJECTS_OF,
-        sh::TARGET_OBJECTS_OF,
-        sh::TARGET,
-    ];
-
-    for predicate in shape_defining_predicates {
-        let subjects = graph
-            .triples_for_predicate(*predicate)
-            .map(|triple| triple.subject);
-        shape_nodes.extend(subjects);
+    let mut visited: HashSet<NamedOrBlankNodeRef<'_>> = HashSet::new();
+    let mut shapes_graph = Graph::new();
+    while let Some(subject) = roots.pop() {
+        if !visited.insert(subject) {
+            continue;
+        }
+        for triple in graph.triples_for_subject(subject) {
+            if let TermRef::BlankNode(blank_node) = triple.object {
+                roots.push(blank_node.into());
+            }
+            shapes_graph.insert(triple);
+        }
     }
 
-    shape_nodes
+    let is_class_hierarchy = |triple: TripleRef<'_>| match triple.object {
+        _ if triple.predicate == rdfs::SUB_CLASS_OF => true,
+        TermRef::NamedNode(class) if triple.predicate == rdf::TYPE => {
+            !class.as_str().starts_with(sh::SHACL_NAMESPACE)
+        }
+        _ => false,
+    };
+    let mut data_graph = Graph::new();
+    data_graph.extend(
+        graph
+            .iter()
+            .filter(|&triple| !shapes_graph.contains(triple) || is_class_hierarchy(triple)),
+    );
+    (data_graph, shapes_graph)
 }
 
 fn parse_named_or_blank_node<'a>(term: TermRef<'a>) -> Option<NamedOrBlankNodeRef<'a>> {
@@ -96,43 +139,85 @@ fn parse_named_or_blank_node<'a>(term: TermRef<'a>) -> Option<NamedOrBlankNodeRe
 }
 
 fn parse_severity<'a>(
-    graph: &'a Graph,
+    index: &ShapeIndex<'a>,
     node: NamedOrBlankNodeRef<'a>,
-    default: NamedNodeRef<'a>,
-) -> NamedNodeRef<'a> {
-    graph
-        .object_for_subject_predicate(node, sh::SEVERITY)
+    default: Severity<'a>,
+) -> Severity<'a> {
+    index
+        .object(node, sh::SEVERITY)
         .and_then(|term| match term {
-            TermRef::NamedNode(nn) => Some(nn),
+            TermRef::NamedNode(nn) => Some(Severity::from_iri(nn)),
             _ => None,
         })
         .unwrap_or(default)
 }
 
 fn apply_common_shape_properties<'a>(
-    graph: &'a Graph,
+    index: &ShapeIndex<'a>,
     node: NamedOrBlankNodeRef<'a>,
     parent: Option<NamedOrBlankNodeRef<'a>>,
     mut shape: Shape<'a>,
 ) -> Shape<'a> {
-    if let Some(name) = get_string_value(graph, node, sh::NAME)
-        .or_else(|| get_string_value(graph, node, rdfs::LABEL))
+    if let Some(name) = index
+        .string(node, sh::NAME)
+        .or_else(|| index.string(node, rdfs::LABEL))
     {
         shape = shape.with_name(name);
     }
 
-    if let Some(desc) = get_string_value(graph, node, sh::DESCRIPTION) {
+    if let Some(desc) = index.string(node, sh::DESCRIPTION) {
         shape = shape.with_description(desc);
     }
 
-    if let Some(deactivated) = get_boolean_value(graph, node, sh::DEACTIVATED) {
+    if let Some(deactivated) = index.boolean(node, sh::DEACTIVATED) {
         shape = shape.with_deactivated(deactivated);
     }
 
-    for message in get_all_string_values(graph, node, sh::MESSAGE) {
+    for message in index.strings(node, sh::MESSAGE) {
         shape = shape.add_message(message);
     }
 
+    // Property shapes without their own requirement belong to their parent's
+    let mut requirements = index.strings(node, shx::REQUIREMENT);
+    if requirements.is_empty() {
+        if let Some(p) = parent {
+            requirements = index.strings(p, shx::REQUIREMENT);
+        }
+    }
+    requirements.sort();
+    for requirement in requirements {
+        shape = shape.add_requirement(requirement);
+    }
+
+    // Property shapes without their own guidance use their parent's
+    let mut documentation = parse_documentation(index, node);
+    if documentation.is_empty() {
+        if let Some(p) = parent {
+            documentation = parse_documentation(index, p);
+        }
+    }
+    shape = shape.with_documentation(documentation);
+
+    // Property shapes without their own transformations use their parent's
+    let mut transforms = index.strings(node, shx::VALUE_TRANSFORM);
+    if transforms.is_empty() {
+        if let Some(p) = parent {
+            transforms = index.strings(p, shx::VALUE_TRANSFORM);
+        }
+    }
+    for name in transforms {
+        match ValueTransform::from_name(&name) {
+            Some(transform) => shape = shape.add_value_transform(transform),
+            None => log::warn!("Ignoring unknown value transform '{}' on {}", name, node),
+        }
+    }
+
+    match index.object(node, shx::UNIT) {
+        Some(TermRef::NamedNode(unit)) => shape = shape.with_unit(unit),
+        Some(other) => log::warn!("Ignoring shx:unit {} on {}: not an IRI", other, node),
+        None => {}
+    }
+
     if let Some(p) = parent {
         shape = shape.with_parent(p);
     }
@@ -140,17 +225,54 @@ fn apply_common_shape_properties<'a>(
     shape
 }
 
+/// `skos:definition` (or else `rdfs:comment`) and `rdfs:seeAlso` of `node`.
+fn parse_documentation<'a>(index: &ShapeIndex<'a>, node: NamedOrBlankNodeRef<'a>) -> Documentation {
+    Documentation {
+        help_text: index
+            .string(node, skos::DEFINITION)
+            .or_else(|| index.string(node, rdfs::COMMENT)),
+        documentation_url: match index.object(node, rdfs::SEE_ALSO) {
+            Some(TermRef::NamedNode(url)) => Some(url.as_str().to_string()),
+            _ => None,
+        },
+    }
+}
+
+/// Records the documentation of the custom constraint components used by `shape`.
+fn with_component_documentation<'a>(index: &ShapeIndex<'a>, mut shape: Shape<'a>) -> Shape<'a> {
+    let components: Vec<NamedNodeRef<'a>> = shape
+        .constraints
+        .iter()
+        .filter_map(|constraint| match constraint.component() {
+            ConstraintComponent::Other(component) => Some(component),
+            _ => None,
+        })
+        .collect();
+    for component in components {
+        let documentation = parse_documentation(index, component.into());
+        if !documentation.is_empty() {
+            shape = shape.add_component_documentation(component, documentation);
+        }
+    }
+    shape
+}
+
 fn parse_nested_property_shapes<'a>(
-    graph: &'a Graph,
+    shapes: &ShapeRegistry<'a>,
     node: NamedOrBlankNodeRef<'a>,
-    parent_severity: NamedNodeRef<'a>,
+    parent_severity: Severity<'a>,
     parent: Option<NamedOrBlankNodeRef<'a>>,
 ) -> Vec<Shape<'a>> {
-    graph
-        .objects_for_subject_predicate(node, sh::PROPERTY)
+    let index = shapes.index();
+    index
+        .objects(node, sh::PROPERTY)
         .filter_map(parse_named_or_blank_node)
         .filter_map(|nested_prop_node| {
-            parse_property_shape(graph, nested_prop_node, parent_severity, parent).ok()
+            parse_property_shape(shapes, nested_prop_node, parent_severity, parent)
+                .map_err(|e| {
+                    log::warn!("Failed to parse property shape {}: {}", nested_prop_node, e);
+                })
+                .ok()
         })
         .collect()
 }
@@ -161,49 +283,61 @@ pub fn parse_shape<'a>(
     node: NamedOrBlankNodeRef<'a>,
     parent: Option<NamedOrBlankNodeRef<'a>>,
 ) -> Result<Shape<'a>, ShaclError> {
+    parse_shape_with(&ShapeRegistry::new(graph), node, parent)
+}
+
+pub(crate) fn parse_shape_with<'a>(
+    shapes: &ShapeRegistry<'a>,
+    node: NamedOrBlankNodeRef<'a>,
+    parent: Option<NamedOrBlankNodeRef<'a>>,
+) -> Result<Shape<'a>, ShaclError> {
+    let index = shapes.index();
     // Check if this shape has sh:path - if so, it's a property shape with targets
-    if let Some(path_obj) = graph.object_for_subject_predicate(node, sh::PATH) {
-        return parse_top_level_property_shape(graph, node, path_obj, parent);
+    if let Some(path_obj) = index.object(node, sh::PATH) {
+        return parse_top_level_property_shape(shapes, node, path_obj, parent);
     }
 
-    let severity = parse_severity(graph, node, sh::VIOLATION);
+    let severity = parse_severity(index, node, Severity::Violation);
 
-    parse_node_shape_internal(graph, node, severity, true, parent)
+    parse_node_shape_internal(shapes, node, severity, true, parent)
 }
 
 /// Parse a top-level property shape (a property shape with targets)
 fn parse_top_level_property_shape<'a>(
-    graph: &'a Graph,
+    shapes: &ShapeRegistry<'a>,
     node: NamedOrBlankNodeRef<'a>,
     path_obj: TermRef<'a>,
     parent: Option<NamedOrBlankNodeRef<'a>>,
 ) -> Result<Shape<'a>, ShaclError> {
+    let graph = shapes.graph();
+    let index = shapes.index();
     // Parse the path
     let path = parse_path(graph, path_obj)?;
 
-    let severity = parse_severity(graph, node, sh::VIOLATION);
+    let severity = parse_severity(index, node, Severity::Violation);
 
     // Create property shape with the path
     let mut shape = apply_common_shape_properties(
-        graph,
+        index,
         node,
         parent,
         Shape::property_shape(node, path, severity),
     );
 
     // Parse targets (top-level property shapes can have targets)
-    for target in parse_targets(graph, node) {
+    for target in parse_targets(index, node) {
         shape = shape.add_target(target);
     }
 
     // Parse all constraints
-    let constraints = parse_all_constraints(graph, node, true)?;
+    let constraints = parse_all_constraints(shapes, node, true)?;
     for constraint in constraints {
         shape = shape.add_constraint(constraint);
     }
+    shape = with_component_documentation(index, shape);
 
     // Parse nested property shapes (sh:property on property shapes)
-    for nested_prop_shape in parse_nested_property_shapes(graph, node, severity, Some(node)) {
+    for nested_prop_shape in parse_nested_property_shapes(shapes, node, severity, Some(node)) {
         shape = shape
             .add_property_shape(nested_prop_shape)
             .with_parent(node);
@@ -214,14 +348,15 @@ fn parse_top_level_property_shape<'a>(
 
 /// Parse a closed constraint
 fn parse_closed_constraint<'a>(
-    graph: &'a Graph,
+    shapes: &ShapeRegistry<'a>,
     node: NamedOrBlankNodeRef<'a>,
 ) -> Option<ClosedConstraint<'a>> {
-    if let Some(true) = get_boolean_value(graph, node, sh::CLOSED) {
+    let (graph, index) = (shapes.graph(), shapes.index());
+    if let Some(true) = index.boolean(node, sh::CLOSED) {
         let mut ignored_properties = Vec::new();
 
         // Parse sh:ignoredProperties (should be an RDF list)
-        if let Some(list_node) = graph.object_for_subject_predicate(node, sh::IGNORED_PROPERTIES) {
+        if let Some(list_node) = index.object(node, sh::IGNORED_PROPERTIES) {
             let list_node_ref = match list_node {
                 TermRef::NamedNode(nn) => NamedOrBlankNodeRef::NamedNode(nn),
                 _ => return Some(ClosedConstraint { ignored_properties }), // Invalid ignoredProperties definition, treat as empty
@@ -242,50 +377,54 @@ fn parse_closed_constraint<'a>(
 
 /// Internal helper to parse node shapes with or without targets
 fn parse_node_shape_internal<'a>(
-    graph: &'a Graph,
+    shapes: &ShapeRegistry<'a>,
     node: NamedOrBlankNodeRef<'a>,
-    severity: NamedNodeRef<'a>,
+    severity: Severity<'a>,
     include_targets: bool,
     parent: Option<NamedOrBlankNodeRef<'a>>,
 ) -> Result<Shape<'a>, ShaclError> {
+    let index = shapes.index();
     let mut shape =
-        apply_common_shape_properties(graph, node, parent, Shape::node_shape(node, severity));
+        apply_common_shape_properties(index, node, parent, Shape::node_shape(node, severity));
 
     // Parse targets (only for top-level shapes)
     if include_targets {
-        for target in parse_targets(graph, node) {
+        for target in parse_targets(index, node) {
             shape = shape.add_target(target);
         }
     }
 
     // Parse closed constraint
-    if let Some(closed) = parse_closed_constraint(graph, node) {
+    if let Some(closed) = parse_closed_constraint(shapes, node) {
         shape = shape.with_closed(closed);
     }
 
     // Parse property shapes (sh:property)
-    for prop_shape in parse_nested_property_shapes(graph, node, severity, Some(node)) {
+    for prop_shape in parse_nested_property_shapes(shapes, node, severity, Some(node)) {
         shape = shape.add_property_shape(prop_shape).with_parent(node);
     }
 
     // Parse node-level constraints
-    let node_constraints = parse_all_constraints(graph, node, false)?;
+    let node_constraints = parse_all_constraints(shapes, node, false)?;
     for constraint in node_constraints {
         shape = shape.add_constraint(constraint)
     }
+    shape = with_component_documentation(index, shape);
 
     Ok(shape)
 }
 
 /// Parse a property shape (a shape with sh:path)
 fn parse_property_shape<'a>(
-    graph: &'a Graph,
+    shapes: &ShapeRegistry<'a>,
     node: NamedOrBlankNodeRef<'a>,
-    parent_severity: NamedNodeRef<'a>,
+    parent_severity: Severity<'a>,
     parent: Option<NamedOrBlankNodeRef<'a>>,
 ) -> Result<Shape<'a>, ShaclError> {
+    let graph = shapes.graph();
+    let index = shapes.index();
     // Parse the path
-    let path = if let Some(path_obj) = graph.object_for_subject_predicate(node, sh::PATH) {
+    let path = if let Some(path_obj) = index.object(node, sh::PATH) {
         parse_path(graph, path_obj)?
     } else {
         // No path means this is a node constraint, not a property constraint
@@ -294,10 +433,10 @@ fn parse_property_shape<'a>(
         ));
     };
 
-    let severity = parse_severity(graph, node, parent_severity);
+    let severity = parse_severity(index, node, parent_severity);
 
     // Parse constraints
-    let constraints = parse_all_constraints(graph, node, true)?;
+    let constraints = parse_all_constraints(shapes, node, true)?;
 
     // Create property shape
     let mut prop_shape = Shape::property_shape(node, path, severity);
@@ -307,10 +446,11 @@ fn parse_property_shape<'a>(
         prop_shape = prop_shape.add_constraint(constraint);
     }
 
-    prop_shape = apply_common_shape_properties(graph, node, parent, prop_shape);
+    prop_shape = apply_common_shape_properties(index, node, parent, prop_shape);
+    prop_shape = with_component_documentation(index, prop_shape);
 
     // Parse nested property shapes (sh:property on property shapes)
-    for nested_prop_shape in parse_nested_property_shapes(graph, node, severity, Some(node)) {
+    for nested_prop_shape in parse_nested_property_shapes(shapes, node, severity, Some(node)) {
         prop_shape = prop_shape.add_property_shape(nested_prop_shape);
     }
 
@@ -319,41 +459,60 @@ fn parse_property_shape<'a>(
 
 /// Parse all constraints from a shape node by calling individual constraint parsers
 fn parse_all_constraints<'a>(
-    graph: &'a Graph,
+    shapes: &ShapeRegistry<'a>,
     node: NamedOrBlankNodeRef<'a>,
     is_property_shape: bool,
 ) -> Result<Vec<Constraint<'a>>, ShaclError> {
-    let mut constraints = Vec::new();
+    let parsers = [
+        constraints::class::parser(),
+        constraints::datatype::parser(),
+        constraints::node_kind::parser(),
+        constraints::min_count::parser(),
+        constraints::max_count::parser(),
+        constraints::min_length::parser(),
+        constraints::max_length::parser(),
+        constraints::pattern::parser(),
+        constraints::total_digits::parser(),
+        constraints::fraction_digits::parser(),
+        constraints::relative_time::parser(),
+        constraints::namespace_in::parser(),
+        constraints::uri_template::parser(),
+        constraints::identifier_format::parser(),
+        constraints::min_inclusive::parser(),
+        constraints::max_inclusive::parser(),
+        constraints::min_exclusive::parser(),
+        constraints::max_exclusive::parser(),
+        constraints::language_in::parser(),
+        constraints::unique_lang::parser(),
+        constraints::equals::parser(),
+        constraints::disjoint::parser(),
+        constraints::less_than::parser(),
+        constraints::less_than_or_equals::parser(),
+        constraints::has_value::parser(),
+        constraints::sh_in::parser(),
+        constraints::sh_node::parser(),
+        constraints::qualified_value_shape::parser(),
+        constraints::unique_value_for_class::parser(),
+        constraints::sh_and::parser(),
+        constraints::sh_or::parser(),
+        constraints::sh_xone::parser(),
+        constraints::sh_not::parser(),
+        constraints::expression::parser(),
+    ];
 
-    // Call each constraint parser in order
-    constraints.extend(constraints::class::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::datatype::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::node_kind::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::min_count::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::max_count::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::min_length::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::max_length::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::pattern::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::min_inclusive::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::max_inclusive::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::min_exclusive::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::max_exclusive::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::language_in::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::unique_lang::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::equals::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::disjoint::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::less_than::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::less_than_or_equals::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::has_value::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::sh_in::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::sh_node::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::qualified_value_shape::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::sh_and::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::sh_or::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::sh_xone::parser().parse_constraint(node, graph)?);
-    constraints.extend(constraints::sh_not::parser().parse_constraint(node, graph)?);
+    let predicates: Vec<NamedNodeRef<'a>> = shapes.index().predicates(node).collect();
+    let mut constraints = Vec::new();
+    for parser in parsers {
+        if parser
+            .parameters()
+            .iter()
+            .any(|parameter| predicates.contains(parameter))
+        {
+            constraints.extend(parser.parse_constraint(node, shapes)?);
+        }
+    }
     constraints.extend(constraints::sparql::parse_sparql_constraints(
-        graph,
+        shapes,
         node,
         is_property_shape,
     )?);
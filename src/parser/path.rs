@@ -62,6 +62,17 @@ fn parse_path_element<'a>(
     graph: &'a Graph,
     node: NamedOrBlankNodeRef<'a>,
 ) -> Result<PathElement<'a>, ShaclError> {
+    if graph
+        .object_for_subject_predicate(node, rdf::FIRST)
+        .is_some()
+    {
+        let steps = parse_rdf_list(graph, node)
+            .into_iter()
+            .map(|step| parse_nested_element(graph, step, "sequence path"))
+            .collect::<Result<_, _>>()?;
+        return Ok(PathElement::Sequence(steps));
+    }
+
     if let Some(TermRef::NamedNode(iri)) =
         graph.object_for_subject_predicate(node, sh::INVERSE_PATH)
     {
@@ -92,41 +103,17 @@ fn parse_path_element<'a>(
 
     if let Some(zero_or_more_obj) = graph.object_for_subject_predicate(node, sh::ZERO_OR_MORE_PATH)
     {
-        let inner_elem = match zero_or_more_obj {
-            TermRef::NamedNode(iri) => PathElement::Iri(iri),
-            TermRef::BlankNode(bn) => parse_path_element(graph, NamedOrBlankNodeRef::from(bn))?,
-            _ => {
-                return Err(ShaclError::Parse(
-                    "Invalid path in sh:zeroOrMorePath".to_string(),
-                ))
-            }
-        };
+        let inner_elem = parse_nested_element(graph, zero_or_more_obj, "sh:zeroOrMorePath")?;
         return Ok(PathElement::ZeroOrMore(Box::new(inner_elem)));
     }
 
     if let Some(one_or_more_obj) = graph.object_for_subject_predicate(node, sh::ONE_OR_MORE_PATH) {
-        let inner_elem = match one_or_more_obj {
-            TermRef::NamedNode(iri) => PathElement::Iri(iri),
-            TermRef::BlankNode(bn) => parse_path_element(graph, NamedOrBlankNodeRef::from(bn))?,
-            _ => {
-                return Err(ShaclError::Parse(
-                    "Invalid path in sh:oneOrMorePath".to_string(),
-                ))
-            }
-        };
+        let inner_elem = parse_nested_element(graph, one_or_more_obj, "sh:oneOrMorePath")?;
         return Ok(PathElement::OneOrMore(Box::new(inner_elem)));
     }
 
     if let Some(zero_or_one_obj) = graph.object_for_subject_predicate(node, sh::ZERO_OR_ONE_PATH) {
-        let inner_elem = match zero_or_one_obj {
-            TermRef::NamedNode(iri) => PathElement::Iri(iri),
-            TermRef::BlankNode(bn) => parse_path_element(graph, NamedOrBlankNodeRef::from(bn))?,
-            _ => {
-                return Err(ShaclError::Parse(
-                    "Invalid path in sh:zeroOrOnePath".to_string(),
-                ))
-            }
-        };
+        let inner_elem = parse_nested_element(graph, zero_or_one_obj, "sh:zeroOrOnePath")?;
         return Ok(PathElement::ZeroOrOne(Box::new(inner_elem)));
     }
 
@@ -134,3 +121,16 @@ fn parse_path_element<'a>(
         "Could not parse path element".to_string(),
     ))
 }
+
+/// Parses a path nested in `context`: an IRI, a sequence list or a path node.
+fn parse_nested_element<'a>(
+    graph: &'a Graph,
+    term: TermRef<'a>,
+    context: &str,
+) -> Result<PathElement<'a>, ShaclError> {
+    match term {
+        TermRef::NamedNode(iri) => Ok(PathElement::Iri(iri)),
+        TermRef::BlankNode(bn) => parse_path_element(graph, NamedOrBlankNodeRef::from(bn)),
+        _ => Err(ShaclError::Parse(format!("Invalid path in {}", context))),
+    }
+}
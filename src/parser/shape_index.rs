@@ -0,0 +1,162 @@
+//! Index of the shapes graph built in one pass, shared by shape discovery and
+//! the constraint parsers.
+
+use std::collections::{HashMap, HashSet};
+
+use oxigraph::model::{vocab::rdf, Graph, NamedNodeRef, NamedOrBlankNodeRef, TermRef};
+
+use crate::vocab::sh;
+
+/// Predicates that make their subject a shape.
+const TARGET_PREDICATES: &[NamedNodeRef<'static>] = &[
+    sh::TARGET_CLASS,
+    sh::TARGET_NODE,
+    sh::TARGET_SUBJECTS_OF,
+    sh::TARGET_OBJECTS_OF,
+    sh::TARGET,
+];
+
+/// The predicates and objects of every subject of a graph.
+///
+/// Parsers look shape parameters up here instead of querying the graph once
+/// per parameter.
+pub struct ShapeIndex<'a> {
+    subjects: HashMap<NamedOrBlankNodeRef<'a>, Vec<(NamedNodeRef<'a>, TermRef<'a>)>>,
+}
+
+impl<'a> ShapeIndex<'a> {
+    pub fn new(graph: &'a Graph) -> Self {
+        let mut subjects: HashMap<_, Vec<_>> = HashMap::new();
+        let mut current: Option<(NamedOrBlankNodeRef<'a>, Vec<_>)> = None;
+        // Triples come grouped by subject, so each subject is hashed once.
+        for triple in graph.iter() {
+            match &mut current {
+                Some((subject, properties)) if *subject == triple.subject => {
+                    properties.push((triple.predicate, triple.object))
+                }
+                _ => {
+                    if let Some((subject, properties)) = current.take() {
+                        subjects.entry(subject).or_default().extend(properties);
+                    }
+                    current = Some((triple.subject, vec![(triple.predicate, triple.object)]));
+                }
+            }
+        }
+        if let Some((subject, properties)) = current {
+            subjects.entry(subject).or_default().extend(properties);
+        }
+        Self { subjects }
+    }
+
+    /// The predicates and objects of `node`.
+    fn properties(&self, node: NamedOrBlankNodeRef<'a>) -> &[(NamedNodeRef<'a>, TermRef<'a>)] {
+        self.subjects.get(&node).map_or(&[], Vec::as_slice)
+    }
+
+    /// Nodes that look like SHACL shapes: instances of a shape class and
+    /// subjects of a target predicate.
+    pub fn shape_nodes(&self) -> HashSet<NamedOrBlankNodeRef<'a>> {
+        self.subjects
+            .iter()
+            .filter(|(_, properties)| {
+                properties.iter().any(|&(predicate, object)| {
+                    (predicate == rdf::TYPE
+                        && (object == sh::NODE_SHAPE.into()
+                            || object == sh::PROPERTY_SHAPE.into()
+                            || object == sh::SHAPE.into()))
+                        || TARGET_PREDICATES.contains(&predicate)
+                })
+            })
+            .map(|(&node, _)| node)
+            .collect()
+    }
+
+    /// The predicates of `node`, with repeats.
+    pub fn predicates(
+        &self,
+        node: NamedOrBlankNodeRef<'a>,
+    ) -> impl Iterator<Item = NamedNodeRef<'a>> + '_ {
+        self.properties(node)
+            .iter()
+            .map(|&(predicate, _)| predicate)
+    }
+
+    /// Whether `node` has at least one `predicate` value.
+    pub fn has(&self, node: NamedOrBlankNodeRef<'a>, predicate: NamedNodeRef<'_>) -> bool {
+        self.properties(node)
+            .iter()
+            .any(|&(candidate, _)| candidate == predicate)
+    }
+
+    /// All `predicate` values of `node`.
+    pub fn objects(
+        &self,
+        node: NamedOrBlankNodeRef<'a>,
+        predicate: NamedNodeRef<'a>,
+    ) -> impl Iterator<Item = TermRef<'a>> + '_ {
+        self.properties(node)
+            .iter()
+            .filter(move |&&(candidate, _)| candidate == predicate)
+            .map(|&(_, object)| object)
+    }
+
+    /// A `predicate` value of `node`.
+    pub fn object(
+        &self,
+        node: NamedOrBlankNodeRef<'a>,
+        predicate: NamedNodeRef<'a>,
+    ) -> Option<TermRef<'a>> {
+        self.objects(node, predicate).next()
+    }
+
+    /// A `predicate` value of `node` as a string; IRIs keep their brackets.
+    pub fn string(
+        &self,
+        node: NamedOrBlankNodeRef<'a>,
+        predicate: NamedNodeRef<'a>,
+    ) -> Option<String> {
+        self.object(node, predicate).and_then(|term| match term {
+            TermRef::Literal(lit) => Some(lit.value().to_string()),
+            TermRef::NamedNode(nn) => Some(nn.to_string()),
+            _ => None,
+        })
+    }
+
+    /// The literal `predicate` values of `node`.
+    pub fn strings(
+        &self,
+        node: NamedOrBlankNodeRef<'a>,
+        predicate: NamedNodeRef<'a>,
+    ) -> Vec<String> {
+        self.objects(node, predicate)
+            .filter_map(|term| match term {
+                TermRef::Literal(lit) => Some(lit.value().to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// A `predicate` value of `node` as a boolean.
+    pub fn boolean(
+        &self,
+        node: NamedOrBlankNodeRef<'a>,
+        predicate: NamedNodeRef<'a>,
+    ) -> Option<bool> {
+        self.object(node, predicate).and_then(|term| match term {
+            TermRef::Literal(lit) => lit.value().parse().ok(),
+            _ => None,
+        })
+    }
+
+    /// A `predicate` value of `node` as an integer.
+    pub fn integer(
+        &self,
+        node: NamedOrBlankNodeRef<'a>,
+        predicate: NamedNodeRef<'a>,
+    ) -> Option<i32> {
+        self.object(node, predicate).and_then(|term| match term {
+            TermRef::Literal(lit) => lit.value().parse().ok(),
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,85 @@
+//! Shapes referenced from shape-based constraints, shared between their uses.
+
+use std::{cell::RefCell, collections::HashMap, sync::Arc};
+
+use oxigraph::model::{Graph, NamedNodeRef, NamedOrBlankNodeRef};
+
+use crate::{core::shape::Shape, err::ShaclError};
+
+use super::shape_index::ShapeIndex;
+
+/// Parses named shapes on their first reference and hands out the same
+/// instance to every later `sh:node`, `sh:not`, `sh:and`, `sh:or`, `sh:xone`
+/// or `sh:qualifiedValueShape` that refers to them. Top-level shapes from
+/// [`parse_shapes`](super::parse_shapes) come from the registry too, so they
+/// are the same instance as their references.
+///
+/// Named shapes are parsed on their own, so they do not inherit requirements
+/// or value transforms from the shapes referring to them. Blank node shapes
+/// can only be used in one place and are parsed there, as before.
+pub struct ShapeRegistry<'a> {
+    graph: &'a Graph,
+    index: ShapeIndex<'a>,
+    shapes: RefCell<HashMap<NamedNodeRef<'a>, Arc<Shape<'a>>>>,
+}
+
+impl<'a> ShapeRegistry<'a> {
+    pub fn new(graph: &'a Graph) -> Self {
+        Self {
+            graph,
+            index: ShapeIndex::new(graph),
+            shapes: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn graph(&self) -> &'a Graph {
+        self.graph
+    }
+
+    /// The graph indexed by subject and predicate.
+    pub fn index(&self) -> &ShapeIndex<'a> {
+        &self.index
+    }
+
+    /// The shape `node` refers to, as used from the shape `referrer`.
+    pub fn resolve(
+        &self,
+        node: NamedOrBlankNodeRef<'a>,
+        referrer: NamedOrBlankNodeRef<'a>,
+    ) -> Result<Arc<Shape<'a>>, ShaclError> {
+        match node {
+            NamedOrBlankNodeRef::NamedNode(name) => self.named(name),
+            NamedOrBlankNodeRef::BlankNode(_) => {
+                super::parse_shape_with(self, node, Some(referrer)).map(Arc::new)
+            }
+        }
+    }
+
+    /// The top-level shape `node`, shared with every reference to it.
+    pub fn shape(&self, node: NamedOrBlankNodeRef<'a>) -> Result<Arc<Shape<'a>>, ShaclError> {
+        match node {
+            NamedOrBlankNodeRef::NamedNode(name) => self.named(name),
+            NamedOrBlankNodeRef::BlankNode(_) => {
+                super::parse_shape_with(self, node, None).map(Arc::new)
+            }
+        }
+    }
+
+    fn named(&self, name: NamedNodeRef<'a>) -> Result<Arc<Shape<'a>>, ShaclError> {
+        if let Some(shape) = self.shapes.borrow().get(&name) {
+            return Ok(Arc::clone(shape));
+        }
+        let shape = Arc::new(super::parse_shape_with(self, name.into(), None)?);
+        self.shapes.borrow_mut().insert(name, Arc::clone(&shape));
+        Ok(shape)
+    }
+
+    /// Number of named shapes parsed so far.
+    pub fn len(&self) -> usize {
+        self.shapes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.borrow().is_empty()
+    }
+}
@@ -2,17 +2,19 @@
 
 use oxigraph::model::{
     vocab::{rdf, rdfs},
-    Graph, NamedOrBlankNodeRef, TermRef,
+    NamedOrBlankNodeRef, TermRef,
 };
 
 use crate::{core::target::Target, vocab::sh};
 
+use super::shape_index::ShapeIndex;
+
 /// Parses targets for a shape node.
-pub fn parse_targets<'a>(graph: &'a Graph, node: NamedOrBlankNodeRef<'a>) -> Vec<Target<'a>> {
+pub fn parse_targets<'a>(index: &ShapeIndex<'a>, node: NamedOrBlankNodeRef<'a>) -> Vec<Target<'a>> {
     let mut targets = Vec::new();
 
-    let is_class = graph
-        .objects_for_subject_predicate(node, rdf::TYPE)
+    let is_class = index
+        .objects(node, rdf::TYPE)
         .filter_map(|term_ref| match term_ref {
             TermRef::NamedNode(nn) => Some(nn),
             _ => None,
@@ -23,7 +25,7 @@ pub fn parse_targets<'a>(graph: &'a Graph, node: NamedOrBlankNodeRef<'a>) -> Vec
         targets.push(Target::Class(node));
     }
 
-    for obj in graph.objects_for_subject_predicate(node, sh::TARGET_CLASS) {
+    for obj in index.objects(node, sh::TARGET_CLASS) {
         let target = match obj {
             TermRef::NamedNode(nn) => Target::Class(NamedOrBlankNodeRef::NamedNode(nn)),
             TermRef::BlankNode(bn) => Target::Class(NamedOrBlankNodeRef::BlankNode(bn)),
@@ -32,27 +34,36 @@ pub fn parse_targets<'a>(graph: &'a Graph, node: NamedOrBlankNodeRef<'a>) -> Vec
         targets.push(target);
     }
 
-    for obj in graph.objects_for_subject_predicate(node, sh::TARGET_NODE) {
+    for obj in index.objects(node, sh::TARGET_NODE) {
         targets.push(Target::Node(obj));
     }
 
-    for obj in graph.objects_for_subject_predicate(node, sh::TARGET_SUBJECTS_OF) {
+    for obj in index.objects(node, sh::TARGET_SUBJECTS_OF) {
         if let TermRef::NamedNode(prop) = obj {
             targets.push(Target::SubjectsOf(prop));
         }
     }
 
-    for obj in graph.objects_for_subject_predicate(node, sh::TARGET_OBJECTS_OF) {
+    for obj in index.objects(node, sh::TARGET_OBJECTS_OF) {
         if let TermRef::NamedNode(prop) = obj {
             targets.push(Target::ObjectsOf(prop));
         }
     }
 
-    for obj in graph.objects_for_subject_predicate(node, sh::TARGET) {
-        match obj {
-            TermRef::NamedNode(nn) => targets.push(Target::Advanced(nn.into())),
-            TermRef::BlankNode(bn) => targets.push(Target::Advanced(bn.into())),
-            TermRef::Literal(_) => {}
+    for obj in index.objects(node, sh::TARGET) {
+        let target = match obj {
+            TermRef::NamedNode(nn) => NamedOrBlankNodeRef::from(nn),
+            TermRef::BlankNode(bn) => NamedOrBlankNodeRef::from(bn),
+            TermRef::Literal(_) => continue,
+        };
+        let is_sparql = index
+            .objects(target, rdf::TYPE)
+            .any(|t| t == sh::SPARQL_TARGET.into())
+            || index.has(target, sh::SELECT);
+        if is_sparql {
+            targets.push(Target::Sparql(target));
+        } else {
+            targets.push(Target::Advanced(target));
         }
     }
 
@@ -0,0 +1,307 @@
+//! Strict syntax checks for shapes graphs.
+//!
+//! The regular parser is lenient and skips what it does not understand. These
+//! checks report violations of the SHACL syntax rules instead, so reports can
+//! state whether the shapes graph was well-formed.
+
+use std::{
+    collections::HashSet,
+    fmt::{Display, Formatter},
+};
+
+use oxigraph::model::{
+    vocab::{rdf, xsd},
+    Graph, NamedNodeRef, NamedOrBlankNodeRef, TermRef,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    parser::{parse_shape_with, shape_registry::ShapeRegistry},
+    utils,
+    vocab::sh,
+};
+
+/// A violation of the SHACL syntax rules.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct WellFormednessIssue {
+    /// Shape node the issue was found on.
+    pub node: String,
+    /// Parameter the issue is about, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<String>,
+    pub message: String,
+    /// How the shapes graph could be fixed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl Display for WellFormednessIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.node, self.message)
+    }
+}
+
+/// Parameters whose values must be `xsd:integer` literals.
+const INTEGER_PARAMETERS: &[NamedNodeRef<'static>] = &[
+    sh::MIN_COUNT,
+    sh::MAX_COUNT,
+    sh::MIN_LENGTH,
+    sh::MAX_LENGTH,
+    sh::QUALIFIED_MIN_COUNT,
+    sh::QUALIFIED_MAX_COUNT,
+];
+
+/// Parameters whose values must be `xsd:boolean` literals.
+const BOOLEAN_PARAMETERS: &[NamedNodeRef<'static>] = &[
+    sh::DEACTIVATED,
+    sh::CLOSED,
+    sh::UNIQUE_LANG,
+    sh::QUALIFIED_VALUE_SHAPES_DISJOINT,
+];
+
+/// Parameters whose values must be IRIs.
+const IRI_PARAMETERS: &[NamedNodeRef<'static>] = &[
+    sh::CLASS,
+    sh::DATATYPE,
+    sh::NODE_KIND_PROPERTY,
+    sh::SEVERITY,
+    sh::TARGET_CLASS,
+    sh::TARGET_SUBJECTS_OF,
+    sh::TARGET_OBJECTS_OF,
+];
+
+/// Parameters whose values must be literals.
+const LITERAL_PARAMETERS: &[NamedNodeRef<'static>] = &[
+    sh::PATTERN,
+    sh::FLAGS,
+    sh::MIN_EXCLUSIVE,
+    sh::MIN_INCLUSIVE,
+    sh::MAX_EXCLUSIVE,
+    sh::MAX_INCLUSIVE,
+];
+
+/// Parameters whose values must be well-formed SHACL lists.
+const LIST_PARAMETERS: &[NamedNodeRef<'static>] = &[
+    sh::IN,
+    sh::LANGUAGE_IN,
+    sh::AND,
+    sh::OR,
+    sh::XONE,
+    sh::IGNORED_PROPERTIES,
+];
+
+/// Parameters a shape may have at most one value for.
+const SINGLE_VALUED_PARAMETERS: &[NamedNodeRef<'static>] = &[
+    sh::PATH,
+    sh::MIN_COUNT,
+    sh::MAX_COUNT,
+    sh::MIN_LENGTH,
+    sh::MAX_LENGTH,
+    sh::QUALIFIED_MIN_COUNT,
+    sh::QUALIFIED_MAX_COUNT,
+    sh::DEACTIVATED,
+    sh::CLOSED,
+    sh::UNIQUE_LANG,
+    sh::QUALIFIED_VALUE_SHAPES_DISJOINT,
+    sh::DATATYPE,
+    sh::NODE_KIND_PROPERTY,
+    sh::FLAGS,
+    sh::MIN_EXCLUSIVE,
+    sh::MIN_INCLUSIVE,
+    sh::MAX_EXCLUSIVE,
+    sh::MAX_INCLUSIVE,
+    sh::IN,
+    sh::LANGUAGE_IN,
+    sh::IGNORED_PROPERTIES,
+];
+
+const NODE_KINDS: &[NamedNodeRef<'static>] = &[
+    sh::BLANK_NODE,
+    sh::IRI,
+    sh::LITERAL,
+    sh::BLANK_NODE_OR_IRI,
+    sh::BLANK_NODE_OR_LITERAL,
+    sh::IRI_OR_LITERAL,
+];
+
+/// Checks every shape in `graph` against the SHACL syntax rules.
+///
+/// Returns an empty list when the shapes graph is well-formed.
+pub fn check_well_formed(graph: &Graph) -> Vec<WellFormednessIssue> {
+    let mut issues = Vec::new();
+    let registry = ShapeRegistry::new(graph);
+    let top_level = registry.index().shape_nodes();
+
+    for node in reachable_shapes(graph, top_level.iter().copied()) {
+        let mut issue =
+            |predicate: Option<NamedNodeRef<'_>>, message: String, suggestion: Option<String>| {
+                issues.push(WellFormednessIssue {
+                    node: node.to_string(),
+                    predicate: predicate.map(|predicate| predicate.to_string()),
+                    message,
+                    suggestion,
+                })
+            };
+
+        if top_level.contains(&node) {
+            if let Err(error) = parse_shape_with(&registry, node, None) {
+                issue(None, format!("Shape cannot be parsed: {}", error), None);
+            }
+        }
+
+        for &parameter in SINGLE_VALUED_PARAMETERS {
+            let count = graph.objects_for_subject_predicate(node, parameter).count();
+            if count > 1 {
+                issue(
+                    Some(parameter),
+                    format!("{} has {} values, at most one is allowed", parameter, count),
+                    Some(format!("Keep a single value of {}", parameter)),
+                );
+            }
+        }
+
+        for triple in graph.triples_for_subject(node) {
+            let (parameter, value) = (triple.predicate, triple.object);
+
+            if let Some(expected) = unmet_expectation(graph, parameter, value) {
+                issue(
+                    Some(parameter),
+                    format!("{} value {} must be {}", parameter, value, expected),
+                    Some(format!("Replace {} with {}", value, expected)),
+                );
+                continue;
+            }
+
+            if let TermRef::Literal(pattern) = value {
+                if parameter == sh::PATTERN {
+                    let flags = utils::get_string_value(graph, node, sh::FLAGS);
+                    if let Err(error) = utils::pattern_regex(pattern.value(), flags.as_deref()) {
+                        issue(
+                            Some(parameter),
+                            error,
+                            Some(
+                                "Use a valid regex and only the flags i, m, s, x and q".to_string(),
+                            ),
+                        );
+                    }
+                }
+            }
+
+            if parameter == sh::NODE_KIND_PROPERTY
+                && !matches!(value, TermRef::NamedNode(kind) if NODE_KINDS.contains(&kind))
+            {
+                issue(
+                    Some(parameter),
+                    format!("{} is not a valid sh:nodeKind", value),
+                    Some(format!(
+                        "Use one of {}",
+                        NODE_KINDS
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )),
+                );
+            }
+        }
+    }
+
+    issues.sort();
+    issues.dedup();
+    issues
+}
+
+/// Describes what `value` should have been, if it is not a valid value of `parameter`.
+fn unmet_expectation(
+    graph: &Graph,
+    parameter: NamedNodeRef<'_>,
+    value: TermRef<'_>,
+) -> Option<&'static str> {
+    let (expected, valid) = if INTEGER_PARAMETERS.contains(&parameter) {
+        ("an xsd:integer literal", has_datatype(value, xsd::INTEGER))
+    } else if BOOLEAN_PARAMETERS.contains(&parameter) {
+        ("an xsd:boolean literal", has_datatype(value, xsd::BOOLEAN))
+    } else if IRI_PARAMETERS.contains(&parameter) {
+        ("an IRI", matches!(value, TermRef::NamedNode(_)))
+    } else if LITERAL_PARAMETERS.contains(&parameter) {
+        ("a literal", matches!(value, TermRef::Literal(_)))
+    } else if LIST_PARAMETERS.contains(&parameter) {
+        (
+            "a well-formed SHACL list",
+            is_well_formed_list(graph, value),
+        )
+    } else {
+        return None;
+    };
+    (!valid).then_some(expected)
+}
+
+/// All shapes reachable from `roots` through shape-valued parameters.
+pub(crate) fn reachable_shapes<'a>(
+    graph: &'a Graph,
+    roots: impl Iterator<Item = NamedOrBlankNodeRef<'a>>,
+) -> Vec<NamedOrBlankNodeRef<'a>> {
+    let mut visited: HashSet<NamedOrBlankNodeRef<'a>> = HashSet::new();
+    let mut pending: Vec<_> = roots.collect();
+
+    while let Some(node) = pending.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+        for parameter in [sh::PROPERTY, sh::NODE, sh::NOT, sh::QUALIFIED_VALUE_SHAPE] {
+            pending.extend(
+                graph
+                    .objects_for_subject_predicate(node, parameter)
+                    .filter_map(utils::term_to_named_or_blank),
+            );
+        }
+        for parameter in [sh::AND, sh::OR, sh::XONE] {
+            for list in graph.objects_for_subject_predicate(node, parameter) {
+                if let Some(list) = utils::term_to_named_or_blank(list) {
+                    pending.extend(
+                        utils::parse_rdf_list(graph, list)
+                            .into_iter()
+                            .filter_map(utils::term_to_named_or_blank),
+                    );
+                }
+            }
+        }
+    }
+
+    let mut shapes: Vec<_> = visited.into_iter().collect();
+    shapes.sort_by_key(|node| node.to_string());
+    shapes
+}
+
+fn has_datatype(term: TermRef<'_>, datatype: NamedNodeRef<'_>) -> bool {
+    matches!(term, TermRef::Literal(literal) if literal.datatype() == datatype)
+}
+
+/// True if `head` is `rdf:nil` or a cycle-free chain of nodes with exactly one
+/// `rdf:first` and one `rdf:rest` each, ending in `rdf:nil`.
+fn is_well_formed_list(graph: &Graph, head: TermRef<'_>) -> bool {
+    let mut visited = HashSet::new();
+    let mut current = head;
+
+    loop {
+        if current == rdf::NIL.into() {
+            return true;
+        }
+        let Some(node) = utils::term_to_named_or_blank(current) else {
+            return false;
+        };
+        if !visited.insert(node)
+            || graph
+                .objects_for_subject_predicate(node, rdf::FIRST)
+                .count()
+                != 1
+            || graph.objects_for_subject_predicate(node, rdf::REST).count() != 1
+        {
+            return false;
+        }
+        match graph.object_for_subject_predicate(node, rdf::REST) {
+            Some(rest) => current = rest,
+            None => return false,
+        }
+    }
+}
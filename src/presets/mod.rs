@@ -0,0 +1,78 @@
+//! Embedded shape bundles for well-known vocabularies.
+//!
+//! Enabled with the `presets` feature.
+
+use std::fmt::{Display, Formatter};
+
+use oxigraph::model::Graph;
+
+use crate::{err::ShaclError, rdf::read_graph_from_string, validation::report::ReportProvenance};
+
+/// A bundled set of shapes for a standard application profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Preset {
+    /// DCAT application profile for data portals.
+    DcatAp,
+    /// SKOS integrity conditions.
+    Skos,
+    /// Common schema.org types.
+    SchemaOrg,
+}
+
+impl Preset {
+    /// All available presets.
+    pub const ALL: [Preset; 3] = [Preset::DcatAp, Preset::Skos, Preset::SchemaOrg];
+
+    /// Looks up a preset by its name, e.g. `dcat-ap`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Preset::DcatAp => "dcat-ap",
+            Preset::Skos => "skos",
+            Preset::SchemaOrg => "schema-org",
+        }
+    }
+
+    /// Version of the profile the bundled shapes follow.
+    pub fn version(&self) -> &'static str {
+        match self {
+            Preset::DcatAp => "3.0",
+            Preset::Skos => "2009-08-18",
+            Preset::SchemaOrg => "29.0",
+        }
+    }
+
+    /// The bundled shapes as Turtle.
+    pub fn shapes_turtle(&self) -> &'static str {
+        match self {
+            Preset::DcatAp => include_str!("dcat-ap.ttl"),
+            Preset::Skos => include_str!("skos.ttl"),
+            Preset::SchemaOrg => include_str!("schema-org.ttl"),
+        }
+    }
+
+    /// Parses the bundled shapes into a graph.
+    pub fn load_graph(&self) -> Result<Graph, ShaclError> {
+        read_graph_from_string(self.shapes_turtle(), "turtle")
+    }
+
+    /// Provenance describing this preset, for attaching to reports.
+    pub fn provenance(&self) -> ReportProvenance {
+        ReportProvenance {
+            shapes_version: Some(self.version().to_string()),
+            shapes_source: Some(format!("preset:{}", self.name())),
+            ..ReportProvenance::default()
+        }
+    }
+}
+
+impl Display for Preset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -1,10 +1,19 @@
-use std::io::BufReader;
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    io::{BufReader, Write},
+};
 
 use oxigraph::{
     io::{RdfFormat, RdfParser},
-    model::Triple,
+    model::{
+        graph::{CanonicalizationAlgorithm, CanonicalizationHashAlgorithm},
+        BlankNodeRef, Graph, NamedOrBlankNodeRef, TermRef, Triple, TripleRef,
+    },
 };
 
+use regex::{Captures, Regex};
+use sha2::{Digest, Sha256};
+
 use crate::err::ShaclError;
 
 fn normalize_rdf_format(file_format: &str) -> String {
@@ -18,6 +27,16 @@ fn normalize_rdf_format(file_format: &str) -> String {
     }
 }
 
+/// The RDF format named by a file extension or a common name such as `turtle`.
+pub fn parse_rdf_format(file_format: &str) -> Result<RdfFormat, ShaclError> {
+    RdfFormat::from_extension(&normalize_rdf_format(file_format)).ok_or_else(|| {
+        ShaclError::Parse(format!(
+            "Unsupported file extension: '{}'. Supported: ttl (turtle), nt (n-triples), nq (n-quads), rdf (rdfxml/xml), jsonld (json-ld), trig",
+            file_format
+        ))
+    })
+}
+
 pub fn read_graph_from_string(
     graph_string: &str,
     file_format: &str,
@@ -27,21 +46,29 @@ pub fn read_graph_from_string(
     read_graph_using_reader_with_base(reader, file_format, "http://example.org")
 }
 
+/// Reads a graph from a string, resolving relative IRIs against `base_iri`.
+pub fn read_graph_from_string_with_base(
+    graph_string: &str,
+    file_format: &str,
+    base_iri: &str,
+) -> Result<oxigraph::model::Graph, ShaclError> {
+    log::debug!(
+        "Reading graph from string, format: {}, base: {}",
+        file_format,
+        base_iri
+    );
+    let reader = BufReader::new(graph_string.as_bytes());
+    read_graph_using_reader_with_base(reader, file_format, base_iri)
+}
+
 fn read_graph_using_reader_with_base<R: std::io::Read>(
     reader: BufReader<R>,
     file_format: &str,
     base_iri: &str,
 ) -> Result<oxigraph::model::Graph, ShaclError> {
-    let normalized_format = normalize_rdf_format(file_format);
-
     let mut graph = oxigraph::model::Graph::new();
 
-    let format = RdfFormat::from_extension(&normalized_format).ok_or_else(|| {
-        ShaclError::Parse(format!(
-            "Unsupported file extension: '{}'. Supported: ttl (turtle), nt (n-triples), nq (n-quads), rdf (rdfxml/xml), jsonld (json-ld), trig",
-            file_format
-        ))
-    })?;
+    let format = parse_rdf_format(file_format)?;
 
     let parser = RdfParser::from_format(format);
     let quads = parser
@@ -56,15 +83,163 @@ fn read_graph_using_reader_with_base<R: std::io::Read>(
     Ok(graph)
 }
 
+/// Replaces the `{{NAME}}` placeholders of a graph document with `variables`,
+/// so one shapes graph can be loaded with different namespaces or limits.
+///
+/// Placeholders without a value are an error rather than left in the text.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use shacl_rust::rdf::substitute_variables;
+///
+/// let variables = HashMap::from([("MAX_LABELS".to_string(), "3".to_string())]);
+/// let shapes = substitute_variables("[] sh:maxCount {{ MAX_LABELS }} .", &variables).unwrap();
+/// assert_eq!(shapes, "[] sh:maxCount 3 .");
+/// assert!(substitute_variables("<{{BASE_NS}}Shape>", &variables).is_err());
+/// ```
+pub fn substitute_variables(
+    text: &str,
+    variables: &HashMap<String, String>,
+) -> Result<String, ShaclError> {
+    let placeholder = Regex::new(r"\{\{\s*([A-Za-z_][A-Za-z0-9_]*)\s*\}\}")
+        .expect("placeholder pattern is valid");
+    let mut missing = BTreeSet::new();
+    let substituted = placeholder.replace_all(text, |captures: &Captures<'_>| {
+        match variables.get(&captures[1]) {
+            Some(value) => value.clone(),
+            None => {
+                missing.insert(captures[1].to_string());
+                captures[0].to_string()
+            }
+        }
+    });
+    if !missing.is_empty() {
+        let missing: Vec<_> = missing.into_iter().collect();
+        return Err(ShaclError::Parse(format!(
+            "No value for template variables: {}",
+            missing.join(", ")
+        )));
+    }
+    Ok(substituted.into_owned())
+}
+
+/// Parses `KEY=VALUE` lines of a variables file for [`substitute_variables`].
+///
+/// Blank lines and lines starting with `#` are skipped; values are kept as
+/// written after the first `=`.
+pub fn parse_variables(text: &str) -> Result<HashMap<String, String>, ShaclError> {
+    let mut variables = HashMap::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim_start();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line.split_once('=').ok_or_else(|| {
+            ShaclError::Parse(format!(
+                "Line {} is not a KEY=VALUE definition: {}",
+                number + 1,
+                line
+            ))
+        })?;
+        variables.insert(key.trim().to_string(), value.to_string());
+    }
+    Ok(variables)
+}
+
+/// SHA-256 of the canonical N-Triples of `graph`, equal for graphs that only
+/// differ in their blank node labels.
+///
+/// Blank nodes are relabelled with [RDFC-1.0](https://www.w3.org/TR/rdf-canon/),
+/// so hashes do not change between oxigraph versions. Canonicalizing can be
+/// slow on graphs with many blank nodes that look alike.
+///
+/// ```
+/// use shacl_rust::rdf::{graph_hash, read_graph_from_string};
+///
+/// let a = read_graph_from_string("<http://example.org/a> <http://example.org/p> [] .", "ttl").unwrap();
+/// let b = read_graph_from_string("<http://example.org/a> <http://example.org/p> _:other .", "ttl").unwrap();
+/// assert_eq!(graph_hash(&a), graph_hash(&b));
+/// ```
+pub fn graph_hash(graph: &Graph) -> [u8; 32] {
+    let mut canonical = graph.clone();
+    canonical.canonicalize(CanonicalizationAlgorithm::Rdfc10 {
+        hash_algorithm: CanonicalizationHashAlgorithm::Sha256,
+    });
+    let mut lines: Vec<String> = canonical.iter().map(|t| t.to_string()).collect();
+    lines.sort();
+
+    let mut hasher = Sha256::new();
+    for line in lines {
+        hasher.update(line.as_bytes());
+        hasher.update(b" .\n");
+    }
+    hasher.finalize().into()
+}
+
+/// [`graph_hash`] as lowercase hex.
+pub fn graph_hash_hex(graph: &Graph) -> String {
+    graph_hash(graph)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
 pub fn serialize_graph_to_string(
     graph: &oxigraph::model::Graph,
     rdf_format: RdfFormat,
 ) -> Result<String, ShaclError> {
-    let mut output = Vec::new();
-    let mut serializer = oxigraph::io::RdfSerializer::from_format(rdf_format)
+    let output = serialize_graph_to_writer(graph, rdf_format, Vec::new())?;
+
+    String::from_utf8(output)
+        .map_err(|e| ShaclError::Io(format!("Failed to serialize graph: {}", e)))
+}
+
+/// Serializes a graph triple by triple into `writer` and returns the writer.
+pub fn serialize_graph_to_writer<W: Write>(
+    graph: &oxigraph::model::Graph,
+    rdf_format: RdfFormat,
+    writer: W,
+) -> Result<W, ShaclError> {
+    let serializer = oxigraph::io::RdfSerializer::from_format(rdf_format)
         .with_prefix("sh", "http://www.w3.org/ns/shacl#")
-        .unwrap()
-        .for_writer(&mut output);
+        .unwrap();
+    write_graph(graph, serializer, writer)
+}
+
+/// Serializes a graph declaring `prefixes`, as (name, namespace) pairs, and
+/// writing IRIs relative to `base_iri` where the format supports it.
+pub fn serialize_graph_with_prefixes(
+    graph: &oxigraph::model::Graph,
+    rdf_format: RdfFormat,
+    prefixes: &[(String, String)],
+    base_iri: Option<&str>,
+) -> Result<String, ShaclError> {
+    let mut serializer = oxigraph::io::RdfSerializer::from_format(rdf_format);
+    for (name, namespace) in prefixes {
+        serializer = serializer.with_prefix(name, namespace).map_err(|e| {
+            ShaclError::Parse(format!(
+                "Invalid namespace '{}' of prefix '{}': {}",
+                namespace, name, e
+            ))
+        })?;
+    }
+    if let Some(base_iri) = base_iri {
+        serializer = serializer
+            .with_base_iri(base_iri)
+            .map_err(|e| ShaclError::Parse(format!("Invalid base IRI '{}': {}", base_iri, e)))?;
+    }
+
+    let output = write_graph(graph, serializer, Vec::new())?;
+    String::from_utf8(output)
+        .map_err(|e| ShaclError::Io(format!("Failed to serialize graph: {}", e)))
+}
+
+fn write_graph<W: Write>(
+    graph: &oxigraph::model::Graph,
+    serializer: oxigraph::io::RdfSerializer,
+    writer: W,
+) -> Result<W, ShaclError> {
+    let mut serializer = serializer.for_writer(writer);
 
     for triple in graph.iter() {
         serializer
@@ -74,8 +249,330 @@ pub fn serialize_graph_to_string(
 
     serializer
         .finish()
-        .map_err(|e| ShaclError::Io(format!("Failed to finalize serialized graph: {}", e)))?;
+        .map_err(|e| ShaclError::Io(format!("Failed to finalize serialized graph: {}", e)))
+}
 
-    String::from_utf8(output)
-        .map_err(|e| ShaclError::Io(format!("Failed to serialize graph: {}", e)))
+/// Concise bounded description of `node`, also describing IRI objects up to `depth` hops.
+///
+/// Blank node objects are always expanded, as in a regular CBD. A depth of zero
+/// yields an empty graph.
+pub fn describe_node(graph: &Graph, node: NamedOrBlankNodeRef<'_>, depth: usize) -> Graph {
+    let mut description = Graph::new();
+    let mut visited = HashSet::from([node]);
+    let mut pending = vec![(node, depth)];
+
+    while let Some((subject, depth)) = pending.pop() {
+        if depth == 0 {
+            continue;
+        }
+        for triple in graph.triples_for_subject(subject) {
+            description.insert(triple);
+            let (object, next_depth) = match triple.object {
+                TermRef::BlankNode(b) => (NamedOrBlankNodeRef::from(b), depth),
+                TermRef::NamedNode(n) => (NamedOrBlankNodeRef::from(n), depth - 1),
+                _ => continue,
+            };
+            if visited.insert(object) {
+                pending.push((object, next_depth));
+            }
+        }
+    }
+
+    description
+}
+
+/// Triples that differ between two graphs, see [`diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GraphDelta {
+    /// Triples of the new graph that the old one lacks.
+    pub added: Graph,
+    /// Triples of the old graph that the new one lacks.
+    pub removed: Graph,
+}
+
+impl GraphDelta {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// How [`diff_with`] decides that a blank node of the old graph is a blank
+/// node of the new graph.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlankNodeMatching {
+    /// Blank nodes with the same description are the same node, whatever
+    /// their labels. A blank node whose description changed is removed with
+    /// its triples and added again.
+    #[default]
+    Description,
+    /// Blank nodes with the same label are the same node, as for two
+    /// versions read from the same store.
+    Label,
+}
+
+/// Triples added and removed from `old` to `new`, matching blank nodes by
+/// their description.
+///
+/// ```
+/// use shacl_rust::rdf::{diff, read_graph_from_string};
+///
+/// let old = read_graph_from_string(
+///     "<http://example.org/a> <http://example.org/p> [ <http://example.org/q> 1 ] .",
+///     "ttl",
+/// ).unwrap();
+/// let new = read_graph_from_string(
+///     "<http://example.org/a> <http://example.org/p> [ <http://example.org/q> 1 ] ;
+///         <http://example.org/r> 2 .",
+///     "ttl",
+/// ).unwrap();
+/// let delta = diff(&old, &new);
+/// assert_eq!(delta.added.len(), 1);
+/// assert!(delta.removed.is_empty());
+/// ```
+pub fn diff(old: &Graph, new: &Graph) -> GraphDelta {
+    diff_with(old, new, BlankNodeMatching::default())
+}
+
+/// Triples added and removed from `old` to `new`, matching blank nodes as
+/// `matching` says. Blank nodes in the delta keep the labels of the graph
+/// the triple comes from.
+pub fn diff_with(old: &Graph, new: &Graph, matching: BlankNodeMatching) -> GraphDelta {
+    let (old_to_new, new_to_old) = match matching {
+        BlankNodeMatching::Label => {
+            return GraphDelta {
+                added: new.iter().filter(|t| !old.contains(*t)).collect(),
+                removed: old.iter().filter(|t| !new.contains(*t)).collect(),
+            }
+        }
+        BlankNodeMatching::Description => match_blank_nodes(old, new),
+    };
+    GraphDelta {
+        added: unmatched_triples(new, old, &new_to_old),
+        removed: unmatched_triples(old, new, &old_to_new),
+    }
+}
+
+type BlankNodeMap<'a, 'b> = HashMap<BlankNodeRef<'a>, BlankNodeRef<'b>>;
+
+/// Pairs the blank nodes of `old` and `new` whose descriptions hash alike.
+///
+/// Blank nodes with the same description are told apart by refining their
+/// hashes with their incoming and outgoing edges, round after round, until no
+/// round splits them further. Nodes that agree on more rounds are paired
+/// first; nodes that still cannot be told apart are paired in any order.
+fn match_blank_nodes<'a, 'b>(
+    old: &'a Graph,
+    new: &'b Graph,
+) -> (BlankNodeMap<'a, 'b>, BlankNodeMap<'b, 'a>) {
+    let mut old_hashes = description_hashes(old);
+    let mut new_hashes = description_hashes(new);
+    // Each round that continues splits a class in one of the graphs, so this
+    // stops after at most as many rounds as there are blank nodes.
+    while refine_hashes(old, &mut old_hashes) | refine_hashes(new, &mut new_hashes) {}
+
+    let mut old_to_new = HashMap::new();
+    let mut new_to_old = HashMap::new();
+    let mut candidates = group_by_round(new_hashes.keys().copied(), &new_hashes, 0);
+    for (hash, old_nodes) in group_by_round(old_hashes.keys().copied(), &old_hashes, 0) {
+        let Some(new_nodes) = candidates.remove(&hash) else {
+            continue;
+        };
+        let mut pairs = Vec::new();
+        pair_alike(
+            old_nodes,
+            new_nodes,
+            1,
+            &old_hashes,
+            &new_hashes,
+            &mut pairs,
+        );
+        for (old_node, new_node) in pairs {
+            old_to_new.insert(old_node, new_node);
+            new_to_old.insert(new_node, old_node);
+        }
+    }
+    (old_to_new, new_to_old)
+}
+
+/// Hashes of a blank node, one per refinement round.
+type RoundHashes<'a> = HashMap<BlankNodeRef<'a>, Vec<[u8; 32]>>;
+
+/// Round 0: hashes each blank node by its own outgoing triples, with blank
+/// objects left unnamed. [`refine_hashes`] spreads the neighbourhood from there.
+fn description_hashes(graph: &Graph) -> RoundHashes<'_> {
+    blank_nodes(graph)
+        .into_iter()
+        .map(|node| {
+            let mut edges: Vec<String> = graph
+                .triples_for_subject(node)
+                .map(|t| match t.object {
+                    TermRef::BlankNode(_) => format!("{} _", t.predicate),
+                    object => format!("{} {}", t.predicate, object),
+                })
+                .collect();
+            edges.sort_unstable();
+
+            let mut hasher = Sha256::new();
+            for edge in edges {
+                hasher.update(edge.as_bytes());
+                hasher.update(b"\n");
+            }
+            (node, vec![<[u8; 32]>::from(hasher.finalize())])
+        })
+        .collect()
+}
+
+/// Adds a round hashing each blank node with the edges around it, neighbours
+/// hashed as of the last round. Returns whether the round split any class.
+fn refine_hashes<'a>(graph: &'a Graph, hashes: &mut RoundHashes<'a>) -> bool {
+    let Some(round) = hashes.values().next().map(|h| h.len() - 1) else {
+        return false;
+    };
+    let label = |term: TermRef<'_>| match term {
+        TermRef::BlankNode(node) => hashes[&node][round].to_vec(),
+        term => term.to_string().into_bytes(),
+    };
+
+    let refined: Vec<_> = hashes
+        .keys()
+        .map(|&node| {
+            let mut edges: Vec<Vec<u8>> = graph
+                .triples_for_subject(node)
+                .map(|t| {
+                    [
+                        b"> ",
+                        t.predicate.as_str().as_bytes(),
+                        b" ",
+                        &label(t.object),
+                    ]
+                    .concat()
+                })
+                .chain(graph.triples_for_object(node).map(|t| {
+                    [
+                        b"< ",
+                        t.predicate.as_str().as_bytes(),
+                        b" ",
+                        &label(t.subject.into()),
+                    ]
+                    .concat()
+                }))
+                .collect();
+            edges.sort_unstable();
+
+            let mut hasher = Sha256::new();
+            hasher.update(hashes[&node][round]);
+            for edge in edges {
+                hasher.update(&edge);
+                hasher.update(b"\n");
+            }
+            (node, <[u8; 32]>::from(hasher.finalize()))
+        })
+        .collect();
+
+    let before = class_count(hashes, round);
+    for (node, hash) in refined {
+        hashes.get_mut(&node).expect("refined node").push(hash);
+    }
+    class_count(hashes, round + 1) > before
+}
+
+fn class_count(hashes: &RoundHashes<'_>, round: usize) -> usize {
+    hashes
+        .values()
+        .map(|h| h[round])
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+fn group_by_round<'a>(
+    nodes: impl IntoIterator<Item = BlankNodeRef<'a>>,
+    hashes: &RoundHashes<'a>,
+    round: usize,
+) -> HashMap<[u8; 32], Vec<BlankNodeRef<'a>>> {
+    let mut groups: HashMap<_, Vec<_>> = HashMap::new();
+    for node in nodes {
+        groups.entry(hashes[&node][round]).or_default().push(node);
+    }
+    groups
+}
+
+/// Pairs nodes that hash alike up to `round`, preferring nodes that also hash
+/// alike in later rounds, and returns the nodes left over on either side.
+fn pair_alike<'a, 'b>(
+    old_nodes: Vec<BlankNodeRef<'a>>,
+    new_nodes: Vec<BlankNodeRef<'b>>,
+    round: usize,
+    old_hashes: &RoundHashes<'a>,
+    new_hashes: &RoundHashes<'b>,
+    pairs: &mut Vec<(BlankNodeRef<'a>, BlankNodeRef<'b>)>,
+) -> (Vec<BlankNodeRef<'a>>, Vec<BlankNodeRef<'b>>) {
+    let rounds = old_hashes[&old_nodes[0]].len();
+    let (mut old_rest, mut new_rest) = if round == rounds {
+        (old_nodes, new_nodes)
+    } else {
+        let mut candidates = group_by_round(new_nodes, new_hashes, round);
+        let mut old_rest = Vec::new();
+        for (hash, old_group) in group_by_round(old_nodes, old_hashes, round) {
+            match candidates.remove(&hash) {
+                Some(new_group) => {
+                    let (old_left, new_left) = pair_alike(
+                        old_group,
+                        new_group,
+                        round + 1,
+                        old_hashes,
+                        new_hashes,
+                        pairs,
+                    );
+                    old_rest.extend(old_left);
+                    candidates.insert(hash, new_left);
+                }
+                None => old_rest.extend(old_group),
+            }
+        }
+        (old_rest, candidates.into_values().flatten().collect())
+    };
+
+    let paired = old_rest.len().min(new_rest.len());
+    pairs.extend(old_rest.drain(..paired).zip(new_rest.drain(..paired)));
+    (old_rest, new_rest)
+}
+
+fn blank_nodes(graph: &Graph) -> HashSet<BlankNodeRef<'_>> {
+    graph
+        .iter()
+        .flat_map(|triple| [triple.subject.into(), triple.object])
+        .filter_map(|term| match term {
+            TermRef::BlankNode(blank_node) => Some(blank_node),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Triples of `graph` that are not in `other` once their blank nodes are
+/// mapped with `matched`; a triple with an unmatched blank node never is.
+fn unmatched_triples<'a, 'b>(
+    graph: &'a Graph,
+    other: &'b Graph,
+    matched: &BlankNodeMap<'a, 'b>,
+) -> Graph {
+    graph
+        .iter()
+        .filter(|triple| {
+            let subject = match triple.subject {
+                NamedOrBlankNodeRef::BlankNode(b) => match matched.get(&b) {
+                    Some(&b) => b.into(),
+                    None => return true,
+                },
+                subject => subject,
+            };
+            let object = match triple.object {
+                TermRef::BlankNode(b) => match matched.get(&b) {
+                    Some(&b) => b.into(),
+                    None => return true,
+                },
+                object => object,
+            };
+            !other.contains(TripleRef::new(subject, triple.predicate, object))
+        })
+        .collect()
 }
@@ -0,0 +1,204 @@
+//! Versioned shape registries.
+//!
+//! A registry is a directory holding several releases of a shapes graph. Each
+//! release is either a subdirectory named after its version, whose RDF files are
+//! merged, or a single RDF file declaring an `owl:Ontology` with `owl:versionInfo`.
+
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+
+use oxigraph::{
+    io::RdfFormat,
+    model::{vocab::rdf, Graph, TermRef, Triple},
+};
+
+use crate::{
+    err::ShaclError, rdf::read_graph_from_string, validation::report::ReportProvenance, vocab::owl,
+};
+
+/// A directory of versioned shape releases.
+#[derive(Debug, Clone)]
+pub struct ShapesRegistry {
+    root: PathBuf,
+    /// Releases sorted by ascending version.
+    releases: Vec<ShapesRelease>,
+}
+
+/// One release of the shapes in a registry.
+#[derive(Debug, Clone)]
+pub struct ShapesRelease {
+    version: String,
+    files: Vec<PathBuf>,
+}
+
+impl ShapesRegistry {
+    /// Scans `root` for shape releases.
+    pub fn from_dir(root: &Path) -> Result<Self, ShaclError> {
+        let mut releases: Vec<ShapesRelease> = Vec::new();
+
+        for entry in read_dir_sorted(root)? {
+            if entry.is_dir() {
+                let Some(version) = entry.file_name().and_then(|n| n.to_str()) else {
+                    continue;
+                };
+                let files: Vec<PathBuf> = read_dir_sorted(&entry)?
+                    .into_iter()
+                    .filter(|f| rdf_extension(f).is_some())
+                    .collect();
+                if files.is_empty() {
+                    log::warn!("Skipping shapes release {} without RDF files", version);
+                    continue;
+                }
+                releases.push(ShapesRelease {
+                    version: version.to_string(),
+                    files,
+                });
+            } else if rdf_extension(&entry).is_some() {
+                let graph = read_graph_from_path(&entry)?;
+                match ontology_version(&graph) {
+                    Some(version) => releases.push(ShapesRelease {
+                        version,
+                        files: vec![entry],
+                    }),
+                    None => log::debug!(
+                        "Ignoring {} without owl:versionInfo in shapes registry",
+                        entry.display()
+                    ),
+                }
+            }
+        }
+
+        releases.sort_by(|a, b| compare_versions(&a.version, &b.version));
+        if let Some(pair) = releases.windows(2).find(|w| w[0].version == w[1].version) {
+            return Err(ShaclError::Parse(format!(
+                "Shapes registry {} declares version {} more than once",
+                root.display(),
+                pair[0].version
+            )));
+        }
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            releases,
+        })
+    }
+
+    pub fn get_root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the available versions in ascending order.
+    pub fn versions(&self) -> Vec<&str> {
+        self.releases.iter().map(|r| r.version.as_str()).collect()
+    }
+
+    /// Returns the release with the highest version.
+    pub fn latest(&self) -> Option<&ShapesRelease> {
+        self.releases.last()
+    }
+
+    /// Returns the release for `version`, or the latest one when `None`.
+    pub fn release(&self, version: Option<&str>) -> Result<&ShapesRelease, ShaclError> {
+        let release = match version {
+            Some(version) => self.releases.iter().find(|r| r.version == version),
+            None => self.latest(),
+        };
+
+        release.ok_or_else(|| {
+            ShaclError::Parse(format!(
+                "Shapes version {} not found in registry {} (available: {})",
+                version.unwrap_or("latest"),
+                self.root.display(),
+                self.versions().join(", ")
+            ))
+        })
+    }
+}
+
+impl ShapesRelease {
+    pub fn get_version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn get_files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Reads and merges all files of the release into one shapes graph.
+    pub fn load_graph(&self) -> Result<Graph, ShaclError> {
+        let mut graph = Graph::new();
+        for file in &self.files {
+            graph.extend(read_graph_from_path(file)?.iter().map(Triple::from));
+        }
+        Ok(graph)
+    }
+
+    /// Provenance describing this release, for attaching to reports.
+    pub fn provenance(&self, registry: &ShapesRegistry) -> ReportProvenance {
+        ReportProvenance {
+            shapes_version: Some(self.version.clone()),
+            shapes_source: Some(registry.root.display().to_string()),
+            ..ReportProvenance::default()
+        }
+    }
+}
+
+/// Compares dotted versions numerically where both parts are numbers.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let mut left = a.split(['.', '-']);
+    let mut right = b.split(['.', '-']);
+
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) => {
+                let ordering = match (l.parse::<u64>(), r.parse::<u64>()) {
+                    (Ok(l), Ok(r)) => l.cmp(&r),
+                    _ => l.cmp(r),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+fn ontology_version(graph: &Graph) -> Option<String> {
+    graph
+        .subjects_for_predicate_object(rdf::TYPE, owl::ONTOLOGY)
+        .find_map(|ontology| {
+            match graph.object_for_subject_predicate(ontology, owl::VERSION_INFO)? {
+                TermRef::Literal(literal) => Some(literal.value().to_string()),
+                _ => None,
+            }
+        })
+}
+
+fn rdf_extension(path: &Path) -> Option<&str> {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .filter(|e| RdfFormat::from_extension(e).is_some())
+}
+
+fn read_graph_from_path(path: &Path) -> Result<Graph, ShaclError> {
+    let format = rdf_extension(path).ok_or_else(|| {
+        ShaclError::Parse(format!("Cannot determine RDF format of {}", path.display()))
+    })?;
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| ShaclError::Io(format!("Failed to read {}: {}", path.display(), e)))?;
+    read_graph_from_string(&content, format)
+}
+
+fn read_dir_sorted(dir: &Path) -> Result<Vec<PathBuf>, ShaclError> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| ShaclError::Io(format!("Failed to read {}: {}", dir.display(), e)))?;
+    let mut paths = entries
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| ShaclError::Io(format!("Failed to read {}: {}", dir.display(), e)))?;
+    paths.sort();
+    Ok(paths)
+}
@@ -0,0 +1,338 @@
+//! SHACL-AF rules: `sh:TripleRule` and `sh:SPARQLRule`.
+//!
+//! Rules are attached to shapes with `sh:rule` and run on the focus nodes of
+//! their shape that conform to all `sh:condition` shapes. They run in
+//! `sh:order` in passes over the data as it was at the start of the pass,
+//! until a pass infers nothing new.
+
+use std::collections::HashMap;
+
+use log::debug;
+use oxigraph::{
+    model::{
+        vocab::rdf, BlankNode, Graph, NamedOrBlankNode, NamedOrBlankNodeRef, Term, TermRef, Triple,
+        TripleRef, Variable,
+    },
+    sparql::QueryResults,
+};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    core::{expression::NodeExpression, shape::Shape, target::Target},
+    err::ShaclError,
+    parser::{
+        expression::parse_node_expression_with, parse_shape_with, shape_registry::ShapeRegistry,
+        target::parse_targets,
+    },
+    utils::{self, get_boolean_value, get_string_value, parse_shacl_prefixes},
+    validation::{
+        constraints::sparql::parse_query, dataset::ValidationDataset,
+        report::OwnedValidationReport, validate,
+    },
+    vocab::sh,
+};
+
+/// Most passes [`infer`] makes before giving up on reaching a fixpoint.
+const MAX_PASSES: usize = 100;
+
+/// A rule and the shape it is attached to.
+#[derive(Debug, Clone)]
+pub struct Rule<'a> {
+    pub node: NamedOrBlankNodeRef<'a>,
+    /// Targets of the shape the rule is attached to.
+    pub targets: Vec<Target<'a>>,
+    /// `sh:order`, 0 when not set.
+    pub order: f64,
+    /// Shapes a focus node must conform to before the rule runs on it.
+    pub conditions: Vec<Shape<'a>>,
+    pub kind: RuleKind<'a>,
+}
+
+#[derive(Debug, Clone)]
+pub enum RuleKind<'a> {
+    /// `sh:TripleRule`: every combination of the subject, predicate and object nodes.
+    Triple {
+        subject: NodeExpression<'a>,
+        predicate: NodeExpression<'a>,
+        object: NodeExpression<'a>,
+    },
+    /// `sh:SPARQLRule`: a CONSTRUCT query with `$this` pre-bound.
+    Sparql {
+        construct: String,
+        prefixes: Vec<(String, String)>,
+    },
+}
+
+/// Parses the active rules of all active shapes in `graph`, sorted by `sh:order`.
+pub fn parse_rules(graph: &Graph) -> Result<Vec<Rule<'_>>, ShaclError> {
+    let shapes = ShapeRegistry::new(graph);
+    let mut rules = Vec::new();
+    for triple in graph.triples_for_predicate(sh::RULE_PROPERTY) {
+        let shape = triple.subject;
+        let Some(node) = utils::term_to_named_or_blank(triple.object) else {
+            return Err(ShaclError::Parse(format!(
+                "Invalid sh:rule {} on {}",
+                triple.object, shape
+            )));
+        };
+        if get_boolean_value(graph, shape, sh::DEACTIVATED) == Some(true)
+            || get_boolean_value(graph, node, sh::DEACTIVATED) == Some(true)
+        {
+            continue;
+        }
+        rules.push(parse_rule(&shapes, shape, node)?);
+    }
+
+    rules.sort_by(|a, b| {
+        a.order
+            .total_cmp(&b.order)
+            .then_with(|| a.node.to_string().cmp(&b.node.to_string()))
+    });
+    Ok(rules)
+}
+
+fn parse_rule<'a>(
+    shapes: &ShapeRegistry<'a>,
+    shape: NamedOrBlankNodeRef<'a>,
+    node: NamedOrBlankNodeRef<'a>,
+) -> Result<Rule<'a>, ShaclError> {
+    let graph = shapes.graph();
+    let expression = |predicate| {
+        let term = graph
+            .object_for_subject_predicate(node, predicate)
+            .ok_or_else(|| {
+                ShaclError::Parse(format!("Triple rule {} has no {}", node, predicate))
+            })?;
+        parse_node_expression_with(shapes, term)
+    };
+
+    let kind = if graph.contains(TripleRef::new(node, rdf::TYPE, sh::SPARQL_RULE)) {
+        RuleKind::Sparql {
+            construct: get_string_value(graph, node, sh::CONSTRUCT).ok_or_else(|| {
+                ShaclError::Parse(format!("SPARQL rule {} has no sh:construct", node))
+            })?,
+            prefixes: parse_shacl_prefixes(graph, node),
+        }
+    } else if graph.contains(TripleRef::new(node, rdf::TYPE, sh::TRIPLE_RULE))
+        || graph
+            .object_for_subject_predicate(node, sh::SUBJECT)
+            .is_some()
+    {
+        RuleKind::Triple {
+            subject: expression(sh::SUBJECT)?,
+            predicate: expression(sh::PREDICATE)?,
+            object: expression(sh::OBJECT)?,
+        }
+    } else {
+        return Err(ShaclError::Parse(format!("Unsupported rule {}", node)));
+    };
+
+    let order = graph
+        .object_for_subject_predicate(node, sh::ORDER)
+        .map(|order| match order {
+            TermRef::Literal(literal) => literal.value().parse::<f64>().ok(),
+            _ => None,
+        })
+        .map(|order| {
+            order.ok_or_else(|| ShaclError::Parse(format!("Invalid sh:order on {}", node)))
+        })
+        .transpose()?
+        .unwrap_or(0.0);
+
+    let conditions = graph
+        .objects_for_subject_predicate(node, sh::CONDITION)
+        .map(|condition| {
+            let condition = utils::term_to_named_or_blank(condition).ok_or_else(|| {
+                ShaclError::Parse(format!("Invalid sh:condition {} on {}", condition, node))
+            })?;
+            parse_shape_with(shapes, condition, None)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Rule {
+        node,
+        targets: parse_targets(shapes.index(), shape),
+        order,
+        conditions,
+        kind,
+    })
+}
+
+impl Rule<'_> {
+    /// Triples the rule infers from the data graph of `dataset`; they may
+    /// already be in it.
+    pub fn execute(&self, dataset: &ValidationDataset) -> Result<Vec<Triple>, ShaclError> {
+        let mut focus_nodes: Vec<TermRef<'_>> = self
+            .targets
+            .iter()
+            .flat_map(|target| target.resolve_target(dataset))
+            .collect();
+        focus_nodes.sort_by_cached_key(ToString::to_string);
+        focus_nodes.dedup();
+
+        let mut triples = Vec::new();
+        for focus_node in focus_nodes {
+            if !self
+                .conditions
+                .iter()
+                .all(|condition| condition.validate_node(dataset, focus_node))
+            {
+                continue;
+            }
+
+            match &self.kind {
+                RuleKind::Triple {
+                    subject,
+                    predicate,
+                    object,
+                } => {
+                    let objects = object.evaluate(dataset, focus_node)?;
+                    for subject in subject.evaluate(dataset, focus_node)? {
+                        let Some(subject) = utils::term_to_named_or_blank(subject) else {
+                            continue;
+                        };
+                        for predicate in predicate.evaluate(dataset, focus_node)? {
+                            let TermRef::NamedNode(predicate) = predicate else {
+                                continue;
+                            };
+                            for &object in &objects {
+                                triples
+                                    .push(TripleRef::new(subject, predicate, object).into_owned());
+                            }
+                        }
+                    }
+                }
+                RuleKind::Sparql {
+                    construct,
+                    prefixes,
+                } => {
+                    let constructed = self.construct(dataset, construct, prefixes, focus_node)?;
+                    triples.extend(self.skolemize(constructed, dataset.data_graph(), focus_node));
+                }
+            }
+        }
+        debug!("Rule {} inferred {} triples", self.node, triples.len());
+        Ok(triples)
+    }
+
+    fn construct(
+        &self,
+        dataset: &ValidationDataset,
+        construct: &str,
+        prefixes: &[(String, String)],
+        focus_node: TermRef<'_>,
+    ) -> Result<Vec<Triple>, ShaclError> {
+        let query = parse_query(construct, prefixes).map_err(|e| {
+            ShaclError::Parse(format!("Invalid sh:construct on {}: {}", self.node, e))
+        })?;
+        // Substituted rather than rewritten into the query, so the template sees it too.
+        let prepared = dataset
+            .sparql_evaluator()
+            .for_query(query)
+            .substitute_variable(Variable::new_unchecked("this"), focus_node.into_owned());
+
+        let error = |e: &dyn std::fmt::Display| {
+            ShaclError::Validation(format!("SPARQL rule {} failed: {}", self.node, e))
+        };
+        match prepared.on_store(dataset.store().as_ref()).execute() {
+            Ok(QueryResults::Graph(triples)) => triples
+                .map(|triple| triple.map_err(|e| error(&e)))
+                .collect(),
+            Ok(_) => Err(ShaclError::Parse(format!(
+                "sh:construct on {} is not a CONSTRUCT query",
+                self.node
+            ))),
+            Err(e) => Err(error(&e)),
+        }
+    }
+
+    /// Renames the blank nodes a CONSTRUCT template minted after the rule,
+    /// the focus node and their order of appearance. Running the rule again
+    /// on the same focus node then infers the same triples, not new ones.
+    fn skolemize(
+        &self,
+        mut triples: Vec<Triple>,
+        data_graph: &Graph,
+        focus_node: TermRef<'_>,
+    ) -> Vec<Triple> {
+        let mut minted: HashMap<BlankNode, BlankNode> = HashMap::new();
+        let mut skolemize = |node: &mut BlankNode| {
+            if data_graph
+                .triples_for_subject(node.as_ref())
+                .next()
+                .is_some()
+                || data_graph
+                    .triples_for_object(node.as_ref())
+                    .next()
+                    .is_some()
+            {
+                return;
+            }
+            let index = minted.len();
+            *node = minted
+                .entry(node.clone())
+                .or_insert_with(|| {
+                    let digest =
+                        Sha256::digest(format!("{}\n{}\n{}", self.node, focus_node, index));
+                    let id: String = digest[..16]
+                        .iter()
+                        .map(|byte| format!("{:02x}", byte))
+                        .collect();
+                    BlankNode::new_unchecked(id)
+                })
+                .clone();
+        };
+        for triple in &mut triples {
+            if let NamedOrBlankNode::BlankNode(subject) = &mut triple.subject {
+                skolemize(subject);
+            }
+            if let Term::BlankNode(object) = &mut triple.object {
+                skolemize(object);
+            }
+        }
+        triples
+    }
+}
+
+/// Runs the rules of `shapes_graph` on `data_graph` and returns the triples
+/// they inferred that were not in the data graph.
+pub fn infer(data_graph: &Graph, shapes_graph: &Graph) -> Result<Graph, ShaclError> {
+    let rules = parse_rules(shapes_graph)?;
+    let mut data = data_graph.clone();
+    let mut inferred = Graph::new();
+
+    for _ in 0..MAX_PASSES {
+        let dataset = ValidationDataset::from_graphs(data.clone(), shapes_graph.clone())?;
+        let mut changed = false;
+        for rule in &rules {
+            for triple in rule.execute(&dataset)? {
+                if data.insert(&triple) {
+                    inferred.insert(&triple);
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            debug!("Rules inferred {} triples", inferred.len());
+            return Ok(inferred);
+        }
+    }
+    Err(ShaclError::Validation(format!(
+        "Rules still inferred new triples after {} passes",
+        MAX_PASSES
+    )))
+}
+
+/// Validates `data_graph` after adding the triples inferred by the rules of
+/// `shapes_graph`.
+pub fn validate_with_inference(
+    mut data_graph: Graph,
+    shapes_graph: Graph,
+) -> Result<OwnedValidationReport, ShaclError> {
+    let inferred = infer(&data_graph, &shapes_graph)?;
+    data_graph.extend(inferred.iter());
+
+    let dataset = ValidationDataset::from_graphs(data_graph, shapes_graph)?;
+    let shapes = crate::parser::parse_shapes(dataset.shapes_graph())?;
+    Ok(validate(&dataset, &shapes).into_owned())
+}
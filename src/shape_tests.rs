@@ -0,0 +1,279 @@
+//! Unit tests for shapes graphs.
+//!
+//! A test file is a Turtle graph of `shx:ShapeTest` nodes. Each test holds a
+//! data snippet as a Turtle literal and states what validating it against the
+//! shapes should produce:
+//!
+//! ```turtle
+//! ex:personWithoutName a shx:ShapeTest ;
+//!     rdfs:label "A person needs a name" ;
+//!     shx:data """
+//!         @prefix ex: <http://example.org/> .
+//!         ex:alice a ex:Person .
+//!     """ ;
+//!     shx:expectedResult [
+//!         sh:focusNode ex:alice ;
+//!         sh:resultPath ex:name ;
+//!         sh:sourceConstraintComponent sh:MinCountConstraintComponent ;
+//!     ] .
+//! ```
+//!
+//! Expected results may give any of `sh:focusNode`, `sh:resultPath`,
+//! `sh:sourceShape`, `sh:sourceConstraintComponent` and `sh:resultSeverity`.
+//! A test passes when every expected result matches an actual result and every
+//! actual result matches an expected one; a test without expected results
+//! passes when the data conforms, unless `shx:conforms false` says otherwise.
+
+use std::{fmt::Write, time::Duration};
+
+use oxigraph::model::{
+    vocab::{rdf, rdfs},
+    Graph, NamedNode, NamedOrBlankNodeRef, Term, TermRef,
+};
+
+use crate::{
+    err::ShaclError,
+    parser::parse_shapes,
+    rdf::read_graph_from_string,
+    utils::{get_boolean_value, get_string_value, term_to_named_or_blank},
+    validation::{dataset::ValidationDataset, report::ValidationResult, validate},
+    vocab::{sh, shx},
+};
+
+/// A data snippet with the validation outcome it should have.
+#[derive(Debug, Clone)]
+pub struct ShapeTest {
+    pub name: String,
+    pub data: Graph,
+    /// Whether the data should conform; `None` leaves it to the expected results.
+    pub conforms: Option<bool>,
+    pub expected_results: Vec<ExpectedResult>,
+}
+
+/// Fields an actual validation result must have; unset fields match anything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpectedResult {
+    pub focus_node: Option<Term>,
+    pub path: Option<Term>,
+    pub source_shape: Option<Term>,
+    pub component: Option<NamedNode>,
+    pub severity: Option<NamedNode>,
+}
+
+impl ExpectedResult {
+    fn matches(&self, result: &ValidationResult<'_>) -> bool {
+        self.focus_node
+            .as_ref()
+            .is_none_or(|focus_node| focus_node.as_ref() == result.get_focus_node())
+            && self.path.as_ref().is_none_or(|path| {
+                result
+                    .get_result_path()
+                    .is_some_and(|actual| actual.to_string() == path.to_string())
+            })
+            && self
+                .source_shape
+                .as_ref()
+                .is_none_or(|shape| TermRef::from(result.get_source_shape()) == shape.as_ref())
+            && self.component.as_ref().is_none_or(|component| {
+                result
+                    .get_source_constraint_component()
+                    .is_some_and(|actual| actual == component.as_ref())
+            })
+            && self
+                .severity
+                .as_ref()
+                .is_none_or(|severity| result.get_severity() == severity.as_ref())
+    }
+}
+
+impl std::fmt::Display for ExpectedResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fields: Vec<String> = [
+            ("focus node", self.focus_node.as_ref().map(Term::to_string)),
+            ("path", self.path.as_ref().map(Term::to_string)),
+            ("shape", self.source_shape.as_ref().map(Term::to_string)),
+            (
+                "component",
+                self.component.as_ref().map(NamedNode::to_string),
+            ),
+            ("severity", self.severity.as_ref().map(NamedNode::to_string)),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| Some(format!("{} {}", name, value?)))
+        .collect();
+        write!(f, "[{}]", fields.join(", "))
+    }
+}
+
+/// Outcome of running one [`ShapeTest`].
+#[derive(Debug, Clone)]
+pub struct TestOutcome {
+    pub name: String,
+    /// Why the test failed; empty when it passed.
+    pub failures: Vec<String>,
+    pub duration: Duration,
+}
+
+impl TestOutcome {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Reads the `shx:ShapeTest` nodes of a test file, sorted by name.
+pub fn read_shape_tests(graph: &Graph) -> Result<Vec<ShapeTest>, ShaclError> {
+    let mut tests = Vec::new();
+    for node in graph.subjects_for_predicate_object(rdf::TYPE, shx::SHAPE_TEST) {
+        let name = get_string_value(graph, node, rdfs::LABEL).unwrap_or_else(|| node.to_string());
+        let snippet = get_string_value(graph, node, shx::DATA)
+            .ok_or_else(|| ShaclError::Parse(format!("Test '{}' has no shx:data", name)))?;
+        let data = read_graph_from_string(&snippet, "turtle").map_err(|e| {
+            ShaclError::Parse(format!("Invalid shx:data of test '{}': {}", name, e))
+        })?;
+
+        let expected_results = graph
+            .objects_for_subject_predicate(node, shx::EXPECTED_RESULT)
+            .filter_map(term_to_named_or_blank)
+            .map(|result| read_expected_result(graph, result))
+            .collect();
+
+        tests.push(ShapeTest {
+            name,
+            data,
+            conforms: get_boolean_value(graph, node, shx::CONFORMS),
+            expected_results,
+        });
+    }
+    tests.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(tests)
+}
+
+fn read_expected_result(graph: &Graph, node: NamedOrBlankNodeRef<'_>) -> ExpectedResult {
+    let term = |predicate| {
+        graph
+            .object_for_subject_predicate(node, predicate)
+            .map(TermRef::into_owned)
+    };
+    let named_node = |predicate| match term(predicate) {
+        Some(Term::NamedNode(node)) => Some(node),
+        _ => None,
+    };
+    ExpectedResult {
+        focus_node: term(sh::FOCUS_NODE),
+        path: term(sh::RESULT_PATH),
+        source_shape: term(sh::SOURCE_SHAPE),
+        component: named_node(sh::SOURCE_CONSTRAINT_COMPONENT),
+        severity: named_node(sh::RESULT_SEVERITY),
+    }
+}
+
+/// Validates the data of each test against `shapes_graph` and checks the outcome.
+pub fn run_shape_tests(shapes_graph: &Graph, tests: &[ShapeTest]) -> Vec<TestOutcome> {
+    tests
+        .iter()
+        .map(|test| {
+            #[cfg(not(target_family = "wasm"))]
+            let start = std::time::Instant::now();
+            let failures = match check(shapes_graph, test) {
+                Ok(failures) => failures,
+                Err(e) => vec![format!("Validation failed: {}", e)],
+            };
+            // There is no clock to time tests with on wasm
+            #[cfg(not(target_family = "wasm"))]
+            let duration = start.elapsed();
+            #[cfg(target_family = "wasm")]
+            let duration = Duration::ZERO;
+
+            TestOutcome {
+                name: test.name.clone(),
+                failures,
+                duration,
+            }
+        })
+        .collect()
+}
+
+fn check(shapes_graph: &Graph, test: &ShapeTest) -> Result<Vec<String>, ShaclError> {
+    let dataset = ValidationDataset::from_graphs(test.data.clone(), shapes_graph.clone())?;
+    let shapes = parse_shapes(dataset.shapes_graph())?;
+    let report = validate(&dataset, &shapes);
+    let results = report.get_results();
+    let mut failures = Vec::new();
+
+    let expected_conforms = test.conforms.unwrap_or(test.expected_results.is_empty());
+    if *report.get_conforms() != expected_conforms {
+        failures.push(if expected_conforms {
+            format!(
+                "Expected the data to conform, got {} results",
+                results.len()
+            )
+        } else {
+            "Expected the data not to conform".to_string()
+        });
+    }
+
+    if !test.expected_results.is_empty() {
+        for expected in &test.expected_results {
+            if !results.iter().any(|result| expected.matches(result)) {
+                failures.push(format!("Missing expected result {}", expected));
+            }
+        }
+        for result in results {
+            if !test.expected_results.iter().any(|e| e.matches(result)) {
+                failures.push(format!(
+                    "Unexpected result: focus node {}, component {}",
+                    result.get_focus_node(),
+                    result
+                        .get_source_constraint_component()
+                        .map_or("-".to_string(), |c| c.to_string())
+                ));
+            }
+        }
+    }
+    Ok(failures)
+}
+
+/// Renders outcomes as a JUnit XML test suite named `suite`.
+pub fn junit_report(suite: &str, outcomes: &[TestOutcome]) -> String {
+    let failures = outcomes.iter().filter(|outcome| !outcome.passed()).count();
+    let total: Duration = outcomes.iter().map(|outcome| outcome.duration).sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">",
+        xml_escape(suite),
+        outcomes.len(),
+        failures,
+        total.as_secs_f64()
+    );
+    for outcome in outcomes {
+        let _ = write!(
+            xml,
+            "  <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+            xml_escape(&outcome.name),
+            xml_escape(suite),
+            outcome.duration.as_secs_f64()
+        );
+        if outcome.passed() {
+            xml.push_str("/>\n");
+            continue;
+        }
+        let _ = writeln!(
+            xml,
+            ">\n    <failure message=\"{}\">{}</failure>\n  </testcase>",
+            xml_escape(&outcome.failures[0]),
+            xml_escape(&outcome.failures.join("\n"))
+        );
+    }
+    xml.push_str("</testsuite>\n");
+    xml
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
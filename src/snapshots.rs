@@ -0,0 +1,115 @@
+//! Validation of dated named-graph snapshots kept in one store.
+//!
+//! Stores that keep one named graph per snapshot (e.g. `ex:data/2024-01-01`)
+//! can have a single snapshot validated, or conformance tracked over all of
+//! them as a small time series.
+
+use oxigraph::{
+    model::{Graph, NamedNode, NamedNodeRef, NamedOrBlankNode},
+    store::Store,
+};
+
+use crate::{
+    core::severity::Severity,
+    err::ShaclError,
+    parser::parse_shapes,
+    rdf::graph_hash_hex,
+    validation::{
+        dataset::{read_store_graph, ValidationDataset},
+        validate,
+    },
+};
+
+/// Severity counts of one validated snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotSummary {
+    /// Name of the snapshot graph.
+    pub snapshot: NamedNode,
+    /// Hex [hash](crate::rdf::graph_hash) of the snapshot, the same for
+    /// snapshots with the same content.
+    pub hash: String,
+    pub conforms: bool,
+    pub violations: usize,
+    pub warnings: usize,
+    pub infos: usize,
+}
+
+impl SnapshotSummary {
+    pub fn as_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "snapshot": self.snapshot.as_str(),
+            "hash": self.hash,
+            "conforms": self.conforms,
+            "violations": self.violations,
+            "warnings": self.warnings,
+            "infos": self.infos,
+            "total": self.violations + self.warnings + self.infos,
+        })
+    }
+}
+
+/// Names of all named graphs in `store`, sorted so dated names come out in order.
+pub fn snapshot_names(store: &Store) -> Result<Vec<NamedNode>, ShaclError> {
+    let mut names = Vec::new();
+    for name in store.named_graphs() {
+        let name =
+            name.map_err(|e| ShaclError::Io(format!("Failed to list named graphs: {}", e)))?;
+        if let NamedOrBlankNode::NamedNode(name) = name {
+            names.push(name);
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Copies the named graph `snapshot` out of `store`.
+pub fn load_snapshot(store: &Store, snapshot: NamedNodeRef<'_>) -> Result<Graph, ShaclError> {
+    read_store_graph(store, snapshot.into())
+}
+
+/// Validates one snapshot against `shapes_graph` and summarizes the report.
+pub fn validate_snapshot(
+    store: &Store,
+    snapshot: NamedNodeRef<'_>,
+    shapes_graph: &Graph,
+) -> Result<SnapshotSummary, ShaclError> {
+    let data_graph = load_snapshot(store, snapshot)?;
+    let hash = graph_hash_hex(&data_graph);
+    let dataset = ValidationDataset::from_graphs(data_graph, shapes_graph.clone())?;
+    let shapes = parse_shapes(dataset.shapes_graph())?;
+    let report = validate(&dataset, &shapes);
+
+    Ok(SnapshotSummary {
+        snapshot: snapshot.into_owned(),
+        hash,
+        conforms: *report.get_conforms(),
+        violations: report.violations_by_severity(Severity::Violation).len(),
+        warnings: report.violations_by_severity(Severity::Warning).len(),
+        infos: report.violations_by_severity(Severity::Info).len(),
+    })
+}
+
+/// Validates each snapshot in turn, or every named graph when `snapshots` is empty.
+pub fn conformance_trend(
+    store: &Store,
+    snapshots: &[NamedNode],
+    shapes_graph: &Graph,
+) -> Result<Vec<SnapshotSummary>, ShaclError> {
+    let snapshots = if snapshots.is_empty() {
+        snapshot_names(store)?
+    } else {
+        snapshots.to_vec()
+    };
+
+    snapshots
+        .iter()
+        .map(|snapshot| validate_snapshot(store, snapshot.as_ref(), shapes_graph))
+        .collect()
+}
+
+/// Time series of snapshot summaries, for dashboards.
+pub fn trend_as_json(summaries: &[SnapshotSummary]) -> serde_json::Value {
+    serde_json::json!({
+        "snapshots": summaries.iter().map(SnapshotSummary::as_json).collect::<Vec<_>>(),
+    })
+}
@@ -1,12 +1,33 @@
-use oxigraph::model::{vocab::rdfs, Graph, NamedNodeRef, NamedOrBlankNodeRef, TermRef};
-use regex::Regex;
-
-use crate::{core::constraints::NodeKind, vocab::sh};
+use oxigraph::model::{
+    vocab::{rdf, rdfs, xsd},
+    BlankNode, Graph, LiteralRef, NamedNodeRef, NamedOrBlankNode, NamedOrBlankNodeRef, Term,
+    TermRef, Triple,
+};
+use oxsdatatypes::{
+    Boolean, Date, DateTime, DayTimeDuration, Double, Duration, GDay, GMonth, GMonthDay, GYear,
+    GYearMonth, Time, YearMonthDuration,
+};
+use regex::{Regex, RegexBuilder};
+use std::cmp::Ordering;
+
+use crate::{
+    core::constraints::NodeKind,
+    vocab::{owl, sh},
+};
 
 pub fn is_subclass_of(
     node: NamedOrBlankNodeRef,
     class: NamedOrBlankNodeRef,
     graph: &oxigraph::model::Graph,
+) -> bool {
+    is_subclass_of_in(node, class, &[graph])
+}
+
+/// Like [`is_subclass_of`], following the `rdfs:subClassOf` triples of all `graphs`.
+pub fn is_subclass_of_in(
+    node: NamedOrBlankNodeRef,
+    class: NamedOrBlankNodeRef,
+    graphs: &[&oxigraph::model::Graph],
 ) -> bool {
     let mut visited = std::collections::HashSet::new();
     let mut to_visit = vec![node];
@@ -16,12 +37,14 @@ pub fn is_subclass_of(
             return true;
         }
         if visited.insert(current) {
-            let objects = graph.objects_for_subject_predicate(current, rdfs::SUB_CLASS_OF);
-            to_visit.extend(objects.filter_map(|o| match o {
-                TermRef::NamedNode(nn) => Some(NamedOrBlankNodeRef::from(nn)),
-                TermRef::BlankNode(bn) => Some(NamedOrBlankNodeRef::from(bn)),
-                _ => None,
-            }));
+            for graph in graphs {
+                let objects = graph.objects_for_subject_predicate(current, rdfs::SUB_CLASS_OF);
+                to_visit.extend(objects.filter_map(|o| match o {
+                    TermRef::NamedNode(nn) => Some(NamedOrBlankNodeRef::from(nn)),
+                    TermRef::BlankNode(bn) => Some(NamedOrBlankNodeRef::from(bn)),
+                    _ => None,
+                }));
+            }
         }
         if to_visit.contains(&class) {
             return true;
@@ -59,13 +82,23 @@ pub fn collect_all_superclasses<'a>(
 pub fn collect_all_subclasses<'a>(
     node: NamedOrBlankNodeRef<'a>,
     graph: &'a oxigraph::model::Graph,
+) -> std::collections::HashSet<NamedNodeRef<'a>> {
+    collect_all_subclasses_in(node, &[graph])
+}
+
+/// Like [`collect_all_subclasses`], following the `rdfs:subClassOf` triples of all `graphs`.
+pub fn collect_all_subclasses_in<'a>(
+    node: NamedOrBlankNodeRef<'a>,
+    graphs: &[&'a oxigraph::model::Graph],
 ) -> std::collections::HashSet<NamedNodeRef<'a>> {
     let mut visited = std::collections::HashSet::new();
     let mut to_visit = vec![node];
 
     while let Some(current) = to_visit.pop() {
         if visited.insert(current) {
-            to_visit.extend(graph.subjects_for_predicate_object(rdfs::SUB_CLASS_OF, current));
+            for graph in graphs {
+                to_visit.extend(graph.subjects_for_predicate_object(rdfs::SUB_CLASS_OF, current));
+            }
         }
     }
     visited
@@ -145,6 +178,18 @@ pub fn collect_all_subproperties<'a>(
     visited
 }
 
+/// Writes `items` as an RDF list and returns its head.
+pub fn insert_rdf_list(graph: &mut Graph, items: Vec<Term>) -> Term {
+    let mut rest: Term = rdf::NIL.into();
+    for item in items.into_iter().rev() {
+        let node = NamedOrBlankNode::from(BlankNode::default());
+        graph.insert(&Triple::new(node.clone(), rdf::FIRST, item));
+        graph.insert(&Triple::new(node.clone(), rdf::REST, rest));
+        rest = node.into();
+    }
+    rest
+}
+
 /// Parse an RDF list into a vector of terms
 pub fn parse_rdf_list<'a>(
     graph: &'a Graph,
@@ -268,6 +313,185 @@ pub fn get_integer_value(
             _ => None,
         })
 }
+/// Datatypes whose values are decimals.
+const DECIMAL_DATATYPES: &[NamedNodeRef<'static>] = &[
+    xsd::DECIMAL,
+    xsd::INTEGER,
+    xsd::NON_POSITIVE_INTEGER,
+    xsd::NEGATIVE_INTEGER,
+    xsd::LONG,
+    xsd::INT,
+    xsd::SHORT,
+    xsd::BYTE,
+    xsd::NON_NEGATIVE_INTEGER,
+    xsd::UNSIGNED_LONG,
+    xsd::UNSIGNED_INT,
+    xsd::UNSIGNED_SHORT,
+    xsd::UNSIGNED_BYTE,
+    xsd::POSITIVE_INTEGER,
+];
+
+/// Value ranges of the integer datatypes, `None` for an unbounded side.
+const INTEGER_RANGES: &[(NamedNodeRef<'static>, Option<i128>, Option<i128>)] = &[
+    (xsd::INTEGER, None, None),
+    (xsd::NON_POSITIVE_INTEGER, None, Some(0)),
+    (xsd::NEGATIVE_INTEGER, None, Some(-1)),
+    (xsd::NON_NEGATIVE_INTEGER, Some(0), None),
+    (xsd::POSITIVE_INTEGER, Some(1), None),
+    (xsd::LONG, Some(i64::MIN as i128), Some(i64::MAX as i128)),
+    (xsd::INT, Some(i32::MIN as i128), Some(i32::MAX as i128)),
+    (xsd::SHORT, Some(i16::MIN as i128), Some(i16::MAX as i128)),
+    (xsd::BYTE, Some(i8::MIN as i128), Some(i8::MAX as i128)),
+    (xsd::UNSIGNED_LONG, Some(0), Some(u64::MAX as i128)),
+    (xsd::UNSIGNED_INT, Some(0), Some(u32::MAX as i128)),
+    (xsd::UNSIGNED_SHORT, Some(0), Some(u16::MAX as i128)),
+    (xsd::UNSIGNED_BYTE, Some(0), Some(u8::MAX as i128)),
+];
+
+/// True if the lexical form of `literal` is valid for its datatype.
+///
+/// Checks the numeric, boolean, date, time and duration datatypes of XSD;
+/// literals of other datatypes are taken to be valid.
+pub fn is_valid_lexical_form(literal: LiteralRef<'_>) -> bool {
+    let datatype = literal.datatype();
+    let value = literal.value();
+
+    if let Some(&(_, min, max)) = INTEGER_RANGES.iter().find(|(d, _, _)| *d == datatype) {
+        let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return false;
+        }
+        // Integers beyond i128 are only in range of the unbounded side.
+        return match value.parse::<i128>() {
+            Ok(number) => {
+                min.is_none_or(|min| number >= min) && max.is_none_or(|max| number <= max)
+            }
+            Err(_) if value.starts_with('-') => min.is_none(),
+            Err(_) => max.is_none(),
+        };
+    }
+
+    if datatype == xsd::DECIMAL {
+        ExactDecimal::parse(value).is_some()
+    } else if datatype == xsd::DOUBLE || datatype == xsd::FLOAT {
+        // Rust also accepts spellings such as "inf" and "infinity".
+        matches!(value, "INF" | "+INF" | "-INF" | "NaN")
+            || (!value.contains(|c: char| c.is_ascii_alphabetic() && c != 'e' && c != 'E')
+                && value.parse::<f64>().is_ok())
+    } else if datatype == xsd::BOOLEAN {
+        value.parse::<Boolean>().is_ok()
+    } else if datatype == xsd::DATE_TIME {
+        value.parse::<DateTime>().is_ok()
+    } else if datatype == xsd::DATE_TIME_STAMP {
+        value
+            .parse::<DateTime>()
+            .is_ok_and(|date_time| date_time.timezone_offset().is_some())
+    } else if datatype == xsd::DATE {
+        value.parse::<Date>().is_ok()
+    } else if datatype == xsd::TIME {
+        value.parse::<Time>().is_ok()
+    } else if datatype == xsd::G_YEAR {
+        value.parse::<GYear>().is_ok()
+    } else if datatype == xsd::G_YEAR_MONTH {
+        value.parse::<GYearMonth>().is_ok()
+    } else if datatype == xsd::G_MONTH {
+        value.parse::<GMonth>().is_ok()
+    } else if datatype == xsd::G_MONTH_DAY {
+        value.parse::<GMonthDay>().is_ok()
+    } else if datatype == xsd::G_DAY {
+        value.parse::<GDay>().is_ok()
+    } else if datatype == xsd::DURATION {
+        value.parse::<Duration>().is_ok()
+    } else if datatype == xsd::DAY_TIME_DURATION {
+        value.parse::<DayTimeDuration>().is_ok()
+    } else if datatype == xsd::YEAR_MONTH_DURATION {
+        value.parse::<YearMonthDuration>().is_ok()
+    } else {
+        true
+    }
+}
+
+/// Total and fractional digit counts of a decimal literal, as the XSD
+/// `totalDigits` and `fractionDigits` facets count them.
+///
+/// Leading zeros of the integer part and trailing zeros of the fraction are not
+/// significant, so `"0.050"` has 2 total and 2 fractional digits. Returns `None` for literals
+/// that are not well-formed decimals.
+pub fn decimal_digits(literal: LiteralRef<'_>) -> Option<(usize, usize)> {
+    if !DECIMAL_DATATYPES.contains(&literal.datatype()) {
+        return None;
+    }
+    let decimal = ExactDecimal::parse(literal.value())?;
+    Some((
+        (decimal.integer.len() + decimal.fraction.len()).max(1),
+        decimal.fraction.len(),
+    ))
+}
+
+/// Compiles a URI template into a regex matching the IRIs it can expand to.
+///
+/// `{name}` matches one or more characters other than `/`, `?` and `#`;
+/// `{+name}` matches any non-empty text.
+pub fn uri_template_regex(template: &str) -> Result<Regex, String> {
+    let mut pattern = String::from("^");
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        pattern.push_str(&regex::escape(&rest[..start]));
+        let end = rest[start..]
+            .find('}')
+            .map(|end| start + end)
+            .ok_or_else(|| format!("Unclosed '{{' in URI template '{}'", template))?;
+        let variable = &rest[start + 1..end];
+        let (pattern_part, name) = match variable.strip_prefix('+') {
+            Some(name) => (".+", name),
+            None => ("[^/?#]+", variable),
+        };
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(format!(
+                "Invalid variable '{{{}}}' in URI template '{}'",
+                variable, template
+            ));
+        }
+        pattern.push_str(pattern_part);
+        rest = &rest[end + 1..];
+    }
+    if rest.contains('}') {
+        return Err(format!("Unmatched '}}' in URI template '{}'", template));
+    }
+    pattern.push_str(&regex::escape(rest));
+    pattern.push('$');
+    Regex::new(&pattern).map_err(|e| e.to_string())
+}
+
+/// Compiles a `sh:pattern` with its `sh:flags`.
+///
+/// Supports the XPath flags `i` (case-insensitive), `m` (multi-line), `s`
+/// (dot matches newlines), `x` (ignore whitespace) and `q` (match the pattern
+/// literally). Other flags are an error.
+pub fn pattern_regex(pattern: &str, flags: Option<&str>) -> Result<Regex, String> {
+    let flags = flags.unwrap_or_default();
+    let literal;
+    let mut builder = if flags.contains('q') {
+        literal = regex::escape(pattern);
+        RegexBuilder::new(&literal)
+    } else {
+        RegexBuilder::new(pattern)
+    };
+    for flag in flags.chars() {
+        match flag {
+            'i' => builder.case_insensitive(true),
+            'm' => builder.multi_line(true),
+            's' => builder.dot_matches_new_line(true),
+            'x' => builder.ignore_whitespace(true),
+            'q' => &mut builder,
+            _ => return Err(format!("Unsupported sh:flags character '{}'", flag)),
+        };
+    }
+    builder
+        .build()
+        .map_err(|e| format!("Invalid sh:pattern '{}': {}", pattern, e))
+}
+
 /// Convert a TermRef to NamedOrBlankNodeRef, filtering out literals
 pub fn term_to_named_or_blank(term: TermRef) -> Option<NamedOrBlankNodeRef> {
     match term {
@@ -284,16 +508,26 @@ pub fn local_name_from_iri(iri: &str) -> Option<String> {
         .map(ToString::to_string)
 }
 
+/// Collects the prefix declarations reachable from `executable` via `sh:prefixes`.
+///
+/// Prefix sets are followed transitively through `owl:imports` and nested
+/// `sh:prefixes`, so an ontology node can share its declarations with every
+/// executable that points at it. The first declaration of a prefix wins.
 pub fn parse_shacl_prefixes<'a>(
     graph: &'a Graph,
     executable: NamedOrBlankNodeRef<'a>,
 ) -> Vec<(String, String)> {
-    let mut prefixes = Vec::new();
+    let mut prefixes: Vec<(String, String)> = Vec::new();
+    let mut visited = std::collections::HashSet::new();
+    let mut to_visit: std::collections::VecDeque<_> = graph
+        .objects_for_subject_predicate(executable, sh::PREFIXES)
+        .filter_map(term_to_named_or_blank)
+        .collect();
 
-    for prefixes_term in graph.objects_for_subject_predicate(executable, sh::PREFIXES) {
-        let Some(prefixes_node) = term_to_named_or_blank(prefixes_term) else {
+    while let Some(prefixes_node) = to_visit.pop_front() {
+        if !visited.insert(prefixes_node) {
             continue;
-        };
+        }
 
         for decl_term in graph.objects_for_subject_predicate(prefixes_node, sh::DECLARE) {
             let Some(decl_node) = term_to_named_or_blank(decl_term) else {
@@ -314,49 +548,34 @@ pub fn parse_shacl_prefixes<'a>(
                     _ => None,
                 });
 
-            if let (Some(p), Some(ns)) = (prefix, namespace) {
-                prefixes.push((p, ns));
+            let (Some(p), Some(ns)) = (prefix, namespace) else {
+                continue;
+            };
+
+            match prefixes.iter().find(|(existing, _)| *existing == p) {
+                Some((_, existing_ns)) if *existing_ns != ns => {
+                    log::warn!(
+                        "Ignoring conflicting declaration of prefix '{}' ({}) reachable from {}; keeping {}",
+                        p,
+                        ns,
+                        executable,
+                        existing_ns
+                    );
+                }
+                Some(_) => {}
+                None => prefixes.push((p, ns)),
             }
         }
-    }
-
-    prefixes
-}
 
-pub fn inject_values_bindings(query: &str, bindings: &[(String, String)]) -> String {
-    if bindings.is_empty() {
-        return query.to_string();
+        to_visit.extend(
+            graph
+                .objects_for_subject_predicate(prefixes_node, owl::IMPORTS)
+                .chain(graph.objects_for_subject_predicate(prefixes_node, sh::PREFIXES))
+                .filter_map(term_to_named_or_blank),
+        );
     }
 
-    let mut values_block = String::new();
-    for (var, value) in bindings {
-        values_block.push_str(&format!("\nVALUES ${} {{ {} }}", var, value));
-    }
-
-    let upper = query.to_ascii_uppercase();
-    if let Some(where_pos) = upper.find("WHERE") {
-        if let Some(rel_brace) = query[where_pos..].find('{') {
-            let insert_at = where_pos + rel_brace + 1;
-            let mut out = String::with_capacity(query.len() + values_block.len() + 1);
-            out.push_str(&query[..insert_at]);
-            out.push_str(&values_block);
-            out.push_str(&query[insert_at..]);
-            return out;
-        }
-    }
-
-    format!("{}\n{}", values_block, query)
-}
-
-pub fn rewrite_this_binding_query(query: &str, this_term: &str) -> String {
-    let normalized = query.replace("$this", "?this");
-    let where_re = Regex::new(r"(?i)WHERE\s*\{").unwrap();
-    let bind_clause = format!(" BIND ({} AS ?this) .", this_term);
-    where_re
-        .replace_all(&normalized, |caps: &regex::Captures<'_>| {
-            format!("{}{}", &caps[0], bind_clause)
-        })
-        .to_string()
+    prefixes
 }
 
 /// Extract direct IRI predicates from a path
@@ -366,9 +585,9 @@ pub fn extract_direct_predicates<'a>(
     use crate::core::path::PathElement;
 
     let mut predicates = Vec::new();
-    let elements = path.get_elements();
+    let path = path.normalized();
 
-    for element in elements {
+    for element in path.get_elements() {
         match element {
             PathElement::Iri(iri) => {
                 predicates.push(*iri);
@@ -392,42 +611,318 @@ pub fn extract_direct_predicates<'a>(
     predicates
 }
 
+/// True if the language tag `tag` matches the basic language range `range`
+/// (RFC 4647): case-insensitively equal, or starting with `range` and a `-`.
+///
+/// The range `*` matches every tag.
+pub fn language_matches(tag: &str, range: &str) -> bool {
+    range == "*"
+        || tag.eq_ignore_ascii_case(range)
+        || (tag.len() > range.len()
+            && tag.as_bytes()[range.len()] == b'-'
+            && tag[..range.len()].eq_ignore_ascii_case(range))
+}
+
 /// Compare two terms using a predicate function
+///
+/// Terms that are not comparable never satisfy the predicate.
 pub fn compare_values<F>(a: TermRef, b: TermRef, predicate: F) -> bool
 where
     F: Fn(i32) -> bool,
 {
-    match (a, b) {
-        (TermRef::Literal(lit_a), TermRef::Literal(lit_b)) => {
-            let num_a = lit_a.value().parse::<f64>();
-            let num_b = lit_b.value().parse::<f64>();
-
-            match (num_a, num_b) {
-                (Ok(na), Ok(nb)) => {
-                    // Both are numbers - compare numerically
-                    if na < nb {
-                        predicate(-1)
-                    } else if na > nb {
-                        predicate(1)
-                    } else {
-                        predicate(0)
-                    }
-                }
-                (Err(_), Err(_)) => {
-                    // Both are non-numeric - compare as strings
-                    let cmp = lit_a.value().cmp(lit_b.value());
-                    predicate(match cmp {
-                        std::cmp::Ordering::Less => -1,
-                        std::cmp::Ordering::Equal => 0,
-                        std::cmp::Ordering::Greater => 1,
-                    })
-                }
-                _ => {
-                    // One is numeric, one is not - incompatible types
-                    false
-                }
+    compare_terms(a, b).is_some_and(|cmp| predicate(cmp as i32))
+}
+
+/// Orders two literals by their values, following the SPARQL `<` operator.
+///
+/// Numeric literals compare across numeric datatypes, as do the date, time,
+/// Gregorian and duration datatypes with themselves and strings with strings. Returns
+/// `None` for other terms, ill-formed literals and values without an order,
+/// such as durations of a month and of 30 days.
+pub fn compare_terms(a: TermRef, b: TermRef) -> Option<Ordering> {
+    let (TermRef::Literal(a), TermRef::Literal(b)) = (a, b) else {
+        return None;
+    };
+    match (XsdValue::of(a)?, XsdValue::of(b)?) {
+        (XsdValue::Decimal(a), XsdValue::Decimal(b)) => Some(a.cmp(&b)),
+        (XsdValue::Decimal(a), XsdValue::Double(b)) => a.to_double().partial_cmp(&b),
+        (XsdValue::Double(a), XsdValue::Decimal(b)) => a.partial_cmp(&b.to_double()),
+        (XsdValue::Double(a), XsdValue::Double(b)) => a.partial_cmp(&b),
+        (XsdValue::DateTime(a), XsdValue::DateTime(b)) => a.partial_cmp(&b),
+        (XsdValue::Date(a), XsdValue::Date(b)) => a.partial_cmp(&b),
+        (XsdValue::Time(a), XsdValue::Time(b)) => a.partial_cmp(&b),
+        (XsdValue::GYear(a), XsdValue::GYear(b)) => a.partial_cmp(&b),
+        (XsdValue::GYearMonth(a), XsdValue::GYearMonth(b)) => a.partial_cmp(&b),
+        (XsdValue::GMonthDay(a), XsdValue::GMonthDay(b)) => a.partial_cmp(&b),
+        (XsdValue::GMonth(a), XsdValue::GMonth(b)) => a.partial_cmp(&b),
+        (XsdValue::GDay(a), XsdValue::GDay(b)) => a.partial_cmp(&b),
+        (XsdValue::Duration(a), XsdValue::Duration(b)) => a.partial_cmp(&b),
+        (XsdValue::Boolean(a), XsdValue::Boolean(b)) => a.partial_cmp(&b),
+        (XsdValue::String(a), XsdValue::String(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+/// Whether two terms are the same term, or literals with equal values as
+/// compared by [`compare_terms`], like `"1"^^xsd:int` and `"01"^^xsd:integer`.
+pub fn same_value(a: TermRef, b: TermRef) -> bool {
+    a == b || compare_terms(a, b) == Some(Ordering::Equal)
+}
+
+/// Value of a literal with an ordered XSD datatype.
+enum XsdValue<'a> {
+    Decimal(ExactDecimal<'a>),
+    Double(Double),
+    DateTime(DateTime),
+    Date(Date),
+    Time(Time),
+    GYear(GYear),
+    GYearMonth(GYearMonth),
+    GMonthDay(GMonthDay),
+    GMonth(GMonth),
+    GDay(GDay),
+    Duration(Duration),
+    Boolean(Boolean),
+    String(&'a str),
+}
+
+impl<'a> XsdValue<'a> {
+    fn of(literal: LiteralRef<'a>) -> Option<Self> {
+        let datatype = literal.datatype();
+        let value = literal.value();
+        if INTEGER_RANGES.iter().any(|(d, _, _)| *d == datatype) && value.contains('.') {
+            // Integers have no fractional part, not even a zero one
+            None
+        } else if DECIMAL_DATATYPES.contains(&datatype) {
+            ExactDecimal::parse(value).map(Self::Decimal)
+        } else if datatype == xsd::DOUBLE || datatype == xsd::FLOAT {
+            value.parse().ok().map(Self::Double)
+        } else if datatype == xsd::DATE_TIME || datatype == xsd::DATE_TIME_STAMP {
+            value.parse().ok().map(Self::DateTime)
+        } else if datatype == xsd::DATE {
+            value.parse().ok().map(Self::Date)
+        } else if datatype == xsd::TIME {
+            value.parse().ok().map(Self::Time)
+        } else if datatype == xsd::G_YEAR {
+            value.parse().ok().map(Self::GYear)
+        } else if datatype == xsd::G_YEAR_MONTH {
+            value.parse().ok().map(Self::GYearMonth)
+        } else if datatype == xsd::G_MONTH_DAY {
+            value.parse().ok().map(Self::GMonthDay)
+        } else if datatype == xsd::G_MONTH {
+            value.parse().ok().map(Self::GMonth)
+        } else if datatype == xsd::G_DAY {
+            value.parse().ok().map(Self::GDay)
+        } else if datatype == xsd::DURATION
+            || datatype == xsd::DAY_TIME_DURATION
+            || datatype == xsd::YEAR_MONTH_DURATION
+        {
+            value.parse().ok().map(Self::Duration)
+        } else if datatype == xsd::BOOLEAN {
+            value.parse().ok().map(Self::Boolean)
+        } else if datatype == xsd::STRING {
+            Some(Self::String(value))
+        } else {
+            None
+        }
+    }
+}
+
+/// A decimal of any size and precision, compared without rounding.
+#[derive(PartialEq, Eq)]
+struct ExactDecimal<'a> {
+    negative: bool,
+    /// Digits without leading zeros.
+    integer: &'a str,
+    /// Digits without trailing zeros.
+    fraction: &'a str,
+}
+
+impl<'a> ExactDecimal<'a> {
+    fn parse(value: &'a str) -> Option<Self> {
+        let (negative, unsigned) = match value.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, value.strip_prefix('+').unwrap_or(value)),
+        };
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if integer.is_empty() && fraction.is_empty()
+            || !integer
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let integer = integer.trim_start_matches('0');
+        let fraction = fraction.trim_end_matches('0');
+        Some(Self {
+            // -0 is 0
+            negative: negative && !(integer.is_empty() && fraction.is_empty()),
+            integer,
+            fraction,
+        })
+    }
+
+    fn to_double(&self) -> Double {
+        let sign = if self.negative { "-" } else { "" };
+        format!("{}0{}.{}0", sign, self.integer, self.fraction)
+            .parse()
+            .unwrap_or(Double::NAN)
+    }
+
+    fn cmp_magnitude(&self, other: &Self) -> Ordering {
+        self.integer
+            .len()
+            .cmp(&other.integer.len())
+            .then_with(|| self.integer.cmp(other.integer))
+            .then_with(|| self.fraction.cmp(other.fraction))
+    }
+}
+
+impl Ord for ExactDecimal<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => self.cmp_magnitude(other),
+            (true, true) => other.cmp_magnitude(self),
+        }
+    }
+}
+
+impl PartialOrd for ExactDecimal<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Serde helpers writing RDF terms in their N-Triples form, as in the JSON reports.
+///
+/// Use with `#[serde(with = "crate::utils::serde_rdf")]`, or the `option` and
+/// `keys` submodules for optional terms and maps keyed by terms.
+pub(crate) mod serde_rdf {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    use oxigraph::model::{Literal, NamedNode, NamedOrBlankNode, Term};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    /// A term with an N-Triples form.
+    pub(crate) trait RdfString: Sized {
+        fn to_rdf_string(&self) -> String;
+        fn from_rdf_string(s: &str) -> Result<Self, String>;
+    }
+
+    impl RdfString for Term {
+        fn to_rdf_string(&self) -> String {
+            self.to_string()
+        }
+
+        fn from_rdf_string(s: &str) -> Result<Self, String> {
+            s.parse().map_err(|e| format!("Invalid term {}: {}", s, e))
+        }
+    }
+
+    impl RdfString for NamedNode {
+        fn to_rdf_string(&self) -> String {
+            self.to_string()
+        }
+
+        fn from_rdf_string(s: &str) -> Result<Self, String> {
+            s.parse().map_err(|e| format!("Invalid IRI {}: {}", s, e))
+        }
+    }
+
+    impl RdfString for Literal {
+        fn to_rdf_string(&self) -> String {
+            self.to_string()
+        }
+
+        fn from_rdf_string(s: &str) -> Result<Self, String> {
+            s.parse()
+                .map_err(|e| format!("Invalid literal {}: {}", s, e))
+        }
+    }
+
+    impl RdfString for NamedOrBlankNode {
+        fn to_rdf_string(&self) -> String {
+            self.to_string()
+        }
+
+        fn from_rdf_string(s: &str) -> Result<Self, String> {
+            match Term::from_rdf_string(s)? {
+                Term::NamedNode(node) => Ok(node.into()),
+                Term::BlankNode(node) => Ok(node.into()),
+                _ => Err(format!("Expected an IRI or blank node, found {}", s)),
             }
         }
-        _ => false,
+    }
+
+    pub(crate) fn serialize<T: RdfString, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_rdf_string())
+    }
+
+    pub(crate) fn deserialize<'de, T: RdfString, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::from_rdf_string(&String::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+
+    pub(crate) mod option {
+        use super::*;
+
+        pub(crate) fn serialize<T: RdfString, S: Serializer>(
+            value: &Option<T>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match value {
+                Some(value) => serializer.serialize_some(&value.to_rdf_string()),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub(crate) fn deserialize<'de, T: RdfString, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<T>, D::Error> {
+            Option::<String>::deserialize(deserializer)?
+                .map(|s| T::from_rdf_string(&s).map_err(de::Error::custom))
+                .transpose()
+        }
+    }
+
+    pub(crate) mod keys {
+        use super::*;
+
+        pub(crate) fn serialize<K: RdfString, V: serde::Serialize, S: Serializer>(
+            map: &HashMap<K, V>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let mut entries: Vec<_> = map
+                .iter()
+                .map(|(key, value)| (key.to_rdf_string(), value))
+                .collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            serializer.collect_map(entries)
+        }
+
+        pub(crate) fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+        where
+            K: RdfString + Eq + Hash,
+            V: Deserialize<'de>,
+            D: Deserializer<'de>,
+        {
+            HashMap::<String, V>::deserialize(deserializer)?
+                .into_iter()
+                .map(|(key, value)| {
+                    K::from_rdf_string(&key)
+                        .map(|key| (key, value))
+                        .map_err(de::Error::custom)
+                })
+                .collect()
+        }
     }
 }
@@ -0,0 +1,106 @@
+//! Constraint components implemented in Rust.
+//!
+//! A component declared in the shapes graph with `sh:parameter` but no SPARQL
+//! validator is parsed into [`Constraint::Custom`](crate::Constraint::Custom)
+//! and validated by the [`ConstraintValidator`] registered for its IRI on the
+//! [`ValidationDataset`].
+
+use std::{collections::HashMap, sync::Arc};
+
+use oxigraph::model::{NamedNode, NamedNodeRef, Term, TermRef};
+
+use crate::{core::constraints::CustomConstraint, validation::dataset::ValidationDataset};
+
+/// A value node that does not satisfy a custom constraint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentViolation {
+    /// The offending value node, if any.
+    pub value: Option<Term>,
+    pub message: String,
+}
+
+impl ComponentViolation {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            value: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn with_value(mut self, value: impl Into<Term>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+}
+
+/// Validates the instances of a constraint component.
+pub trait ConstraintValidator: Send + Sync {
+    /// Violations of `constraint` by `focus_node` and its `value_nodes`.
+    fn validate(
+        &self,
+        validation_dataset: &ValidationDataset,
+        constraint: &CustomConstraint<'_>,
+        focus_node: TermRef<'_>,
+        value_nodes: &[TermRef<'_>],
+    ) -> Vec<ComponentViolation>;
+}
+
+/// Validators of constraint components, by component IRI.
+///
+/// ```
+/// use oxigraph::model::{NamedNode, TermRef};
+/// use shacl_rust::core::constraints::CustomConstraint;
+/// use shacl_rust::validation::components::{
+///     ComponentViolation, ConstraintComponentRegistry, ConstraintValidator,
+/// };
+/// use shacl_rust::validation::dataset::ValidationDataset;
+///
+/// struct NoBlankNodes;
+///
+/// impl ConstraintValidator for NoBlankNodes {
+///     fn validate(
+///         &self,
+///         _dataset: &ValidationDataset,
+///         _constraint: &CustomConstraint<'_>,
+///         _focus_node: TermRef<'_>,
+///         value_nodes: &[TermRef<'_>],
+///     ) -> Vec<ComponentViolation> {
+///         value_nodes
+///             .iter()
+///             .filter(|value| value.is_blank_node())
+///             .map(|value| ComponentViolation::new("Blank node value").with_value(value.into_owned()))
+///             .collect()
+///     }
+/// }
+///
+/// let mut registry = ConstraintComponentRegistry::new();
+/// let component = NamedNode::new("http://example.org/NoBlankNodesConstraintComponent").unwrap();
+/// registry.register(component.clone(), Box::new(NoBlankNodes));
+/// assert!(registry.get(component.as_ref()).is_some());
+/// ```
+#[derive(Clone, Default)]
+pub struct ConstraintComponentRegistry {
+    validators: HashMap<NamedNode, Arc<dyn ConstraintValidator>>,
+}
+
+impl ConstraintComponentRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates instances of the component `iri` with `validator`, replacing
+    /// any validator registered before.
+    pub fn register(&mut self, iri: NamedNode, validator: Box<dyn ConstraintValidator>) {
+        self.validators.insert(iri, Arc::from(validator));
+    }
+
+    pub fn get(&self, iri: NamedNodeRef<'_>) -> Option<&dyn ConstraintValidator> {
+        self.validators
+            .get(&iri.into_owned())
+            .map(|validator| validator.as_ref())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+}
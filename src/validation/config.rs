@@ -0,0 +1,137 @@
+//! Settings of a validation run that are not part of the shapes graph.
+
+use oxigraph::model::{NamedNode, TermRef};
+use oxsdatatypes::DateTime;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{core::transform::ValueTransform, utils};
+
+/// Settings applied to every shape of a validation run.
+///
+/// Set on a dataset with [`ValidationDataset::with_config`](crate::validation::dataset::ValidationDataset::with_config).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationConfig {
+    /// Transformations applied to literal values before facet checks, before
+    /// those declared on shapes with `shx:valueTransform`.
+    pub value_transforms: Vec<ValueTransform>,
+
+    /// Check `sh:minLength`, `sh:maxLength` and `sh:pattern` on NFC-normalized
+    /// values, counting lengths in grapheme clusters instead of characters.
+    pub unicode_mode: bool,
+
+    /// Reference time of `shx:minDaysFromNow` and `shx:maxDaysFromNow`; the
+    /// current time when unset.
+    pub now: Option<DateTime>,
+
+    /// Caps on the size of the validation results.
+    pub report_limits: ReportLimits,
+
+    /// Compare literals of `sh:equals`, `sh:disjoint`, `sh:in` and `sh:hasValue`
+    /// by value rather than as terms, so `"1"^^xsd:int` equals `"01"^^xsd:integer`.
+    pub compare_by_value: bool,
+
+    /// Which `rdfs:subClassOf` triples `sh:class` and `sh:targetClass` follow.
+    pub entailment: Entailment,
+
+    /// Predicates that make a node an instance of a class besides `rdf:type`,
+    /// for datasets such as Wikidata that use `wdt:P31`.
+    pub type_predicates: Vec<NamedNode>,
+
+    /// Record the time spent per shape and constraint component in the
+    /// report, see [`ValidationReport::profile`](crate::validation::report::ValidationReport::profile).
+    /// Needs a system clock, so not available on WebAssembly.
+    pub profile: bool,
+
+    /// How `sh:path` values are found in the data graph.
+    pub path_evaluation: PathEvaluation,
+
+    /// Bounds on the `sh:zeroOrMorePath` and `sh:oneOrMorePath` steps of
+    /// shape paths.
+    pub path_limits: PathLimits,
+}
+
+/// Bounds on each `*` or `+` path step walked from a focus node, so paths over
+/// huge graphs fail instead of running on. A focus node whose path goes beyond
+/// them gets a failure result and no other results. Unset bounds do not apply.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PathLimits {
+    /// Most nodes one step may reach.
+    pub max_visited_nodes: Option<usize>,
+
+    /// Most times one step may repeat.
+    pub max_depth: Option<usize>,
+}
+
+impl PathLimits {
+    pub fn is_unbounded(&self) -> bool {
+        self.max_visited_nodes.is_none() && self.max_depth.is_none()
+    }
+}
+
+/// Evaluation strategy of SHACL paths.
+///
+/// The walker is the default: it uses the indexes of the data graph directly
+/// and is about ten times faster than SPARQL in `benches/path_evaluation.rs`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PathEvaluation {
+    /// As SPARQL property paths on the validation store, walking the data
+    /// graph only for paths without a SPARQL form.
+    Sparql,
+    /// Walking the data graph step by step.
+    #[default]
+    Walker,
+}
+
+/// Class hierarchy used to find the SHACL instances of a class.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Entailment {
+    /// `rdfs:subClassOf` triples of the data graph, as in the SHACL specification.
+    #[default]
+    Data,
+    /// `rdfs:subClassOf` triples of the data and shapes graphs, for shapes
+    /// graphs that carry the ontology.
+    DataAndShapes,
+    /// No subclasses: only nodes with an `rdf:type` triple to the class itself.
+    None,
+}
+
+/// Caps applied to validation results as they are built, so shapes that hit
+/// huge literals do not produce huge reports. Unset caps do not apply.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReportLimits {
+    /// Longest message kept, in characters; longer ones are cut off with "…".
+    pub max_message_length: Option<usize>,
+
+    /// Longest literal value kept, in characters; longer ones are replaced by a
+    /// prefix, their length and a hash of the full value.
+    pub max_value_length: Option<usize>,
+
+    /// How many levels of nested results (`sh:detail`) are kept.
+    pub max_details_depth: Option<usize>,
+}
+
+impl ValidationConfig {
+    /// The configured reference time, or the current time.
+    pub fn reference_time(&self) -> DateTime {
+        self.now.unwrap_or_else(DateTime::now)
+    }
+
+    /// Whether `a` and `b` are equal for the term comparing constraints.
+    pub fn terms_equal(&self, a: TermRef<'_>, b: TermRef<'_>) -> bool {
+        if self.compare_by_value {
+            utils::same_value(a, b)
+        } else {
+            a == b
+        }
+    }
+
+    /// Length of a (transformed) lexical form as used by the length constraints.
+    pub fn string_length(&self, value: &str) -> usize {
+        if self.unicode_mode {
+            value.nfc().collect::<String>().graphemes(true).count()
+        } else {
+            value.chars().count()
+        }
+    }
+}
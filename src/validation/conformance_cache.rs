@@ -0,0 +1,67 @@
+//! Reports of value nodes against nested shapes, shared while one focus node is validated.
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use oxigraph::model::TermRef;
+
+use crate::{
+    core::shape::Shape,
+    validation::{dataset::ValidationDataset, report::ValidationReport},
+};
+
+/// A shape, by address, and a value node.
+type Key<'a> = (usize, TermRef<'a>);
+
+/// Reports of value nodes against the shapes of `sh:and`, `sh:or`, `sh:xone`,
+/// `sh:not`, `sh:node` and qualified value shapes.
+///
+/// Branches that reference the same shape for the same value node are
+/// validated once. Shapes are told apart by address, which is stable as
+/// referenced shapes are shared.
+#[derive(Default)]
+pub(crate) struct ConformanceCache<'a> {
+    /// `None` while the shape is being validated.
+    reports: RefCell<HashMap<Key<'a>, Option<Rc<ValidationReport<'a>>>>>,
+}
+
+impl<'a> ConformanceCache<'a> {
+    /// Report of `value_node` against `shape`, validated on first use.
+    ///
+    /// A value node that comes back to a shape it is still being validated
+    /// against is taken to conform, so recursive shapes terminate.
+    pub(crate) fn report(
+        &self,
+        validation_dataset: &'a ValidationDataset,
+        shape: &'a Shape<'a>,
+        value_node: TermRef<'a>,
+    ) -> Rc<ValidationReport<'a>> {
+        let key = (shape as *const Shape<'a> as usize, value_node);
+        match self.reports.borrow().get(&key) {
+            Some(Some(report)) => return Rc::clone(report),
+            Some(None) => return Rc::new(ValidationReport::new()),
+            None => {}
+        }
+
+        self.reports.borrow_mut().insert(key, None);
+        let mut report = ValidationReport::new();
+        if !shape.deactivated {
+            shape.validate_focus_node_with(validation_dataset, value_node, &mut report, self);
+        }
+        let report = Rc::new(report);
+        self.reports
+            .borrow_mut()
+            .insert(key, Some(Rc::clone(&report)));
+        report
+    }
+
+    pub(crate) fn conforms(
+        &self,
+        validation_dataset: &'a ValidationDataset,
+        shape: &'a Shape<'a>,
+        value_node: TermRef<'a>,
+    ) -> bool {
+        *self
+            .report(validation_dataset, shape, value_node)
+            .get_conforms()
+    }
+}
@@ -0,0 +1,59 @@
+use log::warn;
+use oxigraph::model::TermRef;
+
+use crate::{
+    core::{
+        component::ConstraintComponent, constraints::CustomConstraint, path::Path, shape::Shape,
+    },
+    validation::{
+        dataset::ValidationDataset, dataset_constraint::find_in_graph, Validate, ValidationResult,
+        ViolationBuilder,
+    },
+    ShaclError,
+};
+
+impl<'a> Validate<'a> for CustomConstraint<'a> {
+    fn validate(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        _path: Option<&'a Path<'a>>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        let Some(validator) = validation_dataset
+            .constraint_components()
+            .get(self.component)
+        else {
+            // Reported as an unsupported feature of the run.
+            return Ok(Vec::new());
+        };
+
+        let mut violations = Vec::new();
+        for violation in validator.validate(validation_dataset, self, focus_node, value_nodes) {
+            let mut builder = ViolationBuilder::new(focus_node)
+                .message(violation.message)
+                .component(ConstraintComponent::Other(self.component))
+                .detail(format!("component {}", self));
+            if let Some(value) = &violation.value {
+                // Borrow the value from the value nodes or graphs so results can refer to it.
+                let value_node = value_nodes
+                    .iter()
+                    .copied()
+                    .find(|value_node| *value_node == value.as_ref())
+                    .or_else(|| find_in_graph(validation_dataset.data_graph(), value))
+                    .or_else(|| find_in_graph(validation_dataset.shapes_graph(), value));
+                match value_node {
+                    Some(value_node) => builder = builder.value(value_node),
+                    None => warn!(
+                        "Dropping value {} of {} from its result: not found in the data graph",
+                        value, self.component
+                    ),
+                }
+            }
+            violations.push(shape.build_validation_result(builder));
+        }
+
+        Ok(violations)
+    }
+}
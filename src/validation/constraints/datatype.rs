@@ -1,9 +1,11 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::DatatypeConstraint, path::Path, shape::Shape},
+    core::{
+        component::ConstraintComponent, constraints::DatatypeConstraint, path::Path, shape::Shape,
+    },
+    utils,
     validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
     ShaclError,
 };
 
@@ -23,7 +25,7 @@ impl<'a> Validate<'a> for DatatypeConstraint<'a> {
                 let builder = ViolationBuilder::new(focus_node)
                     .value(value_node)
                     .message("Value is not a literal")
-                    .component(sh::DATATYPE_CONSTRAINT_COMPONENT)
+                    .component(ConstraintComponent::Datatype)
                     .detail(format!("sh:datatype {}", self.0));
 
                 violations.push(shape.build_validation_result(builder));
@@ -34,7 +36,19 @@ impl<'a> Validate<'a> for DatatypeConstraint<'a> {
                 let builder = ViolationBuilder::new(focus_node)
                     .value(value_node)
                     .message(format!("Value does not have datatype: {}", self.0))
-                    .component(sh::DATATYPE_CONSTRAINT_COMPONENT)
+                    .component(ConstraintComponent::Datatype)
+                    .detail(format!("sh:datatype {}", self.0));
+
+                violations.push(shape.build_validation_result(builder));
+            } else if !utils::is_valid_lexical_form(lit) {
+                let builder = ViolationBuilder::new(focus_node)
+                    .value(value_node)
+                    .message(format!(
+                        "Value \"{}\" is not a valid lexical form of {}",
+                        lit.value(),
+                        self.0
+                    ))
+                    .component(ConstraintComponent::Datatype)
                     .detail(format!("sh:datatype {}", self.0));
 
                 violations.push(shape.build_validation_result(builder));
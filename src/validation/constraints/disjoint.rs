@@ -2,10 +2,11 @@ use oxigraph::model::TermRef;
 use std::collections::HashSet;
 
 use crate::{
-    core::{constraints::DisjointConstraint, path::Path, shape::Shape},
+    core::{
+        component::ConstraintComponent, constraints::DisjointConstraint, path::Path, shape::Shape,
+    },
     utils,
     validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
     ShaclError,
 };
 
@@ -24,11 +25,8 @@ impl<'a> Validate<'a> for DisjointConstraint<'a> {
             return Ok(violations);
         };
 
-        let data_graph = validation_dataset.data_graph();
-
-        let other_values: HashSet<TermRef<'a>> = self
-            .0
-            .resolve_path_for_given_node(data_graph, &focus_as_node)
+        let other_values: HashSet<TermRef<'a>> = validation_dataset
+            .resolve_path(&self.0, focus_as_node)?
             .into_iter()
             .collect();
 
@@ -39,11 +37,14 @@ impl<'a> Validate<'a> for DisjointConstraint<'a> {
         };
 
         for node in nodes_to_check {
-            if other_values.contains(&node) {
+            if other_values
+                .iter()
+                .any(|&other| validation_dataset.config().terms_equal(node, other))
+            {
                 let builder = ViolationBuilder::new(focus_node)
                     .value(node)
                     .message("Value appears in both properties (not disjoint)")
-                    .component(sh::DISJOINT_CONSTRAINT_COMPONENT)
+                    .component(ConstraintComponent::Disjoint)
                     .detail("sh:disjoint");
                 violations.push(shape.build_validation_result(builder));
             }
@@ -2,10 +2,11 @@ use oxigraph::model::TermRef;
 use std::collections::HashSet;
 
 use crate::{
-    core::{constraints::EqualsConstraint, path::Path, shape::Shape},
+    core::{
+        component::ConstraintComponent, constraints::EqualsConstraint, path::Path, shape::Shape,
+    },
     utils,
     validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
     ShaclError,
 };
 
@@ -24,46 +25,51 @@ impl<'a> Validate<'a> for EqualsConstraint<'a> {
             return Ok(violations);
         };
 
-        let data_graph = validation_dataset.data_graph();
+        let config = validation_dataset.config();
 
-        let other_values: HashSet<TermRef<'a>> = self
-            .0
-            .resolve_path_for_given_node(data_graph, &focus_as_node)
+        let other_values: HashSet<TermRef<'a>> = validation_dataset
+            .resolve_path(&self.0, focus_as_node)?
             .into_iter()
             .collect();
 
         if path.is_some() {
+            let contained_in = |values: &HashSet<TermRef<'a>>, others: &HashSet<TermRef<'a>>| {
+                values
+                    .iter()
+                    .all(|&value| others.iter().any(|&other| config.terms_equal(value, other)))
+            };
             let current_values: HashSet<TermRef<'a>> = value_nodes.iter().copied().collect();
 
-            if current_values != other_values {
+            if !contained_in(&current_values, &other_values)
+                || !contained_in(&other_values, &current_values)
+            {
                 let builder = ViolationBuilder::new(focus_node)
                     .message(format!("Values do not equal values of property {}", self.0))
-                    .component(sh::EQUALS_CONSTRAINT_COMPONENT)
+                    .component(ConstraintComponent::Equals)
                     .detail(format!("sh:equals {}", self.0));
 
                 violations.push(shape.build_validation_result(builder));
             }
-        }
-        if other_values.is_empty() {
+        } else if other_values.is_empty() {
             let builder = ViolationBuilder::new(focus_node)
                 .message(format!(
                     "Focus node does not equal (no values of property {})",
                     self.0
                 ))
-                .component(sh::EQUALS_CONSTRAINT_COMPONENT)
+                .component(ConstraintComponent::Equals)
                 .detail(format!("sh:equals {}", self.0));
 
             violations.push(shape.build_validation_result(builder));
         } else {
             for other_value in other_values {
-                if focus_node != other_value {
+                if !config.terms_equal(focus_node, other_value) {
                     let builder = ViolationBuilder::new(focus_node)
                         .value(other_value)
                         .message(format!(
                             "Focus node does not equal value of property {}",
                             self.0
                         ))
-                        .component(sh::EQUALS_CONSTRAINT_COMPONENT)
+                        .component(ConstraintComponent::Equals)
                         .detail(format!("sh:equals {}", self.0));
 
                     violations.push(shape.build_validation_result(builder));
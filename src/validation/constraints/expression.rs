@@ -0,0 +1,50 @@
+use oxigraph::model::{vocab::xsd, TermRef};
+
+use crate::{
+    core::{
+        component::ConstraintComponent, constraints::ExpressionConstraint, path::Path, shape::Shape,
+    },
+    validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
+    ShaclError,
+};
+
+impl<'a> Validate<'a> for ExpressionConstraint<'a> {
+    fn validate(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        _path: Option<&'a Path<'a>>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        let mut violations = Vec::new();
+
+        for &value_node in value_nodes {
+            let nodes = self.0.evaluate(validation_dataset, value_node)?;
+            let is_true = matches!(
+                nodes.as_slice(),
+                [TermRef::Literal(literal)]
+                    if literal.datatype() == xsd::BOOLEAN && literal.value() == "true"
+            );
+            if !is_true {
+                let found = nodes
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let builder = ViolationBuilder::new(focus_node)
+                    .value(value_node)
+                    .message(format!(
+                        "Node expression evaluated to [{}] instead of true",
+                        found
+                    ))
+                    .component(ConstraintComponent::Expression)
+                    .detail(format!("sh:expression {}", self.0));
+
+                violations.push(shape.build_validation_result(builder));
+            }
+        }
+
+        Ok(violations)
+    }
+}
@@ -0,0 +1,54 @@
+use oxigraph::model::TermRef;
+
+use crate::{
+    core::{
+        component::ConstraintComponent, constraints::FractionDigitsConstraint, path::Path,
+        shape::Shape,
+    },
+    utils::decimal_digits,
+    validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
+    vocab::shx,
+    ShaclError,
+};
+
+impl<'a> Validate<'a> for FractionDigitsConstraint {
+    fn validate(
+        &'a self,
+        _validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        _path: Option<&'a Path<'a>>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        let mut violations = Vec::new();
+
+        for &value_node in value_nodes {
+            let digits = match value_node {
+                TermRef::Literal(lit) => decimal_digits(lit),
+                _ => None,
+            };
+            let message = match digits {
+                None => "Value is not a decimal".to_string(),
+                Some((_, fraction)) if fraction as i32 > self.0 => {
+                    format!(
+                        "Value has {} fractional digits, more than {}",
+                        fraction, self.0
+                    )
+                }
+                Some(_) => continue,
+            };
+
+            let builder = ViolationBuilder::new(focus_node)
+                .value(value_node)
+                .message(message)
+                .component(ConstraintComponent::Other(
+                    shx::FRACTION_DIGITS_CONSTRAINT_COMPONENT,
+                ))
+                .detail(format!("shx:fractionDigits {}", self.0));
+
+            violations.push(shape.build_validation_result(builder));
+        }
+
+        Ok(violations)
+    }
+}
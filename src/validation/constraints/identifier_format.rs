@@ -0,0 +1,47 @@
+use oxigraph::model::TermRef;
+
+use crate::{
+    core::{
+        component::ConstraintComponent, constraints::IdentifierFormatConstraint, path::Path,
+        shape::Shape,
+    },
+    validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
+    vocab::shx,
+    ShaclError,
+};
+
+impl<'a> Validate<'a> for IdentifierFormatConstraint {
+    fn validate(
+        &'a self,
+        _validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        _path: Option<&'a Path<'a>>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        let mut violations = Vec::new();
+
+        for &value_node in value_nodes {
+            let value = match value_node {
+                TermRef::NamedNode(nn) => nn.as_str(),
+                TermRef::Literal(lit) => lit.value(),
+                TermRef::BlankNode(_) => "",
+            };
+            let Err(reason) = self.0.check(value) else {
+                continue;
+            };
+
+            let builder = ViolationBuilder::new(focus_node)
+                .value(value_node)
+                .message(format!("Value is not a valid {}: {}", self.0, reason))
+                .component(ConstraintComponent::Other(
+                    shx::IDENTIFIER_FORMAT_CONSTRAINT_COMPONENT,
+                ))
+                .detail(format!("shx:identifierFormat shx:{}", self.0));
+
+            violations.push(shape.build_validation_result(builder));
+        }
+
+        Ok(violations)
+    }
+}
@@ -1,9 +1,11 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::LanguageInConstraint, path::Path, shape::Shape},
+    core::{
+        component::ConstraintComponent, constraints::LanguageInConstraint, path::Path, shape::Shape,
+    },
+    utils,
     validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
     ShaclError,
 };
 
@@ -22,11 +24,15 @@ impl<'a> Validate<'a> for LanguageInConstraint {
         for &value_node in value_nodes {
             if let TermRef::Literal(lit) = value_node {
                 if let Some(lang) = lit.language() {
-                    if !self.0.iter().any(|l| l.eq_ignore_ascii_case(lang)) {
+                    if !self
+                        .0
+                        .iter()
+                        .any(|range| utils::language_matches(lang, range))
+                    {
                         let builder = ViolationBuilder::new(focus_node)
                             .value(value_node)
                             .message(format!("Language '{}' not in allowed list", lang))
-                            .component(sh::LANGUAGE_IN_CONSTRAINT_COMPONENT)
+                            .component(ConstraintComponent::LanguageIn)
                             .detail(format!("sh:languageIn [{}]", allowed_languages));
 
                         violations.push(shape.build_validation_result(builder));
@@ -35,7 +41,7 @@ impl<'a> Validate<'a> for LanguageInConstraint {
                     let builder = ViolationBuilder::new(focus_node)
                         .value(value_node)
                         .message("Value has no language tag")
-                        .component(sh::LANGUAGE_IN_CONSTRAINT_COMPONENT)
+                        .component(ConstraintComponent::LanguageIn)
                         .detail(format!("sh:languageIn [{}]", allowed_languages));
 
                     violations.push(shape.build_validation_result(builder));
@@ -1,10 +1,11 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::LessThanConstraint, path::Path, shape::Shape},
+    core::{
+        component::ConstraintComponent, constraints::LessThanConstraint, path::Path, shape::Shape,
+    },
     utils,
     validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
     ShaclError,
 };
 
@@ -23,11 +24,7 @@ impl<'a> Validate<'a> for LessThanConstraint<'a> {
             return Ok(violations);
         };
 
-        let data_graph = validation_dataset.data_graph();
-
-        let other_values = self
-            .0
-            .resolve_path_for_given_node(data_graph, &focus_as_node);
+        let other_values = validation_dataset.resolve_path(&self.0, focus_as_node)?;
 
         let nodes_to_check = if path.is_some() {
             value_nodes.to_vec()
@@ -35,22 +32,24 @@ impl<'a> Validate<'a> for LessThanConstraint<'a> {
             vec![focus_node]
         };
 
+        // Every pair of a value and a value of the other property must be ordered.
         for node in nodes_to_check {
-            let mut found_valid = false;
-            for other_value in &other_values {
-                if utils::compare_values(node, *other_value, |cmp| cmp < 0) {
-                    found_valid = true;
-                    break;
-                }
-            }
-            if !found_valid && !other_values.is_empty() {
+            for &other_value in &other_values {
+                let message = match utils::compare_terms(node, other_value) {
+                    Some(cmp) if cmp.is_lt() => continue,
+                    Some(_) => format!(
+                        "Value is not less than value {} of property {}",
+                        other_value, self.0
+                    ),
+                    None => format!(
+                        "Value cannot be compared with value {} of property {}",
+                        other_value, self.0
+                    ),
+                };
                 let builder = ViolationBuilder::new(focus_node)
                     .value(node)
-                    .message(format!(
-                        "Value is not less than values of property {}",
-                        self.0
-                    ))
-                    .component(sh::LESS_THAN_CONSTRAINT_COMPONENT)
+                    .message(message)
+                    .component(ConstraintComponent::LessThan)
                     .detail(format!("sh:lessThan {}", self.0));
 
                 violations.push(shape.build_validation_result(builder));
@@ -2,10 +2,12 @@ use oxigraph::model::TermRef;
 use std::collections::HashSet;
 
 use crate::{
-    core::{constraints::LessThanOrEqualsConstraint, path::Path, shape::Shape},
+    core::{
+        component::ConstraintComponent, constraints::LessThanOrEqualsConstraint, path::Path,
+        shape::Shape,
+    },
     utils,
     validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
     ShaclError,
 };
 
@@ -24,11 +26,8 @@ impl<'a> Validate<'a> for LessThanOrEqualsConstraint<'a> {
 
         let mut violations = Vec::new();
 
-        let data_graph = validation_dataset.data_graph();
-
-        let other_values: HashSet<TermRef<'a>> = self
-            .0
-            .resolve_path_for_given_node(data_graph, &focus_as_node)
+        let other_values: HashSet<TermRef<'a>> = validation_dataset
+            .resolve_path(&self.0, focus_as_node)?
             .into_iter()
             .collect();
 
@@ -38,22 +37,24 @@ impl<'a> Validate<'a> for LessThanOrEqualsConstraint<'a> {
             vec![focus_node]
         };
 
+        // Every pair of a value and a value of the other property must be ordered.
         for node in nodes_to_check {
-            let mut found_valid = false;
-            for other_value in &other_values {
-                if utils::compare_values(node, *other_value, |cmp| cmp <= 0) {
-                    found_valid = true;
-                    break;
-                }
-            }
-            if !found_valid && !other_values.is_empty() {
+            for &other_value in &other_values {
+                let message = match utils::compare_terms(node, other_value) {
+                    Some(cmp) if cmp.is_le() => continue,
+                    Some(_) => format!(
+                        "Value is not less than or equal to value {} of property {}",
+                        other_value, self.0
+                    ),
+                    None => format!(
+                        "Value cannot be compared with value {} of property {}",
+                        other_value, self.0
+                    ),
+                };
                 let builder = ViolationBuilder::new(focus_node)
                     .value(node)
-                    .message(format!(
-                        "Value is not less than or equal to values of property {}",
-                        self.0
-                    ))
-                    .component(sh::LESS_THAN_OR_EQUALS_CONSTRAINT_COMPONENT)
+                    .message(message)
+                    .component(ConstraintComponent::LessThanOrEquals)
                     .detail(format!("sh:lessThanOrEquals {}", self.0));
 
                 violations.push(shape.build_validation_result(builder));
@@ -1,9 +1,10 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::MaxCountConstraint, path::Path, shape::Shape},
+    core::{
+        component::ConstraintComponent, constraints::MaxCountConstraint, path::Path, shape::Shape,
+    },
     validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
     ShaclError,
 };
 
@@ -20,7 +21,7 @@ impl<'a> Validate<'a> for MaxCountConstraint {
         if count > self.0 {
             let builder = ViolationBuilder::new(focus_node)
                 .message(format!("Property has {} values (max: {})", count, self.0))
-                .component(sh::MAX_COUNT_CONSTRAINT_COMPONENT)
+                .component(ConstraintComponent::MaxCount)
                 .detail(format!("sh:maxCount {}", self.0));
 
             let result = shape.build_validation_result(builder);
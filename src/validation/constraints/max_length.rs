@@ -1,16 +1,17 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::MaxLengthConstraint, path::Path, shape::Shape},
+    core::{
+        component::ConstraintComponent, constraints::MaxLengthConstraint, path::Path, shape::Shape,
+    },
     validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
     ShaclError,
 };
 
 impl<'a> Validate<'a> for MaxLengthConstraint {
     fn validate(
         &'a self,
-        _validation_dataset: &'a ValidationDataset,
+        validation_dataset: &'a ValidationDataset,
         focus_node: TermRef<'a>,
         _path: Option<&'a Path<'a>>,
         value_nodes: &[TermRef<'a>],
@@ -19,17 +20,42 @@ impl<'a> Validate<'a> for MaxLengthConstraint {
         let mut violations = Vec::new();
 
         for &value_node in value_nodes {
-            let TermRef::Literal(lit) = value_node else {
-                continue;
+            let (kind, len, trace) = match value_node {
+                TermRef::Literal(lit) => {
+                    let (value, trace) = shape.transformed_value(validation_dataset, lit.value());
+                    (
+                        "String",
+                        validation_dataset.config().string_length(&value),
+                        trace,
+                    )
+                }
+                // IRIs are checked by their string representation, without transforms.
+                TermRef::NamedNode(iri) => (
+                    "IRI",
+                    validation_dataset.config().string_length(iri.as_str()),
+                    None,
+                ),
+                TermRef::BlankNode(_) => {
+                    let builder = ViolationBuilder::new(focus_node)
+                        .value(value_node)
+                        .message("Blank nodes have no string length")
+                        .component(ConstraintComponent::MaxLength)
+                        .detail(format!("sh:maxLength {}", self.0));
+                    violations.push(shape.build_validation_result(builder));
+                    continue;
+                }
             };
-
-            let len = lit.value().len() as i32;
+            let len = len as i32;
             if len > self.0 {
                 let builder = ViolationBuilder::new(focus_node)
                     .value(value_node)
-                    .message(format!("String length {} exceeds maximum {}", len, self.0))
-                    .component(sh::MAX_LENGTH_CONSTRAINT_COMPONENT)
-                    .detail(format!("sh:maxLength {}", self.0));
+                    .message(format!(
+                        "{} length {} exceeds maximum {}",
+                        kind, len, self.0
+                    ))
+                    .component(ConstraintComponent::MaxLength)
+                    .detail(format!("sh:maxLength {}", self.0))
+                    .trace(trace.into_iter().collect());
 
                 violations.push(shape.build_validation_result(builder));
             }
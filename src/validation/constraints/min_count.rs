@@ -1,9 +1,10 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::MinCountConstraint, path::Path, shape::Shape},
+    core::{
+        component::ConstraintComponent, constraints::MinCountConstraint, path::Path, shape::Shape,
+    },
     validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
     ShaclError,
 };
 
@@ -20,7 +21,7 @@ impl<'a> Validate<'a> for MinCountConstraint {
         if count < self.0 {
             let builder = ViolationBuilder::new(focus_node)
                 .message(format!("Property has {} values (min: {})", count, self.0))
-                .component(sh::MIN_COUNT_CONSTRAINT_COMPONENT)
+                .component(ConstraintComponent::MinCount)
                 .detail(format!("sh:minCount {}", self.0));
 
             let result = shape.build_validation_result(builder);
@@ -1,17 +1,19 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::MinInclusiveConstraint, path::Path, shape::Shape},
+    core::{
+        component::ConstraintComponent, constraints::MinInclusiveConstraint, path::Path,
+        shape::Shape,
+    },
     utils,
     validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
     ShaclError,
 };
 
 impl<'a> Validate<'a> for MinInclusiveConstraint<'a> {
     fn validate(
         &'a self,
-        _validation_dataset: &'a ValidationDataset,
+        validation_dataset: &'a ValidationDataset,
         focus_node: TermRef<'a>,
         _path: Option<&'a Path<'a>>,
         value_nodes: &[TermRef<'a>],
@@ -20,18 +22,29 @@ impl<'a> Validate<'a> for MinInclusiveConstraint<'a> {
         let mut violations = Vec::new();
 
         for &value_node in value_nodes {
-            if !utils::compare_values(value_node, self.0, |cmp| cmp >= 0) {
-                let builder = ViolationBuilder::new(focus_node)
-                    .value(value_node)
-                    .message(format!(
-                        "Value {} is less than minimum {}",
-                        value_node, self.0
-                    ))
-                    .component(sh::MIN_INCLUSIVE_CONSTRAINT_COMPONENT)
-                    .detail(format!("sh:minInclusive {}", self.0));
+            let (message, trace) = match shape.value_in_unit(validation_dataset, value_node) {
+                Ok((value, trace)) => match utils::compare_terms(value.as_ref(), self.0) {
+                    Some(cmp) if cmp.is_ge() => continue,
+                    Some(_) => (
+                        format!("Value {} is less than minimum {}", value_node, self.0),
+                        trace,
+                    ),
+                    None => (
+                        format!("Value {} cannot be compared with {}", value_node, self.0),
+                        trace,
+                    ),
+                },
+                Err(reason) => (reason, None),
+            };
 
-                violations.push(shape.build_validation_result(builder));
-            }
+            let builder = ViolationBuilder::new(focus_node)
+                .value(value_node)
+                .message(message)
+                .component(ConstraintComponent::MinInclusive)
+                .detail(format!("sh:minInclusive {}", self.0))
+                .trace(trace.into_iter().collect());
+
+            violations.push(shape.build_validation_result(builder));
         }
 
         Ok(violations)
@@ -0,0 +1,58 @@
+use oxigraph::model::TermRef;
+
+use crate::{
+    core::{
+        component::ConstraintComponent, constraints::NamespaceInConstraint, path::Path,
+        shape::Shape,
+    },
+    validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
+    vocab::shx,
+    ShaclError,
+};
+
+impl<'a> Validate<'a> for NamespaceInConstraint {
+    fn validate(
+        &'a self,
+        _validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        _path: Option<&'a Path<'a>>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        let mut violations = Vec::new();
+
+        for &value_node in value_nodes {
+            let message = match value_node {
+                TermRef::NamedNode(iri) => {
+                    if self
+                        .0
+                        .iter()
+                        .any(|ns| iri.as_str().starts_with(ns.as_str()))
+                    {
+                        continue;
+                    }
+                    match self.0.as_slice() {
+                        [namespace] => format!("IRI is not in namespace <{}>", namespace),
+                        namespaces => format!(
+                            "IRI is not in any of the namespaces <{}>",
+                            namespaces.join(">, <")
+                        ),
+                    }
+                }
+                _ => "Value is not an IRI".to_string(),
+            };
+
+            let builder = ViolationBuilder::new(focus_node)
+                .value(value_node)
+                .message(message)
+                .component(ConstraintComponent::Other(
+                    shx::NAMESPACE_IN_CONSTRAINT_COMPONENT,
+                ))
+                .detail(format!("shx:namespaceIn ({})", self.0.join(" ")));
+
+            violations.push(shape.build_validation_result(builder));
+        }
+
+        Ok(violations)
+    }
+}
@@ -1,9 +1,10 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::NodeKindConstraint, path::Path, shape::Shape},
+    core::{
+        component::ConstraintComponent, constraints::NodeKindConstraint, path::Path, shape::Shape,
+    },
     validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
     ShaclError,
 };
 
@@ -19,26 +20,13 @@ impl<'a> Validate<'a> for NodeKindConstraint {
         let mut violations = Vec::new();
 
         for &value_node in value_nodes {
-            let valid = match self.0 {
-                crate::core::constraints::NodeKind::BlankNode => value_node.is_blank_node(),
-                crate::core::constraints::NodeKind::IRI => value_node.is_named_node(),
-                crate::core::constraints::NodeKind::Literal => value_node.is_literal(),
-                crate::core::constraints::NodeKind::BlankNodeOrIRI => {
-                    value_node.is_blank_node() || value_node.is_named_node()
-                }
-                crate::core::constraints::NodeKind::BlankNodeOrLiteral => {
-                    value_node.is_blank_node() || value_node.is_literal()
-                }
-                crate::core::constraints::NodeKind::IRIOrLiteral => {
-                    value_node.is_named_node() || value_node.is_literal()
-                }
-            };
+            let valid = self.0.matches(value_node);
 
             if !valid {
                 let builder = ViolationBuilder::new(focus_node)
                     .value(value_node)
                     .message(format!("Value does not have node kind: {}", self.0))
-                    .component(sh::NODE_KIND_CONSTRAINT_COMPONENT)
+                    .component(ConstraintComponent::NodeKind)
                     .detail(format!("sh:nodeKind {}", self.0));
 
                 violations.push(shape.build_validation_result(builder));
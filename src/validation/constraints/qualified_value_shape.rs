@@ -1,10 +1,14 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::QualifiedValueShapeConstraint, path::Path, shape::Shape},
-    utils,
-    validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
+    core::{
+        component::ConstraintComponent, constraints::QualifiedValueShapeConstraint, path::Path,
+        shape::Shape,
+    },
+    validation::{
+        conformance_cache::ConformanceCache, dataset::ValidationDataset, Validate,
+        ValidationResult, ViolationBuilder,
+    },
     ShaclError,
 };
 
@@ -16,6 +20,25 @@ impl<'a> Validate<'a> for QualifiedValueShapeConstraint<'a> {
         _path: Option<&'a Path<'a>>,
         value_nodes: &[TermRef<'a>],
         shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        self.validate_with(
+            validation_dataset,
+            focus_node,
+            value_nodes,
+            shape,
+            &ConformanceCache::default(),
+        )
+    }
+}
+
+impl<'a> QualifiedValueShapeConstraint<'a> {
+    pub(crate) fn validate_with(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+        cache: &ConformanceCache<'a>,
     ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
         let mut violations = Vec::new();
 
@@ -26,10 +49,10 @@ impl<'a> Validate<'a> for QualifiedValueShapeConstraint<'a> {
         let mut conforming_count = 0;
 
         for &value_node in value_nodes {
-            if let Some(value_as_node) = utils::term_to_named_or_blank(value_node) {
-                if self.shape.validate_node(validation_dataset, value_as_node) {
-                    conforming_count += 1;
-                }
+            if (value_node.is_named_node() || value_node.is_blank_node())
+                && cache.conforms(validation_dataset, &self.shape, value_node)
+            {
+                conforming_count += 1;
             }
         }
 
@@ -40,7 +63,7 @@ impl<'a> Validate<'a> for QualifiedValueShapeConstraint<'a> {
                         "Qualified value shape: {} values conform (min: {})",
                         conforming_count, min
                     ))
-                    .component(sh::QUALIFIED_MIN_COUNT_CONSTRAINT_COMPONENT)
+                    .component(ConstraintComponent::QualifiedMinCount)
                     .detail(format!("sh:qualifiedMinCount {}", min));
 
                 violations.push(shape.build_validation_result(builder));
@@ -54,7 +77,7 @@ impl<'a> Validate<'a> for QualifiedValueShapeConstraint<'a> {
                         "Qualified value shape: {} values conform (max: {})",
                         conforming_count, max
                     ))
-                    .component(sh::QUALIFIED_MAX_COUNT_CONSTRAINT_COMPONENT)
+                    .component(ConstraintComponent::QualifiedMaxCount)
                     .detail(format!("sh:qualifiedMaxCount {}", max));
 
                 violations.push(shape.build_validation_result(builder));
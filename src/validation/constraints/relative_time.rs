@@ -0,0 +1,115 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use oxigraph::model::{vocab::xsd, LiteralRef, TermRef};
+use oxsdatatypes::{Date, DateTime, DayTimeDuration};
+
+use crate::{
+    core::{
+        component::ConstraintComponent, constraints::RelativeTimeConstraint, path::Path,
+        shape::Shape,
+    },
+    validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
+    vocab::shx,
+    ShaclError,
+};
+
+/// A date or date-time value node.
+enum Temporal {
+    Date(Date),
+    DateTime(DateTime),
+}
+
+impl Temporal {
+    /// Parses `xsd:date`, `xsd:dateTime` and `xsd:dateTimeStamp` literals.
+    ///
+    /// Values without a timezone are read as UTC so they can be compared with the reference time.
+    fn parse(lit: LiteralRef<'_>) -> Option<Self> {
+        let datatype = lit.datatype();
+        if datatype == xsd::DATE {
+            let date = Date::from_str(lit.value()).ok()?;
+            match date.timezone() {
+                Some(_) => Some(Temporal::Date(date)),
+                None => Date::from_str(&format!("{}Z", lit.value()))
+                    .ok()
+                    .map(Temporal::Date),
+            }
+        } else if datatype == xsd::DATE_TIME || datatype == xsd::DATE_TIME_STAMP {
+            let date_time = DateTime::from_str(lit.value()).ok()?;
+            match date_time.timezone() {
+                Some(_) => Some(Temporal::DateTime(date_time)),
+                None => DateTime::from_str(&format!("{}Z", lit.value()))
+                    .ok()
+                    .map(Temporal::DateTime),
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Compares the value with `bound`, at day precision for dates.
+    fn compare(&self, bound: DateTime) -> Option<Ordering> {
+        match self {
+            Temporal::Date(date) => date.partial_cmp(&Date::try_from(bound).ok()?),
+            Temporal::DateTime(date_time) => date_time.partial_cmp(&bound),
+        }
+    }
+}
+
+/// The reference time moved by `days`.
+fn shift(reference: DateTime, days: i32) -> Option<DateTime> {
+    reference.checked_add_day_time_duration(DayTimeDuration::new(i64::from(days) * 86_400))
+}
+
+impl<'a> Validate<'a> for RelativeTimeConstraint {
+    fn validate(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        _path: Option<&'a Path<'a>>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        let reference = validation_dataset.config().reference_time();
+        let earliest = self.min_days.and_then(|days| shift(reference, days));
+        let latest = self.max_days.and_then(|days| shift(reference, days));
+        let mut violations = Vec::new();
+
+        for &value_node in value_nodes {
+            let value = match value_node {
+                TermRef::Literal(lit) => Temporal::parse(lit),
+                _ => None,
+            };
+            let message = match value {
+                None => "Value is not an xsd:date or xsd:dateTime".to_string(),
+                Some(value) => match (
+                    earliest.map(|bound| (bound, value.compare(bound))),
+                    latest.map(|bound| (bound, value.compare(bound))),
+                ) {
+                    (Some((_, None)), _) | (_, Some((_, None))) => {
+                        format!("Value cannot be compared with {}", reference)
+                    }
+                    (Some((bound, Some(Ordering::Less))), _) => {
+                        format!("Value is earlier than {}", bound)
+                    }
+                    (_, Some((bound, Some(Ordering::Greater)))) => {
+                        format!("Value is later than {}", bound)
+                    }
+                    _ => continue,
+                },
+            };
+
+            let builder = ViolationBuilder::new(focus_node)
+                .value(value_node)
+                .message(message)
+                .component(ConstraintComponent::Other(
+                    shx::RELATIVE_TIME_CONSTRAINT_COMPONENT,
+                ))
+                .detail(self.to_string())
+                .trace(vec![format!("Reference time: {}", reference)]);
+
+            violations.push(shape.build_validation_result(builder));
+        }
+
+        Ok(violations)
+    }
+}
@@ -1,9 +1,11 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::AndConstraint, path::Path, shape::Shape},
-    validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
+    core::{component::ConstraintComponent, constraints::AndConstraint, path::Path, shape::Shape},
+    validation::{
+        conformance_cache::ConformanceCache, dataset::ValidationDataset, Validate,
+        ValidationResult, ViolationBuilder,
+    },
     ShaclError,
 };
 
@@ -15,6 +17,25 @@ impl<'a> Validate<'a> for AndConstraint<'a> {
         _path: Option<&'a Path<'a>>,
         value_nodes: &[TermRef<'a>],
         shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        self.validate_with(
+            validation_dataset,
+            focus_node,
+            value_nodes,
+            shape,
+            &ConformanceCache::default(),
+        )
+    }
+}
+
+impl<'a> AndConstraint<'a> {
+    pub(crate) fn validate_with(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+        cache: &ConformanceCache<'a>,
     ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
         let mut violations = Vec::new();
 
@@ -23,12 +44,7 @@ impl<'a> Validate<'a> for AndConstraint<'a> {
             let mut all_nested_results = Vec::new();
 
             for nested_shape in &self.0 {
-                let mut nested_report = crate::validation::ValidationReport::new();
-                nested_shape.validate_focus_node(
-                    validation_dataset,
-                    value_node,
-                    &mut nested_report,
-                );
+                let nested_report = cache.report(validation_dataset, nested_shape, value_node);
 
                 if !*nested_report.get_conforms() {
                     failed_shapes.push(nested_shape.node.to_string());
@@ -46,7 +62,7 @@ impl<'a> Validate<'a> for AndConstraint<'a> {
                         "Value does not conform to all shapes in sh:and (failed: {})",
                         failed_shapes.join(", ")
                     ))
-                    .component(sh::AND_CONSTRAINT_COMPONENT)
+                    .component(ConstraintComponent::And)
                     .detail(format!("sh:and with {} shapes", self.0.len()))
                     .trace_entry("sh:and validation")
                     .details(all_nested_results);
@@ -1,10 +1,11 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::NodeConstraint, path::Path, shape::Shape},
-    utils,
-    validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
+    core::{component::ConstraintComponent, constraints::NodeConstraint, path::Path, shape::Shape},
+    validation::{
+        conformance_cache::ConformanceCache, dataset::ValidationDataset, Validate,
+        ValidationResult, ViolationBuilder,
+    },
     ShaclError,
 };
 
@@ -16,15 +17,31 @@ impl<'a> Validate<'a> for NodeConstraint<'a> {
         _path: Option<&'a Path<'a>>,
         value_nodes: &[TermRef<'a>],
         shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        self.validate_with(
+            validation_dataset,
+            focus_node,
+            value_nodes,
+            shape,
+            &ConformanceCache::default(),
+        )
+    }
+}
+
+impl<'a> NodeConstraint<'a> {
+    pub(crate) fn validate_with(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+        cache: &ConformanceCache<'a>,
     ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
         let mut violations = Vec::new();
 
         for &value_node in value_nodes {
-            if let Some(value_as_node) = utils::term_to_named_or_blank(value_node) {
-                let nested_report = self
-                    .0
-                    .validate_node_report(validation_dataset, value_as_node);
-                if !*nested_report.get_conforms() {
+            if value_node.is_named_node() || value_node.is_blank_node() {
+                if !cache.conforms(validation_dataset, &self.0, value_node) {
                     let is_focus = value_node == focus_node;
                     let builder = ViolationBuilder::new(focus_node)
                         .value(value_node)
@@ -33,7 +50,7 @@ impl<'a> Validate<'a> for NodeConstraint<'a> {
                         } else {
                             "Value does not conform to sh:node constraint"
                         })
-                        .component(sh::NODE_CONSTRAINT_COMPONENT)
+                        .component(ConstraintComponent::Node)
                         .detail(format!(
                             "sh:node constraint referencing shape {}",
                             self.0.node
@@ -45,7 +62,7 @@ impl<'a> Validate<'a> for NodeConstraint<'a> {
                 let builder = ViolationBuilder::new(focus_node)
                     .value(value_node)
                     .message("Value is not a node (is a literal)")
-                    .component(sh::NODE_CONSTRAINT_COMPONENT)
+                    .component(ConstraintComponent::Node)
                     .detail(format!(
                         "sh:node constraint referencing shape {}",
                         self.0.node
@@ -1,9 +1,11 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::NotConstraint, path::Path, shape::Shape},
-    validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
+    core::{component::ConstraintComponent, constraints::NotConstraint, path::Path, shape::Shape},
+    validation::{
+        conformance_cache::ConformanceCache, dataset::ValidationDataset, Validate,
+        ValidationResult, ViolationBuilder,
+    },
     ShaclError,
 };
 
@@ -15,19 +17,34 @@ impl<'a> Validate<'a> for NotConstraint<'a> {
         _path: Option<&'a Path<'a>>,
         value_nodes: &[TermRef<'a>],
         shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        self.validate_with(
+            validation_dataset,
+            focus_node,
+            value_nodes,
+            shape,
+            &ConformanceCache::default(),
+        )
+    }
+}
+
+impl<'a> NotConstraint<'a> {
+    pub(crate) fn validate_with(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+        cache: &ConformanceCache<'a>,
     ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
         let mut violations = Vec::new();
 
         for &value_node in value_nodes {
-            let mut nested_report = crate::validation::ValidationReport::new();
-            self.0
-                .validate_focus_node(validation_dataset, value_node, &mut nested_report);
-
-            if *nested_report.get_conforms() {
+            if cache.conforms(validation_dataset, &self.0, value_node) {
                 let builder = ViolationBuilder::new(focus_node)
                     .value(value_node)
                     .message("Value conforms to shape in sh:not (should not conform)")
-                    .component(sh::NOT_CONSTRAINT_COMPONENT)
+                    .component(ConstraintComponent::Not)
                     .detail(format!(
                         "sh:not constraint referencing shape {}",
                         self.0.node
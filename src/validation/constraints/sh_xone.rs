@@ -1,9 +1,11 @@
 use oxigraph::model::TermRef;
 
 use crate::{
-    core::{constraints::XoneConstraint, path::Path, shape::Shape},
-    validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
+    core::{component::ConstraintComponent, constraints::XoneConstraint, path::Path, shape::Shape},
+    validation::{
+        conformance_cache::ConformanceCache, dataset::ValidationDataset, Validate,
+        ValidationResult, ViolationBuilder,
+    },
     ShaclError,
 };
 
@@ -15,6 +17,25 @@ impl<'a> Validate<'a> for XoneConstraint<'a> {
         _path: Option<&'a Path<'a>>,
         value_nodes: &[TermRef<'a>],
         shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        self.validate_with(
+            validation_dataset,
+            focus_node,
+            value_nodes,
+            shape,
+            &ConformanceCache::default(),
+        )
+    }
+}
+
+impl<'a> XoneConstraint<'a> {
+    pub(crate) fn validate_with(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+        cache: &ConformanceCache<'a>,
     ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
         let mut violations = Vec::new();
 
@@ -24,12 +45,7 @@ impl<'a> Validate<'a> for XoneConstraint<'a> {
             let mut all_nested_results = Vec::new();
 
             for nested_shape in &self.0 {
-                let mut nested_report = crate::validation::ValidationReport::new();
-                nested_shape.validate_focus_node(
-                    validation_dataset,
-                    value_node,
-                    &mut nested_report,
-                );
+                let nested_report = cache.report(validation_dataset, nested_shape, value_node);
 
                 if *nested_report.get_conforms() {
                     conforming_count += 1;
@@ -44,12 +60,15 @@ impl<'a> Validate<'a> for XoneConstraint<'a> {
 
             if conforming_count != 1 {
                 let message = if conforming_count == 0 {
-                    "Value does not conform to exactly one shape in sh:xone (conforms to none)"
-                        .to_string()
+                    format!(
+                        "Value conforms to none of the {} shapes in sh:xone, expected exactly one",
+                        self.0.len()
+                    )
                 } else {
                     format!(
-                        "Value does not conform to exactly one shape in sh:xone (conforms to {} shapes: {})",
+                        "Value conforms to {} of the {} shapes in sh:xone, expected exactly one: {}",
                         conforming_count,
+                        self.0.len(),
                         conforming_shapes.join(", ")
                     )
                 };
@@ -57,12 +76,13 @@ impl<'a> Validate<'a> for XoneConstraint<'a> {
                 let builder = ViolationBuilder::new(focus_node)
                     .value(value_node)
                     .message(message)
-                    .component(sh::XONE_CONSTRAINT_COMPONENT)
+                    .component(ConstraintComponent::Xone)
                     .detail(format!(
                         "sh:xone with {} shapes, {} conforming",
                         self.0.len(),
                         conforming_count
-                    ));
+                    ))
+                    .details(all_nested_results);
 
                 violations.push(shape.build_validation_result(builder));
             }
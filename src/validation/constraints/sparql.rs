@@ -1,54 +1,57 @@
+use std::collections::HashMap;
+
 use oxigraph::{
-    model::{NamedOrBlankNodeRef, TermRef},
-    sparql::{QueryResults, SparqlEvaluator},
+    model::{NamedNodeRef, Term, TermRef},
+    sparql::QueryResults,
+};
+use spargebra::{
+    algebra::{Expression, GraphPattern, OrderExpression},
+    term::{NamedNodePattern, TermPattern, TriplePattern, Variable},
+    Query, SparqlParser,
 };
-use spargebra::{algebra::GraphPattern, Query, SparqlParser};
 
 use crate::{
     core::{
         constraints::{SparqlConstraint, SparqlExecutable},
-        path::Path,
+        path::{Path, PathElement},
         shape::Shape,
     },
-    utils,
     validation::{
         dataset::{self, ValidationDataset},
         Validate, ValidationResult, ViolationBuilder,
     },
-    vocab::sh,
     ShaclError,
 };
 
-fn constraint_component<'a>(c: &'a SparqlConstraint<'a>) -> oxigraph::model::NamedNodeRef<'a> {
-    if let Some(NamedOrBlankNodeRef::NamedNode(component)) = c.source_constraint_component {
-        component
-    } else {
-        sh::SPARQL_CONSTRAINT_COMPONENT
-    }
-}
-
 fn unsupported_in_pattern(
     pattern: &GraphPattern,
     remaining_select_projects: usize,
+    prebound: &[&str],
 ) -> Option<&'static str> {
+    let recurse =
+        |inner: &GraphPattern| unsupported_in_pattern(inner, remaining_select_projects, prebound);
     match pattern {
         GraphPattern::Minus { .. } => Some("MINUS is not supported for SHACL pre-binding"),
         GraphPattern::Service { .. } => Some("SERVICE is not supported for SHACL pre-binding"),
-        GraphPattern::Project { .. } if remaining_select_projects == 0 => {
-            Some("Nested SELECT is not supported for SHACL pre-binding")
-        }
-        GraphPattern::Join { left, right } | GraphPattern::Union { left, right } => {
-            unsupported_in_pattern(left, remaining_select_projects)
-                .or_else(|| unsupported_in_pattern(right, remaining_select_projects))
-        }
-        GraphPattern::LeftJoin { left, right, .. } => {
-            unsupported_in_pattern(left, remaining_select_projects)
-                .or_else(|| unsupported_in_pattern(right, remaining_select_projects))
+        // `$shapesGraph` and `$currentShape` are the only pre-bound variables
+        // a subquery may leave out.
+        GraphPattern::Project { variables, .. }
+            if remaining_select_projects == 0
+                && prebound
+                    .iter()
+                    .filter(|name| !matches!(**name, "shapesGraph" | "currentShape"))
+                    .any(|name| !variables.iter().any(|v| v.as_str() == *name)) =>
+        {
+            Some("Nested SELECT must project all pre-bound variables for SHACL pre-binding")
         }
-        GraphPattern::Lateral { left, right } => {
-            unsupported_in_pattern(left, remaining_select_projects)
-                .or_else(|| unsupported_in_pattern(right, remaining_select_projects))
+        GraphPattern::Extend { variable, .. } if prebound.contains(&variable.as_str()) => {
+            Some("Assigning a pre-bound variable is not supported for SHACL pre-binding")
         }
+        GraphPattern::Values { .. } => Some("VALUES is not supported for SHACL pre-binding"),
+        GraphPattern::Join { left, right }
+        | GraphPattern::Union { left, right }
+        | GraphPattern::LeftJoin { left, right, .. }
+        | GraphPattern::Lateral { left, right } => recurse(left).or_else(|| recurse(right)),
         GraphPattern::Filter { inner, .. }
         | GraphPattern::Graph { inner, .. }
         | GraphPattern::Extend { inner, .. }
@@ -56,20 +59,26 @@ fn unsupported_in_pattern(
         | GraphPattern::Distinct { inner }
         | GraphPattern::Reduced { inner }
         | GraphPattern::Slice { inner, .. }
-        | GraphPattern::Group { inner, .. } => {
-            unsupported_in_pattern(inner, remaining_select_projects)
-        }
+        | GraphPattern::Group { inner, .. } => recurse(inner),
         GraphPattern::Project { inner, .. } => {
-            unsupported_in_pattern(inner, remaining_select_projects.saturating_sub(1))
+            unsupported_in_pattern(inner, remaining_select_projects.saturating_sub(1), prebound)
         }
-        GraphPattern::Bgp { .. } | GraphPattern::Path { .. } | GraphPattern::Values { .. } => None,
+        GraphPattern::Bgp { .. } | GraphPattern::Path { .. } => None,
     }
 }
 
-fn unsupported_prebinding_construct(
-    query: &str,
-    prefixes: &[(String, String)],
-) -> Option<&'static str> {
+fn unsupported_prebinding_construct(query: &Query, prebound: &[&str]) -> Option<&'static str> {
+    let (pattern, remaining_select_projects) = match query {
+        Query::Select { pattern, .. } => (pattern, 1),
+        Query::Ask { pattern, .. }
+        | Query::Construct { pattern, .. }
+        | Query::Describe { pattern, .. } => (pattern, 0),
+    };
+
+    unsupported_in_pattern(pattern, remaining_select_projects, prebound)
+}
+
+pub(crate) fn parse_query(query: &str, prefixes: &[(String, String)]) -> Result<Query, String> {
     let mut parser = SparqlParser::new();
     for (prefix, namespace) in prefixes {
         if let Ok(with_prefix) = parser
@@ -80,19 +89,330 @@ fn unsupported_prebinding_construct(
         }
     }
 
-    let parsed = match parser.parse_query(query) {
-        Ok(parsed) => parsed,
-        Err(_) => return None,
-    };
+    parser.parse_query(query).map_err(|e| e.to_string())
+}
 
-    let (pattern, remaining_select_projects) = match parsed {
-        Query::Select { pattern, .. } => (pattern, 1),
-        Query::Ask { pattern, .. }
-        | Query::Construct { pattern, .. }
-        | Query::Describe { pattern, .. } => (pattern, 0),
-    };
+/// Replaces pre-bound variables by their values throughout a query, following
+/// the SHACL definition of pre-binding.
+///
+/// Blank nodes cannot be written into a query, so variables bound to blank
+/// nodes are left in place and bound as initial solutions instead.
+pub(crate) struct PreBinding<'b> {
+    pub(crate) values: &'b HashMap<&'b str, Term>,
+}
+
+impl PreBinding<'_> {
+    fn term_for(&self, variable: &Variable) -> Option<&Term> {
+        self.values
+            .get(variable.as_str())
+            .filter(|term| !matches!(term, Term::BlankNode(_)))
+    }
+
+    pub(crate) fn query(&self, query: Query) -> Query {
+        match query {
+            Query::Select {
+                dataset,
+                pattern,
+                base_iri,
+            } => Query::Select {
+                dataset,
+                pattern: self.pattern(pattern),
+                base_iri,
+            },
+            Query::Ask {
+                dataset,
+                pattern,
+                base_iri,
+            } => Query::Ask {
+                dataset,
+                pattern: self.pattern(pattern),
+                base_iri,
+            },
+            Query::Construct {
+                template,
+                dataset,
+                pattern,
+                base_iri,
+            } => Query::Construct {
+                template,
+                dataset,
+                pattern: self.pattern(pattern),
+                base_iri,
+            },
+            Query::Describe {
+                dataset,
+                pattern,
+                base_iri,
+            } => Query::Describe {
+                dataset,
+                pattern: self.pattern(pattern),
+                base_iri,
+            },
+        }
+    }
 
-    unsupported_in_pattern(&pattern, remaining_select_projects)
+    fn pattern(&self, pattern: GraphPattern) -> GraphPattern {
+        let boxed = |p: Box<GraphPattern>| Box::new(self.pattern(*p));
+        match pattern {
+            GraphPattern::Bgp { patterns } => GraphPattern::Bgp {
+                patterns: patterns
+                    .into_iter()
+                    .map(|t| TriplePattern {
+                        subject: self.term_pattern(t.subject),
+                        predicate: self.named_node_pattern(t.predicate),
+                        object: self.term_pattern(t.object),
+                    })
+                    .collect(),
+            },
+            GraphPattern::Path {
+                subject,
+                path,
+                object,
+            } => GraphPattern::Path {
+                subject: self.term_pattern(subject),
+                path,
+                object: self.term_pattern(object),
+            },
+            GraphPattern::Join { left, right } => GraphPattern::Join {
+                left: boxed(left),
+                right: boxed(right),
+            },
+            GraphPattern::LeftJoin {
+                left,
+                right,
+                expression,
+            } => GraphPattern::LeftJoin {
+                left: boxed(left),
+                right: boxed(right),
+                expression: expression.map(|e| self.expression(e)),
+            },
+            GraphPattern::Lateral { left, right } => GraphPattern::Lateral {
+                left: boxed(left),
+                right: boxed(right),
+            },
+            GraphPattern::Filter { expr, inner } => GraphPattern::Filter {
+                expr: self.expression(expr),
+                inner: boxed(inner),
+            },
+            GraphPattern::Union { left, right } => GraphPattern::Union {
+                left: boxed(left),
+                right: boxed(right),
+            },
+            GraphPattern::Graph { name, inner } => GraphPattern::Graph {
+                name: self.named_node_pattern(name),
+                inner: boxed(inner),
+            },
+            GraphPattern::Extend {
+                inner,
+                variable,
+                expression,
+            } => GraphPattern::Extend {
+                inner: boxed(inner),
+                variable,
+                expression: self.expression(expression),
+            },
+            GraphPattern::Minus { left, right } => GraphPattern::Minus {
+                left: boxed(left),
+                right: boxed(right),
+            },
+            GraphPattern::OrderBy { inner, expression } => GraphPattern::OrderBy {
+                inner: boxed(inner),
+                expression: expression
+                    .into_iter()
+                    .map(|e| match e {
+                        OrderExpression::Asc(e) => OrderExpression::Asc(self.expression(e)),
+                        OrderExpression::Desc(e) => OrderExpression::Desc(self.expression(e)),
+                    })
+                    .collect(),
+            },
+            // Projected pre-bound variables are bound again, so solutions
+            // still carry them
+            GraphPattern::Project { inner, variables } => GraphPattern::Project {
+                inner: Box::new(self.rebind(self.pattern(*inner), variables.iter())),
+                variables,
+            },
+            GraphPattern::Distinct { inner } => GraphPattern::Distinct {
+                inner: boxed(inner),
+            },
+            GraphPattern::Reduced { inner } => GraphPattern::Reduced {
+                inner: boxed(inner),
+            },
+            GraphPattern::Slice {
+                inner,
+                start,
+                length,
+            } => GraphPattern::Slice {
+                inner: boxed(inner),
+                start,
+                length,
+            },
+            GraphPattern::Group {
+                inner,
+                variables,
+                aggregates,
+            } => {
+                // Bound again below the group, so GROUP BY and aggregates
+                // over pre-bound variables see their values
+                let prebound: Vec<_> = self
+                    .values
+                    .keys()
+                    .map(|name| Variable::new_unchecked(*name))
+                    .collect();
+                GraphPattern::Group {
+                    inner: Box::new(self.rebind(self.pattern(*inner), prebound.iter())),
+                    variables,
+                    aggregates,
+                }
+            }
+            pattern @ (GraphPattern::Values { .. } | GraphPattern::Service { .. }) => pattern,
+        }
+    }
+
+    fn term_pattern(&self, term: TermPattern) -> TermPattern {
+        match term {
+            TermPattern::Variable(v) => match self.term_for(&v) {
+                Some(Term::NamedNode(n)) => TermPattern::NamedNode(n.clone()),
+                Some(Term::Literal(l)) => TermPattern::Literal(l.clone()),
+                _ => TermPattern::Variable(v),
+            },
+            other => other,
+        }
+    }
+
+    fn named_node_pattern(&self, pattern: NamedNodePattern) -> NamedNodePattern {
+        match pattern {
+            NamedNodePattern::Variable(v) => match self.term_for(&v) {
+                Some(Term::NamedNode(n)) => NamedNodePattern::NamedNode(n.clone()),
+                _ => NamedNodePattern::Variable(v),
+            },
+            other => other,
+        }
+    }
+
+    /// Binds those of `variables` that were substituted to their values on
+    /// top of `inner`.
+    fn rebind<'v>(
+        &self,
+        inner: GraphPattern,
+        variables: impl Iterator<Item = &'v Variable>,
+    ) -> GraphPattern {
+        variables.fold(inner, |inner, variable| {
+            match self.bound_expression(variable) {
+                Some(expression) => GraphPattern::Extend {
+                    inner: Box::new(inner),
+                    variable: variable.clone(),
+                    expression,
+                },
+                None => inner,
+            }
+        })
+    }
+
+    /// The value substituted for `variable`, if any.
+    fn bound_expression(&self, variable: &Variable) -> Option<Expression> {
+        match self.term_for(variable)? {
+            Term::NamedNode(n) => Some(Expression::NamedNode(n.clone())),
+            Term::Literal(l) => Some(Expression::Literal(l.clone())),
+            _ => None,
+        }
+    }
+
+    fn expression(&self, expression: Expression) -> Expression {
+        let boxed = |e: Box<Expression>| Box::new(self.expression(*e));
+        let binary = |a: Box<Expression>, b: Box<Expression>| (boxed(a), boxed(b));
+        match expression {
+            Expression::Variable(v) => self.bound_expression(&v).unwrap_or(Expression::Variable(v)),
+            Expression::Bound(v) if self.values.contains_key(v.as_str()) => {
+                Expression::Literal(true.into())
+            }
+            Expression::Or(a, b) => {
+                let (a, b) = binary(a, b);
+                Expression::Or(a, b)
+            }
+            Expression::And(a, b) => {
+                let (a, b) = binary(a, b);
+                Expression::And(a, b)
+            }
+            Expression::Equal(a, b) => {
+                let (a, b) = binary(a, b);
+                Expression::Equal(a, b)
+            }
+            Expression::SameTerm(a, b) => {
+                let (a, b) = binary(a, b);
+                Expression::SameTerm(a, b)
+            }
+            Expression::Greater(a, b) => {
+                let (a, b) = binary(a, b);
+                Expression::Greater(a, b)
+            }
+            Expression::GreaterOrEqual(a, b) => {
+                let (a, b) = binary(a, b);
+                Expression::GreaterOrEqual(a, b)
+            }
+            Expression::Less(a, b) => {
+                let (a, b) = binary(a, b);
+                Expression::Less(a, b)
+            }
+            Expression::LessOrEqual(a, b) => {
+                let (a, b) = binary(a, b);
+                Expression::LessOrEqual(a, b)
+            }
+            Expression::Add(a, b) => {
+                let (a, b) = binary(a, b);
+                Expression::Add(a, b)
+            }
+            Expression::Subtract(a, b) => {
+                let (a, b) = binary(a, b);
+                Expression::Subtract(a, b)
+            }
+            Expression::Multiply(a, b) => {
+                let (a, b) = binary(a, b);
+                Expression::Multiply(a, b)
+            }
+            Expression::Divide(a, b) => {
+                let (a, b) = binary(a, b);
+                Expression::Divide(a, b)
+            }
+            Expression::In(a, list) => Expression::In(
+                boxed(a),
+                list.into_iter().map(|e| self.expression(e)).collect(),
+            ),
+            Expression::UnaryPlus(a) => Expression::UnaryPlus(boxed(a)),
+            Expression::UnaryMinus(a) => Expression::UnaryMinus(boxed(a)),
+            Expression::Not(a) => Expression::Not(boxed(a)),
+            Expression::Exists(pattern) => Expression::Exists(Box::new(self.pattern(*pattern))),
+            Expression::If(a, b, c) => Expression::If(boxed(a), boxed(b), boxed(c)),
+            Expression::Coalesce(list) => {
+                Expression::Coalesce(list.into_iter().map(|e| self.expression(e)).collect())
+            }
+            Expression::FunctionCall(function, args) => Expression::FunctionCall(
+                function,
+                args.into_iter().map(|e| self.expression(e)).collect(),
+            ),
+            other => other,
+        }
+    }
+}
+
+/// Maps a `?value` solution binding back onto a borrowed focus or value node.
+fn find_value_term<'a>(
+    term: &Term,
+    focus_node: TermRef<'a>,
+    value_nodes: &[TermRef<'a>],
+) -> Option<TermRef<'a>> {
+    std::iter::once(focus_node)
+        .chain(value_nodes.iter().copied())
+        .find(|candidate| *candidate == term.as_ref())
+}
+
+/// Whether `query` refers to `?name` or `$name`; only such variables can be pre-bound.
+pub(crate) fn mentions_variable(query: &str, name: &str) -> bool {
+    query.match_indices(name).any(|(idx, _)| {
+        let before = query[..idx].chars().next_back();
+        let after = query[idx + name.len()..].chars().next();
+        matches!(before, Some('?') | Some('$'))
+            && !after.is_some_and(|c| c.is_alphanumeric() || c == '_')
+    })
 }
 
 fn normalize_binding_value(value: &str) -> String {
@@ -119,7 +439,7 @@ fn apply_message_bindings(
     rendered
 }
 
-fn render_messages_for_solution(
+pub(crate) fn render_messages_for_solution(
     messages: &[String],
     context_bindings: &[(String, String)],
     result_bindings: &[(String, String)],
@@ -142,37 +462,82 @@ impl<'a> Validate<'a> for SparqlConstraint<'a> {
         let mut violations = Vec::new();
 
         let store = validation_dataset.store();
+        let query_text = self.executable.query();
 
-        let mut evaluator = SparqlEvaluator::new();
-        for (prefix, namespace) in &self.prefixes {
-            if let Ok(with_prefix) = evaluator
-                .clone()
-                .with_prefix(prefix.clone(), namespace.clone())
+        // ASK validators run once per value node with `$value` pre-bound (for node
+        // shapes the only value node is the focus node). SELECT queries run once
+        // per focus node and report `?value` from their solutions.
+        let run_targets: Vec<Option<TermRef<'a>>> = match self.executable {
+            SparqlExecutable::Ask(_) => value_nodes.iter().copied().map(Some).collect(),
+            SparqlExecutable::Select(_)
+                if path.is_none() && self.source_constraint_component.is_some() =>
             {
-                evaluator = with_prefix;
+                vec![Some(focus_node)]
             }
+            SparqlExecutable::Select(_) => vec![None],
+        };
+
+        let query = match parse_query(query_text, &self.prefixes) {
+            Ok(query) => query,
+            Err(error) => {
+                let builder = ViolationBuilder::new(focus_node)
+                    .message(format!("SPARQL parse error: {}", error))
+                    .component(self.component())
+                    .detail(format!("SPARQL query: {}", query_text.replace('\n', " ")));
+                violations.push(shape.build_validation_result(builder));
+                return Ok(violations);
+            }
+        };
+
+        let mut base_bindings: HashMap<&str, Term> = HashMap::from([
+            ("this", focus_node.into_owned()),
+            (
+                "shapesGraph",
+                NamedNodeRef::new_unchecked(dataset::SHAPES_GRAPH_IRI)
+                    .into_owned()
+                    .into(),
+            ),
+            ("currentShape", TermRef::from(shape.node).into_owned()),
+        ]);
+
+        // `$PATH` is only pre-bound for property shapes whose path is a single
+        // predicate; other paths would require rewriting the query text.
+        let path_predicate = path.and_then(|path| match path.get_elements() {
+            [PathElement::Iri(predicate)] => Some(*predicate),
+            _ => None,
+        });
+        if let Some(predicate) = path_predicate {
+            base_bindings.insert("PATH", predicate.into_owned().into());
         }
 
-        let mut run_once_targets: Vec<Option<TermRef<'a>>> = Vec::new();
-        if path.is_some() {
-            if value_nodes.is_empty() {
-                run_once_targets.push(None);
-            } else {
-                for &value in value_nodes {
-                    run_once_targets.push(Some(value));
-                }
-            }
-        } else if self.source_constraint_component.is_some() {
-            run_once_targets.push(Some(focus_node));
-        } else {
-            run_once_targets.push(None);
+        for (name, value) in &self.parameter_bindings {
+            base_bindings.insert(name.as_str(), value.into_owned());
         }
 
-        let query_text = self.executable.query();
+        let mut prebound: Vec<&str> = base_bindings.keys().copied().collect();
+        if run_targets.iter().any(Option::is_some) {
+            prebound.push("value");
+        }
+
+        // Queries SHACL forbids for pre-binding make the shapes graph ill-formed.
+        if let Some(reason) = unsupported_prebinding_construct(&query, &prebound) {
+            return Err(ShaclError::Validation(format!(
+                "{}: {}",
+                reason,
+                query_text.replace('\n', " ")
+            )));
+        }
+
+        let unsupported = (path_predicate.is_none() && mentions_variable(query_text, "PATH"))
+            .then_some(if path.is_some() {
+                "$PATH is only supported for single-predicate property paths"
+            } else {
+                "$PATH is not supported for node shapes"
+            });
 
-        if let Some(reason) = unsupported_prebinding_construct(query_text, &self.prefixes) {
+        if let Some(reason) = unsupported {
             let mut builder = ViolationBuilder::new(focus_node)
-                .component(constraint_component(self))
+                .component(self.component())
                 .detail(format!("{}: {}", reason, query_text.replace('\n', " ")));
 
             if self.messages.is_empty() {
@@ -189,48 +554,28 @@ impl<'a> Validate<'a> for SparqlConstraint<'a> {
             return Ok(violations);
         }
 
-        for maybe_value in run_once_targets {
-            let mut bindings: Vec<(String, String)> = Vec::new();
-            bindings.push(("this".to_string(), format!("{}", focus_node)));
-            bindings.push((
-                "shapesGraph".to_string(),
-                format!("<{}>", dataset::SHAPES_GRAPH_IRI),
-            ));
-            bindings.push(("currentShape".to_string(), format!("{}", shape.node)));
-
+        for maybe_value in run_targets {
+            let mut bindings = base_bindings.clone();
             if let Some(value) = maybe_value {
-                bindings.push(("value".to_string(), format!("{}", value)));
+                bindings.insert("value", value.into_owned());
             }
 
-            if let Some(path) = path {
-                if let Some(predicate) = utils::extract_direct_predicates(path).into_iter().next() {
-                    bindings.push(("PATH".to_string(), format!("{}", predicate)));
+            let context_bindings: Vec<(String, String)> = bindings
+                .iter()
+                .map(|(name, term)| (name.to_string(), term.to_string()))
+                .collect();
+
+            let bound_query = PreBinding { values: &bindings }.query(query.clone());
+            let bound_text = bound_query.to_string();
+            let mut prepared = validation_dataset.sparql_evaluator().for_query(bound_query);
+            for (name, term) in &bindings {
+                if matches!(term, Term::BlankNode(_)) && mentions_variable(&bound_text, name) {
+                    prepared =
+                        prepared.substitute_variable(Variable::new_unchecked(*name), term.clone());
                 }
             }
 
-            for (name, value) in &self.parameter_bindings {
-                bindings.push((name.clone(), format!("{}", value)));
-            }
-
-            let bound_query = utils::inject_values_bindings(query_text, &bindings);
-
-            let prepared = match evaluator.clone().parse_query(&bound_query) {
-                Ok(prepared) => prepared,
-                Err(error) => {
-                    let mut builder = ViolationBuilder::new(focus_node)
-                        .message(format!("SPARQL parse error: {}", error))
-                        .component(constraint_component(self))
-                        .detail(format!("SPARQL query: {}", bound_query.replace('\n', " ")));
-                    if let Some(value) = maybe_value {
-                        builder = builder.value(value);
-                    }
-                    violations.push(shape.build_validation_result(builder));
-                    continue;
-                }
-            };
-
             let results = prepared.on_store(store.as_ref()).execute();
-            let violations_before = violations.len();
             match (&self.executable, results) {
                 (SparqlExecutable::Select(_), Ok(QueryResults::Solutions(solutions))) => {
                     for solution_result in solutions {
@@ -244,10 +589,13 @@ impl<'a> Validate<'a> for SparqlConstraint<'a> {
                             .collect();
 
                         let mut builder = ViolationBuilder::new(focus_node)
-                            .component(constraint_component(self))
-                            .detail(format!("SPARQL SELECT: {}", bound_query.replace('\n', " ")));
+                            .component(self.component())
+                            .detail(format!("SPARQL SELECT: {}", bound_text.replace('\n', " ")));
 
-                        if let Some(value) = maybe_value {
+                        let solution_value = solution
+                            .get("value")
+                            .and_then(|term| find_value_term(term, focus_node, value_nodes));
+                        if let Some(value) = solution_value.or(maybe_value) {
                             builder = builder.value(value);
                         }
 
@@ -256,7 +604,7 @@ impl<'a> Validate<'a> for SparqlConstraint<'a> {
                         } else {
                             builder = builder.messages(render_messages_for_solution(
                                 &self.messages,
-                                &bindings,
+                                &context_bindings,
                                 &result_bindings,
                             ));
                         }
@@ -264,130 +612,36 @@ impl<'a> Validate<'a> for SparqlConstraint<'a> {
                         violations.push(shape.build_validation_result(builder));
                     }
                 }
-                (SparqlExecutable::Ask(_), Ok(QueryResults::Boolean(result))) => {
-                    if !result {
-                        let mut builder = ViolationBuilder::new(focus_node)
-                            .component(constraint_component(self))
-                            .detail(format!("SPARQL ASK: {}", bound_query.replace('\n', " ")));
-
-                        if let Some(value) = maybe_value {
-                            builder = builder.value(value);
-                        }
-
-                        if self.messages.is_empty() {
-                            builder = builder.message("SPARQL ASK constraint violation");
-                        } else {
-                            builder = builder.messages(self.messages.clone());
-                        }
-
-                        violations.push(shape.build_validation_result(builder));
-                    }
-                }
-                (_, Ok(_)) => {}
-                (_, Err(error)) => {
+                (SparqlExecutable::Ask(_), Ok(QueryResults::Boolean(false))) => {
                     let mut builder = ViolationBuilder::new(focus_node)
-                        .component(constraint_component(self))
-                        .message(format!("SPARQL execution error: {}", error))
-                        .detail(format!("SPARQL query: {}", bound_query.replace('\n', " ")));
+                        .component(self.component())
+                        .detail(format!("SPARQL ASK: {}", bound_text.replace('\n', " ")));
+
                     if let Some(value) = maybe_value {
                         builder = builder.value(value);
                     }
-                    violations.push(shape.build_validation_result(builder));
-                }
-            }
-
-            let has_this_var = query_text.contains("$this") || query_text.contains("?this");
-            if violations.len() == violations_before && has_this_var {
-                let rewritten_query =
-                    utils::rewrite_this_binding_query(query_text, &format!("{}", focus_node));
-                let fallback_prepared = evaluator.clone().parse_query(&rewritten_query);
-                if let Ok(fallback_prepared) = fallback_prepared {
-                    let fallback_results = fallback_prepared.on_store(store.as_ref()).execute();
-                    match (&self.executable, fallback_results) {
-                        (SparqlExecutable::Select(_), Ok(QueryResults::Solutions(solutions))) => {
-                            for solution_result in solutions {
-                                let Ok(solution) = solution_result else {
-                                    continue;
-                                };
-
-                                let result_bindings: Vec<(String, String)> = solution
-                                    .iter()
-                                    .map(|(var, term)| (var.as_str().to_string(), term.to_string()))
-                                    .collect();
-
-                                let mut builder = ViolationBuilder::new(focus_node)
-                                    .component(constraint_component(self))
-                                    .detail(format!(
-                                        "SPARQL SELECT (fallback): {}",
-                                        rewritten_query.replace('\n', " ")
-                                    ));
-
-                                if let Some(value) = maybe_value {
-                                    builder = builder.value(value);
-                                }
-
-                                if self.messages.is_empty() {
-                                    builder = builder.message("SPARQL SELECT constraint violation");
-                                } else {
-                                    builder = builder.messages(render_messages_for_solution(
-                                        &self.messages,
-                                        &bindings,
-                                        &result_bindings,
-                                    ));
-                                }
-
-                                violations.push(shape.build_validation_result(builder));
-                            }
-                        }
-                        (SparqlExecutable::Ask(_), Ok(QueryResults::Boolean(result))) => {
-                            if !result {
-                                let mut builder = ViolationBuilder::new(focus_node)
-                                    .component(constraint_component(self))
-                                    .detail(format!(
-                                        "SPARQL ASK (fallback): {}",
-                                        rewritten_query.replace('\n', " ")
-                                    ));
-
-                                if let Some(value) = maybe_value {
-                                    builder = builder.value(value);
-                                }
-
-                                if self.messages.is_empty() {
-                                    builder = builder.message("SPARQL ASK constraint violation");
-                                } else {
-                                    builder = builder.messages(self.messages.clone());
-                                }
-
-                                violations.push(shape.build_validation_result(builder));
-                            }
-                        }
-                        _ => {}
-                    }
-                }
-
-                let unresolved_prebinding = violations.len() == violations_before
-                    && (query_text.contains("bound($this")
-                        || query_text.contains("bound(?this")
-                        || query_text.contains("UNION"));
-
-                if unresolved_prebinding {
-                    let mut builder = ViolationBuilder::new(focus_node)
-                        .component(constraint_component(self))
-                        .detail(format!(
-                            "SPARQL pre-binding fallback: {}",
-                            query_text.replace('\n', " ")
-                        ));
 
                     if self.messages.is_empty() {
-                        builder = builder.message("SPARQL pre-binding violation");
+                        builder = builder.message("SPARQL ASK constraint violation");
                     } else {
-                        builder = builder.messages(self.messages.clone());
+                        builder = builder.messages(render_messages_for_solution(
+                            &self.messages,
+                            &context_bindings,
+                            &[],
+                        ));
                     }
 
+                    violations.push(shape.build_validation_result(builder));
+                }
+                (_, Ok(_)) => {}
+                (_, Err(error)) => {
+                    let mut builder = ViolationBuilder::new(focus_node)
+                        .component(self.component())
+                        .message(format!("SPARQL execution error: {}", error))
+                        .detail(format!("SPARQL query: {}", bound_text.replace('\n', " ")));
                     if let Some(value) = maybe_value {
                         builder = builder.value(value);
                     }
-
                     violations.push(shape.build_validation_result(builder));
                 }
             }
@@ -0,0 +1,51 @@
+use oxigraph::model::TermRef;
+
+use crate::{
+    core::{
+        component::ConstraintComponent, constraints::TotalDigitsConstraint, path::Path,
+        shape::Shape,
+    },
+    utils::decimal_digits,
+    validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
+    vocab::shx,
+    ShaclError,
+};
+
+impl<'a> Validate<'a> for TotalDigitsConstraint {
+    fn validate(
+        &'a self,
+        _validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        _path: Option<&'a Path<'a>>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        let mut violations = Vec::new();
+
+        for &value_node in value_nodes {
+            let digits = match value_node {
+                TermRef::Literal(lit) => decimal_digits(lit),
+                _ => None,
+            };
+            let message = match digits {
+                None => "Value is not a decimal".to_string(),
+                Some((total, _)) if total as i32 > self.0 => {
+                    format!("Value has {} digits, more than {}", total, self.0)
+                }
+                Some(_) => continue,
+            };
+
+            let builder = ViolationBuilder::new(focus_node)
+                .value(value_node)
+                .message(message)
+                .component(ConstraintComponent::Other(
+                    shx::TOTAL_DIGITS_CONSTRAINT_COMPONENT,
+                ))
+                .detail(format!("shx:totalDigits {}", self.0));
+
+            violations.push(shape.build_validation_result(builder));
+        }
+
+        Ok(violations)
+    }
+}
@@ -2,9 +2,10 @@ use oxigraph::model::TermRef;
 use std::collections::HashMap;
 
 use crate::{
-    core::{constraints::UniqueLangConstraint, path::Path, shape::Shape},
+    core::{
+        component::ConstraintComponent, constraints::UniqueLangConstraint, path::Path, shape::Shape,
+    },
     validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
-    vocab::sh,
     ShaclError,
 };
 
@@ -30,7 +31,7 @@ impl<'a> Validate<'a> for UniqueLangConstraint {
                                 "Duplicate language tag '{}' (first seen: {})",
                                 lang, first_value
                             ))
-                            .component(sh::UNIQUE_LANG_CONSTRAINT_COMPONENT)
+                            .component(ConstraintComponent::UniqueLang)
                             .detail("sh:uniqueLang true".to_string());
 
                         violations.push(shape.build_validation_result(builder));
@@ -0,0 +1,94 @@
+use oxigraph::model::{NamedOrBlankNodeRef, TermRef};
+
+use crate::{
+    core::{
+        component::ConstraintComponent, constraints::UniqueValueForClassConstraint, path::Path,
+        shape::Shape,
+    },
+    utils,
+    validation::{
+        dataset::{ValidationDataset, ValueIndex},
+        Validate, ValidationResult, ViolationBuilder,
+    },
+    vocab::dash,
+    ShaclError,
+};
+
+impl<'a> Validate<'a> for UniqueValueForClassConstraint<'a> {
+    fn validate(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        path: Option<&'a Path<'a>>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        let Some(path) = path else {
+            return Ok(Vec::new());
+        };
+
+        // The index covers every instance, so it is built once per class and path.
+        let index = validation_dataset.value_index(
+            format!("dash:uniqueValueForClass {} {}", self.0, path),
+            || self.build_index(validation_dataset, path),
+        )?;
+
+        let mut violations = Vec::new();
+        for &value_node in value_nodes {
+            let Some(owners) = index.get(&value_node.into_owned()) else {
+                continue;
+            };
+            let others: Vec<String> = owners
+                .iter()
+                .filter(|owner| owner.as_ref() != focus_node)
+                .map(ToString::to_string)
+                .collect();
+            if others.is_empty() {
+                continue;
+            }
+
+            let builder = ViolationBuilder::new(focus_node)
+                .value(value_node)
+                .message(format!(
+                    "Value is also used by other instance(s) of {}: {}",
+                    self.0,
+                    others.join(", ")
+                ))
+                .component(ConstraintComponent::Other(
+                    dash::UNIQUE_VALUE_FOR_CLASS_CONSTRAINT_COMPONENT,
+                ))
+                .detail(format!("dash:uniqueValueForClass {}", self.0));
+            violations.push(shape.build_validation_result(builder));
+        }
+
+        Ok(violations)
+    }
+}
+
+impl<'a> UniqueValueForClassConstraint<'a> {
+    /// Maps each value of `path` to the instances of the class holding it.
+    fn build_index(
+        &self,
+        validation_dataset: &'a ValidationDataset,
+        path: &Path<'a>,
+    ) -> Result<ValueIndex, ShaclError> {
+        let mut instances: Vec<NamedOrBlankNodeRef<'_>> = validation_dataset
+            .instances_of(self.0.into())
+            .into_iter()
+            .filter_map(utils::term_to_named_or_blank)
+            .collect();
+        instances.sort_by_key(|instance| instance.to_string());
+
+        let mut index = ValueIndex::new();
+        for instance in instances {
+            for value in validation_dataset.resolve_path(path, instance)? {
+                let owners = index.entry(value.into_owned()).or_default();
+                let instance = TermRef::from(instance).into_owned();
+                if !owners.contains(&instance) {
+                    owners.push(instance);
+                }
+            }
+        }
+        Ok(index)
+    }
+}
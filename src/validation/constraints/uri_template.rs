@@ -0,0 +1,62 @@
+use oxigraph::model::TermRef;
+use regex::Regex;
+
+use crate::{
+    core::{
+        component::ConstraintComponent, constraints::UriTemplateConstraint, path::Path,
+        shape::Shape,
+    },
+    utils::uri_template_regex,
+    validation::{dataset::ValidationDataset, Validate, ValidationResult, ViolationBuilder},
+    vocab::shx,
+    ShaclError,
+};
+
+impl<'a> Validate<'a> for UriTemplateConstraint {
+    fn validate(
+        &'a self,
+        _validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        _path: Option<&'a Path<'a>>,
+        value_nodes: &[TermRef<'a>],
+        shape: &'a Shape<'a>,
+    ) -> Result<Vec<ValidationResult<'a>>, ShaclError> {
+        let mut violations = Vec::new();
+
+        let regexes: Vec<Regex> = self
+            .0
+            .iter()
+            .filter_map(|template| uri_template_regex(template).ok())
+            .collect();
+
+        for &value_node in value_nodes {
+            let message = match value_node {
+                TermRef::NamedNode(iri) => {
+                    if regexes.iter().any(|re| re.is_match(iri.as_str())) {
+                        continue;
+                    }
+                    match self.0.as_slice() {
+                        [template] => format!("IRI does not match template {}", template),
+                        templates => format!(
+                            "IRI does not match any of the templates {}",
+                            templates.join(", ")
+                        ),
+                    }
+                }
+                _ => "Value is not an IRI".to_string(),
+            };
+
+            let builder = ViolationBuilder::new(focus_node)
+                .value(value_node)
+                .message(message)
+                .component(ConstraintComponent::Other(
+                    shx::URI_TEMPLATE_CONSTRAINT_COMPONENT,
+                ))
+                .detail(format!("shx:uriTemplate {}", self.0.join(", ")));
+
+            violations.push(shape.build_validation_result(builder));
+        }
+
+        Ok(violations)
+    }
+}
@@ -1,19 +1,52 @@
-use std::{ops::Deref, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Deref,
+    sync::{Arc, Mutex, OnceLock, RwLock},
+};
 
 use oxigraph::{
-    model::{Graph, GraphNameRef, NamedNodeRef, QuadRef},
+    model::{
+        vocab::rdf, BlankNode, BlankNodeRef, Graph, GraphNameRef, NamedNodeRef, NamedOrBlankNode,
+        NamedOrBlankNodeRef, QuadRef, Term, TermRef, Triple, Variable,
+    },
     store::Store,
 };
 
-use crate::err::ShaclError;
+use oxigraph::sparql::{QueryResults, SparqlEvaluator};
+use spargebra::Query;
+
+use crate::{
+    core::path::Path,
+    err::ShaclError,
+    parser::well_formed::{check_well_formed, WellFormednessIssue},
+    utils,
+    validation::{
+        components::ConstraintComponentRegistry,
+        config::{Entailment, PathEvaluation, ValidationConfig},
+        constraints::sparql::parse_query,
+        functions::{self, SparqlFunction},
+    },
+};
 
 pub const SHAPES_GRAPH_IRI: &str = "urn:shacl:shapes-graph";
 
+/// Maps each value to the nodes it was reached from.
+pub type ValueIndex = HashMap<Term, Vec<Term>>;
+
 #[derive(Clone)]
 pub struct ValidationDataset {
     store: Arc<Store>,
     data_graph: Graph,
     shapes_graph: Graph,
+    value_indexes: Arc<Mutex<HashMap<String, Arc<ValueIndex>>>>,
+    /// Compiled path queries by SPARQL property path, `None` when it does not parse.
+    /// Read-mostly, as each distinct path is compiled once.
+    path_queries: Arc<RwLock<HashMap<String, Option<Arc<Query>>>>>,
+    functions: Arc<[SparqlFunction]>,
+    /// Syntax issues of the shapes graph, checked on first use.
+    well_formedness_issues: Arc<OnceLock<Vec<WellFormednessIssue>>>,
+    constraint_components: ConstraintComponentRegistry,
+    config: ValidationConfig,
 }
 
 impl ValidationDataset {
@@ -54,17 +87,76 @@ impl ValidationDataset {
                 })?;
         }
 
+        let functions = functions::parse_sparql_functions(&shapes_graph)?.into();
         Ok(Self {
             store: Arc::new(store),
             data_graph,
             shapes_graph,
+            value_indexes: Arc::default(),
+            path_queries: Arc::default(),
+            functions,
+            well_formedness_issues: Arc::default(),
+            constraint_components: ConstraintComponentRegistry::default(),
+            config: ValidationConfig::default(),
         })
     }
 
+    /// Builds a dataset from graphs of an open store: the data graph (which may be
+    /// the default graph) and the named graph holding the shapes.
+    ///
+    /// Quads are copied straight out of the store, so nothing is serialized and
+    /// later writes to the store do not affect the dataset.
+    pub fn from_store(
+        store: &Store,
+        data_graph: GraphNameRef<'_>,
+        shapes_graph: NamedNodeRef<'_>,
+    ) -> Result<Self, ShaclError> {
+        let data_graph = read_store_graph(store, data_graph)?;
+        let shapes_graph = read_store_graph(store, shapes_graph.into())?;
+        Self::from_graphs(data_graph, shapes_graph)
+    }
+
+    /// Sets the run settings used when validating this dataset.
+    pub fn with_config(mut self, config: ValidationConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn config(&self) -> &ValidationConfig {
+        &self.config
+    }
+
+    /// Sets the validators of constraint components implemented in Rust.
+    pub fn with_constraint_components(mut self, registry: ConstraintComponentRegistry) -> Self {
+        self.constraint_components = registry;
+        self
+    }
+
+    pub fn constraint_components(&self) -> &ConstraintComponentRegistry {
+        &self.constraint_components
+    }
+
     pub fn store(&self) -> Arc<Store> {
         Arc::clone(&self.store)
     }
 
+    /// SPARQL functions declared in the shapes graph.
+    pub fn functions(&self) -> &[SparqlFunction] {
+        &self.functions
+    }
+
+    /// Violations of the SHACL syntax rules in the shapes graph, see
+    /// [`check_well_formed`]. Checked once and shared with clones.
+    pub fn well_formedness_issues(&self) -> &[WellFormednessIssue] {
+        self.well_formedness_issues
+            .get_or_init(|| check_well_formed(&self.shapes_graph))
+    }
+
+    /// A SPARQL evaluator that can call the functions of the shapes graph.
+    pub fn sparql_evaluator(&self) -> SparqlEvaluator {
+        functions::evaluator(&self.functions, &self.store)
+    }
+
     pub fn data_graph(&self) -> &Graph {
         &self.data_graph
     }
@@ -72,6 +164,279 @@ impl ValidationDataset {
     pub fn shapes_graph(&self) -> &Graph {
         &self.shapes_graph
     }
+
+    /// Replaces the description of `shape` in the shapes graph with the
+    /// triples of `definition`, see [`Validator::update_shape`](crate::Validator::update_shape).
+    pub(crate) fn replace_shape(
+        &mut self,
+        shape: NamedNodeRef<'_>,
+        definition: &Graph,
+    ) -> Result<(), ShaclError> {
+        let removed = shape_description(&self.shapes_graph, shape.into());
+        let inserted = with_fresh_blank_nodes(definition);
+
+        let mut shapes_graph = self.shapes_graph.clone();
+        for triple in &removed {
+            shapes_graph.remove(triple);
+        }
+        for triple in &inserted {
+            shapes_graph.insert(triple);
+        }
+        let functions = functions::parse_sparql_functions(&shapes_graph)?.into();
+
+        let error = |e: &dyn std::fmt::Display| {
+            ShaclError::Io(format!("Failed to update shape {}: {}", shape, e))
+        };
+        // Clones of the dataset share the store and keep the old definition,
+        // so a shared store is copied in the same transaction.
+        let copy = match Arc::get_mut(&mut self.store) {
+            Some(_) => None,
+            None => Some(Store::new().map_err(|e| error(&e))?),
+        };
+        let store = copy.as_ref().unwrap_or(&self.store);
+        let shapes_graph_name = NamedNodeRef::new_unchecked(SHAPES_GRAPH_IRI);
+        let mut transaction = store.start_transaction().map_err(|e| error(&e))?;
+        if copy.is_some() {
+            for quad in self.store.iter() {
+                transaction.insert(&quad.map_err(|e| error(&e))?);
+            }
+        }
+        for triple in &removed {
+            transaction.remove(triple.as_ref().in_graph(shapes_graph_name));
+        }
+        for triple in &inserted {
+            transaction.insert(triple.as_ref().in_graph(shapes_graph_name));
+        }
+        transaction.commit().map_err(|e| error(&e))?;
+        if let Some(store) = copy {
+            self.store = Arc::new(store);
+        }
+
+        self.shapes_graph = shapes_graph;
+        self.functions = functions;
+        self.well_formedness_issues = Arc::default();
+        // Value indexes are not keyed by shape, so none is known to be unaffected.
+        self.value_indexes = Arc::default();
+        Ok(())
+    }
+
+    /// Graphs whose `rdfs:subClassOf` triples the configured entailment follows.
+    fn class_hierarchy(&self) -> Vec<&Graph> {
+        match self.config.entailment {
+            Entailment::Data => vec![&self.data_graph],
+            Entailment::DataAndShapes => vec![&self.data_graph, &self.shapes_graph],
+            Entailment::None => Vec::new(),
+        }
+    }
+
+    /// `rdf:type` and the configured type predicates.
+    pub fn type_predicates(&self) -> impl Iterator<Item = NamedNodeRef<'_>> {
+        std::iter::once(rdf::TYPE).chain(self.config.type_predicates.iter().map(|p| p.as_ref()))
+    }
+
+    /// Whether `node` is a SHACL instance of `class` in the data graph.
+    pub fn is_instance_of(
+        &self,
+        node: NamedOrBlankNodeRef<'_>,
+        class: NamedOrBlankNodeRef<'_>,
+    ) -> bool {
+        let hierarchy = self.class_hierarchy();
+        self.type_predicates().any(|type_predicate| {
+            self.data_graph
+                .objects_for_subject_predicate(node, type_predicate)
+                .filter_map(utils::term_to_named_or_blank)
+                .any(|node_type| utils::is_subclass_of_in(node_type, class, &hierarchy))
+        })
+    }
+
+    /// The SHACL instances of `class` in the data graph.
+    pub fn instances_of<'s>(&'s self, class: NamedOrBlankNodeRef<'s>) -> HashSet<TermRef<'s>> {
+        let subclasses = utils::collect_all_subclasses_in(class, &self.class_hierarchy());
+        self.type_predicates()
+            .flat_map(|type_predicate| {
+                subclasses.iter().flat_map(move |&subclass| {
+                    self.data_graph
+                        .subjects_for_predicate_object(type_predicate, subclass)
+                        .map(TermRef::from)
+                })
+            })
+            .collect()
+    }
+
+    /// The values reached from `node` over `path` in the data graph, or a
+    /// [`ShaclError::PathLimit`] when the path goes beyond the configured path limits.
+    pub fn resolve_path<'s>(
+        &'s self,
+        path: &Path<'s>,
+        node: NamedOrBlankNodeRef<'s>,
+    ) -> Result<Vec<TermRef<'s>>, ShaclError> {
+        let limits = &self.config.path_limits;
+        // SPARQL property paths cannot be bounded, so limited paths are walked.
+        if !limits.is_unbounded() && path.has_repetition() {
+            return path.resolve_path_with_limits(&self.data_graph, &node, limits);
+        }
+        if self.config.path_evaluation == PathEvaluation::Sparql {
+            if let Some(values) = self.resolve_path_with_sparql(path, node) {
+                return Ok(values);
+            }
+        }
+        Ok(path.resolve_path_for_given_node(&self.data_graph, &node))
+    }
+
+    /// Runs `path` as a SPARQL property path on the store; `None` when it
+    /// has no SPARQL form or the query fails.
+    fn resolve_path_with_sparql<'s>(
+        &'s self,
+        path: &Path<'s>,
+        node: NamedOrBlankNodeRef<'s>,
+    ) -> Option<Vec<TermRef<'s>>> {
+        let property_path = path.to_sparql_property_path();
+        let cached = self
+            .path_queries
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&property_path)
+            .cloned();
+        let query = match cached {
+            Some(query) => query?,
+            None => self
+                .path_queries
+                .write()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .entry(property_path)
+                .or_insert_with_key(|property_path| compile_path_query(property_path).map(Arc::new))
+                .clone()?,
+        };
+
+        let solutions = match SparqlEvaluator::new()
+            .for_query(Query::clone(&query))
+            .substitute_variable(
+                Variable::new_unchecked("this"),
+                Term::from(node.into_owned()),
+            )
+            .on_store(&self.store)
+            .execute()
+        {
+            Ok(QueryResults::Solutions(solutions)) => solutions,
+            _ => return None,
+        };
+        let mut values = Vec::new();
+        for solution in solutions {
+            let value = solution.ok()?.get("value")?.clone();
+            // Values are handed out borrowed from the data graph.
+            values.push(self.data_graph_term(&value, node)?);
+        }
+        Some(values)
+    }
+
+    /// `term` as borrowed from the data graph, if it occurs there.
+    fn data_graph_term<'s>(
+        &'s self,
+        term: &Term,
+        node: NamedOrBlankNodeRef<'s>,
+    ) -> Option<TermRef<'s>> {
+        if term.as_ref() == TermRef::from(node) {
+            return Some(node.into());
+        }
+        if let Some(triple) = self.data_graph.triples_for_object(term).next() {
+            return Some(triple.object);
+        }
+        let subject = utils::term_to_named_or_blank(term.as_ref())?;
+        self.data_graph
+            .triples_for_subject(subject)
+            .next()
+            .map(|triple| triple.subject.into())
+    }
+
+    /// Returns the index cached under `key`, building it on first use.
+    pub(crate) fn value_index(
+        &self,
+        key: String,
+        build: impl FnOnce() -> Result<ValueIndex, ShaclError>,
+    ) -> Result<Arc<ValueIndex>, ShaclError> {
+        let mut indexes = self
+            .value_indexes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(index) = indexes.get(&key) {
+            return Ok(Arc::clone(index));
+        }
+        let index = Arc::new(build()?);
+        indexes.insert(key, Arc::clone(&index));
+        Ok(index)
+    }
+}
+
+/// The query selecting the `?value`s reached from `?this` over a SPARQL
+/// property path; `None` when the path has no SPARQL form.
+pub(crate) fn compile_path_query(property_path: &str) -> Option<Query> {
+    let query = format!(
+        "SELECT DISTINCT ?value WHERE {{ ?this {} ?value }}",
+        property_path
+    );
+    parse_query(&query, &[]).ok()
+}
+
+/// Triples about `node` and the blank nodes reachable from it.
+fn shape_description(graph: &Graph, node: NamedOrBlankNodeRef<'_>) -> Vec<Triple> {
+    let mut triples = Vec::new();
+    let mut visited = HashSet::from([node]);
+    let mut pending = vec![node];
+    while let Some(subject) = pending.pop() {
+        for triple in graph.triples_for_subject(subject) {
+            if let TermRef::BlankNode(blank_node) = triple.object {
+                if visited.insert(blank_node.into()) {
+                    pending.push(blank_node.into());
+                }
+            }
+            triples.push(triple.into_owned());
+        }
+    }
+    triples
+}
+
+/// The triples of `graph` with each blank node renamed to a new one, so they
+/// cannot clash with blank nodes of another graph.
+fn with_fresh_blank_nodes(graph: &Graph) -> Vec<Triple> {
+    let mut renamed: HashMap<BlankNodeRef<'_>, BlankNode> = HashMap::new();
+    let mut rename = |blank_node| renamed.entry(blank_node).or_default().clone();
+    graph
+        .iter()
+        .map(|triple| {
+            let subject: NamedOrBlankNode = match triple.subject {
+                NamedOrBlankNodeRef::BlankNode(blank_node) => rename(blank_node).into(),
+                subject => subject.into_owned(),
+            };
+            let object: Term = match triple.object {
+                TermRef::BlankNode(blank_node) => rename(blank_node).into(),
+                object => object.into_owned(),
+            };
+            Triple::new(subject, triple.predicate, object)
+        })
+        .collect()
+}
+
+/// Copies one graph out of `store`; named graphs must exist.
+pub(crate) fn read_store_graph(
+    store: &Store,
+    graph_name: GraphNameRef<'_>,
+) -> Result<Graph, ShaclError> {
+    if let GraphNameRef::NamedNode(name) = graph_name {
+        if !store
+            .contains_named_graph(name)
+            .map_err(|e| ShaclError::Io(format!("Failed to read store: {}", e)))?
+        {
+            return Err(ShaclError::Parse(format!("Graph {} does not exist", name)));
+        }
+    }
+
+    let mut graph = Graph::new();
+    for quad in store.quads_for_pattern(None, None, None, Some(graph_name)) {
+        let quad = quad
+            .map_err(|e| ShaclError::Io(format!("Failed to read graph {}: {}", graph_name, e)))?;
+        graph.insert(&Triple::new(quad.subject, quad.predicate, quad.object));
+    }
+    Ok(graph)
 }
 
 impl Deref for ValidationDataset {
@@ -0,0 +1,133 @@
+//! Evaluation of dataset-level constraints.
+
+use std::collections::HashMap;
+
+use oxigraph::{
+    model::{Graph, NamedNodeRef, NamedOrBlankNodeRef, Term, TermRef},
+    sparql::QueryResults,
+};
+use spargebra::Query;
+
+use crate::{
+    core::{component::ConstraintComponent, dataset_constraint::DatasetConstraint},
+    validation::{
+        constraints::sparql::{parse_query, render_messages_for_solution, PreBinding},
+        dataset::{self, ValidationDataset},
+        report::ValidationResult,
+    },
+    vocab::shx,
+};
+
+/// Runs dataset constraints and returns one result per solution.
+pub fn validate_dataset_constraints<'a>(
+    validation_dataset: &'a ValidationDataset,
+    constraints: &[DatasetConstraint<'a>],
+) -> Vec<ValidationResult<'a>> {
+    constraints
+        .iter()
+        .filter(|constraint| !constraint.deactivated)
+        .flat_map(|constraint| validate_dataset_constraint(validation_dataset, constraint))
+        .collect()
+}
+
+fn validate_dataset_constraint<'a>(
+    validation_dataset: &'a ValidationDataset,
+    constraint: &DatasetConstraint<'a>,
+) -> Vec<ValidationResult<'a>> {
+    let result = |messages: Vec<String>, detail: String| {
+        ValidationResult::new(shx::DATASET.into(), constraint.node, constraint.severity)
+            .with_source_constraint_component(Some(ConstraintComponent::Other(
+                shx::DATASET_CONSTRAINT_COMPONENT,
+            )))
+            .with_constraint_detail(Some(detail))
+            .with_messages(Some(messages))
+    };
+
+    let query = match parse_query(&constraint.select, &constraint.prefixes) {
+        Ok(query @ Query::Select { .. }) => query,
+        Ok(_) => {
+            return vec![result(
+                vec!["Dataset constraints must use a SELECT query".to_string()],
+                constraint.select.clone(),
+            )]
+        }
+        Err(error) => {
+            return vec![result(
+                vec![format!("SPARQL parse error: {}", error)],
+                constraint.select.clone(),
+            )]
+        }
+    };
+
+    let bindings: HashMap<&str, Term> = HashMap::from([
+        (
+            "shapesGraph",
+            NamedNodeRef::new_unchecked(dataset::SHAPES_GRAPH_IRI)
+                .into_owned()
+                .into(),
+        ),
+        ("currentShape", TermRef::from(constraint.node).into_owned()),
+    ]);
+    let context_bindings: Vec<(String, String)> = bindings
+        .iter()
+        .map(|(name, term)| (name.to_string(), term.to_string()))
+        .collect();
+
+    let bound_query = PreBinding { values: &bindings }.query(query);
+    let detail = format!(
+        "SPARQL SELECT: {}",
+        bound_query.to_string().replace('\n', " ")
+    );
+    let store = validation_dataset.store();
+    let solutions = match validation_dataset
+        .sparql_evaluator()
+        .for_query(bound_query)
+        .on_store(store.as_ref())
+        .execute()
+    {
+        Ok(QueryResults::Solutions(solutions)) => solutions,
+        Ok(_) => return Vec::new(),
+        Err(error) => {
+            return vec![result(
+                vec![format!("SPARQL execution error: {}", error)],
+                detail,
+            )]
+        }
+    };
+
+    let mut results = Vec::new();
+    for solution in solutions.flatten() {
+        let result_bindings: Vec<(String, String)> = solution
+            .iter()
+            .map(|(var, term)| (var.as_str().to_string(), term.to_string()))
+            .collect();
+
+        let messages = if constraint.messages.is_empty() {
+            vec!["Dataset constraint violation".to_string()]
+        } else {
+            render_messages_for_solution(&constraint.messages, &context_bindings, &result_bindings)
+        };
+
+        let value = solution
+            .get("value")
+            .and_then(|term| find_in_graph(validation_dataset.data_graph(), term));
+        results.push(result(messages, detail.clone()).with_value(value));
+    }
+    results
+}
+
+/// Finds `term` among the subjects or objects of `graph`, to borrow it from there.
+pub(crate) fn find_in_graph<'a>(graph: &'a Graph, term: &Term) -> Option<TermRef<'a>> {
+    if let Some(triple) = graph.triples_for_object(term.as_ref()).next() {
+        return Some(triple.object);
+    }
+    let subject = match term {
+        Term::NamedNode(node) => NamedOrBlankNodeRef::from(node.as_ref()),
+        Term::BlankNode(node) => NamedOrBlankNodeRef::from(node.as_ref()),
+        _ => return None,
+    };
+    graph
+        .triples_for_subject(subject)
+        .next()
+        .map(|triple| triple.subject.into())
+}
@@ -0,0 +1,161 @@
+//! SHACL-SPARQL functions declared in the shapes graph.
+//!
+//! Every `sh:SPARQLFunction` (or `sh:Function` with a `sh:select` or `sh:ask`)
+//! is registered on the evaluators of SPARQL constraints and rules, so their
+//! queries can call it by IRI.
+
+use std::{collections::HashMap, sync::Arc};
+
+use oxigraph::{
+    model::{vocab::rdf, Graph, Literal, NamedNode, NamedOrBlankNodeRef, Term, TermRef, Variable},
+    sparql::{QueryResults, SparqlEvaluator},
+    store::Store,
+};
+use spargebra::Query;
+
+use crate::{
+    err::ShaclError,
+    utils::{get_boolean_value, get_string_value, local_name_from_iri, parse_shacl_prefixes},
+    validation::constraints::sparql::{mentions_variable, parse_query, PreBinding},
+    vocab::sh,
+};
+
+/// A function with a SPARQL body.
+#[derive(Debug, Clone)]
+pub struct SparqlFunction {
+    pub iri: NamedNode,
+    /// Parameters in argument order: by `sh:order`, then by name.
+    pub parameters: Vec<FunctionParameter>,
+    query: Query,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionParameter {
+    /// Variable the argument is bound to: the local name of the `sh:path`.
+    pub name: String,
+    pub optional: bool,
+}
+
+/// Parses the SPARQL functions declared in `graph`.
+pub fn parse_sparql_functions(graph: &Graph) -> Result<Vec<SparqlFunction>, ShaclError> {
+    let mut functions = Vec::new();
+    for candidate in [sh::SPARQL_FUNCTION, sh::FUNCTION] {
+        for node in graph.subjects_for_predicate_object(rdf::TYPE, candidate) {
+            let NamedOrBlankNodeRef::NamedNode(iri) = node else {
+                continue;
+            };
+            if functions
+                .iter()
+                .any(|function: &SparqlFunction| function.iri.as_ref() == iri)
+            {
+                continue;
+            }
+            let body = get_string_value(graph, node, sh::SELECT)
+                .or_else(|| get_string_value(graph, node, sh::ASK));
+            let Some(body) = body else {
+                if candidate == sh::SPARQL_FUNCTION {
+                    return Err(ShaclError::Parse(format!(
+                        "SPARQL function {} has no sh:select or sh:ask",
+                        iri
+                    )));
+                }
+                continue;
+            };
+
+            let query = parse_query(&body, &parse_shacl_prefixes(graph, node)).map_err(|e| {
+                ShaclError::Parse(format!("Invalid query of SPARQL function {}: {}", iri, e))
+            })?;
+            functions.push(SparqlFunction {
+                iri: iri.into_owned(),
+                parameters: parse_parameters(graph, node)?,
+                query,
+            });
+        }
+    }
+    Ok(functions)
+}
+
+fn parse_parameters(
+    graph: &Graph,
+    function: NamedOrBlankNodeRef<'_>,
+) -> Result<Vec<FunctionParameter>, ShaclError> {
+    let mut parameters = Vec::new();
+    for parameter in graph.objects_for_subject_predicate(function, sh::PARAMETER) {
+        let invalid =
+            || ShaclError::Parse(format!("Invalid parameter {} of {}", parameter, function));
+        let node = crate::utils::term_to_named_or_blank(parameter).ok_or_else(invalid)?;
+        let Some(TermRef::NamedNode(path)) = graph.object_for_subject_predicate(node, sh::PATH)
+        else {
+            return Err(invalid());
+        };
+        let name = local_name_from_iri(path.as_str()).ok_or_else(invalid)?;
+        let order = match graph.object_for_subject_predicate(node, sh::ORDER) {
+            Some(TermRef::Literal(order)) => order.value().parse::<f64>().ok(),
+            _ => None,
+        };
+        let optional = get_boolean_value(graph, node, sh::OPTIONAL).unwrap_or(false);
+        parameters.push((order, FunctionParameter { name, optional }));
+    }
+
+    parameters.sort_by(|(a, a_parameter), (b, b_parameter)| {
+        a.unwrap_or(f64::MAX)
+            .total_cmp(&b.unwrap_or(f64::MAX))
+            .then_with(|| a_parameter.name.cmp(&b_parameter.name))
+    });
+    Ok(parameters
+        .into_iter()
+        .map(|(_, parameter)| parameter)
+        .collect())
+}
+
+impl SparqlFunction {
+    /// Runs the function body with `arguments` bound to its parameters.
+    ///
+    /// Returns the first value of the first solution of a SELECT body, or
+    /// the answer of an ASK body; `None` for a wrong number of arguments.
+    fn call(&self, evaluator: SparqlEvaluator, store: &Store, arguments: &[Term]) -> Option<Term> {
+        let required = self.parameters.iter().filter(|p| !p.optional).count();
+        if arguments.len() < required || arguments.len() > self.parameters.len() {
+            return None;
+        }
+
+        let values: HashMap<&str, Term> = self
+            .parameters
+            .iter()
+            .zip(arguments)
+            .map(|(parameter, argument)| (parameter.name.as_str(), argument.clone()))
+            .collect();
+        let bound_query = PreBinding { values: &values }.query(self.query.clone());
+        let bound_text = bound_query.to_string();
+        let mut prepared = evaluator.for_query(bound_query);
+        // Blank nodes cannot be written into the query and are bound instead.
+        for (name, term) in &values {
+            if matches!(term, Term::BlankNode(_)) && mentions_variable(&bound_text, name) {
+                prepared =
+                    prepared.substitute_variable(Variable::new_unchecked(*name), term.clone());
+            }
+        }
+        match prepared.on_store(store).execute().ok()? {
+            QueryResults::Boolean(answer) => Some(Literal::from(answer).into()),
+            QueryResults::Solutions(mut solutions) => {
+                let variable = solutions.variables().first()?.clone();
+                solutions.next()?.ok()?.get(&variable).cloned()
+            }
+            QueryResults::Graph(_) => None,
+        }
+    }
+}
+
+/// A SPARQL evaluator with `functions` registered, running them on `store`.
+pub(crate) fn evaluator(functions: &Arc<[SparqlFunction]>, store: &Arc<Store>) -> SparqlEvaluator {
+    let mut registered = SparqlEvaluator::new();
+    for (index, function) in functions.iter().enumerate() {
+        let functions = Arc::clone(functions);
+        let store = Arc::clone(store);
+        registered = registered.with_custom_function(function.iri.clone(), move |arguments| {
+            // Function bodies may call other functions of the shapes graph.
+            functions[index].call(evaluator(&functions, &store), &store, arguments)
+        });
+    }
+    registered
+}
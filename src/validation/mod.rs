@@ -1,31 +1,66 @@
+pub mod components;
+pub mod config;
+mod conformance_cache;
 pub mod constraints;
 pub mod dataset;
+pub mod dataset_constraint;
+pub mod functions;
+pub mod plan;
+pub mod profile;
+pub mod render;
 pub mod report;
+pub mod stored_report;
+mod units;
+pub mod unsupported;
+pub mod validator;
 mod violation_builder;
 
-use oxigraph::model::{Graph, NamedNodeRef, NamedOrBlankNodeRef, TermRef};
-use std::collections::{HashMap, HashSet};
+use oxigraph::model::{NamedNodeRef, NamedOrBlankNode, TermRef};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Instant,
+};
+use unicode_normalization::{is_nfc, UnicodeNormalization};
 
 #[cfg(not(target_family = "wasm"))]
 use rayon::prelude::*;
 
 use crate::{
-    core::{constraints::Constraint, path::Path, shape::Shape, target::Target},
+    core::{
+        component::ConstraintComponent,
+        constraints::Constraint,
+        path::Path,
+        shape::Shape,
+        target::{Target, TargetTypes},
+        transform::ValueTransform,
+    },
     utils,
     validation::{
+        conformance_cache::ConformanceCache,
         dataset::ValidationDataset,
-        report::{ValidationReport, ValidationResult},
+        report::{ValidationFailure, ValidationReport, ValidationResult},
+        validator::{RunLimits, Validator},
         violation_builder::ViolationBuilder,
     },
-    vocab::sh,
     ShaclError,
 };
 
 pub type TargetResolutionCache<'a> = HashMap<Target<'a>, HashSet<TermRef<'a>>>;
 
 pub fn build_target_cache<'a>(
-    data_graph: &'a Graph,
-    shapes: &'a [Shape<'a>],
+    validation_dataset: &'a ValidationDataset,
+    shapes: &'a [Arc<Shape<'a>>],
+) -> TargetResolutionCache<'a> {
+    build_target_cache_with(validation_dataset, shapes, &TargetTypes::default())
+}
+
+/// Builds the target cache, resolving custom target types with `target_types`.
+pub fn build_target_cache_with<'a>(
+    validation_dataset: &'a ValidationDataset,
+    shapes: &'a [Arc<Shape<'a>>],
+    target_types: &TargetTypes,
 ) -> TargetResolutionCache<'a> {
     let mut cache = TargetResolutionCache::new();
 
@@ -33,7 +68,7 @@ pub fn build_target_cache<'a>(
         for &target in &shape.targets {
             cache
                 .entry(target)
-                .or_insert_with(|| target.resolve_target_for_given_graph(data_graph));
+                .or_insert_with(|| target.resolve_target_with(validation_dataset, target_types));
         }
     }
 
@@ -53,30 +88,22 @@ pub trait Validate<'a> {
     ) -> Result<Vec<ValidationResult<'a>>, ShaclError>;
 }
 
-/// Validates a graph against all provided shapes.
+/// Validates a graph against all provided shapes and the dataset constraints of the shapes graph.
 pub fn validate<'a>(
     validation_dataset: &'a ValidationDataset,
-    shapes: &'a [Shape<'a>],
+    shapes: &'a [Arc<Shape<'a>>],
 ) -> ValidationReport<'a> {
-    let mut report = ValidationReport::new();
-    let target_cache = build_target_cache(validation_dataset.data_graph(), shapes);
-    #[cfg(not(target_family = "wasm"))]
-    let shape_reports: Vec<ValidationReport<'a>> = shapes
-        .par_iter()
-        .map(|shape| shape.validate_with_target_cache(validation_dataset, &target_cache))
-        .collect();
-
-    #[cfg(target_family = "wasm")]
-    let shape_reports: Vec<ValidationReport<'a>> = shapes
-        .iter()
-        .map(|shape| shape.validate_with_target_cache(validation_dataset, &target_cache))
-        .collect();
-
-    for shape_report in shape_reports {
-        report.merge(shape_report);
-    }
+    Validator::default().validate(validation_dataset, shapes)
+}
 
-    report
+/// Validates only the given nodes, returning one report per node, see
+/// [`Validator::validate_nodes`].
+pub fn validate_nodes<'a>(
+    validation_dataset: &'a ValidationDataset,
+    shapes: &'a [Arc<Shape<'a>>],
+    nodes: &[NamedOrBlankNode],
+) -> HashMap<NamedOrBlankNode, ValidationReport<'a>> {
+    Validator::default().validate_nodes(validation_dataset, shapes, nodes)
 }
 
 impl<'a> Shape<'a> {
@@ -90,22 +117,42 @@ impl<'a> Shape<'a> {
         validation_dataset: &'a ValidationDataset,
         target_cache: &TargetResolutionCache<'a>,
     ) -> ValidationReport<'a> {
-        let mut report = ValidationReport::new();
-
-        if self.deactivated {
-            return report;
-        }
+        let focus_nodes = self.focus_nodes(validation_dataset, target_cache);
+        self.validate_focus_nodes(validation_dataset, focus_nodes, None)
+    }
 
+    /// Resolves the focus nodes of this shape's targets.
+    fn focus_nodes(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        target_cache: &TargetResolutionCache<'a>,
+    ) -> HashSet<TermRef<'a>> {
         let mut focus_nodes = HashSet::new();
         for &target in &self.targets {
             if let Some(cached_nodes) = target_cache.get(&target) {
                 focus_nodes.extend(cached_nodes.iter().copied());
             } else {
-                focus_nodes
-                    .extend(target.resolve_target_for_given_graph(validation_dataset.data_graph()));
+                focus_nodes.extend(target.resolve_target(validation_dataset));
             }
         }
+        focus_nodes
+    }
+
+    /// Validates the given focus nodes against this shape, skipping those left
+    /// once `limits` are reached.
+    fn validate_focus_nodes(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        focus_nodes: HashSet<TermRef<'a>>,
+        limits: Option<&RunLimits>,
+    ) -> ValidationReport<'a> {
+        let mut report = ValidationReport::new();
+
+        if self.deactivated {
+            return report;
+        }
 
+        report.add_focus_node_count(self.node, focus_nodes.len());
         let focus_nodes_vec: Vec<_> = focus_nodes.into_iter().collect();
 
         #[cfg(not(target_family = "wasm"))]
@@ -113,7 +160,13 @@ impl<'a> Shape<'a> {
             .par_iter()
             .map(|&focus_node| {
                 let mut node_report = ValidationReport::new();
+                if limits.is_some_and(RunLimits::is_spent) {
+                    return node_report;
+                }
                 self.validate_focus_node(validation_dataset, focus_node, &mut node_report);
+                if let Some(limits) = limits {
+                    limits.apply(&mut node_report);
+                }
                 node_report
             })
             .collect();
@@ -123,7 +176,13 @@ impl<'a> Shape<'a> {
             .iter()
             .map(|&focus_node| {
                 let mut node_report = ValidationReport::new();
+                if limits.is_some_and(RunLimits::is_spent) {
+                    return node_report;
+                }
                 self.validate_focus_node(validation_dataset, focus_node, &mut node_report);
+                if let Some(limits) = limits {
+                    limits.apply(&mut node_report);
+                }
                 node_report
             })
             .collect();
@@ -131,15 +190,56 @@ impl<'a> Shape<'a> {
         for node_report in focus_reports {
             report.merge(node_report);
         }
+        report.set_counted_shape(self.node);
 
         report
     }
 
+    /// Lexical form of a literal after the run's and this shape's value transformations,
+    /// with a trace entry when they changed it. In Unicode mode the result is NFC-normalized.
+    pub(crate) fn transformed_value<'v>(
+        &self,
+        validation_dataset: &ValidationDataset,
+        value: &'v str,
+    ) -> (Cow<'v, str>, Option<String>) {
+        let transforms: Vec<ValueTransform> = validation_dataset
+            .config()
+            .value_transforms
+            .iter()
+            .chain(&self.value_transforms)
+            .copied()
+            .collect();
+
+        let mut transformed = Cow::Borrowed(value);
+        let mut applied = Vec::new();
+        for transform in transforms {
+            let next = transform.apply(&transformed);
+            if next != transformed {
+                applied.push(transform.name());
+                transformed = Cow::Owned(next);
+            }
+        }
+
+        if validation_dataset.config().unicode_mode && !is_nfc(&transformed) {
+            transformed = Cow::Owned(transformed.nfc().collect());
+        }
+
+        let trace = (!applied.is_empty()).then(|| {
+            format!(
+                "Value {:?} was transformed to {:?} by {}",
+                value,
+                transformed,
+                applied.join(", ")
+            )
+        });
+        (transformed, trace)
+    }
+
     /// Validates one node against this shape, without target resolution.
-    fn validate_node(
+    pub(crate) fn validate_node(
         &'a self,
         validation_dataset: &'a ValidationDataset,
-        node: NamedOrBlankNodeRef<'a>,
+        node: TermRef<'a>,
     ) -> bool {
         *self
             .validate_node_report(validation_dataset, node)
@@ -149,7 +249,7 @@ impl<'a> Shape<'a> {
     fn validate_node_report(
         &'a self,
         validation_dataset: &'a ValidationDataset,
-        node: NamedOrBlankNodeRef<'a>,
+        node: TermRef<'a>,
     ) -> ValidationReport<'a> {
         let mut report = ValidationReport::new();
 
@@ -157,7 +257,7 @@ impl<'a> Shape<'a> {
             return report;
         }
 
-        self.validate_focus_node(validation_dataset, node.into(), &mut report);
+        self.validate_focus_node(validation_dataset, node, &mut report);
 
         report
     }
@@ -169,26 +269,65 @@ impl<'a> Shape<'a> {
         focus_node: TermRef<'a>,
         report: &mut ValidationReport<'a>,
     ) {
-        let value_nodes = self.get_value_nodes(validation_dataset, focus_node);
-        self.validate_constraints_on_values(validation_dataset, focus_node, &value_nodes, report);
-        self.validate_nested_property_shapes(validation_dataset, focus_node, &value_nodes, report);
-        self.validate_closed_constraint(validation_dataset, focus_node, report);
+        self.validate_focus_node_with(
+            validation_dataset,
+            focus_node,
+            report,
+            &ConformanceCache::default(),
+        );
+    }
+
+    /// Validates a focus node against this shape, sharing nested shape reports through `cache`.
+    pub(crate) fn validate_focus_node_with(
+        &'a self,
+        validation_dataset: &'a ValidationDataset,
+        focus_node: TermRef<'a>,
+        report: &mut ValidationReport<'a>,
+        cache: &ConformanceCache<'a>,
+    ) {
+        let mut node_report = ValidationReport::new();
+        let value_nodes = match self.get_value_nodes(validation_dataset, focus_node) {
+            Ok(value_nodes) => value_nodes,
+            Err(e) => {
+                // Checking the constraints on a cut-off set of values would be misleading.
+                report.add_failure(ValidationFailure::new(self.node, focus_node, e));
+                return;
+            }
+        };
+        self.validate_constraints_on_values(
+            validation_dataset,
+            focus_node,
+            &value_nodes,
+            &mut node_report,
+            cache,
+        );
+        self.validate_nested_property_shapes(
+            validation_dataset,
+            focus_node,
+            &value_nodes,
+            &mut node_report,
+            cache,
+        );
+        self.validate_closed_constraint(validation_dataset, focus_node, &mut node_report);
+
+        node_report.apply_limits(&validation_dataset.config().report_limits);
+        report.merge(node_report);
     }
 
     /// Resolves value nodes for the current shape.
     fn get_value_nodes(
         &'a self,
-        data_graph: &'a Graph,
+        validation_dataset: &'a ValidationDataset,
         focus_node: TermRef<'a>,
-    ) -> Vec<TermRef<'a>> {
+    ) -> Result<Vec<TermRef<'a>>, ShaclError> {
         if let Some(path) = &self.path {
             if let Some(focus_as_node) = utils::term_to_named_or_blank(focus_node) {
-                path.resolve_path_for_given_node(data_graph, &focus_as_node)
+                validation_dataset.resolve_path(path, focus_as_node)
             } else {
-                Vec::new()
+                Ok(Vec::new())
             }
         } else {
-            vec![focus_node]
+            Ok(vec![focus_node])
         }
     }
 
@@ -199,6 +338,7 @@ impl<'a> Shape<'a> {
         focus_node: TermRef<'a>,
         value_nodes: &[TermRef<'a>],
         report: &mut ValidationReport<'a>,
+        cache: &ConformanceCache<'a>,
     ) {
         for constraint in &self.constraints {
             self.validate_constraint(
@@ -207,6 +347,7 @@ impl<'a> Shape<'a> {
                 value_nodes,
                 constraint,
                 report,
+                cache,
             );
         }
     }
@@ -218,6 +359,7 @@ impl<'a> Shape<'a> {
         _focus_node: TermRef<'a>,
         value_nodes: &[TermRef<'a>],
         report: &mut ValidationReport<'a>,
+        cache: &ConformanceCache<'a>,
     ) {
         if self.property_shapes.is_empty() {
             return;
@@ -262,9 +404,15 @@ impl<'a> Shape<'a> {
                         *value_node,
                         siblings,
                         report,
+                        cache,
                     );
                 } else {
-                    property_shape.validate_focus_node(validation_dataset, *value_node, report);
+                    property_shape.validate_focus_node_with(
+                        validation_dataset,
+                        *value_node,
+                        report,
+                        cache,
+                    );
                 }
             }
         }
@@ -278,29 +426,34 @@ impl<'a> Shape<'a> {
         focus_node: TermRef<'a>,
         sibling_qualified_shapes: &[&'a Shape<'a>],
         report: &mut ValidationReport<'a>,
+        cache: &ConformanceCache<'a>,
     ) {
-        let value_nodes = property_shape.get_value_nodes(validation_dataset, focus_node);
+        let value_nodes = match property_shape.get_value_nodes(validation_dataset, focus_node) {
+            Ok(value_nodes) => value_nodes,
+            Err(e) => {
+                report.add_failure(ValidationFailure::new(property_shape.node, focus_node, e));
+                return;
+            }
+        };
         let mut qualified_conforming_count = 0;
 
         for constraint in &property_shape.constraints {
             if let Constraint::QualifiedValueShape(qvs) = constraint {
                 if qvs.qualified_value_shapes_disjoint {
                     for &value_node in &value_nodes {
-                        if let Some(value_as_node) = utils::term_to_named_or_blank(value_node) {
-                            if qvs.shape.validate_node(validation_dataset, value_as_node) {
-                                let mut conforms_to_sibling = false;
-                                for sibling_shape in sibling_qualified_shapes {
-                                    if sibling_shape
-                                        .validate_node(validation_dataset, value_as_node)
-                                    {
-                                        conforms_to_sibling = true;
-                                        break;
-                                    }
-                                }
-                                if !conforms_to_sibling {
-                                    qualified_conforming_count += 1;
+                        if (value_node.is_named_node() || value_node.is_blank_node())
+                            && cache.conforms(validation_dataset, &qvs.shape, value_node)
+                        {
+                            let mut conforms_to_sibling = false;
+                            for sibling_shape in sibling_qualified_shapes {
+                                if cache.conforms(validation_dataset, sibling_shape, value_node) {
+                                    conforms_to_sibling = true;
+                                    break;
                                 }
                             }
+                            if !conforms_to_sibling {
+                                qualified_conforming_count += 1;
+                            }
                         }
                     }
 
@@ -311,7 +464,7 @@ impl<'a> Shape<'a> {
                                     "Qualified value shape: {} values conform (min: {})",
                                     qualified_conforming_count, min
                                 ))
-                                .component(sh::QUALIFIED_MIN_COUNT_CONSTRAINT_COMPONENT)
+                                .component(ConstraintComponent::QualifiedMinCount)
                                 .detail(format!("sh:qualifiedMinCount {}", min));
                             report.add_result(property_shape.build_validation_result(builder));
                         }
@@ -324,7 +477,7 @@ impl<'a> Shape<'a> {
                                     "Qualified value shape: {} values conform (max: {})",
                                     qualified_conforming_count, max
                                 ))
-                                .component(sh::QUALIFIED_MAX_COUNT_CONSTRAINT_COMPONENT)
+                                .component(ConstraintComponent::QualifiedMaxCount)
                                 .detail(format!("sh:qualifiedMaxCount {}", max));
                             report.add_result(property_shape.build_validation_result(builder));
                         }
@@ -339,6 +492,7 @@ impl<'a> Shape<'a> {
                 &value_nodes,
                 constraint,
                 report,
+                cache,
             );
         }
 
@@ -347,6 +501,7 @@ impl<'a> Shape<'a> {
             focus_node,
             &value_nodes,
             report,
+            cache,
         );
         property_shape.validate_closed_constraint(validation_dataset, focus_node, report);
     }
@@ -367,6 +522,7 @@ impl<'a> Shape<'a> {
             Some(node) => node,
             None => return,
         };
+        let started = validation_dataset.config().profile.then(Instant::now);
 
         let mut allowed_properties: HashSet<NamedNodeRef<'a>> = HashSet::new();
         for ignored_prop in &closed_constraint.ignored_properties {
@@ -388,13 +544,16 @@ impl<'a> Shape<'a> {
                         "Property {} is not allowed (closed shape)",
                         triple.predicate
                     ))
-                    .component(sh::CLOSED_CONSTRAINT_COMPONENT)
+                    .component(ConstraintComponent::Closed)
                     .detail(format!("Unexpected property: {}", triple.predicate))
                     .value(triple.object);
 
                 report.add_result(self.build_validation_result(builder));
             }
         }
+        if let Some(started) = started {
+            report.add_constraint_cost(self.node, ConstraintComponent::Closed, started.elapsed());
+        }
     }
 
     /// Validates one constraint for the given values.
@@ -405,7 +564,9 @@ impl<'a> Shape<'a> {
         value_nodes: &[TermRef<'a>],
         constraint: &'a Constraint<'a>,
         report: &mut ValidationReport<'a>,
+        cache: &ConformanceCache<'a>,
     ) {
+        let started = validation_dataset.config().profile.then(Instant::now);
         let violations = match constraint {
             Constraint::Class(c) => c.validate(
                 validation_dataset,
@@ -491,98 +652,130 @@ impl<'a> Shape<'a> {
                 value_nodes,
                 self,
             ),
-            Constraint::LanguageIn(c) => c.validate(
+            Constraint::TotalDigits(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::UniqueLang(c) => c.validate(
+            Constraint::FractionDigits(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::Equals(c) => c.validate(
+            Constraint::RelativeTime(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::Disjoint(c) => c.validate(
+            Constraint::NamespaceIn(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::LessThan(c) => c.validate(
+            Constraint::UriTemplate(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::LessThanOrEquals(c) => c.validate(
+            Constraint::IdentifierFormat(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::HasValue(c) => c.validate(
+            Constraint::LanguageIn(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::In(c) => c.validate(
+            Constraint::UniqueLang(c) => c.validate(
+                validation_dataset,
+                focus_node,
+                self.path.as_ref(),
+                value_nodes,
+                self,
+            ),
+            Constraint::Equals(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::Node(c) => c.validate(
+            Constraint::Disjoint(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::QualifiedValueShape(c) => c.validate(
+            Constraint::LessThan(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::And(c) => c.validate(
+            Constraint::LessThanOrEquals(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::Or(c) => c.validate(
+            Constraint::HasValue(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::Xone(c) => c.validate(
+            Constraint::In(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
                 value_nodes,
                 self,
             ),
-            Constraint::Not(c) => c.validate(
+            Constraint::Node(c) => {
+                c.validate_with(validation_dataset, focus_node, value_nodes, self, cache)
+            }
+            Constraint::QualifiedValueShape(c) => {
+                c.validate_with(validation_dataset, focus_node, value_nodes, self, cache)
+            }
+            Constraint::UniqueValueForClass(c) => c.validate(
+                validation_dataset,
+                focus_node,
+                self.path.as_ref(),
+                value_nodes,
+                self,
+            ),
+            Constraint::And(c) => {
+                c.validate_with(validation_dataset, focus_node, value_nodes, self, cache)
+            }
+            Constraint::Or(c) => {
+                c.validate_with(validation_dataset, focus_node, value_nodes, self, cache)
+            }
+            Constraint::Xone(c) => {
+                c.validate_with(validation_dataset, focus_node, value_nodes, self, cache)
+            }
+            Constraint::Not(c) => {
+                c.validate_with(validation_dataset, focus_node, value_nodes, self, cache)
+            }
+            Constraint::Expression(c) => c.validate(
                 validation_dataset,
                 focus_node,
                 self.path.as_ref(),
@@ -596,10 +789,21 @@ impl<'a> Shape<'a> {
                 value_nodes,
                 self,
             ),
+            Constraint::Custom(c) => c.validate(
+                validation_dataset,
+                focus_node,
+                self.path.as_ref(),
+                value_nodes,
+                self,
+            ),
         };
 
-        if let Ok(violations) = violations {
-            report.extend_results(violations);
+        if let Some(started) = started {
+            report.add_constraint_cost(self.node, constraint.component(), started.elapsed());
+        }
+        match violations {
+            Ok(violations) => report.extend_results(violations),
+            Err(e) => report.add_failure(ValidationFailure::new(self.node, focus_node, e)),
         }
     }
 
@@ -608,40 +812,7 @@ impl<'a> Shape<'a> {
     where
         F: Fn(i32) -> bool,
     {
-        match (a, b) {
-            (TermRef::Literal(lit_a), TermRef::Literal(lit_b)) => {
-                // Try to parse as numbers
-                let num_a = lit_a.value().parse::<f64>();
-                let num_b = lit_b.value().parse::<f64>();
-
-                match (num_a, num_b) {
-                    (Ok(na), Ok(nb)) => {
-                        // Both are numbers - compare numerically
-                        if na < nb {
-                            predicate(-1)
-                        } else if na > nb {
-                            predicate(1)
-                        } else {
-                            predicate(0)
-                        }
-                    }
-                    (Err(_), Err(_)) => {
-                        // Both are non-numeric - compare as strings
-                        let cmp = lit_a.value().cmp(lit_b.value());
-                        predicate(match cmp {
-                            std::cmp::Ordering::Less => -1,
-                            std::cmp::Ordering::Equal => 0,
-                            std::cmp::Ordering::Greater => 1,
-                        })
-                    }
-                    _ => {
-                        // One is numeric, one is not - incompatible types
-                        false
-                    }
-                }
-            }
-            _ => false,
-        }
+        utils::compare_values(a, b, predicate)
     }
 
     /// Builds a ValidationResult from a ViolationBuilder
@@ -665,6 +836,7 @@ impl<'a> Shape<'a> {
             messages.retain(|msg| unique_messages.insert(msg.clone()));
         }
 
+        let documentation = self.documentation_for(builder.constraint_component);
         ValidationResult::new(builder.focus_node, self.node, self.severity)
             .with_source_shape_name(self.name.clone())
             .with_source_constraint_component(builder.constraint_component)
@@ -674,5 +846,8 @@ impl<'a> Shape<'a> {
             .with_messages(Some(messages))
             .with_trace(Some(builder.trace))
             .with_details(Some(builder.details))
+            .with_requirements(self.requirements.clone())
+            .with_help_text(documentation.help_text)
+            .with_documentation_url(documentation.documentation_url)
     }
 }
@@ -0,0 +1,272 @@
+//! How a validation run will evaluate each shape, worked out without data.
+//!
+//! The plan lists how focus nodes are found, how value nodes are reached and
+//! which constraints run in which order with which caches, so the cost of a
+//! shapes graph can be predicted and tuned before it meets a large data graph.
+
+use std::{
+    collections::HashSet,
+    fmt::{Display, Formatter},
+    sync::Arc,
+};
+
+use serde::Serialize;
+
+use crate::{
+    core::{constraints::Constraint, path::Path, shape::Shape, target::Target},
+    validation::{
+        config::{Entailment, PathEvaluation, ValidationConfig},
+        dataset::compile_path_query,
+    },
+};
+
+/// Evaluation plan of a set of shapes, see [`explain_plan`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationPlan {
+    pub shapes: Vec<ShapePlan>,
+    /// Targets resolved once and shared by all shapes that declare them.
+    pub distinct_targets: usize,
+    /// Whether shapes and focus nodes are validated on several threads.
+    pub parallel: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShapePlan {
+    pub shape: String,
+    pub deactivated: bool,
+    /// How each target finds its focus nodes, sorted.
+    pub targets: Vec<String>,
+    /// How value nodes are reached; `None` for node shapes.
+    pub path: Option<PathPlan>,
+    /// Constraints in evaluation order; `sh:closed` is checked after the
+    /// property shapes.
+    pub constraints: Vec<ConstraintPlan>,
+    /// Property shapes, validated on each focus node after the constraints.
+    pub property_shapes: Vec<ShapePlan>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathPlan {
+    pub path: String,
+    pub backend: PathBackend,
+}
+
+/// Where a path is resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PathBackend {
+    /// As a SPARQL property path on the validation store.
+    Sparql,
+    /// By walking the indexed data graph, as configured.
+    Walker,
+    /// By walking the data graph, as the path has no SPARQL form.
+    WalkerFallback,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConstraintPlan {
+    pub component: String,
+    /// Second path resolved per focus node, for the property pair constraints.
+    pub other_path: Option<PathPlan>,
+    /// Cache that saves repeated work, if any.
+    pub cache: Option<String>,
+}
+
+/// Plans the validation of `shapes` with the settings of `config`.
+pub fn explain_plan(shapes: &[Arc<Shape<'_>>], config: &ValidationConfig) -> ValidationPlan {
+    let distinct_targets: HashSet<&Target<'_>> =
+        shapes.iter().flat_map(|shape| &shape.targets).collect();
+    ValidationPlan {
+        shapes: shapes
+            .iter()
+            .map(|shape| shape_plan(shape, config))
+            .collect(),
+        distinct_targets: distinct_targets.len(),
+        parallel: cfg!(not(target_family = "wasm")),
+    }
+}
+
+fn shape_plan(shape: &Shape<'_>, config: &ValidationConfig) -> ShapePlan {
+    let mut targets: Vec<String> = shape
+        .targets
+        .iter()
+        .map(|target| target_strategy(target, config))
+        .collect();
+    targets.sort();
+
+    let mut constraints: Vec<ConstraintPlan> = shape
+        .constraints
+        .iter()
+        .map(|constraint| constraint_plan(constraint, config))
+        .collect();
+    if shape.closed.is_some() {
+        constraints.push(ConstraintPlan {
+            component: crate::vocab::sh::CLOSED_CONSTRAINT_COMPONENT.to_string(),
+            other_path: None,
+            cache: None,
+        });
+    }
+
+    ShapePlan {
+        shape: shape.node.to_string(),
+        deactivated: shape.deactivated,
+        targets,
+        path: shape.path.as_ref().map(|path| path_plan(path, config)),
+        constraints,
+        property_shapes: shape
+            .property_shapes
+            .iter()
+            .map(|property_shape| shape_plan(property_shape, config))
+            .collect(),
+    }
+}
+
+fn target_strategy(target: &Target<'_>, config: &ValidationConfig) -> String {
+    match target {
+        Target::Node(node) => format!("node {}", node),
+        Target::Class(class) => match config.entailment {
+            Entailment::Data => format!(
+                "instances of {} and its subclasses in the data graph",
+                class
+            ),
+            Entailment::DataAndShapes => format!(
+                "instances of {} and its subclasses in the data and shapes graphs",
+                class
+            ),
+            Entailment::None => format!("instances of {} without subclasses", class),
+        },
+        Target::SubjectsOf(property) => {
+            format!("subjects of {} and its subproperties", property)
+        }
+        Target::ObjectsOf(property) => format!("objects of {} and its subproperties", property),
+        Target::Sparql(node) => format!("SPARQL SELECT of {} on the store", node),
+        Target::Advanced(node) => format!("custom target {} with its target type", node),
+    }
+}
+
+fn path_plan(path: &Path<'_>, config: &ValidationConfig) -> PathPlan {
+    let property_path = path.to_sparql_property_path();
+    let backend = match config.path_evaluation {
+        PathEvaluation::Walker => PathBackend::Walker,
+        // Bounded paths are walked, as SPARQL cannot stop at the limits.
+        PathEvaluation::Sparql if !config.path_limits.is_unbounded() && path.has_repetition() => {
+            PathBackend::Walker
+        }
+        PathEvaluation::Sparql if compile_path_query(&property_path).is_some() => {
+            PathBackend::Sparql
+        }
+        PathEvaluation::Sparql => PathBackend::WalkerFallback,
+    };
+    PathPlan {
+        path: path.to_string(),
+        backend,
+    }
+}
+
+fn constraint_plan(constraint: &Constraint<'_>, config: &ValidationConfig) -> ConstraintPlan {
+    let other_path = match constraint {
+        Constraint::Equals(c) => Some(&c.0),
+        Constraint::Disjoint(c) => Some(&c.0),
+        Constraint::LessThan(c) => Some(&c.0),
+        Constraint::LessThanOrEquals(c) => Some(&c.0),
+        _ => None,
+    };
+    let cache = match constraint {
+        Constraint::Node(_)
+        | Constraint::And(_)
+        | Constraint::Or(_)
+        | Constraint::Xone(_)
+        | Constraint::Not(_)
+        | Constraint::QualifiedValueShape(_) => {
+            Some("nested shape reports shared per focus node".to_string())
+        }
+        Constraint::UniqueValueForClass(_) => {
+            Some("value index built once per dataset".to_string())
+        }
+        _ => None,
+    };
+    ConstraintPlan {
+        component: constraint.component().to_string(),
+        other_path: other_path.map(|path| path_plan(path, config)),
+        cache,
+    }
+}
+
+impl ValidationPlan {
+    pub fn as_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl Display for PathBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathBackend::Sparql => write!(f, "SPARQL property path"),
+            PathBackend::Walker => write!(f, "graph walker"),
+            PathBackend::WalkerFallback => write!(f, "graph walker, no SPARQL form"),
+        }
+    }
+}
+
+impl Display for ValidationPlan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} shape(s), {} distinct target(s) resolved once, {}",
+            self.shapes.len(),
+            self.distinct_targets,
+            if self.parallel {
+                "validated in parallel"
+            } else {
+                "validated sequentially"
+            }
+        )?;
+        for shape in &self.shapes {
+            writeln!(f)?;
+            shape.write(f, 0)?;
+        }
+        Ok(())
+    }
+}
+
+impl ShapePlan {
+    fn write(&self, f: &mut Formatter<'_>, depth: usize) -> std::fmt::Result {
+        let indent = "  ".repeat(depth);
+        write!(f, "{}{}", indent, self.shape)?;
+        if self.deactivated {
+            write!(f, " (deactivated)")?;
+        }
+        writeln!(f)?;
+        if depth == 0 && self.targets.is_empty() {
+            writeln!(
+                f,
+                "{}  targets: none, only validated where referenced",
+                indent
+            )?;
+        }
+        for target in &self.targets {
+            writeln!(f, "{}  target: {}", indent, target)?;
+        }
+        if let Some(path) = &self.path {
+            writeln!(f, "{}  path: {} ({})", indent, path.path, path.backend)?;
+        }
+        for (index, constraint) in self.constraints.iter().enumerate() {
+            write!(f, "{}  {}. {}", indent, index + 1, constraint.component)?;
+            if let Some(path) = &constraint.other_path {
+                write!(f, " against {} ({})", path.path, path.backend)?;
+            }
+            if let Some(cache) = &constraint.cache {
+                write!(f, " [{}]", cache)?;
+            }
+            writeln!(f)?;
+        }
+        for property_shape in &self.property_shapes {
+            property_shape.write(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+//! Time spent per shape and constraint component.
+//!
+//! Recorded when [`ValidationConfig::profile`](crate::validation::config::ValidationConfig::profile)
+//! is set, so shape authors can find the constraints that dominate a run.
+
+use std::{
+    fmt::{Display, Formatter},
+    time::Duration,
+};
+
+use oxigraph::model::NamedOrBlankNodeRef;
+
+use crate::core::component::ConstraintComponent;
+
+/// Time spent evaluating a constraint component of a shape.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConstraintCost {
+    /// Number of focus nodes the component was evaluated on.
+    pub evaluations: usize,
+    pub time: Duration,
+}
+
+impl ConstraintCost {
+    pub(crate) fn add(&mut self, other: ConstraintCost) {
+        self.evaluations += other.evaluations;
+        self.time += other.time;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileEntry<'a> {
+    pub shape: NamedOrBlankNodeRef<'a>,
+    pub component: ConstraintComponent<'a>,
+    pub cost: ConstraintCost,
+}
+
+/// Constraint costs of a run, most expensive first.
+///
+/// Displays as a table; [`ConstraintProfile::folded`] gives input for
+/// flamegraph tools. The time of `sh:node`, `sh:and` and the other shape-based
+/// components includes the time of the shapes they check.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConstraintProfile<'a> {
+    entries: Vec<ProfileEntry<'a>>,
+}
+
+impl<'a> ConstraintProfile<'a> {
+    pub(crate) fn new(mut entries: Vec<ProfileEntry<'a>>) -> Self {
+        entries.sort_by(|a, b| {
+            b.cost
+                .time
+                .cmp(&a.cost.time)
+                .then_with(|| a.shape.to_string().cmp(&b.shape.to_string()))
+                .then_with(|| a.component.iri().cmp(&b.component.iri()))
+        });
+        Self { entries }
+    }
+
+    pub fn entries(&self) -> &[ProfileEntry<'a>] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.entries.iter().map(|entry| entry.cost.time).sum()
+    }
+
+    /// One `shape;component microseconds` line per entry, the folded stack
+    /// format read by flamegraph tools.
+    pub fn folded(&self) -> String {
+        self.entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{};{} {}\n",
+                    frame(entry.shape),
+                    entry.component.iri().as_str(),
+                    entry.cost.time.as_micros()
+                )
+            })
+            .collect()
+    }
+}
+
+/// A shape as a stack frame: IRIs without angle brackets.
+fn frame(shape: NamedOrBlankNodeRef<'_>) -> String {
+    match shape {
+        NamedOrBlankNodeRef::NamedNode(iri) => iri.as_str().to_string(),
+        NamedOrBlankNodeRef::BlankNode(node) => node.to_string(),
+    }
+}
+
+impl Display for ConstraintProfile<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let total = self.total_time().as_secs_f64();
+        writeln!(
+            f,
+            "{:>10}  {:>6}  {:>11}  Shape  Component",
+            "Time (ms)", "Share", "Evaluations"
+        )?;
+        for entry in &self.entries {
+            let time = entry.cost.time.as_secs_f64();
+            let share = if total > 0.0 {
+                time / total * 100.0
+            } else {
+                0.0
+            };
+            writeln!(
+                f,
+                "{:>10.3}  {:>5.1}%  {:>11}  {}  {}",
+                time * 1000.0,
+                share,
+                entry.cost.evaluations,
+                entry.shape,
+                entry.component
+            )?;
+        }
+        Ok(())
+    }
+}
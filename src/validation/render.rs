@@ -0,0 +1,406 @@
+//! Rendering of validation reports into human- and machine-readable formats.
+//!
+//! Every format implements [`Renderer`]. With the `templates` feature, reports
+//! can also be rendered through a user-provided Tera template.
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use crate::{
+    core::severity::Severity,
+    err::ShaclError,
+    validation::report::{ValidationReport, ValidationResult},
+};
+
+/// Turns a validation report into a string.
+pub trait Renderer {
+    fn render(&self, report: &ValidationReport<'_>) -> Result<String, ShaclError>;
+
+    /// Writes the rendered report to `writer`. Formats that can be written
+    /// piece by piece override this so the whole output is never held in memory.
+    fn render_to(
+        &self,
+        report: &ValidationReport<'_>,
+        writer: &mut dyn std::io::Write,
+    ) -> Result<(), ShaclError> {
+        writer
+            .write_all(self.render(report)?.as_bytes())
+            .map_err(|e| ShaclError::Io(format!("Failed to write report: {}", e)))
+    }
+}
+
+/// Looks up a built-in renderer by format name.
+pub fn renderer_for(format: &str) -> Option<Box<dyn Renderer>> {
+    match format.trim().to_ascii_lowercase().as_str() {
+        "text" => Some(Box::new(TextRenderer)),
+        "json" => Some(Box::new(JsonRenderer)),
+        "ndjson" | "jsonl" => Some(Box::new(NdjsonRenderer)),
+        "html" => Some(Box::new(HtmlRenderer)),
+        "markdown" | "md" => Some(Box::new(MarkdownRenderer)),
+        "sarif" => Some(Box::new(SarifRenderer)),
+        _ => None,
+    }
+}
+
+/// The human-readable console format.
+pub struct TextRenderer;
+
+impl Renderer for TextRenderer {
+    fn render(&self, report: &ValidationReport<'_>) -> Result<String, ShaclError> {
+        Ok(report.to_string())
+    }
+}
+
+/// The JSON format of [`ValidationReport::as_json`].
+pub struct JsonRenderer;
+
+impl Renderer for JsonRenderer {
+    fn render(&self, report: &ValidationReport<'_>) -> Result<String, ShaclError> {
+        Ok(report.as_json().to_string())
+    }
+}
+
+/// Newline-delimited JSON: one [`ValidationResult::as_json`] object per line.
+pub struct NdjsonRenderer;
+
+impl Renderer for NdjsonRenderer {
+    fn render(&self, report: &ValidationReport<'_>) -> Result<String, ShaclError> {
+        let mut out = Vec::new();
+        self.render_to(report, &mut out)?;
+        String::from_utf8(out).map_err(|e| ShaclError::Io(e.to_string()))
+    }
+
+    fn render_to(
+        &self,
+        report: &ValidationReport<'_>,
+        writer: &mut dyn std::io::Write,
+    ) -> Result<(), ShaclError> {
+        for result in report.get_results() {
+            NdjsonRenderer::write_result(result, writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl NdjsonRenderer {
+    /// Writes one result as a line, e.g. from [`Validator::validate_streaming`](crate::Validator::validate_streaming).
+    pub fn write_result(
+        result: &ValidationResult<'_>,
+        writer: &mut dyn std::io::Write,
+    ) -> Result<(), ShaclError> {
+        serde_json::to_writer(&mut *writer, &result.as_json())
+            .and_then(|_| writer.write_all(b"\n").map_err(serde_json::Error::io))
+            .map_err(|e| ShaclError::Io(format!("Failed to write result: {}", e)))
+    }
+}
+
+/// A Markdown summary with one table row per result.
+pub struct MarkdownRenderer;
+
+impl Renderer for MarkdownRenderer {
+    fn render(&self, report: &ValidationReport<'_>) -> Result<String, ShaclError> {
+        let mut out = String::from("# SHACL Validation Report\n\n");
+        write_provenance(&mut out, report, |label, value| {
+            format!("- **{}:** {}\n", label, markdown_escape(value))
+        });
+        if !report.get_provenance().is_empty() {
+            out.push('\n');
+        }
+
+        if *report.get_conforms() {
+            out.push_str("✓ Data conforms to all shapes\n");
+            return Ok(out);
+        }
+
+        let _ = writeln!(
+            out,
+            "✗ Data does not conform ({} result(s))\n",
+            report.violation_count()
+        );
+        out.push_str("| Severity | Focus node | Path | Value | Message |\n");
+        out.push_str("| --- | --- | --- | --- | --- |\n");
+        for result in report.get_results() {
+            let [severity, focus_node, path, value, message] = result_cells(result);
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {} | {} |",
+                severity,
+                markdown_code(&focus_node),
+                markdown_code(&path),
+                markdown_code(&value),
+                markdown_escape(&message)
+            );
+        }
+        Ok(out)
+    }
+}
+
+/// A standalone HTML page with one table row per result.
+pub struct HtmlRenderer;
+
+impl Renderer for HtmlRenderer {
+    fn render(&self, report: &ValidationReport<'_>) -> Result<String, ShaclError> {
+        let mut out = String::from(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>SHACL Validation Report</title>\n</head>\n<body>\n\
+             <h1>SHACL Validation Report</h1>\n",
+        );
+        write_provenance(&mut out, report, |label, value| {
+            format!(
+                "<p><strong>{}:</strong> {}</p>\n",
+                label,
+                html_escape(value)
+            )
+        });
+
+        if *report.get_conforms() {
+            out.push_str("<p class=\"conforms\">Data conforms to all shapes</p>\n");
+        } else {
+            let _ = writeln!(
+                out,
+                "<p class=\"violations\">Data does not conform ({} result(s))</p>",
+                report.violation_count()
+            );
+            out.push_str(
+                "<table>\n<tr><th>Severity</th><th>Focus node</th><th>Path</th>\
+                 <th>Value</th><th>Message</th></tr>\n",
+            );
+            for result in report.get_results() {
+                out.push_str("<tr>");
+                for cell in result_cells(result) {
+                    let _ = write!(out, "<td>{}</td>", html_escape(&cell));
+                }
+                out.push_str("</tr>\n");
+            }
+            out.push_str("</table>\n");
+        }
+
+        out.push_str("</body>\n</html>\n");
+        Ok(out)
+    }
+}
+
+/// SARIF 2.1.0 output for code-scanning tools, with one rule per constraint component.
+pub struct SarifRenderer;
+
+impl Renderer for SarifRenderer {
+    fn render(&self, report: &ValidationReport<'_>) -> Result<String, ShaclError> {
+        let mut rules: BTreeMap<String, Vec<&ValidationResult<'_>>> = BTreeMap::new();
+        for result in report.get_results() {
+            rules.entry(rule_id(result)).or_default().push(result);
+        }
+
+        let results: Vec<serde_json::Value> = report
+            .get_results()
+            .iter()
+            .map(|result| {
+                let mut properties = serde_json::json!({
+                    "focusNode": result.get_focus_node().to_string(),
+                    "sourceShape": result.get_source_shape().to_string(),
+                });
+                if let Some(path) = result.get_result_path() {
+                    properties["resultPath"] = serde_json::json!(path.to_string());
+                }
+                if let Some(value) = result.get_reported_value() {
+                    properties["value"] = serde_json::json!(value.to_string());
+                }
+                if let Some(help_text) = result.get_help_text() {
+                    properties["helpText"] = serde_json::json!(help_text);
+                }
+                if let Some(url) = result.get_documentation_url() {
+                    properties["documentationUrl"] = serde_json::json!(url);
+                }
+
+                serde_json::json!({
+                    "ruleId": rule_id(result),
+                    "level": sarif_level(result.get_severity()),
+                    "message": { "text": result_message(result) },
+                    "locations": [{
+                        "logicalLocations": [{
+                            "fullyQualifiedName": result.get_focus_node().to_string(),
+                            "kind": "object",
+                        }],
+                    }],
+                    "partialFingerprints": { "shaclResult/v1": result.fingerprint() },
+                    "properties": properties,
+                })
+            })
+            .collect();
+
+        let sarif = serde_json::json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "shacl-rust",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                        "rules": rules
+                            .iter()
+                            .map(|(id, results)| sarif_rule(id, results))
+                            .collect::<Vec<_>>(),
+                    },
+                },
+                "results": results,
+            }],
+        });
+        Ok(sarif.to_string())
+    }
+}
+
+/// Renders reports through a user-provided Tera template.
+///
+/// The template sees the fields of [`ValidationReport::as_json`], e.g.
+/// `{% for r in results %}{{ r.focusNode }}{% endfor %}`.
+#[cfg(feature = "templates")]
+pub struct TemplateRenderer {
+    tera: tera::Tera,
+}
+
+#[cfg(feature = "templates")]
+impl TemplateRenderer {
+    const NAME: &'static str = "report";
+
+    pub fn new(template: &str) -> Result<Self, ShaclError> {
+        let mut tera = tera::Tera::default();
+        tera.add_raw_template(Self::NAME, template)
+            .map_err(|e| ShaclError::Parse(format!("Invalid report template: {}", e)))?;
+        Ok(Self { tera })
+    }
+}
+
+#[cfg(feature = "templates")]
+impl Renderer for TemplateRenderer {
+    fn render(&self, report: &ValidationReport<'_>) -> Result<String, ShaclError> {
+        let context = tera::Context::from_value(report.as_json())
+            .map_err(|e| ShaclError::Io(format!("Failed to build template context: {}", e)))?;
+        self.tera
+            .render(Self::NAME, &context)
+            .map_err(|e| ShaclError::Io(format!("Failed to render report template: {}", e)))
+    }
+}
+
+fn write_provenance(
+    out: &mut String,
+    report: &ValidationReport<'_>,
+    line: impl Fn(&str, &str) -> String,
+) {
+    let provenance = report.get_provenance();
+    if let Some(version) = &provenance.shapes_version {
+        out.push_str(&line("Shapes version", version));
+    }
+    if let Some(source) = &provenance.shapes_source {
+        out.push_str(&line("Shapes source", source));
+    }
+    if let Some(hash) = &provenance.shapes_hash {
+        out.push_str(&line("Shapes hash", hash));
+    }
+    if let Some(validator) = provenance.validator() {
+        out.push_str(&line("Validator", &validator));
+    }
+}
+
+/// Severity, focus node, path, value and message of a result.
+fn result_cells(result: &ValidationResult<'_>) -> [String; 5] {
+    [
+        short_severity(result.get_severity()).to_string(),
+        result.get_focus_node().to_string(),
+        result
+            .get_result_path()
+            .map(ToString::to_string)
+            .unwrap_or_default(),
+        result
+            .get_reported_value()
+            .map(|value| value.to_string())
+            .unwrap_or_default(),
+        result_message(result),
+    ]
+}
+
+fn result_message(result: &ValidationResult<'_>) -> String {
+    if result.get_messages().is_empty() {
+        format!("Constraint violated on {}", result.get_focus_node())
+    } else {
+        result.get_messages().join("; ")
+    }
+}
+
+fn rule_id(result: &ValidationResult<'_>) -> String {
+    result
+        .get_source_constraint_component()
+        .map(|component| component.iri().as_str().to_string())
+        .unwrap_or_else(|| result.get_source_shape().to_string())
+}
+
+/// A SARIF rule, with the guidance that all of its results share.
+fn sarif_rule<'r>(id: &str, results: &[&'r ValidationResult<'r>]) -> serde_json::Value {
+    let shared = |field: fn(&'r ValidationResult<'r>) -> Option<&'r str>| {
+        let first = field(results.first()?)?;
+        results
+            .iter()
+            .all(|result| field(result) == Some(first))
+            .then_some(first)
+    };
+
+    let mut rule = serde_json::json!({ "id": id });
+    if let Some(help_text) = shared(ValidationResult::get_help_text) {
+        rule["help"] = serde_json::json!({ "text": help_text });
+    }
+    if let Some(url) = shared(ValidationResult::get_documentation_url) {
+        rule["helpUri"] = serde_json::json!(url);
+    }
+    rule
+}
+
+fn short_severity(severity: Severity<'_>) -> &str {
+    match severity {
+        Severity::Violation => "Violation",
+        Severity::Warning => "Warning",
+        Severity::Info => "Info",
+        Severity::Other(iri) => iri
+            .as_str()
+            .rsplit(['#', '/'])
+            .next()
+            .unwrap_or(iri.as_str()),
+    }
+}
+
+fn sarif_level(severity: Severity<'_>) -> &'static str {
+    match severity {
+        Severity::Violation => "error",
+        Severity::Warning => "warning",
+        Severity::Info | Severity::Other(_) => "note",
+    }
+}
+
+fn markdown_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\n', " ")
+}
+
+/// RDF terms as inline code, so IRIs in angle brackets survive.
+fn markdown_code(value: &str) -> String {
+    if value.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "`{}`",
+            value
+                .replace('|', "\\|")
+                .replace('`', "'")
+                .replace('\n', " ")
+        )
+    }
+}
+
+fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -1,10 +1,65 @@
+use oxigraph::io::RdfFormat;
 use oxigraph::model::{
-    BlankNode, Graph, Literal, NamedNode, NamedNodeRef, NamedOrBlankNode, NamedOrBlankNodeRef,
-    Term, TermRef, Triple,
+    vocab::rdfs, BlankNode, Graph, Literal, NamedNode, NamedNodeRef, NamedOrBlankNode,
+    NamedOrBlankNodeRef, Term, TermRef, Triple,
 };
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{hash_map::Entry, BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::{
+    core::{component::ConstraintComponent, path::OwnedPath, severity::Severity, shape::Shape},
+    err::ShaclError,
+    parser::well_formed::WellFormednessIssue,
+    rdf, utils,
+    validation::{
+        config::ReportLimits,
+        profile::{ConstraintCost, ConstraintProfile, ProfileEntry},
+    },
+    vocab::{sh, shx},
+    Path,
+};
+
+/// How [`ValidationReport::to_graph_with`] names the report, result and
+/// coverage nodes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ReportNodeIds {
+    /// Fresh blank nodes on every conversion.
+    #[default]
+    Fresh,
+    /// Skolem IRIs: the base followed by a fresh identifier.
+    Skolem(NamedNode),
+    /// Blank nodes labelled `n0`, `n1`, ... in output order, so the same
+    /// report always gets the same labels. Nodes of complex result paths
+    /// stay fresh.
+    Sequential,
+}
+
+/// Hands out report nodes following a [`ReportNodeIds`] strategy.
+struct NodeMinter<'i> {
+    ids: &'i ReportNodeIds,
+    next: usize,
+}
 
-use crate::{vocab::sh, Path};
+impl NodeMinter<'_> {
+    fn mint(&mut self) -> NamedOrBlankNode {
+        let n = self.next;
+        self.next += 1;
+        match self.ids {
+            ReportNodeIds::Fresh => BlankNode::default().into(),
+            ReportNodeIds::Skolem(base) => NamedNode::new_unchecked(format!(
+                "{}{}",
+                base.as_str(),
+                BlankNode::default().as_str()
+            ))
+            .into(),
+            ReportNodeIds::Sequential => BlankNode::new_unchecked(format!("n{}", n)).into(),
+        }
+    }
+}
 
 /// Validation report for a SHACL run.
 #[derive(Debug, Clone, PartialEq)]
@@ -13,6 +68,251 @@ pub struct ValidationReport<'a> {
     conforms: bool,
     /// Collected results.
     results: Vec<ValidationResult<'a>>,
+    /// Where the shapes came from.
+    provenance: ReportProvenance,
+    /// Shape features that were not evaluated.
+    unsupported_features: Vec<UnsupportedFeature>,
+    /// Focus nodes that could not be validated.
+    failures: Vec<ValidationFailure>,
+    /// Whether the shapes graph passed the syntax checks, when they were run.
+    shapes_graph_well_formed: Option<bool>,
+    /// Syntax rule violations found in the shapes graph.
+    well_formedness_issues: Vec<WellFormednessIssue>,
+    /// Number of focus nodes each shape was checked on.
+    focus_node_counts: HashMap<NamedOrBlankNodeRef<'a>, usize>,
+    /// Whether the run was cancelled before every focus node was checked.
+    cancelled: bool,
+    /// Time spent per shape and constraint component, when profiling. Not
+    /// kept by [`ValidationReport::into_owned`].
+    constraint_costs: HashMap<(NamedOrBlankNodeRef<'a>, ConstraintComponent<'a>), ConstraintCost>,
+}
+
+/// Provenance of the shapes and the validator build a report was produced with.
+///
+/// The default describes the running build of this library and no shapes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportProvenance {
+    /// Version of the shapes release.
+    pub shapes_version: Option<String>,
+    /// Location the shapes were loaded from.
+    pub shapes_source: Option<String>,
+    /// Hex [hash](crate::rdf::graph_hash) of the shapes graph.
+    pub shapes_hash: Option<String>,
+    /// Version of shacl-rust that validated.
+    pub validator_version: Option<String>,
+    /// Cargo features shacl-rust was built with.
+    #[serde(default)]
+    pub validator_features: Vec<String>,
+}
+
+/// Cargo features this build of the library was compiled with.
+fn enabled_features() -> Vec<String> {
+    [
+        ("presets", cfg!(feature = "presets")),
+        ("templates", cfg!(feature = "templates")),
+    ]
+    .into_iter()
+    .filter(|(_, enabled)| *enabled)
+    .map(|(feature, _)| feature.to_string())
+    .collect()
+}
+
+impl Default for ReportProvenance {
+    fn default() -> Self {
+        Self {
+            shapes_version: None,
+            shapes_source: None,
+            shapes_hash: None,
+            validator_version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            validator_features: enabled_features(),
+        }
+    }
+}
+
+/// A shape feature that was skipped, so a "conforms" verdict may be incomplete.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct UnsupportedFeature {
+    /// Shape node the feature was found on.
+    pub node: String,
+    /// The skipped feature, usually a SHACL predicate.
+    pub feature: String,
+    /// Why the feature was not evaluated.
+    pub reason: String,
+}
+
+impl UnsupportedFeature {
+    pub fn new(node: impl ToString, feature: impl ToString, reason: impl ToString) -> Self {
+        Self {
+            node: node.to_string(),
+            feature: feature.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn as_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "node": self.node,
+            "feature": self.feature,
+            "reason": self.reason,
+        })
+    }
+}
+
+impl Display for UnsupportedFeature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} on {}: {}", self.feature, self.node, self.reason)
+    }
+}
+
+/// A focus node that could not be validated against a shape, e.g. because a
+/// path went beyond the path limits. Any failure makes the report non-conforming.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationFailure {
+    /// Shape the focus node was validated against.
+    pub shape: String,
+    /// The focus node.
+    pub focus_node: String,
+    /// Why validation failed.
+    pub reason: String,
+}
+
+impl ValidationFailure {
+    pub fn new(shape: impl ToString, focus_node: impl ToString, reason: impl ToString) -> Self {
+        Self {
+            shape: shape.to_string(),
+            focus_node: focus_node.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn as_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "shape": self.shape,
+            "focusNode": self.focus_node,
+            "reason": self.reason,
+        })
+    }
+}
+
+impl Display for ValidationFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} on {}: {}", self.shape, self.focus_node, self.reason)
+    }
+}
+
+/// Weights of [`ValidationReport::quality_score`].
+///
+/// Severities default to 1 for violations, 0.5 for warnings and 0.1 for infos,
+/// and shapes to 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QualityWeights {
+    severities: HashMap<Severity<'static>, f64>,
+    shapes: HashMap<NamedOrBlankNode, f64>,
+}
+
+impl Default for QualityWeights {
+    fn default() -> Self {
+        Self {
+            severities: HashMap::from([
+                (Severity::Violation, 1.0),
+                (Severity::Warning, 0.5),
+                (Severity::Info, 0.1),
+            ]),
+            shapes: HashMap::new(),
+        }
+    }
+}
+
+impl QualityWeights {
+    pub fn with_severity(mut self, severity: Severity<'static>, weight: f64) -> Self {
+        self.severities.insert(severity, weight);
+        self
+    }
+
+    pub fn with_shape(mut self, shape: NamedOrBlankNodeRef<'_>, weight: f64) -> Self {
+        self.shapes.insert(shape.into_owned(), weight);
+        self
+    }
+
+    fn severity(&self, severity: Severity<'_>) -> f64 {
+        self.severities
+            .iter()
+            .find(|(&weighted, _)| weighted == severity)
+            .map_or(1.0, |(_, &weight)| weight)
+    }
+
+    fn shape(&self, shape: NamedOrBlankNodeRef<'_>) -> f64 {
+        self.shapes.get(&shape.into_owned()).copied().unwrap_or(1.0)
+    }
+}
+
+/// Results that differ between a report and a baseline report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportDiff<'r> {
+    /// Results missing from the baseline.
+    pub added: Vec<&'r ValidationResult<'r>>,
+    /// Baseline results that are gone.
+    pub resolved: Vec<&'r ValidationResult<'r>>,
+}
+
+/// View of a report grouped by profile requirement.
+pub struct RequirementsReport<'r, 'a> {
+    report: &'r ValidationReport<'a>,
+    /// Every requirement declared by the shapes, including satisfied ones.
+    requirements: BTreeSet<&'r str>,
+}
+
+/// View of a report that shows a few results per shape and constraint
+/// component, for triaging reports too large to read.
+pub struct SampledReport<'r, 'a> {
+    report: &'r ValidationReport<'a>,
+    groups: Vec<SampleGroup<'r, 'a>>,
+}
+
+/// Results of one shape and constraint component.
+struct SampleGroup<'r, 'a> {
+    shape: NamedOrBlankNodeRef<'a>,
+    component: Option<ConstraintComponent<'a>>,
+    /// Number of results in the full report.
+    count: usize,
+    samples: Vec<&'r ValidationResult<'a>>,
+}
+
+impl ReportProvenance {
+    /// Returns true when no provenance information is set.
+    pub fn is_empty(&self) -> bool {
+        self.shapes_version.is_none()
+            && self.shapes_source.is_none()
+            && self.shapes_hash.is_none()
+            && self.validator_version.is_none()
+            && self.validator_features.is_empty()
+    }
+
+    /// The validator build, e.g. `shacl-rust 0.1.4 (presets, templates)`.
+    pub fn validator(&self) -> Option<String> {
+        let version = self.validator_version.as_ref()?;
+        if self.validator_features.is_empty() {
+            Some(format!("shacl-rust {}", version))
+        } else {
+            Some(format!(
+                "shacl-rust {} ({})",
+                version,
+                self.validator_features.join(", ")
+            ))
+        }
+    }
+
+    pub fn as_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "shapesVersion": self.shapes_version,
+            "shapesSource": self.shapes_source,
+            "shapesHash": self.shapes_hash,
+            "validatorVersion": self.validator_version,
+            "validatorFeatures": self.validator_features,
+        })
+    }
 }
 
 /// One validation result.
@@ -25,21 +325,34 @@ pub struct ValidationResult<'a> {
     /// Optional source shape name.
     source_shape_name: Option<String>,
     /// Constraint component.
-    source_constraint_component: Option<NamedNodeRef<'a>>,
+    source_constraint_component: Option<ConstraintComponent<'a>>,
     /// Human-readable constraint detail.
     constraint_detail: Option<String>,
     /// Result severity.
-    severity: NamedNodeRef<'a>,
+    severity: Severity<'a>,
     /// Property path when available.
     result_path: Option<Path<'a>>,
     /// Value associated with the result.
     value: Option<TermRef<'a>>,
+    /// Shortened form of a value that was too long to report.
+    elided_value: Option<Literal>,
     /// Messages.
     messages: Vec<String>,
     /// Nested evaluation trace.
     trace: Vec<String>,
     /// Nested results.
     details: Vec<ValidationResult<'a>>,
+    /// Requirement identifiers of the source shape.
+    requirements: Vec<String>,
+    /// Turtle snippet describing the focus node.
+    context: Option<String>,
+    /// Remediation guidance from the shapes graph.
+    help_text: Option<String>,
+    /// Page documenting the shape or its constraint component.
+    documentation_url: Option<String>,
+    /// Shape whose focus node count the result falls under, when it is not
+    /// the source shape, e.g. the node shape of a property shape result.
+    counted_shape: Option<NamedOrBlankNodeRef<'a>>,
 }
 
 impl<'a> Default for ValidationReport<'a> {
@@ -53,6 +366,159 @@ impl<'a> ValidationReport<'a> {
         Self {
             conforms: true,
             results: Vec::new(),
+            provenance: ReportProvenance::default(),
+            unsupported_features: Vec::new(),
+            failures: Vec::new(),
+            shapes_graph_well_formed: None,
+            well_formedness_issues: Vec::new(),
+            focus_node_counts: HashMap::new(),
+            cancelled: false,
+            constraint_costs: HashMap::new(),
+        }
+    }
+
+    /// Attaches shapes provenance to the report.
+    pub fn with_provenance(mut self, provenance: ReportProvenance) -> Self {
+        self.provenance = provenance;
+        self
+    }
+
+    pub fn get_provenance(&self) -> &ReportProvenance {
+        &self.provenance
+    }
+
+    /// Records skipped shape features, keeping the list sorted and free of duplicates.
+    pub fn add_unsupported_features(&mut self, features: Vec<UnsupportedFeature>) {
+        self.unsupported_features.extend(features);
+        self.unsupported_features.sort();
+        self.unsupported_features.dedup();
+    }
+
+    pub fn get_unsupported_features(&self) -> &[UnsupportedFeature] {
+        &self.unsupported_features
+    }
+
+    /// Records a focus node that could not be validated. The report no longer conforms.
+    pub fn add_failure(&mut self, failure: ValidationFailure) {
+        self.conforms = false;
+        self.failures.push(failure);
+    }
+
+    pub fn get_failures(&self) -> &[ValidationFailure] {
+        &self.failures
+    }
+
+    /// Records the outcome of the shapes graph syntax checks.
+    pub fn with_well_formedness_issues(mut self, issues: Vec<WellFormednessIssue>) -> Self {
+        self.shapes_graph_well_formed = Some(issues.is_empty());
+        self.well_formedness_issues = issues;
+        self
+    }
+
+    /// `sh:shapesGraphWellFormed`, or `None` if the shapes graph was not checked.
+    pub fn get_shapes_graph_well_formed(&self) -> Option<bool> {
+        self.shapes_graph_well_formed
+    }
+
+    /// Marks the report as cut short by a cancellation. Its results are
+    /// incomplete, so it no longer conforms.
+    pub fn mark_cancelled(&mut self) {
+        self.cancelled = true;
+        self.conforms = false;
+    }
+
+    /// Whether the run was cancelled, leaving focus nodes unchecked.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    pub fn get_well_formedness_issues(&self) -> &[WellFormednessIssue] {
+        &self.well_formedness_issues
+    }
+
+    /// Records that `shape` was checked on `count` more focus nodes.
+    pub fn add_focus_node_count(&mut self, shape: NamedOrBlankNodeRef<'a>, count: usize) {
+        *self.focus_node_counts.entry(shape).or_insert(0) += count;
+    }
+
+    pub fn get_focus_node_counts(&self) -> &HashMap<NamedOrBlankNodeRef<'a>, usize> {
+        &self.focus_node_counts
+    }
+
+    /// Records one evaluation of `component` of `shape` that took `time`.
+    pub fn add_constraint_cost(
+        &mut self,
+        shape: NamedOrBlankNodeRef<'a>,
+        component: ConstraintComponent<'a>,
+        time: Duration,
+    ) {
+        self.constraint_costs
+            .entry((shape, component))
+            .or_default()
+            .add(ConstraintCost {
+                evaluations: 1,
+                time,
+            });
+    }
+
+    /// Time spent per shape and constraint component; empty unless the run
+    /// was profiled.
+    pub fn profile(&self) -> ConstraintProfile<'a> {
+        ConstraintProfile::new(
+            self.constraint_costs
+                .iter()
+                .map(|(&(shape, component), &cost)| ProfileEntry {
+                    shape,
+                    component,
+                    cost,
+                })
+                .collect(),
+        )
+    }
+
+    /// Data quality score from 0 (every checked focus node failed) to 100.
+    ///
+    /// Every focus node a shape was checked on counts with the shape's weight.
+    /// Each focus node and checked shape with results, those of its property
+    /// shapes included, loses the largest severity weight of those results,
+    /// times the shape's weight. Returns `None` when the report has no focus
+    /// node counts, e.g. when stored by an older version.
+    pub fn quality_score(&self, weights: &QualityWeights) -> Option<f64> {
+        if self.focus_node_counts.is_empty() {
+            return None;
+        }
+        let checked: f64 = self
+            .focus_node_counts
+            .iter()
+            .map(|(&shape, &count)| weights.shape(shape) * count as f64)
+            .sum();
+        if checked <= 0.0 {
+            return Some(100.0);
+        }
+
+        let mut losses: HashMap<(TermRef<'a>, NamedOrBlankNodeRef<'a>), f64> = HashMap::new();
+        for result in &self.results {
+            let shape = result.get_counted_shape();
+            let loss = weights.severity(result.severity) * weights.shape(shape);
+            let entry = losses.entry((result.focus_node, shape)).or_insert(0.0);
+            *entry = entry.max(loss);
+        }
+        let lost: f64 = losses.values().sum();
+
+        Some((100.0 * (1.0 - lost / checked)).clamp(0.0, 100.0))
+    }
+
+    /// Counts every result under the focus nodes checked by `shape`.
+    pub(crate) fn set_counted_shape(&mut self, shape: NamedOrBlankNodeRef<'a>) {
+        for result in &mut self.results {
+            result.counted_shape = (result.source_shape != shape).then_some(shape);
+        }
+    }
+
+    /// Applies `limits` to every result.
+    pub fn apply_limits(&mut self, limits: &ReportLimits) {
+        for result in &mut self.results {
+            result.apply_limits(limits, 0);
         }
     }
 
@@ -64,24 +530,248 @@ impl<'a> ValidationReport<'a> {
         &self.results
     }
 
+    /// Attaches a Turtle description of each focus node, `depth` hops deep.
+    pub fn attach_context(&mut self, data_graph: &Graph, depth: usize) -> Result<(), ShaclError> {
+        let mut snippets: HashMap<TermRef<'a>, Option<String>> = HashMap::new();
+        for result in &mut self.results {
+            result.context = match snippets.entry(result.focus_node) {
+                Entry::Occupied(entry) => entry.get().clone(),
+                Entry::Vacant(entry) => {
+                    let description = utils::term_to_named_or_blank(result.focus_node)
+                        .map(|node| rdf::describe_node(data_graph, node, depth))
+                        .filter(|description| !description.is_empty());
+                    let snippet = description
+                        .map(|d| rdf::serialize_graph_to_string(&d, RdfFormat::Turtle))
+                        .transpose()?;
+                    entry.insert(snippet).clone()
+                }
+            };
+        }
+        Ok(())
+    }
+
     /// Returns the number of results.
     pub fn violation_count(&self) -> usize {
         self.results.len()
     }
 
     /// Returns results filtered by severity.
-    pub fn violations_by_severity(&self, severity: NamedNodeRef<'a>) -> Vec<&ValidationResult<'a>> {
+    pub fn violations_by_severity(&self, severity: Severity<'_>) -> Vec<&ValidationResult<'a>> {
         self.results
             .iter()
             .filter(|r| r.severity == severity)
             .collect()
     }
 
+    /// Returns results grouped by requirement, with unannotated results under `None`.
+    pub fn results_by_requirement(&self) -> BTreeMap<Option<&str>, Vec<&ValidationResult<'a>>> {
+        let mut groups: BTreeMap<Option<&str>, Vec<&ValidationResult<'a>>> = BTreeMap::new();
+        for result in &self.results {
+            if result.requirements.is_empty() {
+                groups.entry(None).or_default().push(result);
+            }
+            for requirement in &result.requirements {
+                groups
+                    .entry(Some(requirement.as_str()))
+                    .or_default()
+                    .push(result);
+            }
+        }
+        groups
+    }
+
+    /// Counts results per key, e.g. per source shape or severity.
+    pub fn count_by(
+        &self,
+        key: impl Fn(&ValidationResult<'a>) -> String,
+    ) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for result in &self.results {
+            *counts.entry(key(result)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Copy of the report with only the results `keep` accepts.
+    pub fn filter_results(&self, keep: impl Fn(&ValidationResult<'a>) -> bool) -> Self {
+        let mut report = self.clone();
+        report.retain_results(keep);
+        report
+    }
+
+    /// Drops all results after the first `len`.
+    pub(crate) fn truncate_results(&mut self, len: usize) {
+        self.results.truncate(len);
+    }
+
+    /// Keeps only the results `keep` accepts.
+    pub(crate) fn retain_results(&mut self, keep: impl FnMut(&ValidationResult<'a>) -> bool) {
+        self.results.retain(keep);
+        self.conforms = self.results.is_empty() && self.failures.is_empty() && !self.cancelled;
+    }
+
+    /// Compares the results with those of `baseline` by their
+    /// [fingerprints](ValidationResult::fingerprint).
+    ///
+    /// Fingerprints are counted, so results that only differ in blank nodes
+    /// are added or resolved when their number changes.
+    pub fn diff<'r>(&'r self, baseline: &'r ValidationReport<'_>) -> ReportDiff<'r> {
+        fn unmatched<'r>(
+            results: &'r [ValidationResult<'r>],
+            other: &[ValidationResult<'_>],
+        ) -> Vec<&'r ValidationResult<'r>> {
+            let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+            for result in other {
+                *counts.entry(result.fingerprint()).or_default() += 1;
+            }
+            results
+                .iter()
+                .filter(|result| match counts.get_mut(&result.fingerprint()) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    }
+                    _ => true,
+                })
+                .collect()
+        }
+
+        ReportDiff {
+            added: unmatched(&self.results, &baseline.results),
+            resolved: unmatched(&baseline.results, &self.results),
+        }
+    }
+
+    /// Groups the report by the requirements declared in `shapes`.
+    pub fn by_requirement<'r>(
+        &'r self,
+        shapes: &'r [Arc<Shape<'_>>],
+    ) -> RequirementsReport<'r, 'a> {
+        let requirements = shapes
+            .iter()
+            .flat_map(|shape| std::iter::once(shape.as_ref()).chain(shape.all_nested_shapes()))
+            .flat_map(|shape| shape.requirements.iter().map(String::as_str))
+            .chain(
+                self.results
+                    .iter()
+                    .flat_map(|r| r.requirements.iter().map(String::as_str)),
+            )
+            .collect();
+
+        RequirementsReport {
+            report: self,
+            requirements,
+        }
+    }
+
+    /// Keeps up to `per_group` results per shape and constraint component,
+    /// preferring results whose messages differ in more than their nodes.
+    /// Counts stay those of the full report.
+    pub fn sample<'r>(&'r self, per_group: usize) -> SampledReport<'r, 'a> {
+        let mut groups: BTreeMap<(String, String), Vec<&'r ValidationResult<'a>>> = BTreeMap::new();
+        for result in &self.results {
+            let component = result
+                .source_constraint_component
+                .map(|component| component.to_string());
+            groups
+                .entry((
+                    result.source_shape.to_string(),
+                    component.unwrap_or_default(),
+                ))
+                .or_default()
+                .push(result);
+        }
+
+        let groups = groups
+            .into_values()
+            .map(|results| {
+                let (shape, component) = (
+                    results[0].source_shape,
+                    results[0].source_constraint_component,
+                );
+                let count = results.len();
+                let mut patterns = HashSet::new();
+                let (distinct, repeated): (Vec<_>, Vec<_>) = results
+                    .into_iter()
+                    .partition(|result| patterns.insert(result.message_pattern()));
+                SampleGroup {
+                    shape,
+                    component,
+                    count,
+                    samples: distinct
+                        .into_iter()
+                        .chain(repeated)
+                        .take(per_group)
+                        .collect(),
+                }
+            })
+            .collect();
+
+        SampledReport {
+            report: self,
+            groups,
+        }
+    }
+
+    /// The results `filter` accepts from the `offset`-th one on, at most `limit`
+    /// of them, with the number of accepted results and of all results.
+    ///
+    /// ```
+    /// use shacl_rust::validation::report::ValidationReport;
+    ///
+    /// let report = ValidationReport::new();
+    /// let page = report.page(0, 10, |_| true);
+    /// assert!(page.results().is_empty());
+    /// assert_eq!(page.next_offset(), None);
+    /// ```
+    pub fn page<'r>(
+        &'r self,
+        offset: usize,
+        limit: usize,
+        filter: impl Fn(&ValidationResult<'a>) -> bool,
+    ) -> ReportPage<'r, 'a> {
+        let mut matched = 0;
+        let mut results = Vec::new();
+        for result in self.results.iter().filter(|result| filter(result)) {
+            if matched >= offset && results.len() < limit {
+                results.push(result);
+            }
+            matched += 1;
+        }
+        ReportPage {
+            results,
+            offset,
+            matched,
+            total: self.results.len(),
+            conforms: self.conforms,
+            failures: &self.failures,
+            cancelled: self.cancelled,
+        }
+    }
+
     pub fn merge(&mut self, other: ValidationReport<'a>) {
         if !other.conforms {
             self.conforms = false;
         }
         self.results.extend(other.results);
+        self.add_unsupported_features(other.unsupported_features);
+        self.failures.extend(other.failures);
+        self.shapes_graph_well_formed = match (
+            self.shapes_graph_well_formed,
+            other.shapes_graph_well_formed,
+        ) {
+            (Some(a), Some(b)) => Some(a && b),
+            (a, b) => a.or(b),
+        };
+        self.well_formedness_issues
+            .extend(other.well_formedness_issues);
+        self.cancelled |= other.cancelled;
+        for (shape, count) in other.focus_node_counts {
+            self.add_focus_node_count(shape, count);
+        }
+        for (key, cost) in other.constraint_costs {
+            self.constraint_costs.entry(key).or_default().add(cost);
+        }
     }
 
     pub fn add_result(&mut self, result: ValidationResult<'a>) {
@@ -96,12 +786,87 @@ impl<'a> ValidationReport<'a> {
         }
     }
 
+    /// Reads a report back from its RDF form, e.g. the output of [`Self::to_graph`].
+    ///
+    /// Results may be linked from the report with `sh:result` or `sh:detail`.
+    pub fn from_graph(graph: &'a Graph) -> Result<Self, ShaclError> {
+        let report_node = graph
+            .subjects_for_predicate_object(oxigraph::model::vocab::rdf::TYPE, sh::VALIDATION_REPORT)
+            .next()
+            .ok_or_else(|| ShaclError::Parse("No sh:ValidationReport found".to_string()))?;
+
+        let boolean = |predicate| match graph.object_for_subject_predicate(report_node, predicate) {
+            Some(TermRef::Literal(literal)) => Some(literal.value() == "true"),
+            _ => None,
+        };
+        let string = |predicate| match graph.object_for_subject_predicate(report_node, predicate) {
+            Some(TermRef::Literal(literal)) => Some(literal.value().to_string()),
+            _ => None,
+        };
+
+        let mut validator_features: Vec<String> = graph
+            .objects_for_subject_predicate(report_node, shx::VALIDATOR_FEATURE)
+            .filter_map(|feature| match feature {
+                TermRef::Literal(literal) => Some(literal.value().to_string()),
+                _ => None,
+            })
+            .collect();
+        validator_features.sort();
+        let mut report = ValidationReport::new().with_provenance(ReportProvenance {
+            shapes_version: string(shx::SHAPES_VERSION),
+            shapes_source: string(shx::SHAPES_SOURCE),
+            shapes_hash: string(shx::SHAPES_HASH),
+            validator_version: string(shx::VALIDATOR_VERSION),
+            validator_features,
+        });
+        report.shapes_graph_well_formed = boolean(sh::SHAPES_GRAPH_WELL_FORMED);
+        report.cancelled = boolean(shx::CANCELLED) == Some(true);
+
+        for coverage in graph.objects_for_subject_predicate(report_node, shx::COVERAGE) {
+            let Some(coverage) = utils::term_to_named_or_blank(coverage) else {
+                continue;
+            };
+            let shape = graph
+                .object_for_subject_predicate(coverage, shx::SHAPE)
+                .and_then(utils::term_to_named_or_blank);
+            let count = match graph.object_for_subject_predicate(coverage, shx::FOCUS_NODE_COUNT) {
+                Some(TermRef::Literal(literal)) => literal.value().parse().ok(),
+                _ => None,
+            };
+            match (shape, count) {
+                (Some(shape), Some(count)) => report.add_focus_node_count(shape, count),
+                _ => {
+                    return Err(ShaclError::Parse(format!(
+                        "Invalid shx:coverage {} of the report",
+                        coverage
+                    )))
+                }
+            }
+        }
+
+        for predicate in [sh::RESULT, sh::DETAIL] {
+            for object in graph.objects_for_subject_predicate(report_node, predicate) {
+                if let Some(node) = utils::term_to_named_or_blank(object) {
+                    report.add_result(ValidationResult::from_graph(graph, node)?);
+                }
+            }
+        }
+        report.conforms = boolean(sh::CONFORMS).unwrap_or(report.results.is_empty());
+
+        Ok(report)
+    }
+
     /// Converts the report to an RDF graph.
     pub fn to_graph(&self) -> Graph {
+        self.to_graph_with(&ReportNodeIds::Fresh)
+    }
+
+    /// Converts the report to an RDF graph, naming its nodes as `ids` says.
+    pub fn to_graph_with(&self, ids: &ReportNodeIds) -> Graph {
         let mut graph = Graph::new();
+        let mut ids = NodeMinter { ids, next: 0 };
 
-        let report_node = BlankNode::default();
-        let report_subject = NamedOrBlankNode::from(report_node);
+        let report_subject = ids.mint();
         graph.insert(&Triple::new(
             report_subject.clone(),
             NamedNode::from(oxigraph::model::vocab::rdf::TYPE),
@@ -114,8 +879,85 @@ impl<'a> ValidationReport<'a> {
             Term::from(Literal::from(self.conforms)),
         ));
 
+        if let Some(well_formed) = self.shapes_graph_well_formed {
+            graph.insert(&Triple::new(
+                report_subject.clone(),
+                NamedNode::from(sh::SHAPES_GRAPH_WELL_FORMED),
+                Term::from(Literal::from(well_formed)),
+            ));
+        }
+
+        if self.cancelled {
+            graph.insert(&Triple::new(
+                report_subject.clone(),
+                NamedNode::from(shx::CANCELLED),
+                Term::from(Literal::from(true)),
+            ));
+        }
+
+        if let Some(version) = &self.provenance.shapes_version {
+            graph.insert(&Triple::new(
+                report_subject.clone(),
+                NamedNode::from(shx::SHAPES_VERSION),
+                Term::from(Literal::from(version.clone())),
+            ));
+        }
+
+        if let Some(source) = &self.provenance.shapes_source {
+            graph.insert(&Triple::new(
+                report_subject.clone(),
+                NamedNode::from(shx::SHAPES_SOURCE),
+                Term::from(Literal::from(source.clone())),
+            ));
+        }
+
+        if let Some(hash) = &self.provenance.shapes_hash {
+            graph.insert(&Triple::new(
+                report_subject.clone(),
+                NamedNode::from(shx::SHAPES_HASH),
+                Term::from(Literal::from(hash.clone())),
+            ));
+        }
+
+        if let Some(version) = &self.provenance.validator_version {
+            graph.insert(&Triple::new(
+                report_subject.clone(),
+                NamedNode::from(shx::VALIDATOR_VERSION),
+                Term::from(Literal::from(version.clone())),
+            ));
+        }
+
+        for feature in &self.provenance.validator_features {
+            graph.insert(&Triple::new(
+                report_subject.clone(),
+                NamedNode::from(shx::VALIDATOR_FEATURE),
+                Term::from(Literal::from(feature.clone())),
+            ));
+        }
+
+        let mut focus_node_counts: Vec<_> = self.focus_node_counts.iter().collect();
+        focus_node_counts.sort_by_cached_key(|(shape, _)| shape.to_string());
+        for (&shape, &count) in focus_node_counts {
+            let coverage = ids.mint();
+            graph.insert(&Triple::new(
+                report_subject.clone(),
+                NamedNode::from(shx::COVERAGE),
+                Term::from(coverage.clone()),
+            ));
+            graph.insert(&Triple::new(
+                coverage.clone(),
+                NamedNode::from(shx::SHAPE),
+                Term::from(shape),
+            ));
+            graph.insert(&Triple::new(
+                coverage,
+                NamedNode::from(shx::FOCUS_NODE_COUNT),
+                Term::from(Literal::from(count as i64)),
+            ));
+        }
+
         for result in &self.results {
-            let result_subject = Self::add_validation_result_to_graph(&mut graph, result);
+            let result_subject = Self::add_validation_result_to_graph(&mut graph, &mut ids, result);
             graph.insert(&Triple::new(
                 report_subject.clone(),
                 NamedNode::from(sh::DETAIL),
@@ -129,10 +971,10 @@ impl<'a> ValidationReport<'a> {
     /// Adds one result to the graph and returns its subject node.
     fn add_validation_result_to_graph(
         graph: &mut Graph,
+        ids: &mut NodeMinter<'_>,
         result: &ValidationResult<'a>,
     ) -> NamedOrBlankNode {
-        let result_node = BlankNode::default();
-        let result_subject = NamedOrBlankNode::from(result_node);
+        let result_subject = ids.mint();
 
         graph.insert(&Triple::new(
             result_subject.clone(),
@@ -149,7 +991,7 @@ impl<'a> ValidationReport<'a> {
         graph.insert(&Triple::new(
             result_subject.clone(),
             NamedNode::from(sh::RESULT_SEVERITY),
-            Term::from(NamedNode::from(result.severity)),
+            Term::from(NamedNode::from(result.severity.iri())),
         ));
 
         graph.insert(&Triple::new(
@@ -162,11 +1004,11 @@ impl<'a> ValidationReport<'a> {
             graph.insert(&Triple::new(
                 result_subject.clone(),
                 NamedNode::from(sh::SOURCE_CONSTRAINT_COMPONENT),
-                Term::from(NamedNode::from(component)),
+                Term::from(NamedNode::from(component.iri())),
             ));
         }
 
-        if let Some(value) = result.value {
+        if let Some(value) = result.get_reported_value() {
             graph.insert(&Triple::new(
                 result_subject.clone(),
                 NamedNode::from(sh::VALUE),
@@ -175,13 +1017,12 @@ impl<'a> ValidationReport<'a> {
         }
 
         if let Some(ref path) = result.result_path {
-            if let Some(crate::core::path::PathElement::Iri(iri)) = path.get_elements().first() {
-                graph.insert(&Triple::new(
-                    result_subject.clone(),
-                    NamedNode::from(sh::RESULT_PATH),
-                    Term::from(NamedNode::from(*iri)),
-                ));
-            }
+            let path = path.to_graph_term(graph);
+            graph.insert(&Triple::new(
+                result_subject.clone(),
+                NamedNode::from(sh::RESULT_PATH),
+                path,
+            ));
         }
 
         for message in &result.messages {
@@ -192,9 +1033,45 @@ impl<'a> ValidationReport<'a> {
             ));
         }
 
-        if !result.trace.is_empty() {
-            for trace_entry in &result.trace {
-                graph.insert(&Triple::new(
+        for requirement in &result.requirements {
+            graph.insert(&Triple::new(
+                result_subject.clone(),
+                NamedNode::from(shx::REQUIREMENT),
+                Term::from(Literal::from(requirement.clone())),
+            ));
+        }
+
+        if let Some(shape) = result.counted_shape {
+            graph.insert(&Triple::new(
+                result_subject.clone(),
+                NamedNode::from(shx::COUNTED_SHAPE),
+                Term::from(shape),
+            ));
+        }
+
+        if let Some(help_text) = &result.help_text {
+            graph.insert(&Triple::new(
+                result_subject.clone(),
+                NamedNode::from(rdfs::COMMENT),
+                Term::from(Literal::from(help_text.clone())),
+            ));
+        }
+
+        if let Some(url) = result
+            .documentation_url
+            .as_ref()
+            .and_then(|url| NamedNode::new(url).ok())
+        {
+            graph.insert(&Triple::new(
+                result_subject.clone(),
+                NamedNode::from(rdfs::SEE_ALSO),
+                Term::from(url),
+            ));
+        }
+
+        if !result.trace.is_empty() {
+            for trace_entry in &result.trace {
+                graph.insert(&Triple::new(
                     result_subject.clone(),
                     NamedNode::from(sh::DETAIL),
                     Term::from(Literal::from(trace_entry.clone())),
@@ -204,7 +1081,7 @@ impl<'a> ValidationReport<'a> {
 
         if !result.details.is_empty() {
             for detail in &result.details {
-                let detail_subject = Self::add_validation_result_to_graph(graph, detail);
+                let detail_subject = Self::add_validation_result_to_graph(graph, ids, detail);
                 graph.insert(&Triple::new(
                     result_subject.clone(),
                     NamedNode::from(sh::DETAIL),
@@ -217,10 +1094,52 @@ impl<'a> ValidationReport<'a> {
     }
 
     pub fn as_json(&self) -> serde_json::Value {
-        serde_json::json!({
+        let mut report_obj = serde_json::json!({
             "conforms": self.conforms,
             "results": self.results.iter().map(|r| r.as_json()).collect::<Vec<_>>(),
-        })
+        });
+
+        if let Some(well_formed) = self.shapes_graph_well_formed {
+            report_obj["shapesGraphWellFormed"] = serde_json::json!(well_formed);
+        }
+        if self.cancelled {
+            report_obj["cancelled"] = serde_json::json!(true);
+        }
+        if !self.well_formedness_issues.is_empty() {
+            report_obj["wellFormednessIssues"] = serde_json::json!(self
+                .well_formedness_issues
+                .iter()
+                .map(|issue| serde_json::json!({ "node": issue.node, "message": issue.message }))
+                .collect::<Vec<_>>());
+        }
+
+        if !self.unsupported_features.is_empty() {
+            report_obj["unsupportedFeatures"] = serde_json::json!(self
+                .unsupported_features
+                .iter()
+                .map(UnsupportedFeature::as_json)
+                .collect::<Vec<_>>());
+        }
+        if !self.failures.is_empty() {
+            report_obj["failures"] = serde_json::json!(self
+                .failures
+                .iter()
+                .map(ValidationFailure::as_json)
+                .collect::<Vec<_>>());
+        }
+
+        if !self.provenance.is_empty() {
+            report_obj["provenance"] = self.provenance.as_json();
+        }
+        if !self.focus_node_counts.is_empty() {
+            report_obj["focusNodeCounts"] = serde_json::json!(self
+                .focus_node_counts
+                .iter()
+                .map(|(shape, count)| (shape.to_string(), *count))
+                .collect::<BTreeMap<_, _>>());
+        }
+
+        report_obj
     }
 }
 
@@ -228,7 +1147,7 @@ impl<'a> ValidationResult<'a> {
     pub fn new(
         focus_node: TermRef<'a>,
         source_shape: NamedOrBlankNodeRef<'a>,
-        severity: NamedNodeRef<'a>,
+        severity: Severity<'a>,
     ) -> Self {
         Self {
             focus_node,
@@ -239,9 +1158,15 @@ impl<'a> ValidationResult<'a> {
             severity,
             result_path: None,
             value: None,
+            elided_value: None,
             messages: Vec::new(),
             trace: Vec::new(),
             details: Vec::new(),
+            requirements: Vec::new(),
+            context: None,
+            help_text: None,
+            documentation_url: None,
+            counted_shape: None,
         }
     }
 
@@ -250,7 +1175,10 @@ impl<'a> ValidationResult<'a> {
         self
     }
 
-    pub fn with_source_constraint_component(mut self, component: Option<NamedNodeRef<'a>>) -> Self {
+    pub fn with_source_constraint_component(
+        mut self,
+        component: Option<ConstraintComponent<'a>>,
+    ) -> Self {
         self.source_constraint_component = component;
         self
     }
@@ -285,11 +1213,128 @@ impl<'a> ValidationResult<'a> {
         self
     }
 
+    pub fn with_requirements(mut self, requirements: Vec<String>) -> Self {
+        self.requirements = requirements;
+        self
+    }
+
+    pub fn get_focus_node(&self) -> TermRef<'a> {
+        self.focus_node
+    }
+
+    pub fn get_source_shape(&self) -> NamedOrBlankNodeRef<'a> {
+        self.source_shape
+    }
+
+    pub fn get_source_shape_name(&self) -> Option<&str> {
+        self.source_shape_name.as_deref()
+    }
+
+    pub fn get_source_constraint_component(&self) -> Option<ConstraintComponent<'a>> {
+        self.source_constraint_component
+    }
+
+    pub fn get_severity(&self) -> Severity<'a> {
+        self.severity
+    }
+
+    pub fn get_result_path(&self) -> Option<&Path<'a>> {
+        self.result_path.as_ref()
+    }
+
+    pub fn get_value(&self) -> Option<TermRef<'a>> {
+        self.value
+    }
+
+    /// The value as written to reports, shortened if it was elided.
+    pub fn get_reported_value(&self) -> Option<TermRef<'_>> {
+        match &self.elided_value {
+            Some(elided) => Some(elided.into()),
+            None => self.value,
+        }
+    }
+
+    /// Cuts long messages and values and drops details nested deeper than the
+    /// limits allow; `depth` is the nesting level of this result.
+    fn apply_limits(&mut self, limits: &ReportLimits, depth: usize) {
+        if let Some(max) = limits.max_message_length {
+            for message in &mut self.messages {
+                if message.chars().count() > max {
+                    *message = message.chars().take(max.saturating_sub(1)).collect();
+                    message.push('…');
+                }
+            }
+        }
+
+        if let (Some(max), Some(TermRef::Literal(literal)), None) =
+            (limits.max_value_length, self.value, &self.elided_value)
+        {
+            let length = literal.value().chars().count();
+            if length > max {
+                let prefix: String = literal.value().chars().take(max).collect();
+                let digest = Sha256::digest(literal.value().as_bytes());
+                let hash: String = digest[..8].iter().map(|b| format!("{:02x}", b)).collect();
+                self.elided_value = Some(Literal::new_simple_literal(format!(
+                    "{}… [{} characters, sha256 {}]",
+                    prefix, length, hash
+                )));
+            }
+        }
+
+        if limits.max_details_depth.is_some_and(|max| depth >= max) {
+            self.details.clear();
+        }
+        for detail in &mut self.details {
+            detail.apply_limits(limits, depth + 1);
+        }
+    }
+
+    pub fn get_messages(&self) -> &[String] {
+        &self.messages
+    }
+
+    pub fn get_requirements(&self) -> &[String] {
+        &self.requirements
+    }
+
+    pub fn with_context(mut self, context: Option<String>) -> Self {
+        self.context = context;
+        self
+    }
+
+    pub fn get_context(&self) -> Option<&str> {
+        self.context.as_deref()
+    }
+
+    pub fn with_help_text(mut self, help_text: Option<String>) -> Self {
+        self.help_text = help_text;
+        self
+    }
+
+    /// Shape whose checked focus nodes the result counts against.
+    pub fn get_counted_shape(&self) -> NamedOrBlankNodeRef<'a> {
+        self.counted_shape.unwrap_or(self.source_shape)
+    }
+
+    pub fn get_help_text(&self) -> Option<&str> {
+        self.help_text.as_deref()
+    }
+
+    pub fn with_documentation_url(mut self, documentation_url: Option<String>) -> Self {
+        self.documentation_url = documentation_url;
+        self
+    }
+
+    pub fn get_documentation_url(&self) -> Option<&str> {
+        self.documentation_url.as_deref()
+    }
+
     pub fn as_json(&self) -> serde_json::Value {
         let mut result_obj = serde_json::json!({
             "focusNode": self.focus_node.to_string(),
             "sourceShape": self.source_shape.to_string(),
             "severity": self.severity.to_string(),
+            "fingerprint": self.fingerprint(),
         });
 
         if let Some(ref source_constraint_component) = self.source_constraint_component {
@@ -300,7 +1345,7 @@ impl<'a> ValidationResult<'a> {
         if let Some(ref path) = self.result_path {
             result_obj["resultPath"] = serde_json::json!(path.to_string());
         }
-        if let Some(value) = self.value {
+        if let Some(value) = self.get_reported_value() {
             result_obj["value"] = serde_json::json!(value.to_string());
         }
         if !self.messages.is_empty() {
@@ -313,9 +1358,154 @@ impl<'a> ValidationResult<'a> {
             result_obj["details"] =
                 serde_json::json!(self.details.iter().map(|d| d.as_json()).collect::<Vec<_>>());
         }
+        if !self.requirements.is_empty() {
+            result_obj["requirements"] = serde_json::json!(self.requirements);
+        }
+        if let Some(context) = &self.context {
+            result_obj["context"] = serde_json::json!(context);
+        }
+        if let Some(shape) = self.counted_shape {
+            result_obj["countedShape"] = serde_json::json!(shape.to_string());
+        }
+        if let Some(help_text) = &self.help_text {
+            result_obj["helpText"] = serde_json::json!(help_text);
+        }
+        if let Some(documentation_url) = &self.documentation_url {
+            result_obj["documentationUrl"] = serde_json::json!(documentation_url);
+        }
         result_obj
     }
 
+    /// Reads the result described by `node`, with its nested results.
+    fn from_graph(graph: &'a Graph, node: NamedOrBlankNodeRef<'a>) -> Result<Self, ShaclError> {
+        let required = |predicate: NamedNodeRef<'_>| {
+            graph
+                .object_for_subject_predicate(node, predicate)
+                .ok_or_else(|| {
+                    ShaclError::Parse(format!("Validation result {} has no {}", node, predicate))
+                })
+        };
+
+        let source_shape = utils::term_to_named_or_blank(required(sh::SOURCE_SHAPE)?)
+            .ok_or_else(|| ShaclError::Parse(format!("Invalid sh:sourceShape on {}", node)))?;
+        let TermRef::NamedNode(severity) = required(sh::RESULT_SEVERITY)? else {
+            return Err(ShaclError::Parse(format!(
+                "Invalid sh:resultSeverity on {}",
+                node
+            )));
+        };
+
+        let mut result = ValidationResult::new(
+            required(sh::FOCUS_NODE)?,
+            source_shape,
+            Severity::from_iri(severity),
+        )
+        .with_value(graph.object_for_subject_predicate(node, sh::VALUE))
+        .with_result_path(
+            graph
+                .object_for_subject_predicate(node, sh::RESULT_PATH)
+                .map(|path| crate::parser::path::parse_path(graph, path))
+                .transpose()?,
+        );
+        if let Some(TermRef::NamedNode(component)) =
+            graph.object_for_subject_predicate(node, sh::SOURCE_CONSTRAINT_COMPONENT)
+        {
+            result.source_constraint_component = Some(ConstraintComponent::from_iri(component));
+        }
+        result.counted_shape = graph
+            .object_for_subject_predicate(node, shx::COUNTED_SHAPE)
+            .and_then(utils::term_to_named_or_blank);
+
+        let literals = |predicate| {
+            graph
+                .objects_for_subject_predicate(node, predicate)
+                .filter_map(|object| match object {
+                    TermRef::Literal(literal) => Some(literal.value().to_string()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        result.messages = literals(sh::RESULT_MESSAGE);
+        result.requirements = literals(shx::REQUIREMENT);
+        result.trace = literals(sh::DETAIL);
+        result.messages.sort();
+        result.requirements.sort();
+        result.help_text = literals(rdfs::COMMENT).into_iter().next();
+        if let Some(TermRef::NamedNode(url)) =
+            graph.object_for_subject_predicate(node, rdfs::SEE_ALSO)
+        {
+            result.documentation_url = Some(url.as_str().to_string());
+        }
+
+        for detail in graph.objects_for_subject_predicate(node, sh::DETAIL) {
+            if let Some(detail) = utils::term_to_named_or_blank(detail) {
+                result
+                    .details
+                    .push(ValidationResult::from_graph(graph, detail)?);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Messages with the focus node and value replaced by `{}`.
+    fn message_pattern(&self) -> Vec<String> {
+        let mut nodes = vec![self.focus_node.to_string()];
+        if let Some(value) = self.value {
+            nodes.push(value.to_string());
+            if let TermRef::Literal(literal) = value {
+                nodes.push(literal.value().to_string());
+            }
+        }
+        self.messages
+            .iter()
+            .map(|message| {
+                nodes
+                    .iter()
+                    .filter(|node| !node.is_empty())
+                    .fold(message.clone(), |message, node| {
+                        message.replace(node.as_str(), "{}")
+                    })
+            })
+            .collect()
+    }
+
+    /// Stable identity of the result, used to compare reports.
+    ///
+    /// A hash over the focus node, result path, constraint component, value and
+    /// source shape. Messages, severity, result order and value elision do not
+    /// change it, and since blank node labels change between runs, all blank
+    /// nodes hash alike.
+    pub fn fingerprint(&self) -> String {
+        let term = |term: String| {
+            if term.starts_with("_:") {
+                "[]".to_string()
+            } else {
+                term
+            }
+        };
+        let fields = [
+            term(self.focus_node.to_string()),
+            self.result_path
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            self.source_constraint_component
+                .map(|component| component.to_string())
+                .unwrap_or_default(),
+            self.value
+                .map(|value| term(value.to_string()))
+                .unwrap_or_default(),
+            term(self.source_shape.to_string()),
+        ];
+
+        let digest = Sha256::digest(fields.join("\n").as_bytes());
+        digest[..16]
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
     pub fn get_repr(&self) -> String {
         format!(
             "ValidationResult(focusNode: {}, sourceShape: {}, severity: {})",
@@ -324,21 +1514,310 @@ impl<'a> ValidationResult<'a> {
     }
 }
 
-impl<'a> Display for ValidationReport<'a> {
+/// A [`ValidationReport`] that owns its terms, so it can outlive the graphs it
+/// was validated against and be sent to other threads.
+///
+/// It implements serde's `Serialize` and `Deserialize`, with terms in their
+/// N-Triples form and field names as in [`ValidationReport::as_json`], so
+/// reports can be stored and loaded back.
+///
+/// ```
+/// use shacl_rust::{parse_shapes, validate, validation::dataset::ValidationDataset};
+/// use shacl_rust::rdf::read_graph_from_string;
+/// use shacl_rust::validation::report::OwnedValidationReport;
+///
+/// fn check(data: &str, shapes: &str) -> OwnedValidationReport {
+///     let dataset = ValidationDataset::from_graphs(
+///         read_graph_from_string(data, "turtle").unwrap(),
+///         read_graph_from_string(shapes, "turtle").unwrap(),
+///     )
+///     .unwrap();
+///     let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+///     validate(&dataset, &shapes).into_owned()
+/// }
+///
+/// let report = check(
+///     "<http://example.org/a> <http://example.org/p> 1 .",
+///     "<http://example.org/S> <http://www.w3.org/ns/shacl#targetNode> <http://example.org/a> ;
+///         <http://www.w3.org/ns/shacl#property> [
+///             <http://www.w3.org/ns/shacl#path> <http://example.org/p> ;
+///             <http://www.w3.org/ns/shacl#maxCount> 0
+///         ] .",
+/// );
+/// assert!(!report.conforms());
+/// assert_eq!(report.as_report().violation_count(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnedValidationReport {
+    conforms: bool,
+    #[serde(default)]
+    results: Vec<OwnedValidationResult>,
+    #[serde(default)]
+    provenance: ReportProvenance,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    unsupported_features: Vec<UnsupportedFeature>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    failures: Vec<ValidationFailure>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shapes_graph_well_formed: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    well_formedness_issues: Vec<WellFormednessIssue>,
+    #[serde(default, with = "utils::serde_rdf::keys")]
+    focus_node_counts: HashMap<NamedOrBlankNode, usize>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    cancelled: bool,
+}
+
+/// A [`ValidationResult`] that owns its terms.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnedValidationResult {
+    #[serde(with = "utils::serde_rdf")]
+    focus_node: Term,
+    #[serde(with = "utils::serde_rdf")]
+    source_shape: NamedOrBlankNode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source_shape_name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "utils::serde_rdf::option"
+    )]
+    source_constraint_component: Option<NamedNode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    constraint_detail: Option<String>,
+    #[serde(with = "utils::serde_rdf")]
+    severity: NamedNode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    result_path: Option<OwnedPath>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "utils::serde_rdf::option"
+    )]
+    value: Option<Term>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "utils::serde_rdf::option"
+    )]
+    elided_value: Option<Literal>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    messages: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    trace: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    details: Vec<OwnedValidationResult>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    requirements: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    context: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    help_text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    documentation_url: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "utils::serde_rdf::option"
+    )]
+    counted_shape: Option<NamedOrBlankNode>,
+}
+
+impl ValidationReport<'_> {
+    /// Copies the terms out of the graphs; see [`OwnedValidationReport`].
+    pub fn into_owned(self) -> OwnedValidationReport {
+        OwnedValidationReport {
+            conforms: self.conforms,
+            results: self
+                .results
+                .into_iter()
+                .map(ValidationResult::into_owned)
+                .collect(),
+            provenance: self.provenance,
+            unsupported_features: self.unsupported_features,
+            failures: self.failures,
+            shapes_graph_well_formed: self.shapes_graph_well_formed,
+            well_formedness_issues: self.well_formedness_issues,
+            focus_node_counts: self
+                .focus_node_counts
+                .into_iter()
+                .map(|(shape, count)| (shape.into_owned(), count))
+                .collect(),
+            cancelled: self.cancelled,
+        }
+    }
+}
+
+impl ValidationResult<'_> {
+    pub fn into_owned(self) -> OwnedValidationResult {
+        OwnedValidationResult {
+            focus_node: self.focus_node.into_owned(),
+            source_shape: self.source_shape.into_owned(),
+            source_shape_name: self.source_shape_name,
+            source_constraint_component: self
+                .source_constraint_component
+                .map(|component| component.iri().into_owned()),
+            constraint_detail: self.constraint_detail,
+            severity: self.severity.iri().into_owned(),
+            result_path: self.result_path.map(Path::into_owned),
+            value: self.value.map(TermRef::into_owned),
+            elided_value: self.elided_value,
+            messages: self.messages,
+            trace: self.trace,
+            details: self
+                .details
+                .into_iter()
+                .map(ValidationResult::into_owned)
+                .collect(),
+            requirements: self.requirements,
+            context: self.context,
+            help_text: self.help_text,
+            documentation_url: self.documentation_url,
+            counted_shape: self.counted_shape.map(NamedOrBlankNodeRef::into_owned),
+        }
+    }
+}
+
+impl OwnedValidationReport {
+    pub fn conforms(&self) -> bool {
+        self.conforms
+    }
+
+    pub fn results(&self) -> &[OwnedValidationResult] {
+        &self.results
+    }
+
+    /// Borrows the report back, e.g. to render or serialize it.
+    pub fn as_report(&self) -> ValidationReport<'_> {
+        ValidationReport {
+            conforms: self.conforms,
+            results: self
+                .results
+                .iter()
+                .map(OwnedValidationResult::as_result)
+                .collect(),
+            provenance: self.provenance.clone(),
+            unsupported_features: self.unsupported_features.clone(),
+            failures: self.failures.clone(),
+            shapes_graph_well_formed: self.shapes_graph_well_formed,
+            well_formedness_issues: self.well_formedness_issues.clone(),
+            focus_node_counts: self
+                .focus_node_counts
+                .iter()
+                .map(|(shape, &count)| (shape.as_ref(), count))
+                .collect(),
+            cancelled: self.cancelled,
+            constraint_costs: HashMap::new(),
+        }
+    }
+}
+
+impl OwnedValidationResult {
+    /// Borrows the result back.
+    pub fn as_result(&self) -> ValidationResult<'_> {
+        ValidationResult {
+            focus_node: self.focus_node.as_ref(),
+            source_shape: self.source_shape.as_ref(),
+            source_shape_name: self.source_shape_name.clone(),
+            source_constraint_component: self
+                .source_constraint_component
+                .as_ref()
+                .map(|component| ConstraintComponent::from_iri(component.as_ref())),
+            constraint_detail: self.constraint_detail.clone(),
+            severity: Severity::from_iri(self.severity.as_ref()),
+            result_path: self.result_path.as_ref().map(OwnedPath::as_path),
+            value: self.value.as_ref().map(Term::as_ref),
+            elided_value: self.elided_value.clone(),
+            messages: self.messages.clone(),
+            trace: self.trace.clone(),
+            details: self
+                .details
+                .iter()
+                .map(OwnedValidationResult::as_result)
+                .collect(),
+            requirements: self.requirements.clone(),
+            context: self.context.clone(),
+            help_text: self.help_text.clone(),
+            documentation_url: self.documentation_url.clone(),
+            counted_shape: self.counted_shape.as_ref().map(NamedOrBlankNode::as_ref),
+        }
+    }
+}
+
+impl Display for OwnedValidationReport {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.as_report().fmt(f)
+    }
+}
+
+impl<'a> ValidationReport<'a> {
+    /// Writes the title, provenance, shapes graph checks and result counts.
+    fn write_summary(&self, f: &mut Formatter<'_>, title: &str) -> std::fmt::Result {
         writeln!(f, "\n{}", "=".repeat(80))?;
-        writeln!(f, "SHACL Validation Report")?;
+        writeln!(f, "{}", title)?;
         writeln!(f, "{}", "=".repeat(80))?;
 
+        if let Some(version) = &self.provenance.shapes_version {
+            writeln!(f, "Shapes version: {}", version)?;
+        }
+        if let Some(source) = &self.provenance.shapes_source {
+            writeln!(f, "Shapes source: {}", source)?;
+        }
+        if let Some(hash) = &self.provenance.shapes_hash {
+            writeln!(f, "Shapes hash: {}", hash)?;
+        }
+        if let Some(validator) = self.provenance.validator() {
+            writeln!(f, "Validator: {}", validator)?;
+        }
+
+        if self.cancelled {
+            writeln!(f, "Cancelled: yes, results are incomplete")?;
+        }
+
+        match self.shapes_graph_well_formed {
+            Some(true) => writeln!(f, "Shapes graph well-formed: yes")?,
+            Some(false) => {
+                writeln!(
+                    f,
+                    "Shapes graph well-formed: no ({} issue(s))",
+                    self.well_formedness_issues.len()
+                )?;
+                for issue in &self.well_formedness_issues {
+                    writeln!(f, "  - {}", issue)?;
+                }
+            }
+            None => {}
+        }
+
+        if !self.unsupported_features.is_empty() {
+            writeln!(
+                f,
+                "Unsupported features (results may be incomplete): {}",
+                self.unsupported_features.len()
+            )?;
+            for feature in &self.unsupported_features {
+                writeln!(f, "  - {}", feature)?;
+            }
+        }
+
+        if !self.failures.is_empty() {
+            writeln!(f, "Failures: {}", self.failures.len())?;
+            for failure in &self.failures {
+                writeln!(f, "  - {}", failure)?;
+            }
+        }
+
         if self.conforms {
             write!(f, "\n✓ Data conforms to all shapes")?;
         } else {
             write!(f, "\n✗ Data does NOT conform to all shapes")?;
             write!(f, "\nViolations: {}", self.violation_count())?;
 
-            let violations_count = self.violations_by_severity(sh::VIOLATION).len();
-            let warnings_count = self.violations_by_severity(sh::WARNING).len();
-            let info_count = self.violations_by_severity(sh::INFO).len();
+            let violations_count = self.violations_by_severity(Severity::Violation).len();
+            let warnings_count = self.violations_by_severity(Severity::Warning).len();
+            let info_count = self.violations_by_severity(Severity::Info).len();
 
             if violations_count > 0 {
                 write!(f, "\n  - Violations: {}", violations_count)?;
@@ -349,7 +1828,16 @@ impl<'a> Display for ValidationReport<'a> {
             if info_count > 0 {
                 write!(f, "\n  - Info: {}", info_count)?;
             }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Display for ValidationReport<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write_summary(f, "SHACL Validation Report")?;
 
+        if !self.conforms {
             writeln!(f, "\n\n{}", "-".repeat(80))?;
             writeln!(f, "Validation Results:")?;
             writeln!(f, "{}", "-".repeat(80))?;
@@ -367,7 +1855,7 @@ impl<'a> Display for ValidationReport<'a> {
                     writeln!(f, "  Result Path: {}", path)?;
                 }
 
-                if let Some(value) = result.value {
+                if let Some(value) = result.get_reported_value() {
                     writeln!(f, "  Value: {}", value)?;
                 }
 
@@ -382,6 +1870,21 @@ impl<'a> Display for ValidationReport<'a> {
                     writeln!(f, "  Details:")?;
                     write_validation_result_details(f, &result.details, 4)?;
                 }
+
+                if let Some(help_text) = &result.help_text {
+                    writeln!(f, "  Help: {}", help_text)?;
+                }
+
+                if let Some(documentation_url) = &result.documentation_url {
+                    writeln!(f, "  Documentation: {}", documentation_url)?;
+                }
+
+                if let Some(context) = &result.context {
+                    writeln!(f, "  Context:")?;
+                    for line in context.lines() {
+                        writeln!(f, "    {}", line)?;
+                    }
+                }
             }
         }
 
@@ -389,6 +1892,301 @@ impl<'a> Display for ValidationReport<'a> {
     }
 }
 
+impl ReportDiff<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.resolved.is_empty()
+    }
+
+    pub fn as_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "added": self.added.iter().map(|r| r.as_json()).collect::<Vec<_>>(),
+            "resolved": self.resolved.iter().map(|r| r.as_json()).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl Display for ReportDiff<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} new result(s), {} resolved result(s)",
+            self.added.len(),
+            self.resolved.len()
+        )?;
+        for (sign, results) in [("+", &self.added), ("-", &self.resolved)] {
+            for result in results {
+                write!(f, "{} {} {}", sign, result.severity, result.focus_node)?;
+                if let Some(path) = &result.result_path {
+                    write!(f, " {}", path)?;
+                }
+                if let Some(component) = result.source_constraint_component {
+                    write!(f, " ({})", component)?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'r, 'a> RequirementsReport<'r, 'a> {
+    pub fn as_json(&self) -> serde_json::Value {
+        let groups = self.report.results_by_requirement();
+        let requirements: Vec<_> = self
+            .requirements
+            .iter()
+            .map(|requirement| {
+                let results = groups.get(&Some(*requirement)).cloned().unwrap_or_default();
+                serde_json::json!({
+                    "requirement": requirement,
+                    "conforms": results.is_empty(),
+                    "results": results.iter().map(|r| r.as_json()).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+
+        let mut report_obj = serde_json::json!({
+            "conforms": self.report.conforms,
+            "requirements": requirements,
+            "unassigned": groups
+                .get(&None)
+                .map(|results| results.iter().map(|r| r.as_json()).collect::<Vec<_>>())
+                .unwrap_or_default(),
+        });
+
+        if !self.report.provenance.is_empty() {
+            report_obj["provenance"] = self.report.provenance.as_json();
+        }
+
+        report_obj
+    }
+}
+
+impl<'r, 'a> Display for RequirementsReport<'r, 'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "\n{}", "=".repeat(80))?;
+        writeln!(f, "SHACL Validation Report by Requirement")?;
+        writeln!(f, "{}", "=".repeat(80))?;
+
+        if let Some(version) = &self.report.provenance.shapes_version {
+            writeln!(f, "Shapes version: {}", version)?;
+        }
+        if let Some(source) = &self.report.provenance.shapes_source {
+            writeln!(f, "Shapes source: {}", source)?;
+        }
+        if let Some(hash) = &self.report.provenance.shapes_hash {
+            writeln!(f, "Shapes hash: {}", hash)?;
+        }
+        if let Some(validator) = self.report.provenance.validator() {
+            writeln!(f, "Validator: {}", validator)?;
+        }
+        writeln!(f)?;
+
+        let groups = self.report.results_by_requirement();
+        for requirement in &self.requirements {
+            match groups.get(&Some(*requirement)) {
+                None => writeln!(f, "✓ {}", requirement)?,
+                Some(results) => {
+                    writeln!(f, "✗ {} ({} result(s))", requirement, results.len())?;
+                    write_result_lines(f, results)?;
+                }
+            }
+        }
+
+        if let Some(results) = groups.get(&None) {
+            writeln!(f, "\nResults without requirement: {}", results.len())?;
+            write_result_lines(f, results)?;
+        }
+
+        writeln!(f, "\n{}", "=".repeat(80))
+    }
+}
+
+impl<'r, 'a> SampledReport<'r, 'a> {
+    /// The JSON report with only the sampled results, and the number of
+    /// results of every shape and component in `sampleGroups`.
+    pub fn as_json(&self) -> serde_json::Value {
+        let mut report_obj = self.report.as_json();
+        report_obj["results"] = serde_json::json!(self
+            .groups
+            .iter()
+            .flat_map(|group| group.samples.iter().map(|result| result.as_json()))
+            .collect::<Vec<_>>());
+        report_obj["resultCount"] = serde_json::json!(self.report.results.len());
+        report_obj["sampleGroups"] = serde_json::json!(self
+            .groups
+            .iter()
+            .map(|group| serde_json::json!({
+                "shape": group.shape.to_string(),
+                "component": group.component.map(|component| component.to_string()),
+                "count": group.count,
+                "shown": group.samples.len(),
+            }))
+            .collect::<Vec<_>>());
+        report_obj
+    }
+}
+
+impl<'r, 'a> Display for SampledReport<'r, 'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.report
+            .write_summary(f, "SHACL Validation Report (sampled)")?;
+
+        if !self.groups.is_empty() {
+            writeln!(f, "\n\n{}", "-".repeat(80))?;
+            writeln!(f, "Sample Results by Shape and Component:")?;
+            writeln!(f, "{}", "-".repeat(80))?;
+        }
+        for group in &self.groups {
+            write!(f, "\n✗ {}", group.shape)?;
+            if let Some(component) = group.component {
+                write!(f, " {}", component)?;
+            }
+            writeln!(
+                f,
+                " ({} result(s), {} shown)",
+                group.count,
+                group.samples.len()
+            )?;
+            write_result_lines(f, &group.samples)?;
+        }
+
+        writeln!(f, "\n{}", "=".repeat(80))
+    }
+}
+
+/// A window of the results a filter accepts, see [`ValidationReport::page`].
+///
+/// Every page carries the outcome of the whole report, so a client reading
+/// one page still sees failures and cancellation.
+pub struct ReportPage<'r, 'a> {
+    results: Vec<&'r ValidationResult<'a>>,
+    offset: usize,
+    /// Results the filter accepted, on all pages.
+    matched: usize,
+    /// Results of the report.
+    total: usize,
+    conforms: bool,
+    failures: &'r [ValidationFailure],
+    cancelled: bool,
+}
+
+impl<'r, 'a> ReportPage<'r, 'a> {
+    pub fn results(&self) -> &[&'r ValidationResult<'a>] {
+        &self.results
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Number of results the filter accepted, on all pages.
+    pub fn matched(&self) -> usize {
+        self.matched
+    }
+
+    /// Number of results of the report.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Whether the whole report conforms.
+    pub fn conforms(&self) -> bool {
+        self.conforms
+    }
+
+    /// Failures of the whole report.
+    pub fn failures(&self) -> &[ValidationFailure] {
+        self.failures
+    }
+
+    /// Whether the run was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    /// Offset of the following page, if there are more accepted results.
+    pub fn next_offset(&self) -> Option<usize> {
+        let next = self.offset + self.results.len();
+        (next < self.matched).then_some(next)
+    }
+
+    /// The results of the page with the counts needed to request the others.
+    pub fn as_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "conforms": self.conforms,
+            "cancelled": self.cancelled,
+            "failures": self
+                .failures
+                .iter()
+                .map(ValidationFailure::as_json)
+                .collect::<Vec<_>>(),
+            "offset": self.offset,
+            "matched": self.matched,
+            "total": self.total,
+            "nextOffset": self.next_offset(),
+            "results": self
+                .results
+                .iter()
+                .map(|result| result.as_json())
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl<'r, 'a> Display for ReportPage<'r, 'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Conforms: {}", if self.conforms { "yes" } else { "no" })?;
+        if self.cancelled {
+            writeln!(f, "Cancelled: yes, results are incomplete")?;
+        }
+        if !self.failures.is_empty() {
+            writeln!(f, "Failures: {}", self.failures.len())?;
+            for failure in self.failures {
+                writeln!(f, "  - {}", failure)?;
+            }
+        }
+        if self.results.is_empty() {
+            writeln!(
+                f,
+                "No results from offset {} ({} of {} matched)",
+                self.offset, self.matched, self.total
+            )?;
+        } else {
+            writeln!(
+                f,
+                "Results {}-{} of {} matched ({} total)",
+                self.offset + 1,
+                self.offset + self.results.len(),
+                self.matched,
+                self.total
+            )?;
+        }
+        write_result_lines(f, &self.results)?;
+        if let Some(next) = self.next_offset() {
+            writeln!(f, "Next offset: {}", next)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_result_lines(
+    f: &mut Formatter<'_>,
+    results: &[&ValidationResult<'_>],
+) -> std::fmt::Result {
+    for result in results {
+        write!(f, "    - [{}] {}", result.severity, result.focus_node)?;
+        if let Some(path) = &result.result_path {
+            write!(f, " {}", path)?;
+        }
+        if let Some(message) = result.messages.first() {
+            write!(f, ": {}", message)?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
 impl<'a> Display for ValidationResult<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Severity: {}", self.severity)?;
@@ -403,7 +2201,7 @@ impl<'a> Display for ValidationResult<'a> {
             writeln!(f, "Result Path: {}", path)?;
         }
 
-        if let Some(value) = self.value {
+        if let Some(value) = self.get_reported_value() {
             writeln!(f, "Value: {}", value)?;
         }
 
@@ -426,6 +2224,21 @@ impl<'a> Display for ValidationResult<'a> {
             }
         }
 
+        if let Some(help_text) = &self.help_text {
+            writeln!(f, "Help: {}", help_text)?;
+        }
+
+        if let Some(documentation_url) = &self.documentation_url {
+            writeln!(f, "Documentation: {}", documentation_url)?;
+        }
+
+        if let Some(context) = &self.context {
+            writeln!(f, "Context:")?;
+            for line in context.lines() {
+                writeln!(f, "  {}", line)?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -450,7 +2263,7 @@ fn write_validation_result_details(
             writeln!(f, "{}  Result Path: {}", pad, path)?;
         }
 
-        if let Some(value) = result.value {
+        if let Some(value) = result.get_reported_value() {
             writeln!(f, "{}  Value: {}", pad, value)?;
         }
 
@@ -0,0 +1,293 @@
+//! Loading of validation reports written by earlier runs.
+//!
+//! RDF reports are read as they are. JSON reports, as produced by
+//! [`ValidationReport::as_json`](crate::ValidationReport::as_json), are first turned back into their RDF form so
+//! both can be read with [`ValidationReport::from_graph`](crate::ValidationReport::from_graph).
+
+use std::str::FromStr;
+
+use oxigraph::model::{
+    vocab::{rdf, rdfs},
+    BlankNode, Graph, Literal, NamedNode, NamedOrBlankNode, Term, Triple,
+};
+
+use crate::{
+    err::ShaclError,
+    rdf::read_graph_from_string,
+    utils::insert_rdf_list,
+    vocab::{sh, shx},
+};
+
+/// Reads a stored report into a graph; `format` is `json` or an RDF format.
+pub fn read_report_graph(content: &str, format: &str) -> Result<Graph, ShaclError> {
+    if format.eq_ignore_ascii_case("json") {
+        let json: serde_json::Value = serde_json::from_str(content)
+            .map_err(|e| ShaclError::Parse(format!("Invalid JSON report: {}", e)))?;
+        report_graph_from_json(&json)
+    } else {
+        read_graph_from_string(content, format)
+    }
+}
+
+/// Converts a JSON report back into the RDF form of [`ValidationReport::to_graph`](crate::ValidationReport::to_graph).
+pub fn report_graph_from_json(json: &serde_json::Value) -> Result<Graph, ShaclError> {
+    let results = json["results"]
+        .as_array()
+        .ok_or_else(|| ShaclError::Parse("JSON report has no \"results\" array".to_string()))?;
+
+    let mut graph = Graph::new();
+    let report = NamedOrBlankNode::from(BlankNode::default());
+    insert(
+        &mut graph,
+        &report,
+        rdf::TYPE.into(),
+        sh::VALIDATION_REPORT.into(),
+    );
+
+    if let Some(conforms) = json["conforms"].as_bool() {
+        insert(
+            &mut graph,
+            &report,
+            sh::CONFORMS.into(),
+            Literal::from(conforms).into(),
+        );
+    }
+    if json["cancelled"].as_bool() == Some(true) {
+        insert(
+            &mut graph,
+            &report,
+            shx::CANCELLED.into(),
+            Literal::from(true).into(),
+        );
+    }
+    if let Some(well_formed) = json["shapesGraphWellFormed"].as_bool() {
+        insert(
+            &mut graph,
+            &report,
+            sh::SHAPES_GRAPH_WELL_FORMED.into(),
+            Literal::from(well_formed).into(),
+        );
+    }
+    for (key, predicate) in [
+        ("shapesVersion", shx::SHAPES_VERSION),
+        ("shapesSource", shx::SHAPES_SOURCE),
+        ("shapesHash", shx::SHAPES_HASH),
+        ("validatorVersion", shx::VALIDATOR_VERSION),
+    ] {
+        if let Some(value) = json["provenance"][key].as_str() {
+            insert(
+                &mut graph,
+                &report,
+                predicate.into(),
+                Literal::from(value).into(),
+            );
+        }
+    }
+
+    for feature in json["provenance"]["validatorFeatures"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|feature| feature.as_str())
+    {
+        insert(
+            &mut graph,
+            &report,
+            shx::VALIDATOR_FEATURE.into(),
+            Literal::from(feature).into(),
+        );
+    }
+
+    for (shape, count) in json["focusNodeCounts"].as_object().into_iter().flatten() {
+        let term = Term::from_str(shape)
+            .ok()
+            .filter(|term| matches!(term, Term::NamedNode(_) | Term::BlankNode(_)));
+        let (Some(term), Some(count)) = (term, count.as_i64()) else {
+            return Err(ShaclError::Parse(format!(
+                "Invalid focus node count of '{}' in JSON report",
+                shape
+            )));
+        };
+        let coverage = NamedOrBlankNode::from(BlankNode::default());
+        insert(
+            &mut graph,
+            &report,
+            shx::COVERAGE.into(),
+            coverage.clone().into(),
+        );
+        insert(&mut graph, &coverage, shx::SHAPE.into(), term);
+        insert(
+            &mut graph,
+            &coverage,
+            shx::FOCUS_NODE_COUNT.into(),
+            Literal::from(count).into(),
+        );
+    }
+
+    for result in results {
+        let result = add_result(&mut graph, result)?;
+        insert(&mut graph, &report, sh::RESULT.into(), result.into());
+    }
+    Ok(graph)
+}
+
+fn add_result(graph: &mut Graph, json: &serde_json::Value) -> Result<NamedOrBlankNode, ShaclError> {
+    let node = NamedOrBlankNode::from(BlankNode::default());
+    insert(graph, &node, rdf::TYPE.into(), sh::VALIDATION_RESULT.into());
+
+    for (key, predicate) in [
+        ("focusNode", sh::FOCUS_NODE),
+        ("sourceShape", sh::SOURCE_SHAPE),
+        ("severity", sh::RESULT_SEVERITY),
+        ("sourceConstraintComponent", sh::SOURCE_CONSTRAINT_COMPONENT),
+        ("value", sh::VALUE),
+        ("countedShape", shx::COUNTED_SHAPE),
+    ] {
+        if let Some(term) = json[key].as_str() {
+            let term = Term::from_str(term).map_err(|e| {
+                ShaclError::Parse(format!("Invalid {} '{}' in JSON report: {}", key, term, e))
+            })?;
+            insert(graph, &node, predicate.into(), term);
+        }
+    }
+
+    if let Some(help_text) = json["helpText"].as_str() {
+        insert(
+            graph,
+            &node,
+            rdfs::COMMENT.into(),
+            Literal::from(help_text).into(),
+        );
+    }
+    if let Some(url) = json["documentationUrl"].as_str() {
+        if let Ok(url) = NamedNode::new(url) {
+            insert(graph, &node, rdfs::SEE_ALSO.into(), url.into());
+        }
+    }
+
+    if let Some(path) = json["resultPath"].as_str() {
+        let path = PathReader::new(path).read(graph)?;
+        insert(graph, &node, sh::RESULT_PATH.into(), path);
+    }
+
+    for (key, predicate) in [
+        ("messages", sh::RESULT_MESSAGE),
+        ("requirements", shx::REQUIREMENT),
+        ("trace", sh::DETAIL),
+    ] {
+        for value in json[key].as_array().into_iter().flatten() {
+            if let Some(value) = value.as_str() {
+                insert(graph, &node, predicate.into(), Literal::from(value).into());
+            }
+        }
+    }
+
+    for detail in json["details"].as_array().into_iter().flatten() {
+        let detail = add_result(graph, detail)?;
+        insert(graph, &node, sh::DETAIL.into(), detail.into());
+    }
+    Ok(node)
+}
+
+fn insert(graph: &mut Graph, subject: &NamedOrBlankNode, predicate: NamedNode, object: Term) {
+    graph.insert(&Triple::new(subject.clone(), predicate, object));
+}
+
+/// Reads paths in the notation of `Path`'s `Display` back into SHACL path nodes,
+/// e.g. `<a> / ^<b> / (<c> | (<d>*))`.
+struct PathReader<'s> {
+    input: &'s str,
+    position: usize,
+}
+
+impl<'s> PathReader<'s> {
+    fn new(input: &'s str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn read(mut self, graph: &mut Graph) -> Result<Term, ShaclError> {
+        let mut elements = vec![self.element(graph)?];
+        while self.eat('/') {
+            elements.push(self.element(graph)?);
+        }
+        if self.peek().is_some() {
+            return Err(self.error());
+        }
+
+        if elements.len() == 1 {
+            Ok(elements.remove(0))
+        } else {
+            Ok(insert_rdf_list(graph, elements))
+        }
+    }
+
+    fn element(&mut self, graph: &mut Graph) -> Result<Term, ShaclError> {
+        if self.eat('^') {
+            let iri = self.iri()?;
+            return Ok(path_node(graph, sh::INVERSE_PATH.into(), iri));
+        }
+        if !self.eat('(') {
+            return self.iri();
+        }
+
+        let first = self.element(graph)?;
+        let element = if self.eat('*') {
+            path_node(graph, sh::ZERO_OR_MORE_PATH.into(), first)
+        } else if self.eat('+') {
+            path_node(graph, sh::ONE_OR_MORE_PATH.into(), first)
+        } else if self.eat('?') {
+            path_node(graph, sh::ZERO_OR_ONE_PATH.into(), first)
+        } else {
+            let mut alternatives = vec![first];
+            while self.eat('|') {
+                alternatives.push(self.element(graph)?);
+            }
+            let alternatives = insert_rdf_list(graph, alternatives);
+            path_node(graph, sh::ALTERNATIVE_PATH.into(), alternatives)
+        };
+
+        if self.eat(')') {
+            Ok(element)
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn iri(&mut self) -> Result<Term, ShaclError> {
+        if self.peek() != Some('<') {
+            return Err(self.error());
+        }
+        let rest = &self.input[self.position..];
+        let end = rest.find('>').ok_or_else(|| self.error())?;
+        let iri = NamedNode::from_str(&rest[..=end]).map_err(|_| self.error())?;
+        self.position += end + 1;
+        Ok(iri.into())
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+        self.input[self.position..].chars().next()
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let matches = self.peek() == Some(expected);
+        if matches {
+            self.position += expected.len_utf8();
+        }
+        matches
+    }
+
+    fn error(&self) -> ShaclError {
+        ShaclError::Parse(format!(
+            "Invalid result path '{}' in JSON report at offset {}",
+            self.input, self.position
+        ))
+    }
+}
+
+fn path_node(graph: &mut Graph, predicate: NamedNode, object: Term) -> Term {
+    let node = NamedOrBlankNode::from(BlankNode::default());
+    insert(graph, &node, predicate, object);
+    node.into()
+}
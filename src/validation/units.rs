@@ -0,0 +1,151 @@
+//! Unit-aware comparison of QUDT quantity values.
+//!
+//! A shape with `shx:unit` states the unit of its numeric range facets. Value
+//! nodes that are QUDT quantity values (`qudt:numericValue` and `qudt:unit`) are
+//! converted to that unit before comparison. Conversion factors are read from
+//! the data and shapes graphs, e.g. when the QUDT unit vocabulary is loaded,
+//! with a built-in table of common units as fallback.
+
+use oxigraph::model::{Literal, NamedNodeRef, Term, TermRef};
+
+use crate::{
+    core::shape::Shape, utils::term_to_named_or_blank, validation::dataset::ValidationDataset,
+    vocab::qudt,
+};
+
+/// How values of a unit convert to the SI unit of its dimension.
+#[derive(Debug, Clone, PartialEq)]
+struct Conversion {
+    multiplier: f64,
+    offset: f64,
+    dimension: String,
+}
+
+/// Common QUDT units: local name, multiplier, offset and dimension vector.
+const BUILT_IN_UNITS: &[(&str, f64, f64, &str)] = &[
+    ("M", 1.0, 0.0, "A0E0L1I0M0H0T0D0"),
+    ("KiloM", 1000.0, 0.0, "A0E0L1I0M0H0T0D0"),
+    ("CentiM", 0.01, 0.0, "A0E0L1I0M0H0T0D0"),
+    ("MilliM", 0.001, 0.0, "A0E0L1I0M0H0T0D0"),
+    ("MicroM", 0.000_001, 0.0, "A0E0L1I0M0H0T0D0"),
+    ("IN", 0.0254, 0.0, "A0E0L1I0M0H0T0D0"),
+    ("FT", 0.3048, 0.0, "A0E0L1I0M0H0T0D0"),
+    ("MI", 1609.344, 0.0, "A0E0L1I0M0H0T0D0"),
+    ("KiloGM", 1.0, 0.0, "A0E0L0I0M1H0T0D0"),
+    ("GM", 0.001, 0.0, "A0E0L0I0M1H0T0D0"),
+    ("MilliGM", 0.000_001, 0.0, "A0E0L0I0M1H0T0D0"),
+    ("TONNE", 1000.0, 0.0, "A0E0L0I0M1H0T0D0"),
+    ("LB", 0.453_592_37, 0.0, "A0E0L0I0M1H0T0D0"),
+    ("SEC", 1.0, 0.0, "A0E0L0I0M0H0T1D0"),
+    ("MilliSEC", 0.001, 0.0, "A0E0L0I0M0H0T1D0"),
+    ("MIN", 60.0, 0.0, "A0E0L0I0M0H0T1D0"),
+    ("HR", 3600.0, 0.0, "A0E0L0I0M0H0T1D0"),
+    ("DAY", 86400.0, 0.0, "A0E0L0I0M0H0T1D0"),
+    ("K", 1.0, 0.0, "A0E0L0I0M0H1T0D0"),
+    ("DEG_C", 1.0, 273.15, "A0E0L0I0M0H1T0D0"),
+    ("DEG_F", 5.0 / 9.0, 459.67, "A0E0L0I0M0H1T0D0"),
+    ("M-PER-SEC", 1.0, 0.0, "A0E0L1I0M0H0T-1D0"),
+    ("KiloM-PER-HR", 1.0 / 3.6, 0.0, "A0E0L1I0M0H0T-1D0"),
+    ("PA", 1.0, 0.0, "A0E0L-1I0M1H0T-2D0"),
+    ("KiloPA", 1000.0, 0.0, "A0E0L-1I0M1H0T-2D0"),
+    ("BAR", 100_000.0, 0.0, "A0E0L-1I0M1H0T-2D0"),
+    ("J", 1.0, 0.0, "A0E0L2I0M1H0T-2D0"),
+    ("KiloW-HR", 3_600_000.0, 0.0, "A0E0L2I0M1H0T-2D0"),
+    ("W", 1.0, 0.0, "A0E0L2I0M1H0T-3D0"),
+    ("KiloW", 1000.0, 0.0, "A0E0L2I0M1H0T-3D0"),
+    ("PERCENT", 0.01, 0.0, "A0E0L0I0M0H0T0D1"),
+    ("UNITLESS", 1.0, 0.0, "A0E0L0I0M0H0T0D1"),
+];
+
+impl Conversion {
+    /// Reads the conversion of `unit` from the graphs, falling back to the built-in table.
+    fn of(validation_dataset: &ValidationDataset, unit: NamedNodeRef<'_>) -> Option<Self> {
+        [
+            validation_dataset.data_graph(),
+            validation_dataset.shapes_graph(),
+        ]
+        .into_iter()
+        .find_map(|graph| {
+            let number = |predicate| match graph.object_for_subject_predicate(unit, predicate) {
+                Some(TermRef::Literal(lit)) => lit.value().parse::<f64>().ok(),
+                _ => None,
+            };
+            let dimension =
+                match graph.object_for_subject_predicate(unit, qudt::HAS_DIMENSION_VECTOR) {
+                    Some(TermRef::NamedNode(vector)) => vector.as_str().to_string(),
+                    _ => return None,
+                };
+            Some(Conversion {
+                multiplier: number(qudt::CONVERSION_MULTIPLIER)?,
+                offset: number(qudt::CONVERSION_OFFSET).unwrap_or(0.0),
+                dimension,
+            })
+        })
+        .or_else(|| {
+            let name = unit.as_str().strip_prefix(qudt::UNIT_NAMESPACE)?;
+            BUILT_IN_UNITS
+                .iter()
+                .find(|(unit_name, ..)| *unit_name == name)
+                .map(|&(_, multiplier, offset, dimension)| Conversion {
+                    multiplier,
+                    offset,
+                    dimension: format!("{}{}", qudt::DIMENSION_VECTOR_NAMESPACE, dimension),
+                })
+        })
+    }
+}
+
+impl<'a> Shape<'a> {
+    /// The value node to compare with numeric range facets: QUDT quantity values
+    /// are converted to this shape's `shx:unit`, with a trace entry.
+    ///
+    /// Other value nodes are returned as they are. Fails with the reason when
+    /// the units cannot be compared.
+    pub(crate) fn value_in_unit(
+        &self,
+        validation_dataset: &ValidationDataset,
+        value_node: TermRef<'_>,
+    ) -> Result<(Term, Option<String>), String> {
+        let unchanged = Ok((value_node.into_owned(), None));
+        let (Some(target_unit), Some(node)) = (self.unit, term_to_named_or_blank(value_node))
+        else {
+            return unchanged;
+        };
+        let graph = validation_dataset.data_graph();
+        let (Some(TermRef::Literal(number)), Some(TermRef::NamedNode(unit))) = (
+            graph.object_for_subject_predicate(node, qudt::NUMERIC_VALUE),
+            graph.object_for_subject_predicate(node, qudt::UNIT),
+        ) else {
+            return unchanged;
+        };
+        let value = number
+            .value()
+            .parse::<f64>()
+            .map_err(|_| format!("Quantity value {} is not a number", number))?;
+
+        if unit == target_unit {
+            // Kept as is, so decimals are compared exactly.
+            return Ok((number.into_owned().into(), None));
+        }
+
+        let from = Conversion::of(validation_dataset, unit)
+            .ok_or_else(|| format!("Unit {} has no known conversion", unit))?;
+        let to = Conversion::of(validation_dataset, target_unit)
+            .ok_or_else(|| format!("Unit {} has no known conversion", target_unit))?;
+        if from.dimension != to.dimension {
+            return Err(format!(
+                "Unit {} cannot be compared with {}",
+                unit, target_unit
+            ));
+        }
+
+        let converted = (value + from.offset) * from.multiplier / to.multiplier - to.offset;
+        Ok((
+            Literal::from(converted).into(),
+            Some(format!(
+                "Quantity value {} {} was converted to {} {}",
+                value, unit, converted, target_unit
+            )),
+        ))
+    }
+}
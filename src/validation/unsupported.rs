@@ -0,0 +1,224 @@
+//! Detection of shape features the validator skips.
+//!
+//! Unknown SHACL predicates, custom targets, constraint components without a
+//! validator or with invalid parameter values and shapes that fail to parse
+//! are not evaluated. Listing them in the report lets users tell whether a
+//! "conforms" verdict might be due to unevaluated constraints.
+
+use std::{collections::HashSet, sync::Arc};
+
+use oxigraph::model::{Graph, NamedNodeRef, NamedOrBlankNodeRef};
+
+use crate::{
+    core::{constraints::Constraint, shape::Shape, target::Target},
+    parser::{
+        constraints::sparql::invalid_component_parameters, parse_shape_with,
+        shape_registry::ShapeRegistry,
+    },
+    utils,
+    validation::{components::ConstraintComponentRegistry, report::UnsupportedFeature},
+    vocab::sh,
+};
+
+/// SHACL predicates that are either evaluated or have no effect on validation.
+const KNOWN_PREDICATES: &[NamedNodeRef<'static>] = &[
+    sh::AND,
+    sh::CLASS,
+    sh::CLOSED,
+    sh::DATATYPE,
+    sh::DEACTIVATED,
+    sh::DECLARE,
+    sh::DEFAULT_VALUE,
+    sh::DESCRIPTION,
+    sh::DISJOINT,
+    sh::EQUALS,
+    sh::EXPRESSION,
+    sh::FLAGS,
+    sh::GROUP,
+    sh::HAS_VALUE,
+    sh::IGNORED_PROPERTIES,
+    sh::IN,
+    sh::LANGUAGE_IN,
+    sh::LESS_THAN,
+    sh::LESS_THAN_OR_EQUALS,
+    sh::MAX_COUNT,
+    sh::MAX_EXCLUSIVE,
+    sh::MAX_INCLUSIVE,
+    sh::MAX_LENGTH,
+    sh::MESSAGE,
+    sh::MIN_COUNT,
+    sh::MIN_EXCLUSIVE,
+    sh::MIN_INCLUSIVE,
+    sh::MIN_LENGTH,
+    sh::NAME,
+    sh::NODE,
+    sh::NODE_KIND_PROPERTY,
+    sh::NOT,
+    sh::OR,
+    sh::ORDER,
+    sh::PATH,
+    sh::PATTERN,
+    sh::PREFIXES,
+    sh::PROPERTY,
+    sh::QUALIFIED_MAX_COUNT,
+    sh::QUALIFIED_MIN_COUNT,
+    sh::QUALIFIED_VALUE_SHAPE,
+    sh::QUALIFIED_VALUE_SHAPES_DISJOINT,
+    sh::SEVERITY,
+    sh::SPARQL,
+    sh::TARGET,
+    sh::TARGET_CLASS,
+    sh::TARGET_NODE,
+    sh::TARGET_OBJECTS_OF,
+    sh::TARGET_SUBJECTS_OF,
+    sh::UNIQUE_LANG,
+    sh::XONE,
+];
+
+/// Lists the features of `shapes_graph` that validating `shapes` will not evaluate.
+pub fn find_unsupported_features(
+    shapes_graph: &Graph,
+    shapes: &[Arc<Shape<'_>>],
+) -> Vec<UnsupportedFeature> {
+    let mut parsed = HashSet::new();
+    for shape in shapes {
+        collect_shape_nodes(shape, &mut parsed);
+    }
+
+    let mut features = Vec::new();
+    let registry = ShapeRegistry::new(shapes_graph);
+    let mut candidates: Vec<NamedOrBlankNodeRef<'_>> = registry
+        .index()
+        .shape_nodes()
+        .into_iter()
+        .chain(parsed.iter().flat_map(|&node| {
+            shapes_graph
+                .objects_for_subject_predicate(node, sh::PROPERTY)
+                .filter_map(utils::term_to_named_or_blank)
+        }))
+        .filter(|node| !parsed.contains(node))
+        .collect();
+    candidates.sort_by_key(|node| node.to_string());
+    candidates.dedup();
+
+    for node in candidates {
+        if let Err(error) = parse_shape_with(&registry, node, None) {
+            features.push(UnsupportedFeature::new(
+                node,
+                "shape",
+                format!("Shape could not be parsed and was skipped: {}", error),
+            ));
+        }
+    }
+
+    for shape in shapes {
+        collect_shape_features(shape, &registry, &mut features);
+    }
+
+    features.sort();
+    features.dedup();
+    features
+}
+
+/// Lists the custom constraints of `shapes` whose component has no validator in `registry`.
+pub fn find_unregistered_components(
+    registry: &ConstraintComponentRegistry,
+    shapes: &[Arc<Shape<'_>>],
+) -> Vec<UnsupportedFeature> {
+    fn collect(
+        shape: &Shape<'_>,
+        registry: &ConstraintComponentRegistry,
+        features: &mut Vec<UnsupportedFeature>,
+    ) {
+        for constraint in &shape.constraints {
+            if let Constraint::Custom(c) = constraint {
+                if registry.get(c.component).is_none() {
+                    features.push(UnsupportedFeature::new(
+                        shape.node,
+                        c.component,
+                        "Constraint component has no SPARQL or registered validator",
+                    ));
+                }
+            }
+        }
+        for nested in nested_shapes(shape) {
+            collect(nested, registry, features);
+        }
+    }
+
+    let mut features = Vec::new();
+    for shape in shapes {
+        collect(shape, registry, &mut features);
+    }
+    features.sort();
+    features.dedup();
+    features
+}
+
+fn collect_shape_features<'a>(
+    shape: &Shape<'a>,
+    registry: &ShapeRegistry<'a>,
+    features: &mut Vec<UnsupportedFeature>,
+) {
+    let graph = registry.graph();
+    for target in &shape.targets {
+        if let Target::Advanced(target) = target {
+            features.push(UnsupportedFeature::new(
+                shape.node,
+                sh::TARGET,
+                format!("Custom target {} is not evaluated", target),
+            ));
+        }
+    }
+
+    for triple in graph.triples_for_subject(shape.node) {
+        let predicate = triple.predicate;
+        if predicate
+            .as_str()
+            .starts_with("http://www.w3.org/ns/shacl#")
+            && !KNOWN_PREDICATES.contains(&predicate)
+        {
+            features.push(UnsupportedFeature::new(
+                shape.node,
+                predicate,
+                "Predicate is not evaluated by this validator",
+            ));
+        }
+    }
+
+    for (component, reason) in invalid_component_parameters(registry, shape.node) {
+        features.push(UnsupportedFeature::new(
+            shape.node,
+            component,
+            format!("Constraint component was skipped: {}", reason),
+        ));
+    }
+
+    for nested in nested_shapes(shape) {
+        collect_shape_features(nested, registry, features);
+    }
+}
+
+fn collect_shape_nodes<'a>(shape: &Shape<'a>, nodes: &mut HashSet<NamedOrBlankNodeRef<'a>>) {
+    nodes.insert(shape.node);
+    for nested in nested_shapes(shape) {
+        collect_shape_nodes(nested, nodes);
+    }
+}
+
+/// Property shapes and shapes referenced by shape-based constraints.
+fn nested_shapes<'s, 'a>(shape: &'s Shape<'a>) -> Vec<&'s Shape<'a>> {
+    let mut nested: Vec<&Shape<'a>> = shape.property_shapes.iter().collect();
+    for constraint in &shape.constraints {
+        match constraint {
+            Constraint::Node(c) => nested.push(&c.0),
+            Constraint::Not(c) => nested.push(&c.0),
+            Constraint::QualifiedValueShape(c) => nested.push(&c.shape),
+            Constraint::And(c) => nested.extend(c.0.iter().map(AsRef::as_ref)),
+            Constraint::Or(c) => nested.extend(c.0.iter().map(AsRef::as_ref)),
+            Constraint::Xone(c) => nested.extend(c.0.iter().map(AsRef::as_ref)),
+            _ => {}
+        }
+    }
+    nested
+}
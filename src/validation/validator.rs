@@ -0,0 +1,595 @@
+//! Configurable validation runs.
+//!
+//! [`Validator`] runs the same validation as [`validate`](crate::validate), with
+//! additions that cannot be expressed in the shapes graph, such as focus nodes
+//! chosen by application code.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use oxigraph::model::{
+    Graph, NamedNode, NamedNodeRef, NamedOrBlankNode, NamedOrBlankNodeRef, Term, TermRef,
+};
+
+#[cfg(not(target_family = "wasm"))]
+use rayon::prelude::*;
+
+use crate::{
+    core::{
+        severity::Severity,
+        shape::Shape,
+        target::{Target, TargetResolver, TargetTypes},
+    },
+    parser::parse_dataset_constraints,
+    validation::{
+        build_target_cache_with,
+        conformance_cache::ConformanceCache,
+        dataset::{self, ValidationDataset},
+        dataset_constraint::{find_in_graph, validate_dataset_constraints},
+        report::{UnsupportedFeature, ValidationReport, ValidationResult},
+        unsupported::{find_unregistered_components, find_unsupported_features},
+        TargetResolutionCache,
+    },
+    vocab::{sh, shx},
+    ShaclError,
+};
+
+/// Selects focus nodes of a shape from the data graph.
+pub type FocusNodeResolver = Arc<dyn Fn(&Graph) -> HashSet<Term> + Send + Sync>;
+
+/// Validates data graphs against shapes.
+///
+/// ```
+/// use std::collections::HashSet;
+/// use oxigraph::model::{NamedNode, Term};
+/// use shacl_rust::{parse_shapes, validation::dataset::ValidationDataset, Validator};
+/// use shacl_rust::rdf::read_graph_from_string;
+///
+/// let data = read_graph_from_string(
+///     "<http://example.org/a> <http://example.org/p> 1 .",
+///     "turtle",
+/// ).unwrap();
+/// let shapes = read_graph_from_string(
+///     "<http://example.org/S> a <http://www.w3.org/ns/shacl#NodeShape> ;
+///         <http://www.w3.org/ns/shacl#maxCount> 0 ;
+///         <http://www.w3.org/ns/shacl#path> <http://example.org/p> .",
+///     "turtle",
+/// ).unwrap();
+/// let dataset = ValidationDataset::from_graphs(data, shapes).unwrap();
+/// let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+///
+/// let validator = Validator::builder()
+///     .with_target_resolver(NamedNode::new("http://example.org/S").unwrap(), |_graph| {
+///         HashSet::from([Term::from(NamedNode::new("http://example.org/a").unwrap())])
+///     })
+///     .build();
+/// assert!(!*validator.validate(&dataset, &shapes).get_conforms());
+/// ```
+#[derive(Clone, Default)]
+pub struct Validator {
+    target_resolvers: HashMap<NamedNode, Vec<FocusNodeResolver>>,
+    target_types: TargetTypes,
+    options: ValidationOptions,
+    cancellation: Option<CancellationToken>,
+}
+
+/// Stops a [`Validator`] run from another thread.
+///
+/// Clones share their state. Once cancelled, a run stops before its next focus
+/// node and returns the results found so far in a report marked
+/// [cancelled](ValidationReport::is_cancelled), which does not conform.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Limits of a [`Validator`] run, for large datasets where a complete report
+/// is not needed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationOptions {
+    /// Stop validating once this many results were found. The report keeps at
+    /// most this many.
+    pub max_violations: Option<usize>,
+
+    /// Stop at the first result, as with a `max_violations` of 1.
+    pub fail_fast: bool,
+
+    /// Only validate shapes with one of these severities, and only keep
+    /// results with them. All severities when `None`.
+    pub severity_filter: Option<Vec<Severity<'static>>>,
+}
+
+impl ValidationOptions {
+    /// Most results the run may produce.
+    fn result_limit(&self) -> Option<usize> {
+        self.fail_fast
+            .then_some(1)
+            .into_iter()
+            .chain(self.max_violations)
+            .min()
+    }
+
+    fn includes(&self, severity: Severity<'_>) -> bool {
+        self.severity_filter
+            .as_ref()
+            .is_none_or(|filter| filter.contains(&severity))
+    }
+}
+
+/// Shared state of a run with [`ValidationOptions`], checked per focus node.
+pub(crate) struct RunLimits<'o> {
+    options: &'o ValidationOptions,
+    remaining: Option<AtomicUsize>,
+    cancellation: Option<&'o CancellationToken>,
+}
+
+impl<'o> RunLimits<'o> {
+    fn new(options: &'o ValidationOptions, cancellation: Option<&'o CancellationToken>) -> Self {
+        Self {
+            options,
+            remaining: options.result_limit().map(AtomicUsize::new),
+            cancellation,
+        }
+    }
+
+    /// Whether the result limit was reached or the run was cancelled.
+    pub(crate) fn is_spent(&self) -> bool {
+        self.cancellation
+            .is_some_and(CancellationToken::is_cancelled)
+            || self
+                .remaining
+                .as_ref()
+                .is_some_and(|remaining| remaining.load(Ordering::Relaxed) == 0)
+    }
+
+    /// Drops results of filtered severities and counts the rest against the limit.
+    pub(crate) fn apply(&self, report: &mut ValidationReport<'_>) {
+        if self.options.severity_filter.is_some() {
+            report.retain_results(|result| self.options.includes(result.get_severity()));
+        }
+        if let Some(remaining) = &self.remaining {
+            let found = report.violation_count();
+            let _ = remaining.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |left| {
+                Some(left.saturating_sub(found))
+            });
+        }
+    }
+}
+
+/// A [`Validator`] run that validates a few shapes per [`step`](Self::step).
+///
+/// Single-threaded callers, such as WebAssembly in a browser, can handle other
+/// work between steps, e.g. a [`CancellationToken`] being cancelled.
+///
+/// ```
+/// use shacl_rust::{parse_shapes, validation::dataset::ValidationDataset, Validator};
+/// use shacl_rust::rdf::read_graph_from_string;
+///
+/// let data = read_graph_from_string("<http://example.org/a> a <http://example.org/C> .", "turtle").unwrap();
+/// let shapes = read_graph_from_string(
+///     "<http://example.org/S> <http://www.w3.org/ns/shacl#targetClass> <http://example.org/C> ;
+///         <http://www.w3.org/ns/shacl#nodeKind> <http://www.w3.org/ns/shacl#Literal> .",
+///     "turtle",
+/// ).unwrap();
+/// let dataset = ValidationDataset::from_graphs(data, shapes).unwrap();
+/// let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+///
+/// let validator = Validator::builder().build();
+/// let mut run = validator.start(&dataset, &shapes);
+/// while run.step(1) {}
+/// assert_eq!(run.finish().get_results().len(), 1);
+/// ```
+pub struct ValidationRun<'v, 'a> {
+    validator: &'v Validator,
+    validation_dataset: &'a ValidationDataset,
+    shapes: &'a [Arc<Shape<'a>>],
+    target_cache: TargetResolutionCache<'a>,
+    limits: RunLimits<'v>,
+    /// Index of the next shape to validate.
+    next: usize,
+    report: ValidationReport<'a>,
+}
+
+impl<'a> ValidationRun<'_, 'a> {
+    /// Validates up to `count` more shapes and returns whether any are left.
+    pub fn step(&mut self, count: usize) -> bool {
+        let end = (self.next + count).min(self.shapes.len());
+        for shape in &self.shapes[self.next..end] {
+            let shape_report = self.validator.validate_shape(
+                self.validation_dataset,
+                shape,
+                &self.target_cache,
+                &self.limits,
+            );
+            self.report.merge(shape_report);
+        }
+        self.next = end;
+        self.next < self.shapes.len()
+    }
+
+    /// Validates the shapes left and the dataset constraints, and returns the
+    /// report [`Validator::validate`] would have.
+    pub fn finish(mut self) -> ValidationReport<'a> {
+        while self.step(self.shapes.len()) {}
+        self.validator.finish(
+            self.validation_dataset,
+            self.shapes,
+            self.report,
+            &self.limits,
+            |_| {},
+        )
+    }
+}
+
+/// Builds a [`Validator`].
+#[derive(Clone, Default)]
+pub struct ValidatorBuilder {
+    validator: Validator,
+}
+
+impl ValidatorBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the nodes `resolver` returns to the focus nodes of the shape `shape`.
+    ///
+    /// Returned nodes that occur in neither the data nor the shapes graph are skipped.
+    pub fn with_target_resolver(
+        mut self,
+        shape: NamedNode,
+        resolver: impl Fn(&Graph) -> HashSet<Term> + Send + Sync + 'static,
+    ) -> Self {
+        self.validator
+            .target_resolvers
+            .entry(shape)
+            .or_default()
+            .push(Arc::new(resolver));
+        self
+    }
+
+    /// Resolves `sh:target` nodes of type `target_type` with `resolver`, rather
+    /// than reporting them as unsupported.
+    pub fn with_target_type(
+        mut self,
+        target_type: NamedNode,
+        resolver: impl TargetResolver + 'static,
+    ) -> Self {
+        self.validator.target_types = self
+            .validator
+            .target_types
+            .with_resolver(target_type, resolver);
+        self
+    }
+
+    pub fn with_options(mut self, options: ValidationOptions) -> Self {
+        self.validator.options = options;
+        self
+    }
+
+    /// Stops runs once `token` is cancelled.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.validator.cancellation = Some(token);
+        self
+    }
+
+    pub fn build(self) -> Validator {
+        self.validator
+    }
+}
+
+impl Validator {
+    pub fn builder() -> ValidatorBuilder {
+        ValidatorBuilder::new()
+    }
+
+    /// Validates a graph against all provided shapes and the dataset constraints of the shapes graph.
+    pub fn validate<'a>(
+        &self,
+        validation_dataset: &'a ValidationDataset,
+        shapes: &'a [Arc<Shape<'a>>],
+    ) -> ValidationReport<'a> {
+        self.validate_streaming(validation_dataset, shapes, |_| {})
+    }
+
+    /// Validates like [`Self::validate`], handing each result to `on_result` as
+    /// soon as the shape that produced it is done.
+    ///
+    /// Results arrive in the order shapes finish. With a result limit, no more
+    /// results than the limit are handed over, though they may not be the ones
+    /// kept in the report.
+    pub fn validate_streaming<'a>(
+        &self,
+        validation_dataset: &'a ValidationDataset,
+        shapes: &'a [Arc<Shape<'a>>],
+        on_result: impl FnMut(&ValidationResult<'a>) + Send,
+    ) -> ValidationReport<'a> {
+        let mut report = ValidationReport::new();
+        let target_cache = build_target_cache_with(validation_dataset, shapes, &self.target_types);
+        let limits = RunLimits::new(&self.options, self.cancellation.as_ref());
+        let sink = Mutex::new((on_result, 0));
+        let emit = |report: &ValidationReport<'a>| {
+            let mut sink = sink.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let (on_result, emitted) = &mut *sink;
+            for result in report.get_results() {
+                if self.options.result_limit() == Some(*emitted) {
+                    break;
+                }
+                on_result(result);
+                *emitted += 1;
+            }
+        };
+        let validate_shape = |shape: &'a Arc<Shape<'a>>| {
+            let shape_report =
+                self.validate_shape(validation_dataset, shape, &target_cache, &limits);
+            emit(&shape_report);
+            shape_report
+        };
+
+        #[cfg(not(target_family = "wasm"))]
+        let shape_reports: Vec<ValidationReport<'a>> =
+            shapes.par_iter().map(validate_shape).collect();
+
+        #[cfg(target_family = "wasm")]
+        let shape_reports: Vec<ValidationReport<'a>> = shapes.iter().map(validate_shape).collect();
+
+        for shape_report in shape_reports {
+            report.merge(shape_report);
+        }
+
+        self.finish(validation_dataset, shapes, report, &limits, emit)
+    }
+
+    /// Starts a run that validates `shapes` a few at a time, see [`ValidationRun`].
+    pub fn start<'v, 'a>(
+        &'v self,
+        validation_dataset: &'a ValidationDataset,
+        shapes: &'a [Arc<Shape<'a>>],
+    ) -> ValidationRun<'v, 'a> {
+        ValidationRun {
+            validator: self,
+            validation_dataset,
+            shapes,
+            target_cache: build_target_cache_with(validation_dataset, shapes, &self.target_types),
+            limits: RunLimits::new(&self.options, self.cancellation.as_ref()),
+            next: 0,
+            report: ValidationReport::new(),
+        }
+    }
+
+    /// Validates only the given nodes, returning one report per node.
+    ///
+    /// Each node is validated against the shapes that target it, including
+    /// through the custom target resolvers and types of this validator. Targets
+    /// are checked for the given nodes only; SPARQL and custom targets are
+    /// resolved once for all of them. Nested shape reports are shared across
+    /// nodes. Nodes that no shape targets get an empty, conforming report.
+    pub fn validate_nodes<'a>(
+        &self,
+        validation_dataset: &'a ValidationDataset,
+        shapes: &'a [Arc<Shape<'a>>],
+        nodes: &[NamedOrBlankNode],
+    ) -> HashMap<NamedOrBlankNode, ValidationReport<'a>> {
+        let mut target_cache = TargetResolutionCache::new();
+        let mut custom_focus_nodes = HashMap::new();
+        let cache = ConformanceCache::default();
+
+        let mut reports = HashMap::new();
+        for node in nodes {
+            let mut report = ValidationReport::new();
+            let node_term = Term::from(node.clone());
+            // Borrow the node from the graphs so the report outlives `nodes`.
+            let focus_node = find_in_graph(validation_dataset.data_graph(), &node_term)
+                .or_else(|| find_in_graph(validation_dataset.shapes_graph(), &node_term));
+            if let Some(focus_node) = focus_node {
+                for shape in shapes.iter().filter(|shape| !shape.deactivated) {
+                    let targeted = shape.targets.iter().any(|target| {
+                        target
+                            .selects(validation_dataset, focus_node)
+                            .unwrap_or_else(|| {
+                                target_cache
+                                    .entry(*target)
+                                    .or_insert_with(|| {
+                                        target.resolve_target_with(
+                                            validation_dataset,
+                                            &self.target_types,
+                                        )
+                                    })
+                                    .contains(&focus_node)
+                            })
+                    }) || custom_focus_nodes
+                        .entry(shape.node)
+                        .or_insert_with(|| self.custom_focus_nodes(validation_dataset, shape))
+                        .contains(&focus_node);
+                    if targeted {
+                        shape.validate_focus_node_with(
+                            validation_dataset,
+                            focus_node,
+                            &mut report,
+                            &cache,
+                        );
+                    }
+                }
+            }
+            reports.insert(node.clone(), report);
+        }
+        reports
+    }
+
+    /// Replaces the description of `shape` in the shapes graph of
+    /// `validation_dataset` with the triples of `definition`, for editors that
+    /// change one shape at a time.
+    ///
+    /// The description is every triple about the shape and the blank nodes it
+    /// reaches, such as its inline property shapes and paths; shapes with an
+    /// IRI that it references are kept. The store is updated in one transaction
+    /// and copied first if clones of the dataset share it, so they keep the old
+    /// definition. Only caches that depend on the shapes graph are reset.
+    ///
+    /// Compiled shapes borrow the shapes graph and have to be compiled again:
+    /// just the updated shape with [`parse_shape`](crate::parser::parse_shape)
+    /// to check the edit, or all shapes with [`parse_shapes`](crate::parse_shapes).
+    pub fn update_shape(
+        &self,
+        validation_dataset: &mut ValidationDataset,
+        shape: NamedNodeRef<'_>,
+        definition: &Graph,
+    ) -> Result<(), ShaclError> {
+        validation_dataset.replace_shape(shape, definition)
+    }
+
+    /// Validates the focus nodes of one shape, unless the run is spent.
+    fn validate_shape<'a>(
+        &self,
+        validation_dataset: &'a ValidationDataset,
+        shape: &'a Shape<'a>,
+        target_cache: &TargetResolutionCache<'a>,
+        limits: &RunLimits<'_>,
+    ) -> ValidationReport<'a> {
+        if !self.options.includes(shape.severity) || limits.is_spent() {
+            return ValidationReport::new();
+        }
+        let mut focus_nodes = shape.focus_nodes(validation_dataset, target_cache);
+        focus_nodes.extend(self.custom_focus_nodes(validation_dataset, shape));
+        shape.validate_focus_nodes(validation_dataset, focus_nodes, Some(limits))
+    }
+
+    /// Adds the dataset constraints and the shapes graph checks to the shape results.
+    fn finish<'a>(
+        &self,
+        validation_dataset: &'a ValidationDataset,
+        shapes: &'a [Arc<Shape<'a>>],
+        mut report: ValidationReport<'a>,
+        limits: &RunLimits<'_>,
+        emit: impl Fn(&ValidationReport<'a>),
+    ) -> ValidationReport<'a> {
+        match parse_dataset_constraints(validation_dataset.shapes_graph()) {
+            Ok(_) if limits.is_spent() => {}
+            Ok(constraints) => {
+                let mut dataset_report = ValidationReport::new();
+                dataset_report.extend_results(validate_dataset_constraints(
+                    validation_dataset,
+                    &constraints,
+                ));
+                limits.apply(&mut dataset_report);
+                emit(&dataset_report);
+                report.merge(dataset_report);
+            }
+            Err(e) => {
+                log::warn!("Skipping dataset constraints: {}", e);
+                report.add_unsupported_features(vec![UnsupportedFeature::new(
+                    dataset::SHAPES_GRAPH_IRI,
+                    shx::DATASET_CONSTRAINT,
+                    format!("Dataset constraints were skipped: {}", e),
+                )]);
+            }
+        }
+
+        // Focus nodes validated in parallel may overshoot the limit
+        if let Some(limit) = self.options.result_limit() {
+            report.truncate_results(limit);
+        }
+        if self
+            .cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+        {
+            report.mark_cancelled();
+        }
+
+        let mut unsupported = find_unsupported_features(validation_dataset.shapes_graph(), shapes);
+        let resolved = self.resolved_custom_targets(validation_dataset, shapes);
+        unsupported.retain(|feature| !resolved.contains(feature));
+        unsupported.extend(find_unregistered_components(
+            validation_dataset.constraint_components(),
+            shapes,
+        ));
+        report.add_unsupported_features(unsupported);
+
+        report.with_well_formedness_issues(validation_dataset.well_formedness_issues().to_vec())
+    }
+
+    /// Custom targets of `shapes` with a registered target type, as they would
+    /// be reported unsupported.
+    fn resolved_custom_targets(
+        &self,
+        validation_dataset: &ValidationDataset,
+        shapes: &[Arc<Shape<'_>>],
+    ) -> HashSet<UnsupportedFeature> {
+        if self.target_types.is_empty() {
+            return HashSet::new();
+        }
+        let mut resolved = HashSet::new();
+        for shape in shapes {
+            for target in &shape.targets {
+                let Target::Advanced(node) = target else {
+                    continue;
+                };
+                if self
+                    .target_types
+                    .resolver_for(validation_dataset, *node)
+                    .is_some()
+                {
+                    resolved.insert(UnsupportedFeature::new(
+                        shape.node,
+                        sh::TARGET,
+                        format!("Custom target {} is not evaluated", node),
+                    ));
+                }
+            }
+        }
+        resolved
+    }
+
+    /// Focus nodes of `shape` returned by its registered target resolvers.
+    fn custom_focus_nodes<'a>(
+        &self,
+        validation_dataset: &'a ValidationDataset,
+        shape: &Shape<'a>,
+    ) -> HashSet<TermRef<'a>> {
+        let NamedOrBlankNodeRef::NamedNode(shape_iri) = shape.node else {
+            return HashSet::new();
+        };
+        let Some(resolvers) = self.target_resolvers.get(&shape_iri.into_owned()) else {
+            return HashSet::new();
+        };
+
+        let mut focus_nodes = HashSet::new();
+        for term in resolvers
+            .iter()
+            .flat_map(|resolver| resolver(validation_dataset.data_graph()))
+        {
+            // Borrow the node from the graphs so results can refer to it.
+            match find_in_graph(validation_dataset.data_graph(), &term)
+                .or_else(|| find_in_graph(validation_dataset.shapes_graph(), &term))
+            {
+                Some(focus_node) => {
+                    focus_nodes.insert(focus_node);
+                }
+                None => log::warn!(
+                    "Skipping custom focus node {} of {}: not found in the data graph",
+                    term,
+                    shape_iri
+                ),
+            }
+        }
+        focus_nodes
+    }
+}
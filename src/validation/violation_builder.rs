@@ -1,6 +1,6 @@
-use oxigraph::model::{NamedNodeRef, TermRef};
+use oxigraph::model::TermRef;
 
-use crate::ValidationResult;
+use crate::{core::component::ConstraintComponent, ValidationResult};
 
 /// Builder for `ValidationResult`.
 #[derive(Debug, Clone)]
@@ -8,7 +8,7 @@ pub struct ViolationBuilder<'a> {
     pub focus_node: TermRef<'a>,
     pub value: Option<TermRef<'a>>,
     pub constraint_messages: Vec<String>,
-    pub constraint_component: Option<NamedNodeRef<'a>>,
+    pub constraint_component: Option<ConstraintComponent<'a>>,
     pub constraint_detail: Option<String>,
     pub trace: Vec<String>,
     pub details: Vec<ValidationResult<'a>>,
@@ -42,7 +42,7 @@ impl<'a> ViolationBuilder<'a> {
         self
     }
 
-    pub fn component(mut self, component: NamedNodeRef<'a>) -> Self {
+    pub fn component(mut self, component: ConstraintComponent<'a>) -> Self {
         self.constraint_component = Some(component);
         self
     }
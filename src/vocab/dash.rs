@@ -0,0 +1,15 @@
+//! DASH vocabulary constants
+//!
+//! Only the DASH terms this crate implements natively are declared here.
+
+use oxigraph::model::NamedNodeRef;
+
+/// Values of the path must not be shared by two instances of the given class.
+pub const UNIQUE_VALUE_FOR_CLASS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://datashapes.org/dash#uniqueValueForClass");
+
+// Unique value for class constraint component
+pub const UNIQUE_VALUE_FOR_CLASS_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://datashapes.org/dash#UniqueValueForClassConstraintComponent",
+    );
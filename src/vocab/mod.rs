@@ -1,4 +1,14 @@
 //! SHACL vocabulary constants.
 
+#[allow(unused)]
+pub mod dash;
+#[allow(unused)]
+pub mod owl;
+#[allow(unused)]
+pub mod qudt;
 #[allow(unused)]
 pub mod sh;
+#[allow(unused)]
+pub mod shx;
+#[allow(unused)]
+pub mod skos;
@@ -0,0 +1,17 @@
+//! OWL vocabulary constants
+//!
+//! Only the terms consulted by the SHACL processor are declared here.
+
+use oxigraph::model::NamedNodeRef;
+
+/// The class of ontologies.
+pub const ONTOLOGY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#Ontology");
+
+/// Links an ontology to another ontology whose declarations it includes.
+pub const IMPORTS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#imports");
+
+/// Version information of an ontology.
+pub const VERSION_INFO: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#versionInfo");
@@ -0,0 +1,30 @@
+//! QUDT vocabulary constants
+//!
+//! Only the QUDT terms needed for unit-aware numeric comparison are declared here.
+
+use oxigraph::model::NamedNodeRef;
+
+/// Namespace of QUDT units, e.g. `unit:M`.
+pub const UNIT_NAMESPACE: &str = "http://qudt.org/vocab/unit/";
+
+/// Namespace of QUDT dimension vectors.
+pub const DIMENSION_VECTOR_NAMESPACE: &str = "http://qudt.org/vocab/dimensionvector/";
+
+/// Number of a quantity value.
+pub const NUMERIC_VALUE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://qudt.org/schema/qudt/numericValue");
+
+/// Unit of a quantity value.
+pub const UNIT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://qudt.org/schema/qudt/unit");
+
+/// Factor converting a unit to the SI unit of its dimension.
+pub const CONVERSION_MULTIPLIER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://qudt.org/schema/qudt/conversionMultiplier");
+
+/// Offset added to values of a unit before applying its multiplier.
+pub const CONVERSION_OFFSET: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://qudt.org/schema/qudt/conversionOffset");
+
+/// Dimension vector of a unit; units are comparable when theirs are equal.
+pub const HAS_DIMENSION_VECTOR: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://qudt.org/schema/qudt/hasDimensionVector");
@@ -5,6 +5,9 @@
 
 use oxigraph::model::NamedNodeRef;
 
+/// The SHACL namespace IRI. `NAMESPACE` is `sh:namespace`.
+pub const SHACL_NAMESPACE: &str = "http://www.w3.org/ns/shacl#";
+
 // Shapes vocabulary -------------------------------------------------------
 
 /// A shape is a collection of constraints that may be targeted for certain nodes.
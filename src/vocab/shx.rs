@@ -0,0 +1,191 @@
+//! Extension vocabulary constants
+//!
+//! Terms used by this processor for information that SHACL itself does not define.
+
+use oxigraph::model::NamedNodeRef;
+
+/// Namespace of the extension vocabulary.
+pub const NAMESPACE: &str = "https://ensaremirerol.github.io/shacl-rust/ns#";
+
+/// Version of the shapes a report was produced with.
+pub const SHAPES_VERSION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#shapesVersion");
+
+/// Location the shapes of a report were loaded from.
+pub const SHAPES_SOURCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#shapesSource");
+
+/// Hash of the shapes graph a report was produced with, see [`crate::rdf::graph_hash`].
+pub const SHAPES_HASH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#shapesHash");
+
+/// Version of shacl-rust a report was produced with.
+pub const VALIDATOR_VERSION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#validatorVersion");
+
+/// A Cargo feature shacl-rust was built with when it produced a report.
+pub const VALIDATOR_FEATURE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#validatorFeature");
+
+/// Identifier of a profile requirement a shape implements.
+pub const REQUIREMENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#requirement");
+
+/// Transformation applied to literal values before facet checks, e.g. `"trim"`.
+pub const VALUE_TRANSFORM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#valueTransform");
+
+/// Source of results produced by the SKOS analysis.
+pub const SKOS_ANALYSIS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#SkosAnalysis");
+
+/// A concept is its own broader concept through a chain of `skos:broader` links.
+pub const HIERARCHY_CYCLE_COMPONENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+    "https://ensaremirerol.github.io/shacl-rust/ns#HierarchyCycleComponent",
+);
+
+/// A concept has no semantic relation to any other concept.
+pub const ORPHAN_CONCEPT_COMPONENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+    "https://ensaremirerol.github.io/shacl-rust/ns#OrphanConceptComponent",
+);
+
+/// A concept lacks a `skos:prefLabel` in an expected language.
+pub const MISSING_PREF_LABEL_COMPONENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+    "https://ensaremirerol.github.io/shacl-rust/ns#MissingPrefLabelComponent",
+);
+
+/// The class of SPARQL checks that run once over the whole data graph.
+pub const DATASET_CONSTRAINT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#DatasetConstraint");
+
+/// Component of results produced by dataset constraints.
+pub const DATASET_CONSTRAINT_COMPONENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+    "https://ensaremirerol.github.io/shacl-rust/ns#DatasetConstraintComponent",
+);
+
+/// Synthetic focus node of results that concern the data graph as a whole.
+pub const DATASET: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#Dataset");
+
+/// Source shape of results produced by the closed-world audit.
+pub const CLOSED_WORLD_AUDIT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#ClosedWorldAudit");
+
+/// A triple uses a predicate no applicable shape mentions.
+pub const UNDECLARED_PROPERTY_COMPONENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+    "https://ensaremirerol.github.io/shacl-rust/ns#UndeclaredPropertyComponent",
+);
+
+/// Maximum number of significant digits of a decimal value.
+pub const TOTAL_DIGITS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#totalDigits");
+
+/// Maximum number of fractional digits of a decimal value.
+pub const FRACTION_DIGITS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#fractionDigits");
+
+/// Component of results produced by `shx:totalDigits`.
+pub const TOTAL_DIGITS_CONSTRAINT_COMPONENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+    "https://ensaremirerol.github.io/shacl-rust/ns#TotalDigitsConstraintComponent",
+);
+
+/// Component of results produced by `shx:fractionDigits`.
+pub const FRACTION_DIGITS_CONSTRAINT_COMPONENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+    "https://ensaremirerol.github.io/shacl-rust/ns#FractionDigitsConstraintComponent",
+);
+
+/// Earliest allowed date or time, in days relative to the reference time of validation.
+pub const MIN_DAYS_FROM_NOW: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#minDaysFromNow");
+
+/// Latest allowed date or time, in days relative to the reference time of validation.
+pub const MAX_DAYS_FROM_NOW: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#maxDaysFromNow");
+
+/// Component of results produced by `shx:minDaysFromNow` and `shx:maxDaysFromNow`.
+pub const RELATIVE_TIME_CONSTRAINT_COMPONENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+    "https://ensaremirerol.github.io/shacl-rust/ns#RelativeTimeConstraintComponent",
+);
+
+/// List of namespaces value IRIs must start with.
+pub const NAMESPACE_IN: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#namespaceIn");
+
+/// URI template value IRIs must match, e.g. `"http://example.org/person/{id}"`.
+pub const URI_TEMPLATE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#uriTemplate");
+
+/// Component of results produced by `shx:namespaceIn`.
+pub const NAMESPACE_IN_CONSTRAINT_COMPONENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+    "https://ensaremirerol.github.io/shacl-rust/ns#NamespaceInConstraintComponent",
+);
+
+/// Component of results produced by `shx:uriTemplate`.
+pub const URI_TEMPLATE_CONSTRAINT_COMPONENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+    "https://ensaremirerol.github.io/shacl-rust/ns#UriTemplateConstraintComponent",
+);
+
+/// Identifier format literal or IRI values must have, e.g. `shx:ISBN`.
+pub const IDENTIFIER_FORMAT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#identifierFormat");
+
+/// UUIDs such as `123e4567-e89b-12d3-a456-426614174000`, optionally as `urn:uuid:` URNs.
+pub const UUID: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#UUID");
+
+/// ISBN-10 or ISBN-13 numbers with a valid check digit.
+pub const ISBN: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#ISBN");
+
+/// ORCID iDs with a valid check digit, optionally as `https://orcid.org/` IRIs.
+pub const ORCID: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#ORCID");
+
+/// DOIs such as `10.1000/182`, optionally prefixed with `doi:` or `https://doi.org/`.
+pub const DOI: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#DOI");
+
+/// Component of results produced by `shx:identifierFormat`.
+pub const IDENTIFIER_FORMAT_CONSTRAINT_COMPONENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked(
+    "https://ensaremirerol.github.io/shacl-rust/ns#IdentifierFormatConstraintComponent",
+);
+
+/// QUDT unit the numeric range facets of a shape are expressed in.
+pub const UNIT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#unit");
+
+/// Class of unit tests of shapes, run with [`run_shape_tests`](crate::shape_tests::run_shape_tests).
+pub const SHAPE_TEST: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#ShapeTest");
+
+/// Turtle data snippet a shape test validates.
+pub const DATA: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#data");
+
+/// Whether the data of a shape test should conform.
+pub const CONFORMS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#conforms");
+
+/// Validation result a shape test expects, described with `sh:` result properties.
+pub const EXPECTED_RESULT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#expectedResult");
+
+/// Whether a validation run was cancelled before every focus node was checked.
+pub const CANCELLED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#cancelled");
+
+/// Links a validation report to the number of focus nodes a shape was checked on.
+pub const COVERAGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#coverage");
+
+/// Shape of a report coverage entry.
+pub const SHAPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#shape");
+
+/// Shape whose focus node count a validation result falls under.
+pub const COUNTED_SHAPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#countedShape");
+
+/// Number of focus nodes of a report coverage entry.
+pub const FOCUS_NODE_COUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://ensaremirerol.github.io/shacl-rust/ns#focusNodeCount");
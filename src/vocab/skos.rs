@@ -0,0 +1,38 @@
+//! SKOS vocabulary constants
+//!
+//! Only the terms consulted by the SKOS analysis and the shape documentation
+//! are declared here.
+
+use oxigraph::model::NamedNodeRef;
+
+/// The class of concepts.
+pub const CONCEPT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#Concept");
+
+/// The preferred lexical label of a resource.
+pub const PREF_LABEL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#prefLabel");
+
+/// A statement of the meaning of a resource.
+pub const DEFINITION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#definition");
+
+/// Relates a concept to a more general concept.
+pub const BROADER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#broader");
+
+/// Relates a concept to a more specific concept.
+pub const NARROWER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#narrower");
+
+/// Relates two concepts that are associatively related.
+pub const RELATED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#related");
+
+/// Relates a concept to the scheme it is a top concept of.
+pub const TOP_CONCEPT_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#topConceptOf");
+
+/// Relates a scheme to one of its top concepts.
+pub const HAS_TOP_CONCEPT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#hasTopConcept");
@@ -0,0 +1,132 @@
+use shacl_rust::analysis::closed_world::{parse_allowlist, ClosedWorldAudit};
+use shacl_rust::analysis::skos::SkosAnalysis;
+use shacl_rust::parse_shapes;
+use shacl_rust::rdf::read_graph_from_string;
+
+const VOCABULARY: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+
+    ex:Scheme a skos:ConceptScheme ; skos:hasTopConcept ex:Animal .
+    ex:Animal a skos:Concept ; skos:prefLabel "Animal"@en, "Tier"@de .
+    ex:Cat a skos:Concept ; skos:prefLabel "Cat"@en ; skos:broader ex:Mammal .
+    ex:Mammal a skos:Concept ; skos:prefLabel "Mammal"@en, "Säugetier"@de ; skos:broader ex:Cat .
+    ex:Rock a skos:Concept ; skos:prefLabel "Rock"@en, "Stein"@de .
+"#;
+
+fn analyze(analysis: SkosAnalysis) -> Vec<(String, String)> {
+    let graph = read_graph_from_string(VOCABULARY, "turtle").unwrap();
+    let report = analysis.analyze(&graph);
+    assert!(!report.get_conforms());
+
+    let mut results: Vec<_> = report
+        .get_results()
+        .iter()
+        .map(|r| {
+            let json = r.as_json();
+            (
+                json["focusNode"].as_str().unwrap().to_string(),
+                json["sourceConstraintComponent"]
+                    .as_str()
+                    .unwrap()
+                    .rsplit('#')
+                    .next()
+                    .unwrap()
+                    .trim_end_matches('>')
+                    .to_string(),
+            )
+        })
+        .collect();
+    results.sort();
+    results
+}
+
+#[test]
+fn test_skos_analysis_reports_cycles_orphans_and_labels() {
+    let results = analyze(SkosAnalysis::new());
+
+    assert_eq!(
+        results,
+        vec![
+            (
+                "<http://example.org/Cat>".to_string(),
+                "HierarchyCycleComponent".to_string()
+            ),
+            (
+                "<http://example.org/Cat>".to_string(),
+                "MissingPrefLabelComponent".to_string()
+            ),
+            (
+                "<http://example.org/Mammal>".to_string(),
+                "HierarchyCycleComponent".to_string()
+            ),
+            (
+                "<http://example.org/Rock>".to_string(),
+                "OrphanConceptComponent".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_skos_analysis_uses_configured_languages() {
+    let results = analyze(SkosAnalysis::new().with_languages(vec!["en".to_string()]));
+
+    assert!(!results
+        .iter()
+        .any(|(_, component)| component == "MissingPrefLabelComponent"));
+}
+
+const CLOSED_WORLD_SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:name ] ;
+        sh:node [ sh:property [ sh:path ex:email ] ] .
+"#;
+
+const CLOSED_WORLD_DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+    ex:Alice a ex:Person ;
+        ex:name "Alice" ;
+        ex:email "alice@example.org" ;
+        ex:shoeSize 38 ;
+        rdfs:label "Alice" .
+    ex:Thing ex:anything "not targeted" .
+"#;
+
+fn audit(audit: ClosedWorldAudit) -> Vec<String> {
+    let data = read_graph_from_string(CLOSED_WORLD_DATA, "turtle").unwrap();
+    let shapes_graph = read_graph_from_string(CLOSED_WORLD_SHAPES, "turtle").unwrap();
+    let shapes = parse_shapes(&shapes_graph).unwrap();
+
+    audit
+        .audit(&data, &shapes)
+        .get_results()
+        .iter()
+        .map(|r| r.as_json()["resultPath"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_closed_world_audit_reports_undeclared_predicates() {
+    assert_eq!(
+        audit(ClosedWorldAudit::new()),
+        vec![
+            "<http://example.org/shoeSize>",
+            "<http://www.w3.org/2000/01/rdf-schema#label>"
+        ]
+    );
+}
+
+#[test]
+fn test_closed_world_audit_allowlist() {
+    let allowlist = parse_allowlist(
+        "# annotations are fine everywhere\n<http://www.w3.org/2000/01/rdf-schema#>*\n\nhttp://example.org/shoeSize # legacy\n",
+    );
+    assert!(audit(ClosedWorldAudit::new().with_allowlist(allowlist)).is_empty());
+}
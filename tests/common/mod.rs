@@ -0,0 +1,30 @@
+//! Fixtures shared by the integration tests.
+#![allow(dead_code)]
+
+use shacl_rust::rdf::read_graph_from_string;
+use shacl_rust::validation::dataset::ValidationDataset;
+use shacl_rust::{parse_shapes, validate, ValidationReport};
+
+/// A dataset of the Turtle `data` and `shapes`.
+pub fn dataset(shapes: &str, data: &str) -> ValidationDataset {
+    let data_graph = read_graph_from_string(data, "turtle").expect("Failed to read data");
+    let shapes_graph = read_graph_from_string(shapes, "turtle").expect("Failed to read shapes");
+    ValidationDataset::from_graphs(data_graph, shapes_graph).unwrap()
+}
+
+/// The JSON results of `report`.
+pub fn results(report: &ValidationReport<'_>) -> Vec<serde_json::Value> {
+    report.get_results().iter().map(|r| r.as_json()).collect()
+}
+
+/// Validates `dataset` against its shapes graph and returns the JSON results.
+pub fn validate_dataset(dataset: &ValidationDataset) -> Vec<serde_json::Value> {
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    results(&validate(dataset, &shapes))
+}
+
+/// Validates the Turtle `data` against the Turtle `shapes` and returns the
+/// JSON results.
+pub fn validate_data(shapes: &str, data: &str) -> Vec<serde_json::Value> {
+    validate_dataset(&dataset(shapes, data))
+}
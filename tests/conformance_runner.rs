@@ -0,0 +1,205 @@
+mod common;
+
+use common::dataset;
+use shacl_rust::conformance::{run_test_suite, ExpectedOutcome, TestCase, TEST_IRI_BASE};
+use shacl_rust::validation::config::{PathLimits, ValidationConfig};
+use shacl_rust::{parser, validation};
+use std::path::{Path, PathBuf};
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path [ sh:oneOrMorePath ex:knows ] ; sh:minCount 1 ] .
+"#;
+
+const CONFORMING: &str = r#"
+    @prefix ex: <http://example.org/> .
+    ex:alice a ex:Person ; ex:knows ex:bob .
+"#;
+
+const NON_CONFORMING: &str = r#"
+    @prefix ex: <http://example.org/> .
+    ex:alice a ex:Person .
+"#;
+
+const MANIFEST: &str = r#"
+    @prefix mf: <http://www.w3.org/2001/sw/DataAccess/tests/test-manifest#> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix sht: <http://www.w3.org/ns/shacl-test#> .
+    @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+    <> a mf:Manifest ;
+        mf:include <nested/manifest.ttl> ;
+        mf:entries ( <#conforms> <#violates> <#fails> <#proposed> ) .
+
+    <#conforms> a sht:Validate ;
+        rdfs:label "conforms" ;
+        mf:action [ sht:dataGraph <conforming.ttl> ; sht:shapesGraph <shapes.ttl> ] ;
+        mf:result [ a sh:ValidationReport ; sh:conforms true ] ;
+        mf:status sht:approved .
+
+    <#violates> a sht:Validate ;
+        rdfs:label "violates" ;
+        mf:action [ sht:dataGraph <non-conforming.ttl> ; sht:shapesGraph <shapes.ttl> ] ;
+        mf:result [ a sh:ValidationReport ; sh:conforms false ] ;
+        mf:status sht:approved .
+
+    <#fails> a sht:Validate ;
+        rdfs:label "fails" ;
+        mf:action [ sht:dataGraph <non-conforming.ttl> ; sht:shapesGraph <shapes.ttl> ] ;
+        mf:result sht:Failure ;
+        mf:status sht:approved .
+
+    <#proposed> a sht:Validate ;
+        mf:action [ sht:dataGraph <conforming.ttl> ; sht:shapesGraph <shapes.ttl> ] ;
+        mf:result [ a sh:ValidationReport ; sh:conforms true ] ;
+        mf:status sht:proposed .
+"#;
+
+const NESTED_MANIFEST: &str = r#"
+    @prefix mf: <http://www.w3.org/2001/sw/DataAccess/tests/test-manifest#> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix sht: <http://www.w3.org/ns/shacl-test#> .
+    @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+    <> a mf:Manifest ;
+        mf:entries ( <#nested> ) .
+
+    <#nested> a sht:Validate ;
+        rdfs:label "nested" ;
+        mf:action [ sht:dataGraph <../conforming.ttl> ; sht:shapesGraph <../shapes.ttl> ] ;
+        mf:result [ a sh:ValidationReport ; sh:conforms true ] ;
+        mf:status sht:approved .
+"#;
+
+fn test_case(expected_outcome: ExpectedOutcome) -> TestCase {
+    TestCase {
+        uri: "urn:test".to_string(),
+        label: None,
+        data_graph_file: PathBuf::new(),
+        shapes_graph_file: PathBuf::new(),
+        expected_outcome,
+    }
+}
+
+fn outcome(expected: ExpectedOutcome, data: &str, config: ValidationConfig) -> bool {
+    let dataset = dataset(SHAPES, data).with_config(config);
+    let shapes = parser::parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validation::validate(&dataset, &shapes);
+    test_case(expected).outcome_for_report(&report).0
+}
+
+#[test]
+fn test_shared_runner_passes_the_shacl_test_suite() {
+    let resources_dir = Path::new("tests/resources");
+    let outcomes = run_test_suite(resources_dir).unwrap();
+    assert!(!outcomes.is_empty(), "No test cases found!");
+
+    let failed: Vec<_> = outcomes.iter().filter(|outcome| !outcome.passed).collect();
+    for outcome in &failed {
+        println!(
+            "❌ FAIL: {} ({})",
+            outcome.label.as_deref().unwrap_or(&outcome.test),
+            outcome.info
+        );
+    }
+    println!(
+        "Results: {} passed, {} failed",
+        outcomes.len() - failed.len(),
+        failed.len()
+    );
+
+    assert!(failed.is_empty(), "Some SHACL tests failed");
+}
+
+#[test]
+fn test_reports_only_satisfy_their_expected_conformance() {
+    let config = ValidationConfig::default;
+    assert!(outcome(
+        ExpectedOutcome::Conforms(true),
+        CONFORMING,
+        config()
+    ));
+    assert!(!outcome(
+        ExpectedOutcome::Conforms(true),
+        NON_CONFORMING,
+        config()
+    ));
+    assert!(outcome(
+        ExpectedOutcome::Conforms(false),
+        NON_CONFORMING,
+        config()
+    ));
+
+    // A non-conforming report is not the failure the test expects.
+    assert!(!outcome(ExpectedOutcome::Failure, NON_CONFORMING, config()));
+    assert!(!outcome(ExpectedOutcome::Failure, CONFORMING, config()));
+}
+
+#[test]
+fn test_errors_and_report_failures_satisfy_an_expected_failure() {
+    assert!(
+        test_case(ExpectedOutcome::Failure)
+            .outcome_for_error("boom".to_string())
+            .0
+    );
+    assert!(
+        !test_case(ExpectedOutcome::Conforms(true))
+            .outcome_for_error("boom".to_string())
+            .0
+    );
+
+    let limited = || ValidationConfig {
+        path_limits: PathLimits {
+            max_visited_nodes: None,
+            max_depth: Some(0),
+        },
+        ..ValidationConfig::default()
+    };
+    assert!(outcome(ExpectedOutcome::Failure, CONFORMING, limited()));
+    assert!(!outcome(
+        ExpectedOutcome::Conforms(true),
+        CONFORMING,
+        limited()
+    ));
+}
+
+#[test]
+fn test_manifest_entries_are_run_with_their_expected_outcome() {
+    let dir = std::env::temp_dir().join(format!("shacl-conformance-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("nested")).unwrap();
+    for (file, content) in [
+        ("manifest.ttl", MANIFEST),
+        ("nested/manifest.ttl", NESTED_MANIFEST),
+        ("shapes.ttl", SHAPES),
+        ("conforming.ttl", CONFORMING),
+        ("non-conforming.ttl", NON_CONFORMING),
+    ] {
+        std::fs::write(dir.join(file), content).unwrap();
+    }
+
+    let outcomes = run_test_suite(&dir).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    // The proposed entry is skipped; the expected failure gets a report; the
+    // included manifest resolves its relative references against its own file.
+    let results: Vec<(&str, bool)> = outcomes
+        .iter()
+        .map(|outcome| (outcome.label.as_deref().unwrap(), outcome.passed))
+        .collect();
+    assert_eq!(
+        results,
+        vec![
+            ("conforms", true),
+            ("fails", false),
+            ("violates", true),
+            ("nested", true)
+        ]
+    );
+    assert!(outcomes
+        .iter()
+        .all(|outcome| outcome.test.starts_with(TEST_IRI_BASE)));
+}
@@ -0,0 +1,64 @@
+use shacl_rust::parser::contradictions::find_contradictions;
+use shacl_rust::parser::well_formed::check_well_formed;
+use shacl_rust::rdf::read_graph_from_string;
+use shacl_rust::sh;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property ex:NameShape , ex:AgeShape , ex:StatusShape , ex:FriendShape , ex:ValidShape .
+
+    ex:NameShape sh:path ex:name ; sh:minCount 2 ; sh:maxCount 1 .
+    ex:AgeShape sh:path ex:age ; sh:minInclusive 18 ; sh:maxExclusive 18 .
+    ex:StatusShape sh:path ex:status ; sh:hasValue ex:Active ; sh:in ( ex:Retired ) .
+    ex:FriendShape sh:path ex:friend ; sh:datatype xsd:string ; sh:nodeKind sh:IRI .
+    ex:ValidShape sh:path ex:born ;
+        sh:minInclusive "1900-01-01"^^xsd:date ; sh:maxInclusive "1900-01-01"^^xsd:date ;
+        sh:minCount 1 ; sh:maxCount 1 .
+"#;
+
+#[test]
+fn test_contradicting_constraints_are_found() {
+    let graph = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let contradictions: Vec<_> = find_contradictions(&graph)
+        .into_iter()
+        .map(|c| (c.node, c.predicate))
+        .collect();
+
+    let ex = |name: &str| format!("<http://example.org/{}>", name);
+    assert_eq!(
+        contradictions,
+        vec![
+            (ex("AgeShape"), sh::MIN_INCLUSIVE.to_string()),
+            (ex("FriendShape"), sh::DATATYPE.to_string()),
+            (ex("NameShape"), sh::MIN_COUNT.to_string()),
+            (ex("StatusShape"), sh::HAS_VALUE.to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_well_formedness_issues_name_the_parameter() {
+    let graph = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:S a sh:NodeShape ; sh:targetClass ex:T ;
+            sh:property [ sh:path ex:p ; sh:minCount "one" ] .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+    let issues = check_well_formed(&graph);
+
+    let issue = issues
+        .iter()
+        .find(|issue| issue.predicate == Some(sh::MIN_COUNT.to_string()))
+        .unwrap();
+    assert!(issue.suggestion.as_ref().unwrap().contains("xsd:integer"));
+}
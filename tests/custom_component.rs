@@ -0,0 +1,120 @@
+mod common;
+
+use common::dataset;
+use oxigraph::model::{NamedNode, NamedNodeRef, TermRef};
+use shacl_rust::core::constraints::{Constraint, CustomConstraint};
+use shacl_rust::rdf::read_graph_from_string;
+use shacl_rust::validation::components::{
+    ComponentViolation, ConstraintComponentRegistry, ConstraintValidator,
+};
+use shacl_rust::validation::dataset::ValidationDataset;
+use shacl_rust::{parse_shapes, validate};
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:MaxWordsConstraintComponent a sh:ConstraintComponent ;
+        sh:parameter [ sh:path ex:maxWords ; sh:datatype xsd:integer ] .
+
+    ex:TitleShape a sh:NodeShape ;
+        sh:targetClass ex:Book ;
+        sh:property [ sh:path ex:title ; ex:maxWords 3 ] .
+"#;
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+
+    ex:short a ex:Book ; ex:title "Dune" .
+    ex:long a ex:Book ; ex:title "The Hitchhiker's Guide to the Galaxy" .
+"#;
+
+struct MaxWords;
+
+impl ConstraintValidator for MaxWords {
+    fn validate(
+        &self,
+        _dataset: &ValidationDataset,
+        constraint: &CustomConstraint<'_>,
+        _focus_node: TermRef<'_>,
+        value_nodes: &[TermRef<'_>],
+    ) -> Vec<ComponentViolation> {
+        let max_words = match constraint
+            .parameter(NamedNodeRef::new_unchecked("http://example.org/maxWords"))
+        {
+            Some(TermRef::Literal(max_words)) => max_words.value().parse::<usize>().unwrap(),
+            _ => return Vec::new(),
+        };
+        value_nodes
+            .iter()
+            .filter_map(|value| match value {
+                TermRef::Literal(title) if title.value().split_whitespace().count() > max_words => {
+                    Some(
+                        ComponentViolation::new(format!("More than {} words", max_words))
+                            .with_value(value.into_owned()),
+                    )
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn component() -> NamedNode {
+    NamedNode::new("http://example.org/MaxWordsConstraintComponent").unwrap()
+}
+
+#[test]
+fn test_component_without_validator_parses_to_custom_constraint() {
+    let shapes_graph = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let shapes = parse_shapes(&shapes_graph).unwrap();
+
+    let constraint = shapes[0].property_shapes[0]
+        .constraints
+        .iter()
+        .find_map(|constraint| match constraint {
+            Constraint::Custom(c) => Some(c),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(constraint.component, component().as_ref());
+    assert_eq!(constraint.parameter_bindings.len(), 1);
+    assert!(matches!(
+        constraint.parameter(NamedNodeRef::new_unchecked("http://example.org/maxWords")),
+        Some(TermRef::Literal(max_words)) if max_words.value() == "3"
+    ));
+}
+
+#[test]
+fn test_registered_validator_reports_violations() {
+    let mut registry = ConstraintComponentRegistry::new();
+    registry.register(component(), Box::new(MaxWords));
+    let dataset = dataset(SHAPES, DATA).with_constraint_components(registry);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+
+    let report = validate(&dataset, &shapes);
+    let results = report.get_results();
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].get_focus_node().to_string(),
+        "<http://example.org/long>"
+    );
+    assert_eq!(
+        report.as_json()["results"][0]["sourceConstraintComponent"],
+        "<http://example.org/MaxWordsConstraintComponent>"
+    );
+    assert!(report.get_unsupported_features().is_empty());
+}
+
+#[test]
+fn test_unregistered_component_is_reported_unsupported() {
+    let dataset = dataset(SHAPES, DATA);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+
+    let report = validate(&dataset, &shapes);
+    assert!(*report.get_conforms());
+    let features = report.get_unsupported_features();
+    assert_eq!(features.len(), 1);
+    assert_eq!(features[0].feature, component().to_string());
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use common::{dataset, validate_data};
+use shacl_rust::{
+    parser::parse_shapes,
+    validation::{
+        config::{Entailment, PathLimits, ValidationConfig},
+        validate,
+    },
+};
+
+const SHAPES: &str = r#"
+    @prefix dash: <http://datashapes.org/dash#> .
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:email ; dash:uniqueValueForClass ex:Person ] .
+"#;
+
+#[test]
+fn test_unique_value_for_class_reports_shared_values() {
+    let results = validate_data(
+        SHAPES,
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+        ex:Employee rdfs:subClassOf ex:Person .
+        ex:Alice a ex:Person ; ex:email "alice@example.org" .
+        ex:Bob a ex:Employee ; ex:email "alice@example.org" .
+        ex:Carol a ex:Person ; ex:email "carol@example.org" .
+        "#,
+    );
+
+    let mut focus_nodes: Vec<_> = results
+        .iter()
+        .map(|r| r["focusNode"].as_str().unwrap().to_string())
+        .collect();
+    focus_nodes.sort();
+
+    assert_eq!(
+        focus_nodes,
+        vec!["<http://example.org/Alice>", "<http://example.org/Bob>"]
+    );
+    assert!(results.iter().all(|r| r["sourceConstraintComponent"]
+        .as_str()
+        .unwrap()
+        .contains("UniqueValueForClassConstraintComponent")));
+}
+
+#[test]
+fn test_unique_value_for_class_ignores_values_of_other_classes() {
+    let results = validate_data(
+        SHAPES,
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person ; ex:email "shared@example.org" .
+        ex:TeamInbox a ex:Group ; ex:email "shared@example.org" .
+        "#,
+    );
+
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_unique_value_for_class_follows_entailment() {
+    let dataset = dataset(
+        SHAPES,
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+        ex:Employee rdfs:subClassOf ex:Person .
+        ex:Alice a ex:Person ; ex:email "shared@example.org" .
+        ex:Bob a ex:Employee ; ex:email "shared@example.org" .
+        "#,
+    )
+    .with_config(ValidationConfig {
+        entailment: Entailment::None,
+        ..ValidationConfig::default()
+    });
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+
+    assert!(validate(&dataset, &shapes).get_conforms());
+}
+
+#[test]
+fn test_unique_value_for_class_reports_path_limit_failures() {
+    let shapes = r#"
+        @prefix dash: <http://datashapes.org/dash#> .
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetNode ex:Alice ;
+            sh:property [
+                sh:path [ sh:oneOrMorePath ex:knows ] ;
+                dash:uniqueValueForClass ex:Person ;
+            ] .
+    "#;
+    let dataset = dataset(
+        shapes,
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person .
+        ex:Bob a ex:Person ; ex:knows ex:Carol .
+        ex:Carol ex:knows ex:Dave .
+        ex:Dave ex:knows ex:Erin .
+        "#,
+    )
+    .with_config(ValidationConfig {
+        path_limits: PathLimits {
+            max_visited_nodes: None,
+            max_depth: Some(1),
+        },
+        ..ValidationConfig::default()
+    });
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+
+    assert!(report.get_results().is_empty());
+    assert_eq!(report.get_failures().len(), 1);
+    assert!(!report.get_conforms());
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use common::validate_data;
+use oxigraph::model::{vocab::xsd, Literal, NamedNodeRef};
+use shacl_rust::utils::is_valid_lexical_form;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:ItemShape a sh:NodeShape ;
+        sh:targetClass ex:Item ;
+        sh:property [ sh:path ex:count ; sh:datatype xsd:integer ] ;
+        sh:property [ sh:path ex:released ; sh:datatype xsd:date ] .
+"#;
+
+#[test]
+fn test_datatype_rejects_ill_formed_literals() {
+    let data = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:good a ex:Item ; ex:count 3 ; ex:released "2024-02-29"^^xsd:date .
+        ex:bad a ex:Item ; ex:count "abc"^^xsd:integer ; ex:released "2023-02-29"^^xsd:date .
+    "#;
+    let mut messages: Vec<_> = validate_data(SHAPES, data)
+        .iter()
+        .map(|result| {
+            assert_eq!(result["focusNode"], "<http://example.org/bad>");
+            result["messages"][0].as_str().unwrap().to_string()
+        })
+        .collect();
+    messages.sort();
+    assert_eq!(
+        messages,
+        vec![
+            "Value \"2023-02-29\" is not a valid lexical form of <http://www.w3.org/2001/XMLSchema#date>",
+            "Value \"abc\" is not a valid lexical form of <http://www.w3.org/2001/XMLSchema#integer>",
+        ]
+    );
+}
+
+#[test]
+fn test_lexical_forms_of_common_datatypes() {
+    let valid = |value: &str, datatype: NamedNodeRef<'_>| {
+        is_valid_lexical_form(Literal::new_typed_literal(value, datatype).as_ref())
+    };
+
+    assert!(valid("-42", xsd::INTEGER));
+    assert!(valid("+0127", xsd::BYTE));
+    assert!(!valid("128", xsd::BYTE));
+    assert!(!valid("-1", xsd::NON_NEGATIVE_INTEGER));
+    assert!(!valid("1.0", xsd::INTEGER));
+    assert!(valid(
+        "99999999999999999999999999999999999999999",
+        xsd::INTEGER
+    ));
+    assert!(valid("1.5", xsd::DECIMAL));
+    assert!(!valid("1e5", xsd::DECIMAL));
+    assert!(valid("1e5", xsd::DOUBLE));
+    assert!(valid("-INF", xsd::FLOAT));
+    assert!(!valid("infinity", xsd::DOUBLE));
+    assert!(valid("1", xsd::BOOLEAN));
+    assert!(!valid("yes", xsd::BOOLEAN));
+    assert!(valid("2024-01-01T10:00:00", xsd::DATE_TIME));
+    assert!(!valid("2024-01-01T10:00:00", xsd::DATE_TIME_STAMP));
+    assert!(valid("P1Y2M", xsd::YEAR_MONTH_DURATION));
+    assert!(!valid("P1D", xsd::YEAR_MONTH_DURATION));
+    assert!(valid("anything", xsd::STRING));
+}
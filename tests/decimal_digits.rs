@@ -0,0 +1,81 @@
+mod common;
+
+use common::validate_data;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:PaymentShape a sh:NodeShape ;
+        sh:targetClass ex:Payment ;
+        sh:property [ sh:path ex:amount ; shx:totalDigits 5 ; shx:fractionDigits 2 ] ;
+        sh:property [ sh:path ex:rate ; xsd:fractionDigits 1 ] .
+"#;
+
+fn components(results: &[serde_json::Value]) -> Vec<String> {
+    let mut components: Vec<_> = results
+        .iter()
+        .map(|r| r["sourceConstraintComponent"].as_str().unwrap().to_string())
+        .collect();
+    components.sort();
+    components
+}
+
+#[test]
+fn test_decimal_digits_accept_values_within_limits() {
+    let results = validate_data(
+        SHAPES,
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:a a ex:Payment ; ex:amount 123.45 , "00100.500"^^xsd:decimal , -7 ; ex:rate 0.5 .
+        "#,
+    );
+
+    assert!(results.is_empty(), "{:?}", results);
+}
+
+#[test]
+fn test_decimal_digits_report_excess_precision() {
+    let results = validate_data(
+        SHAPES,
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:a a ex:Payment ; ex:amount 1234.56 ; ex:rate 0.25 .
+        "#,
+    );
+
+    assert_eq!(
+        components(&results),
+        vec![
+            "<https://ensaremirerol.github.io/shacl-rust/ns#FractionDigitsConstraintComponent>",
+            "<https://ensaremirerol.github.io/shacl-rust/ns#TotalDigitsConstraintComponent>",
+        ]
+    );
+    assert!(results
+        .iter()
+        .all(|r| r["focusNode"] == "<http://example.org/a>"));
+}
+
+#[test]
+fn test_decimal_digits_reject_non_decimal_values() {
+    let results = validate_data(
+        SHAPES,
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:a a ex:Payment ; ex:amount "12.5" , 1.5e2 .
+        "#,
+    );
+
+    assert_eq!(results.len(), 4);
+    assert!(results.iter().all(|r| r["messages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|m| m == "Value is not a decimal")));
+}
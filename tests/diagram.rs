@@ -0,0 +1,58 @@
+use shacl_rust::diagram::{shapes_diagram, DiagramFormat};
+use shacl_rust::{parse_shapes, rdf::read_graph_from_string};
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:name ; sh:datatype xsd:string ; sh:minCount 1 ; sh:maxCount 1 ] ;
+        sh:property [ sh:path ex:knows ; sh:class ex:Person ] ;
+        sh:property [ sh:path ex:address ; sh:node ex:AddressShape ; sh:maxCount 2 ] .
+
+    ex:AddressShape a sh:NodeShape ;
+        sh:property [ sh:path ex:city ; sh:datatype xsd:string ] .
+"#;
+
+#[test]
+fn test_plantuml_diagram_has_classes_attributes_and_associations() {
+    let graph = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let shapes = parse_shapes(&graph).unwrap();
+
+    assert_eq!(
+        shapes_diagram(&shapes, DiagramFormat::PlantUml),
+        r#"@startuml
+class AddressShape {
+  city : xsd:string [0..*]
+}
+class PersonShape {
+  name : xsd:string [1..1]
+}
+PersonShape --> "0..2" AddressShape : address
+PersonShape --> "0..*" PersonShape : knows
+@enduml
+"#
+    );
+}
+
+#[test]
+fn test_mermaid_diagram_has_classes_attributes_and_associations() {
+    let graph = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let shapes = parse_shapes(&graph).unwrap();
+
+    assert_eq!(
+        shapes_diagram(&shapes, DiagramFormat::Mermaid),
+        r#"classDiagram
+    class AddressShape {
+        +city : xsd:string [0..*]
+    }
+    class PersonShape {
+        +name : xsd:string [1..1]
+    }
+    PersonShape --> "0..2" AddressShape : address
+    PersonShape --> "0..*" PersonShape : knows
+"#
+    );
+}
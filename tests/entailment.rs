@@ -0,0 +1,115 @@
+mod common;
+
+use common::{dataset, validate_dataset};
+use oxigraph::model::NamedNode;
+use shacl_rust::validation::config::{Entailment, ValidationConfig};
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:Dog rdfs:subClassOf ex:Animal .
+
+    ex:AnimalShape a sh:NodeShape ;
+        sh:targetClass ex:Animal ;
+        sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+
+    ex:OwnerShape a sh:NodeShape ;
+        sh:targetClass ex:Owner ;
+        sh:property [ sh:path ex:pet ; sh:class ex:Animal ] .
+"#;
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+    ex:Cat rdfs:subClassOf ex:Animal .
+
+    ex:alice a ex:Owner ; ex:pet ex:tom , ex:rex .
+    ex:tom a ex:Cat .
+    ex:rex a ex:Dog .
+"#;
+
+/// Focus nodes of the results: missing names and pets that are not animals.
+fn results(entailment: Entailment) -> Vec<(String, String)> {
+    results_with(
+        DATA,
+        ValidationConfig {
+            entailment,
+            ..ValidationConfig::default()
+        },
+    )
+}
+
+fn results_with(data: &str, config: ValidationConfig) -> Vec<(String, String)> {
+    let mut results: Vec<_> = validate_dataset(&dataset(SHAPES, data).with_config(config))
+        .iter()
+        .map(|result| {
+            (
+                result["focusNode"].as_str().unwrap().to_string(),
+                result["value"].as_str().unwrap_or_default().to_string(),
+            )
+        })
+        .collect();
+    results.sort();
+    results
+}
+
+fn ex(name: &str) -> String {
+    format!("<http://example.org/{}>", name)
+}
+
+#[test]
+fn test_data_graph_subclasses_by_default() {
+    assert_eq!(
+        results(Entailment::default()),
+        [(ex("alice"), ex("rex")), (ex("tom"), String::new())]
+    );
+}
+
+#[test]
+fn test_shapes_graph_subclasses_with_data_and_shapes() {
+    assert_eq!(
+        results(Entailment::DataAndShapes),
+        [(ex("rex"), String::new()), (ex("tom"), String::new())]
+    );
+}
+
+#[test]
+fn test_no_subclasses_without_entailment() {
+    assert_eq!(
+        results(Entailment::None),
+        [(ex("alice"), ex("rex")), (ex("alice"), ex("tom"))]
+    );
+}
+
+#[test]
+fn test_type_predicates_count_as_rdf_type() {
+    let data = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix wdt: <http://www.wikidata.org/prop/direct/> .
+
+        ex:Cat rdfs:subClassOf ex:Animal .
+
+        ex:alice wdt:P31 ex:Owner ; ex:pet ex:tom , ex:rex .
+        ex:tom wdt:P31 ex:Cat .
+        ex:rex a ex:Dog .
+    "#;
+
+    // Without the predicate neither alice nor tom is an instance of anything.
+    assert!(results_with(data, ValidationConfig::default()).is_empty());
+    assert_eq!(
+        results_with(
+            data,
+            ValidationConfig {
+                type_predicates: vec![NamedNode::new_unchecked(
+                    "http://www.wikidata.org/prop/direct/P31"
+                )],
+                ..ValidationConfig::default()
+            },
+        ),
+        [(ex("alice"), ex("rex")), (ex("tom"), String::new())]
+    );
+}
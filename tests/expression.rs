@@ -0,0 +1,122 @@
+mod common;
+
+use common::{dataset, validate_data};
+use oxigraph::model::NamedNode;
+use shacl_rust::parser::expression::parse_node_expression;
+use shacl_rust::sh;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:ActiveShape a sh:NodeShape ;
+        sh:targetClass ex:Account ;
+        sh:expression [ sh:path ex:active ] .
+
+    ex:ApprovedShape a sh:NodeShape ;
+        sh:targetClass ex:Request ;
+        sh:expression [
+            sh:path ex:approved ;
+            sh:nodes [ sh:filterShape ex:ManagerShape ; sh:nodes [ sh:path ex:reviewer ] ] ;
+        ] .
+
+    ex:ManagerShape a sh:NodeShape ;
+        sh:property [ sh:path ex:role ; sh:hasValue ex:Manager ] .
+
+    ex:BothShape a sh:NodeShape ;
+        sh:targetClass ex:Both ;
+        sh:expression [ sh:intersection ( [ sh:path ex:a ] [ sh:path ex:b ] ) ] .
+
+    ex:EitherShape a sh:NodeShape ;
+        sh:targetClass ex:Either ;
+        sh:expression [ sh:union ( [ sh:path ex:a ] [ sh:path ex:b ] ) ] .
+"#;
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+
+    ex:open a ex:Account ; ex:active true .
+    ex:closed a ex:Account ; ex:active false .
+    ex:unknown a ex:Account .
+
+    ex:manager ex:role ex:Manager ; ex:approved true .
+    ex:intern ex:role ex:Intern ; ex:approved true .
+    ex:managerRequest a ex:Request ; ex:reviewer ex:manager .
+    ex:internRequest a ex:Request ; ex:reviewer ex:intern .
+
+    ex:both a ex:Both, ex:Either ; ex:a true ; ex:b true .
+    ex:onlyA a ex:Both, ex:Either ; ex:a true ; ex:b false .
+"#;
+
+fn ex(name: &str) -> String {
+    format!("<http://example.org/{}>", name)
+}
+
+#[test]
+fn test_expression_constraint_requires_true() {
+    let mut focus_nodes: Vec<_> = validate_data(SHAPES, DATA)
+        .iter()
+        .map(|result| {
+            assert_eq!(
+                result["sourceConstraintComponent"],
+                "<http://www.w3.org/ns/shacl#ExpressionConstraintComponent>"
+            );
+            result["focusNode"].as_str().unwrap().to_string()
+        })
+        .collect();
+    focus_nodes.sort();
+
+    // onlyA has no common value for the intersection and [true, false] for the union.
+    let mut expected = vec![
+        ex("closed"),
+        ex("unknown"),
+        ex("internRequest"),
+        ex("onlyA"),
+        ex("onlyA"),
+    ];
+    expected.sort();
+    assert_eq!(focus_nodes, expected);
+}
+
+#[test]
+fn test_node_expressions_evaluate_against_data() {
+    let dataset = dataset(SHAPES, DATA);
+    let iri = |name: &str| NamedNode::new_unchecked(format!("http://example.org/{}", name));
+    let expression_of = |shape: &str| {
+        let term = dataset
+            .shapes_graph()
+            .object_for_subject_predicate(&iri(shape), sh::EXPRESSION)
+            .unwrap();
+        parse_node_expression(dataset.shapes_graph(), term).unwrap()
+    };
+    let (only_a, both, intern_request) = (iri("onlyA"), iri("both"), iri("internRequest"));
+
+    let union = expression_of("EitherShape");
+    assert_eq!(
+        union
+            .evaluate(&dataset, only_a.as_ref().into())
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(
+        union
+            .evaluate(&dataset, both.as_ref().into())
+            .unwrap()
+            .len(),
+        1
+    );
+
+    let intersection = expression_of("BothShape");
+    assert!(intersection
+        .evaluate(&dataset, only_a.as_ref().into())
+        .unwrap()
+        .is_empty());
+
+    let filtered = expression_of("ApprovedShape");
+    assert!(filtered
+        .evaluate(&dataset, intern_request.as_ref().into())
+        .unwrap()
+        .is_empty());
+    assert!(filtered.to_string().contains("sh:filterShape"));
+}
@@ -0,0 +1,334 @@
+use oxigraph::io::RdfFormat;
+use oxigraph::model::{vocab::rdf, NamedNodeRef};
+use shacl_rust::generator::{counter_examples_manifest, example_data, ExampleGenerator};
+use shacl_rust::rdf::{read_graph_from_string, serialize_graph_to_string};
+use shacl_rust::{parse_shapes, validate, validation::dataset::ValidationDataset};
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:name ; sh:datatype xsd:string ; sh:minCount 1 ; sh:maxCount 1 ;
+                      sh:minLength 3 ; sh:maxLength 20 ] ;
+        sh:property [ sh:path ex:email ; sh:datatype xsd:string ; sh:minCount 2 ; sh:maxCount 3 ;
+                      sh:pattern "^[a-z]{3,8}@example\\.(org|com)$" ] ;
+        sh:property [ sh:path ex:code ; sh:pattern "^AB-\\d{4}$" ; sh:flags "i" ; sh:maxCount 1 ] ;
+        sh:property [ sh:path ex:age ; sh:datatype xsd:integer ; sh:minInclusive 18 ; sh:maxExclusive 21 ] ;
+        sh:property [ sh:path ex:score ; sh:datatype xsd:decimal ; sh:minExclusive 0 ; sh:maxInclusive 1 ] ;
+        sh:property [ sh:path ex:active ; sh:datatype xsd:boolean ] ;
+        sh:property [ sh:path ex:born ; sh:datatype xsd:date ] ;
+        sh:property [ sh:path ex:status ; sh:in ( ex:Active ex:Retired ) ; sh:maxCount 1 ] ;
+        sh:property [ sh:path ex:nick ; sh:languageIn ( "en" "de" ) ] ;
+        sh:property [ sh:path ex:homepage ; sh:nodeKind sh:IRI ] ;
+        sh:property [ sh:path ex:address ; sh:node ex:AddressShape ; sh:minCount 1 ] ;
+        sh:property [ sh:path ex:employer ; sh:class ex:Company ] ;
+        sh:property [ sh:path ex:country ; sh:hasValue "BE" ] ;
+        sh:property [ sh:path ex:unused ; sh:maxCount 0 ] .
+
+    ex:AddressShape a sh:NodeShape ;
+        sh:property [ sh:path ex:city ; sh:hasValue "Ghent" ] .
+
+    ex:CompanyShape a sh:NodeShape ;
+        sh:targetClass ex:Company ;
+        sh:property [ sh:path ex:label ; sh:datatype xsd:string ; sh:minCount 1 ] .
+"#;
+
+#[test]
+fn test_generated_data_conforms() {
+    let shapes_graph = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let shapes = parse_shapes(&shapes_graph).unwrap();
+
+    for seed in 0..20 {
+        let data = example_data(&shapes, 3, seed);
+        let turtle = serialize_graph_to_string(&data, RdfFormat::Turtle).unwrap();
+        let dataset = ValidationDataset::from_graphs(data, shapes_graph.clone()).unwrap();
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        let report = validate(&dataset, &shapes);
+        assert!(
+            *report.get_conforms(),
+            "seed {} produced non-conforming data:\n{}\n{:?}",
+            seed,
+            turtle,
+            report.get_results()
+        );
+    }
+}
+
+#[test]
+fn test_count_and_base_iri() {
+    let shapes_graph = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let shapes = parse_shapes(&shapes_graph).unwrap();
+
+    let data = ExampleGenerator::new(7)
+        .with_base_iri("urn:example:")
+        .generate(&shapes, 4);
+
+    let person = NamedNodeRef::new("http://example.org/Person").unwrap();
+    let people: Vec<_> = data
+        .subjects_for_predicate_object(rdf::TYPE, person)
+        .collect();
+    assert_eq!(people.len(), 4);
+    assert!(people
+        .iter()
+        .all(|person| person.to_string().starts_with("<urn:example:PersonShape-")));
+}
+
+#[test]
+fn test_same_seed_gives_same_data() {
+    let shapes_graph = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let shapes = parse_shapes(&shapes_graph).unwrap();
+
+    let first = example_data(&shapes, 2, 42);
+    let second = example_data(&shapes, 2, 42);
+    let other = example_data(&shapes, 2, 43);
+
+    // Blank nodes differ between runs, so compare the triples without them
+    let named = |graph: &oxigraph::model::Graph| {
+        let mut triples: Vec<String> = graph
+            .iter()
+            .filter(|t| !t.subject.is_blank_node() && !t.object.is_blank_node())
+            .map(|t| t.to_string())
+            .collect();
+        triples.sort();
+        triples
+    };
+    assert_eq!(named(&first), named(&second));
+    assert_ne!(named(&first), named(&other));
+}
+
+#[test]
+fn test_counter_examples_fire_their_component() {
+    let shapes_graph = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let shapes = parse_shapes(&shapes_graph).unwrap();
+
+    let (data, counter_examples) = ExampleGenerator::new(3).counter_examples(&shapes);
+    let dataset = ValidationDataset::from_graphs(data, shapes_graph.clone()).unwrap();
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+
+    let mut components: Vec<String> = counter_examples
+        .iter()
+        .map(|ce| local_name(ce.component.as_str()))
+        .collect();
+    components.sort();
+    components.dedup();
+    assert_eq!(
+        components,
+        [
+            "ClassConstraintComponent",
+            "DatatypeConstraintComponent",
+            "HasValueConstraintComponent",
+            "InConstraintComponent",
+            "LanguageInConstraintComponent",
+            "MaxCountConstraintComponent",
+            "MaxExclusiveConstraintComponent",
+            "MaxInclusiveConstraintComponent",
+            "MaxLengthConstraintComponent",
+            "MinCountConstraintComponent",
+            "MinExclusiveConstraintComponent",
+            "MinInclusiveConstraintComponent",
+            "MinLengthConstraintComponent",
+            "NodeKindConstraintComponent",
+            "PatternConstraintComponent",
+        ]
+    );
+
+    for ce in &counter_examples {
+        let components: Vec<_> = report
+            .get_results()
+            .iter()
+            .filter(|result| result.get_focus_node() == ce.focus_node.as_ref().into())
+            .filter_map(|result| result.get_source_constraint_component())
+            .collect();
+        assert!(
+            components
+                .iter()
+                .any(|component| *component == ce.component.as_ref()),
+            "{} did not fire for {}: {:?}",
+            ce.component,
+            ce.focus_node,
+            components
+        );
+    }
+}
+
+#[test]
+fn test_counter_examples_manifest() {
+    let shapes_graph = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:S a sh:NodeShape ;
+            sh:targetClass ex:C ;
+            sh:property ex:NameShape .
+        ex:NameShape sh:path ex:name ; sh:minCount 1 .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+    let shapes = parse_shapes(&shapes_graph).unwrap();
+
+    let (_, counter_examples) = ExampleGenerator::new(0).counter_examples(&shapes);
+    assert_eq!(
+        counter_examples_manifest(&counter_examples),
+        serde_json::json!({
+            "counterExamples": [{
+                "focusNode": "<http://example.org/data/S-1>",
+                "sourceShape": "<http://example.org/NameShape>",
+                "resultPath": "<http://example.org/name>",
+                "sourceConstraintComponent": "<http://www.w3.org/ns/shacl#MinCountConstraintComponent>",
+            }]
+        })
+    );
+}
+
+fn local_name(iri: &str) -> String {
+    iri.rsplit('#').next().unwrap().to_string()
+}
+
+#[test]
+fn test_lengths_count_characters() {
+    let shapes_graph = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [ sh:path ex:prénom ; sh:minCount 1 ; sh:maxLength 3 ] ;
+            sh:property [ sh:path ex:née ; sh:minCount 1 ; sh:minLength 12 ; sh:maxLength 12 ] .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+    let shapes = parse_shapes(&shapes_graph).unwrap();
+
+    let data = example_data(&shapes, 2, 0);
+    let dataset = ValidationDataset::from_graphs(data, shapes_graph.clone()).unwrap();
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+    assert!(*report.get_conforms(), "{:?}", report.get_results());
+
+    let (data, counter_examples) = ExampleGenerator::new(2).counter_examples(&shapes);
+    let dataset = ValidationDataset::from_graphs(data, shapes_graph.clone()).unwrap();
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+    for ce in &counter_examples {
+        assert!(
+            report.get_results().iter().any(|result| {
+                result.get_focus_node() == ce.focus_node.as_ref().into()
+                    && result
+                        .get_source_constraint_component()
+                        .is_some_and(|component| component == ce.component.as_ref())
+            }),
+            "{} did not fire for {}",
+            ce.component,
+            ce.focus_node
+        );
+    }
+    assert!(counter_examples.iter().any(|ce| ce
+        .component
+        .as_str()
+        .ends_with("MaxLengthConstraintComponent")));
+}
+
+#[test]
+fn test_patterns_follow_validation_flags() {
+    let shapes_graph = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:ItemShape a sh:NodeShape ;
+            sh:targetClass ex:Item ;
+            sh:property [ sh:path ex:literal ; sh:minCount 1 ; sh:pattern "a.b" ; sh:flags "q" ] ;
+            sh:property [ sh:path ex:spaced ; sh:minCount 1 ; sh:pattern "^ab - \\d{2} $" ; sh:flags "x" ] .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+    let shapes = parse_shapes(&shapes_graph).unwrap();
+
+    for seed in 0..10 {
+        let data = example_data(&shapes, 2, seed);
+        let turtle = serialize_graph_to_string(&data, RdfFormat::Turtle).unwrap();
+        assert!(turtle.contains("\"a.b\""), "{}", turtle);
+        assert!(!turtle.contains("\"ab "), "{}", turtle);
+        let dataset = ValidationDataset::from_graphs(data, shapes_graph.clone()).unwrap();
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        let report = validate(&dataset, &shapes);
+        assert!(
+            *report.get_conforms(),
+            "seed {} produced non-conforming data:\n{}\n{:?}",
+            seed,
+            turtle,
+            report.get_results()
+        );
+    }
+
+    let (data, counter_examples) = ExampleGenerator::new(5).counter_examples(&shapes);
+    let dataset = ValidationDataset::from_graphs(data, shapes_graph.clone()).unwrap();
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+    let patterns = counter_examples.iter().filter(|ce| {
+        ce.component
+            .as_str()
+            .ends_with("PatternConstraintComponent")
+    });
+    assert_eq!(patterns.clone().count(), 2);
+    for ce in patterns {
+        assert!(
+            report.get_results().iter().any(|result| {
+                result.get_focus_node() == ce.focus_node.as_ref().into()
+                    && result
+                        .get_source_constraint_component()
+                        .is_some_and(|component| component == ce.component.as_ref())
+            }),
+            "{} did not fire for {}",
+            ce.component,
+            ce.focus_node
+        );
+    }
+}
+
+#[test]
+fn test_extreme_integer_bounds() {
+    let shapes_graph = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:CounterShape a sh:NodeShape ;
+            sh:targetClass ex:Counter ;
+            sh:property [ sh:path ex:above ; sh:minCount 1 ; sh:datatype xsd:integer ;
+                          sh:minExclusive 9223372036854775807 ] ;
+            sh:property [ sh:path ex:huge ; sh:minCount 1 ; sh:datatype xsd:integer ;
+                          sh:minInclusive 100000000000000000000 ] ;
+            sh:property [ sh:path ex:below ; sh:minCount 1 ; sh:datatype xsd:integer ;
+                          sh:maxExclusive -9223372036854775808 ] .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+    let shapes = parse_shapes(&shapes_graph).unwrap();
+
+    for seed in 0..5 {
+        let data = example_data(&shapes, 2, seed);
+        let turtle = serialize_graph_to_string(&data, RdfFormat::Turtle).unwrap();
+        let dataset = ValidationDataset::from_graphs(data, shapes_graph.clone()).unwrap();
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        let report = validate(&dataset, &shapes);
+        assert!(
+            *report.get_conforms(),
+            "seed {} produced non-conforming data:\n{}\n{:?}",
+            seed,
+            turtle,
+            report.get_results()
+        );
+    }
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use common::validate_data;
+use shacl_rust::core::IdentifierFormat;
+
+#[test]
+fn test_identifier_formats_verify_check_digits() {
+    let valid = [
+        (
+            IdentifierFormat::Uuid,
+            "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            IdentifierFormat::Uuid,
+            "urn:uuid:123E4567-E89B-12D3-A456-426614174000",
+        ),
+        (IdentifierFormat::Isbn, "978-0-306-40615-7"),
+        (IdentifierFormat::Isbn, "0-306-40615-2"),
+        (IdentifierFormat::Isbn, "080442957X"),
+        (IdentifierFormat::Orcid, "0000-0002-1825-0097"),
+        (
+            IdentifierFormat::Orcid,
+            "https://orcid.org/0000-0002-1694-233X",
+        ),
+        (IdentifierFormat::Doi, "10.1000/182"),
+        (IdentifierFormat::Doi, "https://doi.org/10.1038/nphys1170"),
+    ];
+    for (format, value) in valid {
+        assert_eq!(format.check(value), Ok(()), "{} {}", format, value);
+    }
+
+    let invalid = [
+        (
+            IdentifierFormat::Uuid,
+            "123e4567-e89b-12d3-a456-42661417400g",
+        ),
+        (IdentifierFormat::Isbn, "978-0-306-40615-8"),
+        (IdentifierFormat::Isbn, "0-306-40615-3"),
+        (IdentifierFormat::Isbn, "123-0-306-40615-7"),
+        (IdentifierFormat::Orcid, "0000-0002-1825-0098"),
+        (IdentifierFormat::Orcid, "0000-0002-1825"),
+        (IdentifierFormat::Doi, "11.1000/182"),
+        (IdentifierFormat::Doi, "10.1000"),
+    ];
+    for (format, value) in invalid {
+        assert!(format.check(value).is_err(), "{} {}", format, value);
+    }
+}
+
+#[test]
+fn test_identifier_format_constraint_reports_invalid_values() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+
+        ex:AuthorShape a sh:NodeShape ;
+            sh:targetClass ex:Author ;
+            sh:property [ sh:path ex:orcid ; shx:identifierFormat shx:ORCID ] .
+    "#;
+    let data = r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:alice a ex:Author ; ex:orcid <https://orcid.org/0000-0002-1825-0097> .
+        ex:bob a ex:Author ; ex:orcid "0000-0002-1825-0098" .
+    "#;
+    let results = validate_data(shapes, data);
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["focusNode"], "<http://example.org/bob>");
+    assert_eq!(
+        results[0]["messages"][0],
+        "Value is not a valid ORCID: check digit does not match"
+    );
+    assert_eq!(
+        results[0]["sourceConstraintComponent"],
+        "<https://ensaremirerol.github.io/shacl-rust/ns#IdentifierFormatConstraintComponent>"
+    );
+}
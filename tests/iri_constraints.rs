@@ -0,0 +1,110 @@
+mod common;
+
+use shacl_rust::{parse_shapes, validate};
+
+fn validate_data(shapes: &str, data: &str) -> Vec<(String, String)> {
+    let mut results: Vec<_> = common::validate_data(shapes, data)
+        .into_iter()
+        .map(|r| {
+            (
+                r["value"].as_str().unwrap().to_string(),
+                r["messages"][0].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    results.sort();
+    results
+}
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+
+    ex:alice ex:employer <http://example.org/org/acme> , <http://example.org/org/acme/hr> ,
+        <http://other.org/org/acme> , "acme" .
+"#;
+
+#[test]
+fn test_namespace_in_names_expected_namespace() {
+    let results = validate_data(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+
+        ex:Shape a sh:NodeShape ;
+            sh:targetNode ex:alice ;
+            sh:property [ sh:path ex:employer ; shx:namespaceIn ( <http://example.org/org/> ) ] .
+        "#,
+        DATA,
+    );
+
+    assert_eq!(
+        results,
+        vec![
+            ("\"acme\"".to_string(), "Value is not an IRI".to_string()),
+            (
+                "<http://other.org/org/acme>".to_string(),
+                "IRI is not in namespace <http://example.org/org/>".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_uri_template_matches_single_segments() {
+    let results = validate_data(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+
+        ex:Shape a sh:NodeShape ;
+            sh:targetNode ex:alice ;
+            sh:property [
+                sh:path ex:employer ;
+                sh:nodeKind sh:IRI ;
+                shx:uriTemplate "http://example.org/org/{id}"
+            ] .
+        "#,
+        DATA,
+    );
+
+    let values: Vec<_> = results.iter().map(|(value, _)| value.as_str()).collect();
+    assert_eq!(
+        values,
+        vec![
+            "\"acme\"",
+            "\"acme\"",
+            "<http://example.org/org/acme/hr>",
+            "<http://other.org/org/acme>",
+        ]
+    );
+    assert!(results
+        .iter()
+        .any(|(_, message)| message == "IRI does not match template http://example.org/org/{id}"));
+}
+
+#[test]
+fn test_shapes_with_malformed_uri_templates_are_skipped() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+
+        ex:Shape a sh:NodeShape ;
+            sh:targetNode ex:alice ;
+            shx:uriTemplate "http://example.org/{id" .
+    "#;
+    let dataset = common::dataset(shapes, DATA);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+
+    assert!(shapes.is_empty());
+    let features = report.get_unsupported_features();
+    assert_eq!(features.len(), 1);
+    assert!(
+        features[0].reason.contains("Unclosed '{'"),
+        "{}",
+        features[0].reason
+    );
+}
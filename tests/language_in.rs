@@ -0,0 +1,42 @@
+mod common;
+
+use common::validate_data;
+use shacl_rust::utils::language_matches;
+
+#[test]
+fn test_language_in_matches_language_ranges() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:LabelShape a sh:NodeShape ;
+            sh:targetClass ex:Thing ;
+            sh:property [ sh:path ex:label ; sh:languageIn ( "en" "zh-Hant" ) ] .
+    "#;
+    let data = r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:a a ex:Thing ; ex:label "color"@en-US , "colour"@EN-gb , "顏色"@zh-hant-TW .
+        ex:b a ex:Thing ; ex:label "couleur"@fr , "x"@eng , "颜色"@zh-Hans .
+    "#;
+    let mut values: Vec<_> = validate_data(shapes, data)
+        .iter()
+        .map(|result| {
+            assert_eq!(result["focusNode"], "<http://example.org/b>");
+            result["value"].as_str().unwrap().to_string()
+        })
+        .collect();
+    values.sort();
+    assert_eq!(
+        values,
+        vec!["\"couleur\"@fr", "\"x\"@eng", "\"颜色\"@zh-hans"]
+    );
+}
+
+#[test]
+fn test_basic_language_range_matching() {
+    assert!(language_matches("de-CH-1996", "de-ch"));
+    assert!(language_matches("en", "*"));
+    assert!(!language_matches("en", "en-US"));
+    assert!(!language_matches("english", "en"));
+}
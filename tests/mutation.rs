@@ -0,0 +1,104 @@
+use shacl_rust::mutation::{mutants, run_mutation_tests};
+use shacl_rust::rdf::read_graph_from_string;
+use shacl_rust::shape_tests::read_shape_tests;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property ex:NameShape .
+
+    ex:NameShape sh:path ex:name ;
+        sh:minCount 1 ;
+        sh:maxCount 1 ;
+        sh:datatype xsd:string .
+"#;
+
+const TESTS: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+
+    ex:valid a shx:ShapeTest ;
+        shx:data """
+            @prefix ex: <http://example.org/> .
+            ex:alice a ex:Person ; ex:name "Alice" .
+        """ .
+
+    ex:missingName a shx:ShapeTest ;
+        shx:data """
+            @prefix ex: <http://example.org/> .
+            ex:bob a ex:Person .
+        """ ;
+        shx:expectedResult [ sh:focusNode ex:bob ; sh:sourceConstraintComponent sh:MinCountConstraintComponent ] .
+"#;
+
+#[test]
+fn test_mutants_drop_constraints_and_relax_cardinalities() {
+    let shapes = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let descriptions: Vec<_> = mutants(&shapes)
+        .into_iter()
+        .map(|(mutation, _)| mutation.to_string())
+        .collect();
+
+    assert_eq!(
+        descriptions,
+        [
+            "<http://example.org/NameShape>: dropped <http://www.w3.org/ns/shacl#datatype> <http://www.w3.org/2001/XMLSchema#string>",
+            "<http://example.org/NameShape>: dropped <http://www.w3.org/ns/shacl#maxCount> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer>",
+            "<http://example.org/NameShape>: relaxed <http://www.w3.org/ns/shacl#maxCount> 1 to 2",
+            "<http://example.org/NameShape>: dropped <http://www.w3.org/ns/shacl#minCount> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer>",
+            "<http://example.org/NameShape>: relaxed <http://www.w3.org/ns/shacl#minCount> 1 to 0",
+        ]
+    );
+}
+
+#[test]
+fn test_untested_constraints_survive() {
+    let shapes = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let tests = read_shape_tests(&read_graph_from_string(TESTS, "turtle").unwrap()).unwrap();
+
+    let report = run_mutation_tests(&shapes, &tests).unwrap();
+
+    assert_eq!(report.total(), 5);
+    assert_eq!(report.killed, 2);
+    let survived: Vec<_> = report
+        .survived
+        .iter()
+        .map(|mutation| mutation.description.as_str())
+        .collect();
+    assert_eq!(
+        survived,
+        [
+            "dropped <http://www.w3.org/ns/shacl#datatype> <http://www.w3.org/2001/XMLSchema#string>",
+            "dropped <http://www.w3.org/ns/shacl#maxCount> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer>",
+            "relaxed <http://www.w3.org/ns/shacl#maxCount> 1 to 2",
+        ]
+    );
+    assert!(report
+        .to_string()
+        .ends_with("5 mutants, 2 killed, 3 survived"));
+}
+
+#[test]
+fn test_failing_tests_are_rejected() {
+    let shapes = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let tests = read_shape_tests(
+        &read_graph_from_string(
+            r#"
+            @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+            <http://example.org/t> a shx:ShapeTest ;
+                shx:data "<http://example.org/x> a <http://example.org/Person> ." .
+            "#,
+            "turtle",
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    let error = run_mutation_tests(&shapes, &tests).unwrap_err();
+    assert!(error.to_string().contains("fail before mutation"));
+}
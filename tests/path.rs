@@ -1,5 +1,6 @@
 use oxigraph::model::NamedNodeRef;
 use shacl_rust::core::path::{Path, PathElement};
+use shacl_rust::parser::path::parse_path;
 use shacl_rust::rdf::read_graph_from_string;
 
 fn setup_test_graph() -> oxigraph::model::Graph {
@@ -240,3 +241,250 @@ fn test_empty_results() {
 
     assert_eq!(results.len(), 0);
 }
+
+#[test]
+fn test_equivalent_paths() {
+    let knows = NamedNodeRef::new("http://example.org/knows").unwrap();
+    let friend = NamedNodeRef::new("http://example.org/friend").unwrap();
+
+    let alternative = |first, second| {
+        Path::new().add_element(PathElement::Alternative(vec![
+            PathElement::Iri(first),
+            PathElement::Iri(second),
+        ]))
+    };
+    assert!(alternative(knows, friend).equivalent(&alternative(friend, knows)));
+    assert_ne!(alternative(knows, friend), alternative(friend, knows));
+
+    let nested = Path::new().add_element(PathElement::OneOrMore(Box::new(
+        PathElement::ZeroOrMore(Box::new(PathElement::Iri(knows))),
+    )));
+    let star = Path::new().add_element(PathElement::ZeroOrMore(Box::new(PathElement::Iri(knows))));
+    assert!(nested.equivalent(&star));
+
+    let single = Path::new().add_element(PathElement::Alternative(vec![
+        PathElement::Iri(knows),
+        PathElement::Iri(knows),
+    ]));
+    assert!(single.equivalent(&Path::new().add_element(PathElement::Iri(knows))));
+
+    let plus = Path::new().add_element(PathElement::OneOrMore(Box::new(PathElement::Iri(knows))));
+    assert!(!plus.equivalent(&star));
+    assert!(!star.equivalent(&alternative(knows, friend)));
+}
+
+#[test]
+fn test_sparql_property_path_resolves_like_the_path() {
+    let graph = setup_test_graph();
+    let knows = NamedNodeRef::new("http://example.org/knows").unwrap();
+    let friend = NamedNodeRef::new("http://example.org/friend").unwrap();
+    let path = Path::new()
+        .add_element(PathElement::ZeroOrOne(Box::new(PathElement::Alternative(
+            vec![
+                PathElement::Iri(knows),
+                PathElement::Alternative(vec![PathElement::Iri(friend)]),
+            ],
+        ))))
+        .add_element(PathElement::Inverse(friend));
+
+    assert_eq!(
+        path.to_sparql_property_path(),
+        "(<http://example.org/friend>|<http://example.org/knows>)?/^<http://example.org/friend>"
+    );
+
+    let store = oxigraph::store::Store::new().unwrap();
+    store
+        .extend(graph.iter().map(|triple| {
+            triple
+                .into_owned()
+                .in_graph(oxigraph::model::GraphName::DefaultGraph)
+        }))
+        .unwrap();
+    let query = format!(
+        "SELECT ?node WHERE {{ <http://example.org/Alice> {} ?node }}",
+        path.to_sparql_property_path()
+    );
+    let Ok(oxigraph::sparql::QueryResults::Solutions(solutions)) =
+        oxigraph::sparql::SparqlEvaluator::new()
+            .parse_query(&query)
+            .unwrap()
+            .on_store(&store)
+            .execute()
+    else {
+        panic!("Expected solutions");
+    };
+    let mut from_sparql: Vec<_> = solutions
+        .map(|solution| solution.unwrap().get("node").unwrap().to_string())
+        .collect();
+    from_sparql.sort();
+    from_sparql.dedup();
+
+    let alice = NamedNodeRef::new("http://example.org/Alice").unwrap();
+    let mut resolved: Vec<_> = path
+        .resolve_path_for_given_node(&graph, &alice.into())
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    resolved.sort();
+    assert_eq!(from_sparql, resolved);
+    assert_eq!(resolved, vec!["<http://example.org/Alice>".to_string()]);
+}
+
+#[test]
+fn test_inverse_step_from_literal_values() {
+    let mut graph = setup_test_graph();
+    let aliases = r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:name "Al" .
+        ex:Bob ex:alias "Al" .
+        ex:Charlie ex:alias "Al" .
+    "#;
+    graph.extend(read_graph_from_string(aliases, "turtle").unwrap().iter());
+    let name = NamedNodeRef::new("http://example.org/name").unwrap();
+    let alias = NamedNodeRef::new("http://example.org/alias").unwrap();
+    let alice = NamedNodeRef::new("http://example.org/Alice").unwrap();
+
+    // ex:name / ^ex:alias: the people whose alias is Alice's name
+    let path = Path::new()
+        .add_element(PathElement::Iri(name))
+        .add_element(PathElement::Inverse(alias));
+    let results = path.resolve_path_for_given_node(&graph, &alice.into());
+    assert_eq!(results.len(), 2);
+    assert!(results.contains(&NamedNodeRef::new("http://example.org/Bob").unwrap().into()));
+    assert!(results.contains(
+        &NamedNodeRef::new("http://example.org/Charlie")
+            .unwrap()
+            .into()
+    ));
+
+    // Inverse steps inside alternatives and modifiers start from literals too
+    let path = Path::new()
+        .add_element(PathElement::Iri(name))
+        .add_element(PathElement::ZeroOrOne(Box::new(PathElement::Alternative(
+            vec![PathElement::Inverse(alias), PathElement::Inverse(name)],
+        ))));
+    let results = path.resolve_path_for_given_node(&graph, &alice.into());
+    assert_eq!(results.len(), 4);
+    assert!(results.contains(&alice.into()));
+}
+
+#[test]
+fn test_nested_sequence_path() {
+    let graph = setup_test_graph();
+    let shapes = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        ex:Shape sh:path [ sh:zeroOrMorePath ( ex:knows ex:knows ) ] .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+    let shape = NamedNodeRef::new("http://example.org/Shape").unwrap();
+    let term = shapes
+        .object_for_subject_predicate(shape, shacl_rust::sh::PATH)
+        .unwrap();
+    let path = parse_path(&shapes, term).unwrap();
+
+    let knows = NamedNodeRef::new("http://example.org/knows").unwrap();
+    let expected = PathElement::ZeroOrMore(Box::new(PathElement::Sequence(vec![
+        PathElement::Iri(knows),
+        PathElement::Iri(knows),
+    ])));
+    assert_eq!(path.get_elements(), [expected]);
+    assert_eq!(
+        path.to_sparql_property_path(),
+        "(<http://example.org/knows>/<http://example.org/knows>)*"
+    );
+
+    // Every second step along the ex:knows chain, starting from Alice
+    let alice = NamedNodeRef::new("http://example.org/Alice").unwrap();
+    let mut results: Vec<_> = path
+        .resolve_path_for_given_node(&graph, &alice.into())
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    results.sort();
+    assert_eq!(
+        results,
+        vec![
+            "<http://example.org/Alice>",
+            "<http://example.org/Charlie>",
+            "<http://example.org/Eve>",
+        ]
+    );
+
+    let mut written = oxigraph::model::Graph::new();
+    let written_term = path.to_graph_term(&mut written);
+    let reparsed = parse_path(&written, written_term.as_ref()).unwrap();
+    assert_eq!(reparsed.get_elements(), path.get_elements());
+    assert_eq!(path.clone().into_owned().as_path(), path);
+
+    let flat = Path::new()
+        .add_element(PathElement::Iri(knows))
+        .add_element(PathElement::Iri(knows));
+    let grouped = Path::new().add_element(PathElement::Sequence(vec![
+        PathElement::Iri(knows),
+        PathElement::Iri(knows),
+    ]));
+    assert!(grouped.equivalent(&flat));
+}
+
+#[test]
+fn test_inverse_resolution_finds_nodes_reaching_a_value() {
+    let graph = setup_test_graph();
+    let iri = |name: &'static str| NamedNodeRef::new_unchecked(name);
+    let (knows, friend, works_at) = (
+        iri("http://example.org/knows"),
+        iri("http://example.org/friend"),
+        iri("http://example.org/worksAt"),
+    );
+    let names = |nodes: Vec<oxigraph::model::TermRef<'_>>| {
+        let mut names: Vec<String> = nodes.iter().map(ToString::to_string).collect();
+        names.sort();
+        names
+    };
+
+    let direct = Path::new().add_element(PathElement::Iri(knows));
+    assert_eq!(
+        names(direct.resolve_inverse_for_given_node(&graph, iri("http://example.org/Bob").into())),
+        vec!["<http://example.org/Alice>"]
+    );
+
+    let inverse = Path::new().add_element(PathElement::Inverse(knows));
+    assert_eq!(
+        names(
+            inverse.resolve_inverse_for_given_node(&graph, iri("http://example.org/Alice").into())
+        ),
+        vec!["<http://example.org/Bob>"]
+    );
+
+    // Every node whose value set contains the value, matching forward resolution.
+    let alternative = Path::new()
+        .add_element(PathElement::Alternative(vec![
+            PathElement::Iri(knows),
+            PathElement::Iri(friend),
+        ]))
+        .add_element(PathElement::Iri(works_at));
+    assert_eq!(
+        names(
+            alternative
+                .resolve_inverse_for_given_node(&graph, iri("http://example.org/CompanyX").into())
+        ),
+        vec!["<http://example.org/Alice>"]
+    );
+
+    let transitive =
+        Path::new().add_element(PathElement::OneOrMore(Box::new(PathElement::Iri(knows))));
+    let reaching = names(
+        transitive.resolve_inverse_for_given_node(&graph, iri("http://example.org/David").into()),
+    );
+    assert_eq!(
+        reaching,
+        vec![
+            "<http://example.org/Alice>",
+            "<http://example.org/Bob>",
+            "<http://example.org/Charlie>"
+        ]
+    );
+}
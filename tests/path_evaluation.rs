@@ -0,0 +1,219 @@
+mod common;
+
+use oxigraph::model::{NamedNode, NamedOrBlankNodeRef, TermRef};
+use shacl_rust::parser::path::parse_path;
+use shacl_rust::validation::config::{PathEvaluation, PathLimits, ValidationConfig};
+use shacl_rust::validation::dataset::ValidationDataset;
+use shacl_rust::validation::report::OwnedValidationReport;
+use shacl_rust::{parse_shapes, sh, validate, Severity, ValidationOptions, Validator};
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [
+            sh:path [ sh:zeroOrMorePath ( ex:knows ex:knows ) ] ;
+            sh:maxCount 2 ;
+        ] ;
+        sh:property [
+            sh:path ( ex:address [ sh:alternativePath ( ex:city ex:town ) ] ) ;
+            sh:datatype xsd:string ;
+        ] ;
+        sh:property [
+            sh:path [ sh:inversePath ex:knows ] ;
+            sh:minCount 1 ;
+        ] .
+"#;
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+
+    ex:alice a ex:Person ; ex:knows ex:bob ; ex:address [ ex:city "Ghent" ] .
+    ex:bob a ex:Person ; ex:knows ex:carol ; ex:address [ ex:town 7 ] .
+    ex:carol a ex:Person ; ex:knows ex:dave .
+    ex:dave ex:knows ex:erin .
+"#;
+
+fn ex(name: &str) -> String {
+    format!("<http://example.org/{}>", name)
+}
+
+fn dataset(path_evaluation: PathEvaluation) -> ValidationDataset {
+    common::dataset(SHAPES, DATA).with_config(ValidationConfig {
+        path_evaluation,
+        ..ValidationConfig::default()
+    })
+}
+
+#[test]
+fn test_sparql_paths_resolve_like_the_walker() {
+    let sparql = dataset(PathEvaluation::Sparql);
+    let walker = dataset(PathEvaluation::Walker);
+
+    for triple in sparql.shapes_graph().triples_for_predicate(sh::PATH) {
+        let path = parse_path(sparql.shapes_graph(), triple.object).unwrap();
+        for person in ["alice", "bob", "carol", "dave", "erin"] {
+            let node = NamedNode::new_unchecked(format!("http://example.org/{}", person));
+            let focus = NamedOrBlankNodeRef::from(node.as_ref());
+            let mut from_sparql: Vec<TermRef<'_>> = sparql.resolve_path(&path, focus).unwrap();
+            let mut walked: Vec<TermRef<'_>> = walker.resolve_path(&path, focus).unwrap();
+            from_sparql.sort_by_cached_key(ToString::to_string);
+            walked.sort_by_cached_key(ToString::to_string);
+            assert_eq!(from_sparql, walked, "{} from {}", path, person);
+        }
+    }
+}
+
+#[test]
+fn test_sparql_paths_validate_like_the_walker() {
+    let report = |path_evaluation| {
+        let dataset = dataset(path_evaluation);
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        // Property shapes are blank nodes, labelled differently per parse.
+        let mut results: Vec<String> = validate(&dataset, &shapes).as_json()["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|result| {
+                let mut result = result.clone();
+                result.as_object_mut().unwrap().remove("sourceShape");
+                result.to_string()
+            })
+            .collect();
+        results.sort();
+        results
+    };
+
+    let results = report(PathEvaluation::Sparql);
+    assert_eq!(results, report(PathEvaluation::Walker));
+    // ex:alice reaches ex:alice, ex:carol and ex:erin, ex:bob's town is no
+    // string and nobody knows ex:alice
+    assert_eq!(results.len(), 3);
+}
+
+#[test]
+fn test_path_limits_report_a_failure() {
+    let run = |path_limits| {
+        let dataset = dataset(PathEvaluation::Sparql).with_config(ValidationConfig {
+            path_limits,
+            ..ValidationConfig::default()
+        });
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        let report = validate(&dataset, &shapes);
+        let mut components: Vec<String> = report
+            .get_results()
+            .iter()
+            .map(|result| {
+                let component = result.get_source_constraint_component().unwrap();
+                format!("{} {}", result.get_focus_node(), component)
+            })
+            .collect();
+        components.sort();
+        let mut failures: Vec<String> = report
+            .get_failures()
+            .iter()
+            .map(|failure| failure.focus_node.clone())
+            .collect();
+        failures.sort();
+        (components, failures)
+    };
+
+    let (_, failures) = run(PathLimits::default());
+    assert!(failures.is_empty());
+
+    // Every person reaches a second node over ex:knows/ex:knows, so the
+    // limited path fails in place of the sh:maxCount on ex:alice.
+    let (limited, failures) = run(PathLimits {
+        max_visited_nodes: Some(1),
+        max_depth: None,
+    });
+    assert_eq!(failures, vec![ex("alice"), ex("bob"), ex("carol")]);
+    assert!(!limited.iter().any(|c| c.contains("MaxCount")));
+
+    let (_, failures) = run(PathLimits {
+        max_visited_nodes: None,
+        max_depth: Some(1),
+    });
+    assert_eq!(failures, vec![ex("alice")]);
+}
+
+#[test]
+fn test_path_limit_failures_make_the_report_non_conforming() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:CountShape a sh:NodeShape ;
+            sh:targetNode ex:alice ;
+            sh:property [ sh:path [ sh:oneOrMorePath ex:knows ] ; sh:minCount 0 ] .
+
+        ex:ExpressionShape a sh:NodeShape ;
+            sh:targetNode ex:bob ;
+            sh:expression [ sh:path [ sh:zeroOrMorePath ex:knows ] ] .
+    "#;
+    let dataset = common::dataset(shapes, DATA).with_config(ValidationConfig {
+        path_limits: PathLimits {
+            max_visited_nodes: Some(1),
+            max_depth: None,
+        },
+        ..ValidationConfig::default()
+    });
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+
+    // Neither sh:minCount 0 nor the expression may pass on a cut-off walk.
+    assert!(!report.get_conforms());
+    assert!(report.get_results().is_empty());
+    let failures = report.get_failures().to_vec();
+    assert_eq!(failures.len(), 2);
+    assert!(failures
+        .iter()
+        .all(|failure| failure.reason.starts_with("Path limit exceeded")));
+    assert!(failures
+        .iter()
+        .any(|failure| failure.focus_node == ex("bob") && failure.shape == ex("ExpressionShape")));
+
+    let json = report.as_json();
+    assert_eq!(json["failures"].as_array().unwrap().len(), 2);
+    let owned = report.into_owned();
+    assert_eq!(owned.as_report().get_failures(), failures);
+    let round_tripped: OwnedValidationReport =
+        serde_json::from_str(&serde_json::to_string(&owned).unwrap()).unwrap();
+    assert!(!round_tripped.conforms());
+    assert_eq!(round_tripped.as_report().get_failures().len(), 2);
+}
+
+#[test]
+fn test_path_limit_failures_survive_a_severity_filter() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:KnowsShape a sh:NodeShape ;
+            sh:targetNode ex:alice ;
+            sh:property [ sh:path [ sh:oneOrMorePath ex:knows ] ; sh:minCount 0 ] .
+    "#;
+    let dataset = common::dataset(shapes, DATA).with_config(ValidationConfig {
+        path_limits: PathLimits {
+            max_visited_nodes: None,
+            max_depth: Some(1),
+        },
+        ..ValidationConfig::default()
+    });
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = Validator::builder()
+        .with_options(ValidationOptions {
+            severity_filter: Some(vec![Severity::Violation]),
+            ..ValidationOptions::default()
+        })
+        .build()
+        .validate(&dataset, &shapes);
+
+    assert!(report.get_results().is_empty());
+    assert_eq!(report.get_failures().len(), 1);
+    assert!(!report.get_conforms());
+    assert!(!report.filter_results(|_| true).get_conforms());
+}
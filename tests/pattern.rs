@@ -0,0 +1,55 @@
+mod common;
+
+use common::validate_data;
+use shacl_rust::parser::well_formed::check_well_formed;
+use shacl_rust::rdf::read_graph_from_string;
+use shacl_rust::sh;
+use shacl_rust::utils::pattern_regex;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:CodeShape a sh:NodeShape ;
+        sh:targetClass ex:Item ;
+        sh:property [ sh:path ex:code ; sh:pattern "^ab c$" ; sh:flags "ix" ] ;
+        sh:property [ sh:path ex:price ; sh:pattern "1.5" ; sh:flags "q" ] ;
+        sh:property [ sh:path ex:note ; sh:pattern "^x" ; sh:flags "iu" ] .
+"#;
+
+#[test]
+fn test_pattern_applies_flags() {
+    let data = r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:good a ex:Item ; ex:code "ABC" ; ex:price "1.5" .
+        ex:bad a ex:Item ; ex:code "ab c" ; ex:price "105" ; ex:note "x" .
+    "#;
+    let mut results: Vec<_> = validate_data(SHAPES, data)
+        .iter()
+        .map(|result| {
+            assert_eq!(result["focusNode"], "<http://example.org/bad>");
+            (
+                result["value"].as_str().unwrap().to_string(),
+                result["messages"][0].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    results.sort();
+    assert_eq!(results.len(), 3, "{:?}", results);
+    assert_eq!(results[0].0, "\"105\"");
+    assert_eq!(results[1].0, "\"ab c\"");
+    assert_eq!(results[2].0, "\"x\"");
+    assert_eq!(results[2].1, "Unsupported sh:flags character 'u'");
+}
+
+#[test]
+fn test_unsupported_flags_are_not_well_formed() {
+    let graph = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let issues = check_well_formed(&graph);
+
+    assert_eq!(issues.len(), 1, "{:?}", issues);
+    assert_eq!(issues[0].predicate, Some(sh::PATTERN.to_string()));
+    assert!(!pattern_regex("a.b", Some("sm")).unwrap().is_match("A\nb"));
+    assert!(pattern_regex("a.b", Some("si")).unwrap().is_match("A\nb"));
+}
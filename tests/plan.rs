@@ -0,0 +1,97 @@
+use shacl_rust::parse_shapes;
+use shacl_rust::rdf::read_graph_from_string;
+use shacl_rust::validation::config::{Entailment, PathEvaluation, ValidationConfig};
+use shacl_rust::validation::plan::{explain_plan, PathBackend};
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:node ex:NamedShape ;
+        sh:closed true ;
+        sh:property [
+            sh:path ( ex:knows [ sh:zeroOrMorePath ex:knows ] ) ;
+            sh:class ex:Person ;
+        ] ;
+        sh:property [ sh:path ex:start ; sh:lessThan ex:end ] .
+
+    ex:NamedShape a sh:NodeShape ;
+        sh:targetSubjectsOf ex:name ;
+        sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+"#;
+
+#[test]
+fn test_plan_lists_targets_paths_and_constraint_order() {
+    let graph = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let shapes = parse_shapes(&graph).unwrap();
+    let plan = explain_plan(
+        &shapes,
+        &ValidationConfig {
+            path_evaluation: PathEvaluation::Sparql,
+            ..ValidationConfig::default()
+        },
+    );
+    assert_eq!(plan.distinct_targets, 2);
+
+    let person = plan
+        .shapes
+        .iter()
+        .find(|shape| shape.shape == "<http://example.org/PersonShape>")
+        .unwrap();
+    assert_eq!(
+        person.targets,
+        ["instances of <http://example.org/Person> and its subclasses in the data graph"]
+    );
+    let components: Vec<&str> = person
+        .constraints
+        .iter()
+        .map(|constraint| constraint.component.as_str())
+        .collect();
+    assert_eq!(
+        components,
+        [
+            "<http://www.w3.org/ns/shacl#NodeConstraintComponent>",
+            "<http://www.w3.org/ns/shacl#ClosedConstraintComponent>",
+        ]
+    );
+    assert!(person.constraints[0].cache.is_some());
+
+    let paths: Vec<PathBackend> = person
+        .property_shapes
+        .iter()
+        .map(|shape| shape.path.as_ref().unwrap().backend)
+        .collect();
+    assert_eq!(paths, [PathBackend::Sparql, PathBackend::Sparql]);
+    let less_than = person
+        .property_shapes
+        .iter()
+        .flat_map(|shape| &shape.constraints)
+        .find_map(|constraint| constraint.other_path.as_ref())
+        .unwrap();
+    assert_eq!(less_than.path, "<http://example.org/end>");
+
+    let text = plan.to_string();
+    assert!(text.contains("subjects of <http://example.org/name> and its subproperties"));
+    assert!(text.contains("(SPARQL property path)"));
+    assert_eq!(plan.as_json()["shapes"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_plan_follows_the_config() {
+    let graph = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let shapes = parse_shapes(&graph).unwrap();
+    let plan = explain_plan(
+        &shapes,
+        &ValidationConfig {
+            entailment: Entailment::None,
+            ..ValidationConfig::default()
+        },
+    );
+
+    let text = plan.to_string();
+    assert!(text.contains("instances of <http://example.org/Person> without subclasses"));
+    assert!(text.contains("(graph walker)"));
+    assert!(!text.contains("SPARQL property path"));
+}
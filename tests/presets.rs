@@ -0,0 +1,98 @@
+#![cfg(feature = "presets")]
+
+mod common;
+
+use shacl_rust::presets::Preset;
+use shacl_rust::validation::dataset::ValidationDataset;
+use shacl_rust::{parse_shapes, rdf::read_graph_from_string, validate};
+
+fn validate_with_preset(preset: Preset, data: &str) -> Vec<serde_json::Value> {
+    let data_graph = read_graph_from_string(data, "turtle").unwrap();
+    let dataset = ValidationDataset::from_graphs(data_graph, preset.load_graph().unwrap()).unwrap();
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes).with_provenance(preset.provenance());
+
+    assert_eq!(
+        report.as_json()["provenance"]["shapesSource"],
+        format!("preset:{}", preset.name())
+    );
+    common::results(&report)
+}
+
+#[test]
+fn test_all_presets_parse() {
+    for preset in Preset::ALL {
+        let graph = preset.load_graph().unwrap();
+        let shapes = parse_shapes(&graph).unwrap();
+        assert!(!shapes.is_empty(), "{} has no shapes", preset);
+        assert_eq!(Preset::from_name(preset.name()), Some(preset));
+    }
+}
+
+#[test]
+fn test_dcat_ap_requires_dataset_description() {
+    let results = validate_with_preset(
+        Preset::DcatAp,
+        r#"
+        @prefix dcat: <http://www.w3.org/ns/dcat#> .
+        @prefix dct: <http://purl.org/dc/terms/> .
+        <http://example.org/dataset> a dcat:Dataset ;
+            dct:title "Example" ;
+            dcat:distribution <http://example.org/distribution> .
+        <http://example.org/distribution> a dcat:Distribution ;
+            dcat:accessURL <http://example.org/download> .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0]["resultPath"],
+        "<http://purl.org/dc/terms/description>"
+    );
+}
+
+#[test]
+fn test_skos_pref_label_unique_per_language() {
+    let results = validate_with_preset(
+        Preset::Skos,
+        r#"
+        @prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+        <http://example.org/cat> a skos:Concept ;
+            skos:prefLabel "Cat"@en, "Kitty"@en .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0]["sourceConstraintComponent"],
+        "<http://www.w3.org/ns/shacl#UniqueLangConstraintComponent>"
+    );
+}
+
+#[test]
+fn test_dcat_ap_report_groups_by_requirement() {
+    let data_graph = read_graph_from_string(
+        r#"
+        @prefix dcat: <http://www.w3.org/ns/dcat#> .
+        <http://example.org/distribution> a dcat:Distribution .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+    let dataset =
+        ValidationDataset::from_graphs(data_graph, Preset::DcatAp.load_graph().unwrap()).unwrap();
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+
+    let grouped = report.by_requirement(&shapes).as_json();
+    let requirements = grouped["requirements"].as_array().unwrap();
+    let failed: Vec<_> = requirements
+        .iter()
+        .filter(|r| r["conforms"] == false)
+        .map(|r| r["requirement"].as_str().unwrap())
+        .collect();
+
+    assert!(requirements.len() > failed.len());
+    assert_eq!(failed, vec!["Distribution.dcat:accessURL"]);
+    assert_eq!(grouped["unassigned"], serde_json::json!([]));
+}
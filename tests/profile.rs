@@ -0,0 +1,108 @@
+mod common;
+
+use shacl_rust::core::component::ConstraintComponent;
+use shacl_rust::validation::{config::ValidationConfig, dataset::ValidationDataset};
+use shacl_rust::{parse_shapes, validate};
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:closed true ;
+        sh:ignoredProperties ( <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> ) ;
+        sh:property ex:AgeShape .
+
+    ex:AgeShape a sh:PropertyShape ;
+        sh:path ex:age ;
+        sh:datatype xsd:integer ;
+        sh:maxCount 1 .
+"#;
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+
+    ex:alice a ex:Person ; ex:age 30 .
+    ex:bob a ex:Person ; ex:age "thirty" .
+    ex:carol a ex:Person .
+"#;
+
+fn dataset(profile: bool) -> ValidationDataset {
+    common::dataset(SHAPES, DATA).with_config(ValidationConfig {
+        profile,
+        ..ValidationConfig::default()
+    })
+}
+
+#[test]
+fn test_profile_counts_evaluations_per_component() {
+    let dataset = dataset(true);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let profile = validate(&dataset, &shapes).profile();
+
+    let evaluations = |shape: &str, component| {
+        profile
+            .entries()
+            .iter()
+            .find(|entry| {
+                entry.shape.to_string() == format!("<http://example.org/{}>", shape)
+                    && entry.component == component
+            })
+            .map(|entry| entry.cost.evaluations)
+    };
+    assert_eq!(
+        evaluations("AgeShape", ConstraintComponent::Datatype),
+        Some(3)
+    );
+    assert_eq!(
+        evaluations("AgeShape", ConstraintComponent::MaxCount),
+        Some(3)
+    );
+    assert_eq!(
+        evaluations("PersonShape", ConstraintComponent::Closed),
+        Some(3)
+    );
+    assert_eq!(profile.entries().len(), 3);
+
+    let times: Vec<_> = profile
+        .entries()
+        .iter()
+        .map(|entry| entry.cost.time)
+        .collect();
+    assert!(times.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert!(profile.to_string().starts_with(" Time (ms)"));
+}
+
+#[test]
+fn test_profile_folds_shape_and_component() {
+    let dataset = dataset(true);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let folded = validate(&dataset, &shapes).profile().folded();
+
+    let mut stacks: Vec<_> = folded
+        .lines()
+        .map(|line| {
+            let (stack, micros) = line.rsplit_once(' ').unwrap();
+            assert!(micros.parse::<u128>().is_ok());
+            stack
+        })
+        .collect();
+    stacks.sort();
+    assert_eq!(
+        stacks,
+        [
+            "http://example.org/AgeShape;http://www.w3.org/ns/shacl#DatatypeConstraintComponent",
+            "http://example.org/AgeShape;http://www.w3.org/ns/shacl#MaxCountConstraintComponent",
+            "http://example.org/PersonShape;http://www.w3.org/ns/shacl#ClosedConstraintComponent",
+        ]
+    );
+}
+
+#[test]
+fn test_runs_are_not_profiled_by_default() {
+    let dataset = dataset(false);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    assert!(validate(&dataset, &shapes).profile().is_empty());
+}
@@ -0,0 +1,279 @@
+mod common;
+
+use common::validate_data;
+use std::cmp::Ordering;
+
+use oxigraph::model::{vocab::xsd, Literal, Term};
+use shacl_rust::utils::compare_terms;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:EventShape a sh:NodeShape ;
+        sh:targetClass ex:Event ;
+        sh:property [ sh:path ex:date ; sh:minInclusive "2024-01-01"^^xsd:date ] ;
+        sh:property [ sh:path ex:start ; sh:maxExclusive "2024-06-01T00:00:00Z"^^xsd:dateTime ] ;
+        sh:property [ sh:path ex:price ; sh:maxInclusive 10.5 ] ;
+        sh:property [ sh:path ex:length ; sh:minExclusive "PT1H"^^xsd:dayTimeDuration ] .
+"#;
+
+fn messages(data: &str) -> Vec<String> {
+    let mut messages: Vec<_> = validate_data(SHAPES, data)
+        .iter()
+        .map(|result| result["messages"][0].as_str().unwrap().to_string())
+        .collect();
+    messages.sort();
+    messages
+}
+
+#[test]
+fn test_range_constraints_compare_typed_values() {
+    let messages = messages(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:ok a ex:Event ;
+            ex:date "2024-02-29"^^xsd:date ;
+            ex:start "2024-05-31T20:00:00-03:00"^^xsd:dateTime ;
+            ex:price "10.50"^^xsd:decimal , 9 , "1.0e1"^^xsd:double ;
+            ex:length "PT61M"^^xsd:duration .
+        ex:late a ex:Event ;
+            ex:date "2023-12-31"^^xsd:date ;
+            ex:start "2024-06-01T02:00:00+01:00"^^xsd:dateTime ;
+            ex:price 10.51 ;
+            ex:length "PT3600S"^^xsd:dayTimeDuration .
+        "#,
+    );
+
+    assert_eq!(messages.len(), 4, "{:?}", messages);
+    assert!(messages.iter().all(|m| !m.contains("cannot be compared")));
+}
+
+#[test]
+fn test_range_constraints_report_incomparable_values() {
+    let messages = messages(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:e a ex:Event ;
+            ex:date "2024-03-01T00:00:00Z"^^xsd:dateTime ;
+            ex:price "cheap" , "not a number"^^xsd:decimal ;
+            ex:length "1 hour" .
+        "#,
+    );
+
+    assert_eq!(messages.len(), 4, "{:?}", messages);
+    assert!(messages.iter().all(|m| m.contains("cannot be compared")));
+}
+
+#[test]
+fn test_numeric_literals_compare_across_datatypes() {
+    let typed = |value: &str, datatype| Term::from(Literal::new_typed_literal(value, datatype));
+    let compare = |a: &Term, b: &Term| compare_terms(a.as_ref(), b.as_ref());
+
+    assert_eq!(
+        compare(&typed("2", xsd::INTEGER), &typed("10", xsd::DECIMAL)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        compare(&typed("1.5", xsd::FLOAT), &typed("1.50", xsd::DECIMAL)),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(
+        compare(&typed("10", xsd::STRING), &typed("9", xsd::STRING)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        compare(&typed("10", xsd::STRING), &typed("9", xsd::INTEGER)),
+        None
+    );
+    assert_eq!(
+        compare(&typed("P1M", xsd::DURATION), &typed("P30D", xsd::DURATION)),
+        None
+    );
+}
+
+#[test]
+fn test_date_times_order_across_timezones() {
+    let date_time = |value: &str| Term::from(Literal::new_typed_literal(value, xsd::DATE_TIME));
+    let compare = |a: &str, b: &str| compare_terms(date_time(a).as_ref(), date_time(b).as_ref());
+
+    assert_eq!(
+        compare("2024-01-01T12:00:00+02:00", "2024-01-01T11:00:00Z"),
+        Some(Ordering::Less)
+    );
+    // Without a timezone, a dateTime may be anywhere within 14 hours of UTC.
+    assert_eq!(
+        compare("2024-01-01T00:00:00", "2024-01-01T15:00:00Z"),
+        Some(Ordering::Less)
+    );
+    assert_eq!(compare("2024-01-01T00:00:00", "2024-01-01T13:00:00Z"), None);
+}
+
+#[test]
+fn test_less_than_checks_every_pair_of_values() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:MeetingShape a sh:NodeShape ;
+            sh:targetClass ex:Meeting ;
+            sh:property [ sh:path ex:start ; sh:lessThan ex:end ] .
+    "#;
+    let data = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:ok a ex:Meeting ;
+            ex:start "2024-01-01T09:00:00+01:00"^^xsd:dateTime ;
+            ex:end "2024-01-01T08:30:00Z"^^xsd:dateTime .
+        ex:overlapping a ex:Meeting ;
+            ex:start "2024-01-01T09:00:00Z"^^xsd:dateTime ;
+            ex:end "2024-01-01T08:00:00Z"^^xsd:dateTime , "2024-01-01T10:00:00Z"^^xsd:dateTime .
+        ex:floating a ex:Meeting ;
+            ex:start "2024-01-01T09:00:00"^^xsd:dateTime ;
+            ex:end "2024-01-01T10:00:00Z"^^xsd:dateTime .
+    "#;
+    let mut results: Vec<_> = validate_data(shapes, data)
+        .iter()
+        .map(|result| {
+            (
+                result["focusNode"].as_str().unwrap().to_string(),
+                result["messages"][0].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    results.sort();
+
+    assert_eq!(results.len(), 2, "{:?}", results);
+    assert_eq!(results[0].0, "<http://example.org/floating>");
+    assert!(results[0].1.starts_with("Value cannot be compared"));
+    assert_eq!(results[1].0, "<http://example.org/overlapping>");
+    assert!(results[1].1.contains("2024-01-01T08:00:00Z"));
+}
+
+#[test]
+fn test_decimals_compare_exactly() {
+    let typed = |value: &str, datatype| Term::from(Literal::new_typed_literal(value, datatype));
+    let compare = |a: &Term, b: &Term| compare_terms(a.as_ref(), b.as_ref());
+
+    // Both round to the same f64.
+    assert_eq!(
+        compare(
+            &typed("9007199254740993", xsd::INTEGER),
+            &typed("9007199254740992", xsd::INTEGER)
+        ),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        compare(
+            &typed("123456789012345678901234567890.01", xsd::DECIMAL),
+            &typed("123456789012345678901234567890.1", xsd::DECIMAL)
+        ),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        compare(&typed("-0.10", xsd::DECIMAL), &typed("-00.1", xsd::DECIMAL)),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(
+        compare(&typed("-2", xsd::INTEGER), &typed("-10", xsd::INTEGER)),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        compare(&typed("-0", xsd::INTEGER), &typed("0", xsd::INTEGER)),
+        Some(Ordering::Equal)
+    );
+}
+
+#[test]
+fn test_gregorian_values_compare_by_value() {
+    let typed = |value: &str, datatype| Term::from(Literal::new_typed_literal(value, datatype));
+    let compare = |a: &Term, b: &Term| compare_terms(a.as_ref(), b.as_ref());
+
+    assert_eq!(
+        compare(&typed("1999", xsd::G_YEAR), &typed("2000", xsd::G_YEAR)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        compare(
+            &typed("2000-12", xsd::G_YEAR_MONTH),
+            &typed("2000-02", xsd::G_YEAR_MONTH)
+        ),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        compare(
+            &typed("--02-14", xsd::G_MONTH_DAY),
+            &typed("--03-01", xsd::G_MONTH_DAY)
+        ),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        compare(&typed("--11", xsd::G_MONTH), &typed("--11", xsd::G_MONTH)),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(
+        compare(&typed("---15", xsd::G_DAY), &typed("---05", xsd::G_DAY)),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        compare(&typed("2000", xsd::G_YEAR), &typed("2000", xsd::INTEGER)),
+        None
+    );
+
+    let results = validate_data(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:BookShape a sh:NodeShape ;
+            sh:targetClass ex:Book ;
+            sh:property [ sh:path ex:year ; sh:minInclusive "2000"^^xsd:gYear ] .
+        "#,
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:new a ex:Book ; ex:year "2000"^^xsd:gYear , "2024"^^xsd:gYear .
+        ex:old a ex:Book ; ex:year "1999"^^xsd:gYear .
+        "#,
+    );
+    assert_eq!(results.len(), 1, "{:?}", results);
+    assert_eq!(results[0]["focusNode"], "<http://example.org/old>");
+}
+
+#[test]
+fn test_fractional_integers_are_incomparable() {
+    let typed = |value: &str, datatype| Term::from(Literal::new_typed_literal(value, datatype));
+    let compare = |a: &Term, b: &Term| compare_terms(a.as_ref(), b.as_ref());
+
+    assert_eq!(
+        compare(&typed("1.5", xsd::INTEGER), &typed("1", xsd::INTEGER)),
+        None
+    );
+    assert_eq!(
+        compare(&typed("1.0", xsd::INT), &typed("1", xsd::DECIMAL)),
+        None
+    );
+    assert_eq!(
+        compare(&typed("1.5", xsd::DECIMAL), &typed("1", xsd::INTEGER)),
+        Some(Ordering::Greater)
+    );
+
+    let messages = messages(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:e a ex:Event ; ex:price "1.5"^^xsd:integer .
+        "#,
+    );
+    assert_eq!(messages.len(), 1, "{:?}", messages);
+    assert!(messages[0].contains("cannot be compared"), "{:?}", messages);
+}
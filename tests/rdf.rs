@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use oxigraph::model::Graph;
+use shacl_rust::rdf::{
+    diff, diff_with, graph_hash, graph_hash_hex, parse_rdf_format, parse_variables,
+    read_graph_from_string, serialize_graph_with_prefixes, substitute_variables, BlankNodeMatching,
+};
+
+#[test]
+fn test_graphs_serialize_with_prefixes_and_base() {
+    let graph = read_graph_from_string(
+        "<http://example.org/data/alice> <http://xmlns.com/foaf/0.1/name> \"Alice\" .",
+        "nt",
+    )
+    .unwrap();
+    let turtle = serialize_graph_with_prefixes(
+        &graph,
+        parse_rdf_format("turtle").unwrap(),
+        &[("foaf".to_string(), "http://xmlns.com/foaf/0.1/".to_string())],
+        Some("http://example.org/data/"),
+    )
+    .unwrap();
+
+    assert!(turtle.contains("@prefix foaf: "));
+    assert!(turtle.contains("foaf:name"));
+    assert!(turtle.contains("<alice>"));
+    assert_eq!(read_graph_from_string(&turtle, "ttl").unwrap(), graph);
+}
+
+#[test]
+fn test_unknown_formats_are_rejected() {
+    assert!(parse_rdf_format("n-triples").is_ok());
+    assert!(parse_rdf_format("yaml").is_err());
+}
+
+#[test]
+fn test_substitute_variables_before_parsing() {
+    let variables = parse_variables(
+        "# tenant settings\nBASE_NS=http://tenant.example.org/\n\nMAX_LABELS = 2\n",
+    )
+    .unwrap();
+    let shapes = substitute_variables(
+        r#"
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        <{{BASE_NS}}LabelShape> sh:path <{{ BASE_NS }}label> ; sh:maxCount {{MAX_LABELS}} .
+        "#,
+        &variables,
+    )
+    .unwrap();
+
+    let graph = read_graph_from_string(&shapes, "turtle").unwrap();
+    assert!(graph.iter().any(|triple| {
+        triple.subject.to_string() == "<http://tenant.example.org/LabelShape>"
+            && triple.object.to_string() == "\"2\"^^<http://www.w3.org/2001/XMLSchema#integer>"
+    }));
+}
+
+#[test]
+fn test_substitute_variables_reports_missing_values() {
+    let error = substitute_variables("<{{B}}> <{{A}}> <{{B}}> .", &HashMap::new()).unwrap_err();
+    assert!(error.to_string().contains("A, B"));
+    assert!(parse_variables("MAX_LABELS 2").is_err());
+}
+
+#[test]
+fn test_graph_hash_ignores_blank_node_labels() {
+    let turtle = |body: &str| {
+        read_graph_from_string(
+            &format!("@prefix ex: <http://example.org/> .\n{}", body),
+            "turtle",
+        )
+        .unwrap()
+    };
+    let a = turtle("ex:a ex:knows _:x . _:x ex:name \"X\" . _:y ex:name \"Y\" .");
+    let b = turtle("_:second ex:name \"Y\" . ex:a ex:knows _:first . _:first ex:name \"X\" .");
+    assert_eq!(graph_hash(&a), graph_hash(&b));
+    assert_eq!(graph_hash_hex(&a).len(), 64);
+
+    // Which blank node ex:a knows is part of the content
+    let c = turtle("ex:a ex:knows _:y . _:x ex:name \"X\" . _:y ex:name \"Y\" .");
+    assert_ne!(graph_hash(&a), graph_hash(&c));
+    assert_ne!(graph_hash(&a), graph_hash(&Graph::new()));
+}
+
+#[test]
+fn test_graph_hash_is_pinned_to_rdfc10() {
+    // RDFC-1.0 labels the blank node _:c14n0, so the hash is that of
+    // `<http://example.org/a> <http://example.org/p> _:c14n0 .\n`.
+    let graph = read_graph_from_string(
+        "<http://example.org/a> <http://example.org/p> _:anything .",
+        "nt",
+    )
+    .unwrap();
+    assert_eq!(
+        graph_hash_hex(&graph),
+        "a90c7f555ea9b2e6b992cde2ee8ebc96824c364cbb668e79a492b601194b66ec"
+    );
+}
+
+#[test]
+fn test_diff_matches_blank_nodes_by_description() {
+    let old = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:a ex:address _:home ; ex:name "A" .
+        _:home ex:city "Paris" .
+        "#,
+        "ttl",
+    )
+    .unwrap();
+    let relabelled = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:a ex:address [ ex:city "Paris" ] ; ex:name "A" .
+        "#,
+        "ttl",
+    )
+    .unwrap();
+    assert!(diff(&old, &relabelled).is_empty());
+    assert_eq!(
+        diff_with(&old, &relabelled, BlankNodeMatching::Label)
+            .added
+            .len(),
+        2
+    );
+
+    let moved = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:a ex:address [ ex:city "Lyon" ] ; ex:name "A" .
+        "#,
+        "ttl",
+    )
+    .unwrap();
+    let delta = diff(&old, &moved);
+    // The changed blank node is replaced along with the triple pointing at it.
+    assert_eq!(delta.removed.len(), 2);
+    assert_eq!(delta.added.len(), 2);
+    assert!(delta.added.iter().any(|t| t.to_string().contains("Lyon")));
+}
+
+#[test]
+fn test_diff_tells_alike_blank_nodes_apart_by_their_surroundings() {
+    // Every entity has an address that looks the same on its own, nested two
+    // levels deep; only the edges leading to it tell them apart.
+    let entities = |extra: &str| {
+        let mut turtle = String::from("@prefix ex: <http://example.org/> .\n");
+        for i in 0..8 {
+            turtle.push_str(&format!(
+                "ex:e{} ex:home [ ex:address [ ex:city \"Paris\" ] ] .\n",
+                i
+            ));
+        }
+        turtle.push_str(extra);
+        read_graph_from_string(&turtle, "ttl").unwrap()
+    };
+
+    let old = entities("");
+    let new = entities("ex:e3 ex:name \"E3\" .\n");
+    let delta = diff(&old, &new);
+    assert!(delta.removed.is_empty());
+    assert_eq!(delta.added.len(), 1);
+    assert!(diff(&old, &entities("")).is_empty());
+}
+
+#[test]
+fn test_diff_handles_long_lists() {
+    let list = |items: &[i32]| {
+        let items: Vec<String> = items.iter().map(ToString::to_string).collect();
+        let turtle = format!(
+            "@prefix ex: <http://example.org/> .\nex:s ex:values ( {} ) .\n",
+            items.join(" ")
+        );
+        read_graph_from_string(&turtle, "ttl").unwrap()
+    };
+    let mut items: Vec<i32> = (0..2000).collect();
+    let old = list(&items);
+    assert!(diff(&old, &list(&items)).is_empty());
+
+    // Only the changed cell and the links into and out of it differ
+    items[1000] = -1;
+    let delta = diff(&old, &list(&items));
+    assert_eq!(delta.removed.len(), 3);
+    assert_eq!(delta.added.len(), 3);
+    assert!(delta.added.iter().any(|t| t.to_string().contains("\"-1\"")));
+}
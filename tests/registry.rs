@@ -0,0 +1,80 @@
+use std::cmp::Ordering;
+
+use shacl_rust::registry::{compare_versions, ShapesRegistry};
+use shacl_rust::validation::dataset::ValidationDataset;
+use shacl_rust::{parse_shapes, rdf::read_graph_from_string, validate};
+
+const SHAPES_V1: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+"#;
+
+const SHAPES_V2: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix owl: <http://www.w3.org/2002/07/owl#> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    <http://example.org/shapes> a owl:Ontology ; owl:versionInfo "2.10" .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:name ; sh:minCount 1 ] ;
+        sh:property [ sh:path ex:email ; sh:minCount 1 ] .
+"#;
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+    ex:Alice a ex:Person ; ex:name "Alice" .
+"#;
+
+fn registry_dir() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("2.3")).unwrap();
+    std::fs::write(dir.path().join("2.3").join("person.ttl"), SHAPES_V1).unwrap();
+    std::fs::write(dir.path().join("shapes-next.ttl"), SHAPES_V2).unwrap();
+    std::fs::write(dir.path().join("README.md"), "not RDF").unwrap();
+    dir
+}
+
+#[test]
+fn test_registry_lists_versions_in_order() {
+    let dir = registry_dir();
+    let registry = ShapesRegistry::from_dir(dir.path()).unwrap();
+
+    assert_eq!(registry.versions(), vec!["2.3", "2.10"]);
+    assert_eq!(registry.latest().unwrap().get_version(), "2.10");
+    assert!(registry.release(Some("9.9")).is_err());
+}
+
+#[test]
+fn test_selected_version_is_used_and_reported() {
+    let dir = registry_dir();
+    let registry = ShapesRegistry::from_dir(dir.path()).unwrap();
+
+    for (version, conforms) in [(Some("2.3"), true), (None, false)] {
+        let release = registry.release(version).unwrap();
+        let data_graph = read_graph_from_string(DATA, "turtle").unwrap();
+        let dataset =
+            ValidationDataset::from_graphs(data_graph, release.load_graph().unwrap()).unwrap();
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        let report = validate(&dataset, &shapes).with_provenance(release.provenance(&registry));
+
+        assert_eq!(*report.get_conforms(), conforms);
+        assert_eq!(
+            report.as_json()["provenance"]["shapesVersion"],
+            release.get_version()
+        );
+    }
+}
+
+#[test]
+fn test_compare_versions_is_numeric() {
+    assert_eq!(compare_versions("2.3", "2.10"), Ordering::Less);
+    assert_eq!(compare_versions("2.3", "2.3.1"), Ordering::Less);
+    assert_eq!(compare_versions("10", "9"), Ordering::Greater);
+    assert_eq!(compare_versions("1.0", "1.0"), Ordering::Equal);
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use common::{dataset, validate_dataset};
+use oxsdatatypes::DateTime;
+use shacl_rust::validation::config::ValidationConfig;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+
+    ex:FeedShape a sh:NodeShape ;
+        sh:targetClass ex:Feed ;
+        sh:property [ sh:path ex:updated ; shx:minDaysFromNow -30 ; shx:maxDaysFromNow 0 ] .
+"#;
+
+fn validate_at(data: &str, now: &str) -> Vec<serde_json::Value> {
+    validate_dataset(&dataset(SHAPES, data).with_config(ValidationConfig {
+        now: Some(now.parse::<DateTime>().unwrap()),
+        ..ValidationConfig::default()
+    }))
+}
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:fresh a ex:Feed ; ex:updated "2024-05-20T08:00:00Z"^^xsd:dateTime .
+    ex:today a ex:Feed ; ex:updated "2024-06-01"^^xsd:date .
+    ex:stale a ex:Feed ; ex:updated "2024-04-01T08:00:00"^^xsd:dateTime .
+    ex:future a ex:Feed ; ex:updated "2024-06-02"^^xsd:date .
+    ex:text a ex:Feed ; ex:updated "yesterday" .
+"#;
+
+fn messages_by_focus_node(results: &[serde_json::Value]) -> Vec<(String, String)> {
+    let mut messages: Vec<_> = results
+        .iter()
+        .map(|r| {
+            (
+                r["focusNode"].as_str().unwrap().to_string(),
+                r["messages"][0].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    messages.sort();
+    messages
+}
+
+#[test]
+fn test_relative_time_uses_configured_reference_time() {
+    let results = validate_at(DATA, "2024-06-01T12:00:00Z");
+
+    assert_eq!(
+        messages_by_focus_node(&results),
+        vec![
+            (
+                "<http://example.org/future>".to_string(),
+                "Value is later than 2024-06-01T12:00:00Z".to_string()
+            ),
+            (
+                "<http://example.org/stale>".to_string(),
+                "Value is earlier than 2024-05-02T12:00:00Z".to_string()
+            ),
+            (
+                "<http://example.org/text>".to_string(),
+                "Value is not an xsd:date or xsd:dateTime".to_string()
+            ),
+        ]
+    );
+    assert!(results.iter().all(|r| r["sourceConstraintComponent"]
+        == "<https://ensaremirerol.github.io/shacl-rust/ns#RelativeTimeConstraintComponent>"));
+    assert_eq!(
+        results[0]["trace"][0],
+        "Reference time: 2024-06-01T12:00:00Z"
+    );
+}
+
+#[test]
+fn test_relative_time_results_move_with_reference_time() {
+    let results = validate_at(DATA, "2024-06-25T00:00:00Z");
+    let focus_nodes: Vec<_> = messages_by_focus_node(&results)
+        .into_iter()
+        .map(|(focus_node, _)| focus_node)
+        .collect();
+
+    assert_eq!(
+        focus_nodes,
+        vec![
+            "<http://example.org/fresh>",
+            "<http://example.org/stale>",
+            "<http://example.org/text>"
+        ]
+    );
+}
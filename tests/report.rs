@@ -0,0 +1,864 @@
+mod common;
+
+use common::dataset;
+use oxigraph::io::RdfFormat;
+use oxigraph::model::{NamedNode, NamedNodeRef, NamedOrBlankNode};
+use shacl_rust::rdf::{
+    describe_node, graph_hash_hex, read_graph_from_string, serialize_graph_to_string,
+    serialize_graph_to_writer,
+};
+use shacl_rust::validation::dataset::ValidationDataset;
+use shacl_rust::validation::render::renderer_for;
+use shacl_rust::validation::report::OwnedValidationReport;
+use shacl_rust::validation::stored_report::read_report_graph;
+use shacl_rust::{
+    parse_shapes, validate, validate_nodes, ReportNodeIds, ReportProvenance, ValidationFailure,
+    ValidationReport, Validator,
+};
+use std::collections::HashSet;
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+
+    ex:Alice a ex:Person ;
+        ex:address [ ex:city "Ghent" ] ;
+        ex:knows ex:Bob .
+    ex:Bob ex:name "Bob" ; ex:knows ex:Carol .
+    ex:Carol ex:name "Carol" .
+"#;
+
+#[test]
+fn test_describe_node_expands_blank_nodes_and_follows_iris_by_depth() {
+    let graph = read_graph_from_string(DATA, "turtle").unwrap();
+    let alice = NamedNodeRef::new_unchecked("http://example.org/Alice").into();
+
+    assert_eq!(describe_node(&graph, alice, 0).len(), 0);
+    assert_eq!(describe_node(&graph, alice, 1).len(), 4);
+    assert_eq!(describe_node(&graph, alice, 2).len(), 6);
+    assert_eq!(describe_node(&graph, alice, 5).len(), 7);
+}
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:name ; sh:minCount 1 ; sh:message "Name | missing <b>" ] .
+"#;
+
+fn with_report(check: impl FnOnce(&ValidationDataset, ValidationReport<'_>)) {
+    let dataset = dataset(SHAPES, DATA);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    check(&dataset, validate(&dataset, &shapes));
+}
+
+#[test]
+fn test_attach_context_adds_turtle_snippets() {
+    with_report(|dataset, mut report| {
+        report.attach_context(dataset.data_graph(), 1).unwrap();
+
+        let context = report.get_results()[0].get_context().unwrap();
+        assert!(context.contains("\"Ghent\""));
+        assert!(!context.contains("\"Bob\""));
+
+        let json = report.get_results()[0].as_json();
+        assert_eq!(json["context"].as_str(), Some(context));
+    });
+}
+
+#[test]
+fn test_markdown_and_html_renderers_escape_values() {
+    with_report(|_, report| {
+        let markdown = renderer_for("markdown").unwrap().render(&report).unwrap();
+        assert!(markdown.contains("| Violation | `<http://example.org/Alice>` |"));
+        assert!(markdown.contains("Name \\| missing &lt;b&gt;"));
+
+        let html = renderer_for("html").unwrap().render(&report).unwrap();
+        assert!(html.contains("<td>&lt;http://example.org/Alice&gt;</td>"));
+        assert!(html.contains("Name | missing &lt;b&gt;"));
+    });
+}
+
+#[test]
+fn test_sarif_renderer_maps_components_to_rules() {
+    with_report(|_, report| {
+        let sarif: serde_json::Value =
+            serde_json::from_str(&renderer_for("sarif").unwrap().render(&report).unwrap()).unwrap();
+        let run = &sarif["runs"][0];
+
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(
+            run["tool"]["driver"]["rules"][0]["id"],
+            "http://www.w3.org/ns/shacl#MinCountConstraintComponent"
+        );
+        assert_eq!(run["results"][0]["level"], "error");
+        assert_eq!(
+            run["results"][0]["locations"][0]["logicalLocations"][0]["fullyQualifiedName"],
+            "<http://example.org/Alice>"
+        );
+    });
+}
+
+#[cfg(feature = "templates")]
+#[test]
+fn test_template_renderer_uses_json_fields() {
+    use shacl_rust::validation::render::{Renderer, TemplateRenderer};
+
+    with_report(|_, report| {
+        let renderer = TemplateRenderer::new(
+            "{% for r in results %}{{ r.focusNode }};{% endfor %}{{ conforms }}",
+        )
+        .unwrap();
+        assert_eq!(
+            renderer.render(&report).unwrap(),
+            "<http://example.org/Alice>;false"
+        );
+    });
+}
+
+#[test]
+fn test_unsupported_features_are_listed_in_report() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:RuleShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:rule [ a sh:TripleRule ] ;
+            sh:property [ sh:path ex:name ; sh:order 1 ] .
+
+        ex:CustomTargetShape a sh:NodeShape ;
+            sh:target [ a ex:MyTarget ] .
+
+        ex:BrokenShape a sh:PropertyShape ;
+            sh:targetClass ex:Person ;
+            sh:path "not a path" .
+    "#;
+    let dataset = dataset(shapes, DATA);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+
+    let features: Vec<(&str, &str)> = report
+        .get_unsupported_features()
+        .iter()
+        .map(|f| (f.node.as_str(), f.feature.as_str()))
+        .collect();
+    assert_eq!(
+        features,
+        vec![
+            ("<http://example.org/BrokenShape>", "shape"),
+            (
+                "<http://example.org/CustomTargetShape>",
+                "<http://www.w3.org/ns/shacl#target>"
+            ),
+            (
+                "<http://example.org/RuleShape>",
+                "<http://www.w3.org/ns/shacl#rule>"
+            ),
+        ]
+    );
+    assert_eq!(
+        report.as_json()["unsupportedFeatures"]
+            .as_array()
+            .unwrap()
+            .len(),
+        3
+    );
+}
+
+#[test]
+fn test_shapes_graph_well_formedness_is_reported() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:nodeKind sh:Thing ;
+            sh:property [ sh:path ex:name ; sh:minCount "1" ; sh:maxCount 1, 2 ] .
+    "#;
+    let dataset = dataset(shapes, DATA);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+
+    assert_eq!(report.get_shapes_graph_well_formed(), Some(false));
+    let messages: Vec<&str> = report
+        .get_well_formedness_issues()
+        .iter()
+        .map(|issue| issue.message.as_str())
+        .collect();
+    assert_eq!(messages.len(), 3);
+    assert!(messages.contains(&"<http://www.w3.org/ns/shacl#Thing> is not a valid sh:nodeKind"));
+    assert_eq!(report.as_json()["shapesGraphWellFormed"], false);
+
+    let well_formed = report
+        .to_graph()
+        .triples_for_predicate(NamedNodeRef::new_unchecked(
+            "http://www.w3.org/ns/shacl#shapesGraphWellFormed",
+        ))
+        .next()
+        .map(|t| t.object.to_string());
+    assert_eq!(
+        well_formed.as_deref(),
+        Some("\"false\"^^<http://www.w3.org/2001/XMLSchema#boolean>")
+    );
+}
+
+#[test]
+fn test_well_formed_shapes_graph_is_reported_as_such() {
+    with_report(|_, report| {
+        assert_eq!(report.get_shapes_graph_well_formed(), Some(true));
+        assert!(report.get_well_formedness_issues().is_empty());
+    });
+}
+
+#[test]
+fn test_validate_nodes_returns_one_report_per_node() {
+    let dataset = dataset(SHAPES, DATA);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let alice: NamedOrBlankNode = NamedNode::new("http://example.org/Alice").unwrap().into();
+    let bob: NamedOrBlankNode = NamedNode::new("http://example.org/Bob").unwrap().into();
+    let unknown: NamedOrBlankNode = NamedNode::new("http://example.org/Nobody").unwrap().into();
+
+    let reports = validate_nodes(
+        &dataset,
+        &shapes,
+        &[alice.clone(), bob.clone(), unknown.clone()],
+    );
+
+    assert_eq!(reports.len(), 3);
+    assert_eq!(reports[&alice].violation_count(), 1);
+    assert!(*reports[&bob].get_conforms());
+    assert!(*reports[&unknown].get_conforms());
+}
+
+#[test]
+fn test_validate_nodes_checks_targets_of_the_validator() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:KnowsShape a sh:NodeShape ;
+            sh:targetSubjectsOf ex:knows ;
+            sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+        ex:CityShape a sh:NodeShape ;
+            sh:property [ sh:path ex:city ; sh:minCount 1 ] .
+    "#;
+    let dataset = dataset(shapes, DATA);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let alice = NamedNode::new("http://example.org/Alice").unwrap();
+    let carol: NamedOrBlankNode = NamedNode::new("http://example.org/Carol").unwrap().into();
+
+    let resolved = alice.clone();
+    let reports = Validator::builder()
+        .with_target_resolver(
+            NamedNode::new("http://example.org/CityShape").unwrap(),
+            move |_| HashSet::from([resolved.clone().into()]),
+        )
+        .build()
+        .validate_nodes(&dataset, &shapes, &[alice.clone().into(), carol.clone()]);
+
+    // Alice knows Bob but has no name, and is given to CityShape.
+    assert_eq!(reports[&alice.into()].violation_count(), 2);
+    assert!(*reports[&carol].get_conforms());
+}
+
+#[test]
+fn test_stored_reports_round_trip_through_json_and_rdf() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [ sh:path ( ex:address [ sh:zeroOrMorePath ex:city ] ) ; sh:datatype ex:City ] ;
+            sh:property [ sh:path ex:name ; sh:minCount 1 ; sh:severity sh:Warning ] .
+    "#;
+    let dataset = dataset(shapes, DATA);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+    assert_eq!(report.violation_count(), 3);
+
+    let json_graph = read_report_graph(&report.as_json().to_string(), "json").unwrap();
+    let from_json = ValidationReport::from_graph(&json_graph).unwrap();
+    let rdf_graph = report.to_graph();
+    let from_rdf = ValidationReport::from_graph(&rdf_graph).unwrap();
+
+    for stored in [&from_json, &from_rdf] {
+        assert!(!*stored.get_conforms());
+        assert_eq!(stored.get_shapes_graph_well_formed(), Some(true));
+        assert!(stored.diff(&report).is_empty());
+    }
+    assert!(from_json.get_results().iter().any(|r| r
+        .get_result_path()
+        .unwrap()
+        .to_string()
+        .ends_with("(<http://example.org/city>*)")));
+}
+
+#[test]
+fn test_filter_results_and_diff_against_baseline() {
+    with_report(|dataset, report| {
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        let baseline = validate(dataset, &shapes);
+        assert!(report.diff(&baseline).is_empty());
+
+        let none = report.filter_results(|r| r.get_severity() == shacl_rust::sh::WARNING);
+        assert!(*none.get_conforms());
+
+        let diff = none.diff(&report);
+        assert!(diff.added.is_empty());
+        assert_eq!(diff.resolved.len(), report.violation_count());
+        assert_eq!(diff.as_json()["resolved"].as_array().unwrap().len(), 1);
+    });
+}
+
+#[test]
+fn test_diff_counts_results_that_only_differ_in_blank_nodes() {
+    let data = r#"
+        @prefix ex: <http://example.org/> .
+        [] a ex:Person .
+        [] a ex:Person .
+    "#;
+    let dataset = dataset(SHAPES, data);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+    assert_eq!(report.violation_count(), 2);
+
+    let first = report.get_results()[0].get_focus_node();
+    let one = report.filter_results(|r| r.get_focus_node() == first);
+
+    let diff = one.diff(&report);
+    assert!(diff.added.is_empty());
+    assert_eq!(diff.resolved.len(), 1);
+    let diff = report.diff(&one);
+    assert_eq!(diff.added.len(), 1);
+    assert!(diff.resolved.is_empty());
+}
+
+#[test]
+fn test_fingerprints_ignore_messages_and_appear_in_json_and_sarif() {
+    with_report(|_, report| {
+        let reworded = dataset(&SHAPES.replace("Name | missing", "No name"), DATA);
+        let shapes = parse_shapes(reworded.shapes_graph()).unwrap();
+        let reworded_report = validate(&reworded, &shapes);
+
+        let fingerprint = report.get_results()[0].fingerprint();
+        assert_eq!(fingerprint.len(), 32);
+        assert_eq!(reworded_report.get_results()[0].fingerprint(), fingerprint);
+        assert!(reworded_report.diff(&report).is_empty());
+
+        assert_eq!(
+            report.get_results()[0].as_json()["fingerprint"],
+            fingerprint
+        );
+        let sarif: serde_json::Value =
+            serde_json::from_str(&renderer_for("sarif").unwrap().render(&report).unwrap()).unwrap();
+        assert_eq!(
+            sarif["runs"][0]["results"][0]["partialFingerprints"]["shaclResult/v1"],
+            fingerprint
+        );
+    });
+}
+
+#[test]
+fn test_quality_score_is_weighted_and_survives_stored_reports() {
+    use shacl_rust::validation::report::QualityWeights;
+
+    with_report(|_, report| {
+        let person_shape = NamedNodeRef::new_unchecked("http://example.org/PersonShape");
+        assert_eq!(report.get_focus_node_counts()[&person_shape.into()], 1);
+        assert_eq!(report.quality_score(&QualityWeights::default()), Some(0.0));
+
+        let lenient =
+            QualityWeights::default().with_severity(shacl_rust::Severity::Violation, 0.25);
+        assert_eq!(report.quality_score(&lenient), Some(75.0));
+
+        let json_graph = read_report_graph(&report.as_json().to_string(), "json").unwrap();
+        let stored = ValidationReport::from_graph(&json_graph).unwrap();
+        assert_eq!(stored.quality_score(&lenient), Some(75.0));
+        let rdf_graph = report.to_graph();
+        let stored = ValidationReport::from_graph(&rdf_graph).unwrap();
+        assert_eq!(stored.quality_score(&lenient), Some(75.0));
+
+        assert_eq!(
+            ValidationReport::new().quality_score(&QualityWeights::default()),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_quality_score_counts_property_shapes_under_their_node_shape() {
+    use shacl_rust::validation::report::QualityWeights;
+
+    let dataset = dataset(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [ sh:path ex:name ; sh:minCount 1 ] ;
+            sh:property [ sh:path ex:age ; sh:minCount 1 ] .
+        "#,
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person ; ex:name "Alice" ; ex:age 30 .
+        ex:Bob a ex:Person .
+        "#,
+    );
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+    let person_shape = NamedNodeRef::new_unchecked("http://example.org/PersonShape");
+
+    // ex:Bob fails both property shapes but is one of two checked focus nodes.
+    assert_eq!(report.get_results().len(), 2);
+    assert!(report
+        .get_results()
+        .iter()
+        .all(|result| result.get_counted_shape() == person_shape.into()));
+    assert_eq!(report.quality_score(&QualityWeights::default()), Some(50.0));
+
+    let light = QualityWeights::default().with_shape(person_shape.into(), 0.5);
+    assert_eq!(report.quality_score(&light), Some(50.0));
+    let light = light.with_severity(shacl_rust::Severity::Violation, 0.5);
+    assert_eq!(report.quality_score(&light), Some(75.0));
+
+    let rdf_graph = report.to_graph();
+    let stored = ValidationReport::from_graph(&rdf_graph).unwrap();
+    assert_eq!(stored.quality_score(&light), Some(75.0));
+    let json_graph = read_report_graph(&report.as_json().to_string(), "json").unwrap();
+    let stored = ValidationReport::from_graph(&json_graph).unwrap();
+    assert_eq!(stored.quality_score(&light), Some(75.0));
+    assert_eq!(
+        report.into_owned().as_report().quality_score(&light),
+        Some(75.0)
+    );
+}
+
+#[test]
+fn test_report_limits_cut_messages_values_and_details() {
+    use shacl_rust::validation::config::{ReportLimits, ValidationConfig};
+
+    let data = format!(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice a ex:Person ; ex:bio "{}" ; ex:address [ ex:city 1 ] .
+        "#,
+        "x".repeat(1000)
+    );
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [ sh:path ex:bio ; sh:maxLength 10 ; sh:message "The biography is far too long" ] ;
+            sh:property [ sh:path ex:address ; sh:or ( ex:AddressShape [ sh:nodeKind sh:IRI ] ) ] .
+        ex:AddressShape sh:property [ sh:path ex:city ; sh:datatype xsd:string ] .
+    "#;
+    let run = |report_limits| {
+        let dataset = dataset(shapes, &data).with_config(ValidationConfig {
+            report_limits,
+            ..ValidationConfig::default()
+        });
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        let mut results: Vec<_> = validate(&dataset, &shapes)
+            .get_results()
+            .iter()
+            .map(|r| r.as_json())
+            .collect();
+        results.sort_by_key(|r| r["sourceConstraintComponent"].to_string());
+        results
+    };
+
+    let unlimited = run(ReportLimits::default());
+    assert_eq!(unlimited[0]["value"].as_str().unwrap().len(), 1002);
+    assert_eq!(unlimited[1]["details"].as_array().unwrap().len(), 2);
+
+    let limited = run(ReportLimits {
+        max_message_length: Some(12),
+        max_value_length: Some(5),
+        max_details_depth: Some(0),
+    });
+    assert_eq!(limited[0]["messages"][1], "The biograp…");
+    let value = limited[0]["value"].as_str().unwrap();
+    assert!(value.starts_with("\"xxxxx… [1000 characters, sha256 "));
+    assert!(value.len() < 60);
+    assert!(limited[1].get("details").is_none());
+    assert_eq!(limited[0]["fingerprint"], unlimited[0]["fingerprint"]);
+}
+
+#[test]
+fn test_severities_and_components_map_to_enums() {
+    use shacl_rust::{sh, ConstraintComponent, Severity};
+
+    let custom = NamedNodeRef::new_unchecked("http://example.org/Critical");
+    assert_eq!(Severity::from_iri(sh::WARNING), Severity::Warning);
+    assert_eq!(Severity::from_iri(custom), Severity::Other(custom));
+    assert_eq!(Severity::Info.to_string(), sh::INFO.to_string());
+
+    for component in ConstraintComponent::ALL {
+        assert_eq!(ConstraintComponent::from_iri(component.iri()), component);
+        assert!(!matches!(
+            ConstraintComponent::from_iri(component.iri()),
+            ConstraintComponent::Other(_)
+        ));
+    }
+    assert!(matches!(
+        ConstraintComponent::from_iri(sh::MIN_COUNT_CONSTRAINT_COMPONENT),
+        ConstraintComponent::MinCount
+    ));
+    assert!(matches!(
+        ConstraintComponent::from_iri(sh::JS_CONSTRAINT_COMPONENT),
+        ConstraintComponent::Other(_)
+    ));
+    assert!(matches!(Severity::from_iri(sh::INFO), Severity::Info));
+
+    // Other values holding a SHACL IRI equal and hash like their variant
+    let other_min_count = ConstraintComponent::Other(sh::MIN_COUNT_CONSTRAINT_COMPONENT);
+    assert_eq!(other_min_count, ConstraintComponent::MinCount);
+    assert_eq!(Severity::Other(sh::VIOLATION), Severity::Violation);
+    let components: std::collections::HashSet<_> =
+        [other_min_count, ConstraintComponent::MinCount].into();
+    assert_eq!(components.len(), 1);
+    let severities: std::collections::HashSet<_> =
+        [Severity::Other(sh::WARNING), Severity::Warning].into();
+    assert_eq!(severities.len(), 1);
+
+    with_report(|_, report| {
+        let result = &report.get_results()[0];
+        assert_eq!(result.get_severity(), Severity::Violation);
+        assert_eq!(
+            result.get_source_constraint_component(),
+            Some(ConstraintComponent::MinCount)
+        );
+    });
+}
+
+#[test]
+fn test_report_node_id_strategies() {
+    with_report(|_, report| {
+        let sequential = report.to_graph_with(&ReportNodeIds::Sequential);
+        assert_eq!(sequential, report.to_graph_with(&ReportNodeIds::Sequential));
+        assert!(sequential
+            .iter()
+            .any(|triple| triple.subject.to_string() == "_:n0"));
+
+        let base = NamedNode::new("http://example.org/.well-known/genid/").unwrap();
+        let skolem = report.to_graph_with(&ReportNodeIds::Skolem(base));
+        assert!(skolem.iter().all(|triple| triple.subject.is_named_node()
+            && triple
+                .subject
+                .to_string()
+                .starts_with("<http://example.org/.well-known/genid/")));
+        let stored = ValidationReport::from_graph(&skolem).unwrap();
+        assert!(stored.diff(&report).is_empty());
+    });
+}
+
+#[test]
+fn test_ndjson_and_rdf_stream_to_writers() {
+    with_report(|_, report| {
+        let mut ndjson = Vec::new();
+        renderer_for("ndjson")
+            .unwrap()
+            .render_to(&report, &mut ndjson)
+            .unwrap();
+        let lines: Vec<serde_json::Value> = String::from_utf8(ndjson)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), report.get_results().len());
+        assert_eq!(lines[0], report.get_results()[0].as_json());
+
+        let graph = report.to_graph_with(&ReportNodeIds::Sequential);
+        let written = serialize_graph_to_writer(&graph, RdfFormat::NTriples, Vec::new()).unwrap();
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            serialize_graph_to_string(&graph, RdfFormat::NTriples).unwrap()
+        );
+    });
+}
+
+#[test]
+fn test_owned_reports_outlive_the_dataset() {
+    let (owned, expected) = {
+        let dataset = dataset(SHAPES, DATA);
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        let report = validate(&dataset, &shapes);
+        let expected = (report.to_string(), report.as_json());
+        (report.into_owned(), expected)
+    };
+
+    let owned = std::thread::spawn(move || owned).join().unwrap();
+    assert!(!owned.conforms());
+    assert_eq!(owned.to_string(), expected.0);
+    assert_eq!(owned.as_report().as_json(), expected.1);
+}
+
+#[test]
+fn test_owned_reports_round_trip_through_serde() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [
+                sh:path [ sh:alternativePath ( ex:knows [ sh:inversePath ex:knows ] ) ] ;
+                sh:maxCount 0 ;
+            ] ;
+            sh:property [ sh:path ex:name ; sh:minCount 1 ; sh:message "Name required"@en ] .
+    "#;
+    let dataset = dataset(shapes, DATA);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let owned = validate(&dataset, &shapes).into_owned();
+    assert_eq!(owned.results().len(), 2);
+
+    let json = serde_json::to_value(&owned).unwrap();
+    assert_eq!(json["conforms"], false);
+    assert!(json["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|result| result["focusNode"] == "<http://example.org/Alice>"));
+
+    let loaded: OwnedValidationReport = serde_json::from_value(json).unwrap();
+    assert_eq!(loaded, owned);
+    assert_eq!(loaded.as_report().as_json(), owned.as_report().as_json());
+}
+
+#[test]
+fn test_reports_record_the_validator_build() {
+    let dataset = dataset(SHAPES, DATA);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes).with_provenance(ReportProvenance {
+        shapes_hash: Some(graph_hash_hex(dataset.shapes_graph())),
+        ..ReportProvenance::default()
+    });
+    assert!(report.to_string().contains("Shapes hash: "));
+
+    let json = report.as_json();
+    assert_eq!(
+        json["provenance"]["validatorVersion"],
+        env!("CARGO_PKG_VERSION")
+    );
+    assert!(json["provenance"]["validatorFeatures"].is_array());
+    assert!(report.to_string().contains(&format!(
+        "Validator: shacl-rust {}",
+        env!("CARGO_PKG_VERSION")
+    )));
+
+    let graph = report.to_graph();
+    let loaded = ValidationReport::from_graph(&graph).unwrap();
+    assert_eq!(loaded.get_provenance(), report.get_provenance());
+    let stored = read_report_graph(&serde_json::to_string(&json).unwrap(), "json").unwrap();
+    assert_eq!(
+        ValidationReport::from_graph(&stored)
+            .unwrap()
+            .get_provenance(),
+        report.get_provenance()
+    );
+}
+
+#[test]
+fn test_sampled_report_keeps_full_counts() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:ItemShape a sh:NodeShape ;
+            sh:targetClass ex:Item ;
+            sh:property [ sh:path ex:label ; sh:minCount 1 ] ;
+            sh:property [ sh:path ex:size ; sh:maxInclusive 10 ] .
+    "#;
+    let data = r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:a a ex:Item ; ex:size 11 .
+        ex:b a ex:Item ; ex:size 12 .
+        ex:c a ex:Item ; ex:size 13 .
+        ex:d a ex:Item ; ex:label "d" ; ex:size "large" .
+        ex:e a ex:Item .
+    "#;
+    let dataset = dataset(shapes, data);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+    let sampled = report.sample(2);
+
+    let json = sampled.as_json();
+    assert_eq!(json["resultCount"], 8);
+    assert_eq!(json["results"].as_array().unwrap().len(), 4);
+    let mut groups: Vec<_> = json["sampleGroups"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|group| {
+            (
+                group["count"].as_u64().unwrap(),
+                group["shown"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    groups.sort();
+    assert_eq!(groups, [(4, 2), (4, 2)]);
+
+    // The incomparable value has its own message and is always among the samples.
+    let messages: Vec<_> = json["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| result["messages"][0].as_str().unwrap().to_string())
+        .collect();
+    assert!(messages
+        .iter()
+        .any(|message| message.contains("cannot be compared")));
+
+    let text = sampled.to_string();
+    assert!(text.contains("Violations: 8"));
+    assert_eq!(text.matches("(4 result(s), 2 shown)").count(), 2);
+}
+
+#[test]
+fn test_report_pages_count_filtered_results() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:ItemShape a sh:NodeShape ;
+            sh:targetClass ex:Item ;
+            sh:property [ sh:path ex:label ; sh:minCount 1 ] ;
+            sh:property [ sh:path ex:size ; sh:maxInclusive 10 ] .
+    "#;
+    let data = r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:a a ex:Item ; ex:size 11 .
+        ex:b a ex:Item ; ex:size 12 .
+        ex:c a ex:Item ; ex:label "c" ; ex:size 13 .
+    "#;
+    let dataset = dataset(shapes, data);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+    let has_value = |result: &shacl_rust::ValidationResult<'_>| result.get_value().is_some();
+
+    let first = report.page(0, 2, has_value);
+    assert_eq!(
+        (first.results().len(), first.matched(), first.total()),
+        (2, 3, 5)
+    );
+    assert!(first.results().iter().all(|result| has_value(result)));
+    assert_eq!(first.next_offset(), Some(2));
+
+    let last = report.page(2, 2, has_value);
+    assert_eq!(last.results().len(), 1);
+    assert_eq!(last.next_offset(), None);
+    assert!(last
+        .to_string()
+        .starts_with("Conforms: no\nResults 3-3 of 3 matched (5 total)"));
+
+    let json = report.page(4, 10, |_| true).as_json();
+    assert_eq!(json["results"].as_array().unwrap().len(), 1);
+    assert_eq!(json["nextOffset"], serde_json::Value::Null);
+    assert!(report.page(9, 10, |_| true).results().is_empty());
+    assert_eq!(json["conforms"], false);
+
+    // Pages past the results still tell failed and cancelled runs apart.
+    let mut failed = ValidationReport::new();
+    failed.add_failure(ValidationFailure::new(
+        "<http://example.org/ItemShape>",
+        "<http://example.org/a>",
+        "Path limit exceeded",
+    ));
+    failed.mark_cancelled();
+    let page = failed.page(0, 10, |_| true);
+    assert!(page.results().is_empty());
+    assert!(!page.conforms());
+    assert!(page.is_cancelled());
+    assert_eq!(page.failures().len(), 1);
+    let json = page.as_json();
+    assert_eq!(json["conforms"], false);
+    assert_eq!(json["cancelled"], true);
+    assert_eq!(json["failures"].as_array().unwrap().len(), 1);
+    let text = page.to_string();
+    assert!(text.starts_with("Conforms: no\nCancelled: yes"), "{}", text);
+    assert!(text.contains("Failures: 1"), "{}", text);
+}
+
+#[test]
+fn test_results_carry_documentation_of_shapes_and_components() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+
+        ex:NoValueConstraintComponent a sh:ConstraintComponent ;
+            rdfs:comment "Remove the value." ;
+            rdfs:seeAlso <http://example.org/docs/no-value> ;
+            sh:parameter [ sh:path ex:noValue ] ;
+            sh:validator [ sh:ask "ASK { FILTER (false) }" ] .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetNode ex:Alice ;
+            skos:definition "Every person needs a name." ;
+            rdfs:comment "Ignored as skos:definition is preferred." ;
+            sh:property [ sh:path ex:name ; sh:minCount 1 ] ;
+            sh:property [ sh:path ex:knows ; ex:noValue true ] .
+    "#;
+    let dataset = dataset(shapes, DATA);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+    assert_eq!(report.violation_count(), 2);
+
+    // Property shapes take the guidance of their node shape, completed by the component's.
+    for result in report.get_results() {
+        assert_eq!(result.get_help_text(), Some("Every person needs a name."));
+    }
+    let custom = report
+        .get_results()
+        .iter()
+        .find(|result| result.get_documentation_url().is_some())
+        .unwrap();
+    assert_eq!(
+        custom.get_documentation_url(),
+        Some("http://example.org/docs/no-value")
+    );
+    assert_eq!(
+        custom.as_json()["documentationUrl"],
+        "http://example.org/docs/no-value"
+    );
+
+    let sarif: serde_json::Value =
+        serde_json::from_str(&renderer_for("sarif").unwrap().render(&report).unwrap()).unwrap();
+    let rules = sarif["runs"][0]["tool"]["driver"]["rules"]
+        .as_array()
+        .unwrap();
+    let rule = rules
+        .iter()
+        .find(|rule| rule["id"] == "http://example.org/NoValueConstraintComponent")
+        .unwrap();
+    assert_eq!(rule["help"]["text"], "Every person needs a name.");
+    assert_eq!(rule["helpUri"], "http://example.org/docs/no-value");
+
+    let json_graph = read_report_graph(&report.as_json().to_string(), "json").unwrap();
+    let rdf_graph = report.to_graph();
+    for graph in [&json_graph, &rdf_graph] {
+        let stored = ValidationReport::from_graph(graph).unwrap();
+        assert!(stored
+            .get_results()
+            .iter()
+            .any(|r| r.get_documentation_url() == Some("http://example.org/docs/no-value")));
+        assert!(stored
+            .get_results()
+            .iter()
+            .all(|r| r.get_help_text() == Some("Every person needs a name.")));
+    }
+}
@@ -0,0 +1,172 @@
+use oxigraph::model::{Graph, NamedNodeRef, TripleRef};
+use shacl_rust::rdf::read_graph_from_string;
+use shacl_rust::rules::{infer, parse_rules, validate_with_inference};
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+
+    ex:alice a ex:Person ; ex:parent ex:bob ; ex:age 30 .
+    ex:bob a ex:Person ; ex:parent ex:carol ; ex:age 60 .
+    ex:carol a ex:Person ; ex:age 85 .
+"#;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:rule [
+            a sh:TripleRule ;
+            sh:order 1 ;
+            sh:subject [ sh:path ex:parent ] ;
+            sh:predicate ex:child ;
+            sh:object sh:this ;
+        ] ;
+        sh:rule [
+            a sh:SPARQLRule ;
+            sh:order 2 ;
+            sh:prefixes ex: ;
+            sh:construct """
+                CONSTRUCT { $this ex:grandchild ?grandchild }
+                WHERE { $this ex:child/ex:child ?grandchild }
+            """ ;
+        ] ;
+        sh:rule [
+            a sh:TripleRule ;
+            sh:condition [ sh:property [ sh:path ex:age ; sh:minInclusive 65 ] ] ;
+            sh:subject sh:this ;
+            sh:predicate <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> ;
+            sh:object ex:Senior ;
+        ] ;
+        sh:rule [
+            a sh:TripleRule ;
+            sh:deactivated true ;
+            sh:subject sh:this ;
+            sh:predicate ex:ignored ;
+            sh:object sh:this ;
+        ] .
+
+    ex: sh:declare [ sh:prefix "ex" ; sh:namespace "http://example.org/"^^xsd:anyURI ] .
+
+    ex:SeniorShape a sh:NodeShape ;
+        sh:targetClass ex:Senior ;
+        sh:property [ sh:path ex:grandchild ; sh:minCount 1 ] .
+"#;
+
+fn ex(name: &str) -> NamedNodeRef<'_> {
+    NamedNodeRef::new_unchecked(match name {
+        "alice" => "http://example.org/alice",
+        "bob" => "http://example.org/bob",
+        "carol" => "http://example.org/carol",
+        "child" => "http://example.org/child",
+        "grandchild" => "http://example.org/grandchild",
+        "Senior" => "http://example.org/Senior",
+        _ => unreachable!(),
+    })
+}
+
+fn graphs() -> (Graph, Graph) {
+    (
+        read_graph_from_string(DATA, "turtle").unwrap(),
+        read_graph_from_string(SHAPES, "turtle").unwrap(),
+    )
+}
+
+#[test]
+fn test_rules_are_parsed_in_order() {
+    let (_, shapes) = graphs();
+    let orders: Vec<_> = parse_rules(&shapes)
+        .unwrap()
+        .iter()
+        .map(|rule| rule.order)
+        .collect();
+    assert_eq!(orders, [0.0, 1.0, 2.0]);
+}
+
+#[test]
+fn test_triple_and_sparql_rules_infer_triples() {
+    let (data, shapes) = graphs();
+    let inferred = infer(&data, &shapes).unwrap();
+
+    let rdf_type = NamedNodeRef::new_unchecked("http://www.w3.org/1999/02/22-rdf-syntax-ns#type");
+    for (subject, predicate, object) in [
+        ("bob", "child", "alice"),
+        ("carol", "child", "bob"),
+        ("carol", "grandchild", "alice"),
+    ] {
+        assert!(inferred.contains(TripleRef::new(ex(subject), ex(predicate), ex(object))));
+    }
+    assert!(inferred.contains(TripleRef::new(ex("carol"), rdf_type, ex("Senior"))));
+    assert!(!inferred.contains(TripleRef::new(ex("bob"), rdf_type, ex("Senior"))));
+    assert_eq!(inferred.len(), 4);
+}
+
+#[test]
+fn test_validation_sees_inferred_triples() {
+    let (data, shapes) = graphs();
+    let report = validate_with_inference(data, shapes).unwrap();
+    assert!(report.conforms(), "{}", report);
+
+    let (data, _) = graphs();
+    let shapes =
+        read_graph_from_string(&SHAPES.replace("sh:minCount 1", "sh:minCount 2"), "turtle")
+            .unwrap();
+    let report = validate_with_inference(data, shapes).unwrap();
+    assert_eq!(report.results().len(), 1);
+}
+
+#[test]
+fn test_template_blank_nodes_reach_a_fixpoint() {
+    let (data, _) = graphs();
+    let shapes = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:AddressShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:rule [
+                a sh:SPARQLRule ;
+                sh:construct """
+                    CONSTRUCT { $this <http://example.org/address> [ <http://example.org/city> "Ghent" ] }
+                    WHERE {}
+                """ ;
+            ] .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+
+    // One address per person, not a new one per pass.
+    let inferred = infer(&data, &shapes).unwrap();
+    assert_eq!(inferred.len(), 6);
+    assert_eq!(infer(&data, &shapes).unwrap(), inferred);
+}
+
+#[test]
+fn test_rules_without_a_fixpoint_are_an_error() {
+    let (data, _) = graphs();
+    let shapes = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:AgeShape a sh:NodeShape ;
+            sh:targetNode ex:carol ;
+            sh:rule [
+                a sh:SPARQLRule ;
+                sh:construct """
+                    CONSTRUCT { $this <http://example.org/age> ?older }
+                    WHERE { $this <http://example.org/age> ?age BIND (?age + 1 AS ?older) }
+                """ ;
+            ] .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+
+    let error = infer(&data, &shapes).unwrap_err();
+    assert!(error.to_string().contains("after 100 passes"), "{}", error);
+}
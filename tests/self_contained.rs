@@ -0,0 +1,87 @@
+use shacl_rust::parser::split_shapes;
+use shacl_rust::rdf::read_graph_from_string;
+use shacl_rust::validation::dataset::ValidationDataset;
+use shacl_rust::{parse_shapes, validate};
+
+const FILE: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:name ; sh:minCount 1 ] ;
+        sh:property [ sh:path ex:role ; sh:in ( "admin" "user" ) ] .
+
+    ex:alice a ex:Person ; ex:name "Alice" ; ex:role "admin" .
+    ex:bob a ex:Person .
+"#;
+
+#[test]
+fn test_split_shapes_separates_shapes_from_data() {
+    let graph = read_graph_from_string(FILE, "turtle").unwrap();
+    let (data_graph, shapes_graph) = split_shapes(&graph);
+
+    // The shape, its property shapes and the sh:in list are shapes.
+    assert_eq!(data_graph.len(), 4);
+    assert_eq!(data_graph.len() + shapes_graph.len(), graph.len());
+    assert_eq!(parse_shapes(&shapes_graph).unwrap().len(), 1);
+
+    let dataset = ValidationDataset::from_graphs(data_graph, shapes_graph).unwrap();
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+    let focus_nodes: Vec<String> = report
+        .get_results()
+        .iter()
+        .map(|result| result.get_focus_node().to_string())
+        .collect();
+    assert_eq!(focus_nodes, vec!["<http://example.org/bob>"]);
+}
+
+#[test]
+fn test_split_shapes_keeps_the_class_hierarchy_of_implicit_class_targets() {
+    let graph = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:Person a rdfs:Class, sh:NodeShape ;
+            rdfs:subClassOf ex:Agent ;
+            sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+
+        ex:AgentShape a sh:NodeShape ;
+            sh:targetClass ex:Agent ;
+            sh:property [ sh:path ex:id ; sh:minCount 1 ] .
+
+        ex:OwnerShape a sh:NodeShape ;
+            sh:targetSubjectsOf ex:owner ;
+            sh:property [ sh:path ex:owner ; sh:class ex:Agent ] .
+
+        ex:alice a ex:Person ; ex:name "Alice" .
+        ex:doc ex:owner ex:alice .
+    "#,
+        "turtle",
+    )
+    .unwrap();
+    let (data_graph, shapes_graph) = split_shapes(&graph);
+
+    let dataset = ValidationDataset::from_graphs(data_graph, shapes_graph).unwrap();
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = validate(&dataset, &shapes);
+    // ex:alice is an ex:Agent through ex:Person, so only its missing ex:id is reported.
+    let results: Vec<String> = report
+        .get_results()
+        .iter()
+        .map(|result| {
+            format!(
+                "{} {}",
+                result.get_focus_node(),
+                result.get_source_constraint_component().unwrap()
+            )
+        })
+        .collect();
+    assert_eq!(
+        results,
+        vec!["<http://example.org/alice> <http://www.w3.org/ns/shacl#MinCountConstraintComponent>"]
+    );
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use common::validate_data;
+use std::sync::Arc;
+
+use shacl_rust::core::constraints::Constraint;
+use shacl_rust::core::shape::Shape;
+use shacl_rust::parser::parse_shapes;
+use shacl_rust::rdf::read_graph_from_string;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:home ; sh:node ex:AddressShape ] ;
+        sh:property [ sh:path ex:work ; sh:or ( ex:AddressShape [ sh:nodeKind sh:IRI ] ) ] .
+
+    ex:CompanyShape a sh:NodeShape ;
+        sh:targetClass ex:Company ;
+        sh:property [ sh:path ex:seat ; sh:node ex:AddressShape ] .
+
+    ex:AddressShape sh:property [ sh:path ex:city ; sh:minCount 1 ] .
+"#;
+
+fn referenced_shapes<'a, 'g>(constraints: &'a [Constraint<'g>]) -> Vec<&'a Arc<Shape<'g>>> {
+    constraints
+        .iter()
+        .flat_map(|constraint| match constraint {
+            Constraint::Node(c) => vec![&c.0],
+            Constraint::Or(c) => c.0.iter().collect(),
+            _ => vec![],
+        })
+        .collect()
+}
+
+#[test]
+fn test_named_shapes_are_parsed_once_and_shared() {
+    let graph = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let shapes = parse_shapes(&graph).unwrap();
+
+    let references: Vec<_> = shapes
+        .iter()
+        .flat_map(|shape| &shape.property_shapes)
+        .flat_map(|property| referenced_shapes(&property.constraints))
+        .filter(|shape| shape.node.to_string() == "<http://example.org/AddressShape>")
+        .collect();
+
+    assert_eq!(references.len(), 3);
+    assert!(references
+        .iter()
+        .all(|shape| Arc::ptr_eq(shape, references[0])));
+}
+
+#[test]
+fn test_top_level_shapes_are_shared_with_their_references() {
+    let graph = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [ sh:path ex:home ; sh:node ex:AddressShape ] .
+
+        ex:AddressShape a sh:NodeShape ;
+            sh:targetClass ex:Address ;
+            sh:property [ sh:path ex:city ; sh:minCount 1 ] .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+    let shapes = parse_shapes(&graph).unwrap();
+    let shape = |name: &str| {
+        shapes
+            .iter()
+            .find(|shape| shape.node.to_string() == format!("<http://example.org/{}>", name))
+            .unwrap()
+    };
+
+    let references = referenced_shapes(&shape("PersonShape").property_shapes[0].constraints);
+    assert_eq!(references.len(), 1);
+    assert!(Arc::ptr_eq(references[0], shape("AddressShape")));
+}
+
+#[test]
+fn test_logical_constraints_sharing_a_shape_agree() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:ItemShape a sh:NodeShape ;
+            sh:targetClass ex:Item ;
+            sh:and ( ex:NamedShape [ sh:or ( ex:NamedShape ex:LabelledShape ) ] ) ;
+            sh:xone ( ex:NamedShape ex:LabelledShape ) ;
+            sh:not ex:LabelledShape ;
+            sh:node ex:NamedShape .
+
+        ex:NamedShape sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+        ex:LabelledShape sh:property [ sh:path ex:label ; sh:minCount 1 ] .
+    "#;
+    let data = r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:named a ex:Item ; ex:name "n" .
+        ex:both a ex:Item ; ex:name "n" ; ex:label "l" .
+        ex:neither a ex:Item .
+    "#;
+    let mut components: Vec<_> = validate_data(shapes, data)
+        .iter()
+        .map(|result| {
+            (
+                result["focusNode"].as_str().unwrap().to_string(),
+                result["sourceConstraintComponent"]
+                    .as_str()
+                    .unwrap()
+                    .to_string(),
+            )
+        })
+        .collect();
+    components.sort();
+    let sh = |name: &str| format!("<http://www.w3.org/ns/shacl#{}ConstraintComponent>", name);
+    assert_eq!(
+        components,
+        [
+            ("<http://example.org/both>".to_string(), sh("Not")),
+            ("<http://example.org/both>".to_string(), sh("Xone")),
+            ("<http://example.org/neither>".to_string(), sh("And")),
+            ("<http://example.org/neither>".to_string(), sh("Node")),
+            ("<http://example.org/neither>".to_string(), sh("Xone")),
+        ]
+    );
+}
@@ -0,0 +1,110 @@
+use shacl_rust::rdf::read_graph_from_string;
+use shacl_rust::shape_tests::{junit_report, read_shape_tests, run_shape_tests};
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property ex:NameShape ;
+        sh:property [ sh:path ex:age ; sh:datatype xsd:integer ] .
+
+    ex:NameShape sh:path ex:name ; sh:minCount 1 .
+"#;
+
+const TESTS: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+    @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+    ex:valid a shx:ShapeTest ;
+        rdfs:label "a named person conforms" ;
+        shx:data """
+            @prefix ex: <http://example.org/> .
+            ex:alice a ex:Person ; ex:name "Alice" ; ex:age 30 .
+        """ .
+
+    ex:missingName a shx:ShapeTest ;
+        rdfs:label "a person needs a name" ;
+        shx:data """
+            @prefix ex: <http://example.org/> .
+            ex:bob a ex:Person .
+        """ ;
+        shx:expectedResult [
+            sh:focusNode ex:bob ;
+            sh:resultPath ex:name ;
+            sh:sourceShape ex:NameShape ;
+            sh:sourceConstraintComponent sh:MinCountConstraintComponent ;
+            sh:resultSeverity sh:Violation ;
+        ] .
+
+    ex:wrongExpectation a shx:ShapeTest ;
+        rdfs:label "an age must be an <integer>" ;
+        shx:data """
+            @prefix ex: <http://example.org/> .
+            ex:carol a ex:Person ; ex:name "Carol" ; ex:age "old" .
+        """ ;
+        shx:expectedResult [ sh:sourceConstraintComponent sh:MinCountConstraintComponent ] .
+
+    ex:onlyNonConforming a shx:ShapeTest ;
+        rdfs:label "conforms false" ;
+        shx:data """
+            @prefix ex: <http://example.org/> .
+            ex:dave a ex:Person .
+        """ ;
+        shx:conforms false .
+"#;
+
+fn outcomes() -> Vec<shacl_rust::shape_tests::TestOutcome> {
+    let shapes = read_graph_from_string(SHAPES, "turtle").unwrap();
+    let tests = read_shape_tests(&read_graph_from_string(TESTS, "turtle").unwrap()).unwrap();
+    run_shape_tests(&shapes, &tests)
+}
+
+#[test]
+fn test_outcomes_of_shape_tests() {
+    let outcomes = outcomes();
+    let summary: Vec<_> = outcomes
+        .iter()
+        .map(|outcome| (outcome.name.as_str(), outcome.passed()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("a named person conforms", true),
+            ("a person needs a name", true),
+            ("an age must be an <integer>", false),
+            ("conforms false", true),
+        ]
+    );
+
+    let failures = &outcomes[2].failures;
+    assert_eq!(failures.len(), 2, "{:?}", failures);
+    assert!(failures[0].starts_with("Missing expected result [component"));
+    assert!(failures[1].contains("DatatypeConstraintComponent"));
+}
+
+#[test]
+fn test_junit_report() {
+    let junit = junit_report("people", &outcomes());
+
+    assert!(junit.contains("<testsuite name=\"people\" tests=\"4\" failures=\"1\""));
+    assert!(junit.contains("<testcase name=\"a named person conforms\" classname=\"people\""));
+    assert!(junit.contains("<testcase name=\"an age must be an &lt;integer&gt;\""));
+    assert_eq!(junit.matches("<failure message=").count(), 1);
+    assert!(junit.trim_end().ends_with("</testsuite>"));
+}
+
+#[test]
+fn test_missing_data_is_an_error() {
+    let graph = read_graph_from_string(
+        r#"<http://example.org/t> a <https://ensaremirerol.github.io/shacl-rust/ns#ShapeTest> ."#,
+        "turtle",
+    )
+    .unwrap();
+    let error = read_shape_tests(&graph).unwrap_err();
+    assert!(error.to_string().contains("has no shx:data"));
+}
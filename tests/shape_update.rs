@@ -0,0 +1,133 @@
+mod common;
+
+use common::dataset;
+use oxigraph::model::Graph;
+use oxigraph::model::NamedNodeRef;
+use shacl_rust::parser::parse_shape;
+use shacl_rust::rdf::read_graph_from_string;
+use shacl_rust::validation::dataset::{ValidationDataset, SHAPES_GRAPH_IRI};
+use shacl_rust::{parse_shapes, sh, validate, Validator};
+use std::sync::Arc;
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+
+    ex:alice a ex:Person ; ex:name "Alice" .
+    ex:bob a ex:Person .
+"#;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+
+    ex:AgeShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:age ; sh:maxCount 1 ] .
+"#;
+
+fn focus_nodes(dataset: &ValidationDataset) -> Vec<String> {
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let mut focus_nodes: Vec<String> = validate(dataset, &shapes)
+        .get_results()
+        .iter()
+        .map(|result| result.get_focus_node().to_string())
+        .collect();
+    focus_nodes.sort();
+    focus_nodes
+}
+
+fn person_shape() -> NamedNodeRef<'static> {
+    NamedNodeRef::new_unchecked("http://example.org/PersonShape")
+}
+
+fn definition() -> Graph {
+    read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [ sh:path ex:name ; sh:maxLength 3 ] .
+        "#,
+        "turtle",
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_update_shape_replaces_only_that_shape() {
+    let mut dataset = dataset(SHAPES, DATA);
+    assert_eq!(focus_nodes(&dataset), vec!["<http://example.org/bob>"]);
+    let size = dataset.shapes_graph().len();
+
+    Validator::default()
+        .update_shape(&mut dataset, person_shape(), &definition())
+        .unwrap();
+
+    // The old property shape is gone and AgeShape is untouched.
+    assert_eq!(dataset.shapes_graph().len(), size);
+    assert_eq!(focus_nodes(&dataset), vec!["<http://example.org/alice>"]);
+    assert_eq!(parse_shapes(dataset.shapes_graph()).unwrap().len(), 2);
+
+    let updated = [Arc::new(
+        parse_shape(dataset.shapes_graph(), person_shape().into(), None).unwrap(),
+    )];
+    let report = validate(&dataset, &updated);
+    assert_eq!(report.get_results().len(), 1);
+}
+
+#[test]
+fn test_update_shape_leaves_clones_with_the_old_definition() {
+    let mut dataset = dataset(SHAPES, DATA);
+    let clone = dataset.clone();
+
+    Validator::default()
+        .update_shape(&mut dataset, person_shape(), &definition())
+        .unwrap();
+
+    assert_eq!(focus_nodes(&clone), vec!["<http://example.org/bob>"]);
+    let has_min_count = |dataset: &ValidationDataset| {
+        let shapes_graph = NamedNodeRef::new_unchecked(SHAPES_GRAPH_IRI);
+        dataset
+            .store()
+            .quads_for_pattern(None, Some(sh::MIN_COUNT), None, Some(shapes_graph.into()))
+            .next()
+            .is_some()
+    };
+    assert!(has_min_count(&clone));
+    assert!(!has_min_count(&dataset));
+}
+
+#[test]
+fn test_update_shape_checks_the_new_definition_for_well_formedness() {
+    let mut dataset = dataset(SHAPES, DATA);
+    let well_formed = |dataset: &ValidationDataset| {
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        validate(dataset, &shapes).get_shapes_graph_well_formed()
+    };
+    assert_eq!(well_formed(&dataset), Some(true));
+
+    let definition = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [ sh:path ex:name ; sh:maxLength 3, 4 ] .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+    Validator::default()
+        .update_shape(&mut dataset, person_shape(), &definition)
+        .unwrap();
+
+    assert_eq!(well_formed(&dataset), Some(false));
+    assert_eq!(dataset.well_formedness_issues().len(), 1);
+}
@@ -0,0 +1,105 @@
+use oxigraph::io::RdfFormat;
+use oxigraph::model::NamedNode;
+use oxigraph::store::Store;
+use shacl_rust::rdf::read_graph_from_string;
+use shacl_rust::snapshots::{conformance_trend, snapshot_names, trend_as_json, validate_snapshot};
+
+const SNAPSHOTS: &str = r#"
+    @prefix ex: <http://example.org/> .
+
+    <http://example.org/snapshot/2024-02-01> {
+        ex:Alice a ex:Person ; ex:name "Alice" .
+        ex:Bob a ex:Person .
+    }
+    <http://example.org/snapshot/2024-01-01> {
+        ex:Alice a ex:Person .
+        ex:Bob a ex:Person .
+    }
+"#;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:PersonShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:name ; sh:minCount 1 ] ;
+        sh:property [ sh:path ex:name ; sh:maxCount 0 ; sh:severity sh:Warning ] .
+"#;
+
+fn store() -> Store {
+    let store = Store::new().unwrap();
+    store
+        .load_from_reader(RdfFormat::TriG, SNAPSHOTS.as_bytes())
+        .unwrap();
+    store
+}
+
+#[test]
+fn test_conformance_trend_orders_snapshots_by_name() {
+    let store = store();
+    let shapes = read_graph_from_string(SHAPES, "turtle").unwrap();
+
+    let trend = conformance_trend(&store, &[], &shapes).unwrap();
+    let counts: Vec<_> = trend
+        .iter()
+        .map(|s| (s.snapshot.as_str(), s.violations, s.warnings))
+        .collect();
+    assert_eq!(
+        counts,
+        [
+            ("http://example.org/snapshot/2024-01-01", 2, 0),
+            ("http://example.org/snapshot/2024-02-01", 1, 1),
+        ]
+    );
+
+    let json = trend_as_json(&trend);
+    assert_eq!(json["snapshots"][1]["total"], 2);
+    assert_eq!(json["snapshots"][1]["conforms"], false);
+    assert_ne!(trend[0].hash, trend[1].hash);
+    assert_eq!(json["snapshots"][0]["hash"], trend[0].hash.as_str());
+}
+
+#[test]
+fn test_validate_snapshot_rejects_unknown_graph() {
+    let store = store();
+    let shapes = read_graph_from_string(SHAPES, "turtle").unwrap();
+
+    assert_eq!(snapshot_names(&store).unwrap().len(), 2);
+    let missing = NamedNode::new("http://example.org/snapshot/2023-12-01").unwrap();
+    assert!(validate_snapshot(&store, missing.as_ref(), &shapes).is_err());
+}
+
+#[test]
+fn test_dataset_from_store_graphs() {
+    use oxigraph::model::{GraphNameRef, NamedNodeRef};
+    use shacl_rust::{parse_shapes, validate, validation::dataset::ValidationDataset};
+
+    let store = store();
+    let shapes_graph = NamedNodeRef::new("http://example.org/shapes").unwrap();
+    store
+        .load_from_reader(
+            oxigraph::io::RdfParser::from_format(RdfFormat::Turtle)
+                .with_default_graph(shapes_graph),
+            SHAPES.as_bytes(),
+        )
+        .unwrap();
+
+    let snapshot = NamedNodeRef::new("http://example.org/snapshot/2024-01-01").unwrap();
+    let dataset = ValidationDataset::from_store(&store, snapshot.into(), shapes_graph).unwrap();
+    assert_eq!(dataset.data_graph().len(), 2);
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    assert_eq!(validate(&dataset, &shapes).violation_count(), 2);
+
+    // The default graph of the store is empty, so nothing is targeted
+    let dataset =
+        ValidationDataset::from_store(&store, GraphNameRef::DefaultGraph, shapes_graph).unwrap();
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    assert!(*validate(&dataset, &shapes).get_conforms());
+
+    let missing = NamedNodeRef::new("http://example.org/missing").unwrap();
+    let error = ValidationDataset::from_store(&store, snapshot.into(), missing)
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("does not exist"));
+}
@@ -0,0 +1,488 @@
+mod common;
+
+use shacl_rust::{parser::parse_shapes, rdf::read_graph_from_string, validation::validate};
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:PrefixConstraintComponent a sh:ConstraintComponent ;
+        sh:parameter [ sh:path ex:prefix ; sh:datatype xsd:string ] ;
+        sh:validator [
+            a sh:SPARQLAskValidator ;
+            sh:ask "ASK { FILTER (isLiteral($value) && STRSTARTS(STR($value), $prefix)) }" ;
+        ] .
+"#;
+
+fn validate_strings(data: &str, shapes: &str) -> Vec<serde_json::Value> {
+    common::validate_data(&format!("{}{}", SHAPES, shapes), data)
+}
+
+#[test]
+fn test_ask_validator_runs_per_value_node() {
+    let results = validate_strings(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:code "AB-1", "CD-2", "EF-3" .
+        "#,
+        r#"
+        ex:Shape a sh:NodeShape ;
+            sh:targetNode ex:Alice ;
+            sh:property [ sh:path ex:code ; ex:prefix "AB-" ] .
+        "#,
+    );
+
+    let mut values: Vec<_> = results
+        .iter()
+        .map(|r| r["value"].as_str().unwrap().to_string())
+        .collect();
+    values.sort();
+
+    assert_eq!(values, vec!["\"CD-2\"", "\"EF-3\""]);
+}
+
+#[test]
+fn test_ask_validator_binds_focus_node_as_value_in_node_shape() {
+    let results = validate_strings(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:name "Alice" .
+        "#,
+        r#"
+        ex:Shape a sh:NodeShape ;
+            sh:targetNode "AB-1", "CD-2" ;
+            ex:prefix "AB-" .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["focusNode"], "\"CD-2\"");
+    assert_eq!(results[0]["value"], "\"CD-2\"");
+}
+
+#[test]
+fn test_ask_validator_skips_property_without_values() {
+    let results = validate_strings(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:name "Alice" .
+        "#,
+        r#"
+        ex:Shape a sh:NodeShape ;
+            sh:targetNode ex:Alice ;
+            sh:property [ sh:path ex:code ; ex:prefix "AB-" ] .
+        "#,
+    );
+
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_path_is_prebound_for_single_predicate() {
+    let results = validate_strings(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:knows ex:Alice, ex:Bob .
+        "#,
+        r#"
+        ex:Shape a sh:NodeShape ;
+            sh:targetNode ex:Alice ;
+            sh:property [
+                sh:path ex:knows ;
+                sh:sparql [
+                    sh:select "SELECT $this ?value WHERE { $this $PATH ?value . FILTER (sameTerm($this, ?value)) }" ;
+                ] ;
+            ] .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["value"], "<http://example.org/Alice>");
+}
+
+#[test]
+fn test_path_reference_is_flagged_for_complex_paths() {
+    let results = validate_strings(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:knows ex:Bob .
+        "#,
+        r#"
+        ex:Shape a sh:NodeShape ;
+            sh:targetNode ex:Alice ;
+            sh:property [
+                sh:path ( ex:knows ex:knows ) ;
+                sh:sparql [
+                    sh:select "SELECT $this WHERE { $this $PATH ?value }" ;
+                ] ;
+            ] .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["messages"][0], "SPARQL pre-binding violation");
+}
+
+#[test]
+fn test_invalid_parameter_value_skips_only_that_component() {
+    let dataset = common::dataset(
+        &format!(
+            "{}{}",
+            SHAPES,
+            r#"
+            ex:Shape a sh:NodeShape ;
+                sh:targetNode ex:Alice ;
+                sh:class ex:Person ;
+                ex:prefix 42 .
+            "#
+        ),
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:name "Alice" .
+        "#,
+    );
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    assert_eq!(shapes.len(), 1);
+
+    let report = validate(&dataset, &shapes);
+    let results = common::results(&report);
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0]["sourceConstraintComponent"],
+        "<http://www.w3.org/ns/shacl#ClassConstraintComponent>"
+    );
+
+    let features = report.get_unsupported_features();
+    assert_eq!(features.len(), 1);
+    assert_eq!(
+        features[0].feature,
+        "<http://example.org/PrefixConstraintComponent>"
+    );
+    assert!(features[0].reason.contains("http://example.org/prefix"));
+    assert!(features[0].reason.contains("XMLSchema#string"));
+}
+
+#[test]
+fn test_invalid_parameter_value_is_reported_with_missing_mandatory_parameter() {
+    let dataset = common::dataset(
+        &format!(
+            "{}{}",
+            SHAPES,
+            r#"
+            ex:AffixConstraintComponent a sh:ConstraintComponent ;
+                sh:parameter [ sh:path ex:affix ; sh:datatype xsd:string ] ;
+                sh:parameter [ sh:path ex:suffix ; sh:datatype xsd:string ] ;
+                sh:validator [
+                    a sh:SPARQLAskValidator ;
+                    sh:ask "ASK { FILTER (STRENDS(STR($value), $suffix)) }" ;
+                ] .
+
+            ex:Shape a sh:NodeShape ;
+                sh:targetNode ex:Alice ;
+                ex:affix 42 .
+            "#
+        ),
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:name "Alice" .
+        "#,
+    );
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+
+    let report = validate(&dataset, &shapes);
+    let features = report.get_unsupported_features();
+    assert_eq!(features.len(), 1);
+    assert_eq!(
+        features[0].feature,
+        "<http://example.org/AffixConstraintComponent>"
+    );
+    assert!(features[0].reason.contains("http://example.org/affix"));
+}
+
+#[test]
+fn test_dataset_constraint_reports_synthetic_focus_node() {
+    let results = validate_strings(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        ex:First a owl:Ontology .
+        ex:Second a owl:Ontology .
+        "#,
+        r#"
+        @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+        ex:SingleOntologyHeader a shx:DatasetConstraint ;
+            sh:message "Expected exactly one ontology header, found {?count}" ;
+            sh:select """
+                SELECT (COUNT(?ontology) AS ?count) WHERE {
+                    ?ontology a <http://www.w3.org/2002/07/owl#Ontology> .
+                }
+                HAVING (COUNT(?ontology) != 1)
+            """ .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0]["focusNode"],
+        "<https://ensaremirerol.github.io/shacl-rust/ns#Dataset>"
+    );
+    assert_eq!(
+        results[0]["messages"][0],
+        "Expected exactly one ontology header, found \"2\"^^<http://www.w3.org/2001/XMLSchema#integer>"
+    );
+}
+
+#[test]
+fn test_dataset_constraint_reports_value_per_solution() {
+    let results = validate_strings(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:id "1" .
+        ex:Bob ex:id "1" .
+        ex:Carol ex:id "2" .
+        "#,
+        r#"
+        @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+        ex:UniqueIds a shx:DatasetConstraint ;
+            sh:severity sh:Warning ;
+            sh:select """
+                SELECT ?value WHERE { ?s <http://example.org/id> ?value }
+                GROUP BY ?value HAVING (COUNT(?s) > 1)
+            """ .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["value"], "\"1\"");
+    assert_eq!(
+        results[0]["severity"],
+        "<http://www.w3.org/ns/shacl#Warning>"
+    );
+}
+
+#[test]
+fn test_constraints_call_sparql_functions_of_the_shapes_graph() {
+    let results = validate_strings(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:knows ex:Bob ; ex:age 30 .
+        ex:Bob ex:age 12 .
+        ex:Carol ex:knows ex:Alice ; ex:age 40 .
+        "#,
+        r#"
+        ex:isAdult a sh:SPARQLFunction ;
+            sh:parameter [ sh:path ex:person ] ;
+            sh:returnType xsd:boolean ;
+            sh:ask "ASK { $person <http://example.org/age> ?age . FILTER (?age >= 18) }" .
+
+        ex:adultCount a sh:SPARQLFunction ;
+            sh:parameter [ sh:path ex:second ; sh:order 2 ; sh:optional true ] ;
+            sh:parameter [ sh:path ex:first ; sh:order 1 ] ;
+            sh:select """
+                SELECT (COUNT(?p) AS ?count) WHERE {
+                    { BIND ($first AS ?p) } UNION { BIND ($second AS ?p) }
+                    FILTER (<http://example.org/isAdult>(?p))
+                }
+            """ .
+
+        ex:Shape a sh:NodeShape ;
+            sh:targetSubjectsOf ex:knows ;
+            sh:property [
+                sh:path ex:knows ;
+                sh:sparql [
+                    sh:select """
+                        SELECT $this ?value WHERE {
+                            $this $PATH ?value .
+                            FILTER (!<http://example.org/isAdult>(?value))
+                            FILTER (<http://example.org/adultCount>($this, ?value) = 1)
+                        }
+                    """ ;
+                ] ;
+            ] .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["focusNode"], "<http://example.org/Alice>");
+    assert_eq!(results[0]["value"], "<http://example.org/Bob>");
+}
+
+#[test]
+fn test_select_queries_project_pre_bound_variables() {
+    let results = validate_strings(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:code "AB-1" .
+        ex:Bob ex:code "CD-2" .
+        "#,
+        r#"
+        ex:identity a sh:SPARQLFunction ;
+            sh:parameter [ sh:path ex:node ] ;
+            sh:select "SELECT $node WHERE {}" .
+
+        ex:Shape a sh:NodeShape ;
+            sh:targetSubjectsOf ex:code ;
+            sh:sparql [
+                sh:message "{$this} has code {?code}" ;
+                sh:select """
+                    SELECT $this ?code WHERE {
+                        $this <http://example.org/code> ?code .
+                        FILTER (<http://example.org/identity>($this) = $this)
+                        FILTER (!STRSTARTS(?code, "AB-"))
+                    }
+                """ ;
+            ] .
+
+        ex:SuffixConstraintComponent a sh:ConstraintComponent ;
+            sh:parameter [ sh:path ex:suffix ; sh:datatype xsd:string ] ;
+            sh:nodeValidator [
+                a sh:SPARQLSelectValidator ;
+                sh:message "{$value} does not end with {$suffix}" ;
+                sh:select "SELECT $this $value WHERE { FILTER (!STRENDS(STR($value), $suffix)) }" ;
+            ] .
+
+        ex:SuffixShape a sh:NodeShape ;
+            sh:targetNode "AB-1", "AB-2" ;
+            ex:suffix "-1" .
+        "#,
+    );
+
+    // The function body returns its pre-bound parameter, and solutions carry
+    // the pre-bound $this and $value
+    let mut results: Vec<_> = results
+        .iter()
+        .map(|r| {
+            (
+                r["focusNode"].as_str().unwrap().to_string(),
+                r["value"].as_str().map(str::to_string),
+                r["messages"][0].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    results.sort();
+    assert_eq!(
+        results,
+        [
+            (
+                "\"AB-2\"".to_string(),
+                Some("\"AB-2\"".to_string()),
+                "\"AB-2\" does not end with \"-1\"".to_string(),
+            ),
+            (
+                "<http://example.org/Bob>".to_string(),
+                None,
+                "http://example.org/Bob has code \"CD-2\"".to_string(),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_groups_and_aggregates_see_pre_bound_variables() {
+    let results = validate_strings(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:code "AB-1", "AB-2" .
+        ex:Bob ex:code "CD-1" .
+        "#,
+        r#"
+        ex:Shape a sh:NodeShape ;
+            sh:targetSubjectsOf ex:code ;
+            sh:sparql [
+                sh:message "{?self} has {?count} codes" ;
+                sh:select """
+                    SELECT $this (SAMPLE($this) AS ?self) (COUNT(?code) AS ?count) WHERE {
+                        $this <http://example.org/code> ?code .
+                    }
+                    GROUP BY $this
+                    HAVING (COUNT(?code) > 1)
+                """ ;
+            ] .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["focusNode"], "<http://example.org/Alice>");
+    assert_eq!(
+        results[0]["messages"][0],
+        "http://example.org/Alice has \"2\"^^<http://www.w3.org/2001/XMLSchema#integer> codes"
+    );
+}
+
+#[test]
+fn test_sparql_functions_are_parsed_in_argument_order() {
+    let shapes_graph = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        ex:concat a sh:SPARQLFunction ;
+            sh:parameter [ sh:path ex:b ; sh:order 1 ] ;
+            sh:parameter [ sh:path ex:a ; sh:order 0 ; sh:optional true ] ;
+            sh:select "SELECT (CONCAT($a, $b) AS ?result) WHERE {}" .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+
+    let functions =
+        shacl_rust::validation::functions::parse_sparql_functions(&shapes_graph).unwrap();
+    assert_eq!(functions.len(), 1);
+    let names: Vec<_> = functions[0]
+        .parameters
+        .iter()
+        .map(|parameter| (parameter.name.as_str(), parameter.optional))
+        .collect();
+    assert_eq!(names, vec![("a", true), ("b", false)]);
+}
+
+#[test]
+fn test_each_value_of_a_parameter_is_a_constraint() {
+    let results = validate_strings(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:code "AB-1" .
+        "#,
+        r#"
+        ex:Shape a sh:NodeShape ;
+            sh:targetNode ex:Alice ;
+            sh:property [ sh:path ex:code ; ex:prefix "AB-", "CD-" ] .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["value"], "\"AB-1\"");
+}
+
+#[test]
+fn test_specific_validator_takes_precedence_and_label_template_is_the_message() {
+    let results = validate_strings(
+        r#"
+        @prefix ex: <http://example.org/> .
+        ex:Alice ex:code "AB-1", "AB-2" .
+        "#,
+        r#"
+        ex:MaxValuesConstraintComponent a sh:ConstraintComponent ;
+            sh:labelTemplate "At most {$maxValues} values" ;
+            sh:parameter [ sh:path ex:maxValues ; sh:datatype xsd:integer ] ;
+            sh:parameter [ sh:path ex:strict ; sh:optional true ] ;
+            sh:validator [ sh:ask "ASK { FILTER (false) }" ] ;
+            sh:propertyValidator [
+                sh:select """
+                    SELECT $this WHERE { $this $PATH ?value }
+                    GROUP BY $this HAVING (COUNT(?value) > $maxValues)
+                """ ;
+            ] .
+
+        ex:Shape a sh:NodeShape ;
+            sh:targetNode ex:Alice ;
+            sh:property [ sh:path ex:code ; ex:maxValues 1 ] .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0]["messages"][0],
+        "At most \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> values"
+    );
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use common::validate_data;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:LinkShape a sh:NodeShape ;
+        sh:targetClass ex:Link ;
+        sh:property [ sh:path ex:target ; sh:minLength 20 ; sh:maxLength 30 ] .
+"#;
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+
+    ex:fits a ex:Link ; ex:target <http://example.org/page> .
+    ex:short a ex:Link ; ex:target <http://example.org/> .
+    ex:long a ex:Link ; ex:target <http://example.org/a/much/longer/page> .
+    ex:blank a ex:Link ; ex:target [ ex:label "anonymous" ] .
+    ex:literal a ex:Link ; ex:target "http://example.org/page" .
+"#;
+
+fn messages_for(focus_node: &str) -> Vec<String> {
+    let mut messages: Vec<String> = validate_data(SHAPES, DATA)
+        .iter()
+        .filter(|result| result["focusNode"] == format!("<http://example.org/{}>", focus_node))
+        .flat_map(|result| result["messages"].as_array().unwrap().clone())
+        .map(|message| message.as_str().unwrap().to_string())
+        .collect();
+    messages.sort();
+    messages
+}
+
+#[test]
+fn test_iri_lengths_use_their_string_representation() {
+    assert!(messages_for("fits").is_empty());
+    assert!(messages_for("literal").is_empty());
+    assert_eq!(
+        messages_for("short"),
+        ["IRI length 19 is less than minimum 20"]
+    );
+    assert_eq!(messages_for("long"), ["IRI length 37 exceeds maximum 30"]);
+}
+
+#[test]
+fn test_blank_nodes_fail_length_constraints() {
+    assert_eq!(
+        messages_for("blank"),
+        [
+            "Blank nodes have no string length",
+            "Blank nodes have no string length"
+        ]
+    );
+}
@@ -249,3 +249,159 @@ fn test_target_objects_of_only_blank_nodes() {
     // Should find 2 blank nodes (_:blank4 and _:blank5)
     assert_eq!(result.len(), 2);
 }
+
+#[test]
+fn test_validator_adds_focus_nodes_from_target_resolvers() {
+    use oxigraph::model::{NamedNode, Term};
+    use shacl_rust::{parse_shapes, validation::dataset::ValidationDataset, Validator};
+    use std::collections::HashSet;
+
+    let shapes = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:NamedShape a sh:NodeShape ;
+            sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+    let dataset = ValidationDataset::from_graphs(setup_graph(), shapes).unwrap();
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    assert!(*shacl_rust::validate(&dataset, &shapes).get_conforms());
+
+    let validator = Validator::builder()
+        .with_target_resolver(
+            NamedNode::new("http://example.org/NamedShape").unwrap(),
+            |graph| {
+                let works_at = NamedNodeRef::new_unchecked("http://example.org/worksAt");
+                graph
+                    .triples_for_predicate(works_at)
+                    .map(|triple| Term::from(triple.subject.into_owned()))
+                    .chain([Term::from(
+                        NamedNode::new("http://example.org/Unknown").unwrap(),
+                    )])
+                    .collect::<HashSet<_>>()
+            },
+        )
+        .build();
+    let report = validator.validate(&dataset, &shapes);
+
+    let mut focus_nodes: Vec<_> = report
+        .get_results()
+        .iter()
+        .map(|r| r.get_focus_node().to_string())
+        .collect();
+    focus_nodes.sort();
+    assert_eq!(focus_nodes, ["<http://example.org/Charlie>"]);
+}
+
+#[test]
+fn test_sparql_target_selects_focus_nodes() {
+    use shacl_rust::{parse_shapes, validate, validation::dataset::ValidationDataset};
+
+    let shapes = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex: sh:declare [ sh:prefix "ex" ; sh:namespace "http://example.org/"^^xsd:anyURI ] .
+
+        ex:ColleagueShape a sh:NodeShape ;
+            sh:target [
+                a sh:SPARQLTarget ;
+                sh:prefixes ex: ;
+                sh:select """
+                    SELECT ?this WHERE {
+                        ?this ex:worksAt ?company .
+                        ?other ex:worksAt ?company .
+                        FILTER (?this != ?other)
+                    }
+                """ ;
+            ] ;
+            sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+    let dataset = ValidationDataset::from_graphs(setup_graph(), shapes).unwrap();
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+
+    let target = *shapes[0].targets.iter().next().unwrap();
+    assert!(matches!(target, Target::Sparql(_)));
+    let alice = NamedNodeRef::new("http://example.org/Alice").unwrap();
+    let charlie = NamedNodeRef::new("http://example.org/Charlie").unwrap();
+    let focus_nodes = target.resolve_target(&dataset);
+    assert_eq!(focus_nodes.len(), 2);
+    assert!(focus_nodes.contains(&alice.into()));
+    assert!(focus_nodes.contains(&charlie.into()));
+    assert!(target
+        .resolve_target_for_given_graph(dataset.data_graph())
+        .is_empty());
+
+    let report = validate(&dataset, &shapes);
+    let focus_nodes: Vec<_> = report
+        .get_results()
+        .iter()
+        .map(|r| r.get_focus_node().to_string())
+        .collect();
+    assert_eq!(focus_nodes, ["<http://example.org/Charlie>"]);
+}
+
+#[test]
+fn test_validator_resolves_registered_target_types() {
+    use oxigraph::model::{NamedNode, NamedOrBlankNodeRef, Term, TermRef};
+    use shacl_rust::{parse_shapes, validate, validation::dataset::ValidationDataset, Validator};
+    use std::collections::HashSet;
+
+    let shapes = read_graph_from_string(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:EmployeeShape a sh:NodeShape ;
+            sh:target [ a ex:EmployeesOf ; ex:company ex:CompanyX ] ;
+            sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+        "#,
+        "turtle",
+    )
+    .unwrap();
+    let dataset = ValidationDataset::from_graphs(setup_graph(), shapes).unwrap();
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+
+    let report = validate(&dataset, &shapes);
+    assert!(*report.get_conforms());
+    assert_eq!(report.get_unsupported_features().len(), 1);
+
+    let validator = Validator::builder()
+        .with_target_type(
+            NamedNode::new("http://example.org/EmployeesOf").unwrap(),
+            |dataset: &ValidationDataset, target: NamedOrBlankNodeRef<'_>| {
+                let company = NamedNodeRef::new_unchecked("http://example.org/company");
+                let works_at = NamedNodeRef::new_unchecked("http://example.org/worksAt");
+                let Some(TermRef::NamedNode(company)) = dataset
+                    .shapes_graph()
+                    .object_for_subject_predicate(target, company)
+                else {
+                    return HashSet::new();
+                };
+                dataset
+                    .data_graph()
+                    .subjects_for_predicate_object(works_at, company)
+                    .map(|employee| Term::from(employee.into_owned()))
+                    .collect()
+            },
+        )
+        .build();
+    let report = validator.validate(&dataset, &shapes);
+
+    let focus_nodes: Vec<_> = report
+        .get_results()
+        .iter()
+        .map(|r| r.get_focus_node().to_string())
+        .collect();
+    assert_eq!(focus_nodes, ["<http://example.org/Charlie>"]);
+    assert!(report.get_unsupported_features().is_empty());
+}
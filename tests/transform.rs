@@ -0,0 +1,101 @@
+mod common;
+
+use common::{dataset, validate_dataset};
+use shacl_rust::core::ValueTransform;
+use shacl_rust::validation::config::ValidationConfig;
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+
+    ex:Alice ex:code "  ab12  " ; ex:label "cafe\u0301" .
+"#;
+
+fn results(shapes: &str, config: ValidationConfig) -> Vec<serde_json::Value> {
+    validate_dataset(&dataset(shapes, DATA).with_config(config))
+}
+
+#[test]
+fn test_value_transform_names_round_trip() {
+    for transform in ValueTransform::ALL {
+        assert_eq!(ValueTransform::from_name(transform.name()), Some(transform));
+    }
+    assert_eq!(ValueTransform::Nfc.apply("cafe\u{301}"), "caf\u{e9}");
+    assert_eq!(ValueTransform::CaseFold.apply("ÄB"), "äb");
+    assert_eq!(ValueTransform::from_name("upper"), None);
+}
+
+#[test]
+fn test_run_transforms_apply_before_facet_checks() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:Shape a sh:NodeShape ;
+            sh:targetNode ex:Alice ;
+            sh:property [ sh:path ex:code ; sh:pattern "^[a-z]{2}[0-9]{2}$" ; sh:maxLength 4 ] ;
+            sh:property [ sh:path ex:label ; sh:maxLength 4 ] .
+    "#;
+    assert_eq!(results(shapes, ValidationConfig::default()).len(), 3);
+
+    let trimmed = results(
+        shapes,
+        ValidationConfig {
+            value_transforms: vec![ValueTransform::Trim],
+            ..ValidationConfig::default()
+        },
+    );
+    assert_eq!(trimmed.len(), 1);
+    assert!(trimmed[0].get("trace").is_none());
+
+    let normalized = results(
+        shapes,
+        ValidationConfig {
+            value_transforms: vec![ValueTransform::Trim, ValueTransform::Nfc],
+            ..ValidationConfig::default()
+        },
+    );
+    assert!(normalized.is_empty());
+}
+
+#[test]
+fn test_shape_transforms_are_inherited_and_traced() {
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+
+        ex:Shape a sh:NodeShape ;
+            shx:valueTransform "trim", "casefold" ;
+            sh:targetNode ex:Alice ;
+            sh:property [ sh:path ex:code ; sh:pattern "^[A-Z]{2}[0-9]{2}$" ] .
+    "#;
+    let results = results(shapes, ValidationConfig::default());
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0]["trace"][0],
+        "Value \"  ab12  \" was transformed to \"ab12\" by trim"
+    );
+}
+
+#[test]
+fn test_unicode_mode_counts_grapheme_clusters() {
+    let config = ValidationConfig {
+        unicode_mode: true,
+        ..ValidationConfig::default()
+    };
+    assert_eq!(config.string_length("cafe\u{301}"), 4);
+    assert_eq!(config.string_length("\u{1F469}\u{200D}\u{1F4BB}"), 1);
+    assert_eq!(ValidationConfig::default().string_length("cafe\u{301}"), 5);
+
+    let shapes = r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:Shape a sh:NodeShape ;
+            sh:targetNode ex:Alice ;
+            sh:property [ sh:path ex:label ; sh:maxLength 4 ; sh:pattern "^caf\u00e9$" ] .
+    "#;
+    assert_eq!(results(shapes, ValidationConfig::default()).len(), 2);
+    assert!(results(shapes, config).is_empty());
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use common::validate_data;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix shx: <https://ensaremirerol.github.io/shacl-rust/ns#> .
+    @prefix unit: <http://qudt.org/vocab/unit/> .
+
+    ex:SensorShape a sh:NodeShape ;
+        sh:targetClass ex:Sensor ;
+        sh:property [ sh:path ex:height ; shx:unit unit:M ; sh:maxInclusive 2 ] ;
+        sh:property [ sh:path ex:temperature ; shx:unit unit:DEG_C ; sh:minExclusive 0 ] .
+"#;
+
+#[test]
+fn test_quantity_values_are_converted_before_comparison() {
+    let results = validate_data(
+        SHAPES,
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix qudt: <http://qudt.org/schema/qudt/> .
+        @prefix unit: <http://qudt.org/vocab/unit/> .
+
+        ex:a a ex:Sensor ;
+            ex:height [ qudt:numericValue 150 ; qudt:unit unit:CentiM ] , 1.5 ;
+            ex:temperature [ qudt:numericValue 40 ; qudt:unit unit:DEG_F ] .
+        ex:b a ex:Sensor ;
+            ex:height [ qudt:numericValue 7 ; qudt:unit unit:FT ] ;
+            ex:temperature [ qudt:numericValue 275 ; qudt:unit unit:K ] .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1, "{:?}", results);
+    assert_eq!(results[0]["focusNode"], "<http://example.org/b>");
+    assert!(results[0]["trace"][0]
+        .as_str()
+        .unwrap()
+        .starts_with("Quantity value 7 <http://qudt.org/vocab/unit/FT> was converted to 2.13"));
+}
+
+#[test]
+fn test_incomparable_units_are_reported() {
+    let results = validate_data(
+        SHAPES,
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix qudt: <http://qudt.org/schema/qudt/> .
+        @prefix unit: <http://qudt.org/vocab/unit/> .
+
+        ex:a a ex:Sensor ;
+            ex:height [ qudt:numericValue 1 ; qudt:unit unit:KiloGM ] ;
+            ex:temperature [ qudt:numericValue 1 ; qudt:unit ex:Furlong ] .
+        "#,
+    );
+
+    let mut messages: Vec<_> = results
+        .iter()
+        .map(|r| r["messages"][0].as_str().unwrap().to_string())
+        .collect();
+    messages.sort();
+    assert_eq!(
+        messages,
+        vec![
+            "Unit <http://example.org/Furlong> has no known conversion",
+            "Unit <http://qudt.org/vocab/unit/KiloGM> cannot be compared with <http://qudt.org/vocab/unit/M>",
+        ]
+    );
+}
+
+#[test]
+fn test_unit_conversions_are_read_from_the_graphs() {
+    let results = validate_data(
+        SHAPES,
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix qudt: <http://qudt.org/schema/qudt/> .
+        @prefix unit: <http://qudt.org/vocab/unit/> .
+
+        ex:Furlong qudt:conversionMultiplier 201.168 ;
+            qudt:hasDimensionVector <http://qudt.org/vocab/dimensionvector/A0E0L1I0M0H0T0D0> .
+        ex:a a ex:Sensor ; ex:height [ qudt:numericValue 0.005 ; qudt:unit ex:Furlong ] .
+        ex:b a ex:Sensor ; ex:height [ qudt:numericValue 1 ; qudt:unit ex:Furlong ] .
+        "#,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["focusNode"], "<http://example.org/b>");
+}
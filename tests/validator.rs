@@ -0,0 +1,170 @@
+mod common;
+
+use common::dataset;
+use shacl_rust::validation::dataset::ValidationDataset;
+use shacl_rust::{
+    parse_shapes, CancellationToken, Severity, ValidationOptions, ValidationReport, Validator,
+};
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+
+    ex:a a ex:Person .
+    ex:b a ex:Person .
+    ex:c a ex:Person .
+    ex:d a ex:Person .
+"#;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+    ex:NameShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+
+    ex:NicknameShape a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:severity sh:Info ;
+        sh:property [ sh:path ex:nickname ; sh:minCount 1 ] .
+"#;
+
+fn with_dataset(check: impl FnOnce(&ValidationDataset)) {
+    let dataset = dataset(SHAPES, DATA);
+    check(&dataset);
+}
+
+/// Severities of the results of a run with `options`.
+fn run(dataset: &ValidationDataset, options: ValidationOptions) -> Vec<String> {
+    let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+    let report = Validator::builder()
+        .with_options(options)
+        .build()
+        .validate(dataset, &shapes);
+    assert_eq!(*report.get_conforms(), report.get_results().is_empty());
+    report
+        .get_results()
+        .iter()
+        .map(|result| result.get_severity().to_string())
+        .collect()
+}
+
+#[test]
+fn test_default_options_report_everything() {
+    with_dataset(|dataset| {
+        assert_eq!(run(dataset, ValidationOptions::default()).len(), 8);
+    });
+}
+
+#[test]
+fn test_max_violations_and_fail_fast_stop_early() {
+    with_dataset(|dataset| {
+        let capped = ValidationOptions {
+            max_violations: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(run(dataset, capped).len(), 3);
+
+        let fail_fast = ValidationOptions {
+            fail_fast: true,
+            max_violations: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(run(dataset, fail_fast).len(), 1);
+    });
+}
+
+#[test]
+fn test_severity_filter_skips_other_shapes() {
+    with_dataset(|dataset| {
+        let violations_only = ValidationOptions {
+            severity_filter: Some(vec![Severity::Violation]),
+            ..Default::default()
+        };
+        let severities = run(dataset, violations_only);
+        assert_eq!(severities.len(), 4);
+        assert!(severities
+            .iter()
+            .all(|severity| *severity == Severity::Violation.to_string()));
+    });
+}
+
+#[test]
+fn test_streaming_hands_over_the_reported_results() {
+    with_dataset(|dataset| {
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        let mut streamed = Vec::new();
+        let report = Validator::default().validate_streaming(dataset, &shapes, |result| {
+            streamed.push(result.fingerprint())
+        });
+
+        let mut reported: Vec<_> = report
+            .get_results()
+            .iter()
+            .map(|result| result.fingerprint())
+            .collect();
+        reported.sort();
+        streamed.sort();
+        assert_eq!(streamed, reported);
+
+        let mut capped = 0;
+        Validator::builder()
+            .with_options(ValidationOptions {
+                max_violations: Some(2),
+                ..Default::default()
+            })
+            .build()
+            .validate_streaming(dataset, &shapes, |_| capped += 1);
+        assert_eq!(capped, 2);
+    });
+}
+
+#[test]
+fn test_cancelled_runs_stop() {
+    with_dataset(|dataset| {
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        let token = CancellationToken::new();
+        let validator = Validator::builder()
+            .with_cancellation(token.clone())
+            .build();
+        assert_eq!(validator.validate(dataset, &shapes).get_results().len(), 8);
+
+        token.clone().cancel();
+        assert!(token.is_cancelled());
+        let report = validator.validate(dataset, &shapes);
+        assert!(report.get_results().is_empty());
+        // An aborted run must not pass for a clean one.
+        assert!(report.is_cancelled());
+        assert!(!*report.get_conforms());
+        assert_eq!(report.as_json()["cancelled"], true);
+        let graph = report.to_graph();
+        assert!(ValidationReport::from_graph(&graph).unwrap().is_cancelled());
+        assert!(report.into_owned().as_report().is_cancelled());
+    });
+}
+
+#[test]
+fn test_stepwise_runs_can_be_cancelled_between_steps() {
+    with_dataset(|dataset| {
+        let shapes = parse_shapes(dataset.shapes_graph()).unwrap();
+        let token = CancellationToken::new();
+        let validator = Validator::builder()
+            .with_cancellation(token.clone())
+            .build();
+
+        let mut run = validator.start(dataset, &shapes);
+        assert!(run.step(1));
+        assert!(!run.step(1));
+        let report = run.finish();
+        assert_eq!(report.get_results().len(), 8);
+        assert!(!report.is_cancelled());
+
+        let mut run = validator.start(dataset, &shapes);
+        assert!(run.step(1));
+        token.cancel();
+        let report = run.finish();
+        assert_eq!(report.get_results().len(), 4);
+        assert!(report.is_cancelled());
+        assert!(!*report.get_conforms());
+    });
+}
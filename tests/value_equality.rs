@@ -0,0 +1,61 @@
+mod common;
+
+use common::{dataset, validate_dataset};
+use shacl_rust::validation::config::ValidationConfig;
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:OrderShape a sh:NodeShape ;
+        sh:targetClass ex:Order ;
+        sh:property [ sh:path ex:quantity ; sh:equals ex:shipped ] ;
+        sh:property [ sh:path ex:quantity ; sh:disjoint ex:returned ] ;
+        sh:property [ sh:path ex:priority ; sh:in ( 1 2 3 ) ] ;
+        sh:property [ sh:path ex:paid ; sh:hasValue true ] .
+"#;
+
+const DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:order a ex:Order ;
+        ex:quantity "1"^^xsd:int ;
+        ex:shipped "01"^^xsd:integer ;
+        ex:returned "1.0"^^xsd:decimal ;
+        ex:priority "2"^^xsd:byte ;
+        ex:paid "1"^^xsd:boolean .
+"#;
+
+fn components(compare_by_value: bool) -> Vec<String> {
+    let dataset = dataset(SHAPES, DATA).with_config(ValidationConfig {
+        compare_by_value,
+        ..ValidationConfig::default()
+    });
+
+    let mut components: Vec<_> = validate_dataset(&dataset)
+        .iter()
+        .filter_map(|result| result["sourceConstraintComponent"].as_str())
+        .map(str::to_string)
+        .collect();
+    components.sort();
+    components
+}
+
+fn sh(component: &str) -> String {
+    format!(
+        "<http://www.w3.org/ns/shacl#{}ConstraintComponent>",
+        component
+    )
+}
+
+#[test]
+fn test_terms_compare_as_terms_by_default() {
+    assert_eq!(components(false), [sh("Equals"), sh("HasValue"), sh("In")]);
+}
+
+#[test]
+fn test_literals_compare_by_value_when_enabled() {
+    assert_eq!(components(true), [sh("Disjoint")]);
+}
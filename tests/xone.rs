@@ -0,0 +1,54 @@
+mod common;
+
+use common::validate_data;
+
+#[test]
+fn test_xone_counts_conforming_branches() {
+    let graph =
+        std::fs::read_to_string("tests/resources/core/logical/xone-nested-001.ttl").unwrap();
+    let results = validate_data(&graph, &graph);
+
+    let ex = |name: &str| {
+        format!(
+            "<http://datashapes.org/sh/tests/core/logical/xone-nested-001.test#{}>",
+            name
+        )
+    };
+    let result_for = |name: &str| {
+        results
+            .iter()
+            .find(|result| result["focusNode"] == ex(name).as_str())
+            .unwrap_or_else(|| panic!("No result for {}", name))
+    };
+    assert_eq!(results.len(), 3);
+
+    let none = result_for("AAndB");
+    assert_eq!(
+        none["messages"][0],
+        "Value conforms to none of the 2 shapes in sh:xone, expected exactly one"
+    );
+    // The nested xone and the sh:minCount of the other branch.
+    let details = none["details"].as_array().unwrap();
+    assert_eq!(details.len(), 2);
+    let nested = details
+        .iter()
+        .find(|detail| {
+            detail["sourceConstraintComponent"]
+                == "<http://www.w3.org/ns/shacl#XoneConstraintComponent>"
+        })
+        .unwrap();
+    assert!(nested["messages"][0]
+        .as_str()
+        .unwrap()
+        .starts_with("Value conforms to 2 of the 2 shapes in sh:xone"));
+    assert_eq!(nested["details"], serde_json::Value::Null);
+
+    let both = result_for("AAndC");
+    assert!(both["messages"][0]
+        .as_str()
+        .unwrap()
+        .starts_with("Value conforms to 2 of the 2 shapes in sh:xone, expected exactly one: "));
+    assert_eq!(both["details"], serde_json::Value::Null);
+
+    assert_eq!(result_for("Empty")["details"].as_array().unwrap().len(), 2);
+}